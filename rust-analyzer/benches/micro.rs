@@ -0,0 +1,138 @@
+//! Micro-benchmarks
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Hot paths on synthetic data: `cargo bench` or `cargo bench -- hash`
+//! to run only the benchmarks whose name contains "hash"
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use ue5_fast_startup::asm_bindings::{asm_available, HashState};
+use ue5_fast_startup::graph::{DependencyGraph, DependencyType};
+use ue5_fast_startup::hash::{hash_bytes, turbo_hash_with, TurboStrategy};
+use ue5_fast_startup::scanner::{AssetInfo, AssetType};
+use ue5_fast_startup::testgen::package_bytes;
+use ue5_fast_startup::uasset::UAssetParser;
+use xxhash_rust::xxh3::xxh3_64;
+
+/// Scratch directory for the benchmarks that read files
+fn bench_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("uefast_bench_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).expect("create bench directory");
+    dir
+}
+
+fn synthetic_data(len: usize) -> Vec<u8> {
+    let mut state = 0x9E37_79B9_7F4A_7C15u64;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect()
+}
+
+/// `hash_bytes` against the scalar xxh3 and the ASM (or fallback) streaming state
+fn bench_hash_bytes(c: &mut Criterion) {
+    let asm = if asm_available() { "asm" } else { "asm_fallback" };
+    let mut group = c.benchmark_group("hash_bytes");
+    for size in [4 * 1024, 256 * 1024, 16 * 1024 * 1024] {
+        let data = synthetic_data(size);
+        let label = format!("{}K", size / 1024);
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(BenchmarkId::new("hash_bytes", &label), &data, |b, data| {
+            b.iter(|| hash_bytes(black_box(data)))
+        });
+        group.bench_with_input(BenchmarkId::new("scalar", &label), &data, |b, data| {
+            b.iter(|| xxh3_64(black_box(data)))
+        });
+        group.bench_with_input(BenchmarkId::new(asm, &label), &data, |b, data| {
+            b.iter(|| {
+                let mut state = HashState::new(0);
+                for chunk in black_box(data).chunks(32 * 1024) {
+                    state.update(chunk);
+                }
+                state.finalize()
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_turbo_hash(c: &mut Criterion) {
+    let dir = bench_dir();
+    let path = dir.join("Large.ubulk");
+    std::fs::write(&path, synthetic_data(64 * 1024 * 1024)).expect("write turbo input");
+
+    let mut group = c.benchmark_group("turbo_hash");
+    for strategy in [TurboStrategy::ThreeWindow, TurboStrategy::HeadOnly] {
+        group.bench_function(strategy.algorithm(), |b| {
+            b.iter(|| turbo_hash_with(&path, strategy).expect("turbo hash"))
+        });
+    }
+    group.finish();
+    let _ = std::fs::remove_file(&path);
+}
+
+fn write_package(dir: &Path, count: usize) -> PathBuf {
+    let names: Vec<String> = (0..count).map(|i| format!("/Game/Content/Asset_{:06}", i)).collect();
+    let imports: Vec<&str> = names.iter().map(|n| n.as_str()).collect();
+    let path = dir.join(format!("Names_{}.uasset", count));
+    std::fs::write(&path, package_bytes("/Game/Bench/Names", &imports, 0)).expect("write package");
+    assert!(UAssetParser::parse_name_table(&path).expect("parse names").len() > count);
+    path
+}
+
+fn bench_name_table(c: &mut Criterion) {
+    let dir = bench_dir();
+    let mut group = c.benchmark_group("name_table");
+    for count in [100, 5_000] {
+        let path = write_package(&dir, count);
+        group.bench_with_input(BenchmarkId::new("parse_name_table", count), &path, |b, path| {
+            b.iter(|| UAssetParser::parse_name_table(path).expect("parse names"))
+        });
+        group.bench_with_input(BenchmarkId::new("parse_imports", count), &path, |b, path| {
+            b.iter(|| UAssetParser::parse_imports(path).expect("parse imports"))
+        });
+    }
+    group.finish();
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+fn bench_toposort(c: &mut Criterion) {
+    let mut group = c.benchmark_group("toposort");
+    for count in [1_000, 20_000] {
+        let assets: Vec<AssetInfo> = (0..count)
+            .map(|i| AssetInfo {
+                path: PathBuf::from(format!("/Project/Content/A_{}.uasset", i)),
+                relative_path: format!("Content/A_{}.uasset", i),
+                asset_type: AssetType::UAsset,
+                size_bytes: 1024,
+                modified: 0,
+                mount_point: "/Game".to_string(),
+            })
+            .collect();
+
+        let mut graph = DependencyGraph::new();
+        for asset in &assets {
+            graph.add_asset(asset);
+        }
+        // Each asset depends on up to four earlier ones: a DAG with fan-out
+        for (i, asset) in assets.iter().enumerate() {
+            for step in [1, 7, 31, 127] {
+                if i >= step {
+                    graph.add_dependency(&asset.path, &assets[i - step].path, DependencyType::Import, true);
+                }
+            }
+        }
+
+        group.bench_function(BenchmarkId::from_parameter(count), |b| b.iter(|| graph.compute_load_order()));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_hash_bytes, bench_turbo_hash, bench_name_table, bench_toposort);
+criterion_main!(benches);
@@ -4,57 +4,385 @@
 //!
 //! Analyzes UE5 project startup patterns and provides optimization recommendations
 
-use crate::graph::DependencyGraph;
+use crate::config_preloads::{self, ConfigPreload};
+use crate::content::ContentProvider;
+use crate::editor_data::{EditorData, NeverCookedAsset};
+use crate::editor_open::EditorOpenReport;
+use crate::anomalies::{scan_content, ContentAnomaly};
+use crate::chunks::{late_chunk_assets, ChunkManifests, LateChunkAsset};
+use crate::config::ProjectConfig;
+use crate::estimator::LoadTimeModel;
+use crate::graph::{DependencyGraph, ParserCoverage, SharedSubtree};
+use crate::hardware::HardwareProfile;
 use crate::hash::hash_file;
-use crate::scanner::{AssetInfo, AssetScanner, AssetType};
+use crate::i18n::{tr, Locale};
+use crate::imports::{ImportUsageReport, StaleImport};
+use crate::localization::{find_string_tables, StringTableInfo};
+use crate::rule_packs::{self, RuleHit, RulePack};
+use crate::sampling::{AssetSample, Estimate, SampleSummary};
+use crate::scenarios::{resolve_all, ScenarioConfig, ScenarioReport};
+use crate::source_scan::SourceScan;
+use crate::scanner::{
+    find_case_collisions, is_startup_candidate, AssetFilter, AssetInfo, AssetScanner, AssetType, CaseCollision,
+    StartupHeuristics,
+};
+use crate::tables::{inspect_tables, DataTableInfo};
+use crate::tags::AssetTags;
+use crate::resident_memory::ResidentMemory;
+use crate::ui::{UiReport, MAX_STARTUP_FONT_PAGES};
+use crate::versions::VersionMatrix;
 use crate::Result;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tracing::info;
 
+/// Minimum startup referrers before a shared subtree is reported
+const SHARED_SUBTREE_MIN_REFERRERS: usize = 3;
+
+/// Ignore shared subtrees lighter than this (1 MB)
+const SHARED_SUBTREE_MIN_BYTES: u64 = 1024 * 1024;
+
+/// Flag startup widgets whose textures and fonts exceed this (20 MB)
+const UI_DEPENDENCY_BYTES: u64 = 20 * 1024 * 1024;
+
+/// Startup data tables above this size (10 MB) should be split or async loaded
+const LARGE_TABLE_BYTES: u64 = 10 * 1024 * 1024;
+
 pub struct StartupAnalyzer {
     project_root: PathBuf,
+    sample: Option<AssetSample>,
+    model: LoadTimeModel,
+    hardware: Option<HardwareProfile>,
+    filter: AssetFilter,
+    scenarios: BTreeMap<String, ScenarioConfig>,
+    locale: Locale,
+    content: Option<Arc<dyn ContentProvider>>,
+    scan_source: bool,
+    rule_packs: Vec<RulePack>,
+    editor_data: Option<EditorData>,
+    /// The project's `.uefast.toml`: exclude globs and startup heuristics
+    config: ProjectConfig,
 }
 
 impl StartupAnalyzer {
     pub fn new(project_root: &Path) -> Result<Self> {
         Ok(Self {
             project_root: project_root.to_path_buf(),
+            sample: None,
+            model: LoadTimeModel::default(),
+            hardware: None,
+            filter: AssetFilter::default(),
+            scenarios: BTreeMap::new(),
+            locale: Locale::default(),
+            content: None,
+            scan_source: false,
+            rule_packs: Vec::new(),
+            editor_data: None,
+            config: ProjectConfig::default(),
         })
     }
 
+    /// Scan with the exclude globs and startup heuristics of `config`, the
+    /// project's loaded `.uefast.toml`
+    pub fn with_config(mut self, config: &ProjectConfig) -> Self {
+        self.config = config.clone();
+        self
+    }
+
+    /// Analyze only a reproducible sample of assets and extrapolate totals
+    pub fn with_sample(mut self, sample: AssetSample) -> Self {
+        self.sample = Some(sample);
+        self
+    }
+
+    /// Analyze only assets within the size and age bounds of `filter`
+    pub fn with_filter(mut self, filter: AssetFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Estimate load times with calibrated coefficients instead of defaults
+    pub fn with_model(mut self, model: LoadTimeModel) -> Self {
+        self.model = model;
+        self
+    }
+
+    /// Record the machine profile the estimates were made for
+    pub fn with_hardware(mut self, hardware: HardwareProfile) -> Self {
+        self.hardware = Some(hardware);
+        self
+    }
+
+    /// Also analyze these named startup sets
+    pub fn with_scenarios(mut self, scenarios: BTreeMap<String, ScenarioConfig>) -> Self {
+        self.scenarios = scenarios;
+        self
+    }
+
+    /// Language of recommendation and report text
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Scan through `content` (e.g. base game plus patch layers) instead of
+    /// the local `Content` directory
+    pub fn with_content(mut self, content: Arc<dyn ContentProvider>) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    /// Also scan the C++ `Source` folders for module startup work
+    pub fn with_source_scan(mut self, enabled: bool) -> Self {
+        self.scan_source = enabled;
+        self
+    }
+
+    /// Evaluate these external rule packs on the finished report
+    pub fn with_rule_packs(mut self, rule_packs: Vec<RulePack>) -> Self {
+        self.rule_packs = rule_packs;
+        self
+    }
+
+    /// Dependencies, classes and primary asset rules exported from the
+    /// editor, used instead of package parsing wherever they cover an asset
+    pub fn with_editor_data(mut self, editor_data: EditorData) -> Self {
+        self.editor_data = Some(editor_data);
+        self
+    }
+
+    /// The project configuration given to `with_config`
+    pub fn config(&self) -> &ProjectConfig {
+        &self.config
+    }
+
+    /// Dependency graph of `assets`, from editor data where available
+    pub fn build_graph(&self, assets: &[AssetInfo]) -> Result<DependencyGraph> {
+        DependencyGraph::build_with_editor_data(&self.project_root, assets, self.editor_data.as_ref())
+    }
+
+    fn scanner(&self) -> Result<AssetScanner> {
+        Ok(match &self.content {
+            Some(content) => AssetScanner::from_provider(&self.project_root, content.clone())?,
+            None => AssetScanner::new(&self.project_root)?,
+        }
+        .with_config(&self.config)
+        .with_filter(self.filter.clone()))
+    }
+
+    fn project_name(&self) -> String {
+        self.project_root
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default()
+    }
+
+    /// What opening each of `targets` in the editor loads
+    pub fn editor_open(&self, targets: &[String]) -> Result<EditorOpenReport> {
+        info!("Estimating editor open cost of {} assets...", targets.len());
+        let assets = self.scanner()?.scan_all(None)?;
+        let graph = self.build_graph(&assets)?;
+        Ok(EditorOpenReport::build(
+            &self.project_name(),
+            &self.project_root,
+            &graph,
+            &assets,
+            self.editor_data.as_ref(),
+            targets,
+            &self.model,
+        ))
+    }
+
     pub fn analyze(&self, include_shaders: bool) -> Result<AnalysisReport> {
         info!("Starting project analysis...");
 
-        let scanner = AssetScanner::new(&self.project_root)?;
-        let assets = scanner.scan_all(None)?;
+        let scanner = self.scanner()?;
+        let mut assets = scanner.scan_all(None)?;
+
+        if let Some(sample) = &self.sample {
+            assets = sample.select(assets);
+            info!(
+                "Sampled {} assets ({:.1}%, seed {})",
+                assets.len(),
+                sample.fraction() * 100.0,
+                sample.seed()
+            );
+        }
+
+        let graph = self.build_graph(&assets)?;
+        self.analyze_scanned(&scanner, assets, &graph, include_shaders)
+    }
 
+    /// Analyze assets from an earlier scan (and sample) of `scanner`'s
+    /// project together with their dependency graph
+    pub fn analyze_scanned(
+        &self,
+        scanner: &AssetScanner,
+        assets: Vec<AssetInfo>,
+        graph: &DependencyGraph,
+        include_shaders: bool,
+    ) -> Result<AnalysisReport> {
         let total_assets = assets.len();
         let total_size: u64 = assets.iter().map(|a| a.size_bytes).sum();
 
+        // Feature tags imported with `tags import`
+        let tags = AssetTags::load(&self.project_root)?;
+
+        // Assets the config forces to load are startup roots regardless of path
+        let mut config_preloads = config_preloads::load(&self.project_root);
+        config_preloads::resolve(&mut config_preloads, &assets);
+        let preload_roots = config_preloads::roots(&config_preloads);
+
         // Identify startup-critical assets
-        let startup_assets = scanner.scan_startup_critical()?;
+        let startup_assets: Vec<AssetInfo> = assets
+            .iter()
+            .filter(|a| is_startup_candidate(a, &self.config.startup) || preload_roots.contains_key(a.relative_path.as_str()))
+            .cloned()
+            .collect();
         let startup_count = startup_assets.len();
         let startup_size: u64 = startup_assets.iter().map(|a| a.size_bytes).sum();
 
-        // Build dependency graph
-        let graph = DependencyGraph::build(&self.project_root)?;
+        let mut startup_asset_list: Vec<StartupAssetEntry> = startup_assets
+            .iter()
+            .map(|a| StartupAssetEntry {
+                relative_path: a.relative_path.clone(),
+                size_bytes: a.size_bytes,
+                tags: tags.tags_of(&a.relative_path).map(str::to_string).collect(),
+                preloaded_by: preload_roots.get(a.relative_path.as_str()).map(|p| p.attribution()),
+            })
+            .collect();
+        startup_asset_list.sort_by(|a, b| {
+            b.size_bytes.cmp(&a.size_bytes).then_with(|| a.relative_path.cmp(&b.relative_path))
+        });
+
+        let sample = self.sample.map(|sample| SampleSummary {
+            fraction: sample.fraction(),
+            seed: sample.seed(),
+            sampled_assets: total_assets,
+            estimated_total_assets: sample.extrapolate(assets.iter().map(|_| 1.0)),
+            estimated_total_size_bytes: sample
+                .extrapolate(assets.iter().map(|a| a.size_bytes as f64)),
+            estimated_startup_assets: sample.extrapolate(startup_assets.iter().map(|_| 1.0)),
+            estimated_startup_size_bytes: sample
+                .extrapolate(startup_assets.iter().map(|a| a.size_bytes as f64)),
+        });
+
+        // Find heavy subtrees redundantly hard-referenced by startup assets
+        let startup_paths: HashSet<PathBuf> = startup_assets
+            .iter()
+            .map(|a| a.path.clone())
+            .collect();
+        let shared_subtrees: Vec<SharedSubtree> = graph
+            .find_shared_subtrees(&startup_paths, SHARED_SUBTREE_MIN_REFERRERS)
+            .into_iter()
+            .filter(|s| s.subtree_size_bytes >= SHARED_SUBTREE_MIN_BYTES)
+            .collect();
+
+        // Assets hard-referencing each other in a loop
+        let dependency_cycles: Vec<Vec<String>> = graph
+            .find_hard_cycles()
+            .iter()
+            .map(|cycle| {
+                cycle
+                    .iter()
+                    .map(|path| path.strip_prefix(&self.project_root).unwrap_or(path).to_string_lossy().replace('\\', "/"))
+                    .collect()
+            })
+            .collect();
+        if !dependency_cycles.is_empty() {
+            info!("Found {} hard reference cycles", dependency_cycles.len());
+        }
+
+        // Startup closure size as a range, widened by uncertain edges
+        let startup_closure_size = graph.closure_size_estimate(&startup_paths);
+
+        let scenarios: Vec<ScenarioReport> = resolve_all(&self.scenarios, &self.project_root, graph, &assets, &tags)
+            .iter()
+            .map(|(name, set)| ScenarioReport::new(name, set, &assets))
+            .collect();
+
+        // Widgets and fonts anywhere in the hard startup closure
+        let closure = graph.hard_closure_of(&startup_paths);
+        let closure_assets: Vec<AssetInfo> = assets
+            .iter()
+            .filter(|a| closure.contains(&a.path))
+            .cloned()
+            .collect();
+        let ui = UiReport::build(&closure_assets, &assets);
+
+        // Startup content placed after chunk 0, by the cooked chunk
+        // manifests or else by the editor's registry and primary asset rules
+        let manifests = ChunkManifests::load(&self.project_root)?;
+        if let Some(manifests) = &manifests {
+            info!("Read {} chunk assignments for {}", manifests.len(), manifests.platform);
+        }
+        let late_chunks = late_chunk_assets(&closure_assets, |asset| {
+            manifests
+                .as_ref()
+                .and_then(|m| m.chunk_of(asset))
+                .or_else(|| self.editor_data.as_ref().and_then(|e| e.chunk_of(asset)))
+        });
+
+        // What the startup closure keeps resident at the main menu
+        let resident_memory = ResidentMemory::build(&closure_assets, &assets, self.editor_data.as_ref());
+
+        // Startup content the Asset Manager keeps out of shipping cooks
+        let never_cooked = self
+            .editor_data
+            .as_ref()
+            .map(|editor_data| editor_data.never_cooked(&closure_assets))
+            .unwrap_or_default();
+
+        // Hard imports left behind by refactors keep dead content in the
+        // startup closure
+        let import_usage = ImportUsageReport::build(&startup_assets);
+        info!(
+            "Checked imports of {} startup assets: {} stale package imports",
+            import_usage.checked_assets,
+            import_usage.stale_imports.len()
+        );
+
+        // Unsmudged LFS pointers fail to load; line ending drift breaks
+        // cache verification across machines
+        let content_anomalies = scan_content(&self.project_root);
+
+        // Paths differing only by case break cooking on case-sensitive platforms
+        let case_collisions = find_case_collisions(assets.iter().map(|a| a.relative_path.as_str()));
+
+        // Data and curve tables are deserialized in full when loaded
+        let data_tables = inspect_tables(&startup_assets);
+        let string_tables = find_string_tables(&assets, &startup_assets);
 
         // Analyze asset types
         let mut by_type: HashMap<String, TypeStats> = HashMap::new();
+        // Editor classes tell textures, blueprints, ... apart among .uassets
+        let asset_type = |asset: &AssetInfo| match &self.editor_data {
+            Some(editor_data) => editor_data.asset_type(asset),
+            None => asset.asset_type,
+        };
         for asset in &assets {
             let entry = by_type
-                .entry(asset.asset_type.as_str().to_string())
-                .or_insert(TypeStats::default());
+                .entry(asset_type(asset).as_str().to_string())
+                .or_default();
             entry.count += 1;
             entry.total_size += asset.size_bytes;
         }
+        let by_tag = tag_stats(&tags, &assets, &self.config.startup);
 
         // Find duplicate content
         let duplicates = self.find_duplicates(&assets)?;
 
+        // Compare serialized package versions against the project engine
+        let engine_association = scanner
+            .project()
+            .map(|p| p.engine_association().to_string());
+        let version_matrix = engine_association
+            .as_deref()
+            .map(|assoc| VersionMatrix::build(assoc, &assets));
+
         // Analyze shader usage if requested
         let shader_analysis = if include_shaders {
             Some(self.analyze_shaders(&assets)?)
@@ -62,37 +390,79 @@ impl StartupAnalyzer {
             None
         };
 
+        let source = self.scan_source.then(|| {
+            info!("Scanning C++ sources for module startup work...");
+            SourceScan::scan(&self.project_root, &assets)
+        });
+
         // Calculate estimated savings
-        let estimated_savings = self.estimate_savings(
-            total_assets,
-            startup_count,
-            &duplicates,
-        );
+        let estimated_savings = self.estimate_savings(&assets, &duplicates);
 
-        let recommendations = self.generate_recommendations(
+        let mut recommendations = self.generate_recommendations(
             total_assets,
             startup_count,
             &by_type,
         );
+        recommendations.extend(self.shared_subtree_recommendations(&shared_subtrees));
+        recommendations.extend(self.stale_import_recommendations(&import_usage.stale_imports));
+        recommendations.extend(self.case_collision_recommendations(&case_collisions));
+        recommendations.extend(self.cycle_recommendations(&dependency_cycles));
+        recommendations.extend(self.content_anomaly_recommendations(&content_anomalies));
+        recommendations.extend(self.data_table_recommendations(&data_tables));
+        recommendations.extend(self.string_table_recommendations(&string_tables));
+        recommendations.extend(self.ui_recommendations(&ui));
+        recommendations.extend(self.late_chunk_recommendations(&late_chunks));
+        recommendations.extend(self.never_cooked_recommendations(&never_cooked));
+        if let Some(matrix) = &version_matrix {
+            recommendations.extend(self.version_recommendations(matrix));
+        }
+        if let Some(source) = &source {
+            recommendations.extend(self.source_recommendations(source));
+        }
 
-        let report = AnalysisReport {
-            project_name: self.project_root
-                .file_name()
-                .map(|n| n.to_string_lossy().to_string())
-                .unwrap_or_default(),
+        let mut report = AnalysisReport {
+            project_name: self.project_name(),
             total_assets,
             startup_assets: startup_count,
             total_size_bytes: total_size,
             startup_size_bytes: startup_size,
+            startup_closure_size_bytes: startup_closure_size,
+            scenarios,
+            startup_asset_list,
             by_type,
+            by_tag,
             dependency_count: graph.edge_count(),
+            parser_coverage: graph.coverage().clone(),
             duplicate_count: duplicates.len(),
             duplicates,
             shader_analysis,
+            shared_subtrees,
+            stale_imports: import_usage.stale_imports,
+            content_anomalies,
+            case_collisions,
+            dependency_cycles,
+            data_tables,
+            string_tables,
+            ui,
+            late_chunks,
+            resident_memory,
+            source,
+            config_preloads,
+            hardware: self.hardware.clone(),
+            engine_association,
+            version_matrix,
+            sample,
             estimated_savings_seconds: estimated_savings,
             recommendations,
+            rule_hits: Vec::new(),
+            never_cooked,
+            language: self.locale,
         };
 
+        let (rule_hits, rule_recommendations) = rule_packs::evaluate(&self.rule_packs, &report);
+        report.rule_hits = rule_hits;
+        report.recommendations.extend(rule_recommendations);
+
         info!("Analysis complete");
         Ok(report)
     }
@@ -152,16 +522,11 @@ impl StartupAnalyzer {
         })
     }
 
-    fn estimate_savings(
-        &self,
-        total_assets: usize,
-        startup_assets: usize,
-        duplicates: &[DuplicateGroup],
-    ) -> f64 {
-        // Rough estimation based on typical UE5 startup patterns
-        let non_startup = total_assets - startup_assets;
-        let deferred_load_savings = non_startup as f64 * 0.01; // ~10ms per deferred asset
-        
+    fn estimate_savings(&self, assets: &[AssetInfo], duplicates: &[DuplicateGroup]) -> f64 {
+        // Load time of everything that can be deferred past startup
+        let deferred = assets.iter().filter(|a| !is_startup_candidate(a, &self.config.startup));
+        let deferred_load_savings = self.model.estimate_ms(deferred) / 1000.0;
+
         let duplicate_savings = duplicates.len() as f64 * 0.05; // ~50ms per duplicate avoided
         
         deferred_load_savings + duplicate_savings
@@ -181,10 +546,7 @@ impl StartupAnalyzer {
             recommendations.push(Recommendation {
                 priority: Priority::High,
                 category: "Startup".to_string(),
-                message: format!(
-                    "{}% of assets are loaded at startup. Consider lazy loading.",
-                    (startup_ratio * 100.0) as u32
-                ),
+                message: tr(self.locale, "rec.startup_ratio", &[("percent", &((startup_ratio * 100.0) as u32))]),
                 estimated_impact_seconds: startup_ratio * 10.0,
             });
         }
@@ -195,10 +557,7 @@ impl StartupAnalyzer {
                 recommendations.push(Recommendation {
                     priority: Priority::Medium,
                     category: "Textures".to_string(),
-                    message: format!(
-                        "{} textures found. Consider using texture streaming.",
-                        textures.count
-                    ),
+                    message: tr(self.locale, "rec.textures", &[("count", &textures.count)]),
                     estimated_impact_seconds: 5.0,
                 });
             }
@@ -210,10 +569,7 @@ impl StartupAnalyzer {
                 recommendations.push(Recommendation {
                     priority: Priority::Medium,
                     category: "Blueprints".to_string(),
-                    message: format!(
-                        "{} blueprints found. Consider nativizing hot paths.",
-                        blueprints.count
-                    ),
+                    message: tr(self.locale, "rec.blueprints", &[("count", &blueprints.count)]),
                     estimated_impact_seconds: 3.0,
                 });
             }
@@ -221,6 +577,361 @@ impl StartupAnalyzer {
 
         recommendations
     }
+
+    fn version_recommendations(&self, matrix: &VersionMatrix) -> Vec<Recommendation> {
+        let mut recommendations = Vec::new();
+
+        if !matrix.newer.is_empty() {
+            recommendations.push(Recommendation {
+                priority: Priority::High,
+                category: "EngineVersion".to_string(),
+                message: tr(
+                    self.locale,
+                    "rec.engine_newer",
+                    &[("count", &matrix.newer.len()), ("engine", &matrix.engine_association)],
+                ),
+                estimated_impact_seconds: 0.0,
+            });
+        }
+
+        if !matrix.older.is_empty() {
+            recommendations.push(Recommendation {
+                priority: Priority::Medium,
+                category: "EngineVersion".to_string(),
+                message: tr(
+                    self.locale,
+                    "rec.engine_older",
+                    &[("count", &matrix.older.len()), ("engine", &matrix.engine_association)],
+                ),
+                // ~2ms of on-load conversion per outdated package
+                estimated_impact_seconds: matrix.older.len() as f64 * 0.002,
+            });
+        }
+
+        recommendations
+    }
+
+    fn stale_import_recommendations(&self, stale: &[StaleImport]) -> Vec<Recommendation> {
+        if stale.is_empty() {
+            return Vec::new();
+        }
+
+        let importers: HashSet<&str> = stale.iter().map(|s| s.relative_path.as_str()).collect();
+        vec![Recommendation {
+            priority: Priority::Low,
+            category: "StaleImport".to_string(),
+            message: tr(
+                self.locale,
+                "rec.stale_imports",
+                &[("imports", &stale.len()), ("assets", &importers.len())],
+            ),
+            // ~5ms per package pulled into the startup closure for nothing
+            estimated_impact_seconds: stale.len() as f64 * 0.005,
+        }]
+    }
+
+    fn case_collision_recommendations(&self, collisions: &[CaseCollision]) -> Vec<Recommendation> {
+        if collisions.is_empty() {
+            return Vec::new();
+        }
+
+        let examples: Vec<String> = collisions.iter().take(3).map(|c| c.paths.join(" vs ")).collect();
+        vec![Recommendation {
+            priority: Priority::High,
+            category: "CaseCollision".to_string(),
+            message: tr(
+                self.locale,
+                "rec.case_collisions",
+                &[("count", &collisions.len()), ("examples", &examples.join(", "))],
+            ),
+            estimated_impact_seconds: 0.0,
+        }]
+    }
+
+    fn cycle_recommendations(&self, cycles: &[Vec<String>]) -> Vec<Recommendation> {
+        if cycles.is_empty() {
+            return Vec::new();
+        }
+
+        let examples: Vec<String> = cycles.iter().take(3).map(|c| c.join(" <-> ")).collect();
+        vec![Recommendation {
+            priority: Priority::High,
+            category: "DependencyCycle".to_string(),
+            message: tr(
+                self.locale,
+                "rec.hard_cycles",
+                &[
+                    ("count", &cycles.len()),
+                    ("assets", &cycles.iter().map(Vec::len).sum::<usize>()),
+                    ("examples", &examples.join(", ")),
+                ],
+            ),
+            estimated_impact_seconds: 0.0,
+        }]
+    }
+
+    fn content_anomaly_recommendations(&self, anomalies: &[ContentAnomaly]) -> Vec<Recommendation> {
+        let pointers = anomalies.iter().filter(|a| a.kind.is_broken_asset()).count();
+        let line_endings = anomalies.len() - pointers;
+        let mut recommendations = Vec::new();
+
+        if pointers > 0 {
+            recommendations.push(Recommendation {
+                priority: Priority::High,
+                category: "LfsPointer".to_string(),
+                message: tr(self.locale, "rec.lfs_pointers", &[("count", &pointers)]),
+                estimated_impact_seconds: 0.0,
+            });
+        }
+        if line_endings > 0 {
+            recommendations.push(Recommendation {
+                priority: Priority::Low,
+                category: "LineEndings".to_string(),
+                message: tr(self.locale, "rec.line_endings", &[("count", &line_endings)]),
+                estimated_impact_seconds: 0.0,
+            });
+        }
+        recommendations
+    }
+
+    fn data_table_recommendations(&self, tables: &[DataTableInfo]) -> Vec<Recommendation> {
+        tables
+            .iter()
+            .filter(|t| t.size_bytes >= LARGE_TABLE_BYTES)
+            .take(5)
+            .map(|table| {
+                let rows = table.row_count
+                    .map(|r| tr(self.locale, "rec.data_table.rows", &[("count", &r)]))
+                    .unwrap_or_else(|| tr(self.locale, "rec.data_table.unknown_rows", &[]));
+                let row_struct = table.row_struct
+                    .as_deref()
+                    .map(|s| tr(self.locale, "rec.data_table.row_struct", &[("name", &s)]))
+                    .unwrap_or_default();
+
+                Recommendation {
+                    priority: if table.size_bytes >= 5 * LARGE_TABLE_BYTES {
+                        Priority::High
+                    } else {
+                        Priority::Medium
+                    },
+                    category: "DataTable".to_string(),
+                    message: tr(
+                        self.locale,
+                        "rec.data_table",
+                        &[
+                            ("class", &table.table_class),
+                            ("path", &table.relative_path),
+                            ("rows", &rows),
+                            ("row_struct", &row_struct),
+                            ("mb", &format!("{:.1}", table.size_bytes as f64 / (1024.0 * 1024.0))),
+                        ],
+                    ),
+                    // ~20ms per MB of table deserialization
+                    estimated_impact_seconds: table.size_bytes as f64 / (1024.0 * 1024.0) * 0.02,
+                }
+            })
+            .collect()
+    }
+
+    fn string_table_recommendations(&self, tables: &[StringTableInfo]) -> Vec<Recommendation> {
+        let loaded: Vec<&StringTableInfo> = tables
+            .iter()
+            .filter(|t| !t.startup_referencers.is_empty())
+            .collect();
+        if loaded.is_empty() {
+            return Vec::new();
+        }
+
+        let entries: usize = loaded.iter().filter_map(|t| t.entry_count).sum();
+        let bytes: u64 = loaded.iter().map(|t| t.size_bytes).sum();
+        let referencers: HashSet<&str> = loaded
+            .iter()
+            .flat_map(|t| t.startup_referencers.iter().map(String::as_str))
+            .collect();
+
+        vec![Recommendation {
+            priority: if bytes >= LARGE_TABLE_BYTES { Priority::Medium } else { Priority::Low },
+            category: "Localization".to_string(),
+            message: tr(
+                self.locale,
+                "rec.string_tables",
+                &[
+                    ("tables", &loaded.len()),
+                    ("entries", &entries),
+                    ("mb", &format!("{:.1}", bytes as f64 / (1024.0 * 1024.0))),
+                    ("assets", &referencers.len()),
+                ],
+            ),
+            // ~1ms per table plus ~10us per entry
+            estimated_impact_seconds: loaded.len() as f64 * 0.001 + entries as f64 * 0.00001,
+        }]
+    }
+
+    fn source_recommendations(&self, source: &SourceScan) -> Vec<Recommendation> {
+        let mut recommendations: Vec<Recommendation> = source
+            .hotspots()
+            .map(|startup| Recommendation {
+                priority: if startup.is_early() { Priority::High } else { Priority::Medium },
+                category: "Modules".to_string(),
+                message: tr(
+                    self.locale,
+                    "rec.startup_module",
+                    &[
+                        ("module", &startup.module),
+                        ("location", &startup.location),
+                        ("phase", &startup.loading_phase.as_deref().unwrap_or("Default")),
+                        ("calls", &startup.blocking_calls.len()),
+                        ("kinds", &startup.blocking_kinds()),
+                        ("assets", &startup.assets.len()),
+                        ("mb", &format!("{:.1}", startup.asset_bytes as f64 / (1024.0 * 1024.0))),
+                    ],
+                ),
+                estimated_impact_seconds: startup.estimated_seconds(),
+            })
+            .collect();
+
+        let eager = source.eager_module_loads();
+        if !eager.is_empty() {
+            recommendations.push(Recommendation {
+                priority: Priority::Low,
+                category: "Modules".to_string(),
+                message: tr(
+                    self.locale,
+                    "rec.eager_modules",
+                    &[("count", &eager.len()), ("modules", &eager.join(", "))],
+                ),
+                // ~10ms per module initialized ahead of use
+                estimated_impact_seconds: eager.len() as f64 * 0.01,
+            });
+        }
+        recommendations
+    }
+
+    fn never_cooked_recommendations(&self, never_cooked: &[NeverCookedAsset]) -> Vec<Recommendation> {
+        if never_cooked.is_empty() {
+            return Vec::new();
+        }
+
+        let examples: Vec<String> = never_cooked
+            .iter()
+            .take(3)
+            .map(|n| format!("{} ({})", n.relative_path, n.rule))
+            .collect();
+        vec![Recommendation {
+            priority: Priority::High,
+            category: "NeverCooked".to_string(),
+            message: tr(
+                self.locale,
+                "rec.never_cooked",
+                &[("count", &never_cooked.len()), ("examples", &examples.join(", "))],
+            ),
+            estimated_impact_seconds: 0.0,
+        }]
+    }
+
+    fn ui_recommendations(&self, ui: &UiReport) -> Vec<Recommendation> {
+        let mut recommendations = Vec::new();
+        let mb = |bytes: u64| bytes as f64 / (1024.0 * 1024.0);
+
+        let ui_bytes = ui.total_texture_bytes + ui.total_font_bytes;
+        if ui_bytes >= UI_DEPENDENCY_BYTES {
+            recommendations.push(Recommendation {
+                priority: Priority::Medium,
+                category: "UI".to_string(),
+                message: tr(
+                    self.locale,
+                    "rec.ui_art",
+                    &[
+                        ("widgets", &ui.widgets.len()),
+                        ("texture_mb", &format!("{:.1}", mb(ui.total_texture_bytes))),
+                        ("font_mb", &format!("{:.1}", mb(ui.total_font_bytes))),
+                    ],
+                ),
+                // ~20ms per MB of UI content
+                estimated_impact_seconds: mb(ui_bytes) * 0.02,
+            });
+        }
+
+        for font in ui.heavy_fonts().take(5) {
+            recommendations.push(Recommendation {
+                priority: Priority::Medium,
+                category: "UI".to_string(),
+                message: tr(
+                    self.locale,
+                    "rec.heavy_font",
+                    &[
+                        ("path", &font.relative_path),
+                        ("pages", &font.pages()),
+                        ("max", &MAX_STARTUP_FONT_PAGES),
+                    ],
+                ),
+                // ~15ms per font face loaded
+                estimated_impact_seconds: (font.pages() - MAX_STARTUP_FONT_PAGES) as f64 * 0.015,
+            });
+        }
+
+        recommendations
+    }
+
+    fn late_chunk_recommendations(&self, late_chunks: &[LateChunkAsset]) -> Vec<Recommendation> {
+        if late_chunks.is_empty() {
+            return Vec::new();
+        }
+
+        let bytes: u64 = late_chunks.iter().map(|l| l.size_bytes).sum();
+        let examples: Vec<String> = late_chunks
+            .iter()
+            .take(3)
+            .map(|l| format!("{} (chunk {}, {})", l.relative_path, l.chunk_id, l.source))
+            .collect();
+        vec![Recommendation {
+            priority: Priority::High,
+            category: "ChunkAssignment".to_string(),
+            message: tr(
+                self.locale,
+                "rec.late_chunks",
+                &[
+                    ("count", &late_chunks.len()),
+                    ("mb", &format!("{:.1}", bytes as f64 / (1024.0 * 1024.0))),
+                    ("examples", &examples.join(", ")),
+                ],
+            ),
+            estimated_impact_seconds: 0.0,
+        }]
+    }
+
+    fn shared_subtree_recommendations(&self, shared: &[SharedSubtree]) -> Vec<Recommendation> {
+        shared
+            .iter()
+            .take(5)
+            .map(|subtree| {
+                let root = subtree.root
+                    .strip_prefix(&self.project_root)
+                    .unwrap_or(&subtree.root);
+
+                Recommendation {
+                    priority: if subtree.referrers.len() >= 5 {
+                        Priority::High
+                    } else {
+                        Priority::Medium
+                    },
+                    category: "Preload".to_string(),
+                    message: tr(
+                        self.locale,
+                        "rec.shared_subtree",
+                        &[
+                            ("referrers", &subtree.referrers.len()),
+                            ("root", &root.display()),
+                            ("assets", &subtree.subtree_asset_count),
+                            ("mb", &format!("{:.1}", subtree.subtree_size_bytes as f64 / (1024.0 * 1024.0))),
+                        ],
+                    ),
+                    // ~10ms per asset moved off the referrers' critical path
+                    estimated_impact_seconds: subtree.subtree_asset_count as f64 * 0.01,
+                }
+            })
+            .collect()
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -230,13 +941,95 @@ pub struct AnalysisReport {
     pub startup_assets: usize,
     pub total_size_bytes: u64,
     pub startup_size_bytes: u64,
+    /// Startup assets plus their dependencies, bounded by edge confidence
+    #[serde(default)]
+    pub startup_closure_size_bytes: Estimate,
+    /// Configured startup scenarios, in name order
+    #[serde(default)]
+    pub scenarios: Vec<ScenarioReport>,
+    /// Startup assets, largest first
+    #[serde(default)]
+    pub startup_asset_list: Vec<StartupAssetEntry>,
     pub by_type: HashMap<String, TypeStats>,
+    /// Totals per imported feature tag
+    #[serde(default)]
+    pub by_tag: BTreeMap<String, TagStats>,
     pub dependency_count: usize,
+    /// How many packages the dependency graph could actually parse
+    #[serde(default)]
+    pub parser_coverage: ParserCoverage,
     pub duplicate_count: usize,
     pub duplicates: Vec<DuplicateGroup>,
     pub shader_analysis: Option<ShaderAnalysis>,
+    #[serde(default)]
+    pub shared_subtrees: Vec<SharedSubtree>,
+    /// Package imports of startup assets that no export references
+    #[serde(default)]
+    pub stale_imports: Vec<StaleImport>,
+    /// Unsmudged LFS pointers and text files with divergent line endings
+    #[serde(default)]
+    pub content_anomalies: Vec<ContentAnomaly>,
+    /// Paths differing only by case
+    #[serde(default)]
+    pub case_collisions: Vec<CaseCollision>,
+    /// Groups of assets hard-referencing each other in a loop, largest first
+    #[serde(default)]
+    pub dependency_cycles: Vec<Vec<String>>,
+    /// DataTables and CurveTables among the startup assets, largest first
+    #[serde(default)]
+    pub data_tables: Vec<DataTableInfo>,
+    /// StringTables, those referenced by startup assets first
+    #[serde(default)]
+    pub string_tables: Vec<StringTableInfo>,
+    /// Widget Blueprints and fonts in the startup closure
+    #[serde(default)]
+    pub ui: UiReport,
+    /// Startup closure assets assigned after chunk 0 by the chunk manifests
+    /// or `--editor-data`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub late_chunks: Vec<LateChunkAsset>,
+    /// Estimated resident memory of the startup closure, priced from
+    /// registry tags with `--editor-data`
+    #[serde(default)]
+    pub resident_memory: ResidentMemory,
+    /// C++ module loads and `StartupModule` work, with `--source`
+    #[serde(default)]
+    pub source: Option<SourceScan>,
+    /// Preloads forced by `Config/Default*.ini`, in config order
+    #[serde(default)]
+    pub config_preloads: Vec<ConfigPreload>,
+    /// Machine the estimates were made for
+    #[serde(default)]
+    pub hardware: Option<HardwareProfile>,
+    /// `.uproject` EngineAssociation, when the project has one
+    pub engine_association: Option<String>,
+    pub version_matrix: Option<VersionMatrix>,
+    /// Present when only a sample of assets was analyzed
+    pub sample: Option<SampleSummary>,
     pub estimated_savings_seconds: f64,
     pub recommendations: Vec<Recommendation>,
+    /// Matches of the `[[rule_packs]]` rules
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rule_hits: Vec<RuleHit>,
+    /// Startup closure assets a never-cook primary asset rule excludes
+    /// (needs `--editor-data`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub never_cooked: Vec<NeverCookedAsset>,
+    /// Language of the recommendation and summary text
+    #[serde(default)]
+    pub language: Locale,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StartupAssetEntry {
+    pub relative_path: String,
+    pub size_bytes: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Config line forcing the asset to load, e.g.
+    /// `Config/DefaultEngine.ini:42 (AssetsToAlwaysLoad)`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preloaded_by: Option<String>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -245,6 +1038,36 @@ pub struct TypeStats {
     pub total_size: u64,
 }
 
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TagStats {
+    pub assets: usize,
+    pub size_bytes: u64,
+    pub startup_assets: usize,
+    pub startup_size_bytes: u64,
+}
+
+/// Totals of `assets` per tag; an asset counts towards each of its tags
+fn tag_stats(tags: &AssetTags, assets: &[AssetInfo], heuristics: &StartupHeuristics) -> BTreeMap<String, TagStats> {
+    let mut by_tag: BTreeMap<String, TagStats> = BTreeMap::new();
+    if tags.is_empty() {
+        return by_tag;
+    }
+
+    for asset in assets {
+        let startup = is_startup_candidate(asset, heuristics);
+        for tag in tags.tags_of(&asset.relative_path) {
+            let stats = by_tag.entry(tag.to_string()).or_default();
+            stats.assets += 1;
+            stats.size_bytes += asset.size_bytes;
+            if startup {
+                stats.startup_assets += 1;
+                stats.startup_size_bytes += asset.size_bytes;
+            }
+        }
+    }
+    by_tag
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DuplicateGroup {
     pub hash: u64,
@@ -267,7 +1090,8 @@ pub struct Recommendation {
     pub estimated_impact_seconds: f64,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Ordered most urgent first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Priority {
     High,
     Medium,
@@ -278,6 +1102,33 @@ pub enum Priority {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_tag_stats() {
+        let mut tags = AssetTags::default();
+        tags.insert("/Game/Maps/Lobby", "Multiplayer");
+        tags.insert("/Game/Hero/SK_Hero", "Multiplayer");
+        tags.insert("/Game/Hero/SK_Hero", "Chapter1");
+
+        let asset = |relative_path: &str, asset_type, size_bytes| AssetInfo {
+            path: PathBuf::from(relative_path),
+            relative_path: relative_path.to_string(),
+            asset_type,
+            size_bytes,
+            modified: 0,
+            mount_point: "/Game".to_string(),
+        };
+        let assets = [
+            asset("Content/Maps/Lobby.umap", AssetType::UMap, 100),
+            asset("Content/Hero/SK_Hero.uasset", AssetType::UAsset, 40),
+        ];
+
+        let by_tag = tag_stats(&tags, &assets, &StartupHeuristics::default());
+        let multiplayer = &by_tag["Multiplayer"];
+        assert_eq!((multiplayer.assets, multiplayer.size_bytes), (2, 140));
+        assert_eq!((multiplayer.startup_assets, multiplayer.startup_size_bytes), (1, 100));
+        assert_eq!(by_tag["Chapter1"].startup_assets, 0);
+    }
+
     #[test]
     fn test_type_stats_default() {
         let stats = TypeStats::default();
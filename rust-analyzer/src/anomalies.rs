@@ -0,0 +1,180 @@
+//! Content Anomalies Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Unsmudged Git LFS pointers and text files whose line endings diverge,
+//! the usual sources of broken assets and hash mismatches between syncs
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// Files this small that diverge from larger copies elsewhere are likely
+/// unsmudged Git LFS pointers (~130 bytes: version, oid and size lines)
+pub const LFS_POINTER_MAX_BYTES: u64 = 256;
+
+const LFS_POINTER_HEADER: &[u8] = b"version https://git-lfs.github.com/spec/";
+
+/// Extensions of text files whose hashes change with line ending conversion
+pub const TEXT_EXTENSIONS: &[&str] = &["ini", "ush", "usf", "json", "txt", "csv", "uproject", "uplugin"];
+
+/// Text files larger than this are not inspected
+const MAX_TEXT_BYTES: u64 = 16 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineEndings {
+    Lf,
+    Crlf,
+    Mixed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnomalyKind {
+    /// Git LFS pointer checked out in place of the real file (broken asset)
+    LfsPointer,
+    /// Text file mixing CRLF and LF (verification noise)
+    MixedLineEndings,
+    /// Text file whose line endings differ from the rest of Content
+    /// (verification noise)
+    DivergentLineEndings,
+}
+
+impl AnomalyKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnomalyKind::LfsPointer => "lfs_pointer",
+            AnomalyKind::MixedLineEndings => "mixed_line_endings",
+            AnomalyKind::DivergentLineEndings => "divergent_line_endings",
+        }
+    }
+
+    /// Whether the anomaly breaks the asset rather than only its hash
+    pub fn is_broken_asset(&self) -> bool {
+        matches!(self, AnomalyKind::LfsPointer)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContentAnomaly {
+    pub relative_path: String,
+    pub kind: AnomalyKind,
+}
+
+pub fn is_text_path(path: &Path) -> bool {
+    path.extension()
+        .map(|e| TEXT_EXTENSIONS.contains(&e.to_string_lossy().to_lowercase().as_str()))
+        .unwrap_or(false)
+}
+
+pub fn is_lfs_pointer(data: &[u8]) -> bool {
+    data.len() as u64 <= LFS_POINTER_MAX_BYTES && data.starts_with(LFS_POINTER_HEADER)
+}
+
+/// Line ending style of `data`, `None` if it has no line breaks
+pub fn line_endings(data: &[u8]) -> Option<LineEndings> {
+    let lf = data.iter().filter(|b| **b == b'\n').count();
+    let crlf = data.windows(2).filter(|w| w == b"\r\n").count();
+    match (crlf, lf) {
+        (_, 0) => None,
+        (0, _) => Some(LineEndings::Lf),
+        (c, l) if c == l => Some(LineEndings::Crlf),
+        _ => Some(LineEndings::Mixed),
+    }
+}
+
+/// Check a single file: LFS pointer, or line ending style for text files
+fn inspect(path: &Path) -> Option<(Option<AnomalyKind>, Option<LineEndings>)> {
+    let size = std::fs::metadata(path).ok()?.len();
+    let is_text = is_text_path(path);
+    if size > LFS_POINTER_MAX_BYTES && (!is_text || size > MAX_TEXT_BYTES) {
+        return None;
+    }
+
+    let mut data = Vec::with_capacity(size as usize);
+    std::fs::File::open(path).ok()?.read_to_end(&mut data).ok()?;
+    if is_lfs_pointer(&data) {
+        return Some((Some(AnomalyKind::LfsPointer), None));
+    }
+    if !is_text {
+        return None;
+    }
+    match line_endings(&data) {
+        Some(LineEndings::Mixed) => Some((Some(AnomalyKind::MixedLineEndings), Some(LineEndings::Mixed))),
+        endings => Some((None, endings)),
+    }
+}
+
+/// Anomalies among `paths` (relative to `project_root`). Line endings are
+/// compared against the majority style of the text files among them.
+pub fn find_anomalies<'a>(project_root: &Path, paths: impl IntoIterator<Item = &'a str>) -> Vec<ContentAnomaly> {
+    let paths: Vec<&str> = paths.into_iter().collect();
+    let inspected: Vec<(&str, Option<AnomalyKind>, Option<LineEndings>)> = paths
+        .par_iter()
+        .filter_map(|p| {
+            let (kind, endings) = inspect(&project_root.join(p))?;
+            Some((*p, kind, endings))
+        })
+        .collect();
+
+    let mut styles: HashMap<LineEndings, usize> = HashMap::new();
+    for (_, _, endings) in &inspected {
+        if let Some(e @ (LineEndings::Lf | LineEndings::Crlf)) = endings {
+            *styles.entry(*e).or_default() += 1;
+        }
+    }
+    // Ties favour LF, what git normalizes to
+    let majority = styles
+        .iter()
+        .max_by_key(|(style, count)| (**count, **style == LineEndings::Lf))
+        .map(|(style, _)| *style);
+
+    let mut anomalies: Vec<ContentAnomaly> = inspected
+        .into_iter()
+        .filter_map(|(path, kind, endings)| {
+            let kind = kind.or_else(|| {
+                (endings.is_some() && endings != majority).then_some(AnomalyKind::DivergentLineEndings)
+            })?;
+            Some(ContentAnomaly { relative_path: path.to_string(), kind })
+        })
+        .collect();
+    anomalies.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+    anomalies
+}
+
+/// Anomalies among every file under the project's Content directory
+pub fn scan_content(project_root: &Path) -> Vec<ContentAnomaly> {
+    let relative: Vec<String> = WalkDir::new(project_root.join("Content"))
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| Some(e.path().strip_prefix(project_root).ok()?.to_string_lossy().to_string()))
+        .collect();
+    find_anomalies(project_root, relative.iter().map(|p| p.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_endings() {
+        assert_eq!(line_endings(b"a\nb\n"), Some(LineEndings::Lf));
+        assert_eq!(line_endings(b"a\r\nb\r\n"), Some(LineEndings::Crlf));
+        assert_eq!(line_endings(b"a\r\nb\n"), Some(LineEndings::Mixed));
+        assert_eq!(line_endings(b"ab"), None);
+    }
+
+    #[test]
+    fn test_is_lfs_pointer() {
+        let pointer = b"version https://git-lfs.github.com/spec/v1\n\
+            oid sha256:4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393\n\
+            size 12345\n";
+        assert!(is_lfs_pointer(pointer));
+        assert!(!is_lfs_pointer(b"\xC1\x83\x2A\x9Euasset"));
+    }
+}
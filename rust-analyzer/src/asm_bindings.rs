@@ -2,7 +2,8 @@
 //! Copyright 2026 Eddi Andreé Salazar Matos
 //! Licensed under Apache 2.0
 //!
-//! Safe Rust wrappers for NASM-compiled assembly functions
+//! Safe Rust wrappers for NASM-compiled assembly functions, and NEON
+//! intrinsics for the same hot paths on AArch64
 
 use std::arch::asm;
 
@@ -17,9 +18,18 @@ extern "C" {
     fn count_nulls_simd(buffer: *const u8, size: usize) -> usize;
 }
 
-/// Check if ASM functions are available (linked at compile time)
+/// Check if ASM functions are available (linked at compile time, or NEON
+/// detected at runtime on AArch64)
 pub fn asm_available() -> bool {
-    cfg!(feature = "asm_hotpaths")
+    #[cfg(all(target_arch = "aarch64", feature = "asm_hotpaths"))]
+    {
+        neon::available()
+    }
+
+    #[cfg(not(all(target_arch = "aarch64", feature = "asm_hotpaths")))]
+    {
+        cfg!(feature = "asm_hotpaths")
+    }
 }
 
 /// SIMD-accelerated hash state
@@ -60,6 +70,12 @@ impl HashState {
                 );
             }
 
+            #[cfg(all(target_arch = "aarch64", feature = "asm_hotpaths"))]
+            if neon::available() {
+                unsafe { neon::hash_blocks(data, &mut self.accumulators, block_count) };
+                return;
+            }
+
             #[cfg(not(all(target_arch = "x86_64", feature = "asm_hotpaths")))]
             {
                 self.update_rust_fallback(data, block_count);
@@ -143,6 +159,14 @@ pub fn fast_memcpy(dest: &mut [u8], src: &[u8]) -> usize {
         }
     }
 
+    #[cfg(all(target_arch = "aarch64", feature = "asm_hotpaths"))]
+    {
+        if len >= 128 && neon::available() {
+            unsafe { neon::copy(&mut dest[..len], &src[..len]) };
+            return len;
+        }
+    }
+
     // Fallback to standard copy
     dest[..len].copy_from_slice(&src[..len]);
     len
@@ -159,6 +183,11 @@ pub fn scan_uasset_magic(buffer: &[u8]) -> Option<usize> {
         return None;
     }
 
+    #[cfg(all(target_arch = "aarch64", feature = "asm_hotpaths"))]
+    if neon::available() {
+        return unsafe { neon::find_magic(buffer, 0x9E2A83C1) };
+    }
+
     #[cfg(not(all(target_arch = "x86_64", feature = "asm_hotpaths")))]
     {
         scan_uasset_magic_fallback(buffer)
@@ -183,6 +212,11 @@ pub fn scan_magic(buffer: &[u8], magic: u32) -> Option<usize> {
         return None;
     }
 
+    #[cfg(all(target_arch = "aarch64", feature = "asm_hotpaths"))]
+    if neon::available() {
+        return unsafe { neon::find_magic(buffer, magic) };
+    }
+
     #[cfg(not(all(target_arch = "x86_64", feature = "asm_hotpaths")))]
     {
         let magic_bytes = magic.to_le_bytes();
@@ -198,12 +232,136 @@ pub fn count_nulls(buffer: &[u8]) -> usize {
         return count_nulls_simd(buffer.as_ptr(), buffer.len());
     }
 
+    #[cfg(all(target_arch = "aarch64", feature = "asm_hotpaths"))]
+    if neon::available() {
+        return unsafe { neon::count_nulls(buffer) };
+    }
+
     #[cfg(not(all(target_arch = "x86_64", feature = "asm_hotpaths")))]
     {
         buffer.iter().filter(|&&b| b == 0).count()
     }
 }
 
+/// NEON versions of the hot paths. Every routine produces exactly what the
+/// scalar fallback does, so caches hashed on ARM and x86 build machines match.
+#[cfg(all(target_arch = "aarch64", feature = "asm_hotpaths"))]
+mod neon {
+    use std::arch::aarch64::*;
+
+    /// NEON is mandatory on AArch64, but the check is cheap and cached
+    pub fn available() -> bool {
+        std::arch::is_aarch64_feature_detected!("neon")
+    }
+
+    /// `a * b` on both 64-bit lanes; NEON only multiplies 32-bit halves
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn mul_u64(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+        let (a_lo, a_hi) = (vmovn_u64(a), vshrn_n_u64::<32>(a));
+        let (b_lo, b_hi) = (vmovn_u64(b), vshrn_n_u64::<32>(b));
+        let cross = vaddq_u64(vmull_u32(a_lo, b_hi), vmull_u32(a_hi, b_lo));
+        vaddq_u64(vmull_u32(a_lo, b_lo), vshlq_n_u64::<32>(cross))
+    }
+
+    #[inline]
+    #[target_feature(enable = "neon")]
+    unsafe fn round(acc: uint64x2_t, input: uint64x2_t, prime1: uint64x2_t, prime2: uint64x2_t) -> uint64x2_t {
+        let acc = vaddq_u64(acc, mul_u64(input, prime2));
+        let acc = vorrq_u64(vshlq_n_u64::<31>(acc), vshrq_n_u64::<33>(acc));
+        mul_u64(acc, prime1)
+    }
+
+    /// Two lanes per register, two registers per 32-byte block
+    #[target_feature(enable = "neon")]
+    pub unsafe fn hash_blocks(data: &[u8], accumulators: &mut [u64; 4], block_count: usize) {
+        let prime1 = vdupq_n_u64(0x9E3779B185EBCA87);
+        let prime2 = vdupq_n_u64(0xC2B2AE3D27D4EB4F);
+        let mut low = vld1q_u64(accumulators.as_ptr());
+        let mut high = vld1q_u64(accumulators.as_ptr().add(2));
+
+        let ptr = data.as_ptr();
+        for block in 0..block_count {
+            let block = ptr.add(block * 32);
+            low = round(low, vreinterpretq_u64_u8(vld1q_u8(block)), prime1, prime2);
+            high = round(high, vreinterpretq_u64_u8(vld1q_u8(block.add(16))), prime1, prime2);
+        }
+
+        vst1q_u64(accumulators.as_mut_ptr(), low);
+        vst1q_u64(accumulators.as_mut_ptr().add(2), high);
+    }
+
+    /// `dest` and `src` have the same length
+    #[target_feature(enable = "neon")]
+    pub unsafe fn copy(dest: &mut [u8], src: &[u8]) {
+        let len = src.len();
+        let (to, from) = (dest.as_mut_ptr(), src.as_ptr());
+        let mut i = 0;
+        while i + 64 <= len {
+            let a = vld1q_u8(from.add(i));
+            let b = vld1q_u8(from.add(i + 16));
+            let c = vld1q_u8(from.add(i + 32));
+            let d = vld1q_u8(from.add(i + 48));
+            vst1q_u8(to.add(i), a);
+            vst1q_u8(to.add(i + 16), b);
+            vst1q_u8(to.add(i + 32), c);
+            vst1q_u8(to.add(i + 48), d);
+            i += 64;
+        }
+        while i + 16 <= len {
+            vst1q_u8(to.add(i), vld1q_u8(from.add(i)));
+            i += 16;
+        }
+        dest[i..].copy_from_slice(&src[i..]);
+    }
+
+    /// First offset of the little-endian `magic`. Sixteen candidate offsets
+    /// are screened at once on their first two bytes; hits are confirmed
+    /// with a plain compare.
+    #[target_feature(enable = "neon")]
+    pub unsafe fn find_magic(buffer: &[u8], magic: u32) -> Option<usize> {
+        let magic_bytes = magic.to_le_bytes();
+        if buffer.len() < 4 {
+            return None;
+        }
+        // Offsets that leave room for the whole magic
+        let candidates = buffer.len() - 3;
+        let first = vdupq_n_u8(magic_bytes[0]);
+        let second = vdupq_n_u8(magic_bytes[1]);
+
+        let ptr = buffer.as_ptr();
+        let mut i = 0;
+        while i + 16 <= candidates {
+            let hits = vandq_u8(
+                vceqq_u8(vld1q_u8(ptr.add(i)), first),
+                vceqq_u8(vld1q_u8(ptr.add(i + 1)), second),
+            );
+            if vmaxvq_u8(hits) != 0 {
+                if let Some(offset) = (i..i + 16).find(|&offset| buffer[offset..offset + 4] == magic_bytes) {
+                    return Some(offset);
+                }
+            }
+            i += 16;
+        }
+        buffer[i..].windows(4).position(|window| window == magic_bytes).map(|offset| i + offset)
+    }
+
+    #[target_feature(enable = "neon")]
+    pub unsafe fn count_nulls(buffer: &[u8]) -> usize {
+        let zero = vdupq_n_u8(0);
+        let ptr = buffer.as_ptr();
+        let mut count = 0;
+        let mut i = 0;
+        while i + 16 <= buffer.len() {
+            // 0xFF per null byte, shifted down to 1 so the sum fits in a byte
+            let nulls = vshrq_n_u8::<7>(vceqq_u8(vld1q_u8(ptr.add(i)), zero));
+            count += vaddvq_u8(nulls) as usize;
+            i += 16;
+        }
+        count + buffer[i..].iter().filter(|&&b| b == 0).count()
+    }
+}
+
 /// Check AVX2 support at runtime
 #[cfg(all(target_arch = "x86_64", feature = "asm_hotpaths"))]
 fn is_avx2_supported() -> bool {
@@ -267,6 +425,49 @@ mod tests {
         assert_eq!(result, Some(2));
     }
 
+    #[test]
+    fn test_scan_magic_past_vector_width() {
+        let mut buffer = vec![0xC1u8; 100];
+        for offset in [0, 15, 16, 31, 60, 96] {
+            buffer.fill(0xC1);
+            buffer[offset..offset + 4].copy_from_slice(&[0xC1, 0x83, 0x2A, 0x9E]);
+            assert_eq!(scan_uasset_magic(&buffer), Some(offset));
+            assert_eq!(scan_magic(&buffer, 0x9E2A83C1), Some(offset));
+        }
+        assert_eq!(scan_magic(&buffer[..99], 0x9E2A83C1), None);
+    }
+
+    #[test]
+    fn test_hash_state_known_answers() {
+        // Values of the scalar definition, which every SIMD path must match
+        let mut state = HashState::new(0);
+        state.update(b"0123456789abcdef0123456789abcdef");
+        assert_eq!(state.finalize(), 0x9a26f0a9a1e75791);
+
+        let data: Vec<u8> = (0..1024u32).map(|i| (i.wrapping_mul(2654435761) >> 13) as u8).collect();
+        let mut state = HashState::new(7);
+        state.update(&data);
+        assert_eq!(state.finalize(), 0x3b563067fc108ca2);
+    }
+
+    // Elsewhere the fallback would only be compared with itself
+    #[cfg(all(target_arch = "aarch64", feature = "asm_hotpaths"))]
+    #[test]
+    fn test_hash_blocks_match_fallback() {
+        let data: Vec<u8> = (0..1000u32).map(|i| (i.wrapping_mul(2654435761) >> 13) as u8).collect();
+        let mut state = HashState::new(7);
+        state.update(&data);
+        let mut scalar = HashState::new(7);
+        scalar.total_len = data.len();
+        scalar.update_rust_fallback(&data, data.len() / 32);
+        assert_eq!(state.accumulators, scalar.accumulators);
+
+        let mut copy = vec![0u8; data.len()];
+        assert_eq!(fast_memcpy(&mut copy, &data), data.len());
+        assert_eq!(copy, data);
+        assert_eq!(count_nulls(&data), data.iter().filter(|&&b| b == 0).count());
+    }
+
     #[test]
     fn test_count_nulls() {
         let buffer = [0u8, 1, 0, 2, 0, 3, 0, 0];
@@ -0,0 +1,343 @@
+//! Attribution Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Startup cost as a tree, like a binary bloat report: startup roots, then
+//! the assets only they pull in, each with the unique bytes and load time
+//! its subtree is responsible for. Shared dependencies sit under the
+//! startup itself, so every asset is counted exactly once. Exported as JSON,
+//! HTML or collapsed stacks for flamegraph tooling.
+
+use crate::config_preloads;
+use crate::estimator::LoadTimeModel;
+use crate::graph::DependencyGraph;
+use crate::report::html_escape;
+use crate::scanner::{is_startup_candidate, AssetInfo, StartupHeuristics};
+use crate::{FastStartupError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+const MB: f64 = 1024.0 * 1024.0;
+
+/// Folders listed in the HTML summary
+pub const MAX_HTML_FOLDERS: usize = 50;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AttributionNode {
+    /// Project-relative asset path
+    pub name: String,
+    /// The asset alone
+    pub size_bytes: u64,
+    /// The asset plus everything only it pulls in
+    pub retained_bytes: u64,
+    pub retained_seconds: f64,
+    pub retained_assets: usize,
+    /// Largest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<AttributionNode>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FolderCost {
+    pub folder: String,
+    pub assets: usize,
+    pub size_bytes: u64,
+    pub seconds: f64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AttributionTree {
+    pub project_name: String,
+    pub total_bytes: u64,
+    pub total_seconds: f64,
+    pub total_assets: usize,
+    /// Startup roots and shared dependencies, largest first
+    pub roots: Vec<AttributionNode>,
+    /// Startup closure per folder, largest first
+    pub folders: Vec<FolderCost>,
+}
+
+/// Startup roots of `assets`: the project's path heuristics plus
+/// config-forced preloads
+pub fn startup_roots(project_root: &Path, assets: &[AssetInfo], heuristics: &StartupHeuristics) -> HashSet<PathBuf> {
+    let mut preloads = config_preloads::load(project_root);
+    config_preloads::resolve(&mut preloads, assets);
+    let mut roots = config_preloads::root_paths(&preloads, assets);
+    roots.extend(assets.iter().filter(|a| is_startup_candidate(a, heuristics)).map(|a| a.path.clone()));
+    roots
+}
+
+impl AttributionTree {
+    /// Attribute the hard closure of `roots` along `graph`'s dominator tree
+    pub fn build(
+        project_name: &str,
+        graph: &DependencyGraph,
+        assets: &[AssetInfo],
+        roots: &HashSet<PathBuf>,
+        model: &LoadTimeModel,
+    ) -> Self {
+        let by_path: HashMap<&Path, &AssetInfo> = assets.iter().map(|a| (a.path.as_path(), a)).collect();
+        let dominators = graph.hard_dominators(roots);
+
+        let mut children: HashMap<&Path, Vec<&Path>> = HashMap::new();
+        let mut top: Vec<&Path> = Vec::new();
+        for (path, parent) in &dominators {
+            if !by_path.contains_key(path.as_path()) {
+                continue;
+            }
+            match parent {
+                Some(parent) => children.entry(parent.as_path()).or_default().push(path),
+                None => top.push(path),
+            }
+        }
+
+        // Build bottom-up, deepest first, so chains of any length stay off
+        // the call stack
+        let mut depth: HashMap<&Path, usize> = HashMap::new();
+        let mut order: Vec<&Path> = Vec::new();
+        let mut stack: Vec<(&Path, usize)> = top.iter().map(|p| (*p, 0)).collect();
+        while let Some((path, d)) = stack.pop() {
+            if depth.insert(path, d).is_some() {
+                continue;
+            }
+            order.push(path);
+            for child in children.get(path).into_iter().flatten() {
+                stack.push((child, d + 1));
+            }
+        }
+        order.sort_by_key(|p| std::cmp::Reverse(depth[p]));
+
+        let mut built: HashMap<&Path, AttributionNode> = HashMap::new();
+        for path in order {
+            let asset = by_path[path];
+            let mut node = AttributionNode {
+                name: asset.relative_path.clone(),
+                size_bytes: asset.size_bytes,
+                retained_bytes: asset.size_bytes,
+                retained_seconds: model.estimate_ms([asset]) / 1000.0,
+                retained_assets: 1,
+                children: children
+                    .get(path)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|child| built.remove(child))
+                    .collect(),
+            };
+            for child in &node.children {
+                node.retained_bytes += child.retained_bytes;
+                node.retained_seconds += child.retained_seconds;
+                node.retained_assets += child.retained_assets;
+            }
+            sort_nodes(&mut node.children);
+            built.insert(path, node);
+        }
+
+        let mut tree = Self {
+            project_name: project_name.to_string(),
+            roots: top.iter().filter_map(|p| built.remove(p)).collect(),
+            ..Default::default()
+        };
+        sort_nodes(&mut tree.roots);
+        for root in &tree.roots {
+            tree.total_bytes += root.retained_bytes;
+            tree.total_seconds += root.retained_seconds;
+            tree.total_assets += root.retained_assets;
+        }
+
+        let mut folders: BTreeMap<&str, FolderCost> = BTreeMap::new();
+        for path in dominators.keys() {
+            let Some(asset) = by_path.get(path.as_path()) else {
+                continue;
+            };
+            let folder = asset.relative_path.rsplit_once('/').map(|(dir, _)| dir).unwrap_or("");
+            let cost = folders.entry(folder).or_insert_with(|| FolderCost { folder: folder.to_string(), ..Default::default() });
+            cost.assets += 1;
+            cost.size_bytes += asset.size_bytes;
+            cost.seconds += model.estimate_ms([*asset]) / 1000.0;
+        }
+        tree.folders = folders.into_values().collect();
+        tree.folders.sort_by(|a, b| b.size_bytes.cmp(&a.size_bytes).then_with(|| a.folder.cmp(&b.folder)));
+        tree
+    }
+
+    /// JSON, a collapsible HTML page for `.html`/`.htm`, or collapsed stacks
+    /// for `.folded`/`.collapsed`
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("").to_ascii_lowercase();
+        let text = match extension.as_str() {
+            "html" | "htm" => self.to_html(),
+            "folded" | "collapsed" => self.to_collapsed(),
+            _ => serde_json::to_string_pretty(self).map_err(|e| FastStartupError::SerializationError(e.to_string()))?,
+        };
+        std::fs::write(path, text)?;
+        Ok(())
+    }
+
+    /// Brendan Gregg's collapsed-stack format, one `project;root;...;asset
+    /// bytes` line per asset, for flamegraph.pl, inferno or speedscope
+    pub fn to_collapsed(&self) -> String {
+        let mut out = String::new();
+        let mut stack: Vec<(&AttributionNode, String)> = self
+            .roots
+            .iter()
+            .rev()
+            .map(|root| (root, format!("{};{}", frame(&self.project_name), frame(&root.name))))
+            .collect();
+        while let Some((node, path)) = stack.pop() {
+            if node.size_bytes > 0 {
+                let _ = writeln!(out, "{} {}", path, node.size_bytes);
+            }
+            for child in node.children.iter().rev() {
+                stack.push((child, format!("{};{}", path, frame(&child.name))));
+            }
+        }
+        out
+    }
+
+    pub fn to_html(&self) -> String {
+        let title = html_escape(&format!("Startup attribution: {}", self.project_name));
+        let mut html = String::new();
+        let _ = writeln!(html, "<!DOCTYPE html>");
+        let _ = writeln!(html, "<html><head><meta charset=\"utf-8\"><title>{}</title></head><body>", title);
+        let _ = writeln!(html, "<h1>{}</h1>", title);
+        let _ = writeln!(
+            html,
+            "<p>{} assets, {:.1} MB, ~{:.2}s</p>",
+            self.total_assets,
+            self.total_bytes as f64 / MB,
+            self.total_seconds
+        );
+
+        let _ = writeln!(html, "<h2>Tree</h2>");
+        for root in &self.roots {
+            write_html_node(&mut html, root, self.total_bytes);
+        }
+
+        let _ = writeln!(html, "<h2>Folders</h2>");
+        let _ = writeln!(html, "<table><tr><th>folder</th><th>assets</th><th>MB</th><th>seconds</th></tr>");
+        for folder in self.folders.iter().take(MAX_HTML_FOLDERS) {
+            let _ = writeln!(
+                html,
+                "<tr><td>{}</td><td>{}</td><td>{:.2}</td><td>{:.3}</td></tr>",
+                html_escape(&folder.folder),
+                folder.assets,
+                folder.size_bytes as f64 / MB,
+                folder.seconds
+            );
+        }
+        let _ = writeln!(html, "</table>");
+        let _ = writeln!(html, "</body></html>");
+        html
+    }
+}
+
+/// Frame names may not contain the `;` separator or line breaks
+fn frame(name: &str) -> String {
+    name.replace(';', "_").replace(['\n', '\r'], " ")
+}
+
+fn sort_nodes(nodes: &mut [AttributionNode]) {
+    nodes.sort_by(|a, b| b.retained_bytes.cmp(&a.retained_bytes).then_with(|| a.name.cmp(&b.name)));
+}
+
+/// Nested `<details>`; iterative so deep trees render too
+fn write_html_node(html: &mut String, root: &AttributionNode, total_bytes: u64) {
+    enum Step<'a> {
+        Open(&'a AttributionNode),
+        Close,
+    }
+    let mut stack = vec![Step::Open(root)];
+    while let Some(step) = stack.pop() {
+        let node = match step {
+            Step::Open(node) => node,
+            Step::Close => {
+                let _ = writeln!(html, "</details>");
+                continue;
+            }
+        };
+        let label = format!(
+            "{} &mdash; {:.2} MB ({:.1}%), ~{:.3}s, {} asset{}",
+            html_escape(&node.name),
+            node.retained_bytes as f64 / MB,
+            node.retained_bytes as f64 * 100.0 / total_bytes.max(1) as f64,
+            node.retained_seconds,
+            node.retained_assets,
+            if node.retained_assets == 1 { "" } else { "s" }
+        );
+        if node.children.is_empty() {
+            let _ = writeln!(html, "<div>{}</div>", label);
+            continue;
+        }
+        let _ = writeln!(html, "<details><summary>{}</summary>", label);
+        stack.push(Step::Close);
+        stack.extend(node.children.iter().rev().map(Step::Open));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::DependencyType;
+    use crate::scanner::AssetType;
+
+    fn asset(path: &str, size_bytes: u64) -> AssetInfo {
+        AssetInfo {
+            path: PathBuf::from(path),
+            relative_path: path.to_string(),
+            asset_type: AssetType::UAsset,
+            size_bytes,
+            modified: 0,
+            mount_point: "/Game".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_attribution_tree() {
+        let assets: Vec<AssetInfo> = [
+            ("Content/Maps/Entry", 10),
+            ("Content/Maps/Menu", 5),
+            ("Content/UI/Hud", 100),
+            ("Content/UI/Icons", 40),
+            ("Content/Shared/Font", 30),
+        ]
+        .iter()
+        .map(|(p, s)| asset(p, *s))
+        .collect();
+        let mut graph = DependencyGraph::new();
+        for a in &assets {
+            graph.add_asset(a);
+        }
+        let edge = |graph: &mut DependencyGraph, from: &str, to: &str| {
+            graph.add_dependency(Path::new(from), Path::new(to), DependencyType::Import, true)
+        };
+        edge(&mut graph, "Content/Maps/Entry", "Content/UI/Hud");
+        edge(&mut graph, "Content/UI/Hud", "Content/UI/Icons");
+        edge(&mut graph, "Content/UI/Hud", "Content/Shared/Font");
+        edge(&mut graph, "Content/Maps/Menu", "Content/Shared/Font");
+
+        let roots: HashSet<PathBuf> = ["Content/Maps/Entry", "Content/Maps/Menu"].iter().map(PathBuf::from).collect();
+        let tree = AttributionTree::build("Game", &graph, &assets, &roots, &LoadTimeModel::default());
+
+        assert_eq!(tree.total_bytes, 185);
+        assert_eq!(tree.total_assets, 5);
+        let names: Vec<&str> = tree.roots.iter().map(|r| r.name.as_str()).collect();
+        // The font is reached from both maps, so neither owns it
+        assert_eq!(names, ["Content/Maps/Entry", "Content/Shared/Font", "Content/Maps/Menu"]);
+        assert_eq!(tree.roots[0].retained_bytes, 150);
+        assert_eq!(tree.roots[0].children[0].name, "Content/UI/Hud");
+        assert_eq!(tree.roots[0].children[0].children[0].name, "Content/UI/Icons");
+        assert_eq!(tree.folders[0].folder, "Content/UI");
+        assert_eq!(tree.folders[0].size_bytes, 140);
+
+        let folded = tree.to_collapsed();
+        assert_eq!(folded.lines().next(), Some("Game;Content/Maps/Entry 10"));
+        assert!(folded.contains("Game;Content/Maps/Entry;Content/UI/Hud;Content/UI/Icons 40\n"));
+        assert_eq!(folded.lines().count(), 5);
+
+        let html = tree.to_html();
+        assert!(html.contains("<details><summary>Content/Maps/Entry"));
+        assert_eq!(html.matches("<details>").count(), html.matches("</details>").count());
+    }
+}
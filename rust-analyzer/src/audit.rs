@@ -0,0 +1,164 @@
+//! Access Audit Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Records every Content file the scanner or hasher cannot read, with the
+//! OS error, to diagnose ACL problems on shared drives
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// Step at which a file could not be accessed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AccessOperation {
+    /// Listing a directory
+    Walk,
+    Metadata,
+    Open,
+    Read,
+}
+
+impl AccessOperation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AccessOperation::Walk => "walk",
+            AccessOperation::Metadata => "metadata",
+            AccessOperation::Open => "open",
+            AccessOperation::Read => "read",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessFailure {
+    pub relative_path: String,
+    pub operation: AccessOperation,
+    /// OS error message, e.g. "Permission denied (os error 13)"
+    pub error: String,
+    pub os_code: Option<i32>,
+}
+
+impl AccessFailure {
+    fn new(relative_path: String, operation: AccessOperation, error: &std::io::Error) -> Self {
+        Self {
+            relative_path,
+            operation,
+            error: error.to_string(),
+            os_code: error.raw_os_error(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DirectoryFailures {
+    pub failures: usize,
+    /// Failure count per OS error message
+    pub errors: BTreeMap<String, usize>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AccessAudit {
+    pub files_checked: usize,
+    /// Sorted by path
+    pub failures: Vec<AccessFailure>,
+    /// Failures keyed by parent directory relative to the project root
+    pub by_directory: BTreeMap<String, DirectoryFailures>,
+}
+
+impl AccessAudit {
+    /// Walk Content and try to stat, open and read every file the way the
+    /// scanner and hasher do
+    pub fn run(project_root: &Path) -> Self {
+        let relative = |path: &Path| -> String {
+            path.strip_prefix(project_root)
+                .unwrap_or(path)
+                .to_string_lossy()
+                .to_string()
+        };
+
+        let mut failures = Vec::new();
+        let mut files = Vec::new();
+        for entry in WalkDir::new(project_root.join("Content")).follow_links(true) {
+            match entry {
+                Ok(entry) if entry.file_type().is_file() => files.push(entry.into_path()),
+                Ok(_) => {}
+                Err(e) => {
+                    let path = e.path().map(relative).unwrap_or_default();
+                    let io = e.io_error().map(|io| (io.to_string(), io.raw_os_error()));
+                    let (error, os_code) = io.unwrap_or_else(|| (e.to_string(), None));
+                    failures.push(AccessFailure {
+                        relative_path: path,
+                        operation: AccessOperation::Walk,
+                        error,
+                        os_code,
+                    });
+                }
+            }
+        }
+
+        failures.par_extend(files.par_iter().filter_map(|path| {
+            let fail = |op, e: &std::io::Error| Some(AccessFailure::new(relative(path), op, e));
+            if let Err(e) = std::fs::metadata(path) {
+                return fail(AccessOperation::Metadata, &e);
+            }
+            let mut file = match File::open(path) {
+                Ok(file) => file,
+                Err(e) => return fail(AccessOperation::Open, &e),
+            };
+            // Offline placeholders on cloud/shared drives fail on first read
+            let mut byte = [0u8; 1];
+            let error = file.read(&mut byte).err()?;
+            fail(AccessOperation::Read, &error)
+        }));
+
+        Self::from_failures(files.len(), failures)
+    }
+
+    pub fn from_failures(files_checked: usize, mut failures: Vec<AccessFailure>) -> Self {
+        failures.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+
+        let mut by_directory: BTreeMap<String, DirectoryFailures> = BTreeMap::new();
+        for failure in &failures {
+            let directory = Path::new(&failure.relative_path)
+                .parent()
+                .map(|p| p.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let summary = by_directory.entry(directory).or_default();
+            summary.failures += 1;
+            *summary.errors.entry(failure.error.clone()).or_default() += 1;
+        }
+
+        Self { files_checked, failures, by_directory }
+    }
+
+    pub fn is_clean(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_by_directory() {
+        let denied = std::io::Error::from_raw_os_error(13);
+        let failures = vec![
+            AccessFailure::new("Content/Shared/B.uasset".to_string(), AccessOperation::Open, &denied),
+            AccessFailure::new("Content/Shared/A.uasset".to_string(), AccessOperation::Open, &denied),
+            AccessFailure::new("Content/Maps/M.umap".to_string(), AccessOperation::Read, &denied),
+        ];
+
+        let audit = AccessAudit::from_failures(10, failures);
+        assert_eq!(audit.failures[0].relative_path, "Content/Maps/M.umap");
+        assert_eq!(audit.by_directory["Content/Shared"].failures, 2);
+        assert_eq!(audit.by_directory["Content/Shared"].errors[&denied.to_string()], 2);
+        assert_eq!(audit.failures[0].os_code, Some(13));
+    }
+}
@@ -0,0 +1,86 @@
+//! Bloom Filter Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Compact "definitely not cached" check over relative path hashes
+
+use serde::{Deserialize, Serialize};
+
+/// Target false positive rate for cache path filters
+pub const DEFAULT_FALSE_POSITIVE_RATE: f64 = 0.01;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BloomFilter {
+    bits: Vec<u64>,
+    hash_count: u32,
+}
+
+impl BloomFilter {
+    /// Filter sized for `items` entries at `false_positive_rate`
+    pub fn with_capacity(items: usize, false_positive_rate: f64) -> Self {
+        let items = items.max(1) as f64;
+        let ln2 = std::f64::consts::LN_2;
+        let bits = (-(items * false_positive_rate.ln()) / (ln2 * ln2)).ceil().max(64.0) as usize;
+        let hash_count = ((bits as f64 / items) * ln2).round().clamp(1.0, 16.0) as u32;
+        Self {
+            bits: vec![0; bits.div_ceil(64)],
+            hash_count,
+        }
+    }
+
+    /// Bit positions for `hash` by double hashing its two halves
+    fn positions(&self, hash: u64) -> impl Iterator<Item = usize> + '_ {
+        let bit_count = (self.bits.len() * 64) as u64;
+        let h1 = hash;
+        let h2 = hash.rotate_left(32) | 1;
+        (0..self.hash_count as u64).map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % bit_count) as usize)
+    }
+
+    pub fn insert(&mut self, hash: u64) {
+        if self.bits.is_empty() {
+            return;
+        }
+        let positions: Vec<usize> = self.positions(hash).collect();
+        for bit in positions {
+            self.bits[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+
+    /// `false` means `hash` was never inserted; `true` means it probably was.
+    /// An empty (unbuilt) filter answers `true` for everything.
+    pub fn may_contain(&self, hash: u64) -> bool {
+        if self.bits.is_empty() {
+            return true;
+        }
+        self.positions(hash).all(|bit| self.bits[bit / 64] & (1 << (bit % 64)) != 0)
+    }
+
+    pub fn size_bytes(&self) -> usize {
+        self.bits.len() * 8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::path_index::path_hash;
+
+    #[test]
+    fn test_bloom_filter() {
+        let mut filter = BloomFilter::with_capacity(1000, DEFAULT_FALSE_POSITIVE_RATE);
+        for i in 0..1000 {
+            filter.insert(path_hash(format!("Content/A_{}.uasset", i).as_bytes()));
+        }
+
+        assert!((0..1000).all(|i| filter.may_contain(path_hash(format!("Content/A_{}.uasset", i).as_bytes()))));
+        let false_positives = (0..10_000)
+            .filter(|i| filter.may_contain(path_hash(format!("Content/New_{}.uasset", i).as_bytes())))
+            .count();
+        assert!(false_positives < 300, "{} false positives", false_positives);
+    }
+
+    #[test]
+    fn test_empty_filter_is_permissive() {
+        assert!(BloomFilter::default().may_contain(42));
+    }
+}
@@ -0,0 +1,169 @@
+//! Byte Reader Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Bounds-checked little-endian cursor over mapped package data
+
+use crate::{FastStartupError, Result};
+
+/// Cursor over a byte slice. Every read is checked and fails with an
+/// `AssetError` instead of panicking on truncated or corrupt data.
+#[derive(Debug, Clone)]
+pub struct ByteReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    /// Reader positioned at `offset`
+    pub fn at(data: &'a [u8], offset: usize) -> Result<Self> {
+        let mut reader = Self::new(data);
+        reader.seek(offset)?;
+        Ok(reader)
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.position
+    }
+
+    pub fn seek(&mut self, offset: usize) -> Result<()> {
+        if offset > self.data.len() {
+            return Err(self.out_of_bounds(offset, 0));
+        }
+        self.position = offset;
+        Ok(())
+    }
+
+    pub fn skip(&mut self, count: usize) -> Result<()> {
+        self.bytes(count).map(|_| ())
+    }
+
+    pub fn bytes(&mut self, count: usize) -> Result<&'a [u8]> {
+        let end = self
+            .position
+            .checked_add(count)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| self.out_of_bounds(self.position, count))?;
+        let bytes = &self.data[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut array = [0u8; N];
+        array.copy_from_slice(self.bytes(N)?);
+        Ok(array)
+    }
+
+    pub fn u32(&mut self) -> Result<u32> {
+        self.array().map(u32::from_le_bytes)
+    }
+
+    pub fn i32(&mut self) -> Result<i32> {
+        self.array().map(i32::from_le_bytes)
+    }
+
+    pub fn i64(&mut self) -> Result<i64> {
+        self.array().map(i64::from_le_bytes)
+    }
+
+    pub fn u64(&mut self) -> Result<u64> {
+        self.array().map(u64::from_le_bytes)
+    }
+
+    /// Non-negative i32 count or offset, as usize
+    pub fn index(&mut self) -> Result<usize> {
+        let offset = self.position;
+        let value = self.i32()?;
+        usize::try_from(value).map_err(|_| {
+            FastStartupError::AssetError(format!("Negative count or offset {} at {}", value, offset))
+        })
+    }
+
+    /// Serialized FString: positive length for null-terminated UTF-8/ANSI,
+    /// negative for null-terminated UTF-16 (length in code units)
+    pub fn fstring(&mut self) -> Result<String> {
+        let offset = self.position;
+        let len = self.i32()?;
+        if len == 0 {
+            return Ok(String::new());
+        }
+
+        if len > 0 {
+            let bytes = self.bytes(len as usize)?;
+            Ok(String::from_utf8_lossy(&bytes[..bytes.len() - 1]).to_string())
+        } else {
+            let units = (len as i64).unsigned_abs() as usize;
+            let byte_len = units.checked_mul(2).ok_or_else(|| {
+                FastStartupError::AssetError(format!("Invalid string length {} at {}", len, offset))
+            })?;
+            let utf16: Vec<u16> = self
+                .bytes(byte_len)?
+                .chunks_exact(2)
+                .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                .collect();
+            Ok(String::from_utf16_lossy(&utf16[..utf16.len() - 1]))
+        }
+    }
+
+    fn out_of_bounds(&self, offset: usize, count: usize) -> FastStartupError {
+        FastStartupError::AssetError(format!(
+            "Unexpected end of data: {} bytes at offset {} of {}",
+            count,
+            offset,
+            self.data.len()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primitives_and_bounds() {
+        let data = [1, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF, 2];
+        let mut reader = ByteReader::new(&data);
+        assert_eq!(reader.u32().unwrap(), 1);
+        assert_eq!(reader.i32().unwrap(), -1);
+        assert!(reader.i32().is_err());
+        // A failed read leaves the cursor where it was
+        assert_eq!(reader.position(), 8);
+        assert_eq!(reader.bytes(1).unwrap(), &[2]);
+        assert!(ByteReader::at(&data, 10).is_err());
+        assert!(ByteReader::new(&data).bytes(usize::MAX).is_err());
+    }
+
+    #[test]
+    fn test_fstring() {
+        let mut data = Vec::new();
+        data.extend_from_slice(&4i32.to_le_bytes());
+        data.extend_from_slice(b"Abc\0");
+        data.extend_from_slice(&(-3i32).to_le_bytes());
+        for unit in "Hé\0".encode_utf16() {
+            data.extend_from_slice(&unit.to_le_bytes());
+        }
+        data.extend_from_slice(&100i32.to_le_bytes());
+
+        let mut reader = ByteReader::new(&data);
+        assert_eq!(reader.fstring().unwrap(), "Abc");
+        assert_eq!(reader.fstring().unwrap(), "Hé");
+        assert!(reader.fstring().is_err());
+    }
+}
@@ -4,18 +4,34 @@
 //!
 //! Startup cache generation and management
 
-use crate::graph::DependencyGraph;
-use crate::hash::hash_file;
-use crate::scanner::{AssetScanner, AssetType};
-use crate::{FastStartupError, Result, CACHE_MAGIC};
+use crate::anomalies::{find_anomalies, is_text_path, ContentAnomaly, LFS_POINTER_MAX_BYTES};
+use crate::attribution::startup_roots;
+use crate::cache_log::CacheChanges;
+use crate::config::ProjectConfig;
+use crate::content::ContentProvider;
+use crate::graph::{AssetNode, DependencyGraph};
+use crate::bloom::{BloomFilter, DEFAULT_FALSE_POSITIVE_RATE};
+use crate::load_sim::OrderStrategy;
+use crate::path_index::{path_hash, PathIndex};
+use crate::scenarios::{resolve_all, ScenarioConfig};
+use crate::tags::AssetTags;
+use crate::hash::{
+    hash_file_direct_with, hash_file_with, turbo_hash_with, ContentHash, HashAlgorithm, HashBackend, TurboStrategy,
+};
+use crate::parallelism::{adaptive_filter_map, AdaptiveLimiter};
+use crate::scanner::index::ScanIndex;
+use crate::scanner::{AssetInfo, AssetScanner, AssetType};
+use crate::telemetry::{PhaseMetrics, PhaseTimer};
+use crate::{FastStartupError, Result, CACHE_MAGIC, LEGACY_CACHE_MAGICS};
 use chrono::{DateTime, Utc};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Read, Write};
 use std::path::{Path, PathBuf};
-use tracing::info;
+use std::sync::Arc;
+use tracing::{info, warn};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CachedAsset {
@@ -23,6 +39,7 @@ pub struct CachedAsset {
     pub asset_type: AssetType,
     pub content_hash: u64,
     pub size_bytes: u64,
+    /// Position in `StartupCache::load_order`
     pub load_order: u32,
     pub is_startup_critical: bool,
 }
@@ -33,9 +50,50 @@ pub struct StartupCache {
     pub created_at: DateTime<Utc>,
     pub project_name: String,
     pub hash_algorithm: String,
+    /// Labels such as a build or changelist name
+    pub tags: Vec<String>,
+    /// Arbitrary key/value metadata, e.g. branch or CI job
+    pub metadata: BTreeMap<String, String>,
     pub assets: Vec<CachedAsset>,
-    pub load_order: Vec<String>,
+    /// Offsets into `assets`, in load order
+    pub load_order: Vec<u32>,
+    /// Scenario name -> relative paths of its startup set in load order
+    pub scenarios: BTreeMap<String, Vec<String>>,
     pub shader_variants: Vec<ShaderVariant>,
+    /// Relative path hash -> offset into `assets`, rebuilt on save
+    pub path_index: PathIndex,
+    /// Bloom filter over relative path hashes, rebuilt with `path_index`
+    pub path_filter: BloomFilter,
+}
+
+/// Leading fields of `StartupCache`, in serialization order
+#[derive(Deserialize)]
+struct CacheIndex {
+    version: String,
+    created_at: DateTime<Utc>,
+    project_name: String,
+    hash_algorithm: String,
+    tags: Vec<String>,
+    metadata: BTreeMap<String, String>,
+    assets: Vec<CachedAsset>,
+}
+
+fn read_magic(reader: &mut impl Read) -> Result<()> {
+    let mut magic = [0u8; 8];
+    reader.read_exact(&mut magic)?;
+
+    if LEGACY_CACHE_MAGICS.contains(&&magic) {
+        return Err(FastStartupError::CacheError(format!(
+            "Cache format {} is outdated; rebuild the cache",
+            String::from_utf8_lossy(&magic)
+        )));
+    }
+    if &magic != CACHE_MAGIC {
+        return Err(FastStartupError::CacheError(
+            "Invalid cache file format".to_string()
+        ));
+    }
+    Ok(())
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -51,14 +109,21 @@ impl StartupCache {
             version: crate::VERSION.to_string(),
             created_at: Utc::now(),
             project_name: project_name.to_string(),
-            hash_algorithm: "xxh3".to_string(),
+            hash_algorithm: HashAlgorithm::default().as_str().to_string(),
+            tags: Vec::new(),
+            metadata: BTreeMap::new(),
             assets: Vec::new(),
             load_order: Vec::new(),
+            scenarios: BTreeMap::new(),
             shader_variants: Vec::new(),
+            path_index: PathIndex::default(),
+            path_filter: BloomFilter::default(),
         }
     }
 
-    pub fn save(&self, path: &Path) -> Result<()> {
+    pub fn save(&mut self, path: &Path) -> Result<()> {
+        self.rebuild_index();
+
         let file = File::create(path)?;
         let mut writer = BufWriter::new(file);
 
@@ -74,64 +139,189 @@ impl StartupCache {
         Ok(())
     }
 
+    /// `save` to a temporary file renamed over `path`, so readers never see
+    /// a half written cache
+    pub fn save_atomic(&mut self, path: &Path) -> Result<()> {
+        let mut temporary = path.as_os_str().to_owned();
+        temporary.push(".tmp");
+        let temporary = PathBuf::from(temporary);
+        self.save(&temporary)?;
+        std::fs::rename(&temporary, path).inspect_err(|_| {
+            let _ = std::fs::remove_file(&temporary);
+        })?;
+        Ok(())
+    }
+
     pub fn load(path: &Path) -> Result<Self> {
         let file = File::open(path)?;
-        let mut reader = BufReader::new(file);
-
-        // Verify magic bytes
-        let mut magic = [0u8; 8];
-        reader.read_exact(&mut magic)?;
+        Self::load_from(&mut BufReader::new(file))
+    }
 
-        if &magic != CACHE_MAGIC {
-            return Err(FastStartupError::CacheError(
-                "Invalid cache file format".to_string()
-            ));
-        }
+    /// Whole cache from a reader positioned at the magic bytes
+    pub fn load_from(reader: &mut impl Read) -> Result<Self> {
+        read_magic(reader)?;
 
         // Read cache data
-        let cache: StartupCache = bincode::deserialize_from(&mut reader)
+        let mut cache: StartupCache = bincode::deserialize_from(reader)
             .map_err(|e| FastStartupError::SerializationError(e.to_string()))?;
+        if cache.path_index.len() != cache.assets.len() {
+            cache.rebuild_index();
+        }
 
         info!("Cache loaded: {} assets", cache.assets.len());
         Ok(cache)
     }
 
-    pub fn verify(&self, project_root: &Path) -> Result<VerifyResult> {
-        info!("Verifying cache against project...");
+    /// Header and asset records only, read from the start of a cache file;
+    /// nothing past the records is consumed. Enough to `verify`, without the
+    /// load order, scenarios and shader variants behind them.
+    pub fn load_index(reader: &mut impl Read) -> Result<Self> {
+        read_magic(reader)?;
+        let index: CacheIndex = bincode::deserialize_from(reader)
+            .map_err(|e| FastStartupError::SerializationError(e.to_string()))?;
+
+        let mut cache = Self {
+            version: index.version,
+            created_at: index.created_at,
+            project_name: index.project_name,
+            hash_algorithm: index.hash_algorithm,
+            tags: index.tags,
+            metadata: index.metadata,
+            assets: index.assets,
+            ..Self::new("")
+        };
+        cache.rebuild_index();
+        info!("Cache index loaded: {} assets", cache.assets.len());
+        Ok(cache)
+    }
+
+    /// Rebuild `path_index` and `path_filter` after `assets` changed
+    pub fn rebuild_index(&mut self) {
+        self.path_index = PathIndex::build(self.assets.iter().map(|a| a.relative_path.as_bytes()));
+        self.path_filter = BloomFilter::with_capacity(self.assets.len(), DEFAULT_FALSE_POSITIVE_RATE);
+        for asset in &self.assets {
+            self.path_filter.insert(path_hash(asset.relative_path.as_bytes()));
+        }
+    }
 
-        let scanner = AssetScanner::new(project_root)?;
-        let current_assets = scanner.scan_all(None)?;
+    /// `false` if `relative_path` is definitely not in the cache, without
+    /// touching the asset records
+    pub fn may_contain(&self, relative_path: &Path) -> bool {
+        self.path_filter
+            .may_contain(path_hash(relative_path.as_os_str().as_encoded_bytes()))
+    }
 
-        let current_map: HashMap<_, _> = current_assets
-            .iter()
-            .map(|a| (a.relative_path.clone(), a))
-            .collect();
+    /// Offset into `assets` of the asset at `relative_path`
+    pub fn position(&self, relative_path: &Path) -> Option<usize> {
+        let bytes = relative_path.as_os_str().as_encoded_bytes();
+        self.path_index
+            .candidates(bytes)
+            .find(|r| self.assets[*r].relative_path.as_bytes() == bytes)
+    }
+
+    pub fn find(&self, relative_path: &Path) -> Option<&CachedAsset> {
+        self.position(relative_path).map(|r| &self.assets[r])
+    }
+
+    /// Asset records in load order
+    pub fn ordered_assets(&self) -> impl Iterator<Item = &CachedAsset> {
+        self.load_order.iter().filter_map(|&r| self.assets.get(r as usize))
+    }
+
+    /// Set every ordered record's `load_order` to its position
+    fn renumber_load_order(&mut self) {
+        for (position, &r) in self.load_order.iter().enumerate() {
+            if let Some(asset) = self.assets.get_mut(r as usize) {
+                asset.load_order = position as u32;
+            }
+        }
+    }
+
+    /// Algorithm the asset hashes were computed with
+    pub fn algorithm(&self) -> Result<HashAlgorithm> {
+        if TurboStrategy::from_algorithm(&self.hash_algorithm).is_some() {
+            return Err(FastStartupError::CacheError(format!(
+                "Cache was hashed with turbo sampling ({}); check it with quick-verify",
+                self.hash_algorithm
+            )));
+        }
+        HashAlgorithm::from_name(&self.hash_algorithm).ok_or_else(|| {
+            FastStartupError::CacheError(format!(
+                "Cache uses unknown hash algorithm '{}'; rebuild it with this version",
+                self.hash_algorithm
+            ))
+        })
+    }
+
+    /// Fail unless the cache was hashed with `expected`
+    pub fn require_algorithm(&self, expected: HashAlgorithm) -> Result<()> {
+        let algorithm = self.algorithm()?;
+        if algorithm != expected {
+            return Err(FastStartupError::CacheError(format!(
+                "Cache was built with {} but {} is required; rebuild it with `cache --hash-algorithm {}`",
+                algorithm, expected, expected
+            )));
+        }
+        Ok(())
+    }
+
+    /// Compare the cache with the project's current content, scanned with
+    /// the exclude globs of `config`
+    pub fn verify(&self, project_root: &Path, config: &ProjectConfig) -> Result<VerifyResult> {
+        let algorithm = self.algorithm()?;
+        info!("Verifying cache against project ({})...", algorithm);
+
+        let scanner = AssetScanner::new(project_root)?.with_config(config);
+        let current_assets = scanner.scan_all(None)?;
 
         let mut matching = 0;
         let mut changed = Vec::new();
-        let mut missing = Vec::new();
-
-        for cached in &self.assets {
-            match current_map.get(&cached.relative_path) {
-                Some(current) => {
-                    // Check if hash matches
-                    if let Ok(hash) = hash_file(&current.path) {
-                        if hash.as_u64() == cached.content_hash {
-                            matching += 1;
-                        } else {
-                            changed.push(cached.relative_path.clone());
-                        }
-                    } else {
-                        changed.push(cached.relative_path.clone());
-                    }
-                }
-                None => {
-                    missing.push(cached.relative_path.clone());
-                }
+        let mut added = Vec::new();
+        let mut seen = vec![false; self.assets.len()];
+
+        for current in &current_assets {
+            let Some(record) = self.position(Path::new(&current.relative_path)) else {
+                added.push(current);
+                continue;
+            };
+            seen[record] = true;
+            let cached = &self.assets[record];
+
+            // Check if hash matches
+            match hash_file_with(&current.path, algorithm) {
+                Ok(hash) if hash.as_u64() == cached.content_hash => matching += 1,
+                _ => changed.push(cached.relative_path.clone()),
             }
         }
 
-        let is_valid = changed.is_empty() && missing.is_empty();
+        let missing: Vec<&CachedAsset> = self.assets
+            .iter()
+            .zip(&seen)
+            .filter(|(_, seen)| !**seen)
+            .map(|(cached, _)| cached)
+            .collect();
+
+        // A missing path and a new path with the same content is a move;
+        // only new files sized like a missing one are worth hashing
+        let missing_sizes: HashSet<u64> = missing.iter().map(|a| a.size_bytes).collect();
+        let added: Vec<(String, u64)> = added
+            .par_iter()
+            .filter(|a| missing_sizes.contains(&a.size_bytes))
+            .filter_map(|a| Some((a.relative_path.clone(), hash_file_with(&a.path, algorithm).ok()?.as_u64())))
+            .collect();
+        let renamed = match_renames(&missing, &added);
+
+        let renamed_from: HashSet<&str> = renamed.iter().map(|r| r.from.as_str()).collect();
+        let missing: Vec<String> = missing
+            .iter()
+            .filter(|a| !renamed_from.contains(a.relative_path.as_str()))
+            .map(|a| a.relative_path.clone())
+            .collect();
+
+        let is_valid = changed.is_empty() && missing.is_empty() && renamed.is_empty();
+
+        // Explain changes caused by LFS pointers and line ending conversion
+        let anomalies = find_anomalies(project_root, changed.iter().map(|p| p.as_str()));
 
         Ok(VerifyResult {
             is_valid,
@@ -139,9 +329,188 @@ impl StartupCache {
             matching_assets: matching,
             changed_assets: changed,
             missing_assets: missing,
+            renamed_assets: renamed,
+            anomalies,
         })
     }
 
+    /// Rewrite moved assets' paths in place, keeping their load order and
+    /// startup data. Returns the number of assets renamed.
+    pub fn apply_renames(&mut self, renames: &[Rename]) -> usize {
+        let mut applied = 0;
+        for rename in renames {
+            let Some(record) = self.position(Path::new(&rename.from)) else {
+                continue;
+            };
+            self.assets[record].relative_path = rename.to.clone();
+            applied += 1;
+        }
+        self.rebuild_index();
+        applied
+    }
+
+    /// Bring the cache up to date with `project_root` without a rebuild.
+    /// Only files whose size changed or that were modified since
+    /// `created_at` are rehashed; deleted assets are dropped, moved ones keep
+    /// their records and new ones are appended after the existing load
+    /// order. The dependency graph is not rebuilt, so new assets are not
+    /// startup critical until the next full build. `created_at` moves to the
+    /// update time, so the next update starts from here. The scan applies
+    /// the exclude globs of `config`.
+    pub fn update(&mut self, project_root: &Path, config: &ProjectConfig) -> Result<CacheChanges> {
+        let since = self.created_at.timestamp().max(0) as u64;
+        let updated_at = Utc::now();
+        let current = AssetScanner::new(project_root)?.with_config(config).scan_all(None)?;
+        let changes = self.reconcile(&current, None, |cached, asset| {
+            cached.size_bytes != asset.size_bytes || asset.modified >= since
+        })?;
+        self.created_at = updated_at;
+        Ok(changes)
+    }
+
+    /// `update` limited to `relative_paths`, e.g. from file system events:
+    /// only those files are rehashed, added or dropped and the rest of the
+    /// project is not scanned. `created_at` stays, so a later full `update`
+    /// still catches anything the events missed. Paths the exclude globs of
+    /// `config` match are dropped, as a full `update` would.
+    pub fn update_paths(
+        &mut self,
+        project_root: &Path,
+        config: &ProjectConfig,
+        relative_paths: &[String],
+    ) -> Result<CacheChanges> {
+        let scanner = AssetScanner::new(project_root)?.with_config(config);
+        let current: Vec<AssetInfo> = relative_paths.iter().filter_map(|path| scanner.asset_info(path)).collect();
+        let scope: HashSet<&str> = relative_paths.iter().map(String::as_str).collect();
+        self.reconcile(&current, Some(&scope), |_, _| true)
+    }
+
+    /// Fold `current` into the records: records `is_touched` says changed are
+    /// rehashed, unknown assets added and records in `scope` (all without
+    /// one) that `current` lacks dropped or matched to moves
+    fn reconcile(
+        &mut self,
+        current: &[AssetInfo],
+        scope: Option<&HashSet<&str>>,
+        is_touched: impl Fn(&CachedAsset, &AssetInfo) -> bool,
+    ) -> Result<CacheChanges> {
+        let turbo = TurboStrategy::from_algorithm(&self.hash_algorithm);
+        let algorithm = if turbo.is_some() { HashAlgorithm::default() } else { self.algorithm()? };
+        let hash = |path: &Path| match turbo {
+            Some(strategy) => turbo_hash_with(path, strategy),
+            None => hash_file_with(path, algorithm),
+        };
+        if self.path_index.len() != self.assets.len() {
+            self.rebuild_index();
+        }
+
+        // Records outside the scope count as seen so they are kept
+        let mut seen: Vec<bool> = match scope {
+            Some(scope) => self.assets.iter().map(|a| !scope.contains(a.relative_path.as_str())).collect(),
+            None => vec![false; self.assets.len()],
+        };
+        let mut touched = Vec::new();
+        let mut new = Vec::new();
+        for asset in current {
+            match self.position(Path::new(&asset.relative_path)) {
+                Some(record) => {
+                    seen[record] = true;
+                    if is_touched(&self.assets[record], asset) {
+                        touched.push((record, asset));
+                    }
+                }
+                None => new.push(asset),
+            }
+        }
+        info!("Rehashing {} touched and {} new assets...", touched.len(), new.len());
+
+        let mut changes = CacheChanges::default();
+        let rehashed: Vec<Option<u64>> = touched
+            .par_iter()
+            .map(|(_, asset)| hash(&asset.path).ok().map(|h| h.as_u64()))
+            .collect();
+        for ((record, asset), hash) in touched.iter().zip(rehashed) {
+            let Some(hash) = hash else {
+                // Unreadable now; dropped until it can be hashed again
+                seen[*record] = false;
+                continue;
+            };
+            let cached = &mut self.assets[*record];
+            if cached.content_hash != hash {
+                changes.changed.push(cached.relative_path.clone());
+            }
+            cached.content_hash = hash;
+            cached.size_bytes = asset.size_bytes;
+        }
+        let new: Vec<(&AssetInfo, u64)> = new
+            .par_iter()
+            .filter_map(|asset| Some((*asset, hash(&asset.path).ok()?.as_u64())))
+            .collect();
+
+        // Moved assets keep their load order and startup data
+        let missing: Vec<&CachedAsset> = self.assets.iter().zip(&seen).filter(|(_, seen)| !**seen).map(|(a, _)| a).collect();
+        let candidates: Vec<(String, u64)> = new.iter().map(|(a, hash)| (a.relative_path.clone(), *hash)).collect();
+        let renames = match_renames(&missing, &candidates);
+        let renamed_from: HashSet<&str> = renames.iter().map(|r| r.from.as_str()).collect();
+        let removed: HashSet<String> = missing
+            .iter()
+            .map(|a| a.relative_path.clone())
+            .filter(|path| !renamed_from.contains(path.as_str()))
+            .collect();
+        self.apply_renames(&renames);
+
+        let normalized: HashSet<String> = removed.iter().map(|p| p.replace('\\', "/")).collect();
+        // Where each record ends up once the removed ones are gone
+        let mut kept = 0;
+        let offsets: Vec<Option<u32>> = self
+            .assets
+            .iter()
+            .map(|a| {
+                (!removed.contains(&a.relative_path)).then(|| {
+                    kept += 1;
+                    kept - 1
+                })
+            })
+            .collect();
+        self.assets.retain(|a| !removed.contains(&a.relative_path));
+        self.load_order = self.load_order.iter().filter_map(|&r| offsets.get(r as usize).copied().flatten()).collect();
+        let moved: HashMap<String, String> = renames
+            .iter()
+            .map(|r| (r.from.replace('\\', "/"), r.to.replace('\\', "/")))
+            .collect();
+        for set in self.scenarios.values_mut() {
+            set.retain(|path| !normalized.contains(path));
+            for path in set.iter_mut() {
+                if let Some(to) = moved.get(path) {
+                    *path = to.clone();
+                }
+            }
+        }
+
+        let renamed_to: HashSet<&str> = renames.iter().map(|r| r.to.as_str()).collect();
+        for (asset, hash) in new.into_iter().filter(|(a, _)| !renamed_to.contains(a.relative_path.as_str())) {
+            self.assets.push(CachedAsset {
+                relative_path: asset.relative_path.clone(),
+                asset_type: asset.asset_type,
+                content_hash: hash,
+                size_bytes: asset.size_bytes,
+                load_order: self.load_order.len() as u32,
+                is_startup_critical: false,
+            });
+            self.load_order.push(self.assets.len() as u32 - 1);
+            changes.added.push(asset.relative_path.clone());
+        }
+
+        self.renumber_load_order();
+        self.rebuild_index();
+        changes.removed = removed.into_iter().collect();
+        changes.renamed = renames;
+        changes.added.sort();
+        changes.changed.sort();
+        changes.removed.sort();
+        Ok(changes)
+    }
+
     pub fn asset_count(&self) -> usize {
         self.assets.len()
     }
@@ -155,8 +524,12 @@ impl StartupCache {
             version: self.version.clone(),
             created_at: self.created_at.to_rfc3339(),
             asset_count: self.assets.len(),
+            startup_assets: self.assets.iter().filter(|a| a.is_startup_critical).count(),
             size_bytes: self.size_bytes(),
             hash_algorithm: self.hash_algorithm.clone(),
+            tags: self.tags.clone(),
+            metadata: self.metadata.clone(),
+            scenarios: self.scenarios.iter().map(|(name, set)| (name.clone(), set.len())).collect(),
         }
     }
 }
@@ -168,6 +541,46 @@ pub struct VerifyResult {
     pub matching_assets: usize,
     pub changed_assets: Vec<String>,
     pub missing_assets: Vec<String>,
+    /// Cached assets that moved to a new path with identical content
+    #[serde(default)]
+    pub renamed_assets: Vec<Rename>,
+    /// Changed assets that are LFS pointers or have divergent line endings
+    #[serde(default)]
+    pub anomalies: Vec<ContentAnomaly>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rename {
+    pub from: String,
+    pub to: String,
+}
+
+/// Pair missing cached assets with new `(relative_path, content_hash)` files
+/// of the same content, each used at most once. Candidates sharing the
+/// missing asset's file name are preferred.
+pub fn match_renames(missing: &[&CachedAsset], added: &[(String, u64)]) -> Vec<Rename> {
+    let mut by_hash: HashMap<u64, Vec<&str>> = HashMap::new();
+    for (path, hash) in added {
+        by_hash.entry(*hash).or_default().push(path);
+    }
+
+    let file_name = |p: &str| Path::new(p).file_name().map(|n| n.to_os_string());
+    let mut renames = Vec::new();
+    for asset in missing {
+        let Some(candidates) = by_hash.get_mut(&asset.content_hash) else {
+            continue;
+        };
+        if candidates.is_empty() {
+            continue;
+        }
+        let name = file_name(&asset.relative_path);
+        let pick = candidates.iter().position(|c| file_name(c) == name).unwrap_or(0);
+        renames.push(Rename {
+            from: asset.relative_path.clone(),
+            to: candidates.remove(pick).to_string(),
+        });
+    }
+    renames
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -175,15 +588,178 @@ pub struct CacheStats {
     pub version: String,
     pub created_at: String,
     pub asset_count: usize,
+    /// Assets flagged startup-critical
+    #[serde(default)]
+    pub startup_assets: usize,
     pub size_bytes: usize,
     pub hash_algorithm: String,
+    pub tags: Vec<String>,
+    pub metadata: BTreeMap<String, String>,
+    /// Scenario name -> assets in its startup set
+    pub scenarios: BTreeMap<String, usize>,
+}
+
+/// Most likely reason an asset differs between caches
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DivergenceCause {
+    /// Absent from some caches (partial sync)
+    Missing,
+    /// Pointer-sized in some caches, full size in others
+    LfsPointer,
+    /// Text file whose size differs (CRLF vs LF)
+    LineEndings,
+    Content,
+}
+
+impl DivergenceCause {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DivergenceCause::Missing => "missing",
+            DivergenceCause::LfsPointer => "lfs_pointer",
+            DivergenceCause::LineEndings => "line_endings",
+            DivergenceCause::Content => "content",
+        }
+    }
+}
+
+/// Agreement between two of the compared caches
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PairAgreement {
+    pub a: usize,
+    pub b: usize,
+    /// Assets with the same hash in both
+    pub matching: usize,
+    /// Assets in both with different hashes
+    pub differing: usize,
+    pub only_in_a: usize,
+    pub only_in_b: usize,
+}
+
+impl PairAgreement {
+    /// Matching assets over all assets in either cache
+    pub fn ratio(&self) -> f64 {
+        let total = self.matching + self.differing + self.only_in_a + self.only_in_b;
+        if total == 0 {
+            1.0
+        } else {
+            self.matching as f64 / total as f64
+        }
+    }
+}
+
+/// An asset that is not identical across every compared cache
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DivergentAsset {
+    pub relative_path: String,
+    pub cause: DivergenceCause,
+    /// Hash per cache, `None` where the asset is absent
+    pub hashes: Vec<Option<u64>>,
+    pub sizes: Vec<Option<u64>>,
+}
+
+/// Agreement matrix of several caches of the same project, e.g. built on
+/// different machines from supposedly identical syncs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheComparison {
+    /// Label of each cache (usually its file name)
+    pub caches: Vec<String>,
+    pub pairs: Vec<PairAgreement>,
+    pub divergent: Vec<DivergentAsset>,
+}
+
+impl CacheComparison {
+    pub fn compare(caches: &[(String, StartupCache)]) -> Self {
+        let maps: Vec<HashMap<&str, &CachedAsset>> = caches
+            .iter()
+            .map(|(_, c)| c.assets.iter().map(|a| (a.relative_path.as_str(), a)).collect())
+            .collect();
+
+        let mut pairs = Vec::new();
+        for a in 0..maps.len() {
+            for b in a + 1..maps.len() {
+                let mut pair = PairAgreement { a, b, matching: 0, differing: 0, only_in_a: 0, only_in_b: 0 };
+                for (path, asset) in &maps[a] {
+                    match maps[b].get(path) {
+                        Some(other) if other.content_hash == asset.content_hash => pair.matching += 1,
+                        Some(_) => pair.differing += 1,
+                        None => pair.only_in_a += 1,
+                    }
+                }
+                pair.only_in_b = maps[b].keys().filter(|p| !maps[a].contains_key(*p)).count();
+                pairs.push(pair);
+            }
+        }
+
+        let all_paths: BTreeSet<&str> = maps.iter().flat_map(|m| m.keys().copied()).collect();
+        let divergent = all_paths
+            .into_iter()
+            .filter_map(|path| {
+                let entries: Vec<Option<&&CachedAsset>> = maps.iter().map(|m| m.get(path)).collect();
+                let hashes: Vec<Option<u64>> = entries.iter().map(|e| e.map(|a| a.content_hash)).collect();
+                if hashes.iter().all(|h| *h == hashes[0]) {
+                    return None;
+                }
+                let sizes: Vec<Option<u64>> = entries.iter().map(|e| e.map(|a| a.size_bytes)).collect();
+                Some(DivergentAsset {
+                    cause: classify_divergence(path, &sizes),
+                    relative_path: path.to_string(),
+                    hashes,
+                    sizes,
+                })
+            })
+            .collect();
+
+        Self {
+            caches: caches.iter().map(|(label, _)| label.clone()).collect(),
+            pairs,
+            divergent,
+        }
+    }
+
+    pub fn is_identical(&self) -> bool {
+        self.divergent.is_empty()
+    }
+}
+
+fn classify_divergence(relative_path: &str, sizes: &[Option<u64>]) -> DivergenceCause {
+    if sizes.iter().any(|s| s.is_none()) {
+        return DivergenceCause::Missing;
+    }
+    let sizes: Vec<u64> = sizes.iter().flatten().copied().collect();
+    let min = sizes.iter().copied().min().unwrap_or(0);
+    let max = sizes.iter().copied().max().unwrap_or(0);
+    if min == max {
+        return DivergenceCause::Content;
+    }
+    if min <= LFS_POINTER_MAX_BYTES && max > LFS_POINTER_MAX_BYTES {
+        return DivergenceCause::LfsPointer;
+    }
+    if is_text_path(Path::new(relative_path)) {
+        DivergenceCause::LineEndings
+    } else {
+        DivergenceCause::Content
+    }
 }
 
 pub struct CacheBuilder {
     project_root: PathBuf,
     include_shaders: bool,
+    tags: Vec<String>,
+    metadata: BTreeMap<String, String>,
+    hash_backend: HashBackend,
+    hash_algorithm: HashAlgorithm,
+    direct_io: bool,
+    load_order_strategy: OrderStrategy,
+    scenarios: BTreeMap<String, ScenarioConfig>,
+    content: Option<Arc<dyn ContentProvider>>,
+    /// The project's `.uefast.toml`: exclude globs and startup heuristics
+    config: ProjectConfig,
 }
 
+/// Metadata key recording the strategy a cache's load order was arranged by
+pub const LOAD_ORDER_STRATEGY_KEY: &str = "load_order_strategy";
+
 impl CacheBuilder {
     pub fn new(project_root: &Path) -> Result<Self> {
         if !project_root.exists() {
@@ -195,75 +771,267 @@ impl CacheBuilder {
         Ok(Self {
             project_root: project_root.to_path_buf(),
             include_shaders: true,
+            tags: Vec::new(),
+            metadata: BTreeMap::new(),
+            hash_backend: HashBackend::default(),
+            hash_algorithm: HashAlgorithm::default(),
+            direct_io: false,
+            load_order_strategy: OrderStrategy::Computed,
+            scenarios: BTreeMap::new(),
+            content: None,
+            config: ProjectConfig::default(),
         })
     }
 
+    /// Scan with the exclude globs and startup heuristics of `config`, the
+    /// project's loaded `.uefast.toml`
+    pub fn with_config(mut self, config: &ProjectConfig) -> Self {
+        self.config = config.clone();
+        self
+    }
+
     pub fn include_shaders(mut self, include: bool) -> Self {
         self.include_shaders = include;
         self
     }
 
+    /// Labels embedded in the cache header
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Key/value metadata embedded in the cache header
+    pub fn with_metadata(mut self, metadata: BTreeMap<String, String>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Backend used to hash asset contents
+    pub fn with_hash_backend(mut self, backend: HashBackend) -> Self {
+        self.hash_backend = backend;
+        self
+    }
+
+    /// Content hash function, recorded in the cache header
+    pub fn with_hash_algorithm(mut self, algorithm: HashAlgorithm) -> Self {
+        self.hash_algorithm = algorithm;
+        self
+    }
+
+    /// Read assets with unbuffered I/O while hashing
+    pub fn with_direct_io(mut self, direct_io: bool) -> Self {
+        self.direct_io = direct_io;
+        self
+    }
+
+    /// How the cached load order is arranged; anything but `computed` trades
+    /// dependency order for another on-disk layout. Recorded in the metadata.
+    pub fn with_load_order_strategy(mut self, strategy: OrderStrategy) -> Self {
+        self.load_order_strategy = strategy;
+        self
+    }
+
+    /// Startup scenarios whose sets are cached next to the full load order
+    pub fn with_scenarios(mut self, scenarios: BTreeMap<String, ScenarioConfig>) -> Self {
+        self.scenarios = scenarios;
+        self
+    }
+
+    /// Scan and hash through `content` instead of the local `Content` directory
+    pub fn with_content(mut self, content: Arc<dyn ContentProvider>) -> Self {
+        self.content = Some(content);
+        self
+    }
+
     pub fn build(&self) -> Result<StartupCache> {
+        self.build_instrumented().map(|(cache, _)| cache)
+    }
+
+    /// Build the cache and return throughput metrics of each phase
+    pub fn build_instrumented(&self) -> Result<(StartupCache, Vec<PhaseMetrics>)> {
+        self.hash_backend.ensure_available()?;
+        info!("Building startup cache...");
+        let mut metrics = Vec::new();
+        let mut cache = self.new_cache();
+
+        // Scan assets
+        let timer = PhaseTimer::start("scan");
+        let scanner = match &self.content {
+            Some(content) => AssetScanner::from_provider(&self.project_root, content.clone())?,
+            None => AssetScanner::new(&self.project_root)?,
+        }
+        .with_config(&self.config);
+        let assets = scanner.scan_all(None)?;
+        timer.record(assets.len(), 0);
+        metrics.push(timer.finish());
+
+        cache.assets = self.hash_assets(&assets, &mut metrics);
+
+        // Build dependency graph and compute load order
+        info!("Computing optimal load order...");
+        let timer = PhaseTimer::start("graph");
+        let mut graph = DependencyGraph::build_from_assets(&self.project_root, &assets)?;
+        graph.compute_load_order();
+        timer.record(graph.node_count(), 0);
+        metrics.push(timer.finish());
+
+        self.apply_load_order(&mut cache, &assets, &graph)?;
+        info!("Cache built: {} assets", cache.assets.len());
+        Ok((cache, metrics))
+    }
+
+    /// Build the cache from an earlier scan and a graph of those assets
+    /// whose load order is already computed
+    pub fn build_from(
+        &self,
+        assets: &[AssetInfo],
+        graph: &DependencyGraph,
+    ) -> Result<(StartupCache, Vec<PhaseMetrics>)> {
+        self.hash_backend.ensure_available()?;
         info!("Building startup cache...");
+        let mut metrics = Vec::new();
+
+        let mut cache = self.new_cache();
+        cache.assets = self.hash_assets(assets, &mut metrics);
+        self.apply_load_order(&mut cache, assets, graph)?;
 
+        info!("Cache built: {} assets", cache.assets.len());
+        Ok((cache, metrics))
+    }
+
+    fn new_cache(&self) -> StartupCache {
         let project_name = self.project_root
             .file_name()
             .map(|n| n.to_string_lossy().to_string())
             .unwrap_or_else(|| "Unknown".to_string());
 
         let mut cache = StartupCache::new(&project_name);
+        cache.hash_algorithm = self.hash_algorithm.as_str().to_string();
+        cache.tags = self.tags.clone();
+        cache.metadata = self.metadata.clone();
+        cache
+            .metadata
+            .insert(LOAD_ORDER_STRATEGY_KEY.to_string(), self.load_order_strategy.as_str().to_string());
+        cache
+    }
 
-        // Scan assets
-        let scanner = AssetScanner::new(&self.project_root)?;
-        let assets = scanner.scan_all(None)?;
-
-        info!("Hashing {} assets...", assets.len());
+    fn hash_assets(&self, assets: &[AssetInfo], metrics: &mut Vec<PhaseMetrics>) -> Vec<CachedAsset> {
+        info!("Hashing {} assets ({})...", assets.len(), self.hash_algorithm);
 
         // Hash assets in parallel
-        let cached_assets: Vec<CachedAsset> = assets
-            .par_iter()
-            .enumerate()
-            .filter_map(|(idx, asset)| {
-                let hash = hash_file(&asset.path).ok()?;
-                
+        let timer = PhaseTimer::start("hash");
+        let hash = |asset: &AssetInfo| match &self.content {
+            Some(content) => content.hash(asset, self.hash_algorithm),
+            None if self.direct_io => hash_file_direct_with(&asset.path, self.hash_algorithm),
+            None => self.hash_backend.hash_file_with(&asset.path, self.hash_algorithm),
+        };
+        // Local files whose size and time match the scan index keep its hash
+        let mut scan_index = if self.content.is_none() { ScanIndex::open_existing(&self.project_root) } else { None };
+        let known: Vec<Option<u64>> = assets
+            .iter()
+            .map(|asset| {
+                let index = scan_index.as_ref()?;
+                index.hash(&asset.relative_path, &asset.path, self.hash_algorithm)
+            })
+            .collect();
+        let reused = known.iter().flatten().count();
+        if reused > 0 {
+            info!("Reusing {} hashes from the scan index", reused);
+        }
+
+        let limiter = AdaptiveLimiter::for_current_pool();
+        let indexed: Vec<(usize, &AssetInfo)> = assets.iter().enumerate().collect();
+        let cached_assets: Vec<CachedAsset> = adaptive_filter_map(
+            &indexed,
+            &limiter,
+            |(idx, asset)| if known[*idx].is_some() { 0 } else { asset.size_bytes },
+            |(idx, asset)| {
+                let hash = match known[*idx] {
+                    Some(hash) => ContentHash(hash),
+                    None => timer.track(asset.size_bytes, || hash(asset)).ok()?,
+                };
+
                 Some(CachedAsset {
                     relative_path: asset.relative_path.clone(),
                     asset_type: asset.asset_type,
                     content_hash: hash.as_u64(),
                     size_bytes: asset.size_bytes,
-                    load_order: idx as u32,
+                    load_order: *idx as u32,
                     is_startup_critical: false,
                 })
-            })
-            .collect();
-
-        cache.assets = cached_assets;
+            },
+        );
 
-        // Build dependency graph and compute load order
-        info!("Computing optimal load order...");
-        let mut graph = DependencyGraph::build(&self.project_root)?;
-        graph.compute_load_order();
+        metrics.push(timer.finish());
+        if let Some(index) = &mut scan_index {
+            for cached in &cached_assets {
+                index.record_hash(&cached.relative_path, self.hash_algorithm, cached.content_hash);
+            }
+            if let Err(e) = index.save() {
+                warn!("Could not save hashes to the scan index: {}", e);
+            }
+        }
+        cached_assets
+    }
 
-        let ordered_nodes = graph.get_load_order();
-        cache.load_order = ordered_nodes
+    /// Record the graph's load order, startup flags and scenario sets
+    fn apply_load_order(&self, cache: &mut StartupCache, assets: &[AssetInfo], graph: &DependencyGraph) -> Result<()> {
+        // Graph nodes matched to the asset records they were scanned as
+        cache.rebuild_index();
+        let mut ordered: Vec<(usize, String, &AssetNode)> = Vec::new();
+        for node in graph.get_load_order() {
+            let relative = node.path.strip_prefix(&self.project_root).unwrap_or(&node.path);
+            if let Some(record) = cache.position(relative) {
+                ordered.push((record, relative.to_string_lossy().to_string(), node));
+            }
+        }
+        let entries: Vec<(&str, u64, AssetType)> = ordered
             .iter()
-            .map(|n| n.path.to_string_lossy().to_string())
+            .map(|(_, path, node)| (path.as_str(), node.size_bytes, node.asset_type))
             .collect();
+        cache.load_order = self
+            .load_order_strategy
+            .arrange(&entries)
+            .into_iter()
+            .map(|i| ordered[i].0 as u32)
+            .collect();
+        cache.renumber_load_order();
 
-        // Mark startup-critical assets
-        for node in ordered_nodes {
-            if node.is_startup_critical {
-                if let Some(cached) = cache.assets
-                    .iter_mut()
-                    .find(|a| a.relative_path == node.path.to_string_lossy())
-                {
-                    cached.is_startup_critical = true;
-                }
+        // Startup roots from the path heuristics and config preloads, plus
+        // any the caller already flagged on the graph, and everything they
+        // hard-reference: the closure `analyze` reports as loaded at startup
+        let mut roots = startup_roots(&self.project_root, assets, &self.config.startup);
+        roots.extend(ordered.iter().filter(|(_, _, node)| node.is_startup_critical).map(|(_, _, node)| node.path.clone()));
+        let critical = graph.hard_closure_of(&roots);
+        for (record, _, node) in &ordered {
+            if critical.contains(&node.path) {
+                cache.assets[*record].is_startup_critical = true;
             }
         }
+        info!("{} startup-critical assets", cache.assets.iter().filter(|a| a.is_startup_critical).count());
 
-        info!("Cache built: {} assets", cache.assets.len());
-        Ok(cache)
+        // Startup sets of each scenario, in the shared load order
+        if !self.scenarios.is_empty() {
+            let tags = AssetTags::load(&self.project_root)?;
+            let order = graph.relative_load_order(&self.project_root);
+            for (name, set) in resolve_all(&self.scenarios, &self.project_root, graph, assets, &tags) {
+                let members: HashSet<String> = set
+                    .members
+                    .iter()
+                    .map(|p| p.strip_prefix(&self.project_root).unwrap_or(p).to_string_lossy().replace('\\', "/"))
+                    .collect();
+                for cached in cache.assets.iter_mut() {
+                    if members.contains(&cached.relative_path.replace('\\', "/")) {
+                        cached.is_startup_critical = true;
+                    }
+                }
+                let ordered: Vec<String> = order.iter().filter(|p| members.contains(*p)).cloned().collect();
+                info!("Scenario {}: {} startup assets", name, ordered.len());
+                cache.scenarios.insert(name, ordered);
+            }
+        }
+        Ok(())
     }
 }
 
@@ -278,10 +1046,168 @@ mod tests {
         assert!(cache.assets.is_empty());
     }
 
+    fn cached(relative_path: &str, content_hash: u64, size_bytes: u64) -> CachedAsset {
+        CachedAsset {
+            relative_path: relative_path.to_string(),
+            asset_type: AssetType::from_extension(relative_path.rsplit('.').next().unwrap_or("")),
+            content_hash,
+            size_bytes,
+            load_order: 0,
+            is_startup_critical: false,
+        }
+    }
+
+    #[test]
+    fn test_cache_algorithm() {
+        let mut cache = StartupCache::new("TestProject");
+        assert_eq!(cache.algorithm().unwrap(), HashAlgorithm::Xxh3);
+        assert!(cache.require_algorithm(HashAlgorithm::Xxh3).is_ok());
+
+        let err = cache.require_algorithm(HashAlgorithm::Blake3).unwrap_err().to_string();
+        assert!(err.contains("built with xxh3 but blake3 is required"), "{}", err);
+
+        cache.hash_algorithm = "xxh3-turbo".to_string();
+        assert!(cache.algorithm().unwrap_err().to_string().contains("quick-verify"));
+        cache.hash_algorithm = "sha3".to_string();
+        assert!(cache.algorithm().is_err());
+    }
+
+    #[test]
+    fn test_cache_compare() {
+        let mut a = StartupCache::new("TestProject");
+        a.assets = vec![
+            cached("Content/A.uasset", 1, 4096),
+            cached("Content/Big.uasset", 2, 1 << 20),
+            cached("Config/DefaultGame.ini", 3, 1000),
+            cached("Content/Only.uasset", 4, 10),
+        ];
+        let mut b = StartupCache::new("TestProject");
+        b.assets = vec![
+            cached("Content/A.uasset", 1, 4096),
+            cached("Content/Big.uasset", 5, 130),
+            cached("Config/DefaultGame.ini", 6, 1040),
+        ];
+
+        let comparison = CacheComparison::compare(&[("a".to_string(), a), ("b".to_string(), b)]);
+        let pair = &comparison.pairs[0];
+        assert_eq!((pair.matching, pair.differing, pair.only_in_a, pair.only_in_b), (1, 2, 1, 0));
+        assert_eq!(pair.ratio(), 0.25);
+
+        let causes: Vec<_> = comparison.divergent.iter().map(|d| (d.relative_path.as_str(), d.cause)).collect();
+        assert_eq!(causes, vec![
+            ("Config/DefaultGame.ini", DivergenceCause::LineEndings),
+            ("Content/Big.uasset", DivergenceCause::LfsPointer),
+            ("Content/Only.uasset", DivergenceCause::Missing),
+        ]);
+    }
+
+    #[test]
+    fn test_cache_find() {
+        let mut cache = StartupCache::new("TestProject");
+        cache.assets = vec![cached("Content/A.uasset", 1, 10), cached("Content/B.uasset", 2, 20)];
+        cache.rebuild_index();
+
+        assert_eq!(cache.find(Path::new("Content/B.uasset")).map(|a| a.content_hash), Some(2));
+        assert!(cache.find(Path::new("Content/C.uasset")).is_none());
+        assert!(cache.may_contain(Path::new("Content/A.uasset")));
+    }
+
+    #[test]
+    fn test_update() {
+        let root = std::env::temp_dir().join(format!("uefast_update_{}", std::process::id()));
+        let content = root.join("Content");
+        std::fs::create_dir_all(content.join("Old")).unwrap();
+        std::fs::write(content.join("A.uasset"), b"a").unwrap();
+        std::fs::write(content.join("B.uasset"), b"b").unwrap();
+        std::fs::write(content.join("Old").join("Hero.uasset"), b"hero").unwrap();
+        let mut cache = CacheBuilder::new(&root).unwrap().build().unwrap();
+
+        std::fs::write(content.join("A.uasset"), b"a2").unwrap();
+        std::fs::remove_file(content.join("B.uasset")).unwrap();
+        std::fs::create_dir_all(content.join("New")).unwrap();
+        std::fs::rename(content.join("Old").join("Hero.uasset"), content.join("New").join("Hero.uasset")).unwrap();
+        std::fs::write(content.join("C.uasset"), b"c").unwrap();
+
+        let rel = |p: &str| Path::new(p).to_string_lossy().replace('/', std::path::MAIN_SEPARATOR_STR);
+        let changes = cache.update(&root, &ProjectConfig::default()).unwrap();
+        assert_eq!(changes.changed, [rel("Content/A.uasset")]);
+        assert_eq!(changes.added, [rel("Content/C.uasset")]);
+        assert_eq!(changes.removed, [rel("Content/B.uasset")]);
+        assert_eq!(changes.renamed[0].to, rel("Content/New/Hero.uasset"));
+        assert_eq!(cache.asset_count(), 3);
+        // The removed record leaves the order; the moved one keeps its place
+        let order: Vec<&str> = cache.ordered_assets().map(|a| a.relative_path.as_str()).collect();
+        assert_eq!(order.len(), 3);
+        assert_eq!(order[2], rel("Content/C.uasset"));
+        assert!(cache.ordered_assets().enumerate().all(|(i, a)| a.load_order == i as u32));
+        assert!(cache.verify(&root, &ProjectConfig::default()).unwrap().is_valid);
+        assert_eq!(cache.update(&root, &ProjectConfig::default()).unwrap().total(), 0);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_startup_critical_flags() {
+        use crate::testgen::{generate, TestProjectSpec};
+        let root = std::env::temp_dir().join(format!("uefast_critical_{}", std::process::id()));
+        let spec = TestProjectSpec { asset_count: 60, map_count: 2, fan_out: 3, max_size: 8 * 1024, ..Default::default() };
+        generate(&root, &spec).unwrap();
+
+        let cache = CacheBuilder::new(&root).unwrap().build().unwrap();
+        let critical: HashSet<&str> =
+            cache.assets.iter().filter(|a| a.is_startup_critical).map(|a| a.relative_path.as_str()).collect();
+        // Both maps and some of what they import, but not every asset
+        assert!(critical.len() > spec.map_count && critical.len() < cache.asset_count());
+        assert_eq!(critical.iter().filter(|p| p.ends_with(".umap")).count(), spec.map_count);
+        assert_eq!(cache.statistics().startup_assets, critical.len());
+
+        // The set `analyze` reports: the hard closure of the startup roots
+        let assets = AssetScanner::new(&root).unwrap().scan_all(None).unwrap();
+        let mut graph = DependencyGraph::build_from_assets(&root, &assets).unwrap();
+        let closure = graph.hard_closure_of(&startup_roots(&root, &assets, &ProjectConfig::default().startup));
+        assert_eq!(closure.len(), critical.len());
+
+        // A graph built without startup flags gives the same cache flags
+        graph.compute_load_order();
+        let (from_graph, _) = CacheBuilder::new(&root).unwrap().build_from(&assets, &graph).unwrap();
+        let flagged: HashSet<&str> =
+            from_graph.assets.iter().filter(|a| a.is_startup_critical).map(|a| a.relative_path.as_str()).collect();
+        assert_eq!(flagged, critical);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_renames() {
+        let mut cache = StartupCache::new("TestProject");
+        cache.assets = vec![cached("Content/Old/Hero.uasset", 7, 10), cached("Content/Gone.uasset", 8, 10)];
+        cache.load_order = vec![1, 0];
+        cache.rebuild_index();
+
+        let missing: Vec<&CachedAsset> = cache.assets.iter().collect();
+        let added = vec![
+            ("Content/Copy.uasset".to_string(), 7),
+            ("Content/New/Hero.uasset".to_string(), 7),
+        ];
+        let renames = match_renames(&missing, &added);
+        assert_eq!(renames, vec![Rename {
+            from: "Content/Old/Hero.uasset".to_string(),
+            to: "Content/New/Hero.uasset".to_string(),
+        }]);
+
+        assert_eq!(cache.apply_renames(&renames), 1);
+        assert!(cache.find(Path::new("Content/New/Hero.uasset")).is_some());
+        let order: Vec<&str> = cache.ordered_assets().map(|a| a.relative_path.as_str()).collect();
+        assert_eq!(order, ["Content/Gone.uasset", "Content/New/Hero.uasset"]);
+    }
+
     #[test]
     fn test_cache_stats() {
-        let cache = StartupCache::new("TestProject");
+        let mut cache = StartupCache::new("TestProject");
+        cache.tags.push("build-1.2.3".to_string());
+        cache.metadata.insert("branch".to_string(), "main".to_string());
+
         let stats = cache.statistics();
         assert_eq!(stats.asset_count, 0);
+        assert_eq!(stats.tags, vec!["build-1.2.3"]);
+        assert_eq!(stats.metadata["branch"], "main");
     }
 }
@@ -0,0 +1,178 @@
+//! Cache Log Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Append-only `.uefast.log` next to a cache recording who changed it, when,
+//! and which assets changed (one JSON object per line)
+
+use crate::cache::{Rename, StartupCache};
+use crate::schema::SCHEMA_VERSION;
+use crate::{FastStartupError, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheChanges {
+    pub added: Vec<String>,
+    pub changed: Vec<String>,
+    pub removed: Vec<String>,
+    #[serde(default)]
+    pub renamed: Vec<Rename>,
+}
+
+impl CacheChanges {
+    /// Asset differences from `old` (absent for a first build) to `new`
+    pub fn between(old: Option<&StartupCache>, new: &StartupCache) -> Self {
+        let old_hashes: HashMap<&str, u64> = old
+            .map(|c| c.assets.iter().map(|a| (a.relative_path.as_str(), a.content_hash)).collect())
+            .unwrap_or_default();
+
+        let mut changes = Self::default();
+        for asset in &new.assets {
+            match old_hashes.get(asset.relative_path.as_str()) {
+                None => changes.added.push(asset.relative_path.clone()),
+                Some(hash) if *hash != asset.content_hash => changes.changed.push(asset.relative_path.clone()),
+                Some(_) => {}
+            }
+        }
+        if let Some(old) = old {
+            changes.removed = old.assets
+                .iter()
+                .filter(|a| new.find(Path::new(&a.relative_path)).is_none())
+                .map(|a| a.relative_path.clone())
+                .collect();
+        }
+
+        changes.added.sort();
+        changes.changed.sort();
+        changes.removed.sort();
+        changes
+    }
+
+    pub fn total(&self) -> usize {
+        self.added.len() + self.changed.len() + self.removed.len() + self.renamed.len()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheLogEntry {
+    /// `SCHEMA_VERSION` of the writer; 0 for entries written before versioning
+    #[serde(default)]
+    pub schema_version: u32,
+    pub timestamp: String,
+    pub user: String,
+    pub host: String,
+    /// Command that wrote the cache, e.g. "build" or "update"
+    pub operation: String,
+    pub asset_count: usize,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub changes: CacheChanges,
+}
+
+impl CacheLogEntry {
+    pub fn new(operation: &str, cache: &StartupCache, changes: CacheChanges) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            timestamp: Utc::now().to_rfc3339(),
+            user: current_user(),
+            host: current_host(),
+            operation: operation.to_string(),
+            asset_count: cache.assets.len(),
+            tags: cache.tags.clone(),
+            changes,
+        }
+    }
+}
+
+/// `Game.uefast` -> `Game.uefast.log`
+pub fn log_path(cache_path: &Path) -> PathBuf {
+    let mut name = cache_path.as_os_str().to_os_string();
+    name.push(".log");
+    PathBuf::from(name)
+}
+
+/// Append `entry` to the log of `cache_path`
+pub fn append(cache_path: &Path, entry: &CacheLogEntry) -> Result<PathBuf> {
+    let path = log_path(cache_path);
+    let line = serde_json::to_string(entry)
+        .map_err(|e| FastStartupError::SerializationError(e.to_string()))?;
+
+    let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+    writeln!(file, "{}", line)?;
+    Ok(path)
+}
+
+/// Entries of the log of `cache_path`, oldest first
+pub fn read(cache_path: &Path) -> Result<Vec<CacheLogEntry>> {
+    let file = std::fs::File::open(log_path(cache_path))?;
+    BufReader::new(file)
+        .lines()
+        .filter(|line| !matches!(line, Ok(l) if l.trim().is_empty()))
+        .map(|line| {
+            serde_json::from_str(&line?).map_err(|e| FastStartupError::SerializationError(e.to_string()))
+        })
+        .collect()
+}
+
+fn current_user() -> String {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
+fn current_host() -> String {
+    std::env::var("COMPUTERNAME")
+        .or_else(|_| std::env::var("HOSTNAME"))
+        .ok()
+        .or_else(|| std::fs::read_to_string("/etc/hostname").ok().map(|h| h.trim().to_string()))
+        .filter(|h| !h.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::CachedAsset;
+    use crate::scanner::AssetType;
+
+    fn cache(assets: &[(&str, u64)]) -> StartupCache {
+        let mut cache = StartupCache::new("TestProject");
+        cache.assets = assets
+            .iter()
+            .map(|(path, hash)| CachedAsset {
+                relative_path: path.to_string(),
+                asset_type: AssetType::UAsset,
+                content_hash: *hash,
+                size_bytes: 1,
+                load_order: 0,
+                is_startup_critical: false,
+            })
+            .collect();
+        cache.rebuild_index();
+        cache
+    }
+
+    #[test]
+    fn test_changes_between() {
+        let old = cache(&[("Content/A.uasset", 1), ("Content/B.uasset", 2)]);
+        let new = cache(&[("Content/A.uasset", 1), ("Content/B.uasset", 3), ("Content/C.uasset", 4)]);
+
+        let changes = CacheChanges::between(Some(&old), &new);
+        assert_eq!(changes.added, vec!["Content/C.uasset"]);
+        assert_eq!(changes.changed, vec!["Content/B.uasset"]);
+        assert!(changes.removed.is_empty());
+
+        let removed = CacheChanges::between(Some(&new), &old);
+        assert_eq!(removed.removed, vec!["Content/C.uasset"]);
+    }
+
+    #[test]
+    fn test_log_path() {
+        assert_eq!(log_path(Path::new("out/Game.uefast")), PathBuf::from("out/Game.uefast.log"));
+    }
+}
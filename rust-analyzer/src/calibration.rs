@@ -0,0 +1,369 @@
+//! Calibration Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Fits load time model coefficients to measured startups (engine logs and
+//! Unreal Insights CSV exports)
+
+use crate::estimator::LoadTimeModel;
+use crate::graph::DependencyGraph;
+use crate::scanner::{is_startup_candidate, package_name_from_relative, AssetInfo, StartupHeuristics};
+use crate::{FastStartupError, Result};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+const MB: f64 = 1024.0 * 1024.0;
+
+/// Ridge strength pulling coefficients toward the previous model, relative
+/// to the mean feature energy. Keeps fits stable with few measurements.
+const RIDGE_FACTOR: f64 = 1e-3;
+
+/// A measured load from a log or trace
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Timing {
+    /// `(Engine Initialization) Total time`
+    EngineInit { ms: f64 },
+    /// `Took N seconds to LoadMap(/Game/...)`
+    LoadMap { package: String, ms: f64 },
+    /// Per-package load time from an Insights export
+    Package { package: String, ms: f64 },
+}
+
+impl Timing {
+    pub fn ms(&self) -> f64 {
+        match self {
+            Timing::EngineInit { ms } | Timing::LoadMap { ms, .. } | Timing::Package { ms, .. } => *ms,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Timing::EngineInit { .. } => "Engine initialization".to_string(),
+            Timing::LoadMap { package, .. } => format!("LoadMap {}", package),
+            Timing::Package { package, .. } => package.clone(),
+        }
+    }
+}
+
+/// Extract engine init and map load timings from an engine log
+pub fn parse_log(text: &str) -> Vec<Timing> {
+    let seconds_before = |line: &str, marker: &str| -> Option<f64> {
+        let head = &line[..line.find(marker)?];
+        head.rsplit(|c: char| c.is_whitespace() || c == ':')
+            .find(|s| !s.is_empty())?
+            .parse()
+            .ok()
+    };
+
+    text.lines()
+        .filter_map(|line| {
+            if line.contains("(Engine Initialization) Total time:") {
+                let secs = seconds_before(line, " seconds")?;
+                return Some(Timing::EngineInit { ms: secs * 1000.0 });
+            }
+
+            let start = line.find("to LoadMap(")? + "to LoadMap(".len();
+            let end = start + line[start..].find(')')?;
+            let secs = seconds_before(line, " seconds to LoadMap(")?;
+            let package = line[start..end].split('.').next()?.trim().to_string();
+            Some(Timing::LoadMap { package, ms: secs * 1000.0 })
+        })
+        .collect()
+}
+
+/// Per-package timings from an Insights asset loading CSV export. Needs a
+/// package column and a duration column; durations are milliseconds unless
+/// the header says seconds.
+pub fn parse_insights_csv(text: &str) -> Result<Vec<Timing>> {
+    let mut lines = text.lines().filter(|l| !l.trim().is_empty());
+    let header: Vec<String> = lines
+        .next()
+        .unwrap_or_default()
+        .split(',')
+        .map(|h| h.trim().trim_matches('"').to_ascii_lowercase())
+        .collect();
+
+    let package_col = header
+        .iter()
+        .position(|h| matches!(h.as_str(), "package" | "packagename" | "package name" | "name" | "asset"));
+    let time_col = header
+        .iter()
+        .position(|h| h.contains("ms") || h.contains("duration") || h.contains("time"));
+
+    let (Some(package_col), Some(time_col)) = (package_col, time_col) else {
+        return Err(FastStartupError::InvalidArgument(
+            "Insights CSV needs a package column and a duration/time column".to_string(),
+        ));
+    };
+    let scale = if header[time_col].contains("(s)") || header[time_col].contains("sec") {
+        1000.0
+    } else {
+        1.0
+    };
+
+    Ok(lines
+        .filter_map(|line| {
+            let cols: Vec<&str> = line.split(',').map(|c| c.trim().trim_matches('"')).collect();
+            let package = cols.get(package_col)?.split('.').next()?.to_string();
+            let ms = cols.get(time_col)?.parse::<f64>().ok()? * scale;
+            package.starts_with('/').then_some(Timing::Package { package, ms })
+        })
+        .collect())
+}
+
+/// Launch a measured startup with an absolute log path and return the log.
+/// `command` is split on whitespace; `-abslog=` is appended.
+pub fn run_measured_startup(command: &str, log_path: &Path) -> Result<String> {
+    let mut parts = command.split_whitespace();
+    let program = parts
+        .next()
+        .ok_or_else(|| FastStartupError::InvalidArgument("Empty --run command".to_string()))?;
+
+    if let Some(parent) = log_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let status = std::process::Command::new(program)
+        .args(parts)
+        .arg(format!("-abslog={}", log_path.display()))
+        .status()?;
+    if !status.success() {
+        return Err(FastStartupError::InvalidArgument(format!(
+            "Measured startup exited with {}",
+            status
+        )));
+    }
+
+    Ok(std::fs::read_to_string(log_path)?)
+}
+
+/// One measurement with the assets it loaded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Observation {
+    pub label: String,
+    pub measured_ms: f64,
+    pub asset_count: usize,
+    /// MB loaded per asset type
+    pub mb_by_type: HashMap<String, f64>,
+}
+
+impl Observation {
+    fn from_assets<'a>(timing: &Timing, assets: impl IntoIterator<Item = &'a AssetInfo>) -> Self {
+        let mut asset_count = 0;
+        let mut mb_by_type: HashMap<String, f64> = HashMap::new();
+        for asset in assets {
+            asset_count += 1;
+            *mb_by_type.entry(asset.asset_type.as_str().to_string()).or_default() +=
+                asset.size_bytes as f64 / MB;
+        }
+        Self {
+            label: timing.label(),
+            measured_ms: timing.ms(),
+            asset_count,
+            mb_by_type,
+        }
+    }
+
+    pub fn estimate_ms(&self, model: &LoadTimeModel) -> f64 {
+        self.asset_count as f64 * model.ms_per_asset
+            + self.mb_by_type
+                .iter()
+                .map(|(t, mb)| model.ms_per_mb_for(t) * mb)
+                .sum::<f64>()
+    }
+}
+
+/// Resolve timings to the assets each load pulled in. Engine init covers the
+/// hard closure of all startup candidates under `heuristics`, a map load the
+/// closure of the map. Timings whose package is not in the project are
+/// dropped.
+pub fn build_observations(
+    project_root: &Path,
+    assets: &[AssetInfo],
+    graph: &DependencyGraph,
+    timings: &[Timing],
+    heuristics: &StartupHeuristics,
+) -> Vec<Observation> {
+    let mut by_package: HashMap<String, Vec<&AssetInfo>> = HashMap::new();
+    for asset in assets {
+        if let Some(package) = package_name_from_relative(&asset.relative_path) {
+            by_package.entry(package).or_default().push(asset);
+        }
+    }
+
+    // Every file (.uasset, .uexp, .ubulk) of the packages in a closure
+    let expand = |roots: HashSet<PathBuf>| -> Vec<&AssetInfo> {
+        let packages: BTreeSet<String> = graph
+            .hard_closure_of(&roots)
+            .iter()
+            .filter_map(|p| p.strip_prefix(project_root).ok())
+            .filter_map(|p| package_name_from_relative(&p.to_string_lossy()))
+            .collect();
+        packages
+            .iter()
+            .filter_map(|p| by_package.get(p))
+            .flatten()
+            .copied()
+            .collect()
+    };
+
+    timings
+        .iter()
+        .filter_map(|timing| {
+            let loaded = match timing {
+                Timing::EngineInit { .. } => expand(
+                    assets.iter().filter(|a| is_startup_candidate(a, heuristics)).map(|a| a.path.clone()).collect(),
+                ),
+                Timing::LoadMap { package, .. } => {
+                    expand(by_package.get(package)?.iter().map(|a| a.path.clone()).collect())
+                }
+                Timing::Package { package, .. } => by_package.get(package)?.clone(),
+            };
+            (!loaded.is_empty()).then(|| Observation::from_assets(timing, loaded))
+        })
+        .collect()
+}
+
+/// Least squares fit of the model to `observations`, regularized toward
+/// `prior` so types that no measurement exercised keep their coefficients.
+/// Negative coefficients are clamped to zero.
+pub fn fit(observations: &[Observation], prior: &LoadTimeModel) -> LoadTimeModel {
+    let types: Vec<String> = observations
+        .iter()
+        .flat_map(|o| o.mb_by_type.keys().cloned())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let n = types.len() + 1;
+
+    let features = |o: &Observation| -> Vec<f64> {
+        std::iter::once(o.asset_count as f64)
+            .chain(types.iter().map(|t| o.mb_by_type.get(t).copied().unwrap_or(0.0)))
+            .collect()
+    };
+    let c0: Vec<f64> = std::iter::once(prior.ms_per_asset)
+        .chain(types.iter().map(|t| prior.ms_per_mb_for(t)))
+        .collect();
+
+    // Normal equations (XᵀX + λI) c = Xᵀy + λ c0
+    let mut a = vec![vec![0.0; n]; n];
+    let mut b = vec![0.0; n];
+    for o in observations {
+        let x = features(o);
+        for i in 0..n {
+            b[i] += x[i] * o.measured_ms;
+            for j in 0..n {
+                a[i][j] += x[i] * x[j];
+            }
+        }
+    }
+    let energy = (0..n).map(|i| a[i][i]).sum::<f64>() / n as f64;
+    let lambda = RIDGE_FACTOR * energy + 1e-9;
+    for i in 0..n {
+        a[i][i] += lambda;
+        b[i] += lambda * c0[i];
+    }
+
+    let coefficients = solve(a, b).unwrap_or(c0);
+
+    let mut model = prior.clone();
+    model.ms_per_asset = coefficients[0].max(0.0);
+    for (t, c) in types.iter().zip(&coefficients[1..]) {
+        model.ms_per_mb.insert(t.clone(), c.max(0.0));
+    }
+    model.observations = observations.len();
+    model.calibrated_at = Some(Utc::now().to_rfc3339());
+    model
+}
+
+/// Gaussian elimination with partial pivoting
+fn solve(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        let pivot_row = a[col].clone();
+        for row in col + 1..n {
+            let factor = a[row][col] / pivot_row[col];
+            for (value, pivot) in a[row].iter_mut().zip(&pivot_row).skip(col) {
+                *value -= factor * pivot;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let sum: f64 = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    Some(x)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalibrationFit {
+    pub label: String,
+    pub measured_ms: f64,
+    pub estimated_before_ms: f64,
+    pub estimated_after_ms: f64,
+}
+
+/// Per-measurement comparison of the old and fitted models
+pub fn compare(observations: &[Observation], before: &LoadTimeModel, after: &LoadTimeModel) -> Vec<CalibrationFit> {
+    observations
+        .iter()
+        .map(|o| CalibrationFit {
+            label: o.label.clone(),
+            measured_ms: o.measured_ms,
+            estimated_before_ms: o.estimate_ms(before),
+            estimated_after_ms: o.estimate_ms(after),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_log() {
+        let log = "\
+[2026.03.01-10.00.01:000][  0]LogLoad: Took 1.250000 seconds to LoadMap(/Game/Maps/Entry)
+[2026.03.01-10.00.02:000][  0]LogLoad: (Engine Initialization) Total time: 9.71 seconds
+[2026.03.01-10.00.03:000][  0]LogTemp: unrelated";
+
+        assert_eq!(parse_log(log), vec![
+            Timing::LoadMap { package: "/Game/Maps/Entry".to_string(), ms: 1250.0 },
+            Timing::EngineInit { ms: 9710.0 },
+        ]);
+    }
+
+    #[test]
+    fn test_fit_recovers_coefficients() {
+        // Truth: 2ms per asset, 8ms per uasset MB, 1ms per ubulk MB
+        let observations: Vec<Observation> = [(10, 5.0, 50.0), (40, 20.0, 10.0), (5, 100.0, 0.0), (80, 1.0, 300.0)]
+            .iter()
+            .map(|&(count, uasset_mb, ubulk_mb)| Observation {
+                label: String::new(),
+                measured_ms: 2.0 * count as f64 + 8.0 * uasset_mb + 1.0 * ubulk_mb,
+                asset_count: count,
+                mb_by_type: HashMap::from([
+                    ("uasset".to_string(), uasset_mb),
+                    ("ubulk".to_string(), ubulk_mb),
+                ]),
+            })
+            .collect();
+
+        let model = fit(&observations, &LoadTimeModel::default());
+        assert!((model.ms_per_asset - 2.0).abs() < 0.5);
+        assert!((model.ms_per_mb_for("uasset") - 8.0).abs() < 0.5);
+        assert!((model.ms_per_mb_for("ubulk") - 1.0).abs() < 0.5);
+        assert_eq!(model.observations, 4);
+    }
+}
@@ -0,0 +1,385 @@
+//! Budget Check Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Startup budget evaluation and baseline comparison for CI
+
+use crate::analyzer::{AnalysisReport, StartupAssetEntry};
+use crate::report::{Finding, ReportData, Severity, Table};
+use crate::schema::stamp;
+use crate::{FastStartupError, Result, STATE_DIR};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+const MB: f64 = 1024.0 * 1024.0;
+
+/// Maximum new offenders listed in a check summary
+pub const MAX_OFFENDERS: usize = 10;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Budgets {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_startup_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_startup_assets: Option<usize>,
+    /// Maximum startup size growth relative to the baseline
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_startup_growth_bytes: Option<u64>,
+    /// Startup size budgets of assets carrying a feature tag
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub max_startup_bytes_by_tag: BTreeMap<String, u64>,
+}
+
+impl Budgets {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// `self` with every budget set in `overrides` replaced
+    pub fn overridden_by(&self, overrides: Budgets) -> Budgets {
+        let mut by_tag = self.max_startup_bytes_by_tag.clone();
+        by_tag.extend(overrides.max_startup_bytes_by_tag);
+        Budgets {
+            max_startup_bytes: overrides.max_startup_bytes.or(self.max_startup_bytes),
+            max_startup_assets: overrides.max_startup_assets.or(self.max_startup_assets),
+            max_startup_growth_bytes: overrides.max_startup_growth_bytes.or(self.max_startup_growth_bytes),
+            max_startup_bytes_by_tag: by_tag,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BudgetResult {
+    pub name: String,
+    pub actual: f64,
+    pub limit: f64,
+    pub unit: String,
+    pub passed: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BaselineDelta {
+    pub startup_assets_delta: i64,
+    pub startup_size_delta_bytes: i64,
+    pub total_assets_delta: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckReport {
+    pub project_name: String,
+    pub passed: bool,
+    pub startup_assets: usize,
+    pub startup_size_bytes: u64,
+    pub budgets: Vec<BudgetResult>,
+    pub baseline: Option<BaselineDelta>,
+    /// Largest startup assets absent from the baseline
+    pub new_offenders: Vec<StartupAssetEntry>,
+}
+
+impl CheckReport {
+    pub fn evaluate(
+        current: &AnalysisReport,
+        baseline: Option<&AnalysisReport>,
+        budgets: &Budgets,
+    ) -> Self {
+        let mut results = Vec::new();
+
+        if let Some(limit) = budgets.max_startup_bytes {
+            results.push(BudgetResult {
+                name: "Startup size".to_string(),
+                actual: current.startup_size_bytes as f64 / MB,
+                limit: limit as f64 / MB,
+                unit: "MB".to_string(),
+                passed: current.startup_size_bytes <= limit,
+            });
+        }
+
+        if let Some(limit) = budgets.max_startup_assets {
+            results.push(BudgetResult {
+                name: "Startup assets".to_string(),
+                actual: current.startup_assets as f64,
+                limit: limit as f64,
+                unit: "assets".to_string(),
+                passed: current.startup_assets <= limit,
+            });
+        }
+
+        for (tag, &limit) in &budgets.max_startup_bytes_by_tag {
+            let actual = current.by_tag.get(tag).map_or(0, |t| t.startup_size_bytes);
+            results.push(BudgetResult {
+                name: format!("Startup size [{}]", tag),
+                actual: actual as f64 / MB,
+                limit: limit as f64 / MB,
+                unit: "MB".to_string(),
+                passed: actual <= limit,
+            });
+        }
+
+        let delta = baseline.map(|base| BaselineDelta {
+            startup_assets_delta: current.startup_assets as i64 - base.startup_assets as i64,
+            startup_size_delta_bytes: current.startup_size_bytes as i64
+                - base.startup_size_bytes as i64,
+            total_assets_delta: current.total_assets as i64 - base.total_assets as i64,
+        });
+
+        if let (Some(limit), Some(delta)) = (budgets.max_startup_growth_bytes, &delta) {
+            results.push(BudgetResult {
+                name: "Startup growth".to_string(),
+                actual: delta.startup_size_delta_bytes as f64 / MB,
+                limit: limit as f64 / MB,
+                unit: "MB".to_string(),
+                passed: delta.startup_size_delta_bytes <= limit as i64,
+            });
+        }
+
+        let new_offenders = match baseline {
+            Some(base) => {
+                let known: HashSet<&str> = base.startup_asset_list
+                    .iter()
+                    .map(|a| a.relative_path.as_str())
+                    .collect();
+                current.startup_asset_list
+                    .iter()
+                    .filter(|a| !known.contains(a.relative_path.as_str()))
+                    .take(MAX_OFFENDERS)
+                    .cloned()
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+
+        Self {
+            project_name: current.project_name.clone(),
+            passed: results.iter().all(|b| b.passed),
+            startup_assets: current.startup_assets,
+            startup_size_bytes: current.startup_size_bytes,
+            budgets: results,
+            baseline: delta,
+            new_offenders,
+        }
+    }
+}
+
+/// Load a previous `analyze` JSON report to use as baseline
+pub fn load_baseline(path: &Path) -> Result<AnalysisReport> {
+    let data = std::fs::read_to_string(path)?;
+    serde_json::from_str(&data).map_err(|e| {
+        FastStartupError::SerializationError(format!("{}: {}", path.display(), e))
+    })
+}
+
+pub fn history_dir(project_root: &Path) -> PathBuf {
+    project_root.join(STATE_DIR).join("history")
+}
+
+/// Most recent report saved by `analyze --record`
+pub fn latest_recorded(project_root: &Path) -> Result<Option<AnalysisReport>> {
+    let dir = history_dir(project_root);
+    if !dir.exists() {
+        return Ok(None);
+    }
+
+    // Timestamped names sort chronologically
+    let latest = std::fs::read_dir(&dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().map(|e| e == "json").unwrap_or(false))
+        .max();
+
+    latest.map(|p| load_baseline(&p)).transpose()
+}
+
+/// Save a report into the project's analysis history
+pub fn record(project_root: &Path, report: &AnalysisReport) -> Result<PathBuf> {
+    let dir = history_dir(project_root);
+    std::fs::create_dir_all(&dir)?;
+
+    let path = dir.join(format!("analysis-{}.json", Utc::now().format("%Y%m%dT%H%M%S")));
+    let json = serde_json::to_value(report)
+        .and_then(|value| serde_json::to_string_pretty(&stamp(value)))
+        .map_err(|e| FastStartupError::SerializationError(e.to_string()))?;
+    std::fs::write(&path, json)?;
+    Ok(path)
+}
+
+fn signed_mb(bytes: i64) -> String {
+    format!("{:+.1} MB", bytes as f64 / MB)
+}
+
+impl ReportData for CheckReport {
+    fn title(&self) -> String {
+        let status = if self.passed { "passed" } else { "FAILED" };
+        format!("Startup budget check {}: {}", status, self.project_name)
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        serde_json::to_value(self).map_err(|e| FastStartupError::SerializationError(e.to_string()))
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        let mut rows = vec![
+            (
+                "Startup size".to_string(),
+                format!("{:.1} MB", self.startup_size_bytes as f64 / MB),
+            ),
+            ("Startup assets".to_string(), self.startup_assets.to_string()),
+        ];
+
+        if let Some(delta) = &self.baseline {
+            rows.push((
+                "Startup delta vs baseline".to_string(),
+                format!(
+                    "{} ({:+} assets)",
+                    signed_mb(delta.startup_size_delta_bytes),
+                    delta.startup_assets_delta
+                ),
+            ));
+        }
+
+        for budget in &self.budgets {
+            rows.push((
+                format!("Budget: {}", budget.name),
+                format!(
+                    "{} {:.1} / {:.1} {}",
+                    if budget.passed { "ok" } else { "over" },
+                    budget.actual,
+                    budget.limit,
+                    budget.unit
+                ),
+            ));
+        }
+
+        rows
+    }
+
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["new_offender", "size_mb"]);
+        for asset in &self.new_offenders {
+            table.push(vec![
+                asset.relative_path.clone(),
+                format!("{:.2}", asset.size_bytes as f64 / MB),
+            ]);
+        }
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        let breaches = self.budgets.iter().filter(|b| !b.passed).map(|b| Finding {
+            rule_id: "Budget".to_string(),
+            severity: Severity::Error,
+            message: format!(
+                "{} over budget: {:.1} {} (limit {:.1})",
+                b.name, b.actual, b.unit, b.limit
+            ),
+            location: None,
+        });
+
+        let offenders = self.new_offenders.iter().map(|a| Finding {
+            rule_id: "NewStartupAsset".to_string(),
+            severity: Severity::Warning,
+            message: format!(
+                "New startup asset ({:.2} MB)",
+                a.size_bytes as f64 / MB
+            ),
+            location: Some(a.relative_path.clone()),
+        });
+
+        breaches.chain(offenders).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(entries: &[(&str, u64)]) -> AnalysisReport {
+        let list: Vec<StartupAssetEntry> = entries
+            .iter()
+            .map(|(p, s)| StartupAssetEntry { relative_path: p.to_string(), size_bytes: *s, tags: Vec::new(), preloaded_by: None })
+            .collect();
+
+        AnalysisReport {
+            project_name: "Test".to_string(),
+            total_assets: list.len(),
+            startup_assets: list.len(),
+            total_size_bytes: list.iter().map(|a| a.size_bytes).sum(),
+            startup_size_bytes: list.iter().map(|a| a.size_bytes).sum(),
+            startup_asset_list: list,
+            by_type: Default::default(),
+            by_tag: Default::default(),
+            dependency_count: 0,
+            duplicate_count: 0,
+            duplicates: Vec::new(),
+            shader_analysis: None,
+            shared_subtrees: Vec::new(),
+            stale_imports: Vec::new(),
+            content_anomalies: Vec::new(),
+            case_collisions: Vec::new(),
+            dependency_cycles: Vec::new(),
+            data_tables: Vec::new(),
+            string_tables: Vec::new(),
+            ui: Default::default(),
+            late_chunks: Vec::new(),
+            resident_memory: Default::default(),
+            source: None,
+            config_preloads: Vec::new(),
+            hardware: None,
+            parser_coverage: Default::default(),
+            startup_closure_size_bytes: Default::default(),
+            scenarios: Vec::new(),
+            engine_association: None,
+            version_matrix: None,
+            sample: None,
+            estimated_savings_seconds: 0.0,
+            recommendations: Vec::new(),
+            rule_hits: Vec::new(),
+            never_cooked: Vec::new(),
+            language: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_budget_breach_and_offenders() {
+        let base = report(&[("A", 100)]);
+        let current = report(&[("B", 500), ("A", 100)]);
+        let budgets = Budgets {
+            max_startup_bytes: Some(200),
+            ..Default::default()
+        };
+
+        let check = CheckReport::evaluate(&current, Some(&base), &budgets);
+        assert!(!check.passed);
+        assert_eq!(check.baseline.as_ref().unwrap().startup_size_delta_bytes, 500);
+        assert_eq!(check.new_offenders.len(), 1);
+        assert_eq!(check.new_offenders[0].relative_path, "B");
+    }
+
+    #[test]
+    fn test_tag_budget() {
+        let mut current = report(&[("A", 100)]);
+        current.by_tag.insert(
+            "Multiplayer".to_string(),
+            crate::analyzer::TagStats { startup_size_bytes: 300, ..Default::default() },
+        );
+        let mut budgets = Budgets::default();
+        budgets.max_startup_bytes_by_tag.insert("Multiplayer".to_string(), 200);
+        budgets.max_startup_bytes_by_tag.insert("Chapter1".to_string(), 200);
+
+        let check = CheckReport::evaluate(&current, None, &budgets);
+        assert!(!check.passed);
+        let failed: Vec<&str> = check.budgets.iter().filter(|b| !b.passed).map(|b| b.name.as_str()).collect();
+        assert_eq!(failed, ["Startup size [Multiplayer]"]);
+    }
+
+    #[test]
+    fn test_no_budgets_passes() {
+        let current = report(&[("A", 100)]);
+        let check = CheckReport::evaluate(&current, None, &Budgets::default());
+        assert!(check.passed);
+        assert!(check.new_offenders.is_empty());
+    }
+}
@@ -0,0 +1,178 @@
+//! Chunks Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Chunk assignment of startup assets. The cooker writes the chunks the
+//! asset registry assigns each package to `pakchunk<N>.txt` manifests under
+//! Saved/TmpPackaging/<Platform>; anything outside chunk 0 is missing from
+//! the base install and mounts after it.
+
+use crate::scanner::AssetInfo;
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Where the cooker writes chunk manifests, relative to the project root
+const MANIFEST_DIR: &str = "Saved/TmpPackaging";
+
+/// Startup asset assigned to a chunk after 0
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LateChunkAsset {
+    pub relative_path: String,
+    /// Lowest chunk holding the asset
+    pub chunk_id: i32,
+    /// Where the assignment comes from (`pakchunk2.txt`, ...)
+    pub source: String,
+    pub size_bytes: u64,
+}
+
+/// Chunk manifests of the most recently cooked platform
+#[derive(Debug, Clone, Default)]
+pub struct ChunkManifests {
+    pub platform: String,
+    /// Project-relative path -> (lowest chunk, manifest file name)
+    assignments: HashMap<String, (i32, String)>,
+}
+
+impl ChunkManifests {
+    /// Manifests of the newest platform under Saved/TmpPackaging, if the
+    /// project has been cooked
+    pub fn load(project_root: &Path) -> Result<Option<Self>> {
+        let dir = project_root.join(MANIFEST_DIR);
+        if !dir.is_dir() {
+            return Ok(None);
+        }
+        let newest = fs::read_dir(&dir)?
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().is_dir())
+            .max_by_key(|e| e.metadata().and_then(|m| m.modified()).ok());
+        let Some(platform) = newest else {
+            return Ok(None);
+        };
+
+        let mut manifests = Self {
+            platform: platform.file_name().to_string_lossy().to_string(),
+            assignments: HashMap::new(),
+        };
+        for entry in fs::read_dir(platform.path())?.filter_map(|e| e.ok()) {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let Some(chunk_id) = manifest_chunk_id(&name) else {
+                continue;
+            };
+            manifests.add(chunk_id, &name, &fs::read_to_string(entry.path())?);
+        }
+        Ok(Some(manifests))
+    }
+
+    /// Record the packages of one `pakchunk<N>.txt`; an asset listed in
+    /// several chunks keeps the lowest
+    pub fn add(&mut self, chunk_id: i32, source: &str, manifest: &str) {
+        for relative_path in manifest_paths(manifest) {
+            let entry = self.assignments.entry(relative_path).or_insert((chunk_id, source.to_string()));
+            if chunk_id < entry.0 {
+                *entry = (chunk_id, source.to_string());
+            }
+        }
+    }
+
+    pub fn chunk_of(&self, asset: &AssetInfo) -> Option<(i32, String)> {
+        self.assignments.get(&asset.relative_path.replace('\\', "/")).cloned()
+    }
+
+    pub fn len(&self) -> usize {
+        self.assignments.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.assignments.is_empty()
+    }
+}
+
+/// `assets` whose chunk, as `chunk_of` resolves it, is after 0. Assets
+/// without an assignment land in chunk 0.
+pub fn late_chunk_assets(
+    assets: &[AssetInfo],
+    chunk_of: impl Fn(&AssetInfo) -> Option<(i32, String)>,
+) -> Vec<LateChunkAsset> {
+    let mut late: Vec<LateChunkAsset> = assets
+        .iter()
+        .filter_map(|asset| {
+            let (chunk_id, source) = chunk_of(asset)?;
+            (chunk_id > 0).then(|| LateChunkAsset {
+                relative_path: asset.relative_path.clone(),
+                chunk_id,
+                source,
+                size_bytes: asset.size_bytes,
+            })
+        })
+        .collect();
+    late.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+    late.dedup_by(|a, b| a.relative_path == b.relative_path);
+    late
+}
+
+/// Chunk of a `pakchunk<N>.txt` or `pakchunk<N>-<suffix>.txt` manifest
+fn manifest_chunk_id(file_name: &str) -> Option<i32> {
+    let rest = file_name.strip_prefix("pakchunk")?.strip_suffix(".txt")?;
+    let digits = rest.split('-').next()?;
+    digits.parse().ok()
+}
+
+/// Project-relative paths of a manifest's lines,
+/// `"<cooked file>" "../../../MyGame/Content/Maps/Entry.umap" -compress`.
+/// Engine content is skipped.
+fn manifest_paths(manifest: &str) -> impl Iterator<Item = String> + '_ {
+    manifest.lines().filter_map(|line| {
+        let mount = line.split('"').nth(3)?;
+        let (root, relative) = mount.trim_start_matches("../").split_once('/')?;
+        (!root.eq_ignore_ascii_case("Engine")).then(|| relative.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetType;
+    use std::path::PathBuf;
+
+    fn asset(relative_path: &str) -> AssetInfo {
+        AssetInfo {
+            path: PathBuf::from("/p").join(relative_path),
+            relative_path: relative_path.to_string(),
+            asset_type: AssetType::UAsset,
+            size_bytes: 10,
+            modified: 0,
+            mount_point: "/Game".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_late_chunk_assets() {
+        assert_eq!(manifest_chunk_id("pakchunk0.txt"), Some(0));
+        assert_eq!(manifest_chunk_id("pakchunk12-optional.txt"), Some(12));
+        assert_eq!(manifest_chunk_id("pakchunklist.txt"), None);
+
+        let mut manifests = ChunkManifests::default();
+        manifests.add(
+            0,
+            "pakchunk0.txt",
+            "\"D:/MyGame/Saved/Cooked/Windows/MyGame/Content/Maps/Entry.umap\" \"../../../MyGame/Content/Maps/Entry.umap\" -compress\n\
+             \"D:/MyGame/Saved/Cooked/Windows/Engine/Content/EngineFonts/Roboto.uasset\" \"../../../Engine/Content/EngineFonts/Roboto.uasset\"\n",
+        );
+        manifests.add(
+            2,
+            "pakchunk2.txt",
+            "\"D:/MyGame/Saved/Cooked/Windows/MyGame/Content/UI/Boot.uasset\" \"../../../MyGame/Content/UI/Boot.uasset\"\n\
+             \"D:/MyGame/Saved/Cooked/Windows/MyGame/Content/Maps/Entry.umap\" \"../../../MyGame/Content/Maps/Entry.umap\"\n",
+        );
+        assert_eq!(manifests.len(), 2);
+
+        let assets = vec![asset("Content/Maps/Entry.umap"), asset("Content/UI/Boot.uasset"), asset("Content/UI/Font.uasset")];
+        let late = late_chunk_assets(&assets, |a| manifests.chunk_of(a));
+        assert_eq!(late.len(), 1);
+        assert_eq!((late[0].relative_path.as_str(), late[0].chunk_id), ("Content/UI/Boot.uasset", 2));
+        assert_eq!(late[0].source, "pakchunk2.txt");
+    }
+}
@@ -0,0 +1,286 @@
+//! Config Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Project-level configuration loaded from `.uefast.toml`
+
+use crate::check::Budgets;
+use crate::cook::CookTimeModel;
+use crate::estimator::{LoadTimeModel, StorageTier};
+use crate::hardware::HardwareProfile;
+use crate::hash::HashAlgorithm;
+use crate::i18n::Locale;
+use crate::load_sim::{IoOverrides, OrderStrategy};
+use crate::rule_packs::RulePackSource;
+use crate::scanner::StartupHeuristics;
+use crate::scenarios::ScenarioConfig;
+use crate::{FastStartupError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+pub const CONFIG_FILE_NAME: &str = ".uefast.toml";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectConfig {
+    pub notify: Option<NotifyConfig>,
+    /// Storage tier of the project drive, overriding detection
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage: Option<StorageTier>,
+    /// Language of reports and recommendations (`en`, `es`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<Locale>,
+    /// Worker threads when `--threads` is not given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threads: Option<usize>,
+    /// Content hash `cache` uses when `--hash-algorithm` is not given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash_algorithm: Option<HashAlgorithm>,
+    /// Globs over project-relative paths every scan skips, e.g.
+    /// `Content/Developers/`; `--exclude` adds to them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+    /// Which assets count as startup-critical before the graph is built
+    #[serde(default, skip_serializing_if = "StartupHeuristics::is_default")]
+    pub startup: StartupHeuristics,
+    /// Default output files, relative to the project root
+    #[serde(default, skip_serializing_if = "OutputPaths::is_empty")]
+    pub output: OutputPaths,
+    /// Budgets `check` enforces unless overridden on the command line
+    #[serde(default, skip_serializing_if = "Budgets::is_empty")]
+    pub budgets: Budgets,
+    /// Calibrated load time models keyed by storage tier (`calibrate`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub estimator: BTreeMap<StorageTier, LoadTimeModel>,
+    /// Cook time model coefficients for `predict-cook`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cook: Option<CookTimeModel>,
+    /// I/O model figures for `simulate`, replacing the storage tier's
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub io: Option<IoOverrides>,
+    /// How `cache` arranges the load order; `compare-strategies` picks one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub load_order_strategy: Option<OrderStrategy>,
+    /// Named startup sets analyzed and cached side by side
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub scenarios: BTreeMap<String, ScenarioConfig>,
+    /// External rule packs evaluated on every analysis
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rule_packs: Vec<RulePackSource>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NotifyConfig {
+    /// Slack or Teams incoming webhook URL
+    pub webhook_url: String,
+    /// Message template, see `webhook::render_template` for placeholders
+    pub template: Option<String>,
+    /// Post when startup size grows by at least this much (MB)
+    #[serde(default = "default_regression_threshold_mb")]
+    pub regression_threshold_mb: f64,
+    /// Post after every run, not only on breaches/regressions
+    #[serde(default)]
+    pub always: bool,
+}
+
+fn default_regression_threshold_mb() -> f64 {
+    10.0
+}
+
+/// `[output]` table; `--output` wins over both
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OutputPaths {
+    /// Cache file `cache` writes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache: Option<PathBuf>,
+    /// Report file `analyze` writes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub report: Option<PathBuf>,
+}
+
+impl OutputPaths {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn cache_path(&self, project_root: &Path) -> Option<PathBuf> {
+        self.cache.as_ref().map(|path| project_root.join(path))
+    }
+
+    pub fn report_path(&self, project_root: &Path) -> Option<PathBuf> {
+        self.report.as_ref().map(|path| project_root.join(path))
+    }
+}
+
+impl ProjectConfig {
+    pub fn path_for(project_root: &Path) -> PathBuf {
+        project_root.join(CONFIG_FILE_NAME)
+    }
+
+    /// Load `.uefast.toml` from the project root, or defaults if absent
+    pub fn load(project_root: &Path) -> Result<Self> {
+        let path = Self::path_for(project_root);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let text = std::fs::read_to_string(&path)?;
+        Self::parse(&text)
+            .map_err(|e| FastStartupError::ConfigError(format!("{}: {}", path.display(), e)))
+    }
+
+    pub fn parse(text: &str) -> std::result::Result<Self, toml::de::Error> {
+        toml::from_str(text)
+    }
+
+    /// Write the config back to the project root. Comments in an existing
+    /// file are not preserved.
+    pub fn save(&self, project_root: &Path) -> Result<PathBuf> {
+        let path = Self::path_for(project_root);
+        let text = toml::to_string_pretty(self)
+            .map_err(|e| FastStartupError::ConfigError(e.to_string()))?;
+        std::fs::write(&path, text)?;
+        Ok(path)
+    }
+
+    /// Model calibrated for `tier`. Without a tier, the single calibrated
+    /// model is used if there is exactly one; otherwise the defaults.
+    pub fn load_time_model(&self, tier: Option<StorageTier>) -> LoadTimeModel {
+        let model = match tier {
+            Some(tier) => self.estimator.get(&tier),
+            None if self.estimator.len() == 1 => self.estimator.values().next(),
+            None => None,
+        };
+        model.cloned().unwrap_or_default()
+    }
+
+    /// Profile of this machine with the configured storage tier, if any
+    pub fn hardware_profile(&self, project_root: &Path) -> HardwareProfile {
+        let mut profile = HardwareProfile::detect(project_root);
+        if let Some(storage) = self.storage {
+            profile.storage = storage;
+        }
+        profile
+    }
+
+    /// `requested` (e.g. `--lang`), else the configured language, else the
+    /// environment's
+    pub fn locale(&self, requested: Option<Locale>) -> Locale {
+        requested.or(self.language).unwrap_or_else(Locale::detect)
+    }
+
+    /// Calibrated model for the profile's storage tier, falling back to the
+    /// profile's uncalibrated defaults. An undetected tier uses the single
+    /// calibrated model if there is exactly one.
+    pub fn model_for_profile(&self, profile: &HardwareProfile) -> LoadTimeModel {
+        let tier = match profile.storage {
+            StorageTier::Unknown => None,
+            tier => Some(tier),
+        };
+        let model = self.load_time_model(tier);
+        if model.is_calibrated() {
+            model
+        } else {
+            LoadTimeModel::for_profile(profile)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_notify() {
+        let config = ProjectConfig::parse(
+            r#"
+            [notify]
+            webhook_url = "https://hooks.example.com/abc"
+            "#,
+        )
+        .unwrap();
+
+        let notify = config.notify.unwrap();
+        assert_eq!(notify.webhook_url, "https://hooks.example.com/abc");
+        assert_eq!(notify.regression_threshold_mb, 10.0);
+        assert!(!notify.always);
+    }
+
+    #[test]
+    fn test_estimator_roundtrip() {
+        let mut config = ProjectConfig::default();
+        let mut model = LoadTimeModel::default();
+        model.ms_per_mb.insert("uasset".to_string(), 4.5);
+        model.observations = 2;
+        config.estimator.insert(StorageTier::Nvme, model.clone());
+
+        let text = toml::to_string_pretty(&config).unwrap();
+        let parsed = ProjectConfig::parse(&text).unwrap();
+        assert_eq!(parsed.load_time_model(Some(StorageTier::Nvme)), model);
+        assert_eq!(parsed.load_time_model(None), model);
+        assert!(!parsed.load_time_model(Some(StorageTier::Hdd)).is_calibrated());
+    }
+
+    #[test]
+    fn test_parse_scenarios() {
+        let config = ProjectConfig::parse(
+            r#"
+            [scenarios.FrontendOnly]
+            roots = ["/Game/Maps/Frontend"]
+            exclude = ["/Game/Gameplay/"]
+
+            [scenarios.DediServer]
+            roots = ["/Game/Maps/MP_*"]
+            root_tags = ["Multiplayer"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.scenarios.len(), 2);
+        assert_eq!(config.scenarios["FrontendOnly"].exclude, ["/Game/Gameplay/"]);
+        assert_eq!(config.scenarios["DediServer"].root_tags, ["Multiplayer"]);
+    }
+
+    #[test]
+    fn test_parse_defaults() {
+        let config = ProjectConfig::parse(
+            r#"
+            threads = 6
+            hash_algorithm = "blake3"
+            exclude = ["Content/Developers/", "**/*_Test.uasset"]
+
+            [startup]
+            paths = ["Content/Frontend/**"]
+
+            [output]
+            cache = "Saved/UEFast/Game.uefast"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.threads, Some(6));
+        assert_eq!(config.hash_algorithm, Some(HashAlgorithm::Blake3));
+        assert_eq!(config.exclude.len(), 2);
+        assert_eq!(config.startup.paths, ["Content/Frontend/**"]);
+        assert_eq!(config.startup.keywords, StartupHeuristics::default().keywords);
+        assert_eq!(
+            config.output.cache_path(Path::new("/p")),
+            Some(Path::new("/p").join("Saved/UEFast/Game.uefast"))
+        );
+        assert_eq!(config.output.report_path(Path::new("/p")), None);
+
+        let text = toml::to_string_pretty(&config).unwrap();
+        assert_eq!(ProjectConfig::parse(&text).unwrap().output, config.output);
+        assert!(ProjectConfig::parse("[output]\nlog = \"x\"").is_err());
+    }
+
+    #[test]
+    fn test_empty_config() {
+        let config = ProjectConfig::parse("").unwrap();
+        assert!(config.notify.is_none());
+        assert!(config.startup.is_default() && config.output.is_empty());
+    }
+}
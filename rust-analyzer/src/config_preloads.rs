@@ -0,0 +1,248 @@
+//! Config Preloads Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Assets the project's `Config/Default*.ini` files force to load at boot
+//! (`+AssetsToAlwaysLoad`, `+PreloadedPaths`, default maps and classes),
+//! each attributed to the config line that caused it
+
+use crate::scanner::{package_name_from_relative, AssetInfo};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Config keys whose `/Game` values are loaded at startup
+pub const PRELOAD_KEYS: &[&str] = &[
+    "AssetsToAlwaysLoad",
+    "PreloadedPaths",
+    "GameDefaultMap",
+    "TransitionMap",
+    "GlobalDefaultGameMode",
+    "GameInstanceClass",
+    "GameUserSettingsClassName",
+    "DefaultTouchInterface",
+];
+
+/// Array keys accumulate with `+`; the others hold a single value
+const ARRAY_KEYS: &[&str] = &["AssetsToAlwaysLoad", "PreloadedPaths"];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigPreload {
+    pub key: String,
+    /// `/Game` package or folder as written, without the object name
+    pub path: String,
+    /// `file:line`, project-relative
+    pub source: String,
+    /// Project-relative assets it resolves to; a folder resolves to every
+    /// asset below it
+    #[serde(default)]
+    pub assets: Vec<String>,
+}
+
+impl ConfigPreload {
+    /// e.g. `Config/DefaultEngine.ini:42 (AssetsToAlwaysLoad)`
+    pub fn attribution(&self) -> String {
+        format!("{} ({})", self.source, self.key)
+    }
+}
+
+/// Preload entries of `Config/Default*.ini`, in file and line order
+pub fn load(project_root: &Path) -> Vec<ConfigPreload> {
+    let Ok(entries) = std::fs::read_dir(project_root.join("Config")) else {
+        return Vec::new();
+    };
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e.eq_ignore_ascii_case("ini")))
+        .filter(|p| p.file_name().and_then(|n| n.to_str()).is_some_and(|n| n.starts_with("Default")))
+        .collect();
+    files.sort();
+
+    let mut preloads = Vec::new();
+    for file in files {
+        if let Ok(text) = std::fs::read_to_string(&file) {
+            let relative = file.strip_prefix(project_root).unwrap_or(&file).to_string_lossy().replace('\\', "/");
+            apply(&mut preloads, &relative, &text);
+        }
+    }
+    preloads
+}
+
+/// Apply one ini file's preload lines: `+Key=` and `Key=` add (plain keys
+/// replace their previous value), `-Key=` removes, `!Key=` clears
+pub fn apply(preloads: &mut Vec<ConfigPreload>, file: &str, text: &str) {
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.starts_with([';', '#', '[']) {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let (op, key) = match key.trim().chars().next() {
+            Some(op @ ('+' | '-' | '!' | '.')) => (op, key.trim()[1..].trim()),
+            _ => (' ', key.trim()),
+        };
+        if !PRELOAD_KEYS.contains(&key) {
+            continue;
+        }
+
+        let paths = game_paths(value);
+        match op {
+            '!' => preloads.retain(|p| p.key != key),
+            '-' => preloads.retain(|p| p.key != key || !paths.contains(&p.path)),
+            _ => {
+                if op == ' ' && !ARRAY_KEYS.contains(&key) {
+                    preloads.retain(|p| p.key != key);
+                }
+                for path in paths {
+                    if !preloads.iter().any(|p| p.key == key && p.path == path) {
+                        preloads.push(ConfigPreload {
+                            key: key.to_string(),
+                            path,
+                            source: format!("{}:{}", file, index + 1),
+                            assets: Vec::new(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// `/Game` packages and folders in an ini value, which may be a bare path,
+/// a quoted object path or a struct like `(Path="/Game/UI/Boot.Boot")`
+fn game_paths(value: &str) -> Vec<String> {
+    let mut paths = Vec::new();
+    let mut rest = value;
+    while let Some(start) = rest.find("/Game/") {
+        let tail = &rest[start..];
+        let end = tail.find(['"', '\'', ',', ')', ' ', '\t']).unwrap_or(tail.len());
+        let path = &tail[..end];
+        // `/Game/Dir/Asset.Asset` and `.Asset_C` name an object in a package
+        let package = match path.rsplit_once('/') {
+            Some((dir, name)) => match name.split_once('.') {
+                Some((name, _)) => format!("{}/{}", dir, name),
+                None => path.to_string(),
+            },
+            None => path.to_string(),
+        };
+        paths.push(package);
+        rest = &tail[end..];
+    }
+    paths
+}
+
+/// Fill in the assets each entry resolves to
+pub fn resolve(preloads: &mut [ConfigPreload], assets: &[AssetInfo]) {
+    let mut packages: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+    for asset in assets {
+        if let Some(package) = package_name_from_relative(&asset.relative_path) {
+            packages.entry(package).or_default().push(&asset.relative_path);
+        }
+    }
+
+    for preload in preloads {
+        let exact = packages.get(preload.path.trim_end_matches('/'));
+        preload.assets = match exact {
+            Some(paths) => paths.iter().map(|p| p.to_string()).collect(),
+            None => {
+                let folder = format!("{}/", preload.path.trim_end_matches('/'));
+                packages
+                    .range(folder.clone()..)
+                    .take_while(|(package, _)| package.starts_with(&folder))
+                    .flat_map(|(_, paths)| paths.iter().map(|p| p.to_string()))
+                    .collect()
+            }
+        };
+    }
+}
+
+/// Startup roots forced by config, with the first entry that caused each
+pub fn roots(preloads: &[ConfigPreload]) -> HashMap<&str, &ConfigPreload> {
+    let mut roots = HashMap::new();
+    for preload in preloads {
+        for asset in &preload.assets {
+            roots.entry(asset.as_str()).or_insert(preload);
+        }
+    }
+    roots
+}
+
+/// Absolute paths of the config startup roots among `assets`
+pub fn root_paths(preloads: &[ConfigPreload], assets: &[AssetInfo]) -> HashSet<PathBuf> {
+    let roots = roots(preloads);
+    assets
+        .iter()
+        .filter(|a| roots.contains_key(a.relative_path.as_str()))
+        .map(|a| a.path.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetType;
+
+    fn asset(relative_path: &str) -> AssetInfo {
+        AssetInfo {
+            path: PathBuf::from("/p").join(relative_path),
+            relative_path: relative_path.to_string(),
+            asset_type: AssetType::UAsset,
+            size_bytes: 10,
+            modified: 0,
+            mount_point: "/Game".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_apply_ini() {
+        let engine = "[/Script/EngineSettings.GameMapsSettings]\n\
+                      GameDefaultMap=/Game/Maps/Old.Old\n\
+                      GameDefaultMap=/Game/Maps/Entry.Entry\n\
+                      GameInstanceClass=/Script/Engine.GameInstance\n\
+                      [/Script/Engine.AssetManagerSettings]\n\
+                      +AssetsToAlwaysLoad=(Path=\"/Game/UI/Boot.Boot_C\")\n\
+                      +AssetsToAlwaysLoad=/Game/Data/Removed\n\
+                      ; +AssetsToAlwaysLoad=/Game/Commented\n\
+                      +PreloadedPaths=/Game/Core/\n";
+        let game = "-AssetsToAlwaysLoad=/Game/Data/Removed\n";
+
+        let mut preloads = Vec::new();
+        apply(&mut preloads, "Config/DefaultEngine.ini", engine);
+        apply(&mut preloads, "Config/DefaultGame.ini", game);
+
+        let paths: Vec<(&str, &str)> = preloads.iter().map(|p| (p.key.as_str(), p.path.as_str())).collect();
+        assert_eq!(
+            paths,
+            [
+                ("GameDefaultMap", "/Game/Maps/Entry"),
+                ("AssetsToAlwaysLoad", "/Game/UI/Boot"),
+                ("PreloadedPaths", "/Game/Core/"),
+            ]
+        );
+        assert_eq!(preloads[0].attribution(), "Config/DefaultEngine.ini:3 (GameDefaultMap)");
+
+        apply(&mut preloads, "Config/DefaultGame.ini", "!PreloadedPaths=ClearArray\n");
+        assert_eq!(preloads.len(), 2);
+    }
+
+    #[test]
+    fn test_resolve_packages_and_folders() {
+        let assets = [
+            asset("Content/Maps/Entry.umap"),
+            asset("Content/Core/Fonts/Main.uasset"),
+            asset("Content/Core/Rules.uasset"),
+            asset("Content/CoreExtra/Skip.uasset"),
+        ];
+        let mut preloads = Vec::new();
+        apply(&mut preloads, "Config/DefaultEngine.ini", "GameDefaultMap=/Game/Maps/Entry.Entry\n+PreloadedPaths=/Game/Core\n");
+        resolve(&mut preloads, &assets);
+
+        assert_eq!(preloads[0].assets, ["Content/Maps/Entry.umap"]);
+        assert_eq!(preloads[1].assets, ["Content/Core/Fonts/Main.uasset", "Content/Core/Rules.uasset"]);
+        assert_eq!(root_paths(&preloads, &assets).len(), 3);
+        assert_eq!(roots(&preloads)["Content/Core/Rules.uasset"].key, "PreloadedPaths");
+    }
+}
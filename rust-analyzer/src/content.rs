@@ -0,0 +1,623 @@
+//! Content Provider Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Where project content is listed and read from. The scanner and the cache
+//! hasher go through a `ContentProvider`, so loose files, packaged .pak
+//! containers and remote listings are scanned the same way.
+
+use crate::hash::{hash_bytes_with, hash_file_with, ContentHash, HashAlgorithm};
+use crate::iostore::{find_toc_files, TocEntry, TocFile};
+use crate::pak::{content_relative_path, find_pak_files, PakFile};
+use crate::parallelism::{adaptive_filter_map, AdaptiveLimiter};
+use crate::project::content_roots;
+use crate::scanner::AssetInfo;
+use crate::{FastStartupError, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tracing::{debug, info};
+use walkdir::WalkDir;
+
+/// Listing fetched from the base URL of an HTTP provider
+pub const HTTP_MANIFEST: &str = "manifest.json";
+
+/// One file of project content
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContentEntry {
+    /// Project-relative path with `/` separators, e.g. `Content/Maps/Entry.umap`
+    pub relative_path: String,
+    pub size_bytes: u64,
+    /// Unix seconds
+    #[serde(default)]
+    pub modified: u64,
+    /// Where the file is on local disk, when it is a loose file
+    #[serde(skip)]
+    pub local_path: Option<PathBuf>,
+}
+
+/// Source of project content
+pub trait ContentProvider: Send + Sync {
+    /// Human-readable location, for logs
+    fn describe(&self) -> String;
+
+    /// Every file whose relative path `accept` keeps. `accept` runs before
+    /// any per-file metadata is fetched.
+    fn list(&self, accept: &(dyn Fn(&str) -> bool + Sync)) -> Result<Vec<ContentEntry>>;
+
+    /// Full contents of `relative_path`
+    fn read(&self, relative_path: &str) -> Result<Vec<u8>>;
+
+    fn hash(&self, asset: &AssetInfo, algorithm: HashAlgorithm) -> Result<ContentHash> {
+        self.read(&asset.relative_path).map(|data| hash_bytes_with(&data, algorithm))
+    }
+}
+
+/// `local`, `dir:<root with a Content folder>`, `pak:<file or directory>`,
+/// `iostore:<.utoc or directory>`, an `http(s)://` base URL, or a bare path
+/// to a content root or a packaged build's containers
+pub fn open_provider(spec: &str, project_root: &Path) -> Result<Arc<dyn ContentProvider>> {
+    let provider: Arc<dyn ContentProvider> = if spec.eq_ignore_ascii_case("local") {
+        Arc::new(LocalContent::new(project_root))
+    } else if let Some(root) = spec.strip_prefix("dir:") {
+        let root = project_root.join(root);
+        if !root.join("Content").is_dir() {
+            return Err(FastStartupError::InvalidArgument(format!(
+                "{} has no Content directory",
+                root.display()
+            )));
+        }
+        Arc::new(LocalContent::new(&root))
+    } else if let Some(path) = spec.strip_prefix("pak:") {
+        Arc::new(PakContent::open(Path::new(path))?)
+    } else if let Some(path) = spec.strip_prefix("iostore:") {
+        Arc::new(IoStoreContent::open(Path::new(path))?)
+    } else if spec.starts_with("http://") || spec.starts_with("https://") {
+        Arc::new(HttpContent::new(spec))
+    } else if project_root.join(spec).exists() {
+        // Bare path: a root with a Content folder, else .pak files with
+        // the IoStore containers of UE5 builds layered over them
+        let path = project_root.join(spec);
+        if path.join("Content").is_dir() {
+            Arc::new(LocalContent::new(&path))
+        } else if path.is_dir() && !find_toc_files(&path).is_empty() {
+            let iostore: Arc<dyn ContentProvider> = Arc::new(IoStoreContent::open(&path)?);
+            if find_pak_files(&path).is_empty() {
+                iostore
+            } else {
+                Arc::new(LayeredContent::new(vec![Arc::new(PakContent::open(&path)?), iostore]))
+            }
+        } else {
+            Arc::new(PakContent::open(&path)?)
+        }
+    } else {
+        return Err(FastStartupError::InvalidArgument(format!(
+            "Unknown content source '{}' (expected local, dir:<path>, pak:<path>, iostore:<path>, \
+             an http(s) URL or an existing path)",
+            spec
+        )));
+    };
+    info!("Content source: {}", provider.describe());
+    Ok(provider)
+}
+
+/// One provider per spec, lowest priority first; several specs are layered
+/// so later ones (patches, DLC) override earlier ones (the base game)
+pub fn open_layers(specs: &[String], project_root: &Path) -> Result<Arc<dyn ContentProvider>> {
+    let mut layers = specs
+        .iter()
+        .map(|spec| open_provider(spec, project_root))
+        .collect::<Result<Vec<_>>>()?;
+    match layers.len() {
+        0 => Ok(Arc::new(LocalContent::new(project_root))),
+        1 => Ok(layers.remove(0)),
+        _ => Ok(Arc::new(LayeredContent::new(layers))),
+    }
+}
+
+/// Loose files in the project's `Content` directory and the `Content`
+/// directories of its enabled plugins
+pub struct LocalContent {
+    project_root: PathBuf,
+    content_dirs: Vec<PathBuf>,
+}
+
+impl LocalContent {
+    pub fn new(project_root: &Path) -> Self {
+        Self {
+            project_root: project_root.to_path_buf(),
+            content_dirs: content_roots(project_root)
+                .into_iter()
+                .map(|root| project_root.join(root.relative_dir))
+                .collect(),
+        }
+    }
+}
+
+impl ContentProvider for LocalContent {
+    fn describe(&self) -> String {
+        match self.content_dirs.len() {
+            1 => self.content_dirs[0].display().to_string(),
+            n => format!("{} (+{} plugin content roots)", self.content_dirs[0].display(), n - 1),
+        }
+    }
+
+    fn list(&self, accept: &(dyn Fn(&str) -> bool + Sync)) -> Result<Vec<ContentEntry>> {
+        let entries: Vec<_> = self
+            .content_dirs
+            .iter()
+            .flat_map(|dir| {
+                WalkDir::new(dir)
+                    .follow_links(true)
+                    .max_open(128) // Increase file descriptor limit for speed
+                    .into_iter()
+            })
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            .filter_map(|e| {
+                let relative = e.path().strip_prefix(&self.project_root).ok()?.to_string_lossy().to_string();
+                accept(&relative).then_some((e, relative))
+            })
+            .collect();
+        debug!("Found {} files, processing with {} threads...", entries.len(), rayon::current_num_threads());
+
+        // In-flight stat calls tuned to the storage's observed throughput
+        let limiter = AdaptiveLimiter::for_current_pool();
+        let listed = adaptive_filter_map(&entries, &limiter, |_| 0, |(entry, relative_path)| {
+            let metadata = entry.metadata().ok()?;
+            let modified = metadata.modified().ok()?
+                .duration_since(std::time::UNIX_EPOCH).ok()?
+                .as_secs();
+            Some(ContentEntry {
+                relative_path: relative_path.clone(),
+                size_bytes: metadata.len(),
+                modified,
+                local_path: Some(entry.path().to_path_buf()),
+            })
+        });
+        debug!("Listing finished with {} files in flight", limiter.limit());
+        Ok(listed)
+    }
+
+    fn read(&self, relative_path: &str) -> Result<Vec<u8>> {
+        Ok(std::fs::read(self.project_root.join(relative_path))?)
+    }
+
+    /// Same strategy as direct file hashing, so caches stay comparable
+    fn hash(&self, asset: &AssetInfo, algorithm: HashAlgorithm) -> Result<ContentHash> {
+        hash_file_with(&self.project_root.join(&asset.relative_path), algorithm)
+    }
+}
+
+/// Content packaged into .pak containers. Entries are read as stored, so
+/// compressed entries hash by their compressed bytes.
+pub struct PakContent {
+    paks: Vec<PakFile>,
+    /// Relative path -> (pak, offset, size); earlier containers win
+    index: HashMap<String, (usize, u64, u64)>,
+    source: PathBuf,
+}
+
+impl PakContent {
+    /// A single .pak, or every .pak below a packaged build directory. Patch
+    /// containers come first, like the engine mounts them.
+    pub fn open(path: &Path) -> Result<Self> {
+        let mut pak_paths = if path.is_dir() { find_pak_files(path) } else { vec![path.to_path_buf()] };
+        pak_paths.sort_by_key(|p| std::cmp::Reverse(patch_level(p)));
+        if pak_paths.is_empty() {
+            return Err(FastStartupError::AssetError(format!("No .pak files in {}", path.display())));
+        }
+        let paks = pak_paths.iter().map(|p| PakFile::open(p)).collect::<Result<Vec<_>>>()?;
+        Ok(Self::from_paks(paks, path))
+    }
+
+    /// Index the entries of `paks`, given in mount order
+    fn from_paks(paks: Vec<PakFile>, source: &Path) -> Self {
+        let mut index = HashMap::new();
+        for (pak_idx, pak) in paks.iter().enumerate() {
+            for entry in &pak.entries {
+                if let Some(relative_path) = entry_relative_path(pak, &entry.path) {
+                    index.entry(relative_path).or_insert((pak_idx, entry.offset, entry.size));
+                }
+            }
+        }
+        Self {
+            paks,
+            index,
+            source: source.to_path_buf(),
+        }
+    }
+
+    /// Pak and entry holding `relative_path`
+    fn locate(&self, relative_path: &str) -> Option<(&PakFile, u64, u64)> {
+        let &(pak_idx, offset, size) = self.index.get(relative_path)?;
+        Some((&self.paks[pak_idx], offset, size))
+    }
+}
+
+/// `pakchunk0-Windows_P.pak` is patch level 0, `..._2_P.pak` level 2;
+/// `None` for base containers
+pub fn patch_level(pak_path: &Path) -> Option<u32> {
+    let stem = pak_path.file_stem()?.to_str()?;
+    let rest = stem.strip_suffix("_P").or_else(|| stem.strip_suffix("_p"))?;
+    Some(
+        rest.rsplit_once('_')
+            .and_then(|(_, level)| level.parse().ok())
+            .unwrap_or(0),
+    )
+}
+
+fn entry_relative_path(pak: &PakFile, entry_path: &str) -> Option<String> {
+    mounted_relative_path(&pak.mount_point, entry_path)
+}
+
+fn mounted_relative_path(mount_point: &str, entry_path: &str) -> Option<String> {
+    content_relative_path(&format!("{}{}", mount_point.trim_start_matches("../"), entry_path))
+}
+
+fn modified_secs(path: &Path) -> u64 {
+    std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+impl ContentProvider for PakContent {
+    fn describe(&self) -> String {
+        format!("{} ({} pak files)", self.source.display(), self.paks.len())
+    }
+
+    fn list(&self, accept: &(dyn Fn(&str) -> bool + Sync)) -> Result<Vec<ContentEntry>> {
+        let mut seen = std::collections::HashSet::new();
+        let mut listed = Vec::new();
+        for pak in &self.paks {
+            let modified = modified_secs(&pak.path);
+            for entry in &pak.entries {
+                let Some(relative_path) = entry_relative_path(pak, &entry.path) else {
+                    continue;
+                };
+                if accept(&relative_path) && seen.insert(relative_path.clone()) {
+                    listed.push(ContentEntry {
+                        relative_path,
+                        size_bytes: entry.size,
+                        modified,
+                        local_path: None,
+                    });
+                }
+            }
+        }
+        Ok(listed)
+    }
+
+    fn read(&self, relative_path: &str) -> Result<Vec<u8>> {
+        let (pak, offset, size) = self.locate(relative_path).ok_or_else(|| {
+            FastStartupError::AssetError(format!("{} is not in {}", relative_path, self.source.display()))
+        })?;
+        let mut file = File::open(&pak.path)?;
+        file.seek(SeekFrom::Start(offset))?;
+        let mut data = vec![0u8; size as usize];
+        file.read_exact(&mut data)?;
+        Ok(data)
+    }
+}
+
+/// Packages in the IoStore containers of a UE5 build. Reads decode the
+/// .ucas blocks, so entries hash by their uncompressed bytes.
+pub struct IoStoreContent {
+    tocs: Vec<TocFile>,
+    /// Relative path -> (container, entry); earlier containers win
+    index: HashMap<String, (usize, usize)>,
+    source: PathBuf,
+}
+
+impl IoStoreContent {
+    /// A single .utoc, or every .utoc below a packaged build directory.
+    /// Patch containers come first, like the engine mounts them.
+    pub fn open(path: &Path) -> Result<Self> {
+        let mut toc_paths = if path.is_dir() { find_toc_files(path) } else { vec![path.to_path_buf()] };
+        toc_paths.sort_by_key(|p| std::cmp::Reverse(patch_level(p)));
+        if toc_paths.is_empty() {
+            return Err(FastStartupError::AssetError(format!("No .utoc files in {}", path.display())));
+        }
+        let tocs = toc_paths.iter().map(|p| TocFile::open(p)).collect::<Result<Vec<_>>>()?;
+        let mut index = HashMap::new();
+        for (toc_idx, toc) in tocs.iter().enumerate() {
+            for (entry_idx, entry) in toc.entries.iter().enumerate() {
+                if let Some(relative_path) = mounted_relative_path(&toc.mount_point, &entry.path) {
+                    index.entry(relative_path).or_insert((toc_idx, entry_idx));
+                }
+            }
+        }
+        Ok(Self {
+            tocs,
+            index,
+            source: path.to_path_buf(),
+        })
+    }
+
+    /// Container and chunk holding `relative_path`
+    fn locate(&self, relative_path: &str) -> Option<(&TocFile, &TocEntry)> {
+        let &(toc_idx, entry_idx) = self.index.get(relative_path)?;
+        let toc = &self.tocs[toc_idx];
+        Some((toc, &toc.entries[entry_idx]))
+    }
+}
+
+impl ContentProvider for IoStoreContent {
+    fn describe(&self) -> String {
+        format!("{} ({} IoStore containers)", self.source.display(), self.tocs.len())
+    }
+
+    fn list(&self, accept: &(dyn Fn(&str) -> bool + Sync)) -> Result<Vec<ContentEntry>> {
+        let mut seen = std::collections::HashSet::new();
+        let mut listed = Vec::new();
+        for toc in &self.tocs {
+            let modified = modified_secs(&toc.path);
+            for entry in &toc.entries {
+                let Some(relative_path) = mounted_relative_path(&toc.mount_point, &entry.path) else {
+                    continue;
+                };
+                if accept(&relative_path) && seen.insert(relative_path.clone()) {
+                    listed.push(ContentEntry {
+                        relative_path,
+                        size_bytes: entry.length,
+                        modified,
+                        local_path: None,
+                    });
+                }
+            }
+        }
+        Ok(listed)
+    }
+
+    fn read(&self, relative_path: &str) -> Result<Vec<u8>> {
+        let (toc, entry) = self.locate(relative_path).ok_or_else(|| {
+            FastStartupError::AssetError(format!("{} is not in {}", relative_path, self.source.display()))
+        })?;
+        toc.read_entry(entry)
+    }
+}
+
+/// Remote content: `<base>/manifest.json` lists `ContentEntry` records and
+/// `<base>/<relative_path>` serves each file
+pub struct HttpContent {
+    base_url: String,
+}
+
+impl HttpContent {
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    fn url(&self, relative_path: &str) -> String {
+        format!("{}/{}", self.base_url, relative_path.trim_start_matches('/'))
+    }
+}
+
+impl ContentProvider for HttpContent {
+    fn describe(&self) -> String {
+        self.base_url.clone()
+    }
+
+    fn list(&self, accept: &(dyn Fn(&str) -> bool + Sync)) -> Result<Vec<ContentEntry>> {
+        let url = self.url(HTTP_MANIFEST);
+        let entries: Vec<ContentEntry> = ureq::get(&url)
+            .call()
+            .map_err(|e| FastStartupError::AssetError(e.to_string()))?
+            .into_json()
+            .map_err(|e| FastStartupError::SerializationError(format!("{}: {}", url, e)))?;
+        Ok(entries
+            .into_iter()
+            .map(|e| ContentEntry {
+                relative_path: e.relative_path.replace('\\', "/"),
+                local_path: None,
+                ..e
+            })
+            .filter(|e| accept(&e.relative_path))
+            .collect())
+    }
+
+    fn read(&self, relative_path: &str) -> Result<Vec<u8>> {
+        let url = self.url(relative_path);
+        let mut data = Vec::new();
+        ureq::get(&url)
+            .call()
+            .map_err(|e| FastStartupError::AssetError(e.to_string()))?
+            .into_reader()
+            .read_to_end(&mut data)?;
+        Ok(data)
+    }
+}
+
+/// Providers stacked base first; a file in a later layer replaces the file
+/// with the same relative path in every earlier layer
+pub struct LayeredContent {
+    layers: Vec<Arc<dyn ContentProvider>>,
+    /// Layer serving each path, from the last listing
+    owners: Mutex<HashMap<String, usize>>,
+}
+
+impl LayeredContent {
+    pub fn new(layers: Vec<Arc<dyn ContentProvider>>) -> Self {
+        Self {
+            layers,
+            owners: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Layer serving `relative_path`; unlisted paths go to the topmost layer
+    /// that can read them
+    fn owner(&self, relative_path: &str) -> Option<usize> {
+        self.owners.lock().ok()?.get(relative_path).copied()
+    }
+
+    fn with_owner<T>(&self, relative_path: &str, op: impl Fn(&dyn ContentProvider) -> Result<T>) -> Result<T> {
+        if let Some(layer) = self.owner(relative_path) {
+            return op(self.layers[layer].as_ref());
+        }
+        let mut last_error = None;
+        for layer in self.layers.iter().rev() {
+            match op(layer.as_ref()) {
+                Ok(value) => return Ok(value),
+                Err(e) => last_error = Some(e),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| FastStartupError::AssetError("No content layers".to_string())))
+    }
+}
+
+impl ContentProvider for LayeredContent {
+    fn describe(&self) -> String {
+        self.layers.iter().map(|l| l.describe()).collect::<Vec<_>>().join(" < ")
+    }
+
+    fn list(&self, accept: &(dyn Fn(&str) -> bool + Sync)) -> Result<Vec<ContentEntry>> {
+        let mut merged: BTreeMap<String, (usize, ContentEntry)> = BTreeMap::new();
+        let mut overridden = 0;
+        for (layer, provider) in self.layers.iter().enumerate() {
+            for entry in provider.list(accept)? {
+                if merged.insert(entry.relative_path.clone(), (layer, entry)).is_some() {
+                    overridden += 1;
+                }
+            }
+        }
+        info!("{} files overridden by later content layers", overridden);
+
+        if let Ok(mut owners) = self.owners.lock() {
+            *owners = merged.iter().map(|(path, (layer, _))| (path.clone(), *layer)).collect();
+        }
+        Ok(merged.into_values().map(|(_, entry)| entry).collect())
+    }
+
+    fn read(&self, relative_path: &str) -> Result<Vec<u8>> {
+        self.with_owner(relative_path, |layer| layer.read(relative_path))
+    }
+
+    fn hash(&self, asset: &AssetInfo, algorithm: HashAlgorithm) -> Result<ContentHash> {
+        self.with_owner(&asset.relative_path, |layer| layer.hash(asset, algorithm))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_local_list_and_read() {
+        let root = std::env::temp_dir().join(format!("uefast_content_{}", std::process::id()));
+        std::fs::create_dir_all(root.join("Content").join("Maps")).unwrap();
+        std::fs::write(root.join("Content").join("Maps").join("Entry.umap"), b"map").unwrap();
+        std::fs::write(root.join("Content").join("notes.txt"), b"notes").unwrap();
+
+        let local = LocalContent::new(&root);
+        let listed = local.list(&|p: &str| p.ends_with(".umap")).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].size_bytes, 3);
+        assert_eq!(local.read(&listed[0].relative_path).unwrap(), b"map");
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_layers_override_base() {
+        let root = std::env::temp_dir().join(format!("uefast_layers_{}", std::process::id()));
+        let write = |layer: &str, name: &str, data: &[u8]| {
+            let dir = root.join(layer).join("Content");
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(dir.join(name), data).unwrap();
+        };
+        write("Base", "A.uasset", b"base");
+        write("Base", "B.uasset", b"b");
+        write("Patch", "A.uasset", b"patched");
+
+        let layered = open_layers(&["dir:Base".to_string(), "dir:Patch".to_string()], &root).unwrap();
+        let listed = layered.list(&|_: &str| true).unwrap();
+        assert_eq!(listed.len(), 2);
+        let a = listed.iter().find(|e| e.relative_path.ends_with("A.uasset")).unwrap();
+        assert_eq!(a.size_bytes, 7);
+        assert_eq!(a.local_path.as_deref(), Some(root.join("Patch").join("Content").join("A.uasset").as_path()));
+        assert_eq!(layered.read(&a.relative_path).unwrap(), b"patched");
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_patch_level() {
+        assert_eq!(patch_level(Path::new("pakchunk0-Windows.pak")), None);
+        assert_eq!(patch_level(Path::new("pakchunk0-Windows_P.pak")), Some(0));
+        assert_eq!(patch_level(Path::new("pakchunk0-Windows_2_P.pak")), Some(2));
+    }
+
+    #[test]
+    fn test_open_provider_spec() {
+        let root = Path::new(".");
+        assert!(open_provider("local", root).unwrap().describe().ends_with("Content"));
+        assert_eq!(open_provider("https://cdn.example/game/", root).unwrap().describe(), "https://cdn.example/game");
+        assert!(matches!(open_provider("p4://depot", root), Err(FastStartupError::InvalidArgument(_))));
+        assert_eq!(HttpContent::new("http://host/c").url("Content/A.uasset"), "http://host/c/Content/A.uasset");
+    }
+
+    #[test]
+    fn test_pak_patch_overrides_base() {
+        use crate::pak::PakEntry;
+        let pak = |name: &str, entries: &[(&str, u64)]| PakFile {
+            path: PathBuf::from(name),
+            version: 11,
+            mount_point: "../../../".to_string(),
+            entries: entries
+                .iter()
+                .map(|(p, offset)| PakEntry { path: p.to_string(), offset: *offset, size: 10 })
+                .collect(),
+        };
+        let content = PakContent::from_paks(
+            vec![
+                pak("pakchunk0-Windows_P.pak", &[("Game/Content/Maps/Entry.umap", 0)]),
+                pak("pakchunk0-Windows.pak", &[("Game/Content/Maps/Entry.umap", 0), ("Game/Content/UI/W_Main.uasset", 10)]),
+            ],
+            Path::new("Paks"),
+        );
+
+        let (patch, offset, _) = content.locate("Content/Maps/Entry.umap").unwrap();
+        assert_eq!((patch.path.to_str(), offset), (Some("pakchunk0-Windows_P.pak"), 0));
+        let (base, offset, _) = content.locate("Content/UI/W_Main.uasset").unwrap();
+        assert_eq!((base.path.to_str(), offset), (Some("pakchunk0-Windows.pak"), 10));
+        assert!(content.locate("Content/Missing.uasset").is_none());
+    }
+
+    #[test]
+    fn test_iostore_patch_overrides_base() {
+        use crate::iostore::tests::write_container;
+        let root = std::env::temp_dir().join(format!("uefast_iostore_content_{}", std::process::id()));
+        let paks = root.join("Paks");
+        std::fs::create_dir_all(&paks).unwrap();
+        write_container(
+            &paks,
+            "pakchunk0-Windows",
+            &[("Content/Maps/Entry.umap", b"base map", true), ("Content/UI/W_Main.uasset", b"widget", false)],
+            0,
+        );
+        write_container(&paks, "pakchunk0-Windows_P", &[("Content/Maps/Entry.umap", b"patched map", false)], 0);
+
+        let iostore = open_provider("Paks", &root).unwrap();
+        assert!(iostore.describe().ends_with("(2 IoStore containers)"));
+        let mut listed: Vec<(String, u64)> =
+            iostore.list(&|_: &str| true).unwrap().into_iter().map(|e| (e.relative_path, e.size_bytes)).collect();
+        listed.sort();
+        assert_eq!(
+            listed,
+            [("Content/Maps/Entry.umap".to_string(), 11), ("Content/UI/W_Main.uasset".to_string(), 6)]
+        );
+        assert_eq!(iostore.read("Content/Maps/Entry.umap").unwrap(), b"patched map");
+        assert_eq!(iostore.read("Content/UI/W_Main.uasset").unwrap(), b"widget");
+        assert!(iostore.read("Content/Missing.uasset").is_err());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,354 @@
+//! Cook Prediction Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Incremental cook time of a change set. A changed package invalidates
+//! every package that hard-imports it, directly or transitively, so the
+//! recook set comes from the same dependency graph as the startup closure;
+//! each recooked package is priced with a linear per-type model.
+
+use crate::editor_data::EditorData;
+use crate::graph::DependencyGraph;
+use crate::report::{Finding, ReportData, Severity, Table};
+use crate::scanner::{package_stem, AssetInfo, AssetType};
+use crate::{FastStartupError, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+const MB: f64 = 1024.0 * 1024.0;
+
+/// Share of the project above which an incremental cook is barely faster
+/// than a full one
+pub const FULL_COOK_RATIO: f64 = 0.5;
+
+/// Coefficients of the cook time model, overridable in `.uefast.toml`
+/// under `[cook]`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CookTimeModel {
+    /// Cooker startup and asset registry load, paid once per cook
+    #[serde(default = "default_fixed_seconds")]
+    pub fixed_seconds: f64,
+    #[serde(default = "default_seconds_per_package")]
+    pub seconds_per_package: f64,
+    /// Seconds per MB, keyed by `AssetType::as_str`; types not listed
+    /// fall back to the defaults
+    #[serde(default)]
+    pub seconds_per_mb: BTreeMap<String, f64>,
+}
+
+fn default_fixed_seconds() -> f64 {
+    45.0
+}
+
+fn default_seconds_per_package() -> f64 {
+    0.05
+}
+
+impl Default for CookTimeModel {
+    fn default() -> Self {
+        Self {
+            fixed_seconds: default_fixed_seconds(),
+            seconds_per_package: default_seconds_per_package(),
+            seconds_per_mb: BTreeMap::new(),
+        }
+    }
+}
+
+impl CookTimeModel {
+    pub fn seconds_per_mb_for(&self, asset_type: AssetType) -> f64 {
+        if let Some(&seconds) = self.seconds_per_mb.get(asset_type.as_str()) {
+            return seconds;
+        }
+        // Materials compile shaders and textures compress per platform
+        match asset_type {
+            AssetType::Material => 2.0,
+            AssetType::Texture => 0.5,
+            AssetType::Blueprint => 0.3,
+            AssetType::Audio => 0.3,
+            AssetType::UMap => 0.25,
+            AssetType::Animation => 0.2,
+            _ => 0.1,
+        }
+    }
+
+    /// Seconds to cook one package of `size_bytes` (companion files included)
+    pub fn package_seconds(&self, asset_type: AssetType, size_bytes: u64) -> f64 {
+        self.seconds_per_package + self.seconds_per_mb_for(asset_type) * size_bytes as f64 / MB
+    }
+}
+
+/// A package the change set forces to recook
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecookedPackage {
+    pub relative_path: String,
+    pub asset_type: AssetType,
+    /// Package plus its `.uexp`/`.ubulk`
+    pub size_bytes: u64,
+    pub seconds: f64,
+    /// Recooked dependency it was invalidated through; `None` when it is
+    /// itself in the change set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub via: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CookPrediction {
+    pub project_name: String,
+    pub changed_packages: usize,
+    /// Slowest first
+    pub recooked: Vec<RecookedPackage>,
+    /// Change list entries that match no package
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unmatched: Vec<String>,
+    pub total_packages: usize,
+    pub incremental_seconds: f64,
+    pub full_cook_seconds: f64,
+}
+
+impl CookPrediction {
+    /// Predict the cook after `changes` (project-relative or absolute paths,
+    /// or `/Game/` package names) with `graph` built from `assets`
+    pub fn predict(
+        project_name: &str,
+        project_root: &Path,
+        graph: &DependencyGraph,
+        assets: &[AssetInfo],
+        editor_data: Option<&EditorData>,
+        changes: &[String],
+        model: &CookTimeModel,
+    ) -> Self {
+        let packages = Packages::index(assets, editor_data);
+        let mut changed = HashSet::new();
+        let mut unmatched = Vec::new();
+        for entry in changes {
+            match packages.resolve(project_root, entry) {
+                Some(path) => {
+                    changed.insert(path);
+                }
+                None => unmatched.push(entry.clone()),
+            }
+        }
+
+        let relative = |path: &Path| packages.by_path.get(path).map(|p| p.relative_path.clone());
+        let mut recooked: Vec<RecookedPackage> = graph
+            .hard_dependents_of(&changed)
+            .into_iter()
+            .filter_map(|(path, via)| {
+                let package = packages.by_path.get(&path)?;
+                Some(RecookedPackage {
+                    relative_path: package.relative_path.clone(),
+                    asset_type: package.asset_type,
+                    size_bytes: package.size_bytes,
+                    seconds: model.package_seconds(package.asset_type, package.size_bytes),
+                    via: via.and_then(|v| relative(&v)),
+                })
+            })
+            .collect();
+        recooked.sort_by(|a, b| b.seconds.total_cmp(&a.seconds).then(a.relative_path.cmp(&b.relative_path)));
+
+        let full_cook_seconds = model.fixed_seconds
+            + packages
+                .by_path
+                .values()
+                .map(|p| model.package_seconds(p.asset_type, p.size_bytes))
+                .sum::<f64>();
+        let incremental_seconds = if recooked.is_empty() {
+            0.0
+        } else {
+            model.fixed_seconds + recooked.iter().map(|p| p.seconds).sum::<f64>()
+        };
+
+        Self {
+            project_name: project_name.to_string(),
+            changed_packages: changed.len(),
+            recooked,
+            unmatched,
+            total_packages: packages.by_path.len(),
+            incremental_seconds,
+            full_cook_seconds,
+        }
+    }
+
+    /// Recooked packages over all packages
+    pub fn recook_ratio(&self) -> f64 {
+        if self.total_packages == 0 {
+            0.0
+        } else {
+            self.recooked.len() as f64 / self.total_packages as f64
+        }
+    }
+}
+
+/// Change list: one path or package name per line, `#` comments and blank
+/// lines skipped
+pub fn read_change_list(path: &Path) -> Result<Vec<String>> {
+    let text = std::fs::read_to_string(path).map_err(|e| {
+        FastStartupError::InvalidArgument(format!("Cannot read change list {}: {}", path.display(), e))
+    })?;
+    Ok(text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect())
+}
+
+struct PackageInfo {
+    relative_path: String,
+    asset_type: AssetType,
+    size_bytes: u64,
+}
+
+/// `.uasset`/`.umap` packages of the project with their companion bytes
+struct Packages {
+    by_path: HashMap<PathBuf, PackageInfo>,
+    /// Extensionless relative path -> package path
+    by_stem: HashMap<String, PathBuf>,
+}
+
+impl Packages {
+    fn index(assets: &[AssetInfo], editor_data: Option<&EditorData>) -> Self {
+        let stem = |relative: &str| package_stem(Path::new(""), relative);
+
+        let mut by_path = HashMap::new();
+        let mut by_stem = HashMap::new();
+        for asset in assets.iter().filter(|a| matches!(a.asset_type, AssetType::UAsset | AssetType::UMap)) {
+            by_stem.insert(stem(&asset.relative_path), asset.path.clone());
+            by_path.insert(
+                asset.path.clone(),
+                PackageInfo {
+                    relative_path: asset.relative_path.replace('\\', "/"),
+                    asset_type: editor_data.map(|d| d.asset_type(asset)).unwrap_or(asset.asset_type),
+                    size_bytes: asset.size_bytes,
+                },
+            );
+        }
+        for asset in assets.iter().filter(|a| matches!(a.asset_type, AssetType::UExp | AssetType::UBulk)) {
+            if let Some(package) = by_stem.get(&stem(&asset.relative_path)).and_then(|p| by_path.get_mut(p)) {
+                package.size_bytes += asset.size_bytes;
+            }
+        }
+        Self { by_path, by_stem }
+    }
+
+    /// Package a change list entry refers to; a changed `.uexp`/`.ubulk`
+    /// counts as its package
+    fn resolve(&self, project_root: &Path, entry: &str) -> Option<PathBuf> {
+        self.by_stem.get(&package_stem(project_root, entry)).cloned()
+    }
+}
+
+impl ReportData for CookPrediction {
+    fn title(&self) -> String {
+        format!("Cook prediction: {}", self.project_name)
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        serde_json::to_value(self).map_err(|e| FastStartupError::SerializationError(e.to_string()))
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        vec![
+            ("Changed packages".to_string(), self.changed_packages.to_string()),
+            (
+                "Recooked packages".to_string(),
+                format!("{} of {} ({:.1}%)", self.recooked.len(), self.total_packages, self.recook_ratio() * 100.0),
+            ),
+            ("Incremental cook".to_string(), format!("~{:.0}s", self.incremental_seconds)),
+            ("Full cook".to_string(), format!("~{:.0}s", self.full_cook_seconds)),
+            ("Unmatched entries".to_string(), self.unmatched.len().to_string()),
+        ]
+    }
+
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["package", "type", "size_mb", "seconds", "via"]);
+        for package in &self.recooked {
+            table.push(vec![
+                package.relative_path.clone(),
+                package.asset_type.as_str().to_string(),
+                format!("{:.2}", package.size_bytes as f64 / MB),
+                format!("{:.2}", package.seconds),
+                package.via.clone().unwrap_or_default(),
+            ]);
+        }
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        let mut findings: Vec<Finding> = self
+            .unmatched
+            .iter()
+            .map(|entry| Finding {
+                rule_id: "cook/unmatched-change".to_string(),
+                severity: Severity::Warning,
+                message: format!("{} matches no package in the project", entry),
+                location: None,
+            })
+            .collect();
+        if self.recook_ratio() >= FULL_COOK_RATIO {
+            findings.push(Finding {
+                rule_id: "cook/wide-invalidation".to_string(),
+                severity: Severity::Note,
+                message: format!(
+                    "The change set invalidates {:.0}% of the project; a full cook costs about the same",
+                    self.recook_ratio() * 100.0
+                ),
+                location: None,
+            });
+        }
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::DependencyType;
+
+    fn asset(relative_path: &str, size_bytes: u64) -> AssetInfo {
+        AssetInfo {
+            path: PathBuf::from("/p").join(relative_path),
+            relative_path: relative_path.to_string(),
+            asset_type: AssetType::from_extension(relative_path.rsplit('.').next().unwrap_or("")),
+            size_bytes,
+            modified: 0,
+            mount_point: "/Game".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_predict_recooks_hard_dependents() {
+        let assets = vec![
+            asset("Content/Maps/Entry.umap", 1024),
+            asset("Content/UI/Menu.uasset", 1024),
+            asset("Content/UI/Icon.uasset", 1024),
+            asset("Content/UI/Icon.ubulk", 4 * 1024 * 1024),
+            asset("Content/Props/Crate.uasset", 1024),
+        ];
+        let mut graph = DependencyGraph::new();
+        for a in &assets {
+            graph.add_asset(a);
+        }
+        graph.add_dependency(&assets[0].path, &assets[1].path, DependencyType::Import, true);
+        graph.add_dependency(&assets[1].path, &assets[2].path, DependencyType::Import, true);
+        graph.add_dependency(&assets[4].path, &assets[2].path, DependencyType::SoftReference, false);
+
+        let changes = vec!["/Game/UI/Icon.Icon".to_string(), "Content/Gone.uasset".to_string()];
+        let model = CookTimeModel::default();
+        let prediction = CookPrediction::predict("Game", Path::new("/p"), &graph, &assets, None, &changes, &model);
+
+        assert_eq!(prediction.changed_packages, 1);
+        assert_eq!(prediction.total_packages, 4);
+        assert_eq!(prediction.unmatched, ["Content/Gone.uasset"]);
+        let paths: Vec<&str> = prediction.recooked.iter().map(|p| p.relative_path.as_str()).collect();
+        assert_eq!(paths, ["Content/UI/Icon.uasset", "Content/Maps/Entry.umap", "Content/UI/Menu.uasset"]);
+        assert_eq!(prediction.recooked[0].size_bytes, 1024 + 4 * 1024 * 1024);
+        assert_eq!(prediction.recooked[1].via.as_deref(), Some("Content/UI/Menu.uasset"));
+        assert!(prediction.incremental_seconds > model.fixed_seconds);
+        assert!(prediction.incremental_seconds < prediction.full_cook_seconds);
+        assert_eq!(prediction.findings().len(), 2);
+    }
+}
@@ -0,0 +1,403 @@
+//! Crashes Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Correlates crash, assert, ensure and hang reports from `Saved/Crashes`
+//! with the cache change log. A report written early in a session counts as
+//! a startup failure; the startup assets added or changed in the hours
+//! before it are its suspects, ranked first when the crash message or the
+//! log the crash reporter kept names them.
+
+use crate::cache_log::CacheLogEntry;
+use crate::report::{Finding, ReportData, Severity, Table};
+use crate::scanner::{package_name_from_relative, package_stem};
+use crate::{FastStartupError, Result};
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Reports this many seconds into a session or earlier are startup failures
+pub const DEFAULT_STARTUP_SECONDS: f64 = 60.0;
+
+/// Cache changes this long before a crash are suspects
+pub const DEFAULT_WINDOW_HOURS: i64 = 24;
+
+/// Crash properties written by `FGenericCrashContext`
+const CRASH_CONTEXT_FILE: &str = "CrashContext.runtime-xml";
+
+/// Lines from the end of the crash folder's log searched for package names
+const LOG_TAIL_LINES: usize = 400;
+
+/// Logged once the engine is up; its absence means the session died booting
+const ENGINE_INIT_MARKER: &str = "(Engine Initialization) Total time";
+
+/// `FDateTime` ticks (100 ns since 0001-01-01) at the Unix epoch
+const UNIX_EPOCH_TICKS: i64 = 621_355_968_000_000_000;
+const TICKS_PER_SECOND: i64 = 10_000_000;
+
+/// Startup asset changed shortly before a crash
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuspectAsset {
+    pub relative_path: String,
+    /// `added`, `changed` or `renamed`
+    pub change: String,
+    pub changed_at: DateTime<Utc>,
+    /// `user@host` and operation of the cache log entry
+    pub changed_by: String,
+    pub operation: String,
+    /// Named by the crash message, call stack or log tail
+    pub mentioned: bool,
+}
+
+/// One report folder under `Saved/Crashes`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrashRecord {
+    pub folder: PathBuf,
+    /// `Crash`, `Assert`, `Ensure`, `Hang`, `GPUCrash`, ...
+    pub crash_type: String,
+    pub time: DateTime<Utc>,
+    pub seconds_since_start: Option<f64>,
+    pub during_startup: bool,
+    pub error_message: String,
+    pub build_version: String,
+    pub engine_version: String,
+    /// Error message, call stack and log tail, searched for package names
+    #[serde(skip)]
+    text: String,
+    /// Startup assets changed in the window before the crash, mentioned
+    /// first, then most recent first
+    pub suspects: Vec<SuspectAsset>,
+}
+
+impl CrashRecord {
+    /// Read a report folder; `None` when it holds no crash context
+    pub fn read(folder: &Path, startup_seconds: f64) -> Result<Option<Self>> {
+        let context_path = folder.join(CRASH_CONTEXT_FILE);
+        if !context_path.exists() {
+            return Ok(None);
+        }
+        let context = std::fs::read_to_string(&context_path)?;
+        let property = |name: &str| xml_property(&context, name).unwrap_or_default();
+
+        let time = property("TimeOfCrash")
+            .parse::<i64>()
+            .ok()
+            .and_then(|ticks| DateTime::from_timestamp((ticks - UNIX_EPOCH_TICKS) / TICKS_PER_SECOND, 0))
+            .or_else(|| {
+                let modified = std::fs::metadata(&context_path).and_then(|m| m.modified()).ok()?;
+                Some(DateTime::<Utc>::from(modified))
+            })
+            .ok_or_else(|| FastStartupError::AssetError(format!("{} has no crash time", context_path.display())))?;
+
+        let log_tail = log_tail(folder)?;
+        let seconds_since_start = property("SecondsSinceStart").parse::<f64>().ok();
+        let during_startup = match seconds_since_start {
+            Some(seconds) => seconds <= startup_seconds,
+            None => !log_tail.contains(ENGINE_INIT_MARKER),
+        };
+        let error_message = property("ErrorMessage");
+        let text = format!("{}\n{}\n{}", error_message, property("CallStack"), log_tail);
+
+        Ok(Some(Self {
+            folder: folder.to_path_buf(),
+            crash_type: Some(property("CrashType")).filter(|t| !t.is_empty()).unwrap_or_else(|| "Crash".to_string()),
+            time,
+            seconds_since_start,
+            during_startup,
+            error_message,
+            build_version: property("BuildVersion"),
+            engine_version: property("EngineVersion"),
+            text,
+            suspects: Vec::new(),
+        }))
+    }
+
+    /// Whether the crash text names `relative_path` by package name
+    pub fn mentions(&self, relative_path: &str) -> bool {
+        let Some(package) = package_name_from_relative(relative_path) else {
+            return false;
+        };
+        self.text.match_indices(&package).any(|(start, _)| {
+            let next = self.text[start + package.len()..].chars().next();
+            !next.is_some_and(|c| c.is_alphanumeric() || c == '_')
+        })
+    }
+
+    /// First line of the error message
+    pub fn headline(&self) -> &str {
+        self.error_message.lines().next().unwrap_or("").trim()
+    }
+}
+
+/// Every report under `crash_dir`, oldest first
+pub fn read_crashes(crash_dir: &Path, startup_seconds: f64) -> Result<Vec<CrashRecord>> {
+    let mut crashes = Vec::new();
+    for entry in std::fs::read_dir(crash_dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            if let Some(crash) = CrashRecord::read(&path, startup_seconds)? {
+                crashes.push(crash);
+            }
+        }
+    }
+    crashes.sort_by_key(|c| c.time);
+    Ok(crashes)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrashCorrelation {
+    pub project_name: String,
+    pub startup_seconds: f64,
+    pub window_hours: i64,
+    pub crashes: Vec<CrashRecord>,
+}
+
+impl CrashCorrelation {
+    /// Attach to each startup crash the startup packages (relative paths in
+    /// `startup_order`, companions included) that `log` records as added,
+    /// changed or renamed within `window_hours` before it
+    pub fn correlate(
+        project_name: &str,
+        mut crashes: Vec<CrashRecord>,
+        startup_order: &[String],
+        log: &[CacheLogEntry],
+        startup_seconds: f64,
+        window_hours: i64,
+    ) -> Self {
+        let stem = |relative: &str| package_stem(Path::new(""), relative);
+        let startup: HashSet<String> = startup_order.iter().map(|p| stem(p)).collect();
+        let changes: Vec<(DateTime<Utc>, &CacheLogEntry)> = log
+            .iter()
+            .filter_map(|entry| Some((DateTime::parse_from_rfc3339(&entry.timestamp).ok()?.to_utc(), entry)))
+            .collect();
+
+        for crash in crashes.iter_mut().filter(|c| c.during_startup) {
+            let since = crash.time - Duration::hours(window_hours);
+            let mut latest: HashMap<&str, SuspectAsset> = HashMap::new();
+            for (at, entry) in changes.iter().filter(|(at, _)| *at > since && *at <= crash.time) {
+                let logged = &entry.changes;
+                let touched = logged.added.iter().map(|p| (p.as_str(), "added"))
+                    .chain(logged.changed.iter().map(|p| (p.as_str(), "changed")))
+                    .chain(logged.renamed.iter().map(|r| (r.to.as_str(), "renamed")));
+                for (path, change) in touched.filter(|(path, _)| startup.contains(&stem(path))) {
+                    latest.insert(
+                        path,
+                        SuspectAsset {
+                            relative_path: path.to_string(),
+                            change: change.to_string(),
+                            changed_at: *at,
+                            changed_by: format!("{}@{}", entry.user, entry.host),
+                            operation: entry.operation.clone(),
+                            mentioned: crash.mentions(path),
+                        },
+                    );
+                }
+            }
+            crash.suspects = latest.into_values().collect();
+            crash.suspects.sort_by(|a, b| {
+                b.mentioned
+                    .cmp(&a.mentioned)
+                    .then_with(|| b.changed_at.cmp(&a.changed_at))
+                    .then_with(|| a.relative_path.cmp(&b.relative_path))
+            });
+        }
+
+        Self { project_name: project_name.to_string(), startup_seconds, window_hours, crashes }
+    }
+
+    pub fn startup_crashes(&self) -> impl Iterator<Item = &CrashRecord> {
+        self.crashes.iter().filter(|c| c.during_startup)
+    }
+}
+
+/// Text of `<name>` in a crash context, unescaped
+fn xml_property(xml: &str, name: &str) -> Option<String> {
+    let open = format!("<{}>", name);
+    let start = xml.find(&open)? + open.len();
+    let end = start + xml[start..].find(&format!("</{}>", name))?;
+    Some(
+        xml[start..end]
+            .trim()
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&apos;", "'")
+            .replace("&amp;", "&"),
+    )
+}
+
+/// Last `LOG_TAIL_LINES` lines of the session log kept with the report
+fn log_tail(folder: &Path) -> Result<String> {
+    for entry in std::fs::read_dir(folder)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "log") {
+            let log = String::from_utf8_lossy(&std::fs::read(&path)?).to_string();
+            let lines: Vec<&str> = log.lines().collect();
+            return Ok(lines[lines.len().saturating_sub(LOG_TAIL_LINES)..].join("\n"));
+        }
+    }
+    Ok(String::new())
+}
+
+impl ReportData for CrashCorrelation {
+    fn title(&self) -> String {
+        format!("Startup crashes: {}", self.project_name)
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        serde_json::to_value(self).map_err(|e| FastStartupError::SerializationError(e.to_string()))
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        let suspects: HashSet<&str> = self
+            .startup_crashes()
+            .flat_map(|c| c.suspects.iter().map(|s| s.relative_path.as_str()))
+            .collect();
+        vec![
+            ("Reports".to_string(), self.crashes.len().to_string()),
+            (
+                format!("During startup (first {:.0} s)", self.startup_seconds),
+                self.startup_crashes().count().to_string(),
+            ),
+            (format!("Startup assets changed in the {} h before", self.window_hours), suspects.len().to_string()),
+            (
+                "Latest".to_string(),
+                self.crashes.last().map(|c| format!("{} {}", c.crash_type, c.time.to_rfc3339_opts(SecondsFormat::Secs, true))).unwrap_or_default(),
+            ),
+        ]
+    }
+
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["time", "type", "seconds", "startup", "suspects", "top_suspect", "message"]);
+        for crash in &self.crashes {
+            table.push(vec![
+                crash.time.to_rfc3339_opts(SecondsFormat::Secs, true),
+                crash.crash_type.clone(),
+                crash.seconds_since_start.map(|s| format!("{:.1}", s)).unwrap_or_default(),
+                crash.during_startup.to_string(),
+                crash.suspects.len().to_string(),
+                crash.suspects.first().map(|s| s.relative_path.clone()).unwrap_or_default(),
+                crash.headline().to_string(),
+            ]);
+        }
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        for crash in self.startup_crashes() {
+            let location = Some(crash.folder.display().to_string());
+            for suspect in crash.suspects.iter().filter(|s| s.mentioned) {
+                findings.push(Finding {
+                    rule_id: "crashes/mentioned-asset".to_string(),
+                    severity: Severity::Error,
+                    message: format!(
+                        "{} during startup names {}, {} {} by {} ({:.1} h before)",
+                        crash.crash_type,
+                        suspect.relative_path,
+                        suspect.change,
+                        suspect.changed_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+                        suspect.changed_by,
+                        (crash.time - suspect.changed_at).num_minutes() as f64 / 60.0
+                    ),
+                    location: location.clone(),
+                });
+            }
+            if crash.suspects.iter().all(|s| !s.mentioned) {
+                let message = match crash.suspects.first() {
+                    Some(latest) => format!(
+                        "{} during startup after {} startup assets changed in the {} h before it, most recently {} by {}",
+                        crash.crash_type,
+                        crash.suspects.len(),
+                        self.window_hours,
+                        latest.relative_path,
+                        latest.changed_by
+                    ),
+                    None => format!(
+                        "{} during startup with no startup asset changes logged in the {} h before it",
+                        crash.crash_type, self.window_hours
+                    ),
+                };
+                findings.push(Finding {
+                    rule_id: "crashes/startup".to_string(),
+                    severity: Severity::Warning,
+                    message,
+                    location,
+                });
+            }
+        }
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache_log::CacheChanges;
+
+    #[test]
+    fn test_correlate_startup_crash() {
+        let root = std::env::temp_dir().join(format!("uefast_crashes_{}", std::process::id()));
+        let crash_time = DateTime::parse_from_rfc3339("2026-03-02T09:00:00Z").unwrap().to_utc();
+        let ticks = crash_time.timestamp() * TICKS_PER_SECOND + UNIX_EPOCH_TICKS;
+        for (folder, seconds, hours) in [("UECC-Windows-A_0000", 7.5, 0), ("UECC-Windows-B_0000", 900.0, 1)] {
+            std::fs::create_dir_all(root.join(folder)).unwrap();
+            std::fs::write(
+                root.join(folder).join(CRASH_CONTEXT_FILE),
+                format!(
+                    "<FGenericCrashContext><RuntimeProperties><CrashType>Assert</CrashType>\
+                     <ErrorMessage>Assertion failed: Widget &amp;&amp; IsValid</ErrorMessage>\
+                     <SecondsSinceStart>{}</SecondsSinceStart><TimeOfCrash>{}</TimeOfCrash>\
+                     </RuntimeProperties></FGenericCrashContext>",
+                    seconds,
+                    ticks + hours * 3600 * TICKS_PER_SECOND
+                ),
+            )
+            .unwrap();
+        }
+        std::fs::write(
+            root.join("UECC-Windows-A_0000/Shooter.log"),
+            "LogStreaming: Error: Failed to load /Game/UI/W_Main.W_Main_C\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(root.join("Empty")).unwrap();
+
+        let crashes = read_crashes(&root, DEFAULT_STARTUP_SECONDS).unwrap();
+        assert_eq!(crashes.len(), 2);
+        assert_eq!(crashes[0].error_message, "Assertion failed: Widget && IsValid");
+        assert_eq!(crashes[0].time, crash_time);
+        assert!(crashes[0].mentions("Content/UI/W_Main.uasset"));
+        assert!(!crashes[0].mentions("Content/UI/W_Ma.uasset"));
+
+        let startup_order = ["Content/UI/W_Main.uasset".to_string(), "Content/UI/W_Hud.uasset".to_string()];
+        let entry = |timestamp: &str, changed: &[&str]| CacheLogEntry {
+            schema_version: 0,
+            timestamp: timestamp.to_string(),
+            user: "alice".to_string(),
+            host: "ws-12".to_string(),
+            operation: "update".to_string(),
+            asset_count: 3,
+            tags: Vec::new(),
+            changes: CacheChanges { changed: changed.iter().map(|p| p.to_string()).collect(), ..Default::default() },
+        };
+        let log = [
+            entry("2026-02-20T09:00:00Z", &["Content/UI/W_Hud.uasset"]),
+            entry("2026-03-01T18:00:00Z", &["Content/UI/W_Main.uasset", "Content/Rock.uasset"]),
+            entry("2026-03-02T08:00:00Z", &["Content/UI/W_Hud.uexp"]),
+        ];
+
+        let correlation =
+            CrashCorrelation::correlate("Shooter", crashes, &startup_order, &log, DEFAULT_STARTUP_SECONDS, DEFAULT_WINDOW_HOURS);
+        let startup: Vec<&CrashRecord> = correlation.startup_crashes().collect();
+        assert_eq!(startup.len(), 1);
+        let suspects: Vec<(&str, bool)> =
+            startup[0].suspects.iter().map(|s| (s.relative_path.as_str(), s.mentioned)).collect();
+        assert_eq!(suspects, [("Content/UI/W_Main.uasset", true), ("Content/UI/W_Hud.uexp", false)]);
+        let findings = correlation.findings();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "crashes/mentioned-asset");
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
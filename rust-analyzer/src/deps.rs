@@ -0,0 +1,246 @@
+//! Deps Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Direct and transitive dependencies of one asset, or with `reverse` the
+//! assets that depend on it: what touching a texture can invalidate, and
+//! whether any of that loads at startup.
+
+use crate::graph::DependencyGraph;
+use crate::report::{Finding, ReportData, Severity, Table};
+use crate::scanner::{package_name_from_relative, AssetInfo, AssetType};
+use crate::{FastStartupError, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+/// Startup-critical dependents named in a finding before it is cut short
+const LISTED_STARTUP_DEPENDENTS: usize = 5;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyEntry {
+    pub relative_path: String,
+    /// 1 for direct dependencies (or dependents)
+    pub depth: usize,
+    /// The asset this one was reached through
+    pub via: String,
+    pub asset_type: AssetType,
+    pub size_bytes: u64,
+    pub is_startup_critical: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyQuery {
+    pub project_name: String,
+    pub asset: String,
+    /// Dependents of `asset` rather than its dependencies
+    pub reverse: bool,
+    pub hard_only: bool,
+    /// Nearest first
+    pub entries: Vec<DependencyEntry>,
+}
+
+impl DependencyQuery {
+    /// Walk `graph` from `asset`, stopping after `max_depth` edges if given
+    pub fn run(
+        graph: &DependencyGraph,
+        project_root: &Path,
+        asset: &Path,
+        reverse: bool,
+        hard_only: bool,
+        max_depth: Option<usize>,
+    ) -> Self {
+        let relative = |path: &Path| {
+            path.strip_prefix(project_root).unwrap_or(path).to_string_lossy().replace('\\', "/")
+        };
+        let entries = graph
+            .reachable_from(asset, reverse, hard_only)
+            .into_iter()
+            .take_while(|reached| max_depth.is_none_or(|max| reached.depth <= max))
+            .map(|reached| DependencyEntry {
+                relative_path: relative(&reached.node.path),
+                depth: reached.depth,
+                via: relative(&reached.via.path),
+                asset_type: reached.node.asset_type,
+                size_bytes: reached.node.size_bytes,
+                is_startup_critical: reached.node.is_startup_critical,
+            })
+            .collect();
+
+        Self {
+            project_name: project_root.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default(),
+            asset: relative(asset),
+            reverse,
+            hard_only,
+            entries,
+        }
+    }
+
+    pub fn direct(&self) -> impl Iterator<Item = &DependencyEntry> {
+        self.entries.iter().filter(|entry| entry.depth == 1)
+    }
+
+    pub fn startup_critical(&self) -> impl Iterator<Item = &DependencyEntry> {
+        self.entries.iter().filter(|entry| entry.is_startup_critical)
+    }
+
+    pub fn max_depth(&self) -> usize {
+        self.entries.last().map(|entry| entry.depth).unwrap_or(0)
+    }
+
+    fn noun(&self) -> &'static str {
+        if self.reverse { "dependents" } else { "dependencies" }
+    }
+}
+
+/// The scanned asset `query` names: a project-relative path, a long package
+/// name such as `/Game/Textures/T_Rock`, or a path on disk
+pub fn resolve_asset(project_root: &Path, assets: &[AssetInfo], query: &str) -> Result<PathBuf> {
+    let normalized = query.replace('\\', "/");
+    let on_disk = Path::new(query).strip_prefix(project_root).ok().map(|p| p.to_string_lossy().replace('\\', "/"));
+    let package = normalized.split('.').next().unwrap_or(&normalized);
+    assets
+        .iter()
+        .find(|asset| asset.relative_path.replace('\\', "/") == normalized)
+        .or_else(|| assets.iter().find(|asset| on_disk.as_deref() == Some(asset.relative_path.replace('\\', "/").as_str())))
+        .or_else(|| {
+            assets.iter().find(|asset| {
+                asset.asset_type != AssetType::UExp
+                    && package_name_from_relative(&asset.relative_path).as_deref() == Some(package)
+            })
+        })
+        .map(|asset| asset.path.clone())
+        .ok_or_else(|| {
+            FastStartupError::InvalidArgument(format!(
+                "{} is not an asset of {}; pass a path like Content/Textures/T_Rock.uasset or a package like /Game/Textures/T_Rock",
+                query,
+                project_root.display()
+            ))
+        })
+}
+
+impl ReportData for DependencyQuery {
+    fn title(&self) -> String {
+        format!("{} of {}", if self.reverse { "Dependents" } else { "Dependencies" }, self.asset)
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        serde_json::to_value(self).map_err(|e| FastStartupError::SerializationError(e.to_string()))
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        let noun = self.noun();
+        vec![
+            ("Asset".to_string(), self.asset.clone()),
+            (format!("Direct {}", noun), self.direct().count().to_string()),
+            (format!("All {}", noun), self.entries.len().to_string()),
+            ("Deepest".to_string(), self.max_depth().to_string()),
+            ("Startup-critical".to_string(), self.startup_critical().count().to_string()),
+            ("Edges".to_string(), if self.hard_only { "hard only" } else { "hard and soft" }.to_string()),
+        ]
+    }
+
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["depth", "relative_path", "via", "asset_type", "size_bytes", "startup_critical"]);
+        for entry in &self.entries {
+            table.push(vec![
+                entry.depth.to_string(),
+                entry.relative_path.clone(),
+                entry.via.clone(),
+                entry.asset_type.as_str().to_string(),
+                entry.size_bytes.to_string(),
+                entry.is_startup_critical.to_string(),
+            ]);
+        }
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        let startup: Vec<&DependencyEntry> = self.startup_critical().collect();
+        if !self.reverse || startup.is_empty() {
+            return Vec::new();
+        }
+        let mut names: Vec<&str> = startup.iter().take(LISTED_STARTUP_DEPENDENTS).map(|e| e.relative_path.as_str()).collect();
+        if startup.len() > LISTED_STARTUP_DEPENDENTS {
+            names.push("...");
+        }
+        vec![Finding {
+            rule_id: "deps/startup-dependents".to_string(),
+            severity: Severity::Warning,
+            message: format!(
+                "Changing {} affects {} startup-critical assets: {}",
+                self.asset,
+                startup.len(),
+                names.join(", ")
+            ),
+            location: Some(self.asset.clone()),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::DependencyType;
+
+    fn asset(relative_path: &str) -> AssetInfo {
+        let ext = Path::new(relative_path).extension().unwrap().to_str().unwrap();
+        AssetInfo {
+            path: Path::new("/p").join(relative_path),
+            relative_path: relative_path.to_string(),
+            asset_type: AssetType::from_extension(ext),
+            size_bytes: 10,
+            modified: 0,
+            mount_point: "/Game".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_reverse_query_sorted_by_depth() {
+        let assets: Vec<AssetInfo> = [
+            "Content/Maps/Entry.umap",
+            "Content/UI/Menu.uasset",
+            "Content/UI/Button.uasset",
+            "Content/Textures/T_Rock.uasset",
+            "Content/Props/Rock.uasset",
+        ]
+        .iter()
+        .map(|path| asset(path))
+        .collect();
+        let mut graph = DependencyGraph::new();
+        for asset in &assets {
+            graph.add_asset(asset);
+        }
+        let link = |graph: &mut DependencyGraph, from: usize, to: usize, hard: bool| {
+            graph.add_dependency(&assets[from].path, &assets[to].path, DependencyType::Import, hard);
+        };
+        link(&mut graph, 0, 1, true);
+        link(&mut graph, 1, 2, true);
+        link(&mut graph, 2, 3, true);
+        link(&mut graph, 4, 3, false);
+        graph.mark_startup_roots(&[assets[0].path.clone()].into_iter().collect());
+
+        let texture = resolve_asset(Path::new("/p"), &assets, "/Game/Textures/T_Rock").unwrap();
+        let query = DependencyQuery::run(&graph, Path::new("/p"), &texture, true, false, None);
+        let order: Vec<(usize, &str)> = query.entries.iter().map(|e| (e.depth, e.relative_path.as_str())).collect();
+        assert_eq!(
+            order,
+            [
+                (1, "Content/Props/Rock.uasset"),
+                (1, "Content/UI/Button.uasset"),
+                (2, "Content/UI/Menu.uasset"),
+                (3, "Content/Maps/Entry.umap"),
+            ]
+        );
+        assert_eq!(query.entries[2].via, "Content/UI/Button.uasset");
+        assert_eq!(query.startup_critical().count(), 3);
+        assert_eq!(query.findings().len(), 1);
+
+        let hard = DependencyQuery::run(&graph, Path::new("/p"), &texture, true, true, Some(2));
+        assert_eq!(hard.entries.len(), 2);
+        let forward = DependencyQuery::run(&graph, Path::new("/p"), &assets[0].path, false, false, None);
+        assert_eq!(forward.max_depth(), 3);
+        assert!(forward.findings().is_empty());
+        assert!(resolve_asset(Path::new("/p"), &assets, "Content/Missing.uasset").is_err());
+    }
+}
@@ -0,0 +1,176 @@
+//! Direct I/O Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Unbuffered reads (O_DIRECT / F_NOCACHE / FILE_FLAG_NO_BUFFERING) so
+//! benchmarks measure the disk instead of the OS page cache
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+/// Alignment required for O_DIRECT and FILE_FLAG_NO_BUFFERING buffers
+pub const DIRECT_IO_ALIGN: usize = 4096;
+
+/// Read size of direct I/O; divides the chunk sizes used by hashing
+pub const DIRECT_IO_BUFFER: usize = 1024 * 1024;
+
+/// Open `path` bypassing the page cache where the platform and filesystem
+/// allow it. Filesystems without O_DIRECT support (e.g. tmpfs) fall back to
+/// a normal open after dropping the file's cached pages.
+pub fn open_uncached(path: &Path) -> std::io::Result<File> {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        match std::fs::OpenOptions::new().read(true).custom_flags(libc::O_DIRECT).open(path) {
+            Ok(file) => Ok(file),
+            Err(e) if e.raw_os_error() == Some(libc::EINVAL) => {
+                let file = File::open(path)?;
+                drop_cached_pages(&file);
+                Ok(file)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        use std::os::unix::io::AsRawFd;
+        let file = File::open(path)?;
+        unsafe {
+            libc::fcntl(file.as_raw_fd(), libc::F_NOCACHE, 1);
+        }
+        Ok(file)
+    }
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::OpenOptionsExt;
+        const FILE_FLAG_NO_BUFFERING: u32 = 0x2000_0000;
+        std::fs::OpenOptions::new().read(true).custom_flags(FILE_FLAG_NO_BUFFERING).open(path)
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos", windows)))]
+    {
+        File::open(path)
+    }
+}
+
+/// Ask the kernel to evict clean cached pages of `file`
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn drop_cached_pages(file: &File) {
+    use std::os::unix::io::AsRawFd;
+    unsafe {
+        libc::posix_fadvise(file.as_raw_fd(), 0, 0, libc::POSIX_FADV_DONTNEED);
+    }
+}
+
+/// How `drop_page_cache` emptied the OS file cache
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheDrop {
+    /// The whole system cache was flushed (needs root/administrator)
+    System,
+    /// Cached pages of the given files were evicted
+    PerFile,
+    /// Nothing could be flushed on this platform; use direct I/O instead
+    Unsupported,
+}
+
+impl CacheDrop {
+    pub fn describe(&self) -> &'static str {
+        match self {
+            CacheDrop::System => "system page cache flushed",
+            CacheDrop::PerFile => "cached pages of benchmark files evicted",
+            CacheDrop::Unsupported => "page cache could not be flushed on this platform",
+        }
+    }
+}
+
+/// Flush the OS file cache before a cold benchmark iteration, falling back
+/// to evicting just `paths` when the process lacks the privileges
+pub fn drop_page_cache(paths: &[&Path]) -> CacheDrop {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        unsafe {
+            libc::sync();
+        }
+        if std::fs::write("/proc/sys/vm/drop_caches", "1").is_ok() {
+            return CacheDrop::System;
+        }
+        for path in paths {
+            if let Ok(file) = File::open(path) {
+                drop_cached_pages(&file);
+            }
+        }
+        CacheDrop::PerFile
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let _ = paths;
+        match std::process::Command::new("purge").status() {
+            Ok(status) if status.success() => CacheDrop::System,
+            _ => CacheDrop::Unsupported,
+        }
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos")))]
+    {
+        let _ = paths;
+        CacheDrop::Unsupported
+    }
+}
+
+/// Heap buffer whose usable region starts on a `DIRECT_IO_ALIGN` boundary
+pub struct AlignedBuffer {
+    raw: Vec<u8>,
+    offset: usize,
+    len: usize,
+}
+
+impl AlignedBuffer {
+    /// `len` is rounded up to a multiple of `DIRECT_IO_ALIGN`
+    pub fn new(len: usize) -> Self {
+        let len = len.max(1).div_ceil(DIRECT_IO_ALIGN) * DIRECT_IO_ALIGN;
+        let raw = vec![0u8; len + DIRECT_IO_ALIGN];
+        let offset = raw.as_ptr().align_offset(DIRECT_IO_ALIGN);
+        Self { raw, offset, len }
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.raw[self.offset..self.offset + self.len]
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.raw[self.offset..self.offset + self.len]
+    }
+}
+
+/// Fill `buffer` from `file` until `wanted` bytes or end of file. Only the
+/// final read of a file may be short, which keeps every read offset aligned.
+pub fn read_block(file: &mut File, buffer: &mut AlignedBuffer, wanted: usize) -> std::io::Result<usize> {
+    let wanted = wanted.min(buffer.len);
+    let block = buffer.as_mut_slice();
+    let mut filled = 0;
+    while filled < wanted {
+        match file.read(&mut block[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled.min(wanted))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aligned_buffer() {
+        let buffer = AlignedBuffer::new(5000);
+        assert_eq!(buffer.as_slice().len(), 2 * DIRECT_IO_ALIGN);
+        assert_eq!(buffer.as_slice().as_ptr() as usize % DIRECT_IO_ALIGN, 0);
+    }
+}
@@ -0,0 +1,258 @@
+//! Project Discovery Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Locates known `.uproject` files from editor recents, Epic launcher
+//! manifests and user-supplied root directories
+
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use tracing::debug;
+use walkdir::WalkDir;
+
+/// How deep to look for `.uproject` files below a root or install location
+const ROOT_SEARCH_DEPTH: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DiscoverySource {
+    /// Editor "recently opened projects" list
+    EditorRecent,
+    /// Epic Games Launcher install manifest
+    EpicManifest,
+    /// User-supplied search root
+    SearchRoot,
+}
+
+impl DiscoverySource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiscoverySource::EditorRecent => "recent",
+            DiscoverySource::EpicManifest => "epic-manifest",
+            DiscoverySource::SearchRoot => "root",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscoveredProject {
+    pub name: String,
+    pub uproject: PathBuf,
+    pub project_root: PathBuf,
+    pub source: DiscoverySource,
+}
+
+#[derive(Debug)]
+pub struct ProjectDiscovery {
+    roots: Vec<PathBuf>,
+    include_system: bool,
+}
+
+impl ProjectDiscovery {
+    pub fn new() -> Self {
+        Self {
+            roots: Vec::new(),
+            include_system: true,
+        }
+    }
+
+    pub fn with_roots(mut self, roots: Vec<PathBuf>) -> Self {
+        self.roots = roots;
+        self
+    }
+
+    /// Whether to look at editor recents and launcher manifests
+    pub fn include_system(mut self, include: bool) -> Self {
+        self.include_system = include;
+        self
+    }
+
+    pub fn discover(&self) -> Result<Vec<DiscoveredProject>> {
+        let mut found = Vec::new();
+
+        if self.include_system {
+            for ini in editor_settings_files() {
+                for path in parse_recent_projects(&std::fs::read_to_string(&ini).unwrap_or_default()) {
+                    found.push((path, DiscoverySource::EditorRecent));
+                }
+            }
+
+            for install in epic_install_locations() {
+                for path in find_uprojects(&install) {
+                    found.push((path, DiscoverySource::EpicManifest));
+                }
+            }
+        }
+
+        for root in &self.roots {
+            for path in find_uprojects(root) {
+                found.push((path, DiscoverySource::SearchRoot));
+            }
+        }
+
+        // Keep the first source per project, skipping stale recents
+        let mut seen = HashSet::new();
+        let projects = found
+            .into_iter()
+            .filter(|(path, _)| path.is_file())
+            .filter(|(path, _)| {
+                let key = path.canonicalize().unwrap_or_else(|_| path.clone());
+                seen.insert(key)
+            })
+            .map(|(uproject, source)| DiscoveredProject {
+                name: uproject
+                    .file_stem()
+                    .map(|s| s.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+                project_root: uproject.parent().map(Path::to_path_buf).unwrap_or_default(),
+                uproject,
+                source,
+            })
+            .collect();
+
+        Ok(projects)
+    }
+}
+
+impl Default for ProjectDiscovery {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn find_uprojects(root: &Path) -> Vec<PathBuf> {
+    WalkDir::new(root)
+        .max_depth(ROOT_SEARCH_DEPTH)
+        .into_iter()
+        .filter_entry(|e| !is_skipped_dir(e.path()))
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter(|e| {
+            e.path()
+                .extension()
+                .map(|ext| ext.eq_ignore_ascii_case("uproject"))
+                .unwrap_or(false)
+        })
+        .map(|e| e.into_path())
+        .collect()
+}
+
+/// Build output and VCS folders never contain project descriptors
+fn is_skipped_dir(path: &Path) -> bool {
+    matches!(
+        path.file_name().and_then(|n| n.to_str()),
+        Some("Intermediate" | "Saved" | "Binaries" | "DerivedDataCache" | ".git" | ".uefast")
+    )
+}
+
+/// Extract `.uproject` paths from an EditorSettings.ini. Handles both the UE4
+/// `RecentlyOpenedProjectFiles=path` and UE5 `(ProjectName="path",...)` forms.
+pub fn parse_recent_projects(ini: &str) -> Vec<PathBuf> {
+    ini.lines()
+        .filter(|line| line.contains("RecentlyOpenedProjectFiles"))
+        .filter_map(|line| {
+            let value = line.split_once('=')?.1;
+            let end = value.to_ascii_lowercase().find(".uproject")? + ".uproject".len();
+            let start = value[..end]
+                .rfind(['"', '='])
+                .map(|i| i + 1)
+                .unwrap_or(0);
+            let path = value[start..end].trim_start_matches('(').trim();
+            Some(PathBuf::from(path))
+        })
+        .collect()
+}
+
+/// Per-engine-version EditorSettings.ini files for the current user
+fn editor_settings_files() -> Vec<PathBuf> {
+    let (base, platform) = if cfg!(windows) {
+        (std::env::var_os("LOCALAPPDATA").map(|d| PathBuf::from(d).join("UnrealEngine")), "Windows")
+    } else if cfg!(target_os = "macos") {
+        (
+            std::env::var_os("HOME")
+                .map(|h| PathBuf::from(h).join("Library/Preferences/Unreal Engine")),
+            "Mac",
+        )
+    } else {
+        (
+            std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config/Epic/UnrealEngine")),
+            "Linux",
+        )
+    };
+
+    let Some(base) = base else {
+        return Vec::new();
+    };
+
+    let Ok(versions) = std::fs::read_dir(&base) else {
+        return Vec::new();
+    };
+
+    versions
+        .filter_map(|e| e.ok())
+        .map(|e| {
+            e.path()
+                .join("Saved/Config")
+                .join(platform)
+                .join("EditorSettings.ini")
+        })
+        .filter(|p| p.is_file())
+        .collect()
+}
+
+/// Install locations listed in the Epic Games Launcher's `.item` manifests
+fn epic_install_locations() -> Vec<PathBuf> {
+    let manifest_dir = if cfg!(windows) {
+        std::env::var_os("PROGRAMDATA")
+            .map(|d| PathBuf::from(d).join("Epic/EpicGamesLauncher/Data/Manifests"))
+    } else if cfg!(target_os = "macos") {
+        std::env::var_os("HOME").map(|h| {
+            PathBuf::from(h)
+                .join("Library/Application Support/Epic/EpicGamesLauncher/Data/Manifests")
+        })
+    } else {
+        None
+    };
+
+    let Some(dir) = manifest_dir else {
+        return Vec::new();
+    };
+
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().map(|e| e == "item").unwrap_or(false))
+        .filter_map(|p| {
+            let text = std::fs::read_to_string(&p).ok()?;
+            let manifest: serde_json::Value = serde_json::from_str(&text).ok()?;
+            let location = manifest.get("InstallLocation")?.as_str()?;
+            debug!("Epic manifest {} -> {}", p.display(), location);
+            Some(PathBuf::from(location))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_recent_projects() {
+        let ini = r#"
+[/Script/UnrealEd.EditorSettings]
+RecentlyOpenedProjectFiles=(ProjectName="D:/Projects/Shooter/Shooter.uproject",LastOpenTime=2026.01.02-10.00.00)
+RecentlyOpenedProjectFiles=C:/Old/Legacy.uproject
+bCopyStarterContentPreference=True
+"#;
+        let projects = parse_recent_projects(ini);
+        assert_eq!(projects, vec![
+            PathBuf::from("D:/Projects/Shooter/Shooter.uproject"),
+            PathBuf::from("C:/Old/Legacy.uproject"),
+        ]);
+    }
+}
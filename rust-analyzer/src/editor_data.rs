@@ -0,0 +1,491 @@
+//! Editor Data Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Editor-side truth the package parser cannot see: asset registry
+//! dependencies (maps included) and Asset Manager primary asset rules.
+//! `export-editor-script` writes an Unreal Python script that dumps them;
+//! with `--editor-data` the dump replaces package parsing for every package
+//! it covers, and its asset classes replace extension-based types.
+
+use crate::graph::DependencyGraph;
+use crate::scanner::{package_name_from_relative, AssetInfo, AssetType};
+use crate::{FastStartupError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Bumped whenever the exported layout changes
+pub const EDITOR_DATA_VERSION: u32 = 1;
+
+/// Where the generated script writes, relative to the project root
+pub const DEFAULT_EDITOR_DATA: &str = "Saved/UEFast/editor_data.json";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PackageDependencies {
+    /// Class of the package's main asset (`Texture2D`, `WidgetBlueprint`, ...)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub class: String,
+    #[serde(default)]
+    pub hard: Vec<String>,
+    #[serde(default)]
+    pub soft: Vec<String>,
+    /// Asset registry tags sizing the asset in memory (`Dimensions`,
+    /// `Format`, `Vertices`, `Duration`, ...)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tags: BTreeMap<String, String>,
+    /// Chunks the asset registry assigns the package, when the registry
+    /// carries them (cooked registries, `PrimaryAssetLabel`s)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chunks: Vec<i32>,
+}
+
+/// One `PrimaryAssetTypesToScan` entry of the Asset Manager settings
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PrimaryAssetType {
+    pub primary_asset_type: String,
+    #[serde(default)]
+    pub directories: Vec<String>,
+    /// Packages listed one by one
+    #[serde(default)]
+    pub specific_assets: Vec<String>,
+    #[serde(default)]
+    pub cook_rule: String,
+    #[serde(default)]
+    pub chunk_id: i32,
+}
+
+/// One `PrimaryAssetRules` override, resolved to its packages by the editor
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PrimaryAssetRule {
+    pub primary_asset_id: String,
+    #[serde(default)]
+    pub packages: Vec<String>,
+    #[serde(default)]
+    pub cook_rule: String,
+    #[serde(default)]
+    pub chunk_id: i32,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EditorData {
+    pub format_version: u32,
+    #[serde(default)]
+    pub engine_version: String,
+    #[serde(default)]
+    pub exported_at: String,
+    /// Package name (`/Game/Maps/Entry`) -> its registry dependencies
+    pub packages: BTreeMap<String, PackageDependencies>,
+    #[serde(default)]
+    pub primary_asset_types: Vec<PrimaryAssetType>,
+    #[serde(default)]
+    pub primary_asset_rules: Vec<PrimaryAssetRule>,
+}
+
+/// Asset in the startup closure that cooking leaves out
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NeverCookedAsset {
+    pub relative_path: String,
+    /// Primary asset type or id whose rule excludes it
+    pub rule: String,
+    pub cook_rule: String,
+}
+
+/// What `EditorData::apply` added to a graph
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EditorDataStats {
+    pub packages: usize,
+    /// Exported packages found among the scanned assets
+    pub matched: usize,
+    pub edges_added: usize,
+}
+
+/// Asset type of an editor asset class, for the classes `AssetType` names
+pub fn class_asset_type(class: &str) -> Option<AssetType> {
+    let asset_type = if class.starts_with("Texture") {
+        AssetType::Texture
+    } else if class.ends_with("Blueprint") {
+        AssetType::Blueprint
+    } else if class.starts_with("Material") {
+        AssetType::Material
+    } else if class.starts_with("Anim") || class.starts_with("BlendSpace") {
+        AssetType::Animation
+    } else if class.starts_with("Sound") {
+        AssetType::Audio
+    } else {
+        return None;
+    };
+    Some(asset_type)
+}
+
+/// Whether a cook rule keeps the asset out of shipping builds
+/// (`NeverCook`, `DevelopmentAlwaysProductionNeverCook`, `NEVER_COOK`, ...)
+fn is_never_cook(cook_rule: &str) -> bool {
+    cook_rule.replace('_', "").to_ascii_lowercase().ends_with("nevercook")
+}
+
+impl EditorData {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        let data: Self = serde_json::from_str(&text)
+            .map_err(|e| FastStartupError::SerializationError(format!("{}: {}", path.display(), e)))?;
+        if data.format_version > EDITOR_DATA_VERSION {
+            return Err(FastStartupError::ConfigError(format!(
+                "{} has format version {}, this build reads up to {}; upgrade ue5-fast-startup",
+                path.display(),
+                data.format_version,
+                EDITOR_DATA_VERSION
+            )));
+        }
+        Ok(data)
+    }
+
+    /// Assets whose package the export covers, so they need no parse
+    pub fn covered_paths(&self, assets: &[AssetInfo]) -> HashSet<PathBuf> {
+        package_paths(assets)
+            .into_iter()
+            .filter(|(package, _)| self.packages.contains_key(package))
+            .map(|(_, path)| path)
+            .collect()
+    }
+
+    /// Editor class of `asset`, if the export has one
+    pub fn class_of(&self, asset: &AssetInfo) -> Option<&str> {
+        let package = package_name_from_relative(&asset.relative_path)?;
+        let class = self.packages.get(&package)?.class.as_str();
+        (!class.is_empty()).then_some(class)
+    }
+
+    /// Registry tags of `asset`, if the export has any
+    pub fn tags_of(&self, asset: &AssetInfo) -> Option<&BTreeMap<String, String>> {
+        let package = package_name_from_relative(&asset.relative_path)?;
+        let tags = &self.packages.get(&package)?.tags;
+        (!tags.is_empty()).then_some(tags)
+    }
+
+    /// Type of `asset` by its editor class, falling back to its extension
+    pub fn asset_type(&self, asset: &AssetInfo) -> AssetType {
+        match asset.asset_type {
+            AssetType::UAsset => self.class_of(asset).and_then(class_asset_type).unwrap_or(asset.asset_type),
+            other => other,
+        }
+    }
+
+    /// Add the registry dependencies among `assets` to `graph`, skipping
+    /// edges the package parser already found
+    pub fn apply(&self, graph: &mut DependencyGraph, assets: &[AssetInfo]) -> EditorDataStats {
+        let by_package = package_paths(assets);
+        let mut stats = EditorDataStats { packages: self.packages.len(), ..Default::default() };
+        for (package, dependencies) in &self.packages {
+            let Some(from) = by_package.get(package.as_str()) else {
+                continue;
+            };
+            stats.matched += 1;
+            let edges = dependencies.hard.iter().map(|d| (d, true)).chain(dependencies.soft.iter().map(|d| (d, false)));
+            for (dependency, is_hard) in edges {
+                if let Some(to) = by_package.get(dependency.as_str()) {
+                    if graph.add_registry_dependency(from, to, is_hard) {
+                        stats.edges_added += 1;
+                    }
+                }
+            }
+        }
+        stats
+    }
+
+    /// Primary asset rule covering `package` as (type or id, cook rule,
+    /// chunk id); an override on a primary asset id wins over its type's rule
+    fn primary_rule(&self, package: &str) -> Option<(&str, &str, i32)> {
+        if let Some(r) = self.primary_asset_rules.iter().find(|r| r.packages.iter().any(|p| p == package)) {
+            return Some((r.primary_asset_id.as_str(), r.cook_rule.as_str(), r.chunk_id));
+        }
+        self.primary_asset_types
+            .iter()
+            .find(|t| {
+                t.specific_assets.iter().any(|s| s.split('.').next() == Some(package))
+                    || t.directories.iter().any(|d| package.starts_with(&format!("{}/", d.trim_end_matches('/'))))
+            })
+            .map(|t| (t.primary_asset_type.as_str(), t.cook_rule.as_str(), t.chunk_id))
+    }
+
+    /// `assets` that a never-cook rule excludes from shipping builds
+    pub fn never_cooked(&self, assets: &[AssetInfo]) -> Vec<NeverCookedAsset> {
+        let mut never: Vec<NeverCookedAsset> = assets
+            .iter()
+            .filter_map(|asset| {
+                let package = package_name_from_relative(&asset.relative_path)?;
+                let (rule, cook_rule, _) = self.primary_rule(&package)?;
+                is_never_cook(cook_rule).then(|| NeverCookedAsset {
+                    relative_path: asset.relative_path.clone(),
+                    rule: rule.to_string(),
+                    cook_rule: cook_rule.to_string(),
+                })
+            })
+            .collect();
+        never.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+        never.dedup_by(|a, b| a.relative_path == b.relative_path);
+        never
+    }
+
+    /// Chunk of `asset` as (chunk id, source): the registry's own chunk
+    /// ids win, then the ChunkId of its primary asset rule
+    pub fn chunk_of(&self, asset: &AssetInfo) -> Option<(i32, String)> {
+        let package = package_name_from_relative(&asset.relative_path)?;
+        if let Some(chunk) = self.packages.get(&package).and_then(|p| p.chunks.iter().min()) {
+            return Some((*chunk, "registry".to_string()));
+        }
+        let (rule, _, chunk) = self.primary_rule(&package)?;
+        Some((chunk, rule.to_string()))
+    }
+}
+
+/// Package name -> absolute path of the `.uasset`/`.umap` holding it
+fn package_paths(assets: &[AssetInfo]) -> HashMap<String, PathBuf> {
+    assets
+        .iter()
+        .filter(|a| matches!(a.asset_type, AssetType::UAsset | AssetType::UMap))
+        .filter_map(|a| package_name_from_relative(&a.relative_path).map(|p| (p, a.path.clone())))
+        .collect()
+}
+
+/// Unreal Python script exporting the editor data to `output` (relative
+/// paths resolve against the project directory)
+pub fn editor_script(output: &str) -> String {
+    EDITOR_SCRIPT
+        .replace("{tool_version}", crate::VERSION)
+        .replace("{format_version}", &EDITOR_DATA_VERSION.to_string())
+        .replace("{output}", &output.replace('\\', "/"))
+}
+
+const EDITOR_SCRIPT: &str = r#"# Generated by ue5-fast-startup {tool_version}.
+# Exports asset registry dependencies and primary asset rules for
+# `ue5-fast-startup analyze --editor-data`. Run it in the editor
+# (Tools > Execute Python Script) or headless:
+#   UnrealEditor-Cmd MyGame.uproject -run=pythonscript -script="<this file>"
+import datetime
+import json
+import os
+
+import unreal
+
+OUTPUT = "{output}"
+# Registry tags the resident memory estimate reads
+MEMORY_TAGS = ["Dimensions", "Format", "Vertices", "Triangles", "Duration", "SampleRate", "NumChannels"]
+
+
+def prop(obj, name, default=None):
+    try:
+        return obj.get_editor_property(name)
+    except Exception:
+        return default
+
+
+def enum_name(value):
+    return getattr(value, "name", str(value)) if value is not None else ""
+
+
+def type_name(value):
+    # FPrimaryAssetType wraps its FName; plain FNames are used as they are
+    return str(prop(value, "name", value)) if value is not None else ""
+
+
+def chunk_ids(asset):
+    # Filled by cooked registries and PrimaryAssetLabels; empty otherwise
+    ids = getattr(asset, "chunk_ids", None) or prop(asset, "chunk_ids", []) or []
+    return sorted({int(i) for i in ids})
+
+
+def memory_tags(asset):
+    tags = {}
+    for name in MEMORY_TAGS:
+        value = asset.get_tag_value(name)
+        # Older engines return (found, value)
+        if isinstance(value, tuple):
+            value = value[1] if value[0] else None
+        if value:
+            tags[name] = str(value)
+    return tags
+
+
+def content_roots():
+    # /Game plus the mounts of the project's own plugins, named after their .uplugin
+    roots = ["/Game"]
+    for folder, _, files in os.walk(os.path.join(unreal.Paths.project_dir(), "Plugins")):
+        for name in files:
+            if name.endswith(".uplugin") and os.path.isdir(os.path.join(folder, "Content")):
+                roots.append("/" + name[:-len(".uplugin")])
+    return roots
+
+
+def dependencies(registry, package, hard, roots):
+    options = unreal.AssetRegistryDependencyOptions(
+        include_soft_package_references=not hard,
+        include_hard_package_references=hard,
+        include_searchable_names=False,
+        include_soft_management_references=False,
+        include_hard_management_references=False,
+    )
+    found = registry.get_dependencies(package, options) or []
+    prefixes = tuple(root + "/" for root in roots)
+    return sorted({str(p) for p in found if str(p).startswith(prefixes)})
+
+
+def main():
+    registry = unreal.AssetRegistryHelpers.get_asset_registry()
+    registry.search_all_assets(True)
+
+    roots = content_roots()
+    by_name = {}
+    packages = {}
+    for root in roots:
+        for asset in registry.get_assets_by_path(root, recursive=True):
+            package = str(asset.package_name)
+            by_name.setdefault(str(asset.asset_name), []).append(package)
+            if package not in packages:
+                class_path = getattr(asset, "asset_class_path", None)
+                packages[package] = {
+                    "class": str(class_path.asset_name) if class_path else str(asset.asset_class),
+                    "hard": dependencies(registry, package, True, roots),
+                    "soft": dependencies(registry, package, False, roots),
+                    "tags": memory_tags(asset),
+                    "chunks": chunk_ids(asset),
+                }
+
+    settings = unreal.get_default_object(unreal.AssetManagerSettings)
+    types = []
+    for info in prop(settings, "primary_asset_types_to_scan", []) or []:
+        rules = prop(info, "rules")
+        types.append({
+            "primary_asset_type": type_name(prop(info, "primary_asset_type")),
+            "directories": [str(prop(d, "path", "")) for d in prop(info, "directories", []) or []],
+            "specific_assets": [str(a) for a in prop(info, "specific_assets", []) or []],
+            "cook_rule": enum_name(prop(rules, "cook_rule")) if rules else "",
+            "chunk_id": int(prop(rules, "chunk_id", -1)) if rules else -1,
+        })
+
+    overrides = []
+    for override in prop(settings, "primary_asset_rules", []) or []:
+        asset_id = prop(override, "primary_asset_id")
+        name = str(prop(asset_id, "primary_asset_name", "")) if asset_id else ""
+        rules = prop(override, "rules")
+        overrides.append({
+            "primary_asset_id": "{}:{}".format(type_name(prop(asset_id, "primary_asset_type")), name) if asset_id else "",
+            "packages": sorted(set(by_name.get(name, []))),
+            "cook_rule": enum_name(prop(rules, "cook_rule")) if rules else "",
+            "chunk_id": int(prop(rules, "chunk_id", -1)) if rules else -1,
+        })
+
+    output = OUTPUT if os.path.isabs(OUTPUT) else os.path.join(unreal.Paths.project_dir(), OUTPUT)
+    os.makedirs(os.path.dirname(os.path.abspath(output)), exist_ok=True)
+    with open(output, "w", encoding="utf-8") as f:
+        json.dump({
+            "format_version": {format_version},
+            "engine_version": unreal.SystemLibrary.get_engine_version(),
+            "exported_at": datetime.datetime.utcnow().isoformat() + "Z",
+            "packages": packages,
+            "primary_asset_types": types,
+            "primary_asset_rules": overrides,
+        }, f, indent=1, sort_keys=True)
+    unreal.log("ue5-fast-startup: {} packages written to {}".format(len(packages), os.path.abspath(output)))
+
+
+main()
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunks::late_chunk_assets;
+
+    fn asset(relative_path: &str, asset_type: AssetType) -> AssetInfo {
+        AssetInfo {
+            path: PathBuf::from("/p").join(relative_path),
+            relative_path: relative_path.to_string(),
+            asset_type,
+            size_bytes: 1,
+            modified: 0,
+            mount_point: "/Game".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_apply_editor_data() {
+        let json = r#"{
+            "format_version": 1,
+            "packages": {
+                "/Game/Maps/Entry": {"class": "World", "hard": ["/Game/UI/Boot", "/Script/Engine"], "soft": ["/Game/Debug/Cheats"]},
+                "/Game/UI/Boot": {"class": "WidgetBlueprint"},
+                "/Game/Gone/Old": {"hard": ["/Game/UI/Boot"]}
+            },
+            "primary_asset_types": [
+                {"primary_asset_type": "Map", "directories": ["/Game/Maps"], "cook_rule": "ALWAYS_COOK"},
+                {"primary_asset_type": "DebugData", "directories": ["/Game/Debug/"], "cook_rule": "DEVELOPMENT_ALWAYS_PRODUCTION_NEVER_COOK"}
+            ],
+            "primary_asset_rules": [
+                {"primary_asset_id": "DebugData:Allowed", "packages": ["/Game/Debug/Allowed"], "cook_rule": "ALWAYS_COOK"}
+            ]
+        }"#;
+        let data: EditorData = serde_json::from_str(json).unwrap();
+        let assets = vec![
+            asset("Content/Maps/Entry.umap", AssetType::UMap),
+            asset("Content/UI/Boot.uasset", AssetType::UAsset),
+            asset("Content/Debug/Cheats.uasset", AssetType::UAsset),
+            asset("Content/Debug/Allowed.uasset", AssetType::UAsset),
+        ];
+        let mut graph = DependencyGraph::new();
+        for a in &assets {
+            graph.add_asset(a);
+        }
+
+        let stats = data.apply(&mut graph, &assets);
+        assert_eq!((stats.packages, stats.matched, stats.edges_added), (3, 2, 2));
+        assert_eq!(graph.coverage().registry_edges, 2);
+        assert_eq!(graph.get_dependencies(&assets[0].path).len(), 2);
+        // Applying twice adds nothing new
+        assert_eq!(data.apply(&mut graph, &assets).edges_added, 0);
+
+        assert_eq!(data.covered_paths(&assets).len(), 2);
+        assert_eq!(data.asset_type(&assets[1]), AssetType::Blueprint);
+        assert_eq!(data.asset_type(&assets[2]), AssetType::UAsset);
+
+        let never = data.never_cooked(&assets);
+        assert_eq!(never.len(), 1);
+        assert_eq!(never[0].relative_path, "Content/Debug/Cheats.uasset");
+        assert_eq!(never[0].rule, "DebugData");
+
+        let script = editor_script("Saved/UEFast/editor_data.json");
+        assert!(script.contains("OUTPUT = \"Saved/UEFast/editor_data.json\""));
+        assert!(script.contains("\"format_version\": 1,"));
+    }
+
+    #[test]
+    fn test_chunk_of() {
+        let json = r#"{
+            "format_version": 1,
+            "packages": {
+                "/Game/UI/Boot": {"chunks": [3, 2]},
+                "/Game/UI/Font": {}
+            },
+            "primary_asset_types": [
+                {"primary_asset_type": "Map", "directories": ["/Game/Maps"], "chunk_id": -1},
+                {"primary_asset_type": "UI", "directories": ["/Game/UI"], "chunk_id": 4}
+            ],
+            "primary_asset_rules": [
+                {"primary_asset_id": "UI:Logo", "packages": ["/Game/UI/Logo"], "chunk_id": 0}
+            ]
+        }"#;
+        let data: EditorData = serde_json::from_str(json).unwrap();
+        let assets = vec![
+            asset("Content/Maps/Entry.umap", AssetType::UMap),
+            asset("Content/UI/Boot.uasset", AssetType::UAsset),
+            asset("Content/UI/Font.uasset", AssetType::UAsset),
+            asset("Content/UI/Logo.uasset", AssetType::UAsset),
+            asset("Content/Props/Rock.uasset", AssetType::UAsset),
+        ];
+
+        let late = late_chunk_assets(&assets, |a| data.chunk_of(a));
+        let found: Vec<(&str, i32, &str)> =
+            late.iter().map(|l| (l.relative_path.as_str(), l.chunk_id, l.source.as_str())).collect();
+        assert_eq!(found, [("Content/UI/Boot.uasset", 2, "registry"), ("Content/UI/Font.uasset", 4, "UI")]);
+    }
+}
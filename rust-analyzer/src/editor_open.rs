@@ -0,0 +1,238 @@
+//! Editor Open Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! What opening one asset in the editor loads: the asset and its hard
+//! dependency closure, with sizes, a per-type breakdown and the estimated
+//! load time. The per-asset variant of the startup closure question, for
+//! the heavy assets artists open all day.
+
+use crate::editor_data::EditorData;
+use crate::estimator::LoadTimeModel;
+use crate::graph::DependencyGraph;
+use crate::report::{Finding, ReportData, Severity, Table};
+use crate::scanner::{package_stem, AssetInfo, AssetType};
+use crate::{FastStartupError, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+const MB: f64 = 1024.0 * 1024.0;
+
+/// Heaviest dependencies listed per opened asset
+pub const MAX_HEAVIEST: usize = 20;
+
+/// Opening an asset that loads more than this (512 MB) is flagged
+pub const EDITOR_OPEN_WARN_BYTES: u64 = 512 * 1024 * 1024;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TypeLoad {
+    pub assets: usize,
+    pub size_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadedDependency {
+    pub relative_path: String,
+    pub asset_type: AssetType,
+    pub size_bytes: u64,
+}
+
+/// Load cost of opening one asset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenedAsset {
+    pub relative_path: String,
+    /// The asset itself included
+    pub loaded_assets: usize,
+    pub loaded_bytes: u64,
+    pub estimated_seconds: f64,
+    /// Keyed by `AssetType::as_str`
+    pub by_type: BTreeMap<String, TypeLoad>,
+    /// Largest first, at most `MAX_HEAVIEST`
+    pub heaviest: Vec<LoadedDependency>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EditorOpenReport {
+    pub project_name: String,
+    pub opened: Vec<OpenedAsset>,
+    /// Requested assets that match no package
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unmatched: Vec<String>,
+}
+
+impl EditorOpenReport {
+    /// Closure of each of `targets` (project-relative or absolute paths, or
+    /// `/Game/` package names) in `graph`, priced with `model`; editor data
+    /// classes refine the per-type breakdown
+    pub fn build(
+        project_name: &str,
+        project_root: &Path,
+        graph: &DependencyGraph,
+        assets: &[AssetInfo],
+        editor_data: Option<&EditorData>,
+        targets: &[String],
+        model: &LoadTimeModel,
+    ) -> Self {
+        let by_path: HashMap<&Path, &AssetInfo> = assets.iter().map(|a| (a.path.as_path(), a)).collect();
+        let by_stem: HashMap<String, &AssetInfo> = assets
+            .iter()
+            .filter(|a| matches!(a.asset_type, AssetType::UAsset | AssetType::UMap))
+            .map(|a| (package_stem(Path::new(""), &a.relative_path), a))
+            .collect();
+
+        let mut report = Self { project_name: project_name.to_string(), ..Self::default() };
+        for target in targets {
+            let Some(asset) = by_stem.get(&package_stem(project_root, target)) else {
+                report.unmatched.push(target.clone());
+                continue;
+            };
+
+            let roots: HashSet<PathBuf> = [asset.path.clone()].into_iter().collect();
+            let mut loaded: Vec<&AssetInfo> = graph
+                .hard_closure_of(&roots)
+                .iter()
+                .filter_map(|path| by_path.get(path.as_path()).copied())
+                .collect();
+            loaded.sort_by(|a, b| b.size_bytes.cmp(&a.size_bytes).then(a.relative_path.cmp(&b.relative_path)));
+
+            let asset_type = |a: &AssetInfo| editor_data.map(|d| d.asset_type(a)).unwrap_or(a.asset_type);
+            let mut by_type: BTreeMap<String, TypeLoad> = BTreeMap::new();
+            for dependency in &loaded {
+                let load = by_type.entry(asset_type(dependency).as_str().to_string()).or_default();
+                load.assets += 1;
+                load.size_bytes += dependency.size_bytes;
+            }
+            report.opened.push(OpenedAsset {
+                relative_path: asset.relative_path.replace('\\', "/"),
+                loaded_assets: loaded.len(),
+                loaded_bytes: loaded.iter().map(|a| a.size_bytes).sum(),
+                estimated_seconds: model.estimate_ms(loaded.iter().copied()) / 1000.0,
+                by_type,
+                heaviest: loaded
+                    .iter()
+                    .filter(|a| a.path != asset.path)
+                    .take(MAX_HEAVIEST)
+                    .map(|a| LoadedDependency {
+                        relative_path: a.relative_path.replace('\\', "/"),
+                        asset_type: asset_type(a),
+                        size_bytes: a.size_bytes,
+                    })
+                    .collect(),
+            });
+        }
+        report
+    }
+}
+
+impl ReportData for EditorOpenReport {
+    fn title(&self) -> String {
+        format!("Editor open cost: {}", self.project_name)
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        serde_json::to_value(self).map_err(|e| FastStartupError::SerializationError(e.to_string()))
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        let heaviest = self.opened.iter().max_by_key(|o| o.loaded_bytes);
+        vec![
+            ("Opened assets".to_string(), self.opened.len().to_string()),
+            (
+                "Heaviest open".to_string(),
+                heaviest
+                    .map(|o| format!("{} ({:.1} MB, ~{:.1}s)", o.relative_path, o.loaded_bytes as f64 / MB, o.estimated_seconds))
+                    .unwrap_or_default(),
+            ),
+            ("Unmatched".to_string(), self.unmatched.len().to_string()),
+        ]
+    }
+
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["asset", "loaded_assets", "loaded_mb", "estimated_seconds", "heaviest_dependency"]);
+        for opened in &self.opened {
+            table.push(vec![
+                opened.relative_path.clone(),
+                opened.loaded_assets.to_string(),
+                format!("{:.2}", opened.loaded_bytes as f64 / MB),
+                format!("{:.2}", opened.estimated_seconds),
+                opened.heaviest.first().map(|d| d.relative_path.clone()).unwrap_or_default(),
+            ]);
+        }
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        let mut findings: Vec<Finding> = self
+            .opened
+            .iter()
+            .filter(|o| o.loaded_bytes > EDITOR_OPEN_WARN_BYTES)
+            .map(|o| Finding {
+                rule_id: "editor-open/heavy".to_string(),
+                severity: Severity::Warning,
+                message: format!(
+                    "Opening loads {} assets ({:.0} MB); soft-reference content the editor does not need up front",
+                    o.loaded_assets,
+                    o.loaded_bytes as f64 / MB
+                ),
+                location: Some(o.relative_path.clone()),
+            })
+            .collect();
+        findings.extend(self.unmatched.iter().map(|target| Finding {
+            rule_id: "editor-open/unmatched".to_string(),
+            severity: Severity::Warning,
+            message: format!("{} matches no package in the project", target),
+            location: None,
+        }));
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::DependencyType;
+
+    fn asset(relative_path: &str, size_bytes: u64) -> AssetInfo {
+        AssetInfo {
+            path: PathBuf::from("/p").join(relative_path),
+            relative_path: relative_path.to_string(),
+            asset_type: AssetType::from_extension(relative_path.rsplit('.').next().unwrap_or("")),
+            size_bytes,
+            modified: 0,
+            mount_point: "/Game".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_editor_open_closure() {
+        let assets = vec![
+            asset("Content/Chars/Hero.uasset", 100),
+            asset("Content/Chars/HeroMesh.uasset", 600 * 1024 * 1024),
+            asset("Content/Chars/HeroVoice.uasset", 5000),
+            asset("Content/Props/Crate.uasset", 10),
+        ];
+        let mut graph = DependencyGraph::new();
+        for a in &assets {
+            graph.add_asset(a);
+        }
+        graph.add_dependency(&assets[0].path, &assets[1].path, DependencyType::Import, true);
+        graph.add_dependency(&assets[0].path, &assets[2].path, DependencyType::SoftReference, false);
+
+        let targets = vec!["/Game/Chars/Hero.Hero".to_string(), "Content/Props/Crate.uasset".to_string(), "/Game/Nope".to_string()];
+        let model = LoadTimeModel::default();
+        let report = EditorOpenReport::build("Game", Path::new("/p"), &graph, &assets, None, &targets, &model);
+
+        let hero = &report.opened[0];
+        assert_eq!(hero.loaded_assets, 2);
+        assert_eq!(hero.loaded_bytes, 600 * 1024 * 1024 + 100);
+        assert_eq!(hero.heaviest[0].relative_path, "Content/Chars/HeroMesh.uasset");
+        assert_eq!(hero.by_type["uasset"].assets, 2);
+        assert_eq!(report.opened[1].loaded_assets, 1);
+        assert_eq!(report.unmatched, ["/Game/Nope"]);
+
+        let rules: Vec<String> = report.findings().into_iter().map(|f| f.rule_id).collect();
+        assert_eq!(rules, ["editor-open/heavy", "editor-open/unmatched"]);
+    }
+}
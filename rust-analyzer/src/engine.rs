@@ -0,0 +1,317 @@
+//! Engine Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Locate the engine a project is associated with (launcher installs,
+//! registered source builds, or an engine tree around the project) and run
+//! its commandlets headless with a captured log
+
+use crate::project::Project;
+use crate::{FastStartupError, Result};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+use tracing::debug;
+
+/// Engine root used instead of the association when set
+pub const ENGINE_DIR_ENV: &str = "UE_ENGINE_DIR";
+
+/// Flags every headless commandlet run gets
+const COMMANDLET_FLAGS: &[&str] = &["-unattended", "-nop4", "-nosplash", "-NullRHI", "-stdout", "-FullStdOutLogOutput"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EngineSource {
+    /// `--engine` or `UE_ENGINE_DIR`
+    Override,
+    /// Epic Games Launcher install of the associated version
+    Launcher,
+    /// Source build registered under the association's id
+    Registered,
+    /// Engine tree the project lives in (empty association)
+    Enclosing,
+}
+
+impl EngineSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EngineSource::Override => "override",
+            EngineSource::Launcher => "launcher",
+            EngineSource::Registered => "registered build",
+            EngineSource::Enclosing => "enclosing engine tree",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EngineInstall {
+    /// Directory holding `Engine/`
+    pub root: PathBuf,
+    pub association: String,
+    pub source: EngineSource,
+}
+
+/// Outcome of one commandlet run
+#[derive(Debug, Clone, Serialize)]
+pub struct CommandletRun {
+    pub commandlet: String,
+    pub exit_code: Option<i32>,
+    pub log_path: PathBuf,
+    pub errors: usize,
+    pub warnings: usize,
+    pub seconds: f64,
+}
+
+impl CommandletRun {
+    pub fn succeeded(&self) -> bool {
+        self.exit_code == Some(0)
+    }
+}
+
+impl EngineInstall {
+    /// Engine for `project`: `override_dir`, then `UE_ENGINE_DIR`, then its
+    /// `EngineAssociation`
+    pub fn locate(project: &Project, override_dir: Option<&Path>) -> Result<Self> {
+        let association = project.engine_association().to_string();
+        let override_dir = override_dir
+            .map(Path::to_path_buf)
+            .or_else(|| std::env::var_os(ENGINE_DIR_ENV).map(PathBuf::from));
+        if let Some(root) = override_dir {
+            return Self::checked(engine_root(&root), association, EngineSource::Override);
+        }
+
+        if association.is_empty() {
+            return project
+                .root
+                .ancestors()
+                .find(|dir| dir.join("Engine/Binaries").is_dir())
+                .map(|root| Self { root: root.to_path_buf(), association, source: EngineSource::Enclosing })
+                .ok_or_else(|| {
+                    FastStartupError::ConfigError(format!(
+                        "{} has no EngineAssociation and no engine tree encloses it; pass --engine or set {}",
+                        project.uproject_path.display(),
+                        ENGINE_DIR_ENV
+                    ))
+                });
+        }
+
+        if let Some(root) = registered_build(&association) {
+            return Self::checked(root, association, EngineSource::Registered);
+        }
+        if let Some(root) = launcher_install(&association) {
+            return Self::checked(root, association, EngineSource::Launcher);
+        }
+        Err(FastStartupError::ConfigError(format!(
+            "Engine '{}' of {} is not installed or registered; pass --engine or set {}",
+            association,
+            project.uproject_path.display(),
+            ENGINE_DIR_ENV
+        )))
+    }
+
+    fn checked(root: PathBuf, association: String, source: EngineSource) -> Result<Self> {
+        if !root.join("Engine").is_dir() {
+            return Err(FastStartupError::ConfigError(format!(
+                "{} ({}) has no Engine directory",
+                root.display(),
+                source.as_str()
+            )));
+        }
+        Ok(Self { root, association, source })
+    }
+
+    /// Headless editor binary for this platform
+    pub fn editor_cmd(&self) -> PathBuf {
+        let binaries = self.root.join("Engine/Binaries");
+        if cfg!(windows) {
+            binaries.join("Win64/UnrealEditor-Cmd.exe")
+        } else if cfg!(target_os = "macos") {
+            binaries.join("Mac/UnrealEditor.app/Contents/MacOS/UnrealEditor")
+        } else {
+            binaries.join("Linux/UnrealEditor")
+        }
+    }
+
+    /// Command line running `commandlet` on `project`, logging to `log_path`
+    pub fn commandlet(&self, project: &Project, commandlet: &str, args: &[String], log_path: &Path) -> Command {
+        let mut command = Command::new(self.editor_cmd());
+        command
+            .arg(&project.uproject_path)
+            .arg(format!("-run={}", commandlet))
+            .args(args)
+            .args(COMMANDLET_FLAGS)
+            .arg(format!("-abslog={}", log_path.display()));
+        command
+    }
+
+    /// Run `commandlet` to completion; its output streams to the terminal
+    /// and the log is scanned for errors and warnings afterwards
+    pub fn run_commandlet(
+        &self,
+        project: &Project,
+        commandlet: &str,
+        args: &[String],
+        log_path: &Path,
+    ) -> Result<CommandletRun> {
+        let editor = self.editor_cmd();
+        if !editor.is_file() {
+            return Err(FastStartupError::ConfigError(format!(
+                "{} not found; build the editor target of {}",
+                editor.display(),
+                self.root.display()
+            )));
+        }
+        if let Some(parent) = log_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let mut command = self.commandlet(project, commandlet, args, log_path);
+        debug!("Running {:?}", command);
+        let started = Instant::now();
+        let status = command.status()?;
+        let (errors, warnings) = std::fs::read_to_string(log_path)
+            .map(|log| count_log_issues(&log))
+            .unwrap_or_default();
+        Ok(CommandletRun {
+            commandlet: commandlet.to_string(),
+            exit_code: status.code(),
+            log_path: log_path.to_path_buf(),
+            errors,
+            warnings,
+            seconds: started.elapsed().as_secs_f64(),
+        })
+    }
+}
+
+/// `path` itself, or its parent when it points at the `Engine` directory
+fn engine_root(path: &Path) -> PathBuf {
+    match path.file_name() {
+        Some(name) if name.eq_ignore_ascii_case("Engine") && path.join("Binaries").is_dir() => {
+            path.parent().map(Path::to_path_buf).unwrap_or_default()
+        }
+        _ => path.to_path_buf(),
+    }
+}
+
+/// `(errors, warnings)` lines of an Unreal log
+pub fn count_log_issues(log: &str) -> (usize, usize) {
+    log.lines().fold((0, 0), |(errors, warnings), line| {
+        if line.contains("Error: ") {
+            (errors + 1, warnings)
+        } else if line.contains("Warning: ") {
+            (errors, warnings + 1)
+        } else {
+            (errors, warnings)
+        }
+    })
+}
+
+/// Install location of `UE_<version>` in a `LauncherInstalled.dat` or a
+/// launcher `.item` manifest
+pub fn parse_launcher_manifest(text: &str, version: &str) -> Option<PathBuf> {
+    let manifest: serde_json::Value = serde_json::from_str(text).ok()?;
+    let app_name = format!("UE_{}", version);
+    let entries = match manifest.get("InstallationList") {
+        Some(list) => list.as_array()?.iter().collect(),
+        None => vec![&manifest],
+    };
+    entries.into_iter().find_map(|entry| {
+        if entry.get("AppName")?.as_str()? != app_name {
+            return None;
+        }
+        entry.get("InstallLocation")?.as_str().map(PathBuf::from)
+    })
+}
+
+/// Path registered for build `id` in an `Install.ini` `[Installations]` section
+pub fn parse_install_ini(text: &str, id: &str) -> Option<PathBuf> {
+    let mut in_section = false;
+    for line in text.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_section = line.eq_ignore_ascii_case("[Installations]");
+        } else if in_section {
+            if let Some((key, value)) = line.split_once('=') {
+                if key.trim().eq_ignore_ascii_case(id) {
+                    return Some(PathBuf::from(value.trim()));
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Launcher installs only carry `major.minor` associations
+fn launcher_install(association: &str) -> Option<PathBuf> {
+    if !association.split('.').all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit())) {
+        return None;
+    }
+
+    let mut manifests: Vec<PathBuf> = Vec::new();
+    let mut defaults: Vec<PathBuf> = Vec::new();
+    if cfg!(windows) {
+        if let Some(data) = std::env::var_os("PROGRAMDATA").map(PathBuf::from) {
+            manifests.push(data.join("Epic/UnrealEngineLauncher/LauncherInstalled.dat"));
+            if let Ok(items) = std::fs::read_dir(data.join("Epic/EpicGamesLauncher/Data/Manifests")) {
+                manifests.extend(items.filter_map(|e| e.ok()).map(|e| e.path()));
+            }
+        }
+        defaults.push(PathBuf::from(format!("C:/Program Files/Epic Games/UE_{}", association)));
+    } else if cfg!(target_os = "macos") {
+        manifests.push(PathBuf::from("/Users/Shared/Epic Games/UnrealEngineLauncher/LauncherInstalled.dat"));
+        if let Some(home) = std::env::var_os("HOME").map(PathBuf::from) {
+            let items = home.join("Library/Application Support/Epic/EpicGamesLauncher/Data/Manifests");
+            if let Ok(items) = std::fs::read_dir(items) {
+                manifests.extend(items.filter_map(|e| e.ok()).map(|e| e.path()));
+            }
+        }
+        defaults.push(PathBuf::from(format!("/Users/Shared/Epic Games/UE_{}", association)));
+    }
+
+    manifests
+        .iter()
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .find_map(|text| parse_launcher_manifest(&text, association))
+        .or_else(|| defaults.into_iter().find(|dir| dir.join("Engine").is_dir()))
+}
+
+/// Source builds registered by UnrealVersionSelector
+fn registered_build(id: &str) -> Option<PathBuf> {
+    if cfg!(windows) {
+        let output = Command::new("reg")
+            .args(["query", r"HKCU\Software\Epic Games\Unreal Engine\Builds", "/v", id])
+            .output()
+            .ok()?;
+        let text = String::from_utf8_lossy(&output.stdout);
+        text.lines()
+            .find_map(|line| line.split_once("REG_SZ").map(|(_, path)| PathBuf::from(path.trim())))
+    } else {
+        let config = if cfg!(target_os = "macos") { "Library/Application Support/Epic" } else { ".config/Epic" };
+        let ini = PathBuf::from(std::env::var_os("HOME")?).join(config).join("UnrealEngine/Install.ini");
+        parse_install_ini(&std::fs::read_to_string(ini).ok()?, id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_engine_registrations() {
+        let dat = r#"{"InstallationList": [
+            {"InstallLocation": "C:\\Epic\\UE_5.2", "AppName": "UE_5.2", "AppVersion": "5.2.1"},
+            {"InstallLocation": "D:\\Epic\\UE_5.3", "AppName": "UE_5.3", "AppVersion": "5.3.2"}
+        ]}"#;
+        assert_eq!(parse_launcher_manifest(dat, "5.3"), Some(PathBuf::from("D:\\Epic\\UE_5.3")));
+        assert_eq!(parse_launcher_manifest(dat, "5.4"), None);
+        let item = r#"{"AppName": "UE_5.4", "InstallLocation": "/Users/Shared/Epic Games/UE_5.4"}"#;
+        assert_eq!(parse_launcher_manifest(item, "5.4"), Some(PathBuf::from("/Users/Shared/Epic Games/UE_5.4")));
+
+        let ini = "[Other]\n{ABC}=/nope\n[Installations]\n{6A9E1C2B-0001}=/work/UnrealEngine\n";
+        assert_eq!(parse_install_ini(ini, "{6a9e1c2b-0001}"), Some(PathBuf::from("/work/UnrealEngine")));
+        assert_eq!(parse_install_ini(ini, "{ABC}"), None);
+
+        let log = "LogInit: Display: ok\nLogPython: Error: boom\nLogLinker: Warning: missing\nLogLinker: Warning: again\n";
+        assert_eq!(count_log_issues(log), (1, 2));
+    }
+}
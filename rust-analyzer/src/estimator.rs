@@ -0,0 +1,169 @@
+//! Load Time Estimator Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Linear load time model (per-asset overhead plus ms per MB per asset type)
+//! with coefficients calibrated per storage tier
+
+use crate::hardware::HardwareProfile;
+use crate::scanner::AssetInfo;
+use crate::FastStartupError;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+const MB: f64 = 1024.0 * 1024.0;
+
+/// Uncalibrated per-asset overhead (~10ms per asset)
+pub const DEFAULT_MS_PER_ASSET: f64 = 10.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageTier {
+    Nvme,
+    Ssd,
+    Hdd,
+    Network,
+    Unknown,
+}
+
+impl StorageTier {
+    /// Uncalibrated per-asset overhead on this tier. `Unknown` keeps the
+    /// historical default.
+    pub fn default_ms_per_asset(&self) -> f64 {
+        match self {
+            StorageTier::Nvme => 4.0,
+            StorageTier::Ssd => 7.0,
+            StorageTier::Hdd => 20.0,
+            StorageTier::Network => 25.0,
+            StorageTier::Unknown => DEFAULT_MS_PER_ASSET,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageTier::Nvme => "nvme",
+            StorageTier::Ssd => "ssd",
+            StorageTier::Hdd => "hdd",
+            StorageTier::Network => "network",
+            StorageTier::Unknown => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for StorageTier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for StorageTier {
+    type Err = FastStartupError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "nvme" => Ok(StorageTier::Nvme),
+            "ssd" | "sata" => Ok(StorageTier::Ssd),
+            "hdd" => Ok(StorageTier::Hdd),
+            "network" | "nas" => Ok(StorageTier::Network),
+            "unknown" => Ok(StorageTier::Unknown),
+            other => Err(FastStartupError::InvalidArgument(format!(
+                "Unknown storage tier '{}' (expected nvme, ssd, hdd, network or unknown)",
+                other
+            ))),
+        }
+    }
+}
+
+/// Coefficients of the load time model for one machine/storage tier
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LoadTimeModel {
+    pub ms_per_asset: f64,
+    /// Milliseconds per MB, keyed by `AssetType::as_str`
+    #[serde(default)]
+    pub ms_per_mb: BTreeMap<String, f64>,
+    /// Measurements the coefficients were fitted from (0 = defaults)
+    #[serde(default)]
+    pub observations: usize,
+    #[serde(default)]
+    pub calibrated_at: Option<String>,
+}
+
+impl Default for LoadTimeModel {
+    fn default() -> Self {
+        Self {
+            ms_per_asset: DEFAULT_MS_PER_ASSET,
+            ms_per_mb: BTreeMap::new(),
+            observations: 0,
+            calibrated_at: None,
+        }
+    }
+}
+
+impl LoadTimeModel {
+    /// Uncalibrated model for a machine: storage sets the per-asset cost and
+    /// async loading overlaps it across cores (relative to a 4-core baseline)
+    pub fn for_profile(profile: &HardwareProfile) -> Self {
+        let cpu_factor = (4.0 / profile.cpu_cores.max(1) as f64).sqrt().clamp(0.5, 2.0);
+        Self {
+            ms_per_asset: profile.storage.default_ms_per_asset() * cpu_factor,
+            ..Self::default()
+        }
+    }
+
+    pub fn is_calibrated(&self) -> bool {
+        self.observations > 0
+    }
+
+    pub fn ms_per_mb_for(&self, asset_type: &str) -> f64 {
+        self.ms_per_mb.get(asset_type).copied().unwrap_or(0.0)
+    }
+
+    /// Estimated milliseconds to load `assets`
+    pub fn estimate_ms<'a>(&self, assets: impl IntoIterator<Item = &'a AssetInfo>) -> f64 {
+        assets
+            .into_iter()
+            .map(|a| self.ms_per_asset + self.ms_per_mb_for(a.asset_type.as_str()) * a.size_bytes as f64 / MB)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetType;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_estimate_ms() {
+        let asset = AssetInfo {
+            path: PathBuf::from("/p/Content/A.uasset"),
+            relative_path: "Content/A.uasset".to_string(),
+            asset_type: AssetType::UAsset,
+            size_bytes: 2 * 1024 * 1024,
+            modified: 0,
+            mount_point: "/Game".to_string(),
+        };
+
+        let mut model = LoadTimeModel::default();
+        assert_eq!(model.estimate_ms([&asset]), DEFAULT_MS_PER_ASSET);
+
+        model.ms_per_mb.insert("uasset".to_string(), 5.0);
+        assert_eq!(model.estimate_ms([&asset]), DEFAULT_MS_PER_ASSET + 10.0);
+    }
+
+    #[test]
+    fn test_for_profile() {
+        let profile = |cpu_cores, storage| HardwareProfile {
+            cpu_cores,
+            total_ram_bytes: None,
+            storage,
+            os: "linux".to_string(),
+        };
+
+        let laptop = LoadTimeModel::for_profile(&profile(4, StorageTier::Hdd));
+        let server = LoadTimeModel::for_profile(&profile(32, StorageTier::Nvme));
+        assert_eq!(laptop.ms_per_asset, 20.0);
+        assert_eq!(server.ms_per_asset, 2.0);
+    }
+}
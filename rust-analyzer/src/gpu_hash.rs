@@ -0,0 +1,413 @@
+//! GPU Hash Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Experimental xxh3 hashing of large files on a GPU compute device
+//! (`--hash-backend gpu`, feature `gpu_hash`). Files are uploaded in batches
+//! of `chunked_hash` lanes; each lane's eight xxh3 accumulators run as the
+//! eight invocations of one workgroup, and the CPU merges them, so the
+//! result equals `chunked_hash` of the same bytes.
+
+use crate::hash::{combine_lanes, ContentHash, PARALLEL_CHUNK_SIZE};
+use crate::mmap_pool::MmapPool;
+use crate::{FastStartupError, Result};
+use std::path::Path;
+use std::sync::{mpsc, Mutex, OnceLock};
+use wgpu::util::DeviceExt;
+use xxhash_rust::xxh3::xxh3_64;
+
+/// xxh3's default secret (kSecret)
+const SECRET: [u8; 192] = [
+    0xb8, 0xfe, 0x6c, 0x39, 0x23, 0xa4, 0x4b, 0xbe, 0x7c, 0x01, 0x81, 0x2c, 0xf7, 0x21, 0xad, 0x1c,
+    0xde, 0xd4, 0x6d, 0xe9, 0x83, 0x90, 0x97, 0xdb, 0x72, 0x40, 0xa4, 0xa4, 0xb7, 0xb3, 0x67, 0x1f,
+    0xcb, 0x79, 0xe6, 0x4e, 0xcc, 0xc0, 0xe5, 0x78, 0x82, 0x5a, 0xd0, 0x7d, 0xcc, 0xff, 0x72, 0x21,
+    0xb8, 0x08, 0x46, 0x74, 0xf7, 0x43, 0x24, 0x8e, 0xe0, 0x35, 0x90, 0xe6, 0x81, 0x3a, 0x26, 0x4c,
+    0x3c, 0x28, 0x52, 0xbb, 0x91, 0xc3, 0x00, 0xcb, 0x88, 0xd0, 0x65, 0x8b, 0x1b, 0x53, 0x2e, 0xa3,
+    0x71, 0x64, 0x48, 0x97, 0xa2, 0x0d, 0xf9, 0x4e, 0x38, 0x19, 0xef, 0x46, 0xa9, 0xde, 0xac, 0xd8,
+    0xa8, 0xfa, 0x76, 0x3f, 0xe3, 0x9c, 0x34, 0x3f, 0xf9, 0xdc, 0xbb, 0xc7, 0xc7, 0x0b, 0x4f, 0x1d,
+    0x8a, 0x51, 0xe0, 0x4b, 0xcd, 0xb4, 0x59, 0x31, 0xc8, 0x9f, 0x7e, 0xc9, 0xd9, 0x78, 0x73, 0x64,
+    0xea, 0xc5, 0xac, 0x83, 0x34, 0xd3, 0xeb, 0xc3, 0xc5, 0x81, 0xa0, 0xff, 0xfa, 0x13, 0x63, 0xeb,
+    0x17, 0x0d, 0xdd, 0x51, 0xb7, 0xf0, 0xda, 0x49, 0xd3, 0x16, 0x55, 0x26, 0x29, 0xd4, 0x68, 0x9e,
+    0x2b, 0x16, 0xbe, 0x58, 0x7d, 0x47, 0xa1, 0xfc, 0x8f, 0xf8, 0xb8, 0xd1, 0x7a, 0xd0, 0x31, 0xce,
+    0x45, 0xcb, 0x3a, 0x8f, 0x95, 0x16, 0x04, 0x28, 0xaf, 0xd7, 0xfb, 0xca, 0xbb, 0x4b, 0x40, 0x7e,
+];
+
+const PRIME32_1: u64 = 0x9E37_79B1;
+const PRIME32_2: u64 = 0x85EB_CA77;
+const PRIME32_3: u64 = 0xC2B2_AE3D;
+const PRIME64_1: u64 = 0x9E37_79B1_85EB_CA87;
+const PRIME64_2: u64 = 0xC2B2_AE3D_27D4_EB4F;
+const PRIME64_3: u64 = 0x1656_67B1_9E37_79F9;
+const PRIME64_4: u64 = 0x85EB_CA77_C2B2_AE63;
+const PRIME64_5: u64 = 0x27D4_EB2F_1656_67C5;
+
+const INITIAL_ACC: [u64; 8] = [PRIME32_3, PRIME64_1, PRIME64_2, PRIME64_3, PRIME64_4, PRIME32_2, PRIME64_5, PRIME32_1];
+
+/// Inputs up to this length take xxh3's short paths and are hashed on the CPU
+const MID_SIZE_MAX: usize = 240;
+/// Secret offsets of the last stripe and of the final merge
+const LAST_STRIPE_KEY: usize = 192 - 64 - 7;
+const MERGE_KEY: usize = 11;
+/// Upper bound on lanes per upload (128 MB), lowered to the device's
+/// storage binding limit
+const MAX_BATCH_LANES: usize = 8;
+/// Zero words after a batch, read by the unaligned loads of the last stripe
+const UPLOAD_PADDING: usize = 16;
+
+/// Compute shader: workgroup `x` is a lane, invocation `j` owns xxh3
+/// accumulator `j`. u64 values are `vec2<u32>` (low, high).
+const SHADER: &str = r#"
+@group(0) @binding(0) var<storage, read> data: array<u32>;
+@group(0) @binding(1) var<storage, read> lens: array<u32>;
+@group(0) @binding(2) var<storage, read_write> accs: array<vec2<u32>>;
+
+const LANE_WORDS: u32 = {LANE_WORDS}u;
+const PRIME32_1: u32 = 0x9E3779B1u;
+var<private> KEYS: array<vec2<u32>, 24> = array<vec2<u32>, 24>({KEYS});
+var<private> LAST_KEYS: array<vec2<u32>, 8> = array<vec2<u32>, 8>({LAST_KEYS});
+var<private> INITIAL_ACC: array<vec2<u32>, 8> = array<vec2<u32>, 8>({INITIAL_ACC});
+
+fn add64(a: vec2<u32>, b: vec2<u32>) -> vec2<u32> {
+    let lo = a.x + b.x;
+    return vec2<u32>(lo, a.y + b.y + select(0u, 1u, lo < a.x));
+}
+
+fn mul32x32(a: u32, b: u32) -> vec2<u32> {
+    let al = a & 0xFFFFu;
+    let ah = a >> 16u;
+    let bl = b & 0xFFFFu;
+    let bh = b >> 16u;
+    let ll = al * bl;
+    let lh = al * bh;
+    let mid = lh + ah * bl;
+    let mid_carry = select(0u, 0x10000u, mid < lh);
+    let lo = ll + (mid << 16u);
+    return vec2<u32>(lo, ah * bh + (mid >> 16u) + mid_carry + select(0u, 1u, lo < ll));
+}
+
+fn load64(byte: u32) -> vec2<u32> {
+    let w = byte >> 2u;
+    let shift = (byte & 3u) * 8u;
+    if (shift == 0u) {
+        return vec2<u32>(data[w], data[w + 1u]);
+    }
+    let a = data[w];
+    let b = data[w + 1u];
+    let c = data[w + 2u];
+    return vec2<u32>((a >> shift) | (b << (32u - shift)), (b >> shift) | (c << (32u - shift)));
+}
+
+fn accumulate(acc: vec2<u32>, value: vec2<u32>, swapped: vec2<u32>, key: vec2<u32>) -> vec2<u32> {
+    let keyed = value ^ key;
+    return add64(add64(acc, swapped), mul32x32(keyed.x, keyed.y));
+}
+
+fn stripe(acc: vec2<u32>, word: u32, j: u32, n: u32) -> vec2<u32> {
+    let value = vec2<u32>(data[word + 2u * j], data[word + 2u * j + 1u]);
+    let other = j ^ 1u;
+    let swapped = vec2<u32>(data[word + 2u * other], data[word + 2u * other + 1u]);
+    return accumulate(acc, value, swapped, KEYS[n + j]);
+}
+
+fn scramble(acc: vec2<u32>, key: vec2<u32>) -> vec2<u32> {
+    let mixed = vec2<u32>(acc.x ^ (acc.y >> 15u), acc.y) ^ key;
+    let product = mul32x32(mixed.x, PRIME32_1);
+    return vec2<u32>(product.x, product.y + mixed.y * PRIME32_1);
+}
+
+@compute @workgroup_size(8)
+fn main(@builtin(workgroup_id) group: vec3<u32>, @builtin(local_invocation_index) j: u32) {
+    let lane = group.x;
+    let len = lens[lane];
+    let base = lane * LANE_WORDS;
+    var acc = INITIAL_ACC[j];
+
+    let blocks = (len - 1u) / 1024u;
+    for (var b = 0u; b < blocks; b += 1u) {
+        for (var n = 0u; n < 16u; n += 1u) {
+            acc = stripe(acc, base + b * 256u + n * 16u, j, n);
+        }
+        acc = scramble(acc, KEYS[16u + j]);
+    }
+    let stripes = ((len - 1u) - blocks * 1024u) / 64u;
+    for (var n = 0u; n < stripes; n += 1u) {
+        acc = stripe(acc, base + blocks * 256u + n * 16u, j, n);
+    }
+
+    let last = base * 4u + len - 64u;
+    acc = accumulate(acc, load64(last + 8u * j), load64(last + 8u * (j ^ 1u)), LAST_KEYS[j]);
+    accs[lane * 8u + j] = acc;
+}
+"#;
+
+/// Compute device and pipeline, created on first use
+pub struct GpuHasher {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline: wgpu::ComputePipeline,
+    batch_lanes: usize,
+    /// One batch in flight, so parallel workers do not stack uploads
+    busy: Mutex<()>,
+    pub adapter: String,
+}
+
+static HASHER: OnceLock<std::result::Result<GpuHasher, String>> = OnceLock::new();
+
+impl GpuHasher {
+    /// Shared hasher, or why no usable device was found
+    pub fn global() -> Result<&'static GpuHasher> {
+        HASHER
+            .get_or_init(|| pollster::block_on(Self::new()))
+            .as_ref()
+            .map_err(|e| FastStartupError::GpuError(e.clone()))
+    }
+
+    async fn new() -> std::result::Result<Self, String> {
+        let instance = wgpu::Instance::default();
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                ..Default::default()
+            })
+            .await
+            .map_err(|e| format!("no GPU adapter: {}", e))?;
+        let name = adapter.get_info().name;
+        if !adapter
+            .get_downlevel_capabilities()
+            .flags
+            .contains(wgpu::DownlevelFlags::COMPUTE_SHADERS)
+        {
+            return Err(format!("{} does not support compute shaders", name));
+        }
+
+        let limits = adapter.limits();
+        let lane_binding = (PARALLEL_CHUNK_SIZE + UPLOAD_PADDING * 4) as u64;
+        let batch_lanes = (limits.max_storage_buffer_binding_size as u64 / lane_binding)
+            .min(limits.max_buffer_size / lane_binding)
+            .min(MAX_BATCH_LANES as u64) as usize;
+        if batch_lanes == 0 {
+            return Err(format!("{} cannot bind a {} MB lane", name, PARALLEL_CHUNK_SIZE >> 20));
+        }
+
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor {
+                label: Some("uefast-hash"),
+                required_limits: limits,
+                ..Default::default()
+            })
+            .await
+            .map_err(|e| format!("{}: {}", name, e))?;
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("xxh3-lanes"),
+            source: wgpu::ShaderSource::Wgsl(shader_source().into()),
+        });
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("xxh3-lanes"),
+            layout: None,
+            module: &module,
+            entry_point: Some("main"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
+        Ok(Self { device, queue, pipeline, batch_lanes, busy: Mutex::new(()), adapter: name })
+    }
+
+    /// Same result as `hash_file_with(path, HashAlgorithm::Xxh3)` for files
+    /// at or above `PARALLEL_HASH_THRESHOLD`
+    pub fn hash_file(&self, path: &Path) -> Result<ContentHash> {
+        let mmap = MmapPool::global().map(path)?;
+        self.hash_bytes(&mmap)
+    }
+
+    /// Same result as `chunked_hash(data)`
+    pub fn hash_bytes(&self, data: &[u8]) -> Result<ContentHash> {
+        let _busy = self.busy.lock().unwrap_or_else(|e| e.into_inner());
+        let mut lanes = Vec::with_capacity(data.len().div_ceil(PARALLEL_CHUNK_SIZE));
+        for batch in data.chunks(PARALLEL_CHUNK_SIZE * self.batch_lanes) {
+            lanes.extend(self.hash_batch(batch)?);
+        }
+        Ok(combine_lanes(&lanes, data.len() as u64))
+    }
+
+    fn hash_batch(&self, batch: &[u8]) -> Result<Vec<u64>> {
+        // Only the last lane of a file can be short enough for xxh3's
+        // short-input paths
+        let lens: Vec<usize> = batch.chunks(PARALLEL_CHUNK_SIZE).map(|lane| lane.len()).collect();
+        let gpu_lanes = lens.iter().take_while(|&&len| len > MID_SIZE_MAX).count();
+        let mut hashes = if gpu_lanes > 0 {
+            let end = batch.len().min(gpu_lanes * PARALLEL_CHUNK_SIZE);
+            self.dispatch(&batch[..end], &lens[..gpu_lanes])?
+        } else {
+            Vec::new()
+        };
+        hashes.extend(batch.chunks(PARALLEL_CHUNK_SIZE).skip(gpu_lanes).map(xxh3_64));
+        Ok(hashes)
+    }
+
+    fn dispatch(&self, data: &[u8], lens: &[usize]) -> Result<Vec<u64>> {
+        let upload_size = (data.len().div_ceil(4) + UPLOAD_PADDING) * 4;
+        let upload = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("xxh3-data"),
+            size: upload_size as u64,
+            usage: wgpu::BufferUsages::STORAGE,
+            mapped_at_creation: true,
+        });
+        upload.slice(..).get_mapped_range_mut()[..data.len()].copy_from_slice(data);
+        upload.unmap();
+
+        let len_bytes: Vec<u8> = lens.iter().flat_map(|&len| (len as u32).to_le_bytes()).collect();
+        let lens_buffer = self.device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("xxh3-lens"),
+            contents: &len_bytes,
+            usage: wgpu::BufferUsages::STORAGE,
+        });
+        let accs_size = (lens.len() * 8 * 8) as u64;
+        let accs = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("xxh3-accs"),
+            size: accs_size,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let readback = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("xxh3-readback"),
+            size: accs_size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("xxh3-lanes"),
+            layout: &self.pipeline.get_bind_group_layout(0),
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: upload.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 1, resource: lens_buffer.as_entire_binding() },
+                wgpu::BindGroupEntry { binding: 2, resource: accs.as_entire_binding() },
+            ],
+        });
+
+        let mut encoder = self.device.create_command_encoder(&wgpu::CommandEncoderDescriptor { label: None });
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(lens.len() as u32, 1, 1);
+        }
+        encoder.copy_buffer_to_buffer(&accs, 0, &readback, 0, accs_size);
+        self.queue.submit([encoder.finish()]);
+
+        let slice = readback.slice(..);
+        let (sender, receiver) = mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |mapped| {
+            let _ = sender.send(mapped);
+        });
+        self.device.poll(wgpu::PollType::Wait).map_err(gpu_error)?;
+        receiver.recv().map_err(gpu_error)?.map_err(gpu_error)?;
+
+        let words: Vec<u64> = slice
+            .get_mapped_range()
+            .chunks_exact(8)
+            .map(|word| u64::from_le_bytes(word.try_into().expect("8-byte chunk")))
+            .collect();
+        readback.unmap();
+        Ok(words
+            .chunks_exact(8)
+            .zip(lens)
+            .map(|(acc, &len)| merge_accs(acc.try_into().expect("8 accumulators"), len as u64))
+            .collect())
+    }
+}
+
+fn gpu_error(e: impl std::fmt::Display) -> FastStartupError {
+    FastStartupError::GpuError(e.to_string())
+}
+
+fn secret64(offset: usize) -> u64 {
+    u64::from_le_bytes(SECRET[offset..offset + 8].try_into().expect("8-byte key"))
+}
+
+fn wgsl_u64s(values: impl Iterator<Item = u64>) -> String {
+    values
+        .map(|v| format!("vec2<u32>({}u, {}u)", v as u32, (v >> 32) as u32))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// `SHADER` with the lane size and secret keys filled in
+fn shader_source() -> String {
+    SHADER
+        .replace("{LANE_WORDS}", &(PARALLEL_CHUNK_SIZE / 4).to_string())
+        .replace("{KEYS}", &wgsl_u64s((0..24).map(|k| secret64(k * 8))))
+        .replace("{LAST_KEYS}", &wgsl_u64s((0..8).map(|j| secret64(LAST_STRIPE_KEY + j * 8))))
+        .replace("{INITIAL_ACC}", &wgsl_u64s(INITIAL_ACC.into_iter()))
+}
+
+/// Final step of the xxh3 long hash, over a lane's accumulators
+fn merge_accs(acc: &[u64; 8], len: u64) -> u64 {
+    let mut result = len.wrapping_mul(PRIME64_1);
+    for i in 0..4 {
+        let key = MERGE_KEY + i * 16;
+        let product = ((acc[2 * i] ^ secret64(key)) as u128) * ((acc[2 * i + 1] ^ secret64(key + 8)) as u128);
+        result = result.wrapping_add(product as u64 ^ (product >> 64) as u64);
+    }
+    result ^= result >> 37;
+    result = result.wrapping_mul(0x1656_6791_9E37_79F9);
+    result ^ (result >> 32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::chunked_hash;
+
+    /// What invocation `j` of the shader computes, run on the CPU
+    fn shader_acc(data: &[u8], j: usize) -> u64 {
+        let read = |at: usize| u64::from_le_bytes(data[at..at + 8].try_into().unwrap());
+        let accumulate = |acc: u64, at: usize, key: u64| {
+            let keyed = read(at + 8 * j) ^ key;
+            acc.wrapping_add(read(at + 8 * (j ^ 1))).wrapping_add((keyed & 0xFFFF_FFFF) * (keyed >> 32))
+        };
+        let mut acc = INITIAL_ACC[j];
+        let blocks = (data.len() - 1) / 1024;
+        for b in 0..blocks {
+            for n in 0..16 {
+                acc = accumulate(acc, b * 1024 + n * 64, secret64((n + j) * 8));
+            }
+            acc = ((acc ^ (acc >> 47)) ^ secret64(128 + j * 8)).wrapping_mul(PRIME32_1);
+        }
+        for n in 0..((data.len() - 1) - blocks * 1024) / 64 {
+            acc = accumulate(acc, blocks * 1024 + n * 64, secret64((n + j) * 8));
+        }
+        accumulate(acc, data.len() - 64, secret64(LAST_STRIPE_KEY + j * 8))
+    }
+
+    #[test]
+    fn test_accumulator_split_matches_xxh3() {
+        let data: Vec<u8> = (0..100_003u32).map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8).collect();
+        for len in [241, 1024, 1025, 2048, 5003, 100_003] {
+            let lane = &data[..len];
+            let acc: [u64; 8] = std::array::from_fn(|j| shader_acc(lane, j));
+            assert_eq!(merge_accs(&acc, len as u64), xxh3_64(lane), "len {}", len);
+        }
+    }
+
+    #[test]
+    fn test_shader_validates() {
+        use wgpu::naga;
+        let module = naga::front::wgsl::parse_str(&shader_source()).expect("WGSL parses");
+        naga::valid::Validator::new(naga::valid::ValidationFlags::all(), naga::valid::Capabilities::empty())
+            .validate(&module)
+            .expect("WGSL validates");
+    }
+
+    #[test]
+    fn test_gpu_matches_chunked_hash() {
+        // Machines without a compute-capable adapter only run the CPU checks
+        let Ok(hasher) = GpuHasher::global() else {
+            return;
+        };
+        let data: Vec<u8> = (0..2 * PARALLEL_CHUNK_SIZE as u32 + 123).map(|i| (i % 251) as u8).collect();
+        assert_eq!(hasher.hash_bytes(&data).unwrap(), chunked_hash(&data));
+        // A short final lane goes to the CPU
+        assert_eq!(hasher.hash_bytes(&data[..PARALLEL_CHUNK_SIZE + 100]).unwrap(), chunked_hash(&data[..PARALLEL_CHUNK_SIZE + 100]));
+    }
+}
@@ -4,14 +4,24 @@
 //!
 //! Asset dependency graph builder and analyzer
 
+use crate::config::ProjectConfig;
+use crate::config_preloads;
+use crate::editor_data::EditorData;
+use crate::graph_export::{node_id, GraphExporter, GraphFormat};
+use crate::graph_view::GraphView;
+use crate::heuristics::ConventionIndex;
 use crate::scanner::{AssetInfo, AssetScanner, AssetType};
-use crate::uasset::UAssetParser;
-use crate::Result;
+use crate::memory::MemoryPlan;
+use crate::import_cache::ImportCache;
+use crate::project::{content_roots, ContentRoot};
+use crate::sampling::Estimate;
+use crate::uasset::{Package, PackageReferences, ParseFailure};
+use crate::{FastStartupError, Result};
 use petgraph::graph::{DiGraph, NodeIndex};
-use petgraph::visit::Dfs;
+use petgraph::visit::{Dfs, EdgeRef};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::path::{Path, PathBuf};
 use tracing::{debug, info, warn};
 
@@ -19,6 +29,7 @@ use tracing::{debug, info, warn};
 pub struct AssetNode {
     pub path: PathBuf,
     pub asset_type: AssetType,
+    pub size_bytes: u64,
     pub is_startup_critical: bool,
     pub load_order: Option<u32>,
 }
@@ -27,6 +38,60 @@ pub struct AssetNode {
 pub struct DependencyEdge {
     pub dependency_type: DependencyType,
     pub is_hard: bool,
+    #[serde(default)]
+    pub confidence: EdgeConfidence,
+}
+
+/// Edges reserved per asset before any package is parsed
+const EDGES_PER_ASSET_HINT: usize = 4;
+
+/// Source, target and weight of an edge resolved while parsing, waiting to
+/// be appended
+type PendingEdge = (NodeIndex, NodeIndex, DependencyEdge);
+
+/// Chance that a startup load follows a heuristic edge
+const HEURISTIC_LOAD_PROBABILITY: f64 = 0.5;
+
+/// Chance that a startup load follows a hard registry-derived edge, which
+/// may be stale relative to the package on disk
+const REGISTRY_LOAD_PROBABILITY: f64 = 0.9;
+
+impl DependencyEdge {
+    /// Chance that loading the source at startup also loads the target
+    pub fn load_probability(&self) -> f64 {
+        match self.confidence {
+            // Guesses carry no hard/soft information of their own
+            EdgeConfidence::Heuristic => HEURISTIC_LOAD_PROBABILITY,
+            _ if !self.is_hard => 0.0,
+            EdgeConfidence::RegistryDerived => REGISTRY_LOAD_PROBABILITY,
+            _ => 1.0,
+        }
+    }
+}
+
+/// Where an edge came from, most trustworthy first
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum EdgeConfidence {
+    /// Hard import read from the package's import table
+    #[default]
+    ParsedHard,
+    /// Soft reference read from the package
+    ParsedSoft,
+    /// Taken from the asset registry instead of the package itself
+    RegistryDerived,
+    /// Guessed from naming and folder conventions
+    Heuristic,
+}
+
+impl EdgeConfidence {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EdgeConfidence::ParsedHard => "parsed_hard",
+            EdgeConfidence::ParsedSoft => "parsed_soft",
+            EdgeConfidence::RegistryDerived => "registry_derived",
+            EdgeConfidence::Heuristic => "heuristic",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -39,9 +104,84 @@ pub enum DependencyType {
     Animation,
 }
 
+impl DependencyType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DependencyType::Import => "import",
+            DependencyType::SoftReference => "soft_reference",
+            DependencyType::Blueprint => "blueprint",
+            DependencyType::Material => "material",
+            DependencyType::Texture => "texture",
+            DependencyType::Animation => "animation",
+        }
+    }
+}
+
+/// How much of a graph rests on real package parses
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ParserCoverage {
+    /// Packages whose imports were needed
+    pub packages: usize,
+    /// Packages parsed or served from the import cache
+    pub parsed: usize,
+    /// Of `parsed`, packages served from the import cache
+    pub reused: usize,
+    pub failures: BTreeMap<ParseFailure, usize>,
+    /// Edges read from import tables
+    pub parsed_edges: usize,
+    /// Edges inferred without a parse
+    pub heuristic_edges: usize,
+    /// Of `parsed_edges`, soft package references (only counted when asked
+    /// for)
+    #[serde(default)]
+    pub soft_edges: usize,
+    /// Edges taken from exported editor asset registry data
+    #[serde(default)]
+    pub registry_edges: usize,
+    /// Packages whose dependencies came from editor data instead of a parse;
+    /// not counted in `packages`
+    #[serde(default)]
+    pub editor_packages: usize,
+}
+
+impl ParserCoverage {
+    pub fn failed(&self) -> usize {
+        self.failures.values().sum()
+    }
+
+    /// Fraction of packages parsed; 1.0 when there was nothing to parse
+    pub fn parsed_ratio(&self) -> f64 {
+        if self.packages == 0 {
+            1.0
+        } else {
+            self.parsed as f64 / self.packages as f64
+        }
+    }
+
+    /// Fraction of edges read from import tables rather than inferred
+    pub fn parsed_edge_ratio(&self) -> f64 {
+        let total = self.parsed_edges + self.heuristic_edges;
+        if total == 0 {
+            1.0
+        } else {
+            self.parsed_edges as f64 / total as f64
+        }
+    }
+
+    /// e.g. "zen: 120, corrupt: 3"
+    pub fn describe_failures(&self) -> String {
+        self.failures
+            .iter()
+            .map(|(failure, count)| format!("{}: {}", failure.as_str(), count))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
 pub struct DependencyGraph {
     graph: DiGraph<AssetNode, DependencyEdge>,
     path_to_node: HashMap<PathBuf, NodeIndex>,
+    coverage: ParserCoverage,
 }
 
 impl DependencyGraph {
@@ -49,59 +189,251 @@ impl DependencyGraph {
         Self {
             graph: DiGraph::new(),
             path_to_node: HashMap::new(),
+            coverage: ParserCoverage::default(),
         }
     }
 
-    pub fn build(project_root: &Path) -> Result<Self> {
+    /// Scan the project with its `config` and build the graph of its assets
+    pub fn build(project_root: &Path, config: &ProjectConfig) -> Result<Self> {
         info!("Building dependency graph for: {}", project_root.display());
 
-        let scanner = AssetScanner::new(project_root)?;
+        let scanner = AssetScanner::new(project_root)?.with_config(config);
         let assets = scanner.scan_all(None)?;
 
-        let mut graph = Self::new();
+        Self::build_from_assets(project_root, &assets)
+    }
 
-        // Add all assets as nodes
-        for asset in &assets {
+    /// Build the graph from an already scanned (or sampled) asset list
+    pub fn build_from_assets(project_root: &Path, assets: &[AssetInfo]) -> Result<Self> {
+        Self::build_with_editor_data(project_root, assets, None)
+    }
+
+    /// `build_from_assets` taking the dependencies of every package in
+    /// `editor_data` from the editor instead of parsing it; packages the
+    /// export does not know (added since) are still parsed
+    pub fn build_with_editor_data(
+        project_root: &Path,
+        assets: &[AssetInfo],
+        editor_data: Option<&EditorData>,
+    ) -> Result<Self> {
+        Self::build_graph(project_root, assets, editor_data, false)
+    }
+
+    /// `build_with_editor_data` also adding a soft edge for every soft
+    /// package reference of the parsed packages. Soft edges are followed
+    /// when marking startup closures, so these graphs are for inspection.
+    pub fn build_with_soft_references(
+        project_root: &Path,
+        assets: &[AssetInfo],
+        editor_data: Option<&EditorData>,
+    ) -> Result<Self> {
+        Self::build_graph(project_root, assets, editor_data, true)
+    }
+
+    fn build_graph(
+        project_root: &Path,
+        assets: &[AssetInfo],
+        editor_data: Option<&EditorData>,
+        include_soft: bool,
+    ) -> Result<Self> {
+        // Add all assets as nodes, sized for a handful of imports each
+        let mut graph = Self::with_capacity(assets.len(), assets.len() * EDGES_PER_ASSET_HINT);
+        for asset in assets {
             graph.add_asset(asset);
         }
 
         info!("Added {} nodes to graph", graph.node_count());
 
-        // Parse dependencies in parallel
-        let dependencies: Vec<_> = assets
-            .par_iter()
-            .filter(|a| a.asset_type == AssetType::UAsset)
-            .filter_map(|asset| {
-                match UAssetParser::parse_imports(&asset.path) {
-                    Ok(imports) => Some((asset.path.clone(), imports)),
-                    Err(e) => {
-                        debug!("Failed to parse {}: {}", asset.path.display(), e);
-                        None
+        // Parse dependencies in parallel, reusing reference lists of packages
+        // unchanged since an earlier build. Without enough memory for every
+        // package at once, shards are parsed and turned into edges in turn.
+        let mut import_cache = ImportCache::load(project_root);
+        let roots = content_roots(project_root);
+        let from_editor = editor_data.map(|e| e.covered_paths(assets)).unwrap_or_default();
+        // Maps are packages too, and as startup roots their imports matter most
+        let packages: Vec<&AssetInfo> = assets
+            .iter()
+            .filter(|a| matches!(a.asset_type, AssetType::UAsset | AssetType::UMap) && !from_editor.contains(&a.path))
+            .collect();
+        let plan = MemoryPlan::detect(packages.len());
+        let pool = if plan.is_streaming(packages.len()) {
+            warn!(
+                "Low memory: ~{} MB estimated for {} packages, building the graph in {}",
+                MemoryPlan::estimate_bytes(packages.len()) / (1024 * 1024),
+                packages.len(),
+                plan.describe()
+            );
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(plan.threads)
+                .build()
+                .map_err(|e| FastStartupError::AssetError(e.to_string()))?;
+            Some(pool)
+        } else {
+            None
+        };
+
+        // Edges are resolved against the nodes on the parsing threads, so the
+        // serial merge below only appends them
+        type Parsed = std::result::Result<(Option<u64>, PackageReferences, Vec<PendingEdge>), ParseFailure>;
+        let mut coverage = ParserCoverage {
+            packages: packages.len(),
+            ..Default::default()
+        };
+        let mut unparsed: Vec<&AssetInfo> = Vec::new();
+        for shard in packages.chunks(plan.shard_size) {
+            let parsed: Vec<(&AssetInfo, Parsed)> = {
+                let graph = &graph;
+                let edges = |asset: &AssetInfo, references: &PackageReferences| {
+                    graph.package_edges(project_root, &roots, asset, references, include_soft)
+                };
+                let parse = || {
+                    shard
+                        .par_iter()
+                        .map(|&asset| {
+                            if let Some(references) = import_cache.get(asset) {
+                                let edges = edges(asset, references);
+                                return (asset, Ok((None, references.clone(), edges)));
+                            }
+
+                            let parsed = Package::open(&asset.path).and_then(|package| {
+                                let summary_hash = package.summary_hash();
+                                let references = match import_cache.get_by_summary(summary_hash) {
+                                    Some(references) => references.clone(),
+                                    None => package.package_references()?,
+                                };
+                                let edges = edges(asset, &references);
+                                Ok((Some(summary_hash), references, edges))
+                            });
+                            let parsed = parsed.map_err(|e| {
+                                debug!("Failed to parse {}: {}", asset.path.display(), e);
+                                ParseFailure::classify(&asset.path, &e)
+                            });
+                            (asset, parsed)
+                        })
+                        .collect()
+                };
+                match &pool {
+                    Some(pool) => pool.install(parse),
+                    None => parse(),
+                }
+            };
+
+            let new_edges: usize = parsed.iter().filter_map(|(_, r)| r.as_ref().ok()).map(|(_, _, e)| e.len()).sum();
+            graph.graph.reserve_edges(new_edges);
+            for (asset, result) in parsed {
+                match result {
+                    Ok((summary_hash, references, edges)) => {
+                        coverage.parsed += 1;
+                        for (from, to, edge) in edges {
+                            coverage.soft_edges += usize::from(!edge.is_hard);
+                            graph.graph.add_edge(from, to, edge);
+                        }
+                        match summary_hash {
+                            Some(hash) => import_cache.insert(asset, hash, references),
+                            None => coverage.reused += 1,
+                        }
+                    }
+                    Err(failure) => {
+                        *coverage.failures.entry(failure).or_default() += 1;
+                        unparsed.push(asset);
                     }
                 }
-            })
-            .collect();
+            }
+        }
 
-        // Add edges
-        for (source_path, imports) in dependencies {
-            for import in imports {
-                let import_path = resolve_import_path(project_root, &import);
-                if let Some(target_path) = import_path {
-                    graph.add_dependency(
-                        &source_path,
-                        &target_path,
-                        DependencyType::Import,
-                        true,
-                    );
+        import_cache.record_hits(coverage.reused);
+        if coverage.reused > 0 {
+            info!("Reused imports of {} unchanged packages", coverage.reused);
+        }
+        if let Err(e) = import_cache.save(project_root) {
+            debug!("Failed to save import cache: {}", e);
+        }
+
+        coverage.parsed_edges = graph.edge_count();
+
+        // Fill blind spots left by unparsable packages with low-confidence
+        // soft edges guessed from naming and folder conventions
+        if !unparsed.is_empty() {
+            let conventions = ConventionIndex::new(assets);
+            for asset in unparsed {
+                for (target_path, dep_type) in conventions.infer(asset) {
+                    graph.add_inferred_dependency(&asset.path, &target_path, dep_type);
                 }
             }
+            coverage.heuristic_edges = graph.edge_count() - coverage.parsed_edges;
+            if coverage.heuristic_edges > 0 {
+                info!("Inferred {} edges from naming conventions", coverage.heuristic_edges);
+            }
+        }
+        if coverage.failed() > 0 {
+            warn!(
+                "{} of {} packages could not be parsed ({})",
+                coverage.failed(),
+                coverage.packages,
+                coverage.describe_failures()
+            );
+        }
+        graph.coverage = coverage;
+
+        if let Some(editor_data) = editor_data {
+            let stats = editor_data.apply(&mut graph, assets);
+            graph.coverage.editor_packages = stats.matched;
+            info!(
+                "Editor data: {} of {} packages matched, {} registry edges",
+                stats.matched, stats.packages, stats.edges_added
+            );
         }
 
         info!("Added {} edges to graph", graph.edge_count());
 
+        // Assets forced to load by config are startup roots whatever their path
+        let mut preloads = config_preloads::load(project_root);
+        config_preloads::resolve(&mut preloads, assets);
+        let roots = config_preloads::root_paths(&preloads, assets);
+        if !roots.is_empty() {
+            info!("{} startup roots from config preloads", roots.len());
+            graph.mark_startup_roots(&roots);
+        }
+
         Ok(graph)
     }
 
+    fn with_capacity(nodes: usize, edges: usize) -> Self {
+        Self {
+            graph: DiGraph::with_capacity(nodes, edges),
+            path_to_node: HashMap::with_capacity(nodes),
+            coverage: ParserCoverage::default(),
+        }
+    }
+
+    /// Edges for the imports (and with `include_soft` the soft references)
+    /// of `asset` whose targets are nodes of the graph
+    fn package_edges(
+        &self,
+        project_root: &Path,
+        roots: &[ContentRoot],
+        asset: &AssetInfo,
+        references: &PackageReferences,
+        include_soft: bool,
+    ) -> Vec<PendingEdge> {
+        let Some(&from) = self.path_to_node.get(&asset.path) else {
+            return Vec::new();
+        };
+        let soft: &[String] = if include_soft { &references.soft } else { &[] };
+        let hard = references.imports.iter().map(|import| (import, true));
+        hard.chain(soft.iter().map(|reference| (reference, false)))
+            .filter_map(|(import, is_hard)| {
+                let to = *self.path_to_node.get(&import_path(project_root, roots, import))?;
+                let edge = DependencyEdge {
+                    dependency_type: if is_hard { DependencyType::Import } else { DependencyType::SoftReference },
+                    is_hard,
+                    confidence: if is_hard { EdgeConfidence::ParsedHard } else { EdgeConfidence::ParsedSoft },
+                };
+                Some((from, to, edge))
+            })
+            .collect()
+    }
+
     pub fn add_asset(&mut self, asset: &AssetInfo) -> NodeIndex {
         if let Some(&idx) = self.path_to_node.get(&asset.path) {
             return idx;
@@ -110,6 +442,7 @@ impl DependencyGraph {
         let node = AssetNode {
             path: asset.path.clone(),
             asset_type: asset.asset_type,
+            size_bytes: asset.size_bytes,
             is_startup_critical: false,
             load_order: None,
         };
@@ -125,6 +458,27 @@ impl DependencyGraph {
         to: &Path,
         dep_type: DependencyType,
         is_hard: bool,
+    ) {
+        let confidence = if is_hard {
+            EdgeConfidence::ParsedHard
+        } else {
+            EdgeConfidence::ParsedSoft
+        };
+        self.add_dependency_with_confidence(from, to, dep_type, is_hard, confidence);
+    }
+
+    /// Add a soft edge guessed rather than read from the package
+    pub fn add_inferred_dependency(&mut self, from: &Path, to: &Path, dep_type: DependencyType) {
+        self.add_dependency_with_confidence(from, to, dep_type, false, EdgeConfidence::Heuristic);
+    }
+
+    pub fn add_dependency_with_confidence(
+        &mut self,
+        from: &Path,
+        to: &Path,
+        dep_type: DependencyType,
+        is_hard: bool,
+        confidence: EdgeConfidence,
     ) {
         let from_idx = match self.path_to_node.get(from) {
             Some(&idx) => idx,
@@ -139,11 +493,32 @@ impl DependencyGraph {
         let edge = DependencyEdge {
             dependency_type: dep_type,
             is_hard,
+            confidence,
         };
 
         self.graph.add_edge(from_idx, to_idx, edge);
     }
 
+    /// Add an edge from editor asset registry data unless the nodes are
+    /// unknown or already linked that way; `true` when it was added
+    pub fn add_registry_dependency(&mut self, from: &Path, to: &Path, is_hard: bool) -> bool {
+        let (Some(&from_idx), Some(&to_idx)) = (self.path_to_node.get(from), self.path_to_node.get(to)) else {
+            return false;
+        };
+        if self.graph.edges_connecting(from_idx, to_idx).any(|e| e.weight().is_hard || !is_hard) {
+            return false;
+        }
+        let dependency_type = if is_hard { DependencyType::Import } else { DependencyType::SoftReference };
+        self.add_dependency_with_confidence(from, to, dependency_type, is_hard, EdgeConfidence::RegistryDerived);
+        self.coverage.registry_edges += 1;
+        true
+    }
+
+    /// Parse success and edge provenance of the last build
+    pub fn coverage(&self) -> &ParserCoverage {
+        &self.coverage
+    }
+
     pub fn node_count(&self) -> usize {
         self.graph.node_count()
     }
@@ -152,6 +527,15 @@ impl DependencyGraph {
         self.graph.edge_count()
     }
 
+    pub fn node(&self, path: &Path) -> Option<&AssetNode> {
+        self.path_to_node.get(path).map(|&idx| &self.graph[idx])
+    }
+
+    /// Freeze the graph into a view threads can share for queries
+    pub fn into_view(self) -> GraphView {
+        GraphView::new(self)
+    }
+
     pub fn get_dependencies(&self, path: &Path) -> Vec<&AssetNode> {
         let idx = match self.path_to_node.get(path) {
             Some(&idx) => idx,
@@ -176,12 +560,27 @@ impl DependencyGraph {
             .collect()
     }
 
+    /// Mark `roots` and everything they depend on as startup-critical
+    pub fn mark_startup_roots(&mut self, roots: &HashSet<PathBuf>) {
+        for root in roots {
+            let Some(&idx) = self.path_to_node.get(root) else {
+                continue;
+            };
+            let mut dfs = Dfs::new(&self.graph, idx);
+            while let Some(dep_idx) = dfs.next(&self.graph) {
+                self.graph[dep_idx].is_startup_critical = true;
+            }
+        }
+    }
+
     pub fn filter_startup_critical(mut self) -> Self {
-        // Mark startup-critical assets
+        // Mark startup-critical assets; roots marked earlier (config
+        // preloads) stay
         let critical_indices: Vec<_> = self.graph
             .node_indices()
             .filter(|&idx| {
                 let node = &self.graph[idx];
+                node.is_startup_critical ||
                 node.asset_type == AssetType::UMap ||
                 node.path.to_string_lossy().to_lowercase().contains("startup")
             })
@@ -218,26 +617,92 @@ impl DependencyGraph {
     }
 
     pub fn compute_load_order(&mut self) {
-        use petgraph::algo::toposort;
+        use petgraph::algo::{tarjan_scc, toposort};
 
-        match toposort(&self.graph, None) {
-            Ok(order) => {
-                for (i, idx) in order.iter().enumerate() {
-                    self.graph[*idx].load_order = Some(i as u32);
-                }
-            }
+        let order = match toposort(&self.graph, None) {
+            Ok(order) => order,
             Err(_) => {
-                warn!("Cycle detected in dependency graph, using fallback ordering");
-                for (i, idx) in self.graph.node_indices().enumerate() {
-                    self.graph[idx].load_order = Some(i as u32);
-                }
+                // Order the cycles as single units; their members keep index order
+                let cycles = self.find_cycles();
+                warn!(
+                    "{} dependency cycles ({} assets) in graph, ordering each cycle as one unit",
+                    cycles.len(),
+                    cycles.iter().map(Vec::len).sum::<usize>()
+                );
+                tarjan_scc(&self.graph)
+                    .into_iter()
+                    .rev()
+                    .flat_map(|mut scc| {
+                        scc.sort();
+                        scc
+                    })
+                    .collect()
             }
+        };
+        for (i, idx) in order.iter().enumerate() {
+            self.graph[*idx].load_order = Some(i as u32);
         }
     }
 
+    /// Groups of assets that reference each other in a loop, as strongly
+    /// connected components found with Tarjan's algorithm. Largest first,
+    /// paths sorted within each group.
+    pub fn find_cycles(&self) -> Vec<Vec<PathBuf>> {
+        self.cycles_where(|_| true)
+    }
+
+    /// `find_cycles` over hard references only: loops the loader has to
+    /// resolve while blocking on every package in them
+    pub fn find_hard_cycles(&self) -> Vec<Vec<PathBuf>> {
+        self.cycles_where(|edge| edge.is_hard)
+    }
+
+    fn cycles_where(&self, keep: impl Fn(&DependencyEdge) -> bool) -> Vec<Vec<PathBuf>> {
+        use petgraph::algo::tarjan_scc;
+        use petgraph::visit::EdgeFiltered;
+
+        let filtered = EdgeFiltered::from_fn(&self.graph, |edge| keep(edge.weight()));
+        let mut cycles: Vec<Vec<PathBuf>> = tarjan_scc(&filtered)
+            .into_iter()
+            .filter(|scc| {
+                scc.len() > 1 || self.graph.edges_connecting(scc[0], scc[0]).any(|edge| keep(edge.weight()))
+            })
+            .map(|scc| {
+                let mut paths: Vec<PathBuf> = scc.into_iter().map(|idx| self.graph[idx].path.clone()).collect();
+                paths.sort();
+                paths
+            })
+            .collect();
+        cycles.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        cycles
+    }
+
     pub fn to_dot(&self) -> String {
-        use petgraph::dot::{Config, Dot};
-        format!("{:?}", Dot::with_config(&self.graph, &[Config::EdgeNoLabel]))
+        self.export_string(GraphFormat::Dot)
+    }
+
+    /// GraphML keeping asset types, sizes, startup flags and the type,
+    /// hardness and confidence of every edge, e.g. for Gephi
+    pub fn to_graphml(&self) -> String {
+        self.export_string(GraphFormat::GraphMl)
+    }
+
+    /// The same fields as `to_graphml` as `{"nodes": [...], "edges": [...]}`
+    pub fn to_json(&self) -> String {
+        self.export_string(GraphFormat::Json)
+    }
+
+    fn export_string(&self, format: GraphFormat) -> String {
+        let mut out = Vec::new();
+        GraphExporter::new(self, Path::new(""))
+            .write(&mut out, format)
+            .expect("writing to memory cannot fail");
+        String::from_utf8_lossy(&out).into_owned()
+    }
+
+    /// The underlying petgraph, for exporters that walk it directly
+    pub(crate) fn petgraph(&self) -> &DiGraph<AssetNode, DependencyEdge> {
+        &self.graph
     }
 
     pub fn get_load_order(&self) -> Vec<&AssetNode> {
@@ -251,28 +716,50 @@ impl DependencyGraph {
     }
 }
 
+impl DependencyGraph {
+    /// Project-relative paths of all nodes in computed load order
+    pub fn relative_load_order(&self, project_root: &Path) -> Vec<String> {
+        self.get_load_order()
+            .iter()
+            .map(|n| {
+                n.path
+                    .strip_prefix(project_root)
+                    .unwrap_or(&n.path)
+                    .to_string_lossy()
+                    .replace('\\', "/")
+            })
+            .collect()
+    }
+}
+
 impl Default for DependencyGraph {
     fn default() -> Self {
         Self::new()
     }
 }
 
-fn resolve_import_path(project_root: &Path, import: &str) -> Option<PathBuf> {
+/// Where the package `import` names would be on disk; it becomes an edge
+/// only if that path is a node of the graph
+fn import_path(project_root: &Path, roots: &[ContentRoot], import: &str) -> PathBuf {
     // Convert UE import path to filesystem path
-    // e.g., "/Game/Characters/Hero" -> "Content/Characters/Hero.uasset"
+    // e.g., "/Game/Characters/Hero" -> "Content/Characters/Hero.uasset",
+    // "/Weapons/Rifle" -> "Plugins/Weapons/Content/Rifle.uasset"
     
-    let cleaned = import
-        .trim_start_matches('/')
-        .replace("/Game/", "Content/")
-        .replace("/Engine/", "Engine/Content/");
-
-    let path = project_root.join(&cleaned).with_extension("uasset");
-    
-    if path.exists() {
-        Some(path)
+    let mounted = roots.iter().find_map(|root| {
+        let rest = import.strip_prefix(root.mount_point.as_str())?.strip_prefix('/')?;
+        Some(format!("{}/{}", root.relative_dir, rest))
+    });
+    let cleaned = if let Some(mounted) = mounted {
+        mounted
+    } else if let Some(rest) = import.strip_prefix("/Game/") {
+        format!("Content/{}", rest)
+    } else if let Some(rest) = import.strip_prefix("/Engine/") {
+        format!("Engine/Content/{}", rest)
     } else {
-        None
-    }
+        import.trim_start_matches('/').to_string()
+    };
+
+    project_root.join(&cleaned).with_extension("uasset")
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -299,6 +786,422 @@ impl DependencyGraph {
     }
 }
 
+/// One node of a `GraphReport`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphNodeRecord {
+    /// Stable across runs, as in DOT and GraphML exports
+    pub id: String,
+    pub relative_path: String,
+    pub asset_type: AssetType,
+    pub size_bytes: u64,
+    pub is_startup_critical: bool,
+    pub dependencies: usize,
+    pub dependents: usize,
+}
+
+/// Which edges `subgraph` follows away from its roots
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SubgraphDirection {
+    /// What the roots load
+    #[default]
+    Dependencies,
+    /// What loads the roots
+    Dependents,
+    Both,
+}
+
+impl SubgraphDirection {
+    /// For logs: what a subgraph in this direction holds
+    pub fn describe(&self) -> &'static str {
+        match self {
+            SubgraphDirection::Dependencies => "dependencies",
+            SubgraphDirection::Dependents => "dependents",
+            SubgraphDirection::Both => "dependencies and dependents",
+        }
+    }
+}
+
+impl std::str::FromStr for SubgraphDirection {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "dependencies" | "deps" => Ok(SubgraphDirection::Dependencies),
+            "dependents" | "reverse" => Ok(SubgraphDirection::Dependents),
+            "both" => Ok(SubgraphDirection::Both),
+            other => Err(format!("unknown direction '{}' (expected dependencies, dependents, both)", other)),
+        }
+    }
+}
+
+/// An asset found by `reachable_from`
+#[derive(Debug, Clone, Copy)]
+pub struct ReachedAsset<'a> {
+    pub node: &'a AssetNode,
+    /// Edges between it and the starting asset
+    pub depth: usize,
+    /// The asset it was reached through; the starting asset at depth 1
+    pub via: &'a AssetNode,
+}
+
+/// Counts, parser coverage and nodes of a built graph
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GraphReport {
+    pub project_name: String,
+    pub node_count: usize,
+    pub edge_count: usize,
+    pub startup_critical_count: usize,
+    pub coverage: ParserCoverage,
+    /// Sorted by relative path
+    pub nodes: Vec<GraphNodeRecord>,
+    /// Assets referencing each other in a loop, as from `find_cycles`
+    #[serde(default)]
+    pub cycles: Vec<Vec<String>>,
+}
+
+impl DependencyGraph {
+    pub fn report(&self, project_root: &Path) -> GraphReport {
+        let relative = |path: &Path| path.strip_prefix(project_root).unwrap_or(path).to_string_lossy().replace('\\', "/");
+        let mut nodes: Vec<GraphNodeRecord> = self
+            .graph
+            .node_indices()
+            .map(|idx| {
+                let node = &self.graph[idx];
+                let relative_path = relative(&node.path);
+                GraphNodeRecord {
+                    id: node_id(&relative_path),
+                    relative_path,
+                    asset_type: node.asset_type,
+                    size_bytes: node.size_bytes,
+                    is_startup_critical: node.is_startup_critical,
+                    dependencies: self.graph.neighbors(idx).count(),
+                    dependents: self.graph.neighbors_directed(idx, petgraph::Direction::Incoming).count(),
+                }
+            })
+            .collect();
+        nodes.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+
+        GraphReport {
+            project_name: project_root.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default(),
+            node_count: self.node_count(),
+            edge_count: self.edge_count(),
+            startup_critical_count: nodes.iter().filter(|n| n.is_startup_critical).count(),
+            coverage: self.coverage.clone(),
+            nodes,
+            cycles: self
+                .find_cycles()
+                .iter()
+                .map(|cycle| cycle.iter().map(|path| relative(path)).collect())
+                .collect(),
+        }
+    }
+}
+
+/// A heavy subtree that several startup assets hard-reference independently
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SharedSubtree {
+    pub root: PathBuf,
+    pub referrers: Vec<PathBuf>,
+    pub subtree_asset_count: usize,
+    pub subtree_size_bytes: u64,
+    /// Bytes resolved again by every referrer after the first
+    pub redundant_bytes: u64,
+}
+
+impl DependencyGraph {
+    /// Detect diamond patterns where `min_referrers` or more of the given
+    /// startup assets hard-reference the same dependency subtree.
+    pub fn find_shared_subtrees(
+        &self,
+        startup_paths: &HashSet<PathBuf>,
+        min_referrers: usize,
+    ) -> Vec<SharedSubtree> {
+        let mut referrers: HashMap<NodeIndex, Vec<NodeIndex>> = HashMap::new();
+
+        for path in startup_paths {
+            let Some(&src) = self.path_to_node.get(path) else {
+                continue;
+            };
+
+            let mut seen = HashSet::new();
+            for edge in self.graph.edges(src) {
+                if edge.weight().is_hard && seen.insert(edge.target()) {
+                    referrers.entry(edge.target()).or_default().push(src);
+                }
+            }
+        }
+
+        let mut shared: Vec<SharedSubtree> = referrers
+            .into_iter()
+            .filter(|(_, refs)| refs.len() >= min_referrers.max(2))
+            .map(|(root, refs)| {
+                let closure = self.hard_closure(root);
+                let subtree_size_bytes: u64 = closure
+                    .iter()
+                    .map(|&idx| self.graph[idx].size_bytes)
+                    .sum();
+
+                let mut referrers: Vec<PathBuf> = refs
+                    .iter()
+                    .map(|&idx| self.graph[idx].path.clone())
+                    .collect();
+                referrers.sort();
+
+                SharedSubtree {
+                    root: self.graph[root].path.clone(),
+                    redundant_bytes: subtree_size_bytes * (referrers.len() as u64 - 1),
+                    referrers,
+                    subtree_asset_count: closure.len(),
+                    subtree_size_bytes,
+                }
+            })
+            .collect();
+
+        // Ties by path, so reports do not follow hash map order
+        shared.sort_by(|a, b| b.redundant_bytes.cmp(&a.redundant_bytes).then_with(|| a.root.cmp(&b.root)));
+        shared
+    }
+
+    /// Paths reachable from any of `roots` through hard edges, roots included
+    pub fn hard_closure_of(&self, roots: &HashSet<PathBuf>) -> HashSet<PathBuf> {
+        self.hard_closure_where(roots, |_| true)
+    }
+
+    /// `hard_closure_of` that neither includes nor passes through nodes
+    /// whose path fails `keep`
+    pub fn hard_closure_where(
+        &self,
+        roots: &HashSet<PathBuf>,
+        keep: impl Fn(&Path) -> bool,
+    ) -> HashSet<PathBuf> {
+        roots
+            .iter()
+            .filter_map(|path| self.path_to_node.get(path))
+            .flat_map(|&idx| self.hard_closure_filtered(idx, &keep))
+            .map(|idx| self.graph[idx].path.clone())
+            .collect()
+    }
+
+    /// Assets that hard-reference any of `changed`, directly or through other
+    /// assets, mapped to the dependency they were reached through (`None`
+    /// for `changed` itself)
+    pub fn hard_dependents_of(&self, changed: &HashSet<PathBuf>) -> HashMap<PathBuf, Option<PathBuf>> {
+        let mut reached: HashMap<NodeIndex, Option<NodeIndex>> = HashMap::new();
+        let mut queue: VecDeque<NodeIndex> = VecDeque::new();
+        for &idx in changed.iter().filter_map(|path| self.path_to_node.get(path)) {
+            if reached.insert(idx, None).is_none() {
+                queue.push_back(idx);
+            }
+        }
+
+        while let Some(idx) = queue.pop_front() {
+            for edge in self.graph.edges_directed(idx, petgraph::Direction::Incoming) {
+                if edge.weight().is_hard && !reached.contains_key(&edge.source()) {
+                    reached.insert(edge.source(), Some(idx));
+                    queue.push_back(edge.source());
+                }
+            }
+        }
+
+        reached
+            .into_iter()
+            .map(|(idx, via)| (self.graph[idx].path.clone(), via.map(|v| self.graph[v].path.clone())))
+            .collect()
+    }
+
+    /// Assets reached from `path` along dependency edges, or against them
+    /// (its dependents) with `reverse`, nearest first. Each comes with its
+    /// depth and the asset it was first reached through.
+    pub fn reachable_from(&self, path: &Path, reverse: bool, hard_only: bool) -> Vec<ReachedAsset<'_>> {
+        let Some(&start) = self.path_to_node.get(path) else {
+            return Vec::new();
+        };
+        let direction = if reverse { SubgraphDirection::Dependents } else { SubgraphDirection::Dependencies };
+        let mut assets: Vec<ReachedAsset<'_>> = self
+            .neighborhood(&[start], direction, None, hard_only)
+            .into_iter()
+            .filter(|(idx, _)| *idx != start)
+            .map(|(idx, (depth, via))| ReachedAsset { node: &self.graph[idx], depth, via: &self.graph[via] })
+            .collect();
+        assets.sort_by(|a, b| a.depth.cmp(&b.depth).then_with(|| a.node.path.cmp(&b.node.path)));
+        assets
+    }
+
+    /// The part of the graph within `depth` edges of `roots` (unbounded if
+    /// `None`) in `direction`, with every edge between the assets kept
+    pub fn subgraph(&self, roots: &HashSet<PathBuf>, depth: Option<usize>, direction: SubgraphDirection) -> Self {
+        let mut starts: Vec<NodeIndex> = roots.iter().filter_map(|path| self.path_to_node.get(path).copied()).collect();
+        starts.sort();
+        let mut kept: Vec<NodeIndex> = self.neighborhood(&starts, direction, depth, false).into_keys().collect();
+        kept.sort();
+
+        let mut sub = Self { coverage: self.coverage.clone(), ..Self::new() };
+        let mut mapped: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+        for idx in kept {
+            let node = self.graph[idx].clone();
+            let new_idx = sub.graph.add_node(node);
+            sub.path_to_node.insert(self.graph[idx].path.clone(), new_idx);
+            mapped.insert(idx, new_idx);
+        }
+        for edge in self.graph.edge_references() {
+            if let (Some(&from), Some(&to)) = (mapped.get(&edge.source()), mapped.get(&edge.target())) {
+                sub.graph.add_edge(from, to, edge.weight().clone());
+            }
+        }
+        sub
+    }
+
+    /// Breadth-first walk from `starts`: depth of every asset reached and
+    /// the asset it was first reached through (itself for the starts)
+    fn neighborhood(
+        &self,
+        starts: &[NodeIndex],
+        direction: SubgraphDirection,
+        max_depth: Option<usize>,
+        hard_only: bool,
+    ) -> HashMap<NodeIndex, (usize, NodeIndex)> {
+        let directions: &[petgraph::Direction] = match direction {
+            SubgraphDirection::Dependencies => &[petgraph::Direction::Outgoing],
+            SubgraphDirection::Dependents => &[petgraph::Direction::Incoming],
+            SubgraphDirection::Both => &[petgraph::Direction::Outgoing, petgraph::Direction::Incoming],
+        };
+        let mut reached: HashMap<NodeIndex, (usize, NodeIndex)> = starts.iter().map(|&idx| (idx, (0, idx))).collect();
+        let mut queue: VecDeque<NodeIndex> = starts.iter().copied().collect();
+
+        while let Some(idx) = queue.pop_front() {
+            let depth = reached[&idx].0;
+            if max_depth.is_some_and(|max| depth >= max) {
+                continue;
+            }
+            for &dir in directions {
+                for edge in self.graph.edges_directed(idx, dir) {
+                    let next = if dir == petgraph::Direction::Incoming { edge.source() } else { edge.target() };
+                    if (hard_only && !edge.weight().is_hard) || reached.contains_key(&next) {
+                        continue;
+                    }
+                    reached.insert(next, (depth + 1, idx));
+                    queue.push_back(next);
+                }
+            }
+        }
+        reached
+    }
+
+    /// Immediate dominator of every asset hard-reachable from `roots`: the
+    /// asset through which all of its startup load paths pass. `None` means
+    /// only the startup itself does (a root, or an asset several roots share).
+    pub fn hard_dominators(&self, roots: &HashSet<PathBuf>) -> HashMap<PathBuf, Option<PathBuf>> {
+        use petgraph::algo::dominators::simple_fast;
+
+        // Hard subgraph under a virtual start node
+        let mut sub: DiGraph<Option<NodeIndex>, ()> = DiGraph::new();
+        let start = sub.add_node(None);
+        let mut mapped: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+        let mut stack: Vec<NodeIndex> = Vec::new();
+        let mut root_indices: Vec<NodeIndex> = roots.iter().filter_map(|p| self.path_to_node.get(p).copied()).collect();
+        root_indices.sort();
+        for idx in root_indices {
+            let node = *mapped.entry(idx).or_insert_with(|| sub.add_node(Some(idx)));
+            sub.add_edge(start, node, ());
+            stack.push(idx);
+        }
+        let mut visited = HashSet::new();
+        while let Some(idx) = stack.pop() {
+            if !visited.insert(idx) {
+                continue;
+            }
+            let from = mapped[&idx];
+            for edge in self.graph.edges(idx).filter(|e| e.weight().is_hard) {
+                let target = edge.target();
+                let to = *mapped.entry(target).or_insert_with(|| sub.add_node(Some(target)));
+                sub.add_edge(from, to, ());
+                stack.push(target);
+            }
+        }
+
+        let dominators = simple_fast(&sub, start);
+        mapped
+            .iter()
+            .map(|(&idx, &node)| {
+                let parent = dominators
+                    .immediate_dominator(node)
+                    .and_then(|d| sub[d])
+                    .map(|d| self.graph[d].path.clone());
+                (self.graph[idx].path.clone(), parent)
+            })
+            .collect()
+    }
+
+    /// Bytes loaded at startup from `roots` and their dependencies. `lower`
+    /// counts only assets reached through certain hard edges, `upper` every
+    /// asset any load-bearing edge reaches, and `value` weighs each asset by
+    /// the likeliest path's edge confidences.
+    pub fn closure_size_estimate(&self, roots: &HashSet<PathBuf>) -> Estimate {
+        self.closure_size_estimate_where(roots, |_| true)
+    }
+
+    /// `closure_size_estimate` over only the nodes whose path passes `keep`
+    pub fn closure_size_estimate_where(
+        &self,
+        roots: &HashSet<PathBuf>,
+        keep: impl Fn(&Path) -> bool,
+    ) -> Estimate {
+        let mut probability: HashMap<NodeIndex, f64> = HashMap::new();
+        let mut stack: Vec<(NodeIndex, f64)> = roots
+            .iter()
+            .filter_map(|path| self.path_to_node.get(path))
+            .map(|&idx| (idx, 1.0))
+            .collect();
+
+        while let Some((idx, p)) = stack.pop() {
+            if probability.get(&idx).is_some_and(|&known| known >= p) || !keep(&self.graph[idx].path) {
+                continue;
+            }
+            probability.insert(idx, p);
+            for edge in self.graph.edges(idx) {
+                let next = p * edge.weight().load_probability();
+                if next > 0.0 {
+                    stack.push((edge.target(), next));
+                }
+            }
+        }
+
+        let mut estimate = Estimate::default();
+        for (idx, p) in probability {
+            let size = self.graph[idx].size_bytes as f64;
+            estimate.value += size * p;
+            estimate.upper += size;
+            if p >= 1.0 {
+                estimate.lower += size;
+            }
+        }
+        estimate
+    }
+
+    /// All nodes reachable from `root` through hard edges, including `root`
+    fn hard_closure(&self, root: NodeIndex) -> Vec<NodeIndex> {
+        self.hard_closure_filtered(root, &|_| true)
+    }
+
+    fn hard_closure_filtered(&self, root: NodeIndex, keep: &dyn Fn(&Path) -> bool) -> Vec<NodeIndex> {
+        let mut visited = HashSet::new();
+        let mut stack = vec![root];
+
+        while let Some(idx) = stack.pop() {
+            if !keep(&self.graph[idx].path) || !visited.insert(idx) {
+                continue;
+            }
+            for edge in self.graph.edges(idx) {
+                if edge.weight().is_hard {
+                    stack.push(edge.target());
+                }
+            }
+        }
+
+        visited.into_iter().collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -310,12 +1213,146 @@ mod tests {
         assert_eq!(graph.edge_count(), 0);
     }
 
+    fn test_asset(path: &str, size_bytes: u64) -> AssetInfo {
+        AssetInfo {
+            path: PathBuf::from(path),
+            relative_path: path.to_string(),
+            asset_type: AssetType::UAsset,
+            size_bytes,
+            modified: 0,
+            mount_point: "/Game".to_string(),
+        }
+    }
+
     #[test]
-    fn test_resolve_import_path() {
+    fn test_find_shared_subtrees() {
+        let mut graph = DependencyGraph::new();
+        for (path, size) in [("A", 1), ("B", 1), ("C", 1), ("Shared", 100), ("Leaf", 50)] {
+            graph.add_asset(&test_asset(path, size));
+        }
+        for from in ["A", "B", "C"] {
+            graph.add_dependency(Path::new(from), Path::new("Shared"), DependencyType::Import, true);
+        }
+        graph.add_dependency(Path::new("Shared"), Path::new("Leaf"), DependencyType::Import, true);
+
+        let startup: HashSet<PathBuf> = ["A", "B", "C"].iter().map(PathBuf::from).collect();
+        let shared = graph.find_shared_subtrees(&startup, 3);
+
+        assert_eq!(shared.len(), 1);
+        assert_eq!(shared[0].root, PathBuf::from("Shared"));
+        assert_eq!(shared[0].subtree_asset_count, 2);
+        assert_eq!(shared[0].subtree_size_bytes, 150);
+        assert_eq!(shared[0].redundant_bytes, 300);
+
+        // Subtrees saving the same bytes come out by root path
+        for path in ["Zeta", "Alpha", "Mid"] {
+            graph.add_asset(&test_asset(path, 100));
+            for from in ["A", "B"] {
+                graph.add_dependency(Path::new(from), Path::new(path), DependencyType::Import, true);
+            }
+        }
+        let roots: Vec<PathBuf> = graph.find_shared_subtrees(&startup, 2).into_iter().map(|s| s.root).collect();
+        assert_eq!(roots, ["Shared", "Alpha", "Mid", "Zeta"].map(PathBuf::from));
+    }
+
+    #[test]
+    fn test_subgraph_limits_depth_and_direction() {
+        let mut graph = DependencyGraph::new();
+        for path in ["Map", "Menu", "Button", "Font", "Other"] {
+            graph.add_asset(&test_asset(path, 10));
+        }
+        for (from, to) in [("Map", "Menu"), ("Menu", "Button"), ("Button", "Font"), ("Other", "Font")] {
+            graph.add_dependency(Path::new(from), Path::new(to), DependencyType::Import, true);
+        }
+        let roots: HashSet<PathBuf> = [PathBuf::from("Menu")].into_iter().collect();
+
+        let near = graph.subgraph(&roots, Some(1), SubgraphDirection::Dependencies);
+        assert_eq!((near.node_count(), near.edge_count()), (2, 1));
+        let around = graph.subgraph(&roots, Some(1), SubgraphDirection::Both);
+        assert_eq!((around.node_count(), around.edge_count()), (3, 2));
+        let below = graph.subgraph(&roots, None, SubgraphDirection::Dependencies);
+        assert_eq!(below.get_dependencies(Path::new("Button")).len(), 1);
+        assert!(below.get_dependents(Path::new("Font")).iter().all(|n| n.path != Path::new("Other")));
+        assert_eq!("reverse".parse::<SubgraphDirection>(), Ok(SubgraphDirection::Dependents));
+    }
+
+    #[test]
+    fn test_find_cycles_and_load_order() {
+        let mut graph = DependencyGraph::new();
+        for path in ["Map", "A", "B", "C", "Self", "Leaf"] {
+            graph.add_asset(&test_asset(path, 10));
+        }
+        let hard = [("Map", "A"), ("A", "B"), ("B", "A"), ("Self", "Self"), ("A", "Leaf")];
+        for (from, to) in hard {
+            graph.add_dependency(Path::new(from), Path::new(to), DependencyType::Import, true);
+        }
+        graph.add_dependency(Path::new("B"), Path::new("C"), DependencyType::Import, true);
+        graph.add_dependency(Path::new("C"), Path::new("B"), DependencyType::SoftReference, false);
+
+        let cycles = graph.find_cycles();
+        let names = |cycle: &[PathBuf]| cycle.iter().map(|p| p.to_string_lossy().into_owned()).collect::<Vec<_>>();
+        assert_eq!(cycles.len(), 2);
+        assert_eq!(names(&cycles[0]), ["A", "B", "C"]);
+        assert_eq!(names(&cycles[1]), ["Self"]);
+        let hard_cycles = graph.find_hard_cycles();
+        assert_eq!(names(&hard_cycles[0]), ["A", "B"]);
+
+        graph.compute_load_order();
+        let order: Vec<String> = graph.get_load_order().iter().map(|n| n.path.to_string_lossy().into_owned()).collect();
+        let position = |name: &str| order.iter().position(|p| p == name).unwrap();
+        assert!(position("Map") < position("A"));
+        assert!(position("B") < position("Leaf"));
+    }
+
+    #[test]
+    fn test_inferred_edges_are_soft() {
+        let mut graph = DependencyGraph::new();
+        for path in ["SK_Hero", "T_Hero_D"] {
+            graph.add_asset(&test_asset(path, 10));
+        }
+        graph.add_inferred_dependency(Path::new("SK_Hero"), Path::new("T_Hero_D"), DependencyType::Texture);
+
+        assert_eq!(graph.get_dependencies(Path::new("SK_Hero")).len(), 1);
+        let roots: HashSet<PathBuf> = [PathBuf::from("SK_Hero")].into_iter().collect();
+        assert_eq!(graph.hard_closure_of(&roots).len(), 1);
+        assert!(graph.to_dot().contains("style=dashed"));
+        assert!(graph.to_graphml().contains("<data key=\"dependency\">texture</data>"));
+        assert!(graph.to_json().contains("\"confidence\":\"heuristic\""));
+    }
+
+    #[test]
+    fn test_closure_size_estimate() {
+        let mut graph = DependencyGraph::new();
+        for (path, size) in [("Map", 10), ("Hard", 100), ("Guess", 40), ("Soft", 1000)] {
+            graph.add_asset(&test_asset(path, size));
+        }
+        graph.add_dependency(Path::new("Map"), Path::new("Hard"), DependencyType::Import, true);
+        graph.add_dependency(Path::new("Map"), Path::new("Soft"), DependencyType::SoftReference, false);
+        graph.add_inferred_dependency(Path::new("Hard"), Path::new("Guess"), DependencyType::Texture);
+
+        let roots: HashSet<PathBuf> = [PathBuf::from("Map")].into_iter().collect();
+        let estimate = graph.closure_size_estimate(&roots);
+        assert_eq!(estimate.lower, 110.0);
+        assert_eq!(estimate.value, 130.0);
+        assert_eq!(estimate.upper, 150.0);
+    }
+
+    #[test]
+    fn test_parser_coverage() {
+        assert_eq!(ParserCoverage::default().parsed_ratio(), 1.0);
+
+        let mut coverage = ParserCoverage { packages: 4, parsed: 3, parsed_edges: 9, heuristic_edges: 1, ..Default::default() };
+        coverage.failures.insert(ParseFailure::Zen, 1);
+        assert_eq!(coverage.parsed_ratio(), 0.75);
+        assert_eq!(coverage.parsed_edge_ratio(), 0.9);
+        assert_eq!(coverage.describe_failures(), "zen: 1");
+    }
+
+    #[test]
+    fn test_import_path() {
         let project = Path::new("C:/Projects/MyGame");
-        
-        let result = resolve_import_path(project, "/Game/Characters/Hero");
-        // Will be None since path doesn't exist, but tests the logic
-        assert!(result.is_none());
+
+        let result = import_path(project, &content_roots(project), "/Game/Characters/Hero");
+        assert_eq!(result, project.join("Content/Characters/Hero.uasset"));
     }
 }
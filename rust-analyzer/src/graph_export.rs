@@ -0,0 +1,464 @@
+//! Graph Export Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Streams a dependency graph to DOT, GraphML or JSON. Nodes and edges are written
+//! a batch at a time, with each batch's lines formatted in parallel, so a
+//! graph of millions of assets never sits in memory as one string. Caps and
+//! filters cut graphs that are too large to lay out down to the part that
+//! matters for startup. Node ids are hashes of project-relative paths, so
+//! exports of different runs can be diffed node by node.
+
+use crate::path_index::path_hash;
+use crate::graph::{AssetNode, DependencyEdge, DependencyGraph, EdgeConfidence};
+use crate::report::html_escape;
+use crate::scanner::AssetType;
+use crate::Result;
+use petgraph::graph::{EdgeIndex, NodeIndex};
+use rayon::prelude::*;
+use serde::Serialize;
+use serde_json::json;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Nodes or edges formatted together before they are written
+const BATCH_SIZE: usize = 64 * 1024;
+
+/// Slice of a batch formatted by one worker
+const WORKER_CHUNK: usize = 4 * 1024;
+
+/// Id of the asset at `relative_path` in every export: the same path gets
+/// the same id whatever else changed in the project
+pub fn node_id(relative_path: &str) -> String {
+    format_id(path_hash(relative_path.replace('\\', "/").as_bytes()))
+}
+
+fn format_id(hash: u64) -> String {
+    format!("n{:016x}", hash)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    Dot,
+    GraphMl,
+    /// `{"nodes": [...], "edges": [...]}` with every node and edge field,
+    /// for custom viewers
+    Json,
+}
+
+impl GraphFormat {
+    /// GraphML for `.graphml` files, JSON for `.json`, DOT for anything else
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("graphml") => GraphFormat::GraphMl,
+            Some(ext) if ext.eq_ignore_ascii_case("json") => GraphFormat::Json,
+            _ => GraphFormat::Dot,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GraphFormat::Dot => "dot",
+            GraphFormat::GraphMl => "graphml",
+            GraphFormat::Json => "json",
+        }
+    }
+
+    /// Written between two nodes or two edges
+    fn separator(&self) -> &'static str {
+        match self {
+            GraphFormat::Json => ",\n",
+            GraphFormat::Dot | GraphFormat::GraphMl => "",
+        }
+    }
+}
+
+impl FromStr for GraphFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "dot" | "gv" => Ok(GraphFormat::Dot),
+            "graphml" => Ok(GraphFormat::GraphMl),
+            "json" => Ok(GraphFormat::Json),
+            other => Err(format!("unknown graph format '{}' (expected dot, graphml, json)", other)),
+        }
+    }
+}
+
+/// What an export wrote and what the caps and filters left out
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ExportSummary {
+    pub nodes: usize,
+    pub edges: usize,
+    pub skipped_nodes: usize,
+    pub skipped_edges: usize,
+}
+
+pub struct GraphExporter<'a> {
+    graph: &'a DependencyGraph,
+    project_root: PathBuf,
+    max_nodes: Option<usize>,
+    max_edges: Option<usize>,
+    under: Option<String>,
+    asset_types: Vec<AssetType>,
+    hard_only: bool,
+}
+
+impl<'a> GraphExporter<'a> {
+    pub fn new(graph: &'a DependencyGraph, project_root: &Path) -> Self {
+        Self {
+            graph,
+            project_root: project_root.to_path_buf(),
+            max_nodes: None,
+            max_edges: None,
+            under: None,
+            asset_types: Vec::new(),
+            hard_only: false,
+        }
+    }
+
+    /// Keep at most `max` nodes, startup-critical ones first, then in load order
+    pub fn with_max_nodes(mut self, max: Option<usize>) -> Self {
+        self.max_nodes = max;
+        self
+    }
+
+    /// Keep at most `max` edges, the most trustworthy first
+    pub fn with_max_edges(mut self, max: Option<usize>) -> Self {
+        self.max_edges = max;
+        self
+    }
+
+    /// Keep only assets under a project-relative folder such as `Content/UI`
+    pub fn with_under(mut self, folder: Option<String>) -> Self {
+        self.under = folder.map(|folder| folder.replace('\\', "/").trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Keep only assets of these types; all types when empty
+    pub fn with_asset_types(mut self, types: Vec<AssetType>) -> Self {
+        self.asset_types = types;
+        self
+    }
+
+    /// Drop soft and inferred edges
+    pub fn with_hard_only(mut self, hard_only: bool) -> Self {
+        self.hard_only = hard_only;
+        self
+    }
+
+    pub fn write_file(&self, path: &Path, format: GraphFormat) -> Result<ExportSummary> {
+        let mut out = BufWriter::new(File::create(path)?);
+        let summary = self.write(&mut out, format)?;
+        out.flush()?;
+        Ok(summary)
+    }
+
+    pub fn write(&self, out: &mut dyn Write, format: GraphFormat) -> Result<ExportSummary> {
+        let graph = self.graph.petgraph();
+        let mut nodes = self.select_nodes();
+        // Path hash of every kept node, by graph index
+        let mut ids: Vec<Option<u64>> = vec![None; graph.node_count()];
+        let hashes: Vec<u64> = nodes.par_iter().map(|idx| path_hash(self.relative_path(&graph[*idx]).as_bytes())).collect();
+        for (idx, hash) in nodes.iter().zip(hashes) {
+            ids[idx.index()] = Some(hash);
+        }
+        let mut edges = self.select_edges(&ids);
+
+        // Written in id order, so runs that scanned in a different order
+        // still produce the same file
+        let id_of = |idx: NodeIndex| ids[idx.index()].unwrap();
+        nodes.par_sort_unstable_by_key(|&idx| id_of(idx));
+        edges.par_sort_by_key(|&idx| {
+            let (from, to) = graph.edge_endpoints(idx).unwrap();
+            (id_of(from), id_of(to))
+        });
+
+        match format {
+            GraphFormat::Dot => out.write_all(b"digraph dependencies {\n    node [shape=box];\n")?,
+            GraphFormat::GraphMl => out.write_all(GRAPHML_HEADER.as_bytes())?,
+            GraphFormat::Json => out.write_all(b"{\n  \"nodes\": [\n")?,
+        }
+        write_batches(out, &nodes, format.separator(), |idx| {
+            self.node_line(format, ids[idx.index()].unwrap(), &graph[*idx])
+        })?;
+        if format == GraphFormat::Json {
+            out.write_all(b"\n  ],\n  \"edges\": [\n")?;
+        }
+        write_batches(out, &edges, format.separator(), |idx| {
+            let (from, to) = graph.edge_endpoints(*idx).unwrap();
+            edge_line(format, id_of(from), id_of(to), &graph[*idx])
+        })?;
+        match format {
+            GraphFormat::Dot => out.write_all(b"}\n")?,
+            GraphFormat::GraphMl => out.write_all(b"  </graph>\n</graphml>\n")?,
+            GraphFormat::Json => out.write_all(b"\n  ]\n}\n")?,
+        }
+
+        Ok(ExportSummary {
+            nodes: nodes.len(),
+            edges: edges.len(),
+            skipped_nodes: graph.node_count() - nodes.len(),
+            skipped_edges: graph.edge_count() - edges.len(),
+        })
+    }
+
+    fn select_nodes(&self) -> Vec<NodeIndex> {
+        let graph = self.graph.petgraph();
+        let mut nodes: Vec<NodeIndex> = graph
+            .node_indices()
+            .filter(|&idx| {
+                let node = &graph[idx];
+                (self.asset_types.is_empty() || self.asset_types.contains(&node.asset_type))
+                    && self.under.as_deref().is_none_or(|folder| {
+                        let path = self.relative_path(node);
+                        path.strip_prefix(folder).is_some_and(|rest| rest.starts_with('/'))
+                    })
+            })
+            .collect();
+        if let Some(max) = self.max_nodes {
+            if nodes.len() > max {
+                nodes.sort_by_key(|&idx| {
+                    let node = &graph[idx];
+                    (!node.is_startup_critical, node.load_order.unwrap_or(u32::MAX), idx)
+                });
+                nodes.truncate(max);
+                nodes.sort();
+            }
+        }
+        nodes
+    }
+
+    fn select_edges(&self, ids: &[Option<u64>]) -> Vec<EdgeIndex> {
+        let graph = self.graph.petgraph();
+        let mut edges: Vec<EdgeIndex> = graph
+            .edge_indices()
+            .filter(|&idx| {
+                let (from, to) = graph.edge_endpoints(idx).unwrap();
+                ids[from.index()].is_some() && ids[to.index()].is_some() && (!self.hard_only || graph[idx].is_hard)
+            })
+            .collect();
+        if let Some(max) = self.max_edges {
+            if edges.len() > max {
+                edges.sort_by_key(|&idx| (graph[idx].confidence, !graph[idx].is_hard, idx));
+                edges.truncate(max);
+                edges.sort();
+            }
+        }
+        edges
+    }
+
+    fn relative_path(&self, node: &AssetNode) -> String {
+        node.path
+            .strip_prefix(&self.project_root)
+            .unwrap_or(&node.path)
+            .to_string_lossy()
+            .replace('\\', "/")
+    }
+
+    fn node_line(&self, format: GraphFormat, id: u64, node: &AssetNode) -> String {
+        let path = self.relative_path(node);
+        match format {
+            GraphFormat::Dot => format!(
+                "    {} [label=\"{}\", asset_type=\"{}\", size_bytes={}{}];\n",
+                format_id(id),
+                path.replace('\\', "\\\\").replace('"', "\\\""),
+                node.asset_type.as_str(),
+                node.size_bytes,
+                if node.is_startup_critical { ", penwidth=2" } else { "" }
+            ),
+            GraphFormat::GraphMl => format!(
+                "    <node id=\"{}\"><data key=\"path\">{}</data><data key=\"type\">{}</data>\
+                 <data key=\"size\">{}</data><data key=\"startup\">{}</data></node>\n",
+                format_id(id),
+                html_escape(&path),
+                node.asset_type.as_str(),
+                node.size_bytes,
+                node.is_startup_critical
+            ),
+            GraphFormat::Json => format!(
+                "    {}",
+                json!({
+                    "id": format_id(id),
+                    "path": path,
+                    "asset_type": node.asset_type.as_str(),
+                    "size_bytes": node.size_bytes,
+                    "startup_critical": node.is_startup_critical,
+                    "load_order": node.load_order,
+                })
+            ),
+        }
+    }
+}
+
+fn edge_line(format: GraphFormat, from: u64, to: u64, edge: &DependencyEdge) -> String {
+    let (from, to) = (format_id(from), format_id(to));
+    match format {
+        // Less certain edges are drawn lighter so guesses stand out from parses
+        GraphFormat::Dot => match edge.confidence {
+            EdgeConfidence::ParsedHard => format!("    {} -> {};\n", from, to),
+            EdgeConfidence::ParsedSoft | EdgeConfidence::RegistryDerived => {
+                format!("    {} -> {} [style=dotted];\n", from, to)
+            }
+            EdgeConfidence::Heuristic => format!("    {} -> {} [style=dashed];\n", from, to),
+        },
+        GraphFormat::GraphMl => format!(
+            "    <edge source=\"{}\" target=\"{}\"><data key=\"dependency\">{}</data>\
+             <data key=\"confidence\">{}</data><data key=\"hard\">{}</data></edge>\n",
+            from,
+            to,
+            edge.dependency_type.as_str(),
+            edge.confidence.as_str(),
+            edge.is_hard
+        ),
+        GraphFormat::Json => format!(
+            "    {}",
+            json!({
+                "source": from,
+                "target": to,
+                "dependency_type": edge.dependency_type.as_str(),
+                "hard": edge.is_hard,
+                "confidence": edge.confidence.as_str(),
+            })
+        ),
+    }
+}
+
+const GRAPHML_HEADER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<graphml xmlns="http://graphml.graphdrawing.org/xmlns">
+  <key id="path" for="node" attr.name="path" attr.type="string"/>
+  <key id="type" for="node" attr.name="asset_type" attr.type="string"/>
+  <key id="size" for="node" attr.name="size_bytes" attr.type="long"/>
+  <key id="startup" for="node" attr.name="startup_critical" attr.type="boolean"/>
+  <key id="dependency" for="edge" attr.name="dependency_type" attr.type="string"/>
+  <key id="confidence" for="edge" attr.name="confidence" attr.type="string"/>
+  <key id="hard" for="edge" attr.name="hard" attr.type="boolean"/>
+  <graph id="dependencies" edgedefault="directed">
+"#;
+
+/// Format `items` a batch at a time, in parallel within a batch, and write
+/// the lines in order with `separator` between each two
+fn write_batches<T: Sync>(
+    out: &mut dyn Write,
+    items: &[T],
+    separator: &str,
+    line: impl Fn(&T) -> String + Sync,
+) -> Result<()> {
+    for (i, batch) in items.chunks(BATCH_SIZE).enumerate() {
+        let chunks: Vec<String> = batch
+            .par_chunks(WORKER_CHUNK)
+            .map(|chunk| chunk.iter().map(&line).collect::<Vec<_>>().join(separator))
+            .collect();
+        for (j, chunk) in chunks.iter().enumerate() {
+            if i > 0 || j > 0 {
+                out.write_all(separator.as_bytes())?;
+            }
+            out.write_all(chunk.as_bytes())?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::DependencyType;
+    use crate::scanner::AssetInfo;
+
+    fn sample_graph() -> DependencyGraph {
+        let mut graph = DependencyGraph::new();
+        for (path, asset_type) in [
+            ("Content/UI/Menu.uasset", AssetType::UAsset),
+            ("Content/Maps/Main.umap", AssetType::UMap),
+            ("Content/UI/Font.uasset", AssetType::UAsset),
+            ("Content/FX/Smoke \"big\".uasset", AssetType::UAsset),
+        ] {
+            graph.add_asset(&AssetInfo {
+                path: PathBuf::from("/p").join(path),
+                relative_path: path.to_string(),
+                asset_type,
+                size_bytes: 10,
+                modified: 0,
+                mount_point: "/Game".to_string(),
+            });
+        }
+        let path = |p: &str| PathBuf::from("/p").join(p);
+        graph.mark_startup_roots(&[path("Content/Maps/Main.umap")].into_iter().collect());
+        graph.add_dependency(&path("Content/Maps/Main.umap"), &path("Content/UI/Menu.uasset"), DependencyType::Blueprint, true);
+        graph.add_dependency(&path("Content/UI/Menu.uasset"), &path("Content/UI/Font.uasset"), DependencyType::Import, true);
+        graph.add_inferred_dependency(
+            &path("Content/Maps/Main.umap"),
+            &path("Content/FX/Smoke \"big\".uasset"),
+            DependencyType::Material,
+        );
+        graph
+    }
+
+    fn export(exporter: &GraphExporter<'_>, format: GraphFormat) -> (String, ExportSummary) {
+        let mut out = Vec::new();
+        let summary = exporter.write(&mut out, format).unwrap();
+        (String::from_utf8(out).unwrap(), summary)
+    }
+
+    #[test]
+    fn test_dot_caps_and_filters() {
+        let graph = sample_graph();
+        let (dot, summary) = export(&GraphExporter::new(&graph, Path::new("/p")), GraphFormat::Dot);
+        assert_eq!((summary.nodes, summary.edges), (4, 3));
+        assert!(dot.contains("label=\"Content/FX/Smoke \\\"big\\\".uasset\""));
+        let dashed = format!(
+            "{} -> {} [style=dashed];",
+            node_id("Content/Maps/Main.umap"),
+            node_id("Content/FX/Smoke \"big\".uasset")
+        );
+        assert!(dot.contains(&dashed));
+
+        let capped = GraphExporter::new(&graph, Path::new("/p")).with_max_nodes(Some(1)).with_hard_only(true);
+        let (dot, summary) = export(&capped, GraphFormat::Dot);
+        assert_eq!((summary.nodes, summary.skipped_nodes, summary.edges), (1, 3, 0));
+        assert!(dot.contains("Main.umap"));
+
+        let ui = GraphExporter::new(&graph, Path::new("/p")).with_under(Some("Content/UI/".to_string()));
+        let (ui_dot, summary) = export(&ui, GraphFormat::Dot);
+        assert_eq!((summary.nodes, summary.edges), (2, 1));
+        // Filtering leaves the ids of the remaining nodes alone
+        let menu = format!("{} [label=\"Content/UI/Menu.uasset\"", node_id("Content/UI/Menu.uasset"));
+        assert!(ui_dot.contains(&menu));
+    }
+
+    #[test]
+    fn test_graphml_is_escaped() {
+        let graph = sample_graph();
+        let exporter = GraphExporter::new(&graph, Path::new("/p")).with_max_edges(Some(2));
+        let (xml, summary) = export(&exporter, GraphFormat::GraphMl);
+        assert_eq!(summary.skipped_edges, 1);
+        assert!(xml.contains("Smoke &quot;big&quot;.uasset"));
+        assert!(!xml.contains("heuristic"));
+        assert!(xml.ends_with("</graphml>\n"));
+        assert!(xml.contains("<data key=\"dependency\">blueprint</data>"));
+        assert_eq!(GraphFormat::from_path(Path::new("deps.GraphML")), GraphFormat::GraphMl);
+    }
+
+    #[test]
+    fn test_json_keeps_edge_metadata() {
+        let graph = sample_graph();
+        let (json, summary) = export(&GraphExporter::new(&graph, Path::new("/p")), GraphFormat::Json);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let nodes = value["nodes"].as_array().unwrap();
+        let edges = value["edges"].as_array().unwrap();
+        assert_eq!((nodes.len(), edges.len()), (summary.nodes, summary.edges));
+        let main = nodes.iter().find(|n| n["path"] == "Content/Maps/Main.umap").unwrap();
+        assert_eq!(main["startup_critical"], true);
+        assert_eq!(main["size_bytes"], 10);
+        let smoke = edges.iter().find(|e| e["dependency_type"] == "material").unwrap();
+        assert_eq!((&smoke["hard"], &smoke["confidence"]), (&json!(false), &json!("heuristic")));
+
+        let empty = DependencyGraph::new();
+        let (json, _) = export(&GraphExporter::new(&empty, Path::new("/p")), GraphFormat::Json);
+        assert!(serde_json::from_str::<serde_json::Value>(&json).is_ok());
+        assert_eq!("JSON".parse::<GraphFormat>(), Ok(GraphFormat::Json));
+    }
+}
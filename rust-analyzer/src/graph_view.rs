@@ -0,0 +1,131 @@
+//! Graph View Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! An immutable, shareable handle on a built dependency graph. Cloning a
+//! view is a reference count bump, and a view has no way to mutate the
+//! graph, so any number of threads (e.g. request handlers of a long-running
+//! server) can query it at once without copies or locks.
+
+use crate::graph::{AssetNode, DependencyGraph, GraphReport, ReachedAsset, SubgraphDirection};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct GraphView {
+    graph: Arc<DependencyGraph>,
+}
+
+impl GraphView {
+    pub fn new(graph: DependencyGraph) -> Self {
+        Self { graph: Arc::new(graph) }
+    }
+
+    /// The frozen graph, for queries without a shortcut here
+    pub fn graph(&self) -> &DependencyGraph {
+        &self.graph
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+
+    pub fn node(&self, path: &Path) -> Option<&AssetNode> {
+        self.graph.node(path)
+    }
+
+    pub fn dependencies(&self, path: &Path) -> Vec<&AssetNode> {
+        self.graph.get_dependencies(path)
+    }
+
+    pub fn dependents(&self, path: &Path) -> Vec<&AssetNode> {
+        self.graph.get_dependents(path)
+    }
+
+    /// See `DependencyGraph::reachable_from`
+    pub fn reachable_from(&self, path: &Path, reverse: bool, hard_only: bool) -> Vec<ReachedAsset<'_>> {
+        self.graph.reachable_from(path, reverse, hard_only)
+    }
+
+    pub fn hard_closure_of(&self, roots: &HashSet<PathBuf>) -> HashSet<PathBuf> {
+        self.graph.hard_closure_of(roots)
+    }
+
+    pub fn hard_dependents_of(&self, changed: &HashSet<PathBuf>) -> HashMap<PathBuf, Option<PathBuf>> {
+        self.graph.hard_dependents_of(changed)
+    }
+
+    /// An owned copy of a neighborhood, which the caller may change freely
+    pub fn subgraph(
+        &self,
+        roots: &HashSet<PathBuf>,
+        depth: Option<usize>,
+        direction: SubgraphDirection,
+    ) -> DependencyGraph {
+        self.graph.subgraph(roots, depth, direction)
+    }
+
+    pub fn find_cycles(&self) -> Vec<Vec<PathBuf>> {
+        self.graph.find_cycles()
+    }
+
+    pub fn report(&self, project_root: &Path) -> GraphReport {
+        self.graph.report(project_root)
+    }
+}
+
+impl From<DependencyGraph> for GraphView {
+    fn from(graph: DependencyGraph) -> Self {
+        Self::new(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::DependencyType;
+    use crate::scanner::{AssetInfo, AssetType};
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_concurrent_queries() {
+        assert_send_sync::<GraphView>();
+
+        let mut graph = DependencyGraph::new();
+        for path in ["Map", "Menu", "Font"] {
+            graph.add_asset(&AssetInfo {
+                path: PathBuf::from(path),
+                relative_path: path.to_string(),
+                asset_type: AssetType::UAsset,
+                size_bytes: 10,
+                modified: 0,
+                mount_point: "/Game".to_string(),
+            });
+        }
+        graph.add_dependency(Path::new("Map"), Path::new("Menu"), DependencyType::Import, true);
+        graph.add_dependency(Path::new("Menu"), Path::new("Font"), DependencyType::Import, true);
+        let view = graph.into_view();
+
+        let roots: HashSet<PathBuf> = [PathBuf::from("Map")].into_iter().collect();
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4)
+                .map(|_| {
+                    let view = view.clone();
+                    let roots = &roots;
+                    scope.spawn(move || (view.hard_closure_of(roots).len(), view.dependents(Path::new("Font")).len()))
+                })
+                .collect();
+            for handle in handles {
+                assert_eq!(handle.join().unwrap(), (3, 1));
+            }
+        });
+        assert_eq!(view.reachable_from(Path::new("Font"), true, false).len(), 2);
+        assert!(view.node(Path::new("Missing")).is_none());
+    }
+}
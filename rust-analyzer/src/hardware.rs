@@ -0,0 +1,231 @@
+//! Hardware Profile Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Runtime detection of CPU cores, RAM and the storage tier backing a project
+
+use crate::estimator::StorageTier;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Filesystems served over the network
+#[cfg(target_os = "linux")]
+const NETWORK_FILESYSTEMS: &[&str] = &["nfs", "nfs4", "cifs", "smb3", "smbfs", "fuse.sshfs", "9p", "afs"];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HardwareProfile {
+    pub cpu_cores: usize,
+    pub total_ram_bytes: Option<u64>,
+    /// Storage holding the project directory
+    pub storage: StorageTier,
+    pub os: String,
+}
+
+impl HardwareProfile {
+    /// Profile this machine for a project at `path`
+    pub fn detect(path: &Path) -> Self {
+        Self {
+            cpu_cores: std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1),
+            total_ram_bytes: total_ram_bytes(),
+            storage: detect_storage(path),
+            os: std::env::consts::OS.to_string(),
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        let ram = self.total_ram_bytes
+            .map(|b| format!("{:.0} GB RAM", b as f64 / (1024.0 * 1024.0 * 1024.0)))
+            .unwrap_or_else(|| "unknown RAM".to_string());
+        format!("{} cores, {}, {} storage ({})", self.cpu_cores, ram, self.storage, self.os)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn total_ram_bytes() -> Option<u64> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    parse_meminfo_total(&meminfo)
+}
+
+#[cfg(target_os = "macos")]
+fn total_ram_bytes() -> Option<u64> {
+    let output = std::process::Command::new("sysctl").args(["-n", "hw.memsize"]).output().ok()?;
+    String::from_utf8_lossy(&output.stdout).trim().parse().ok()
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn total_ram_bytes() -> Option<u64> {
+    None
+}
+
+/// Memory that can be allocated without swapping, right now
+#[cfg(target_os = "linux")]
+pub fn available_ram_bytes() -> Option<u64> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    parse_meminfo_field(&meminfo, "MemAvailable")
+}
+
+#[cfg(windows)]
+pub fn available_ram_bytes() -> Option<u64> {
+    /// `MEMORYSTATUSEX`
+    #[repr(C)]
+    struct MemoryStatus {
+        length: u32,
+        memory_load: u32,
+        total_phys: u64,
+        avail_phys: u64,
+        total_page_file: u64,
+        avail_page_file: u64,
+        total_virtual: u64,
+        avail_virtual: u64,
+        avail_extended_virtual: u64,
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GlobalMemoryStatusEx(buffer: *mut MemoryStatus) -> i32;
+    }
+
+    let mut status = MemoryStatus {
+        length: std::mem::size_of::<MemoryStatus>() as u32,
+        memory_load: 0,
+        total_phys: 0,
+        avail_phys: 0,
+        total_page_file: 0,
+        avail_page_file: 0,
+        total_virtual: 0,
+        avail_virtual: 0,
+        avail_extended_virtual: 0,
+    };
+    // `status` is a correctly sized MEMORYSTATUSEX with `length` set
+    let ok = unsafe { GlobalMemoryStatusEx(&mut status) };
+    (ok != 0).then_some(status.avail_phys)
+}
+
+#[cfg(not(any(target_os = "linux", windows)))]
+pub fn available_ram_bytes() -> Option<u64> {
+    None
+}
+
+/// `MemTotal:  16318480 kB`
+pub fn parse_meminfo_total(meminfo: &str) -> Option<u64> {
+    parse_meminfo_field(meminfo, "MemTotal")
+}
+
+/// Value of `field` in `/proc/meminfo`, in bytes
+pub fn parse_meminfo_field(meminfo: &str, field: &str) -> Option<u64> {
+    let line = meminfo
+        .lines()
+        .find(|l| l.split_once(':').is_some_and(|(name, _)| name == field))?;
+    let kb: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kb * 1024)
+}
+
+#[cfg(target_os = "linux")]
+fn detect_storage(path: &Path) -> StorageTier {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let Ok(mountinfo) = std::fs::read_to_string("/proc/self/mountinfo") else {
+        return StorageTier::Unknown;
+    };
+    let Some((fs_type, source)) = mount_for(&mountinfo, &path) else {
+        return StorageTier::Unknown;
+    };
+
+    if NETWORK_FILESYSTEMS.contains(&fs_type.as_str()) {
+        return StorageTier::Network;
+    }
+
+    // /dev/nvme0n1p2 -> nvme0n1, /dev/sda1 -> sda
+    let Some(device) = source.strip_prefix("/dev/") else {
+        return StorageTier::Unknown;
+    };
+    let block = block_device_name(device);
+    if block.starts_with("nvme") {
+        return StorageTier::Nvme;
+    }
+
+    match std::fs::read_to_string(format!("/sys/block/{}/queue/rotational", block)) {
+        Ok(value) if value.trim() == "1" => StorageTier::Hdd,
+        Ok(_) => StorageTier::Ssd,
+        Err(_) => StorageTier::Unknown,
+    }
+}
+
+#[cfg(windows)]
+fn detect_storage(path: &Path) -> StorageTier {
+    // UNC paths and mapped shares are the common shared-drive setups
+    if path.to_string_lossy().starts_with(r"\\") {
+        StorageTier::Network
+    } else {
+        StorageTier::Unknown
+    }
+}
+
+#[cfg(not(any(target_os = "linux", windows)))]
+fn detect_storage(_path: &Path) -> StorageTier {
+    StorageTier::Unknown
+}
+
+/// Filesystem type and source of the mount containing `path`, from
+/// `/proc/self/mountinfo` (longest matching mount point wins)
+pub fn mount_for(mountinfo: &str, path: &Path) -> Option<(String, String)> {
+    mountinfo
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let mount_point = fields.get(4)?;
+            let separator = fields.iter().position(|f| *f == "-")?;
+            let fs_type = fields.get(separator + 1)?;
+            let source = fields.get(separator + 2)?;
+            path.starts_with(mount_point)
+                .then(|| (mount_point.len(), fs_type.to_string(), source.to_string()))
+        })
+        .max_by_key(|(len, _, _)| *len)
+        .map(|(_, fs_type, source)| (fs_type, source))
+}
+
+/// Strip the partition suffix from a block device name
+pub fn block_device_name(device: &str) -> String {
+    if device.starts_with("nvme") || device.starts_with("mmcblk") {
+        // nvme0n1p2 -> nvme0n1
+        match device.rfind('p') {
+            Some(i) if i > 4 && device[i + 1..].chars().all(|c| c.is_ascii_digit()) => {
+                device[..i].to_string()
+            }
+            _ => device.to_string(),
+        }
+    } else {
+        device.trim_end_matches(|c: char| c.is_ascii_digit()).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mount_for() {
+        let mountinfo = "\
+22 1 259:2 / / rw,relatime shared:1 - ext4 /dev/nvme0n1p2 rw
+40 22 0:45 / /mnt/share rw,relatime shared:20 - cifs //server/share rw";
+
+        assert_eq!(
+            mount_for(mountinfo, Path::new("/home/dev/Game")),
+            Some(("ext4".to_string(), "/dev/nvme0n1p2".to_string()))
+        );
+        assert_eq!(
+            mount_for(mountinfo, Path::new("/mnt/share/Game")).map(|m| m.0),
+            Some("cifs".to_string())
+        );
+        assert_eq!(block_device_name("nvme0n1p2"), "nvme0n1");
+        assert_eq!(block_device_name("sda1"), "sda");
+    }
+
+    #[test]
+    fn test_parse_meminfo_total() {
+        let meminfo = "MemTotal:       16318480 kB\nMemFree:         1000 kB\nMemAvailable:    2000 kB\n";
+        assert_eq!(parse_meminfo_total(meminfo), Some(16318480 * 1024));
+        assert_eq!(parse_meminfo_field(meminfo, "MemAvailable"), Some(2000 * 1024));
+    }
+}
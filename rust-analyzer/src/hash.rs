@@ -6,18 +6,24 @@
 //! Optimized for maximum throughput with prefetch and parallel processing
 
 use crate::asm_bindings::HashState;
+use crate::direct_io::{open_uncached, read_block, AlignedBuffer, DIRECT_IO_BUFFER};
+use crate::estimator::StorageTier;
+use crate::mmap_pool::MmapPool;
 use crate::Result;
 use memmap2::Mmap;
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::path::Path;
 #[allow(unused_imports)]
 use std::path::PathBuf;
-use xxhash_rust::xxh3::xxh3_64;
+use xxhash_rust::xxh3::{xxh3_64, Xxh3};
 
 pub const CHUNK_SIZE: usize = 256 * 1024; // 256KB chunks for better throughput
 pub const SMALL_FILE_THRESHOLD: u64 = 4 * 1024; // 4KB - read directly
 pub const MMAP_THRESHOLD: u64 = 64 * 1024; // 64KB - use mmap above this
+pub const PARALLEL_HASH_THRESHOLD: u64 = 256 * 1024 * 1024; // 256MB - hash chunks in parallel above this
+pub const PARALLEL_CHUNK_SIZE: usize = 16 * 1024 * 1024; // 16MB per chunk lane
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ContentHash(pub u64);
@@ -38,8 +44,67 @@ impl std::fmt::Display for ContentHash {
     }
 }
 
+/// Content hash function of a cache, recorded in its header
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HashAlgorithm {
+    /// xxHash3 with the ASM fast path: fastest, not collision resistant
+    #[default]
+    Xxh3,
+    /// First 8 bytes of the BLAKE3 digest, for caches shared between
+    /// machines that must not be forged
+    Blake3,
+    /// CRC-32C (Castagnoli), hardware accelerated on x86 and ARM
+    Crc32c,
+}
+
+impl HashAlgorithm {
+    /// Name recorded as the cache's hash algorithm
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Xxh3 => "xxh3",
+            HashAlgorithm::Blake3 => "blake3",
+            HashAlgorithm::Crc32c => "crc32c",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "xxh3" => Some(HashAlgorithm::Xxh3),
+            "blake3" => Some(HashAlgorithm::Blake3),
+            "crc32c" => Some(HashAlgorithm::Crc32c),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for HashAlgorithm {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::from_name(&s.to_ascii_lowercase())
+            .ok_or_else(|| format!("unknown hash algorithm '{}' (expected xxh3, blake3 or crc32c)", s))
+    }
+}
+
 /// ULTRA-OPTIMIZED file hashing with adaptive I/O strategy
 pub fn hash_file(path: &Path) -> Result<ContentHash> {
+    hash_file_with(path, HashAlgorithm::Xxh3)
+}
+
+/// `hash_file` with `algorithm`
+pub fn hash_file_with(path: &Path, algorithm: HashAlgorithm) -> Result<ContentHash> {
+    hash_file_with_threshold(path, algorithm, PARALLEL_HASH_THRESHOLD)
+}
+
+/// `hash_file_with`, hashing chunks in parallel from `threshold` bytes
+fn hash_file_with_threshold(path: &Path, algorithm: HashAlgorithm, threshold: u64) -> Result<ContentHash> {
     let file = File::open(path)?;
     let metadata = file.metadata()?;
     let len = metadata.len();
@@ -47,17 +112,18 @@ pub fn hash_file(path: &Path) -> Result<ContentHash> {
     // Strategy 1: Very small files - direct read (fastest for tiny files)
     if len < SMALL_FILE_THRESHOLD {
         let data = std::fs::read(path)?;
-        return Ok(hash_bytes(&data));
+        return Ok(hash_bytes_with(&data, algorithm));
     }
     
     // Strategy 2: Small-medium files - buffered read
     if len < MMAP_THRESHOLD {
         let data = std::fs::read(path)?;
-        return Ok(hash_bytes(&data));
+        return Ok(hash_bytes_with(&data, algorithm));
     }
 
-    // Strategy 3: Large files - memory mapping with prefetch hint
-    let mmap = unsafe { Mmap::map(&file)? };
+    // Strategy 3: Large files - pooled memory mapping (shared with the
+    // package parser) with prefetch hint
+    let mmap = MmapPool::global().map(path)?;
     
     // Advise kernel for sequential access (prefetch optimization)
     #[cfg(unix)]
@@ -67,12 +133,275 @@ pub fn hash_file(path: &Path) -> Result<ContentHash> {
             libc::posix_fadvise(file.as_raw_fd(), 0, len as i64, libc::POSIX_FADV_SEQUENTIAL);
         }
     }
-    
-    Ok(hash_bytes(&mmap))
+
+    // Strategy 4: Huge .pak/.ubulk files - one lane per chunk so a single
+    // file does not serialize a worker for minutes. BLAKE3 is a tree hash,
+    // so its parallel result equals the sequential one.
+    if len >= threshold {
+        match algorithm {
+            HashAlgorithm::Xxh3 => return Ok(chunked_hash(&mmap)),
+            HashAlgorithm::Blake3 => {
+                let mut hasher = blake3::Hasher::new();
+                hasher.update_rayon(&mmap);
+                return Ok(blake3_hash(&hasher));
+            }
+            HashAlgorithm::Crc32c => {}
+        }
+    }
+
+    Ok(hash_bytes_with_threshold(&mmap, algorithm, threshold))
+}
+
+fn blake3_hash(hasher: &blake3::Hasher) -> ContentHash {
+    let digest = hasher.finalize();
+    let mut head = [0u8; 8];
+    head.copy_from_slice(&digest.as_bytes()[..8]);
+    ContentHash(u64::from_le_bytes(head))
+}
+
+/// Merkle-style hash: chunks are hashed in parallel, then the chunk hashes
+/// and the total length are hashed together. Deterministic regardless of
+/// thread count, but differs from `hash_bytes` of the same data;
+/// `hash_bytes_with` uses it for xxh3 above `PARALLEL_HASH_THRESHOLD`.
+pub fn chunked_hash(data: &[u8]) -> ContentHash {
+    let chunk_hashes: Vec<u64> = data
+        .par_chunks(PARALLEL_CHUNK_SIZE)
+        .map(xxh3_64)
+        .collect();
+
+    combine_lanes(&chunk_hashes, data.len() as u64)
+}
+
+pub(crate) fn combine_lanes(lane_hashes: &[u64], len: u64) -> ContentHash {
+    let mut combined = Vec::with_capacity(lane_hashes.len() * 8 + 8);
+    for hash in lane_hashes {
+        combined.extend_from_slice(&hash.to_le_bytes());
+    }
+    combined.extend_from_slice(&len.to_le_bytes());
+    ContentHash(xxh3_64(&combined))
+}
+
+/// Same result as `hash_file`, but read with unbuffered I/O so the timing
+/// reflects the disk rather than the page cache
+pub fn hash_file_direct(path: &Path) -> Result<ContentHash> {
+    hash_file_direct_with(path, HashAlgorithm::Xxh3)
+}
+
+/// Same result as `hash_file_with`, read with unbuffered I/O
+pub fn hash_file_direct_with(path: &Path, algorithm: HashAlgorithm) -> Result<ContentHash> {
+    hash_file_direct_with_threshold(path, algorithm, PARALLEL_HASH_THRESHOLD)
+}
+
+/// `hash_file_direct_with`, switching to `chunked_hash` lanes from
+/// `threshold` bytes
+fn hash_file_direct_with_threshold(path: &Path, algorithm: HashAlgorithm, threshold: u64) -> Result<ContentHash> {
+    let mut file = open_uncached(path)?;
+    let len = file.metadata()?.len();
+    let mut buffer = AlignedBuffer::new(DIRECT_IO_BUFFER);
+
+    if len >= threshold && algorithm == HashAlgorithm::Xxh3 {
+        // Lanes of `chunked_hash`, computed sequentially; DIRECT_IO_BUFFER
+        // divides PARALLEL_CHUNK_SIZE so no read spans two lanes
+        let mut lanes = Vec::new();
+        let mut lane = Xxh3::new();
+        let mut in_lane = 0;
+        let mut remaining = len;
+        while remaining > 0 {
+            let n = read_block(&mut file, &mut buffer, remaining.min(DIRECT_IO_BUFFER as u64) as usize)?;
+            if n == 0 {
+                break;
+            }
+            lane.update(&buffer.as_slice()[..n]);
+            in_lane += n;
+            remaining -= n as u64;
+            if in_lane == PARALLEL_CHUNK_SIZE {
+                lanes.push(lane.digest());
+                lane.reset();
+                in_lane = 0;
+            }
+        }
+        if in_lane > 0 {
+            lanes.push(lane.digest());
+        }
+        return Ok(combine_lanes(&lanes, len));
+    }
+
+    let mut hasher = StreamingHash::new(algorithm, len);
+    let mut remaining = len;
+    while remaining > 0 {
+        let n = read_block(&mut file, &mut buffer, remaining.min(DIRECT_IO_BUFFER as u64) as usize)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buffer.as_slice()[..n]);
+        remaining -= n as u64;
+    }
+    Ok(hasher.finish())
+}
+
+/// Streaming equivalent of `hash_bytes_with` for input of a known length.
+/// Updates must be multiples of 32KB except the last.
+enum StreamingHash {
+    Xxh3(Box<Xxh3>),
+    Blake3(Box<blake3::Hasher>),
+    Crc32c(u32),
+    #[cfg(feature = "asm_hotpaths")]
+    Asm(HashState),
+}
+
+impl StreamingHash {
+    fn new(algorithm: HashAlgorithm, len: u64) -> Self {
+        match algorithm {
+            HashAlgorithm::Xxh3 => {}
+            HashAlgorithm::Blake3 => return StreamingHash::Blake3(Box::new(blake3::Hasher::new())),
+            HashAlgorithm::Crc32c => return StreamingHash::Crc32c(0),
+        }
+        #[cfg(feature = "asm_hotpaths")]
+        if len >= 256 {
+            return StreamingHash::Asm(HashState::new(0));
+        }
+        let _ = len;
+        StreamingHash::Xxh3(Box::new(Xxh3::new()))
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            StreamingHash::Xxh3(hasher) => hasher.update(data),
+            StreamingHash::Blake3(hasher) => {
+                hasher.update(data);
+            }
+            StreamingHash::Crc32c(crc) => *crc = crc32c::crc32c_append(*crc, data),
+            #[cfg(feature = "asm_hotpaths")]
+            StreamingHash::Asm(state) => {
+                for chunk in data.chunks(32 * 1024) {
+                    state.update(chunk);
+                }
+            }
+        }
+    }
+
+    fn finish(self) -> ContentHash {
+        match self {
+            StreamingHash::Xxh3(hasher) => ContentHash(hasher.digest()),
+            StreamingHash::Blake3(hasher) => blake3_hash(&hasher),
+            StreamingHash::Crc32c(crc) => ContentHash(crc as u64),
+            #[cfg(feature = "asm_hotpaths")]
+            StreamingHash::Asm(state) => ContentHash(state.finalize()),
+        }
+    }
+}
+
+/// Where bulk content hashing runs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HashBackend {
+    #[default]
+    Cpu,
+    /// Experimental: xxh3 files at or above `PARALLEL_HASH_THRESHOLD` are
+    /// hashed on a GPU compute device (feature `gpu_hash`)
+    Gpu,
+}
+
+impl HashBackend {
+    /// Fail early if this build or machine cannot run the backend
+    pub fn ensure_available(&self) -> Result<()> {
+        match self {
+            HashBackend::Cpu => Ok(()),
+            #[cfg(feature = "gpu_hash")]
+            HashBackend::Gpu => crate::gpu_hash::GpuHasher::global().map(|_| ()),
+            #[cfg(not(feature = "gpu_hash"))]
+            HashBackend::Gpu => Err(crate::FastStartupError::ConfigError(
+                "this build has no GPU hash backend; rebuild with --features gpu_hash or use --hash-backend cpu"
+                    .to_string(),
+            )),
+        }
+    }
+
+    /// `hash_file_with` on this backend; every backend gives the same hash
+    pub fn hash_file_with(&self, path: &Path, algorithm: HashAlgorithm) -> Result<ContentHash> {
+        #[cfg(feature = "gpu_hash")]
+        if *self == HashBackend::Gpu
+            && algorithm == HashAlgorithm::Xxh3
+            && std::fs::metadata(path)?.len() >= PARALLEL_HASH_THRESHOLD
+        {
+            return crate::gpu_hash::GpuHasher::global()?.hash_file(path);
+        }
+        hash_file_with(path, algorithm)
+    }
+}
+
+impl std::str::FromStr for HashBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "cpu" => Ok(HashBackend::Cpu),
+            "gpu" => Ok(HashBackend::Gpu),
+            other => Err(format!("unknown hash backend '{}' (expected cpu or gpu)", other)),
+        }
+    }
+}
+
+/// Window size of turbo sampling strategies
+pub const TURBO_WINDOW: usize = 64 * 1024;
+
+/// Which parts of a large file turbo hashing reads
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurboStrategy {
+    /// Head, middle and tail windows: best change detection, three seeks
+    ThreeWindow,
+    /// A single sequential read from the start, for HDD and network storage
+    HeadOnly,
+}
+
+impl TurboStrategy {
+    /// Pick the strategy that avoids random seeks on slow storage
+    pub fn for_storage(storage: StorageTier) -> Self {
+        match storage {
+            StorageTier::Hdd | StorageTier::Network => TurboStrategy::HeadOnly,
+            _ => TurboStrategy::ThreeWindow,
+        }
+    }
+
+    /// Name recorded as the cache's hash algorithm
+    pub fn algorithm(&self) -> &'static str {
+        match self {
+            TurboStrategy::ThreeWindow => "xxh3-turbo",
+            TurboStrategy::HeadOnly => "xxh3-turbo-head",
+        }
+    }
+
+    pub fn from_algorithm(algorithm: &str) -> Option<Self> {
+        match algorithm {
+            "xxh3-turbo" => Some(TurboStrategy::ThreeWindow),
+            "xxh3-turbo-head" => Some(TurboStrategy::HeadOnly),
+            _ => None,
+        }
+    }
+}
+
+impl std::str::FromStr for TurboStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "three-window" | "three" => Ok(TurboStrategy::ThreeWindow),
+            "head-only" | "head" => Ok(TurboStrategy::HeadOnly),
+            other => Err(format!("unknown turbo strategy '{}' (expected three-window or head-only)", other)),
+        }
+    }
 }
 
 /// TURBO hash - uses quick sampling for very fast change detection
 pub fn turbo_hash(path: &Path) -> Result<ContentHash> {
+    turbo_hash_with(path, TurboStrategy::ThreeWindow)
+}
+
+/// Turbo hash reading the windows of `strategy`
+pub fn turbo_hash_with(path: &Path, strategy: TurboStrategy) -> Result<ContentHash> {
+    if strategy == TurboStrategy::HeadOnly {
+        return head_hash(path);
+    }
+
     let file = File::open(path)?;
     let metadata = file.metadata()?;
     let len = metadata.len();
@@ -85,7 +414,7 @@ pub fn turbo_hash(path: &Path) -> Result<ContentHash> {
     let mmap = unsafe { Mmap::map(&file)? };
     
     // Sample: first 64KB + middle 64KB + last 64KB + file size
-    let sample_size = 64 * 1024;
+    let sample_size = TURBO_WINDOW;
     let middle = (len as usize) / 2;
     
     let mut combined = Vec::with_capacity(sample_size * 3 + 8);
@@ -105,6 +434,23 @@ pub fn turbo_hash(path: &Path) -> Result<ContentHash> {
     Ok(hash_bytes(&combined))
 }
 
+/// Hash of the first three windows plus the file size, read sequentially
+fn head_hash(path: &Path) -> Result<ContentHash> {
+    use std::io::Read;
+
+    let file = File::open(path)?;
+    let len = file.metadata()?.len();
+    if len < CHUNK_SIZE as u64 * 2 {
+        return hash_file(path);
+    }
+
+    // Same amount of data as three windows, without the seeks
+    let mut combined = Vec::with_capacity(TURBO_WINDOW * 3 + 8);
+    file.take((TURBO_WINDOW * 3) as u64).read_to_end(&mut combined)?;
+    combined.extend_from_slice(&len.to_le_bytes());
+    Ok(hash_bytes(&combined))
+}
+
 /// Batch hash multiple files with maximum parallelism
 pub fn hash_files_batch(paths: &[PathBuf]) -> Vec<(PathBuf, Option<ContentHash>)> {
     paths
@@ -128,6 +474,26 @@ pub fn hash_bytes(data: &[u8]) -> ContentHash {
     ContentHash(xxh3_64(data))
 }
 
+/// Hash bytes with `algorithm`, matching `hash_file_with` of a file holding
+/// them: `hash_bytes` for xxh3, `chunked_hash` once the data is large
+pub fn hash_bytes_with(data: &[u8], algorithm: HashAlgorithm) -> ContentHash {
+    hash_bytes_with_threshold(data, algorithm, PARALLEL_HASH_THRESHOLD)
+}
+
+/// `hash_bytes_with`, switching to `chunked_hash` from `threshold` bytes
+fn hash_bytes_with_threshold(data: &[u8], algorithm: HashAlgorithm, threshold: u64) -> ContentHash {
+    match algorithm {
+        HashAlgorithm::Xxh3 if data.len() as u64 >= threshold => chunked_hash(data),
+        HashAlgorithm::Xxh3 => hash_bytes(data),
+        HashAlgorithm::Blake3 => {
+            let mut hasher = blake3::Hasher::new();
+            hasher.update(data);
+            blake3_hash(&hasher)
+        }
+        HashAlgorithm::Crc32c => ContentHash(crc32c::crc32c(data) as u64),
+    }
+}
+
 /// ASM-accelerated hashing for large buffers
 #[cfg(feature = "asm_hotpaths")]
 fn hash_bytes_asm(data: &[u8]) -> ContentHash {
@@ -243,6 +609,91 @@ mod tests {
         assert_eq!(hash1, hash2);
     }
 
+    #[test]
+    fn test_chunked_hash() {
+        let mut data = vec![7u8; PARALLEL_CHUNK_SIZE * 2 + 10];
+        let hash = chunked_hash(&data);
+        assert_eq!(hash, chunked_hash(&data));
+
+        // A change in any lane alters the root
+        data[PARALLEL_CHUNK_SIZE + 1] = 8;
+        assert_ne!(hash, chunked_hash(&data));
+        data.pop();
+        assert_ne!(chunked_hash(&data), hash);
+    }
+
+    #[test]
+    fn test_large_file_matches_bytes() {
+        // A threshold small enough to cross without writing 256MB
+        let threshold = 2 * PARALLEL_CHUNK_SIZE as u64;
+        let path = std::env::temp_dir().join(format!("uefast_large_{}.bin", std::process::id()));
+        let data: Vec<u8> = (0..threshold as u32 + 1000).map(|i| (i % 241) as u8).collect();
+        std::fs::write(&path, &data).unwrap();
+
+        let hash = hash_file_with_threshold(&path, HashAlgorithm::Xxh3, threshold).unwrap();
+        assert_eq!(hash, chunked_hash(&data));
+        assert_eq!(hash, hash_bytes_with_threshold(&data, HashAlgorithm::Xxh3, threshold));
+        assert_eq!(hash, hash_file_direct_with_threshold(&path, HashAlgorithm::Xxh3, threshold).unwrap());
+
+        // Below the shipped threshold the same data hashes sequentially
+        assert!((data.len() as u64) < PARALLEL_HASH_THRESHOLD);
+        assert_eq!(hash_bytes_with(&data, HashAlgorithm::Xxh3), hash_bytes(&data));
+        assert_eq!(hash_file_with(&path, HashAlgorithm::Xxh3).unwrap(), hash_bytes(&data));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_hash_file_direct_matches() {
+        let path = std::env::temp_dir().join(format!("uefast_direct_{}.bin", std::process::id()));
+        let data: Vec<u8> = (0..300_000u32).map(|i| (i % 251) as u8).collect();
+        std::fs::write(&path, &data).unwrap();
+
+        let direct = hash_file_direct(&path).unwrap();
+        assert_eq!(direct, hash_file(&path).unwrap());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_hash_algorithms() {
+        let path = std::env::temp_dir().join(format!("uefast_algorithms_{}.bin", std::process::id()));
+        let data: Vec<u8> = (0..200_000u32).map(|i| (i % 253) as u8).collect();
+        std::fs::write(&path, &data).unwrap();
+
+        let mut seen = Vec::new();
+        for algorithm in [HashAlgorithm::Xxh3, HashAlgorithm::Blake3, HashAlgorithm::Crc32c] {
+            assert_eq!(algorithm.as_str().parse::<HashAlgorithm>(), Ok(algorithm));
+            let hash = hash_file_with(&path, algorithm).unwrap();
+            assert_eq!(hash, hash_bytes_with(&data, algorithm));
+            assert_eq!(hash, hash_file_direct_with(&path, algorithm).unwrap());
+            seen.push(hash);
+        }
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(seen[0], hash_bytes(&data));
+        assert_ne!(seen[0], seen[1]);
+        assert_eq!(hash_bytes_with(b"123456789", HashAlgorithm::Crc32c).as_u64(), 0xe306_9283);
+        assert!("md5".parse::<HashAlgorithm>().is_err());
+    }
+
+    #[test]
+    fn test_hash_backend() {
+        assert_eq!("GPU".parse::<HashBackend>(), Ok(HashBackend::Gpu));
+        assert!(HashBackend::Cpu.ensure_available().is_ok());
+        #[cfg(not(feature = "gpu_hash"))]
+        assert!(HashBackend::Gpu.ensure_available().is_err());
+        assert!("fpga".parse::<HashBackend>().is_err());
+    }
+
+    #[test]
+    fn test_turbo_strategy() {
+        assert_eq!(TurboStrategy::for_storage(StorageTier::Network), TurboStrategy::HeadOnly);
+        assert_eq!(TurboStrategy::for_storage(StorageTier::Nvme), TurboStrategy::ThreeWindow);
+        for strategy in [TurboStrategy::ThreeWindow, TurboStrategy::HeadOnly] {
+            assert_eq!(TurboStrategy::from_algorithm(strategy.algorithm()), Some(strategy));
+        }
+        assert_eq!(TurboStrategy::from_algorithm("xxh3"), None);
+    }
+
     #[test]
     fn test_incremental_hasher() {
         let data = b"Hello, World!";
@@ -0,0 +1,188 @@
+//! Heuristics Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Naming and folder convention guesses at the dependencies of packages the
+//! parser could not read (e.g. `SK_Hero` -> `T_Hero_D` in a sibling folder)
+
+use crate::graph::DependencyType;
+use crate::scanner::{AssetInfo, AssetType};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Upper bound on guesses per package, so one short base name cannot fan out
+/// across a whole folder tree
+const MAX_INFERRED_PER_ASSET: usize = 16;
+
+/// Asset prefixes in the UE naming convention and what each kind of asset
+/// typically references
+const CONVENTIONS: &[(&str, &[&str])] = &[
+    ("SK_", &["SKEL_", "PHYS_", "MI_", "M_", "T_"]),
+    ("SKM_", &["SKEL_", "PHYS_", "MI_", "M_", "T_"]),
+    ("SM_", &["MI_", "M_", "T_"]),
+    ("MI_", &["M_", "T_"]),
+    ("M_", &["MF_", "T_"]),
+    ("ABP_", &["SKEL_", "SK_", "SKM_", "AM_", "BS_", "A_"]),
+    ("BP_", &["ABP_", "SK_", "SKM_", "SM_", "MI_", "M_", "T_", "S_", "SC_"]),
+    ("WBP_", &["T_", "MI_", "M_"]),
+    ("NS_", &["MI_", "M_", "T_", "SM_"]),
+];
+
+/// Assets indexed by folder for convention lookups
+pub struct ConventionIndex<'a> {
+    by_dir: HashMap<&'a Path, Vec<&'a AssetInfo>>,
+}
+
+impl<'a> ConventionIndex<'a> {
+    pub fn new(assets: &'a [AssetInfo]) -> Self {
+        let mut by_dir: HashMap<&Path, Vec<&AssetInfo>> = HashMap::new();
+        for asset in assets.iter().filter(|a| a.asset_type == AssetType::UAsset) {
+            if let Some(dir) = asset.path.parent() {
+                by_dir.entry(dir).or_default().push(asset);
+            }
+        }
+        Self { by_dir }
+    }
+
+    /// Likely dependencies of `asset`: assets in its own folder, its
+    /// subfolders or its sibling folders that share its base name and carry
+    /// a prefix its kind of asset usually references
+    pub fn infer(&self, asset: &AssetInfo) -> Vec<(PathBuf, DependencyType)> {
+        let Some(stem) = asset.path.file_stem().map(|s| s.to_string_lossy()) else {
+            return Vec::new();
+        };
+        let (prefix, base) = split_prefix(&stem);
+        if base.len() < 3 {
+            return Vec::new();
+        }
+        let base = base.to_lowercase();
+        // Leaf kinds such as textures reference nothing by convention;
+        // unprefixed names may reference any known kind
+        let targets = match prefix {
+            Some(prefix) => match CONVENTIONS.iter().find(|(p, _)| *p == prefix) {
+                Some((_, targets)) => Some(*targets),
+                None => return Vec::new(),
+            },
+            None => None,
+        };
+
+        let mut inferred: Vec<(PathBuf, DependencyType)> = self
+            .nearby_dirs(&asset.path)
+            .into_iter()
+            .filter_map(|dir| self.by_dir.get(dir))
+            .flatten()
+            .filter(|candidate| candidate.path != asset.path)
+            .filter_map(|candidate| {
+                let stem = candidate.path.file_stem()?.to_string_lossy();
+                let (candidate_prefix, candidate_base) = split_prefix(&stem);
+                let candidate_prefix = candidate_prefix?;
+                if targets.is_some_and(|t| !t.contains(&candidate_prefix)) {
+                    return None;
+                }
+                let candidate_base = candidate_base.to_lowercase();
+                let same_base = candidate_base == base
+                    || candidate_base.strip_prefix(&base).is_some_and(|rest| rest.starts_with('_'));
+                same_base.then(|| (candidate.path.clone(), dependency_type(candidate_prefix)))
+            })
+            .collect();
+
+        inferred.sort_by(|a, b| a.0.cmp(&b.0));
+        inferred.dedup_by(|a, b| a.0 == b.0);
+        inferred.truncate(MAX_INFERRED_PER_ASSET);
+        inferred
+    }
+
+    /// The asset's folder, its direct subfolders and its sibling folders
+    fn nearby_dirs(&self, path: &Path) -> Vec<&'a Path> {
+        let Some(dir) = path.parent() else {
+            return Vec::new();
+        };
+        let parent = dir.parent();
+        self.by_dir
+            .keys()
+            .copied()
+            .filter(|candidate| *candidate == dir || candidate.parent() == Some(dir) || candidate.parent() == parent)
+            .collect()
+    }
+}
+
+/// Split `SK_Hero_Body` into `(Some("SK_"), "Hero_Body")` when the prefix
+/// is a known convention prefix
+fn split_prefix(stem: &str) -> (Option<&'static str>, &str) {
+    let Some((head, rest)) = stem.split_once('_') else {
+        return (None, stem);
+    };
+    let known = CONVENTIONS
+        .iter()
+        .flat_map(|(source, targets)| std::iter::once(source).chain(targets.iter()))
+        .find(|p| p.len() == head.len() + 1 && p[..head.len()].eq_ignore_ascii_case(head));
+    match known {
+        Some(prefix) => (Some(prefix), rest),
+        None => (None, stem),
+    }
+}
+
+fn dependency_type(prefix: &str) -> DependencyType {
+    match prefix {
+        "T_" => DependencyType::Texture,
+        "M_" | "MI_" | "MF_" => DependencyType::Material,
+        "A_" | "AM_" | "BS_" | "ABP_" | "SKEL_" => DependencyType::Animation,
+        "BP_" => DependencyType::Blueprint,
+        _ => DependencyType::SoftReference,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn asset(path: &str) -> AssetInfo {
+        AssetInfo {
+            path: PathBuf::from(path),
+            relative_path: path.to_string(),
+            asset_type: AssetType::UAsset,
+            size_bytes: 1,
+            modified: 0,
+            mount_point: "/Game".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_infer_from_conventions() {
+        let assets: Vec<AssetInfo> = [
+            "Content/Hero/Meshes/SK_Hero.uasset",
+            "Content/Hero/Textures/T_Hero_D.uasset",
+            "Content/Hero/Textures/T_Hero_N.uasset",
+            "Content/Hero/Textures/T_Heroic.uasset",
+            "Content/Hero/Materials/MI_Hero.uasset",
+            "Content/Hero/Materials/SM_Hero.uasset",
+            "Content/Villain/Textures/T_Hero_D.uasset",
+        ]
+        .iter()
+        .map(|p| asset(p))
+        .collect();
+        let index = ConventionIndex::new(&assets);
+
+        let inferred = index.infer(&assets[0]);
+        let paths: Vec<&str> = inferred.iter().map(|(p, _)| p.to_str().unwrap()).collect();
+        assert_eq!(
+            paths,
+            [
+                "Content/Hero/Materials/MI_Hero.uasset",
+                "Content/Hero/Textures/T_Hero_D.uasset",
+                "Content/Hero/Textures/T_Hero_N.uasset",
+            ]
+        );
+        assert_eq!(inferred[0].1, DependencyType::Material);
+        assert_eq!(inferred[1].1, DependencyType::Texture);
+
+        assert!(index.infer(&assets[1]).is_empty());
+    }
+
+    #[test]
+    fn test_split_prefix() {
+        assert_eq!(split_prefix("SK_Hero_Body"), (Some("SK_"), "Hero_Body"));
+        assert_eq!(split_prefix("mi_Hero"), (Some("MI_"), "Hero"));
+        assert_eq!(split_prefix("Hero_Body"), (None, "Hero_Body"));
+    }
+}
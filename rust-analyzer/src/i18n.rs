@@ -0,0 +1,372 @@
+//! Localization Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Message catalogs for report and recommendation text. Messages are looked
+//! up by key and fill `{name}` placeholders; missing translations fall back
+//! to English.
+
+use crate::FastStartupError;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// Overrides the locale taken from `LC_ALL` / `LC_MESSAGES` / `LANG`
+pub const LANG_ENV: &str = "UEFAST_LANG";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    En,
+    Es,
+}
+
+impl Locale {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Es => "es",
+        }
+    }
+
+    /// Locale of the environment, English unless it asks for Spanish
+    pub fn detect() -> Self {
+        Self::from_env(|key| std::env::var(key).ok())
+    }
+
+    fn from_env(env: impl Fn(&str) -> Option<String>) -> Self {
+        [LANG_ENV, "LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .filter_map(|key| env(key).filter(|v| !v.is_empty()))
+            .map(|value| Self::from_tag(&value))
+            .next()
+            .unwrap_or_default()
+    }
+
+    /// `es`, `es_MX.UTF-8`, `es-419` -> Spanish; anything else English
+    fn from_tag(tag: &str) -> Self {
+        let language = tag.split(['_', '-', '.', '@']).next().unwrap_or_default();
+        if language.eq_ignore_ascii_case("es") {
+            Locale::Es
+        } else {
+            Locale::En
+        }
+    }
+
+    fn catalog(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Locale::En => EN,
+            Locale::Es => ES,
+        }
+    }
+}
+
+impl std::str::FromStr for Locale {
+    type Err = FastStartupError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "en" | "english" => Ok(Locale::En),
+            "es" | "spanish" | "español" => Ok(Locale::Es),
+            other => Err(FastStartupError::InvalidArgument(format!(
+                "Unknown language '{}' (expected en or es)",
+                other
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Message `key` in `locale` with every `{name}` replaced by its argument
+pub fn tr(locale: Locale, key: &str, args: &[(&str, &dyn Display)]) -> String {
+    let template = lookup(locale.catalog(), key)
+        .or_else(|| lookup(EN, key))
+        .unwrap_or(key);
+    let mut message = template.to_string();
+    for (name, value) in args {
+        message = message.replace(&format!("{{{}}}", name), &value.to_string());
+    }
+    message
+}
+
+fn lookup(catalog: &'static [(&'static str, &'static str)], key: &str) -> Option<&'static str> {
+    catalog.iter().find(|(k, _)| *k == key).map(|(_, v)| *v)
+}
+
+const EN: &[(&str, &str)] = &[
+    // Recommendations
+    ("rec.startup_ratio", "{percent}% of assets are loaded at startup. Consider lazy loading."),
+    ("rec.textures", "{count} textures found. Consider using texture streaming."),
+    ("rec.blueprints", "{count} blueprints found. Consider nativizing hot paths."),
+    (
+        "rec.engine_newer",
+        "{count} assets were saved with a newer engine than {engine} and will fail to load.",
+    ),
+    (
+        "rec.engine_older",
+        "{count} assets were saved with an older engine than {engine} and are converted on every load. Resave them.",
+    ),
+    (
+        "rec.stale_imports",
+        "{imports} package imports in {assets} startup assets are not referenced by any export. \
+         Resave or fix up redirectors in those assets to drop the dead imports.",
+    ),
+    (
+        "rec.case_collisions",
+        "{count} paths differ only by case ({examples}). They fail on Linux and consoles and merge on Windows; \
+         rename them to a single spelling.",
+    ),
+    (
+        "rec.hard_cycles",
+        "{count} groups of assets ({assets} in total) hard-reference each other in a loop ({examples}). \
+         The loader has to resolve every package of a cycle before any of them finishes; \
+         break each loop by turning one reference into a soft reference.",
+    ),
+    (
+        "rec.lfs_pointers",
+        "{count} files in Content are Git LFS pointers that were never smudged and will fail to load. \
+         Run `git lfs pull` and check that LFS is installed on this machine.",
+    ),
+    (
+        "rec.line_endings",
+        "{count} text files in Content have mixed or divergent line endings, so their hashes \
+         differ between machines. Normalize them with a .gitattributes eol rule.",
+    ),
+    (
+        "rec.data_table",
+        "{class} {path} ({rows}{row_struct}, {mb} MB) is loaded at startup. Split it into \
+         smaller tables or load it asynchronously after startup.",
+    ),
+    ("rec.data_table.rows", "{count} rows"),
+    ("rec.data_table.unknown_rows", "unknown rows"),
+    ("rec.data_table.row_struct", " of {name}"),
+    (
+        "rec.string_tables",
+        "{tables} string tables ({entries} entries, {mb} MB) are loaded at boot by {assets} startup assets. \
+         Move text not shown on the first screens to asynchronously loaded tables.",
+    ),
+    (
+        "rec.ui_art",
+        "{widgets} Widget Blueprints in the startup closure import {texture_mb} MB of textures \
+         and {font_mb} MB of fonts. Soft-reference art for screens not shown at boot.",
+    ),
+    (
+        "rec.heavy_font",
+        "Font {path} loads {pages} faces/pages at boot (more than {max}). Move rarely used \
+         fallback typefaces to a lazily loaded font.",
+    ),
+    (
+        "rec.startup_module",
+        "{module} StartupModule ({location}, loading phase {phase}) makes {calls} blocking calls ({kinds}) \
+         and loads {assets} assets ({mb} MB) before the first frame. Defer the work to first use or load asynchronously.",
+    ),
+    (
+        "rec.eager_modules",
+        "{count} modules are loaded eagerly from StartupModule implementations ({modules}). \
+         Load them where they are first used.",
+    ),
+    (
+        "rec.shared_subtree",
+        "{referrers} startup assets hard-reference {root} ({assets} assets, {mb} MB). \
+         Aggregate it into a preload data asset so the subtree is \
+         prefetched once ahead of its referrers.",
+    ),
+    (
+        "rec.never_cooked",
+        "{count} startup assets are excluded from shipping cooks by a primary asset rule ({examples}). \
+         They load in the editor but fail in packaged builds; change the cook rule or drop the references.",
+    ),
+    (
+        "rec.late_chunks",
+        "{count} startup assets ({mb} MB) are assigned to chunks after 0 ({examples}). \
+         Chunk 0 ships with the base install and mounts first; move them there with a \
+         PrimaryAssetLabel (ChunkId 0) or change the ChunkId of the rule that assigns them.",
+    ),
+    // Report layout
+    ("report.metric", "Metric"),
+    ("report.value", "Value"),
+    ("report.findings", "Findings"),
+    ("report.details", "Details"),
+    // Analysis report
+    ("report.title", "Startup analysis: {project}"),
+    ("report.total_assets", "Total assets"),
+    ("report.startup_assets", "Startup assets"),
+    ("report.total_size_mb", "Total size (MB)"),
+    ("report.startup_size_mb", "Startup size (MB)"),
+    ("report.closure_mb", "Startup closure (MB)"),
+    ("report.resident_mb", "Resident memory (MB)"),
+    ("report.resident_value", "{mb} ({priced} assets priced from registry tags)"),
+    ("report.dependencies", "Dependencies"),
+    ("report.parsed_packages", "Parsed packages"),
+    ("report.duplicate_groups", "Duplicate groups"),
+    ("report.savings_s", "Estimated savings (s)"),
+    ("report.hardware", "Hardware"),
+    ("report.scenario", "Scenario {name} (MB)"),
+    ("report.scenario_value", "{mb} ({assets} assets from {roots} roots)"),
+    ("report.tag", "Tag {name} (startup MB)"),
+    ("report.tag_value", "{mb} ({startup} of {assets} assets)"),
+    ("report.duplicate", "Identical content to {path}"),
+    ("report.stale_import", "Hard import of {package} is not referenced by any export"),
+];
+
+const ES: &[(&str, &str)] = &[
+    // Recommendations
+    (
+        "rec.startup_ratio",
+        "El {percent}% de los assets se carga al arrancar. Considera la carga diferida.",
+    ),
+    ("rec.textures", "{count} texturas encontradas. Considera usar texture streaming."),
+    ("rec.blueprints", "{count} blueprints encontrados. Considera nativizar las rutas críticas."),
+    (
+        "rec.engine_newer",
+        "{count} assets se guardaron con un motor más nuevo que {engine} y no se podrán cargar.",
+    ),
+    (
+        "rec.engine_older",
+        "{count} assets se guardaron con un motor más antiguo que {engine} y se convierten en cada carga. Vuelve a guardarlos.",
+    ),
+    (
+        "rec.stale_imports",
+        "{imports} importaciones de paquetes en {assets} assets de arranque no las usa ningún export. \
+         Vuelve a guardar esos assets o corrige sus redirectores para eliminar las importaciones muertas.",
+    ),
+    (
+        "rec.case_collisions",
+        "{count} rutas solo se diferencian en mayúsculas ({examples}). Fallan en Linux y consolas y se fusionan en Windows; \
+         renómbralas con una sola grafía.",
+    ),
+    (
+        "rec.hard_cycles",
+        "{count} grupos de assets ({assets} en total) se referencian entre sí con referencias duras en bucle ({examples}). \
+         El cargador tiene que resolver todos los paquetes del ciclo antes de terminar cualquiera de ellos; \
+         rompe cada bucle convirtiendo una de las referencias en soft reference.",
+    ),
+    (
+        "rec.lfs_pointers",
+        "{count} archivos de Content son punteros de Git LFS que nunca se descargaron y no se podrán cargar. \
+         Ejecuta `git lfs pull` y comprueba que LFS está instalado en esta máquina.",
+    ),
+    (
+        "rec.line_endings",
+        "{count} archivos de texto de Content mezclan o difieren en sus finales de línea, así que su hash \
+         cambia entre máquinas. Normalízalos con una regla eol en .gitattributes.",
+    ),
+    (
+        "rec.data_table",
+        "{class} {path} ({rows}{row_struct}, {mb} MB) se carga al arrancar. Divídela en \
+         tablas más pequeñas o cárgala de forma asíncrona después del arranque.",
+    ),
+    ("rec.data_table.rows", "{count} filas"),
+    ("rec.data_table.unknown_rows", "filas desconocidas"),
+    ("rec.data_table.row_struct", " de {name}"),
+    (
+        "rec.string_tables",
+        "{tables} tablas de textos ({entries} entradas, {mb} MB) se cargan al arrancar desde {assets} assets de arranque. \
+         Mueve el texto que no aparece en las primeras pantallas a tablas cargadas de forma asíncrona.",
+    ),
+    (
+        "rec.ui_art",
+        "{widgets} Widget Blueprints del cierre de arranque importan {texture_mb} MB de texturas \
+         y {font_mb} MB de fuentes. Usa referencias soft para el arte de pantallas que no se muestran al arrancar.",
+    ),
+    (
+        "rec.heavy_font",
+        "La fuente {path} carga {pages} caras/páginas al arrancar (más de {max}). Mueve las tipografías \
+         de respaldo poco usadas a una fuente de carga diferida.",
+    ),
+    (
+        "rec.startup_module",
+        "StartupModule de {module} ({location}, fase de carga {phase}) hace {calls} llamadas bloqueantes ({kinds}) \
+         y carga {assets} assets ({mb} MB) antes del primer fotograma. Aplaza el trabajo hasta el primer uso o cárgalo de forma asíncrona.",
+    ),
+    (
+        "rec.eager_modules",
+        "{count} módulos se cargan de forma anticipada desde implementaciones de StartupModule ({modules}). \
+         Cárgalos donde se usan por primera vez.",
+    ),
+    (
+        "rec.shared_subtree",
+        "{referrers} assets de arranque tienen referencias hard a {root} ({assets} assets, {mb} MB). \
+         Agrúpalo en un data asset de precarga para que el subárbol se \
+         precargue una sola vez antes que quienes lo usan.",
+    ),
+    (
+        "rec.never_cooked",
+        "{count} assets de arranque quedan fuera del cook de distribución por una regla de primary asset ({examples}). \
+         Cargan en el editor pero fallan en builds empaquetadas; cambia la regla de cook o elimina las referencias.",
+    ),
+    (
+        "rec.late_chunks",
+        "{count} assets de arranque ({mb} MB) están asignados a chunks posteriores al 0 ({examples}). \
+         El chunk 0 va en la instalación base y se monta primero; muévelos ahí con un \
+         PrimaryAssetLabel (ChunkId 0) o cambia el ChunkId de la regla que los asigna.",
+    ),
+    // Report layout
+    ("report.metric", "Métrica"),
+    ("report.value", "Valor"),
+    ("report.findings", "Hallazgos"),
+    ("report.details", "Detalles"),
+    // Analysis report
+    ("report.title", "Análisis de arranque: {project}"),
+    ("report.total_assets", "Assets totales"),
+    ("report.startup_assets", "Assets de arranque"),
+    ("report.total_size_mb", "Tamaño total (MB)"),
+    ("report.startup_size_mb", "Tamaño de arranque (MB)"),
+    ("report.closure_mb", "Cierre de arranque (MB)"),
+    ("report.resident_mb", "Memoria residente (MB)"),
+    ("report.resident_value", "{mb} ({priced} assets valorados con etiquetas del registro)"),
+    ("report.dependencies", "Dependencias"),
+    ("report.parsed_packages", "Paquetes analizados"),
+    ("report.duplicate_groups", "Grupos duplicados"),
+    ("report.savings_s", "Ahorro estimado (s)"),
+    ("report.hardware", "Hardware"),
+    ("report.scenario", "Escenario {name} (MB)"),
+    ("report.scenario_value", "{mb} ({assets} assets desde {roots} raíces)"),
+    ("report.tag", "Etiqueta {name} (MB de arranque)"),
+    ("report.tag_value", "{mb} ({startup} de {assets} assets)"),
+    ("report.duplicate", "Contenido idéntico a {path}"),
+    ("report.stale_import", "La importación hard de {package} no la usa ningún export"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placeholders(text: &str) -> Vec<&str> {
+        let mut names: Vec<&str> = text
+            .split('{')
+            .skip(1)
+            .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    #[test]
+    fn test_catalogs_match() {
+        for (key, english) in EN {
+            let spanish = lookup(ES, key).unwrap_or_else(|| panic!("missing es translation of {}", key));
+            assert_eq!(placeholders(english), placeholders(spanish), "{}", key);
+        }
+        assert_eq!(EN.len(), ES.len());
+    }
+
+    #[test]
+    fn test_tr_and_detection() {
+        assert_eq!(tr(Locale::Es, "report.title", &[("project", &"Demo")]), "Análisis de arranque: Demo");
+        assert_eq!(tr(Locale::En, "rec.textures", &[("count", &1200)]), "1200 textures found. Consider using texture streaming.");
+        assert_eq!(tr(Locale::Es, "no.such.key", &[]), "no.such.key");
+
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |key: &str| vars.iter().find(|(k, _)| *k == key).map(|(_, v)| v.to_string())
+        };
+        assert_eq!(Locale::from_env(env(&[("LANG", "es_MX.UTF-8")])), Locale::Es);
+        assert_eq!(Locale::from_env(env(&[(LANG_ENV, "en"), ("LANG", "es_ES")])), Locale::En);
+        assert_eq!(Locale::from_env(env(&[("LC_ALL", ""), ("LANG", "C")])), Locale::En);
+    }
+}
@@ -0,0 +1,150 @@
+//! Import Cache Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Parsed package references persisted in `.uefast/imports.bin`, keyed by
+//! the hash of each package summary so unchanged assets are not re-parsed by
+//! later graph builds
+
+use crate::scanner::AssetInfo;
+use crate::uasset::PackageReferences;
+use crate::{FastStartupError, Result, STATE_DIR};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+pub const IMPORT_CACHE_FILE: &str = "imports.bin";
+
+/// Bumped whenever the layout or the meaning of the cached lists changes
+const IMPORT_CACHE_VERSION: u32 = 3;
+
+/// File state a reference list was read from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+struct FileStamp {
+    size_bytes: u64,
+    modified: u64,
+    summary_hash: u64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ImportCache {
+    version: u32,
+    /// Relative path -> stamp, so unchanged files are not even opened
+    files: HashMap<String, FileStamp>,
+    /// Package summary hash -> imported and softly referenced packages
+    imports: HashMap<u64, PackageReferences>,
+    #[serde(skip)]
+    hits: usize,
+}
+
+impl ImportCache {
+    pub fn path(project_root: &Path) -> PathBuf {
+        project_root.join(STATE_DIR).join(IMPORT_CACHE_FILE)
+    }
+
+    /// Cache of `project_root`, empty if missing, unreadable or outdated
+    pub fn load(project_root: &Path) -> Self {
+        std::fs::read(Self::path(project_root))
+            .ok()
+            .and_then(|data| bincode::deserialize::<Self>(&data).ok())
+            .filter(|cache| cache.version == IMPORT_CACHE_VERSION)
+            .unwrap_or_else(|| Self {
+                version: IMPORT_CACHE_VERSION,
+                ..Self::default()
+            })
+    }
+
+    /// Write the cache, dropping reference lists no file refers to any more
+    pub fn save(&mut self, project_root: &Path) -> Result<PathBuf> {
+        let live: HashSet<u64> = self.files.values().map(|s| s.summary_hash).collect();
+        self.imports.retain(|hash, _| live.contains(hash));
+
+        let path = Self::path(project_root);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let data = bincode::serialize(self)
+            .map_err(|e| FastStartupError::SerializationError(e.to_string()))?;
+        std::fs::write(&path, data)?;
+        Ok(path)
+    }
+
+    /// References of `asset` if its size and modification time are unchanged
+    pub fn get(&self, asset: &AssetInfo) -> Option<&PackageReferences> {
+        let stamp = self.files.get(&asset.relative_path)?;
+        if stamp.size_bytes != asset.size_bytes || stamp.modified != asset.modified {
+            return None;
+        }
+        self.imports.get(&stamp.summary_hash)
+    }
+
+    /// References of another file with the same summary, e.g. after a touch
+    /// or move
+    pub fn get_by_summary(&self, summary_hash: u64) -> Option<&PackageReferences> {
+        self.imports.get(&summary_hash)
+    }
+
+    pub fn insert(&mut self, asset: &AssetInfo, summary_hash: u64, references: PackageReferences) {
+        self.files.insert(
+            asset.relative_path.clone(),
+            FileStamp {
+                size_bytes: asset.size_bytes,
+                modified: asset.modified,
+                summary_hash,
+            },
+        );
+        self.imports.insert(summary_hash, references);
+    }
+
+    pub fn record_hits(&mut self, hits: usize) {
+        self.hits += hits;
+    }
+
+    /// Assets served from the cache since it was loaded
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetType;
+
+    fn asset(modified: u64) -> AssetInfo {
+        AssetInfo {
+            path: PathBuf::from("/Project/Content/A.uasset"),
+            relative_path: "Content/A.uasset".to_string(),
+            asset_type: AssetType::UAsset,
+            size_bytes: 100,
+            modified,
+            mount_point: "/Game".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_import_cache_round_trip() {
+        let root = std::env::temp_dir().join(format!("uefast_import_cache_{}", std::process::id()));
+        let mut cache = ImportCache::load(&root);
+        assert!(cache.is_empty());
+
+        let references = PackageReferences { imports: vec!["/Game/B".to_string()], soft: vec!["/Game/C".to_string()] };
+        cache.insert(&asset(1), 7, references.clone());
+        cache.save(&root).unwrap();
+
+        let cache = ImportCache::load(&root);
+        assert_eq!(cache.get(&asset(1)), Some(&references));
+        // Touched file: stamp misses, but the same summary is still known
+        assert_eq!(cache.get(&asset(2)), None);
+        assert!(cache.get_by_summary(7).is_some());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,153 @@
+//! Import Usage Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Flags hard package imports that no export references anymore
+
+use crate::scanner::{AssetInfo, AssetType};
+use crate::uasset::{ExportEntry, ImportEntry, Package};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Package import with no referencing export in its importer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StaleImport {
+    pub relative_path: String,
+    pub package: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ImportUsageReport {
+    /// Assets whose import and export maps could both be parsed
+    pub checked_assets: usize,
+    pub stale_imports: Vec<StaleImport>,
+}
+
+impl ImportUsageReport {
+    pub fn build(assets: &[AssetInfo]) -> Self {
+        let results: Vec<Vec<StaleImport>> = assets
+            .par_iter()
+            .filter(|a| matches!(a.asset_type, AssetType::UAsset | AssetType::UMap))
+            .filter_map(|asset| {
+                let package = Package::open(&asset.path).ok()?;
+                let imports = package.imports().ok()?;
+                let exports = package.exports().ok()?;
+
+                Some(
+                    unreferenced_packages(&imports, &exports, package.data())
+                        .into_iter()
+                        .map(|package| StaleImport {
+                            relative_path: asset.relative_path.clone(),
+                            package,
+                        })
+                        .collect(),
+                )
+            })
+            .collect();
+
+        let checked_assets = results.len();
+        let mut stale_imports: Vec<StaleImport> = results.into_iter().flatten().collect();
+        stale_imports.sort_by(|a, b| {
+            a.relative_path.cmp(&b.relative_path).then_with(|| a.package.cmp(&b.package))
+        });
+
+        Self {
+            checked_assets,
+            stale_imports,
+        }
+    }
+}
+
+/// `/Game/` package imports not reachable from any export. An import counts
+/// as referenced when an export names it as class, super, template or outer,
+/// or when its package index appears in an export's serialized data (object
+/// properties). Byte scanning can only over-report usage, so results err on
+/// the side of keeping an import.
+pub fn unreferenced_packages(
+    imports: &[ImportEntry],
+    exports: &[ExportEntry],
+    data: &[u8],
+) -> Vec<String> {
+    let count = imports.len() as i32;
+    let as_import = |index: i32| (index < 0 && -index <= count).then(|| (-index - 1) as usize);
+
+    let mut referenced: HashSet<usize> = HashSet::new();
+    for export in exports {
+        for index in [export.class_index, export.super_index, export.template_index, export.outer_index] {
+            referenced.extend(as_import(index));
+        }
+
+        let start = export.serial_offset.max(0) as usize;
+        let end = (export.serial_offset.saturating_add(export.serial_size)).max(0) as usize;
+        if let Some(serial) = data.get(start..end.min(data.len())) {
+            referenced.extend(
+                serial
+                    .windows(4)
+                    .filter_map(|w| as_import(i32::from_le_bytes([w[0], w[1], w[2], w[3]]))),
+            );
+        }
+    }
+
+    // A referenced object keeps every outer up to its package alive
+    let mut used: HashSet<usize> = HashSet::new();
+    for &index in &referenced {
+        let mut current = Some(index);
+        while let Some(i) = current {
+            if !used.insert(i) {
+                break;
+            }
+            current = as_import(imports[i].outer_index);
+        }
+    }
+
+    let mut stale: Vec<String> = imports
+        .iter()
+        .enumerate()
+        .filter(|(i, import)| {
+            import.is_package() && import.object_name.starts_with("/Game/") && !used.contains(i)
+        })
+        .map(|(_, import)| import.object_name.clone())
+        .collect();
+    stale.sort();
+    stale.dedup();
+    stale
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn import(class_name: &str, outer_index: i32, object_name: &str) -> ImportEntry {
+        ImportEntry {
+            class_package: "/Script/CoreUObject".to_string(),
+            class_name: class_name.to_string(),
+            outer_index,
+            object_name: object_name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_unreferenced_packages() {
+        let imports = vec![
+            import("Package", 0, "/Game/UI/T_Used"),
+            import("Texture2D", -1, "T_Used"),
+            import("Package", 0, "/Game/Old/T_Stale"),
+            import("Texture2D", -3, "T_Stale"),
+        ];
+
+        // The export's serialized data references import #1 (index -2)
+        let mut data = vec![0u8; 8];
+        data.extend_from_slice(&(-2i32).to_le_bytes());
+        let exports = vec![ExportEntry {
+            class_index: 0,
+            super_index: 0,
+            template_index: 0,
+            outer_index: 0,
+            serial_size: 4,
+            serial_offset: 8,
+        }];
+
+        assert_eq!(unreferenced_packages(&imports, &exports, &data), vec!["/Game/Old/T_Stale"]);
+    }
+}
@@ -0,0 +1,113 @@
+//! Config Hints Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Engine config settings suggested by an analysis, rendered as an ini
+//! snippet to merge into the project's `Config/Default*.ini` by hand
+
+use crate::analyzer::AnalysisReport;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Startup asset count above which loading gets a larger time slice
+const MANY_STARTUP_ASSETS: usize = 1000;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IniHint {
+    /// Project-relative config file, e.g. `Config/DefaultEngine.ini`
+    pub file: String,
+    pub section: String,
+    pub key: String,
+    pub value: String,
+    pub reason: String,
+}
+
+impl IniHint {
+    fn new(file: &str, section: &str, key: &str, value: &str, reason: &str) -> Self {
+        Self {
+            file: file.to_string(),
+            section: section.to_string(),
+            key: key.to_string(),
+            value: value.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+/// Settings worth applying given `report`
+pub fn suggest(report: &AnalysisReport) -> Vec<IniHint> {
+    let mut hints = vec![IniHint::new(
+        "Config/DefaultEngine.ini",
+        "/Script/Engine.StreamingSettings",
+        "s.AsyncLoadingThreadEnabled",
+        "True",
+        "Deserialize packages on the async loading thread instead of the game thread",
+    )];
+
+    if report.startup_assets > MANY_STARTUP_ASSETS {
+        hints.push(IniHint::new(
+            "Config/DefaultEngine.ini",
+            "/Script/Engine.StreamingSettings",
+            "s.AsyncLoadingTimeLimit",
+            "10",
+            &format!(
+                "{} startup assets; a larger per-frame loading budget shortens boot while nothing is rendered",
+                report.startup_assets
+            ),
+        ));
+    }
+
+    if report.shader_analysis.as_ref().is_some_and(|s| s.total_shaders > 0) {
+        hints.push(IniHint::new(
+            "Config/DefaultGame.ini",
+            "/Script/UnrealEd.ProjectPackagingSettings",
+            "bShareMaterialShaderCode",
+            "True",
+            "Store shader code once in a shared library instead of per material",
+        ));
+    }
+
+    hints
+}
+
+/// Hints grouped by file and section, each key preceded by its reason
+pub fn render(hints: &[IniHint]) -> String {
+    let mut grouped: BTreeMap<(&str, &str), Vec<&IniHint>> = BTreeMap::new();
+    for hint in hints {
+        grouped.entry((&hint.file, &hint.section)).or_default().push(hint);
+    }
+
+    let mut out = String::new();
+    let mut current_file = None;
+    for ((file, section), hints) in grouped {
+        if current_file != Some(file) {
+            out.push_str(&format!("; ---- {} ----\n", file));
+            current_file = Some(file);
+        }
+        out.push_str(&format!("[{}]\n", section));
+        for hint in hints {
+            out.push_str(&format!("; {}\n{}={}\n", hint.reason, hint.key, hint.value));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_groups_sections() {
+        let hints = [
+            IniHint::new("Config/DefaultEngine.ini", "S", "a", "1", "first"),
+            IniHint::new("Config/DefaultGame.ini", "T", "c", "3", "third"),
+            IniHint::new("Config/DefaultEngine.ini", "S", "b", "2", "second"),
+        ];
+        assert_eq!(
+            render(&hints),
+            "; ---- Config/DefaultEngine.ini ----\n[S]\n; first\na=1\n; second\nb=2\n\n\
+             ; ---- Config/DefaultGame.ini ----\n[T]\n; third\nc=3\n\n"
+        );
+    }
+}
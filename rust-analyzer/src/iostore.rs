@@ -0,0 +1,551 @@
+//! IoStore Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Reads IoStore containers: the .utoc table of contents that names each
+//! packaged chunk, and the .ucas partitions holding the compressed blocks
+//! UE5 builds store packages in instead of .pak entries
+
+use crate::byte_reader::ByteReader;
+use crate::{FastStartupError, Result};
+use flate2::read::{GzDecoder, ZlibDecoder};
+use memmap2::Mmap;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+const TOC_MAGIC: &[u8; 16] = b"-==--==--==--==-";
+
+/// `EIoStoreTocVersion` values that change the layout
+const TOC_VERSION_PARTITION_SIZE: u8 = 3;
+const TOC_VERSION_PERFECT_HASH: u8 = 4;
+const TOC_VERSION_PERFECT_HASH_WITH_OVERFLOW: u8 = 5;
+
+/// `EIoContainerFlags`
+const FLAG_ENCRYPTED: u8 = 1 << 1;
+const FLAG_SIGNED: u8 = 1 << 2;
+const FLAG_INDEXED: u8 = 1 << 3;
+
+/// Ends directory and file lists in the directory index
+const INVALID_INDEX: u32 = u32::MAX;
+
+const CHUNK_ID_BYTES: usize = 12;
+const OFFSET_LENGTH_BYTES: usize = 10;
+const BLOCK_ENTRY_BYTES: usize = 12;
+const BLOCK_SIGNATURE_BYTES: usize = 20;
+
+/// `EIoChunkType::ExportBundleData`, the chunk holding a package
+pub const CHUNK_TYPE_PACKAGE: u8 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TocEntry {
+    /// Path below the container mount point, e.g. `Shooter/Content/UI/W_Main.uasset`
+    pub path: String,
+    /// `EIoChunkType`: 1 for package data, 2 and 3 for bulk data
+    pub chunk_type: u8,
+    /// Offset in the container's uncompressed address space
+    pub offset: u64,
+    pub length: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressionBlock {
+    /// Offset across the .ucas partitions
+    pub offset: u64,
+    pub compressed_size: u32,
+    pub uncompressed_size: u32,
+    /// 0 for stored blocks, else one past the index into `compression_methods`
+    pub method: u8,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TocFile {
+    pub path: PathBuf,
+    pub version: u8,
+    pub mount_point: String,
+    pub compression_block_size: u32,
+    /// Bytes per .ucas partition; `u64::MAX` for single-partition containers
+    pub partition_size: u64,
+    pub compression_methods: Vec<String>,
+    /// Named chunks in directory index order; empty for containers built
+    /// without a directory index, such as global.utoc
+    pub entries: Vec<TocEntry>,
+    pub blocks: Vec<CompressionBlock>,
+}
+
+impl TocFile {
+    pub fn open(path: &Path) -> Result<Self> {
+        let file = File::open(path)?;
+        let mmap = unsafe { Mmap::map(&file)? };
+        Self::parse(path, &mmap)
+    }
+
+    fn parse(path: &Path, data: &[u8]) -> Result<Self> {
+        let invalid = |what: String| FastStartupError::AssetError(format!("{}: {}", path.display(), what));
+        let mut reader = ByteReader::new(data);
+        if reader.bytes(TOC_MAGIC.len())? != TOC_MAGIC {
+            return Err(invalid("not an IoStore table of contents".to_string()));
+        }
+        let version = reader.bytes(1)?[0];
+        reader.skip(3)?;
+        let header_size = reader.u32()? as usize;
+        let entry_count = reader.u32()? as usize;
+        let block_count = reader.u32()? as usize;
+        let block_entry_size = reader.u32()? as usize;
+        let method_count = reader.u32()? as usize;
+        let method_length = reader.u32()? as usize;
+        let compression_block_size = reader.u32()?;
+        let directory_index_size = reader.u32()? as usize;
+        let _partition_count = reader.u32()?;
+        // Container id and encryption key guid
+        reader.skip(8 + 16)?;
+        let flags = reader.bytes(1)?[0];
+        reader.skip(3)?;
+        let perfect_hash_seed_count = reader.u32()? as usize;
+        let partition_size = reader.u64()?;
+        let overflow_count = reader.u32()? as usize;
+
+        if flags & FLAG_ENCRYPTED != 0 {
+            return Err(invalid("container is encrypted".to_string()));
+        }
+        if block_entry_size != BLOCK_ENTRY_BYTES {
+            return Err(invalid(format!("unsupported compression block entry size {}", block_entry_size)));
+        }
+
+        reader.seek(header_size)?;
+        let chunk_ids = table(&mut reader, entry_count, CHUNK_ID_BYTES)?;
+        let offsets = table(&mut reader, entry_count, OFFSET_LENGTH_BYTES)?;
+        if version >= TOC_VERSION_PERFECT_HASH {
+            table(&mut reader, perfect_hash_seed_count, 4)?;
+        }
+        if version >= TOC_VERSION_PERFECT_HASH_WITH_OVERFLOW {
+            table(&mut reader, overflow_count, 4)?;
+        }
+        let blocks = table(&mut reader, block_count, BLOCK_ENTRY_BYTES)?.chunks_exact(BLOCK_ENTRY_BYTES).map(block_entry).collect();
+        let compression_methods = (0..method_count)
+            .map(|_| {
+                reader.bytes(method_length).map(|name| {
+                    let end = name.iter().position(|&b| b == 0).unwrap_or(name.len());
+                    String::from_utf8_lossy(&name[..end]).to_string()
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if flags & FLAG_SIGNED != 0 {
+            // Table of contents and block signatures, then one hash per block
+            let hash_size = reader.index()?;
+            table(&mut reader, hash_size, 2)?;
+            table(&mut reader, block_count, BLOCK_SIGNATURE_BYTES)?;
+        }
+
+        let (mount_point, entries) = if flags & FLAG_INDEXED != 0 && directory_index_size > 0 {
+            let index = reader.bytes(directory_index_size)?;
+            read_directory_index(index, chunk_ids, offsets).map_err(|e| invalid(format!("directory index: {}", e)))?
+        } else {
+            (String::new(), Vec::new())
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            version,
+            mount_point,
+            compression_block_size,
+            partition_size: if version >= TOC_VERSION_PARTITION_SIZE && partition_size > 0 {
+                partition_size
+            } else {
+                u64::MAX
+            },
+            compression_methods,
+            entries,
+            blocks,
+        })
+    }
+
+    /// `Game.ucas` for partition 0, `Game_s1.ucas` for partition 1, ...
+    pub fn partition_path(&self, partition: u64) -> PathBuf {
+        if partition == 0 {
+            return self.path.with_extension("ucas");
+        }
+        let stem = self.path.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+        self.path.with_file_name(format!("{}_s{}.ucas", stem, partition))
+    }
+
+    /// Uncompressed bytes of `entry`, decoded block by block from the .ucas
+    /// partitions. Zlib and Gzip blocks are supported; Oodle, the UE5
+    /// default, is not and fails with an error naming it.
+    pub fn read_entry(&self, entry: &TocEntry) -> Result<Vec<u8>> {
+        if entry.length == 0 {
+            return Ok(Vec::new());
+        }
+        let block_size = u64::from(self.compression_block_size);
+        if block_size == 0 {
+            return Err(FastStartupError::AssetError(format!("{}: compression block size is 0", self.path.display())));
+        }
+        let first = entry.offset / block_size;
+        let last = (entry.offset + entry.length - 1) / block_size;
+
+        // `length` comes from the .utoc, so check it before sizing anything by it
+        if last >= self.blocks.len() as u64 {
+            return Err(FastStartupError::AssetError(format!(
+                "{}: {} needs block {} of {}",
+                self.path.display(),
+                entry.path,
+                last,
+                self.blocks.len()
+            )));
+        }
+        let blocks = &self.blocks[first as usize..=last as usize];
+
+        let mut partitions: Vec<Option<File>> = Vec::new();
+        let mut data = Vec::with_capacity(blocks.iter().map(|block| block.uncompressed_size as usize).sum());
+        for block in blocks {
+            let partition = block.offset / self.partition_size;
+            if partitions.len() <= partition as usize {
+                partitions.resize_with(partition as usize + 1, || None);
+            }
+            let file = match &mut partitions[partition as usize] {
+                Some(file) => file,
+                slot => slot.insert(File::open(self.partition_path(partition))?),
+            };
+            file.seek(SeekFrom::Start(block.offset % self.partition_size))?;
+            let mut raw = vec![0u8; block.compressed_size as usize];
+            file.read_exact(&mut raw)?;
+            data.extend(self.decode_block(block, raw)?);
+        }
+
+        let start = (entry.offset - first * block_size) as usize;
+        let end = start + entry.length as usize;
+        if end > data.len() {
+            return Err(FastStartupError::AssetError(format!(
+                "{}: {} runs past its blocks",
+                self.path.display(),
+                entry.path
+            )));
+        }
+        data.truncate(end);
+        data.drain(..start);
+        Ok(data)
+    }
+
+    fn decode_block(&self, block: &CompressionBlock, raw: Vec<u8>) -> Result<Vec<u8>> {
+        if block.method == 0 {
+            return Ok(raw);
+        }
+        let method = self.compression_methods.get(block.method as usize - 1).map(String::as_str).unwrap_or("unknown");
+        let mut decoded = Vec::with_capacity(block.uncompressed_size as usize);
+        let decoder: Box<dyn Read> = if method.eq_ignore_ascii_case("zlib") {
+            Box::new(ZlibDecoder::new(raw.as_slice()))
+        } else if method.eq_ignore_ascii_case("gzip") {
+            Box::new(GzDecoder::new(raw.as_slice()))
+        } else if method.eq_ignore_ascii_case("oodle") {
+            return Err(FastStartupError::AssetError(format!(
+                "{}: blocks compressed with Oodle cannot be decoded (the Oodle SDK is not available); \
+                 package with the Zlib compression format or without compression",
+                self.path.display()
+            )));
+        } else {
+            return Err(FastStartupError::AssetError(format!(
+                "{}: blocks compressed with {} cannot be decoded; cook with zlib or without compression",
+                self.path.display(),
+                method
+            )));
+        };
+        decoder.take(u64::from(block.uncompressed_size)).read_to_end(&mut decoded)?;
+        if decoded.len() != block.uncompressed_size as usize {
+            return Err(FastStartupError::AssetError(format!(
+                "{}: block at {} decoded to {} of {} bytes",
+                self.path.display(),
+                block.offset,
+                decoded.len(),
+                block.uncompressed_size
+            )));
+        }
+        Ok(decoded)
+    }
+}
+
+/// `count` fixed-size records from `reader`
+fn table<'a>(reader: &mut ByteReader<'a>, count: usize, size: usize) -> Result<&'a [u8]> {
+    let len = count
+        .checked_mul(size)
+        .ok_or_else(|| FastStartupError::AssetError(format!("Table of {} entries is too large", count)))?;
+    reader.bytes(len)
+}
+
+/// 40-bit offset, 24-bit compressed and uncompressed sizes, method index
+fn block_entry(entry: &[u8]) -> CompressionBlock {
+    let le = |bytes: &[u8]| bytes.iter().rev().fold(0u64, |acc, &b| (acc << 8) | u64::from(b));
+    CompressionBlock {
+        offset: le(&entry[0..5]),
+        compressed_size: le(&entry[5..8]) as u32,
+        uncompressed_size: le(&entry[8..11]) as u32,
+        method: entry[11],
+    }
+}
+
+/// `FIoOffsetAndLength`: two 40-bit big-endian values
+fn offset_and_length(entry: &[u8]) -> (u64, u64) {
+    let be = |bytes: &[u8]| bytes.iter().fold(0u64, |acc, &b| (acc << 8) | u64::from(b));
+    (be(&entry[0..5]), be(&entry[5..10]))
+}
+
+/// Mount point and named chunks of a `FIoDirectoryIndexResource`: a tree of
+/// directories and files linked by index, names in a shared string table
+fn read_directory_index(index: &[u8], chunk_ids: &[u8], offsets: &[u8]) -> Result<(String, Vec<TocEntry>)> {
+    let mut reader = ByteReader::new(index);
+    let mount_point = reader.fstring()?;
+    let directory_count = reader.index()?;
+    let directories = (0..directory_count)
+        .map(|_| Ok([reader.u32()?, reader.u32()?, reader.u32()?, reader.u32()?]))
+        .collect::<Result<Vec<_>>>()?;
+    let file_count = reader.index()?;
+    let files = (0..file_count)
+        .map(|_| Ok([reader.u32()?, reader.u32()?, reader.u32()?]))
+        .collect::<Result<Vec<_>>>()?;
+    let string_count = reader.index()?;
+    let strings = (0..string_count).map(|_| reader.fstring()).collect::<Result<Vec<_>>>()?;
+
+    let broken = |what: &str, index: u32| FastStartupError::AssetError(format!("{} {} out of range", what, index));
+    let name = |index: u32| strings.get(index as usize).ok_or_else(|| broken("name", index));
+    let mut entries = Vec::new();
+    let mut pending = if directories.is_empty() { Vec::new() } else { vec![(0u32, String::new())] };
+    let mut visited = 0;
+    while let Some((directory, parent)) = pending.pop() {
+        visited += 1;
+        let [dir_name, first_child, _, first_file] =
+            *directories.get(directory as usize).filter(|_| visited <= directories.len()).ok_or_else(|| broken("directory", directory))?;
+        let prefix = if dir_name == INVALID_INDEX { parent } else { format!("{}{}/", parent, name(dir_name)?) };
+
+        let mut file = first_file;
+        let mut listed = 0;
+        while file != INVALID_INDEX {
+            listed += 1;
+            let [file_name, next_file, chunk] =
+                *files.get(file as usize).filter(|_| listed <= files.len()).ok_or_else(|| broken("file", file))?;
+            let chunk = chunk as usize;
+            let (Some(id), Some(span)) = (
+                chunk_ids.get(chunk * CHUNK_ID_BYTES..(chunk + 1) * CHUNK_ID_BYTES),
+                offsets.get(chunk * OFFSET_LENGTH_BYTES..(chunk + 1) * OFFSET_LENGTH_BYTES),
+            ) else {
+                return Err(broken("chunk", chunk as u32));
+            };
+            let (offset, length) = offset_and_length(span);
+            entries.push(TocEntry {
+                path: format!("{}{}", prefix, name(file_name)?),
+                chunk_type: id[CHUNK_ID_BYTES - 1],
+                offset,
+                length,
+            });
+            file = next_file;
+        }
+
+        let mut child = first_child;
+        while child != INVALID_INDEX {
+            let next_sibling = directories.get(child as usize).ok_or_else(|| broken("directory", child))?[2];
+            pending.push((child, prefix.clone()));
+            if pending.len() > directories.len() {
+                return Err(broken("directory", child));
+            }
+            child = next_sibling;
+        }
+    }
+    Ok((mount_point, entries))
+}
+
+/// Every .utoc below a packaged build directory, sorted by path
+pub fn find_toc_files(build_dir: &Path) -> Vec<PathBuf> {
+    let mut tocs: Vec<PathBuf> = WalkDir::new(build_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.into_path())
+        .filter(|p| p.extension().map(|e| e.eq_ignore_ascii_case("utoc")).unwrap_or(false))
+        .collect();
+    tocs.sort();
+    tocs
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::Write;
+
+    const BLOCK_SIZE: u32 = 64;
+
+    /// Writes `<dir>/<name>.utoc` and `.ucas` holding `files` below mount
+    /// point `../../../Shooter/`, one file per directory level given by its
+    /// path; files marked compressed are stored as zlib blocks
+    pub(crate) fn write_container(dir: &Path, name: &str, files: &[(&str, &[u8], bool)], flags: u8) -> PathBuf {
+        let mut ucas = Vec::new();
+        let mut blocks = Vec::new();
+        let mut offsets = Vec::new();
+        let mut chunk_ids = Vec::new();
+        let mut uncompressed = 0u64;
+        for (index, (_, data, compress)) in files.iter().enumerate() {
+            offsets.extend_from_slice(&uncompressed.to_be_bytes()[3..]);
+            offsets.extend_from_slice(&(data.len() as u64).to_be_bytes()[3..]);
+            let mut id = [0u8; CHUNK_ID_BYTES];
+            id[0] = index as u8;
+            id[CHUNK_ID_BYTES - 1] = CHUNK_TYPE_PACKAGE;
+            chunk_ids.extend_from_slice(&id);
+            for chunk in data.chunks(BLOCK_SIZE as usize) {
+                let stored = if *compress {
+                    let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+                    encoder.write_all(chunk).unwrap();
+                    encoder.finish().unwrap()
+                } else {
+                    chunk.to_vec()
+                };
+                blocks.extend_from_slice(&(ucas.len() as u64).to_le_bytes()[..5]);
+                blocks.extend_from_slice(&(stored.len() as u32).to_le_bytes()[..3]);
+                blocks.extend_from_slice(&(chunk.len() as u32).to_le_bytes()[..3]);
+                blocks.push(u8::from(*compress));
+                ucas.extend(stored);
+            }
+            uncompressed += data.len().div_ceil(BLOCK_SIZE as usize) as u64 * u64::from(BLOCK_SIZE);
+        }
+
+        // Directory index: root, then one directory per distinct parent
+        let fstring = |out: &mut Vec<u8>, s: &str| {
+            out.extend_from_slice(&(s.len() as i32 + 1).to_le_bytes());
+            out.extend_from_slice(s.as_bytes());
+            out.push(0);
+        };
+        let mut strings: Vec<String> = Vec::new();
+        let mut intern = |s: &str| match strings.iter().position(|x| x == s) {
+            Some(i) => i as u32,
+            None => {
+                strings.push(s.to_string());
+                strings.len() as u32 - 1
+            }
+        };
+        let mut directories: Vec<[u32; 4]> = vec![[INVALID_INDEX; 4]];
+        let mut dir_paths = vec![String::new()];
+        let mut file_entries: Vec<[u32; 3]> = Vec::new();
+        for (index, (path, _, _)) in files.iter().enumerate() {
+            let mut dir = 0usize;
+            let mut parts: Vec<&str> = path.split('/').collect();
+            let file_name = parts.pop().unwrap();
+            for part in parts {
+                let child_path = format!("{}{}/", dir_paths[dir], part);
+                dir = match dir_paths.iter().position(|p| *p == child_path) {
+                    Some(existing) => existing,
+                    None => {
+                        directories.push([intern(part), INVALID_INDEX, directories[dir][1], INVALID_INDEX]);
+                        dir_paths.push(child_path);
+                        directories[dir][1] = directories.len() as u32 - 1;
+                        directories.len() - 1
+                    }
+                };
+            }
+            file_entries.push([intern(file_name), directories[dir][3], index as u32]);
+            directories[dir][3] = file_entries.len() as u32 - 1;
+        }
+        let mut index = Vec::new();
+        fstring(&mut index, "../../../Shooter/");
+        index.extend_from_slice(&(directories.len() as i32).to_le_bytes());
+        for dir in &directories {
+            dir.iter().for_each(|v| index.extend_from_slice(&v.to_le_bytes()));
+        }
+        index.extend_from_slice(&(file_entries.len() as i32).to_le_bytes());
+        for file in &file_entries {
+            file.iter().for_each(|v| index.extend_from_slice(&v.to_le_bytes()));
+        }
+        index.extend_from_slice(&(strings.len() as i32).to_le_bytes());
+        strings.iter().for_each(|s| fstring(&mut index, s));
+
+        let mut header = Vec::new();
+        header.extend_from_slice(TOC_MAGIC);
+        header.extend_from_slice(&[TOC_VERSION_PERFECT_HASH_WITH_OVERFLOW, 0, 0, 0]);
+        for value in [
+            144,
+            files.len() as u32,
+            (blocks.len() / BLOCK_ENTRY_BYTES) as u32,
+            BLOCK_ENTRY_BYTES as u32,
+            1,
+            32,
+            BLOCK_SIZE,
+            index.len() as u32,
+            1,
+        ] {
+            header.extend_from_slice(&value.to_le_bytes());
+        }
+        header.extend_from_slice(&[0u8; 24]);
+        header.extend_from_slice(&[flags | FLAG_INDEXED, 0, 0, 0]);
+        header.extend_from_slice(&0u32.to_le_bytes());
+        header.extend_from_slice(&u64::MAX.to_le_bytes());
+        header.extend_from_slice(&0u32.to_le_bytes());
+        header.resize(144, 0);
+
+        let mut method = [0u8; 32];
+        method[..4].copy_from_slice(b"Zlib");
+        let toc = [header, chunk_ids, offsets, blocks, method.to_vec(), index].concat();
+        let toc_path = dir.join(format!("{}.utoc", name));
+        std::fs::write(&toc_path, toc).unwrap();
+        std::fs::write(dir.join(format!("{}.ucas", name)), ucas).unwrap();
+        toc_path
+    }
+
+    #[test]
+    fn test_read_container() {
+        let dir = std::env::temp_dir().join(format!("uefast_iostore_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let map: Vec<u8> = (0..150u8).collect();
+        let path = write_container(
+            &dir,
+            "pakchunk0-Windows",
+            &[
+                ("Content/Maps/Entry.umap", &map, true),
+                ("Content/UI/W_Main.uasset", b"widget", false),
+                ("Content/Maps/Entry.uexp", &[], false),
+            ],
+            0,
+        );
+
+        let toc = TocFile::open(&path).unwrap();
+        assert_eq!(toc.mount_point, "../../../Shooter/");
+        assert_eq!(toc.compression_methods, ["Zlib"]);
+        let mut names: Vec<&str> = toc.entries.iter().map(|e| e.path.as_str()).collect();
+        names.sort();
+        assert_eq!(names, ["Content/Maps/Entry.uexp", "Content/Maps/Entry.umap", "Content/UI/W_Main.uasset"]);
+        let entry = |name: &str| toc.entries.iter().find(|e| e.path.ends_with(name)).unwrap();
+        assert_eq!(entry("Entry.umap").length, 150);
+        assert_eq!(entry("Entry.umap").chunk_type, CHUNK_TYPE_PACKAGE);
+        assert_eq!(toc.read_entry(entry("Entry.umap")).unwrap(), map);
+        assert_eq!(toc.read_entry(entry("W_Main.uasset")).unwrap(), b"widget");
+        assert!(toc.read_entry(entry("Entry.uexp")).unwrap().is_empty());
+        // A corrupt 40-bit length is an error, not a terabyte allocation
+        let mut corrupt = entry("W_Main.uasset").clone();
+        corrupt.length = (1 << 40) - 1;
+        assert!(toc.read_entry(&corrupt).unwrap_err().to_string().contains("needs block"));
+        assert_eq!(toc.partition_path(2), dir.join("pakchunk0-Windows_s2.ucas"));
+        assert_eq!(find_toc_files(&dir), [path]);
+
+        let encrypted = write_container(&dir, "encrypted", &[("Content/A.uasset", b"a", false)], FLAG_ENCRYPTED);
+        assert!(TocFile::open(&encrypted).unwrap_err().to_string().contains("encrypted"));
+        std::fs::write(dir.join("broken.utoc"), b"-==--==--==--==-").unwrap();
+        assert!(TocFile::open(&dir.join("broken.utoc")).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_oodle_blocks_are_reported() {
+        let dir = std::env::temp_dir().join(format!("uefast_iostore_oodle_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = write_container(&dir, "pakchunk0-Windows", &[("Content/Maps/Entry.umap", b"map", true)], 0);
+        // Same container with its one compression method renamed
+        let mut toc = std::fs::read(&path).unwrap();
+        let at = toc.windows(5).position(|w| w == b"Zlib\0").unwrap();
+        toc[at..at + 5].copy_from_slice(b"Oodle");
+        std::fs::write(&path, toc).unwrap();
+
+        let toc = TocFile::open(&path).unwrap();
+        assert_eq!(toc.compression_methods, ["Oodle"]);
+        let error = toc.read_entry(&toc.entries[0]).unwrap_err().to_string();
+        assert!(error.contains("compressed with Oodle"), "{}", error);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
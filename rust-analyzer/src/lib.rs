@@ -3,12 +3,83 @@
 //! Licensed under Apache 2.0
 
 pub mod analyzer;
+pub mod anomalies;
+pub mod attribution;
+pub mod audit;
+pub mod bloom;
+pub mod byte_reader;
 pub mod cache;
+pub mod cache_log;
+pub mod calibration;
+pub mod check;
+pub mod chunks;
+pub mod config;
+pub mod config_preloads;
+pub mod content;
+pub mod cook;
+pub mod crashes;
+pub mod deps;
+pub mod direct_io;
+pub mod discovery;
+pub mod editor_data;
+pub mod editor_open;
+pub mod engine;
+pub mod estimator;
+#[cfg(feature = "gpu_hash")]
+pub mod gpu_hash;
 pub mod graph;
+pub mod graph_export;
+pub mod graph_view;
+pub mod hardware;
 pub mod hash;
+pub mod heuristics;
+pub mod i18n;
+pub mod import_cache;
+pub mod ini_hints;
+pub mod imports;
+pub mod iostore;
+pub mod load_sim;
+pub mod localization;
+pub mod memory;
+pub mod mmap_pool;
+pub mod next_steps;
+pub mod pak;
+pub mod parallelism;
+pub mod patch_diff;
+pub mod path_index;
+pub mod portfolio;
+pub mod priority;
+pub mod project;
+pub mod properties;
+pub mod redact;
+pub mod remote_cache;
+pub mod report;
+pub mod resident_memory;
+pub mod rule_packs;
+pub mod sampling;
+pub mod scenarios;
+pub mod schema;
 pub mod scanner;
+pub mod scripting;
+pub mod shards;
+pub mod source_scan;
+pub mod store_manifest;
+pub mod strategy_compare;
+pub mod tables;
+pub mod tags;
+pub mod telemetry;
+pub mod term;
+pub mod testgen;
 pub mod asm_bindings;
 pub mod uasset;
+pub mod ui;
+pub mod usage;
+pub mod verify_state;
+pub mod versions;
+pub mod warmup;
+pub mod watcher;
+pub mod webhook;
+pub mod wizard;
 
 use thiserror::Error;
 
@@ -31,9 +102,61 @@ pub enum FastStartupError {
 
     #[error("Serialization error: {0}")]
     SerializationError(String),
+
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+
+    #[error("Config error: {0}")]
+    ConfigError(String),
+
+    #[error("Notification error: {0}")]
+    NotifyError(String),
+
+    #[error("Telemetry error: {0}")]
+    TelemetryError(String),
+
+    #[error("Script error: {0}")]
+    ScriptError(String),
+
+    #[error("Watch error: {0}")]
+    WatchError(String),
+
+    #[error("Scan index error: {0}")]
+    IndexError(String),
+
+    #[error("GPU error: {0}")]
+    GpuError(String),
+}
+
+impl FastStartupError {
+    /// Stable name of the error kind, without any message details
+    pub fn category(&self) -> &'static str {
+        match self {
+            FastStartupError::ProjectNotFound(_) => "project_not_found",
+            FastStartupError::InvalidProject(_) => "invalid_project",
+            FastStartupError::AssetError(_) => "asset",
+            FastStartupError::CacheError(_) => "cache",
+            FastStartupError::IoError(_) => "io",
+            FastStartupError::SerializationError(_) => "serialization",
+            FastStartupError::InvalidArgument(_) => "invalid_argument",
+            FastStartupError::ConfigError(_) => "config",
+            FastStartupError::NotifyError(_) => "notify",
+            FastStartupError::TelemetryError(_) => "telemetry",
+            FastStartupError::ScriptError(_) => "script",
+            FastStartupError::WatchError(_) => "watch",
+            FastStartupError::IndexError(_) => "index",
+            FastStartupError::GpuError(_) => "gpu",
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, FastStartupError>;
 
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
-pub const CACHE_MAGIC: &[u8; 8] = b"UEFAST01";
+pub const CACHE_MAGIC: &[u8; 8] = b"UEFAST07";
+/// Magics of earlier cache layouts, rejected with a rebuild hint
+pub const LEGACY_CACHE_MAGICS: &[&[u8; 8]] =
+    &[b"UEFAST01", b"UEFAST02", b"UEFAST03", b"UEFAST04", b"UEFAST05", b"UEFAST06"];
+
+/// Per-project state directory (history, sidecars) under the project root
+pub const STATE_DIR: &str = ".uefast";
@@ -0,0 +1,483 @@
+//! Load Simulation Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Replay of the startup load order against an I/O model. The engine
+//! requests packages in the computed load order; each ordering strategy
+//! lays them out contiguously on disk in its own order, and the simulated
+//! device pays a seek whenever a read does not continue the previous one,
+//! overlaps up to `queue_depth` requests and shares its bandwidth between
+//! them. Comparing the timelines prices an ordering before it ships.
+
+use crate::estimator::StorageTier;
+use crate::report::{Finding, ReportData, Severity, Table};
+use crate::scanner::{package_stem, AssetInfo, AssetType};
+use crate::{FastStartupError, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+const MB: f64 = 1024.0 * 1024.0;
+
+/// A strategy this much slower than the fastest one is reported
+pub const SLOWER_WARN_RATIO: f64 = 1.1;
+
+/// Seek latency, sustained bandwidth and outstanding requests of a device
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct IoModel {
+    pub seek_ms: f64,
+    pub bandwidth_mb_s: f64,
+    pub queue_depth: usize,
+}
+
+impl IoModel {
+    /// Typical figures for a storage tier
+    pub fn for_tier(tier: StorageTier) -> Self {
+        let (seek_ms, bandwidth_mb_s, queue_depth) = match tier {
+            StorageTier::Nvme => (0.02, 3000.0, 32),
+            StorageTier::Ssd => (0.1, 500.0, 8),
+            StorageTier::Hdd => (8.0, 150.0, 1),
+            StorageTier::Network => (1.0, 100.0, 4),
+            StorageTier::Unknown => (0.1, 500.0, 4),
+        };
+        Self { seek_ms, bandwidth_mb_s, queue_depth }
+    }
+
+    /// Reads served from the OS page cache: no seeks, memory copy speed
+    pub fn page_cache() -> Self {
+        Self { seek_ms: 0.0, bandwidth_mb_s: 10000.0, queue_depth: 32 }
+    }
+
+    pub fn describe(&self) -> String {
+        format!("{} ms seek, {} MB/s, queue depth {}", self.seek_ms, self.bandwidth_mb_s, self.queue_depth)
+    }
+}
+
+/// `[io]` of `.uefast.toml` and the matching command line flags: whatever
+/// is set replaces the storage tier's figure
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct IoOverrides {
+    pub seek_ms: Option<f64>,
+    pub bandwidth_mb_s: Option<f64>,
+    pub queue_depth: Option<usize>,
+}
+
+impl IoOverrides {
+    pub fn apply(&self, model: IoModel) -> IoModel {
+        IoModel {
+            seek_ms: self.seek_ms.unwrap_or(model.seek_ms),
+            bandwidth_mb_s: self.bandwidth_mb_s.unwrap_or(model.bandwidth_mb_s),
+            queue_depth: self.queue_depth.unwrap_or(model.queue_depth).max(1),
+        }
+    }
+}
+
+/// On-disk order of the startup packages
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OrderStrategy {
+    /// The computed load order, as `optimize` writes it
+    Computed,
+    /// By path, what packaging does without an order file
+    Alphabetical,
+    /// Largest first
+    Size,
+    /// Grouped by asset type, by path within a type
+    Type,
+    /// An existing open order file
+    File,
+}
+
+impl OrderStrategy {
+    /// Strategies simulated when none are asked for (`file` needs `--order`)
+    pub const BUILTIN: [OrderStrategy; 4] =
+        [OrderStrategy::Computed, OrderStrategy::Alphabetical, OrderStrategy::Size, OrderStrategy::Type];
+
+    /// Positions into `entries` (relative path, bytes and type, in load
+    /// order) in this strategy's order. `File` keeps the load order; the
+    /// order file's ranks are applied by the caller.
+    pub fn arrange(&self, entries: &[(&str, u64, AssetType)]) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..entries.len()).collect();
+        match self {
+            OrderStrategy::Computed | OrderStrategy::File => {}
+            OrderStrategy::Alphabetical => order.sort_by(|&a, &b| entries[a].0.cmp(entries[b].0)),
+            OrderStrategy::Size => order.sort_by(|&a, &b| entries[b].1.cmp(&entries[a].1).then(entries[a].0.cmp(entries[b].0))),
+            OrderStrategy::Type => order.sort_by(|&a, &b| {
+                entries[a].2.as_str().cmp(entries[b].2.as_str()).then(entries[a].0.cmp(entries[b].0))
+            }),
+        }
+        order
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderStrategy::Computed => "computed",
+            OrderStrategy::Alphabetical => "alphabetical",
+            OrderStrategy::Size => "size",
+            OrderStrategy::Type => "type",
+            OrderStrategy::File => "file",
+        }
+    }
+}
+
+impl std::fmt::Display for OrderStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for OrderStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "computed" => Ok(OrderStrategy::Computed),
+            "alphabetical" | "path" => Ok(OrderStrategy::Alphabetical),
+            "size" => Ok(OrderStrategy::Size),
+            "type" => Ok(OrderStrategy::Type),
+            "file" => Ok(OrderStrategy::File),
+            other => Err(format!(
+                "unknown order strategy '{}' (expected computed, alphabetical, size, type or file)",
+                other
+            )),
+        }
+    }
+}
+
+/// One package read of a simulated timeline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineEntry {
+    pub relative_path: String,
+    /// Byte offset in the simulated layout
+    pub offset: u64,
+    pub size_bytes: u64,
+    pub start_ms: f64,
+    pub end_ms: f64,
+    /// The read did not continue the previous one
+    pub seek: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulatedOrder {
+    pub strategy: OrderStrategy,
+    pub total_ms: f64,
+    pub seeks: usize,
+    /// Time the device spent seeking, overlapped or not
+    pub seek_ms: f64,
+    /// Milliseconds until half the startup bytes are read
+    pub half_loaded_ms: f64,
+    pub timeline: Vec<TimelineEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoadSimulation {
+    pub project_name: String,
+    pub io: IoModel,
+    pub packages: usize,
+    pub total_bytes: u64,
+    /// Fastest first
+    pub orders: Vec<SimulatedOrder>,
+    /// Open order file entries that match no startup package
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unmatched: Vec<String>,
+}
+
+/// A startup package with its companion `.uexp`/`.ubulk` bytes
+struct Package<'a> {
+    asset: &'a AssetInfo,
+    size_bytes: u64,
+}
+
+impl LoadSimulation {
+    /// Simulate `strategies` for the packages of `load_order` (project-relative
+    /// paths in the order the engine requests them); `order_file` entries
+    /// lay out the `file` strategy
+    pub fn run(
+        project_name: &str,
+        load_order: &[String],
+        assets: &[AssetInfo],
+        io: IoModel,
+        strategies: &[OrderStrategy],
+        order_file: &[String],
+    ) -> Self {
+        let stem = |relative: &str| package_stem(Path::new(""), relative);
+        let mut companions: HashMap<String, u64> = HashMap::new();
+        for asset in assets.iter().filter(|a| matches!(a.asset_type, AssetType::UExp | AssetType::UBulk)) {
+            *companions.entry(stem(&asset.relative_path)).or_default() += asset.size_bytes;
+        }
+        let by_relative: HashMap<String, &AssetInfo> =
+            assets.iter().map(|a| (a.relative_path.replace('\\', "/"), a)).collect();
+        let demand: Vec<Package> = load_order
+            .iter()
+            .filter_map(|relative| by_relative.get(relative.as_str()))
+            .filter(|a| !matches!(a.asset_type, AssetType::UExp | AssetType::UBulk))
+            .map(|&asset| Package {
+                asset,
+                size_bytes: asset.size_bytes + companions.get(&stem(&asset.relative_path)).copied().unwrap_or(0),
+            })
+            .collect();
+
+        // Order file positions by package, so `.uexp` entries count for
+        // their package too
+        let mut file_rank: HashMap<String, usize> = HashMap::new();
+        let mut unmatched = Vec::new();
+        let startup: HashSet<String> = demand.iter().map(|p| stem(&p.asset.relative_path)).collect();
+        for (rank, entry) in order_file.iter().enumerate() {
+            let key = stem(entry);
+            if startup.contains(&key) {
+                file_rank.entry(key).or_insert(rank);
+            } else if !by_relative.contains_key(entry.as_str()) {
+                unmatched.push(entry.clone());
+            }
+        }
+
+        let mut orders: Vec<SimulatedOrder> = strategies
+            .iter()
+            .map(|&strategy| {
+                let layout = match strategy {
+                    // Packages the file leaves out are appended in load order
+                    OrderStrategy::File => {
+                        let mut layout: Vec<usize> = (0..demand.len()).collect();
+                        layout.sort_by_key(|&i| {
+                            file_rank.get(&stem(&demand[i].asset.relative_path)).copied().unwrap_or(usize::MAX)
+                        });
+                        layout
+                    }
+                    _ => strategy.arrange(
+                        &demand.iter().map(|p| (p.asset.relative_path.as_str(), p.size_bytes, p.asset.asset_type)).collect::<Vec<_>>(),
+                    ),
+                };
+                simulate(strategy, &demand, &layout, &io)
+            })
+            .collect();
+        orders.sort_by(|a, b| a.total_ms.total_cmp(&b.total_ms));
+
+        Self {
+            project_name: project_name.to_string(),
+            io,
+            packages: demand.len(),
+            total_bytes: demand.iter().map(|p| p.size_bytes).sum(),
+            orders,
+            unmatched,
+        }
+    }
+
+    pub fn fastest(&self) -> Option<&SimulatedOrder> {
+        self.orders.first()
+    }
+}
+
+/// Replay `demand` against `layout` (positions into `demand`, in disk order)
+fn simulate(strategy: OrderStrategy, demand: &[Package], layout: &[usize], io: &IoModel) -> SimulatedOrder {
+    let mut offsets = vec![0u64; demand.len()];
+    let mut offset = 0;
+    for &i in layout {
+        offsets[i] = offset;
+        offset += demand[i].size_bytes;
+    }
+
+    let bytes_per_ms = io.bandwidth_mb_s.max(f64::MIN_POSITIVE) * MB / 1000.0;
+    let half_bytes = demand.iter().map(|p| p.size_bytes).sum::<u64>() / 2;
+    let mut slots = vec![0.0f64; io.queue_depth.max(1)];
+    let mut bus_free = 0.0f64;
+    let mut head = 0u64;
+    let mut read_bytes = 0u64;
+    let mut order = SimulatedOrder {
+        strategy,
+        total_ms: 0.0,
+        seeks: 0,
+        seek_ms: 0.0,
+        half_loaded_ms: 0.0,
+        timeline: Vec::with_capacity(demand.len()),
+    };
+    for (i, package) in demand.iter().enumerate() {
+        // Issued as soon as a queue slot frees up
+        let slot = (0..slots.len()).min_by(|&a, &b| slots[a].total_cmp(&slots[b])).unwrap_or(0);
+        let seek = offsets[i] != head;
+        let ready = slots[slot] + if seek { io.seek_ms } else { 0.0 };
+        let start = ready.max(bus_free);
+        let end = start + package.size_bytes as f64 / bytes_per_ms;
+        bus_free = end;
+        slots[slot] = end;
+        head = offsets[i] + package.size_bytes;
+
+        if seek {
+            order.seeks += 1;
+            order.seek_ms += io.seek_ms;
+        }
+        read_bytes += package.size_bytes;
+        if order.half_loaded_ms == 0.0 && read_bytes >= half_bytes {
+            order.half_loaded_ms = end;
+        }
+        order.total_ms = order.total_ms.max(end);
+        order.timeline.push(TimelineEntry {
+            relative_path: package.asset.relative_path.replace('\\', "/"),
+            offset: offsets[i],
+            size_bytes: package.size_bytes,
+            start_ms: start,
+            end_ms: end,
+            seek,
+        });
+    }
+    order
+}
+
+/// Paths of an open order file (`"../../../Game/Content/A.uasset" 1` lines,
+/// as `optimize` writes them, or bare project-relative paths), by rank
+pub fn read_order_file(path: &Path) -> Result<Vec<String>> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| FastStartupError::InvalidArgument(format!("{}: {}", path.display(), e)))?;
+    let mut entries: Vec<(u64, usize, String)> = Vec::new();
+    for (line_number, line) in text.lines().map(str::trim).enumerate() {
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        let (file, rank) = match line.strip_prefix('"').and_then(|rest| rest.split_once('"')) {
+            Some((file, rest)) => (file, rest.trim().parse().unwrap_or(line_number as u64)),
+            None => (line, line_number as u64),
+        };
+        // "../../../<Project>/Content/A.uasset" -> "Content/A.uasset"
+        let mut relative = file.replace('\\', "/");
+        while let Some(rest) = relative.strip_prefix("../") {
+            relative = rest.to_string();
+        }
+        if file.starts_with("../") {
+            relative = relative.split_once('/').map(|(_, rest)| rest.to_string()).unwrap_or(relative);
+        }
+        entries.push((rank, line_number, relative));
+    }
+    entries.sort();
+    Ok(entries.into_iter().map(|(_, _, relative)| relative).collect())
+}
+
+impl ReportData for LoadSimulation {
+    fn title(&self) -> String {
+        format!("Load order simulation: {}", self.project_name)
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        serde_json::to_value(self).map_err(|e| FastStartupError::SerializationError(e.to_string()))
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        vec![
+            ("Startup packages".to_string(), self.packages.to_string()),
+            ("Startup size (MB)".to_string(), format!("{:.1}", self.total_bytes as f64 / MB)),
+            ("I/O model".to_string(), self.io.describe()),
+            (
+                "Fastest order".to_string(),
+                self.fastest().map(|o| format!("{} ({:.0} ms)", o.strategy, o.total_ms)).unwrap_or_default(),
+            ),
+        ]
+    }
+
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["strategy", "total_ms", "half_loaded_ms", "seeks", "seek_ms", "vs_fastest"]);
+        let fastest = self.fastest().map(|o| o.total_ms).unwrap_or(0.0);
+        for order in &self.orders {
+            table.push(vec![
+                order.strategy.to_string(),
+                format!("{:.1}", order.total_ms),
+                format!("{:.1}", order.half_loaded_ms),
+                order.seeks.to_string(),
+                format!("{:.1}", order.seek_ms),
+                format!("{:.2}x", if fastest > 0.0 { order.total_ms / fastest } else { 1.0 }),
+            ]);
+        }
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        let Some(fastest) = self.fastest() else {
+            return Vec::new();
+        };
+        let mut findings: Vec<Finding> = self
+            .orders
+            .iter()
+            .filter(|o| fastest.total_ms > 0.0 && o.total_ms > fastest.total_ms * SLOWER_WARN_RATIO)
+            .map(|o| Finding {
+                rule_id: "simulate/slower-order".to_string(),
+                // The shipped order file is the one that matters
+                severity: if o.strategy == OrderStrategy::File { Severity::Warning } else { Severity::Note },
+                message: format!(
+                    "{} order loads in {:.0} ms, {:.0}% slower than {} ({} seeks)",
+                    o.strategy,
+                    o.total_ms,
+                    (o.total_ms / fastest.total_ms - 1.0) * 100.0,
+                    fastest.strategy,
+                    o.seeks
+                ),
+                location: None,
+            })
+            .collect();
+        findings.extend(self.unmatched.iter().map(|entry| Finding {
+            rule_id: "simulate/unmatched".to_string(),
+            severity: Severity::Note,
+            message: format!("{} is in the order file but not a startup package", entry),
+            location: None,
+        }));
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn asset(relative_path: &str, size_bytes: u64) -> AssetInfo {
+        AssetInfo {
+            path: PathBuf::from("/p").join(relative_path),
+            relative_path: relative_path.to_string(),
+            asset_type: AssetType::from_extension(relative_path.rsplit('.').next().unwrap_or("")),
+            size_bytes,
+            modified: 0,
+            mount_point: "/Game".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_simulate_orders() {
+        let assets = vec![
+            asset("Content/Maps/Entry.umap", MB as u64),
+            asset("Content/A/Hero.uasset", MB as u64),
+            asset("Content/A/Hero.ubulk", MB as u64),
+            asset("Content/Z/Sky.uasset", 4 * MB as u64),
+        ];
+        let load_order: Vec<String> =
+            ["Content/Maps/Entry.umap", "Content/Z/Sky.uasset", "Content/A/Hero.uasset"].map(String::from).to_vec();
+        let io = IoModel { seek_ms: 10.0, bandwidth_mb_s: 1000.0, queue_depth: 1 };
+        let order_file = vec!["Content/A/Hero.uexp".to_string(), "Content/Gone.uasset".to_string()];
+        let strategies = [OrderStrategy::Computed, OrderStrategy::Alphabetical, OrderStrategy::File];
+        let simulation = LoadSimulation::run("Game", &load_order, &assets, io, &strategies, &order_file);
+
+        assert_eq!(simulation.packages, 3);
+        assert_eq!(simulation.total_bytes, 7 * MB as u64);
+        let computed = &simulation.orders[0];
+        assert_eq!(computed.strategy, OrderStrategy::Computed);
+        // Contiguous from the start: 7 MB at 1 MB/ms
+        assert_eq!(computed.seeks, 0);
+        assert!((computed.total_ms - 7.0).abs() < 1e-9);
+        let alphabetical = simulation.orders.iter().find(|o| o.strategy == OrderStrategy::Alphabetical).unwrap();
+        // Seeks to Entry, streams on into Sky, seeks back to Hero
+        assert_eq!(alphabetical.seeks, 2);
+        assert!((alphabetical.total_ms - 27.0).abs() < 1e-9);
+        assert_eq!(simulation.unmatched, ["Content/Gone.uasset"]);
+
+        let slower: Vec<Severity> = simulation
+            .findings()
+            .into_iter()
+            .filter(|f| f.rule_id == "simulate/slower-order")
+            .map(|f| f.severity)
+            .collect();
+        assert_eq!(slower.len(), 2);
+        assert!(slower.contains(&Severity::Warning));
+
+        let path = std::env::temp_dir().join(format!("uefast-order-{}.txt", std::process::id()));
+        std::fs::write(&path, "\"../../../Game/Content/B.uasset\" 2\n\"../../../Game/Content/A.uasset\" 1\n").unwrap();
+        assert_eq!(read_order_file(&path).unwrap(), ["Content/A.uasset", "Content/B.uasset"]);
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,143 @@
+//! Localization Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! StringTable detection and which startup assets pull them in at boot
+
+use crate::properties::PropertyReader;
+use crate::scanner::{package_name_from_relative, AssetInfo, AssetType};
+use crate::uasset::{resolve_import, Package, UAssetParser};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StringTableInfo {
+    pub relative_path: String,
+    pub package_name: String,
+    /// `None` when the table's native data could not be read
+    pub entry_count: Option<usize>,
+    pub size_bytes: u64,
+    /// Startup assets that reference the table, by import or FText table id
+    pub startup_referencers: Vec<String>,
+}
+
+/// Find StringTables among `assets` and the `startup_assets` that use them.
+/// Tables referenced at startup come first, heaviest first.
+pub fn find_string_tables(assets: &[AssetInfo], startup_assets: &[AssetInfo]) -> Vec<StringTableInfo> {
+    let mut tables: Vec<StringTableInfo> = assets
+        .par_iter()
+        .filter(|a| a.asset_type == AssetType::UAsset)
+        .filter_map(inspect_string_table)
+        .collect();
+
+    if tables.is_empty() {
+        return tables;
+    }
+
+    // FText string table references serialize the table id as an FName, so
+    // the package path shows up in the referencer's name map even without
+    // a hard import
+    let by_package: HashMap<String, usize> = tables
+        .iter()
+        .enumerate()
+        .map(|(i, t)| (t.package_name.clone(), i))
+        .collect();
+
+    let references: Vec<(usize, String)> = startup_assets
+        .par_iter()
+        .filter(|a| matches!(a.asset_type, AssetType::UAsset | AssetType::UMap))
+        .flat_map_iter(|asset| {
+            let names = UAssetParser::parse_name_table(&asset.path).unwrap_or_default();
+            let mut hits: Vec<usize> = names
+                .iter()
+                .filter_map(|name| {
+                    let package = name.split_once('.').map(|(p, _)| p).unwrap_or(name);
+                    by_package.get(package).copied()
+                })
+                .collect();
+            hits.sort_unstable();
+            hits.dedup();
+            hits.into_iter()
+                .filter(|&i| tables[i].relative_path != asset.relative_path)
+                .map(|i| (i, asset.relative_path.clone()))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    for (index, referencer) in references {
+        tables[index].startup_referencers.push(referencer);
+    }
+    for table in &mut tables {
+        table.startup_referencers.sort();
+    }
+
+    tables.sort_by(|a, b| {
+        a.startup_referencers
+            .is_empty()
+            .cmp(&b.startup_referencers.is_empty())
+            .then_with(|| b.size_bytes.cmp(&a.size_bytes))
+            .then_with(|| a.relative_path.cmp(&b.relative_path))
+    });
+    tables
+}
+
+/// `None` unless the asset exports a StringTable
+fn inspect_string_table(asset: &AssetInfo) -> Option<StringTableInfo> {
+    let package = Package::open(&asset.path).ok()?;
+    let imports = package.imports().ok()?;
+    let exports = package.exports().ok()?;
+
+    let export = exports.iter().find(|e| {
+        resolve_import(&imports, e.class_index)
+            .map(|i| i.object_name == "StringTable")
+            .unwrap_or(false)
+    })?;
+
+    let entry_count = package
+        .export_data(export)
+        .and_then(|serial| parse_entry_count(serial, &package.names, package.header.file_version_ue5));
+
+    Some(StringTableInfo {
+        relative_path: asset.relative_path.clone(),
+        package_name: package_name_from_relative(&asset.relative_path)?,
+        entry_count,
+        size_bytes: asset.size_bytes,
+        startup_referencers: Vec::new(),
+    })
+}
+
+/// Tagged properties, UObject guid, then FStringTable's namespace and
+/// key-to-entry map
+pub fn parse_entry_count(data: &[u8], names: &[String], ue5_version: i32) -> Option<usize> {
+    let mut reader = PropertyReader::new(data, names, ue5_version);
+    reader.read_tags()?;
+    if reader.read_i32()? != 0 {
+        reader.skip(16)?;
+    }
+    reader.skip_fstring()?;
+    let entries = usize::try_from(reader.read_i32()?).ok()?;
+    // Every entry needs at least two FString lengths
+    (entries * 8 <= data.len()).then_some(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_entry_count() {
+        let names = vec!["None".to_string()];
+
+        let mut data = Vec::new();
+        for value in [0i32, 0, 0] {
+            data.extend_from_slice(&value.to_le_bytes()); // None, no guid
+        }
+        data.extend_from_slice(&3i32.to_le_bytes());
+        data.extend_from_slice(b"UI\0");
+        data.extend_from_slice(&2i32.to_le_bytes());
+        data.extend_from_slice(&[0u8; 16]);
+
+        assert_eq!(parse_entry_count(&data, &names, 1009), Some(2));
+    }
+}
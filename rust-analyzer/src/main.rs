@@ -3,18 +3,79 @@
 //! Licensed under Apache 2.0
 
 use anyhow::Result;
-use clap::{Parser, Subcommand};
-use std::path::PathBuf;
-use tracing::{info, Level};
+use clap::{Args, CommandFactory, FromArgMatches, Parser, Subcommand};
+use indicatif::{ProgressBar, ProgressStyle};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use tracing::{debug, info, warn, Level};
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
 use tracing_subscriber::FmtSubscriber;
 
 use ue5_fast_startup::{
-    cache::CacheBuilder,
-    scanner::AssetScanner,
-    graph::DependencyGraph,
-    analyzer::StartupAnalyzer,
+    cache::{CacheBuilder, CacheComparison, StartupCache},
+    cache_log::{self, CacheChanges, CacheLogEntry},
+    calibration::{self, Timing},
+    scanner::index::ScanIndex,
+    scanner::{
+        find_case_collisions, parse_since, parse_size, AssetFilter, AssetScanner, AssetType,
+        ScanReport,
+    },
+    schema::{self, SchemaKind},
+    scripting::ScriptContext,
+    graph::{DependencyGraph, SubgraphDirection},
+    graph_export::{GraphExporter, GraphFormat},
+    analyzer::{Priority, Recommendation, StartupAnalyzer},
+    attribution::{self, AttributionTree},
+    audit::AccessAudit,
+    check::{latest_recorded, load_baseline, record as record_report, Budgets, CheckReport},
+    config::{ProjectConfig, CONFIG_FILE_NAME},
+    content::open_layers,
+    cook::{read_change_list, CookPrediction},
+    crashes::{self, CrashCorrelation},
+    deps::{self, DependencyQuery},
+    direct_io::{drop_page_cache, CacheDrop},
+    discovery::ProjectDiscovery,
+    editor_data::{self, EditorData},
+    engine::{CommandletRun, EngineInstall},
+    estimator::StorageTier,
+    hardware::HardwareProfile,
+    i18n::Locale,
+    hash::{hash_file, hash_file_with, turbo_hash_with, HashAlgorithm, HashBackend, TurboStrategy},
+    ini_hints,
+    load_sim::{read_order_file, IoModel, IoOverrides, LoadSimulation, OrderStrategy},
+    next_steps,
+    pak::{find_pak_files, open_order_file, OrderValidation, PakFile, OPEN_ORDER_FILE},
+    patch_diff,
+    portfolio::{self, PortfolioReport},
+    priority,
+    project::Project,
+    redact::Redactor,
+    remote_cache,
+    rule_packs,
+    report::{print_report, write_report, OutputFormat, PrintFormat, Severity},
+    sampling::AssetSample,
+    shards,
+    store_manifest::{ManifestReport, StoreManifest},
+    strategy_compare::{StrategyComparison, StrategySpec},
+    tags::{self, AssetTags},
+    term::{self, glyph, ColorMode, Glyph, OutputStyle},
+    telemetry::{BenchIteration, BenchReport},
+    testgen::{self, TestProjectSpec},
+    webhook::{self, Notification},
+    usage::{self, TelemetrySettings},
+    verify_state::{FileStamp, QuickVerifyResult, VerifyState},
+    versions::VersionMatrix,
+    warmup::{self, WarmupStatus},
+    watcher::CacheWatcher,
+    wizard::{self, InitAnswers, Prompter},
+    FastStartupError,
 };
 
+const MB: f64 = 1024.0 * 1024.0;
+
+/// Reference cycles `graph` lists before leaving the rest to --print
+const MAX_LISTED_CYCLES: usize = 10;
+
 #[derive(Parser)]
 #[command(name = "ue5-fast-startup")]
 #[command(author = "Eddi Andreé Salazar Matos")]
@@ -28,20 +89,46 @@ struct Cli {
     #[arg(short, long, global = true)]
     verbose: bool,
 
-    /// Number of threads (0 = auto)
+    /// Number of threads (0 = .uefast.toml threads, else auto)
     #[arg(short, long, global = true, default_value = "0")]
     threads: usize,
+
+    /// Plain ASCII status markers (also UEFAST_ASCII=1; detected for legacy consoles)
+    #[arg(long, global = true)]
+    ascii: bool,
+
+    /// ANSI colors: auto, always, never (auto honors NO_COLOR)
+    #[arg(long, global = true, default_value = "auto")]
+    color: ColorMode,
+
+    /// Lower process priority: nice value 0-19 (Windows: below normal / idle)
+    #[arg(long, global = true)]
+    nice: Option<i32>,
+
+    /// Pin to these CPUs, e.g. 0-3,6; keeps long builds off interactive cores
+    #[arg(long, global = true)]
+    affinity: Option<String>,
+
+    /// Print the result of verify, quick-verify, stats, graph, deps and bench
+    /// to stdout: text (the log), json or ndjson (the log goes to stderr).
+    /// Separate from the report `--format` of each command
+    #[arg(long, value_name = "FORMAT", global = true, default_value = "text")]
+    print: PrintFormat,
 }
 
 #[derive(Subcommand)]
 enum Commands {
     /// Analyze UE5 project assets and dependencies
-    Analyze {
+    Analyze(AnalyzeArgs),
+
+    /// Scan, graph, analyze, cache and export load order and ini hints in one run
+    Optimize {
         /// Path to UE5 project root
         #[arg(short, long)]
         project: PathBuf,
 
-        /// Output analysis report as JSON
+        /// Directory for the report, cache, open order file and ini hints
+        /// (defaults to <project>/.uefast/optimize)
         #[arg(short, long)]
         output: Option<PathBuf>,
 
@@ -50,69 +137,271 @@ enum Commands {
         shaders: bool,
     },
 
+    /// Check startup budgets and compare against a baseline report
+    Check {
+        /// Path to UE5 project root
+        #[arg(short, long)]
+        project: PathBuf,
+
+        /// Baseline analysis report (JSON from `analyze -o`)
+        #[arg(short, long)]
+        baseline: Option<PathBuf>,
+
+        /// Startup size budget in MB
+        #[arg(long)]
+        max_startup_mb: Option<f64>,
+
+        /// Startup asset count budget
+        #[arg(long)]
+        max_startup_assets: Option<usize>,
+
+        /// Maximum startup size growth vs baseline in MB
+        #[arg(long)]
+        max_growth_mb: Option<f64>,
+
+        /// TAG=MB startup size budget of assets with a feature tag (repeatable)
+        #[arg(long, value_parser = parse_key_value)]
+        max_tag_mb: Vec<(String, String)>,
+
+        /// Output file (stdout if omitted)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Report format: json, ndjson, csv, html, markdown, sarif
+        #[arg(long, default_value = "markdown")]
+        format: OutputFormat,
+
+        /// Post budget breaches to the webhook configured in .uefast.toml
+        #[arg(long)]
+        notify: bool,
+    },
+
     /// Scan project for assets
     Scan {
         /// Path to UE5 project root
         #[arg(short, long)]
         project: PathBuf,
 
-        /// Output asset list as JSON
+        /// Output asset list
         #[arg(short, long)]
         output: Option<PathBuf>,
 
+        /// Report format: json, ndjson, csv, html, markdown, sarif
+        #[arg(long, default_value = "json")]
+        format: OutputFormat,
+
         /// Filter by asset type (e.g., "uasset", "umap")
         #[arg(short, long)]
         filter: Option<String>,
+
+        #[command(flatten)]
+        bounds: FilterArgs,
+
+        /// Content source: local, dir:<root>, pak:<file or dir>,
+        /// iostore:<.utoc or dir>, or an http(s) base URL serving
+        /// manifest.json. Repeat to layer patches and DLC over the base
+        /// game; later sources override earlier ones.
+        #[arg(long, value_name = "SPEC")]
+        content: Vec<String>,
+
+        /// Record every file that cannot be read, with the OS error
+        #[arg(long)]
+        audit_access: bool,
+
+        /// Write the access audit to this file (in --format)
+        #[arg(long, requires = "audit_access")]
+        audit_output: Option<PathBuf>,
+
+        /// Keep a scan index in .uefast/scan_index.sqlite; once it exists,
+        /// scans and cache builds only revisit directories that changed
+        #[arg(long, conflicts_with = "content")]
+        index: bool,
+
+        /// Drop the scan index and list every directory again
+        #[arg(long, conflicts_with = "content")]
+        reindex: bool,
     },
 
-    /// Build startup cache
+    /// Build startup cache, or work with existing caches
+    #[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
     Cache {
+        #[command(subcommand)]
+        command: Option<CacheCommand>,
+
+        #[command(flatten)]
+        build: CacheBuildArgs,
+    },
+
+    /// Verify existing cache
+    Verify {
+        #[command(flatten)]
+        args: VerifyArgs,
+    },
+
+    /// Show cache statistics
+    Stats {
+        /// Path to cache file
+        #[arg(short, long)]
+        cache: PathBuf,
+    },
+
+    /// Build dependency graph
+    Graph {
         /// Path to UE5 project root
         #[arg(short, long)]
         project: PathBuf,
 
-        /// Output cache file (.uefast)
+        /// Output graph as DOT, or GraphML / JSON for a .graphml / .json path
         #[arg(short, long)]
-        output: PathBuf,
+        output: Option<PathBuf>,
 
-        /// Force rebuild even if cache exists
-        #[arg(short, long)]
-        force: bool,
+        /// Include only startup-critical assets
+        #[arg(long)]
+        startup_only: bool,
+
+        /// Add soft package references as soft edges (dotted in DOT output);
+        /// with --startup-only they widen the startup set
+        #[arg(long)]
+        include_soft: bool,
+
+        /// Take dependencies from editor data (export-editor-script) instead
+        /// of parsing the packages it covers
+        #[arg(long, value_name = "FILE")]
+        editor_data: Option<PathBuf>,
+
+        #[command(flatten)]
+        export: GraphExportArgs,
     },
 
-    /// Verify existing cache
-    Verify {
-        /// Path to cache file
+    /// List what an asset depends on, or with --reverse what depends on it
+    Deps {
+        #[command(flatten)]
+        args: DepsArgs,
+    },
+
+    /// Break startup cost down into a tree of the assets responsible
+    Attribution {
+        /// Path to UE5 project root
         #[arg(short, long)]
-        cache: PathBuf,
+        project: PathBuf,
+
+        /// Output tree (.json, .html for a collapsible page, or .folded
+        /// collapsed stacks for flamegraph.pl, inferno or speedscope)
+        #[arg(short, long, default_value = "attribution.html")]
+        output: PathBuf,
+    },
 
+    /// Predict the incremental cook time of a change set: the changed
+    /// packages plus everything that hard-references them
+    PredictCook {
         /// Path to UE5 project root
         #[arg(short, long)]
         project: PathBuf,
+
+        /// Changed files or /Game/ package names, one per line
+        #[arg(long, value_name = "FILE")]
+        changes: PathBuf,
+
+        /// Output prediction
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Report format: json, ndjson, csv, html, markdown, sarif
+        #[arg(long, default_value = "json")]
+        format: OutputFormat,
+
+        /// Take dependencies from editor data (export-editor-script) instead
+        /// of parsing the packages it covers
+        #[arg(long, value_name = "FILE")]
+        editor_data: Option<PathBuf>,
     },
 
-    /// Show cache statistics
-    Stats {
-        /// Path to cache file
+    /// Replay the startup load order against an I/O model and compare
+    /// on-disk ordering strategies
+    Simulate(SimulateArgs),
+
+    /// Keep a cache current while the project is edited: rehash changed
+    /// assets and rewrite the cache after each burst of changes
+    Watch {
+        /// Path to UE5 project root
+        #[arg(short, long)]
+        project: PathBuf,
+
+        /// Cache file (.uefast) to keep current; build it with `cache` first
         #[arg(short, long)]
         cache: PathBuf,
+
+        /// Quiet time in milliseconds before changes are written
+        #[arg(long, default_value_t = 2000)]
+        debounce_ms: u64,
+
+        /// Append every write to <cache>.log (always done once the log exists)
+        #[arg(long)]
+        log: bool,
     },
 
-    /// Build dependency graph
-    Graph {
+    /// Pre-read the startup set into the OS page cache, or with --status
+    /// only report how much of it is cached and the expected read time
+    Warmup {
         /// Path to UE5 project root
         #[arg(short, long)]
         project: PathBuf,
 
-        /// Output graph as DOT format
+        /// Only sample page cache residency; read nothing
+        #[arg(long)]
+        status: bool,
+
+        /// Storage tier the cold estimate assumes: nvme, ssd, hdd, network,
+        /// unknown (.uefast.toml, then detected from the project path if omitted)
+        #[arg(long)]
+        storage: Option<StorageTier>,
+
+        /// Output status report
         #[arg(short, long)]
         output: Option<PathBuf>,
 
-        /// Include only startup-critical assets
+        /// Report format: json, ndjson, csv, html, markdown, sarif
+        #[arg(long, default_value = "json")]
+        format: OutputFormat,
+    },
+
+    /// Correlate crash, assert, ensure and hang reports from Saved/Crashes
+    /// with startup assets changed shortly before them (cache change log)
+    Crashes {
+        /// Path to UE5 project root
+        #[arg(short, long)]
+        project: PathBuf,
+
+        /// Cache whose change log (<cache>.log) dates the asset changes
+        #[arg(short, long)]
+        cache: PathBuf,
+
+        /// Crash report directory (default <project>/Saved/Crashes; packaged
+        /// builds write to the user's Saved/Crashes)
         #[arg(long)]
-        startup_only: bool,
+        crashes: Option<PathBuf>,
+
+        /// Reports this many seconds into a session or earlier are startup crashes
+        #[arg(long, default_value_t = crashes::DEFAULT_STARTUP_SECONDS)]
+        startup_seconds: f64,
+
+        /// Hours of cache changes before a crash that are suspects
+        #[arg(long, default_value_t = crashes::DEFAULT_WINDOW_HOURS)]
+        window_hours: i64,
+
+        /// Output correlation report
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Report format: json, ndjson, csv, html, markdown, sarif
+        #[arg(long, default_value = "json")]
+        format: OutputFormat,
     },
 
+    /// Build caches with two or more load order and hash strategies and
+    /// compare their startup cost side by side
+    CompareStrategies(CompareStrategiesArgs),
+
     /// Benchmark performance
     Bench {
         /// Path to UE5 project root
@@ -122,6 +411,14 @@ enum Commands {
         /// Number of iterations
         #[arg(short, long, default_value = "3")]
         iterations: u32,
+
+        /// Hash with unbuffered reads so iterations after the first are not served from RAM
+        #[arg(long)]
+        direct_io: bool,
+
+        /// Flush the OS file cache before every iteration (system-wide with root)
+        #[arg(long)]
+        cold: bool,
     },
 
     /// TURBO mode - ultra-fast cache with sampling
@@ -133,6 +430,11 @@ enum Commands {
         /// Output cache file (.uefast)
         #[arg(short, long)]
         output: PathBuf,
+
+        /// Sampling strategy: three-window or head-only (auto-selected from
+        /// the storage type if omitted)
+        #[arg(long)]
+        strategy: Option<TurboStrategy>,
     },
 
     /// Quick verify - fast change detection
@@ -144,237 +446,2714 @@ enum Commands {
         /// Path to UE5 project root
         #[arg(short, long)]
         project: PathBuf,
+
+        /// Only rehash files touched since the last quick verify of this
+        /// cache, reusing the earlier results for the rest
+        #[arg(long)]
+        since_last: bool,
+    },
+
+    /// Fit load time estimator coefficients to a measured startup
+    Calibrate {
+        /// Path to UE5 project root
+        #[arg(short, long)]
+        project: PathBuf,
+
+        /// Engine log of a measured startup (LoadMap and engine init timings)
+        #[arg(long)]
+        log: Option<PathBuf>,
+
+        /// Unreal Insights asset loading CSV export (per-package load times)
+        #[arg(long)]
+        insights_csv: Option<PathBuf>,
+
+        /// Command that launches a measured startup and exits, e.g.
+        /// "UnrealEditor Game.uproject -game -ExecCmds=quit"
+        #[arg(long)]
+        run: Option<String>,
+
+        /// Storage tier the measurement ran on: nvme, ssd, hdd, network, unknown
+        /// (detected from the project path if omitted)
+        #[arg(long)]
+        storage: Option<StorageTier>,
+
+        /// Show the fit without writing .uefast.toml
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Validate pak ordering of a packaged build against the computed load order
+    PakOrder {
+        /// Path to UE5 project root
+        #[arg(short, long)]
+        project: PathBuf,
+
+        /// Packaged build directory (searched for .pak files)
+        #[arg(short, long)]
+        build: PathBuf,
+
+        /// Output validation report
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Report format: json, ndjson, csv, html, markdown, sarif
+        #[arg(long, default_value = "json")]
+        format: OutputFormat,
+    },
+
+    /// Analyze a packaged build from its Steam depot manifest or Epic build
+    /// manifest: files, content hashes, chunking and patch download size
+    StoreManifest {
+        /// Steam depot manifest (binary or text listing) or Epic build
+        /// manifest (binary or JSON)
+        manifest: PathBuf,
+
+        /// Manifest of an earlier build of the same app; reports what
+        /// updating from it downloads
+        #[arg(long)]
+        base: Option<PathBuf>,
+
+        /// Output manifest report
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Report format: json, ndjson, csv, html, markdown, sarif
+        #[arg(long, default_value = "json")]
+        format: OutputFormat,
+    },
+
+    /// Locate and list UE projects on this machine
+    Projects {
+        #[command(subcommand)]
+        command: ProjectsCommand,
+    },
+
+    /// Import feature tags used to slice reports and budgets
+    Tags {
+        #[command(subcommand)]
+        command: TagsCommand,
+    },
+
+    /// Locate the project's engine and run its commandlets, feeding their
+    /// output back into the analysis
+    Ue {
+        #[command(subcommand)]
+        command: UeCommand,
+    },
+
+    /// Write an Unreal Python script exporting asset registry dependencies
+    /// and primary asset rules for analyze --editor-data
+    ExportEditorScript {
+        /// Script to write
+        #[arg(short, long, default_value = "uefast_export.py")]
+        output: PathBuf,
+
+        /// Where the script writes its data, relative to the project directory
+        #[arg(long, default_value = editor_data::DEFAULT_EDITOR_DATA)]
+        data: String,
+    },
+
+    /// Run custom Rhai queries and checks over the asset database and graph
+    Script {
+        #[command(subcommand)]
+        command: ScriptCommand,
+    },
+
+    /// Print the JSON Schema of a JSON output (all of them if omitted)
+    Schema {
+        /// analysis, verify, scan or cache-log
+        kind: Option<SchemaKind>,
+
+        /// Output file (stdout if omitted)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Opt-in anonymized usage metrics: command, duration, project size
+    /// bucket and error kind, never paths or names
+    Telemetry {
+        #[command(subcommand)]
+        command: TelemetryCommand,
+    },
+
+    /// Write .uefast.toml from a few questions about the project
+    Init {
+        /// Path to UE5 project root (current directory or a discovered project if omitted)
+        #[arg(short, long)]
+        project: Option<PathBuf>,
+
+        /// Accept every detected default without asking
+        #[arg(long)]
+        defaults: bool,
+    },
+
+    /// Generate a synthetic UE-like project for benchmarking and testing
+    Testgen {
+        /// Directory to create the project in (must not exist)
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Number of .uasset packages
+        #[arg(long, default_value = "1000")]
+        assets: usize,
+
+        /// Number of .umap packages
+        #[arg(long, default_value = "3")]
+        maps: usize,
+
+        /// Imports per asset
+        #[arg(long, default_value = "4")]
+        fan_out: usize,
+
+        /// Smallest asset size (e.g. 4KB)
+        #[arg(long, default_value = "4KB")]
+        min_size: String,
+
+        /// Largest asset size (e.g. 4MB); sizes are log-uniform in between
+        #[arg(long, default_value = "4MB")]
+        max_size: String,
+
+        /// Random seed; the same seed generates the same project
+        #[arg(long, default_value = "42")]
+        seed: u64,
     },
 }
 
-fn main() -> Result<()> {
-    let cli = Cli::parse();
+/// Size and age bounds shared by scan and analyze
+#[derive(Args)]
+struct FilterArgs {
+    /// Skip assets smaller than this (e.g. 10MB)
+    #[arg(long)]
+    min_size: Option<String>,
+
+    /// Skip assets larger than this (e.g. 2GB)
+    #[arg(long)]
+    max_size: Option<String>,
+
+    /// Skip assets not modified since this date (YYYY-MM-DD, RFC 3339 or e.g. 14d)
+    #[arg(long)]
+    modified_since: Option<String>,
+
+    /// Skip assets whose project-relative path matches this glob, e.g.
+    /// Content/Developers/ or **/*_Test.uasset (repeatable; adds to the
+    /// .uefast.toml exclude list)
+    #[arg(long, value_name = "GLOB")]
+    exclude: Vec<String>,
+}
+
+impl FilterArgs {
+    fn to_filter(&self) -> Result<AssetFilter> {
+        Ok(AssetFilter {
+            min_size: self.min_size.as_deref().map(parse_size).transpose()?,
+            max_size: self.max_size.as_deref().map(parse_size).transpose()?,
+            modified_since: self.modified_since.as_deref().map(parse_since).transpose()?,
+            exclude: self.exclude.clone(),
+        })
+    }
+}
+
+#[derive(Args)]
+struct DepsArgs {
+    /// Path to UE5 project root
+    #[arg(short, long)]
+    project: PathBuf,
+
+    /// Asset to start from: a project-relative path or a package name
+    /// (e.g. /Game/Textures/T_Rock)
+    #[arg(long, value_name = "ASSET")]
+    of: String,
+
+    /// List the assets that depend on it instead of its dependencies
+    #[arg(long)]
+    reverse: bool,
+
+    /// Follow hard imports only
+    #[arg(long)]
+    hard_only: bool,
+
+    /// Follow soft package references as well
+    #[arg(long, conflicts_with = "hard_only")]
+    include_soft: bool,
+
+    /// Stop this many edges away from the asset
+    #[arg(long)]
+    max_depth: Option<usize>,
+
+    /// Write the list to this file
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Report format: json, ndjson, csv, html, markdown, sarif
+    #[arg(long, default_value = "json")]
+    format: OutputFormat,
+}
+
+/// Focus, caps, filters and format of graph exports
+#[derive(Args)]
+struct GraphExportArgs {
+    /// Keep only the neighborhood of this asset, a project-relative path or
+    /// package name (repeatable)
+    #[arg(long, value_name = "ASSET")]
+    root: Vec<String>,
+
+    /// Edges to follow away from --root (all when omitted)
+    #[arg(long, requires = "root")]
+    depth: Option<usize>,
+
+    /// Which way to follow edges from --root: dependencies, dependents or both
+    #[arg(long, requires = "root", default_value = "dependencies")]
+    direction: SubgraphDirection,
+
+    /// Write at most this many nodes, startup-critical ones first
+    #[arg(long)]
+    max_nodes: Option<usize>,
+
+    /// Write at most this many edges, hard imports first
+    #[arg(long)]
+    max_edges: Option<usize>,
+
+    /// Write only assets under this project-relative folder (e.g. Content/UI)
+    #[arg(long, value_name = "FOLDER")]
+    under: Option<String>,
+
+    /// Write only assets with this extension, e.g. umap (repeatable)
+    #[arg(long = "type", value_name = "EXT")]
+    asset_type: Vec<String>,
+
+    /// Leave out soft and inferred edges
+    #[arg(long)]
+    hard_only: bool,
+
+    /// Output format: dot, graphml or json (from the --output extension
+    /// when omitted)
+    #[arg(long, value_name = "FORMAT")]
+    graph_format: Option<GraphFormat>,
+}
+
+#[derive(Args)]
+struct SimulateArgs {
+    /// Path to UE5 project root
+    #[arg(short, long)]
+    project: PathBuf,
+
+    /// Ordering to simulate: computed, alphabetical, size, type or file
+    /// (repeatable; all but file by default)
+    #[arg(long)]
+    strategy: Vec<OrderStrategy>,
+
+    /// Open order file laying out the file strategy (GameOpenOrder.txt);
+    /// implies --strategy file
+    #[arg(long, value_name = "FILE")]
+    order: Option<PathBuf>,
+
+    /// Storage tier the I/O model starts from: nvme, ssd, hdd, network,
+    /// unknown (.uefast.toml, then detected from the project path if omitted)
+    #[arg(long)]
+    storage: Option<StorageTier>,
+
+    /// Seek latency in milliseconds
+    #[arg(long)]
+    seek_ms: Option<f64>,
+
+    /// Sustained read bandwidth in MB/s
+    #[arg(long)]
+    bandwidth_mb_s: Option<f64>,
+
+    /// Requests the device overlaps
+    #[arg(long)]
+    queue_depth: Option<usize>,
+
+    /// Output simulation with the timeline of every strategy
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Report format: json, ndjson, csv, html, markdown, sarif
+    #[arg(long, default_value = "json")]
+    format: OutputFormat,
+}
+
+#[derive(Args)]
+struct CompareStrategiesArgs {
+    /// Path to UE5 project root
+    #[arg(short, long)]
+    project: PathBuf,
+
+    /// <order>[:<hash>] to compare, e.g. computed, size:blake3 (at least
+    /// two; the first is the baseline)
+    #[arg(long, required = true, num_args = 1.., value_name = "STRATEGY")]
+    strategy: Vec<StrategySpec>,
+
+    /// Storage tier the I/O model starts from: nvme, ssd, hdd, network,
+    /// unknown (.uefast.toml, then detected from the project path if omitted)
+    #[arg(long)]
+    storage: Option<StorageTier>,
+
+    /// Seek latency in milliseconds
+    #[arg(long)]
+    seek_ms: Option<f64>,
+
+    /// Sustained read bandwidth in MB/s
+    #[arg(long)]
+    bandwidth_mb_s: Option<f64>,
+
+    /// Requests the device overlaps
+    #[arg(long)]
+    queue_depth: Option<usize>,
+
+    /// Keep the built caches here instead of discarding them
+    #[arg(long, value_name = "DIR")]
+    output_dir: Option<PathBuf>,
+
+    /// Output comparison report
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Report format: json, ndjson, csv, html, markdown, sarif
+    #[arg(long, default_value = "json")]
+    format: OutputFormat,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Path to cache file
+    #[arg(short, long, required_unless_present = "cache_url", conflicts_with = "cache_url")]
+    cache: Option<PathBuf>,
+
+    /// Verify against a cache on an HTTP store, fetching only its
+    /// header and asset records with range requests
+    #[arg(long)]
+    cache_url: Option<String>,
+
+    /// Path to UE5 project root
+    #[arg(short, long)]
+    project: PathBuf,
+
+    /// Output verification result
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Report format: json, ndjson, csv, html, markdown, sarif
+    #[arg(long, default_value = "json")]
+    format: OutputFormat,
+
+    /// Expand one next-action rule (e.g. StaleCache, LfsPointer)
+    #[arg(long)]
+    explain: Option<String>,
+
+    /// Reject the cache unless it was hashed with this algorithm
+    #[arg(long)]
+    hash_algorithm: Option<HashAlgorithm>,
+}
+
+#[derive(Args)]
+struct CacheBuildArgs {
+    /// Path to UE5 project root
+    #[arg(short, long, required = true)]
+    project: Option<PathBuf>,
+
+    /// Output cache file (.uefast); defaults to .uefast.toml [output] cache
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Force rebuild even if cache exists
+    #[arg(short, long)]
+    force: bool,
+
+    /// Label stored in the cache header, e.g. build-1.2.3 (repeatable)
+    #[arg(long)]
+    tag: Vec<String>,
+
+    /// KEY=VALUE metadata stored in the cache header (repeatable)
+    #[arg(long, value_parser = parse_key_value)]
+    meta: Vec<(String, String)>,
+
+    /// Append the changes to <output>.log (always done once the log exists)
+    #[arg(long)]
+    log: bool,
+
+    /// Hashing backend: cpu, or the experimental gpu for xxh3 files of
+    /// 256 MB and more (builds with the gpu_hash feature)
+    #[arg(long, default_value = "cpu")]
+    hash_backend: HashBackend,
+
+    /// Content hash: xxh3 (fastest), blake3 (tamper resistant, for shared
+    /// caches) or crc32c (.uefast.toml hash_algorithm, then xxh3 if omitted)
+    #[arg(long)]
+    hash_algorithm: Option<HashAlgorithm>,
+
+    /// Load order arrangement: computed, alphabetical, size or type
+    /// (.uefast.toml load_order_strategy, then computed if omitted)
+    #[arg(long)]
+    load_order_strategy: Option<OrderStrategy>,
+
+    /// Hash with unbuffered reads (O_DIRECT / FILE_FLAG_NO_BUFFERING)
+    #[arg(long)]
+    direct_io: bool,
+
+    /// Content source: local, dir:<root>, pak:<file or dir>,
+    /// iostore:<.utoc or dir>, or an http(s) base URL serving manifest.json.
+    /// Repeat to layer patches and DLC over the base game; later sources
+    /// override earlier ones.
+    #[arg(long, value_name = "SPEC", conflicts_with = "direct_io")]
+    content: Vec<String>,
+}
+
+fn parse_key_value(s: &str) -> std::result::Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((key, value)) if !key.trim().is_empty() => Ok((key.trim().to_string(), value.to_string())),
+        _ => Err(format!("expected KEY=VALUE, got '{}'", s)),
+    }
+}
+
+#[derive(Subcommand)]
+enum CacheCommand {
+    /// Compare caches of the same project built on different machines
+    Compare {
+        /// Cache files (.uefast) to compare
+        #[arg(required = true, num_args = 2..)]
+        caches: Vec<PathBuf>,
+
+        /// Output comparison report
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Report format: json, ndjson, csv, html, markdown, sarif
+        #[arg(long, default_value = "json")]
+        format: OutputFormat,
+    },
+
+    /// Update an existing cache in place, rehashing only files touched
+    /// since it was written
+    Update {
+        /// Path to cache file
+        #[arg(short, long)]
+        cache: PathBuf,
+
+        /// Path to UE5 project root
+        #[arg(short, long)]
+        project: PathBuf,
+
+        /// Moved assets now always keep their load order data; accepted so
+        /// older scripts keep working
+        #[arg(long, hide = true)]
+        apply_renames: bool,
+
+        /// Append the changes to <cache>.log (always done once the log exists)
+        #[arg(long)]
+        log: bool,
+    },
+
+    /// Show the change log of a cache
+    Log {
+        /// Path to cache file
+        #[arg(short, long)]
+        cache: PathBuf,
+
+        /// Show only the most recent entries
+        #[arg(short = 'n', long)]
+        limit: Option<usize>,
+    },
+
+    /// Split a cache into per-scenario shards with a shards.json manifest
+    Shard {
+        /// Path to cache file
+        #[arg(short, long)]
+        cache: PathBuf,
+
+        /// Directory for the shards and manifest
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+
+    /// Fetch the shards a startup profile needs and merge them into one cache
+    Pull {
+        /// Shard directory or http(s):// base URL holding shards.json
+        #[arg(long)]
+        from: String,
+
+        /// Scenario to pull shards for (default: every shard)
+        #[arg(long)]
+        profile: Option<String>,
+
+        /// Output cache file (.uefast)
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum ProjectsCommand {
+    /// Discover .uproject files from editor recents, launcher manifests and roots
+    Discover {
+        /// Additional directories to search (repeatable)
+        #[arg(short, long)]
+        root: Vec<PathBuf>,
+
+        /// Only search the given roots
+        #[arg(long)]
+        roots_only: bool,
+
+        /// Output project list as JSON
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Validate a project root and show its .uproject descriptor
+    Validate {
+        /// Path to UE5 project root or .uproject file
+        #[arg(short, long)]
+        project: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum TelemetryCommand {
+    /// Start recording usage to the local usage file
+    Enable {
+        /// Endpoint `telemetry upload` posts the records to
+        #[arg(long)]
+        upload_url: Option<String>,
+    },
+
+    /// Stop recording; records already written are kept
+    Disable,
+
+    /// Show whether recording is on and summarize the records
+    Status,
+
+    /// Post the records as a JSON array, then delete them
+    Upload {
+        /// Endpoint (defaults to the one saved with `enable`)
+        #[arg(long)]
+        url: Option<String>,
+    },
+
+    /// Delete the records
+    Clear,
+}
+
+#[derive(Subcommand)]
+enum UeCommand {
+    /// Show which engine the project resolves to
+    Locate {
+        /// Path to UE5 project root
+        #[arg(short, long)]
+        project: PathBuf,
+
+        /// Engine root (defaults to UE_ENGINE_DIR, then the EngineAssociation)
+        #[arg(long)]
+        engine: Option<PathBuf>,
+    },
+
+    /// Export registry dependencies, classes and cook rules through the
+    /// editor, then analyze with them (analyze --editor-data)
+    Analyze {
+        /// Path to UE5 project root
+        #[arg(short, long)]
+        project: PathBuf,
+
+        /// Engine root (defaults to UE_ENGINE_DIR, then the EngineAssociation)
+        #[arg(long)]
+        engine: Option<PathBuf>,
+
+        /// Output analysis report
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Report format: json, ndjson, csv, html, markdown, sarif
+        #[arg(long, default_value = "json")]
+        format: OutputFormat,
+
+        /// Print the commandlet line without running it
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Resave packages saved by another engine version with ResavePackages
+    Resave {
+        /// Path to UE5 project root
+        #[arg(short, long)]
+        project: PathBuf,
+
+        /// Engine root (defaults to UE_ENGINE_DIR, then the EngineAssociation)
+        #[arg(long)]
+        engine: Option<PathBuf>,
+
+        /// Write the package list and print the commandlet line only
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Run any commandlet (e.g. DumpAssetRegistry) headless with a captured log
+    Run {
+        /// Commandlet name, as passed to -run=
+        commandlet: String,
+
+        /// Path to UE5 project root
+        #[arg(short, long)]
+        project: PathBuf,
+
+        /// Engine root (defaults to UE_ENGINE_DIR, then the EngineAssociation)
+        #[arg(long)]
+        engine: Option<PathBuf>,
+
+        /// Print the commandlet line without running it
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Extra commandlet arguments
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum TagsCommand {
+    /// Replace the project's tags with those of its collections and CSVs
+    Import {
+        /// Path to UE5 project root
+        #[arg(short, long)]
+        project: PathBuf,
+
+        /// CSV with a package/path column and a tag column (repeatable)
+        #[arg(long)]
+        csv: Vec<PathBuf>,
+
+        /// Skip Content/Collections and Saved/Collections
+        #[arg(long)]
+        no_collections: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum ScriptCommand {
+    /// Run a .rhai script; fails when it reports an error finding
+    Run {
+        /// Script file
+        script: PathBuf,
+
+        /// Path to UE5 project root
+        #[arg(short, long)]
+        project: PathBuf,
+
+        /// Write the script's findings as a report
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Report format: json, ndjson, csv, html, markdown, sarif
+        #[arg(long, default_value = "json")]
+        format: OutputFormat,
+
+        /// Arguments exposed to the script as ARGS
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+}
+
+#[derive(Args)]
+struct AnalyzeArgs {
+    /// Path to UE5 project root
+    #[arg(short, long, required_unless_present = "projects")]
+    project: Option<PathBuf>,
+
+    /// File listing project roots, one per line; analyzes them concurrently
+    /// into one comparative report with content shared across projects
+    #[arg(
+        long,
+        value_name = "LIST",
+        conflicts_with_all = ["project", "sample", "record", "resave_list", "explain", "content", "base"]
+    )]
+    projects: Option<PathBuf>,
+
+    /// With --projects, also compare each project's content plugins
+    #[arg(long, requires = "projects")]
+    plugins: bool,
+
+    /// Output analysis report
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Report format: json, ndjson, csv, html, markdown, sarif
+    #[arg(long, default_value = "json")]
+    format: OutputFormat,
+
+    /// Include shader analysis
+    #[arg(long)]
+    shaders: bool,
+
+    /// Scan C++ Source folders for LoadModule calls and StartupModule hotspots
+    #[arg(long)]
+    source: bool,
+
+    /// Analyze only this fraction of assets (e.g. 0.1) and extrapolate totals
+    #[arg(long)]
+    sample: Option<f64>,
+
+    /// Seed for reproducible sampling
+    #[arg(long, default_value_t = ue5_fast_startup::sampling::DEFAULT_SAMPLE_SEED)]
+    seed: u64,
+
+    /// Save the report to the project history and compare with the previous run
+    #[arg(long)]
+    record: bool,
+
+    /// Post regressions to the webhook configured in .uefast.toml
+    #[arg(long)]
+    notify: bool,
+
+    /// Write outdated packages as a ResavePackages -PackageList file
+    #[arg(long)]
+    resave_list: Option<PathBuf>,
+
+    /// Expand one next-action rule (e.g. Preload, StaleImport) with every finding
+    #[arg(long)]
+    explain: Option<String>,
+
+    /// Editor data written by the export-editor-script script: its registry
+    /// dependencies and asset classes replace package parsing for the
+    /// packages it covers, and primary asset cook rules are checked
+    #[arg(long, value_name = "FILE", conflicts_with = "projects")]
+    editor_data: Option<PathBuf>,
+
+    /// Estimate what opening this asset in the editor loads (its hard
+    /// dependency closure) instead of the startup; a path or /Game/ package
+    /// name, repeatable
+    #[arg(long, value_name = "ASSET", conflicts_with_all = ["projects", "sample", "base", "record", "resave_list"])]
+    editor_open: Vec<String>,
+
+    /// Replace content paths in the written report with stable pseudonyms,
+    /// keeping the mapping in .uefast/redaction.json
+    #[arg(long, requires = "output", conflicts_with_all = ["projects", "base"])]
+    redact: bool,
+
+    /// Report language: en, es (defaults to .uefast.toml, then the system locale)
+    #[arg(long)]
+    lang: Option<Locale>,
+
+    /// Content source: local, dir:<root>, pak:<file or dir>,
+    /// iostore:<.utoc or dir>, or an http(s) base URL serving manifest.json.
+    /// Repeat to layer patches and DLC over the base game; later sources
+    /// override earlier ones.
+    #[arg(long, value_name = "SPEC")]
+    content: Vec<String>,
+
+    /// Base build content (a directory, .pak files or a --content spec);
+    /// reports what --patch changes in the startup set
+    #[arg(long, value_name = "SPEC", requires = "patch", conflicts_with_all = ["content", "sample"])]
+    base: Option<String>,
+
+    /// Patch or DLC content layered over --base
+    #[arg(long, value_name = "SPEC", requires = "base")]
+    patch: Option<String>,
+
+    #[command(flatten)]
+    bounds: FilterArgs,
+}
+
+fn main() -> Result<()> {
+    let matches = Cli::command().get_matches();
+    let cli = Cli::from_arg_matches(&matches)?;
+
+    let style = term::init(OutputStyle::detect(cli.ascii, cli.color));
+
+    // Setup logging
+    let level = if cli.verbose { Level::DEBUG } else { Level::INFO };
+    // Structured results own stdout, so the log moves to stderr
+    let writer = if cli.print.is_structured() {
+        BoxMakeWriter::new(std::io::stderr)
+    } else {
+        BoxMakeWriter::new(std::io::stdout)
+    };
+    let subscriber = FmtSubscriber::builder()
+        .with_max_level(level)
+        .with_target(false)
+        .with_ansi(style.color)
+        .with_writer(writer)
+        .finish();
+    tracing::subscriber::set_global_default(subscriber)?;
+
+    // Priority and affinity go first so every worker thread inherits them
+    if let Some(nice) = cli.nice {
+        priority::set_nice(nice)?;
+    }
+    let pinned = match &cli.affinity {
+        Some(list) => {
+            let cpus = priority::parse_cpu_list(list)?;
+            priority::set_affinity(&cpus)?;
+            cpus.len()
+        }
+        None => 0,
+    };
+
+    // Settings of the command's project that apply before it runs
+    let config = match project_arg(&matches) {
+        Some(project) => ProjectConfig::load(&project)?,
+        None => ProjectConfig::default(),
+    };
+
+    // Configure thread pool; pinned runs default to one thread per CPU
+    let threads = if cli.threads > 0 { cli.threads } else { config.threads.unwrap_or(pinned) };
+    if threads > 0 {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()?;
+    }
+
+    info!("UE5 Fast Startup Accelerator v0.1.0");
+
+    // Usage records (opt-in) name the subcommand path only
+    let command = command_path(&matches);
+    if !command.starts_with("telemetry") {
+        usage::begin(&command);
+        usage::install_panic_hook();
+    }
+    let result = run(cli.command, cli.print);
+    let outcome = match &result {
+        Ok(()) => "ok",
+        Err(e) => e.downcast_ref::<FastStartupError>().map(|e| e.category()).unwrap_or("other"),
+    };
+    if let Err(e) = usage::finish(outcome) {
+        debug!("Usage record not written: {}", e);
+    }
+    result
+}
+
+/// `--project` of the invoked subcommand, if it takes one
+fn project_arg(matches: &clap::ArgMatches) -> Option<PathBuf> {
+    let mut project = None;
+    let mut current = matches;
+    loop {
+        if let Ok(Some(path)) = current.try_get_one::<PathBuf>("project") {
+            project = Some(path.clone());
+        }
+        match current.subcommand() {
+            Some((_, sub)) => current = sub,
+            None => return project,
+        }
+    }
+}
+
+/// `analyze`, `cache compare`, ...
+fn command_path(matches: &clap::ArgMatches) -> String {
+    let mut names = Vec::new();
+    let mut current = matches;
+    while let Some((name, sub)) = current.subcommand() {
+        names.push(name);
+        current = sub;
+    }
+    names.join(" ")
+}
+
+fn run(command: Commands, print: PrintFormat) -> Result<()> {
+    match command {
+        Commands::Analyze(args) => {
+            cmd_analyze(args)
+        }
+        Commands::Optimize { project, output, shaders } => {
+            cmd_optimize(project, output, shaders)
+        }
+        Commands::Check {
+            project,
+            baseline,
+            max_startup_mb,
+            max_startup_assets,
+            max_growth_mb,
+            max_tag_mb,
+            output,
+            format,
+            notify,
+        } => {
+            let max_startup_bytes_by_tag = max_tag_mb
+                .into_iter()
+                .map(|(tag, mb)| match mb.parse::<f64>() {
+                    Ok(mb) => Ok((tag, (mb * MB) as u64)),
+                    Err(_) => Err(anyhow::anyhow!("invalid --max-tag-mb size '{}' for {}", mb, tag)),
+                })
+                .collect::<Result<_>>()?;
+            let budgets = Budgets {
+                max_startup_bytes: max_startup_mb.map(|mb| (mb * MB) as u64),
+                max_startup_assets,
+                max_startup_growth_bytes: max_growth_mb.map(|mb| (mb * MB) as u64),
+                max_startup_bytes_by_tag,
+            };
+            cmd_check(project, baseline, budgets, output, format, notify)
+        }
+        Commands::Scan {
+            project,
+            output,
+            format,
+            filter,
+            bounds,
+            content,
+            audit_access,
+            audit_output,
+            index,
+            reindex,
+        } => {
+            let config = ProjectConfig::load(&project)?;
+            let scanner = if content.is_empty() {
+                if reindex {
+                    ScanIndex::remove(&project)?;
+                }
+                let scanner = AssetScanner::new(&project)?;
+                let indexed = scanner.uses_index() || index || reindex;
+                scanner.with_index(indexed)
+            } else {
+                AssetScanner::from_provider(&project, open_layers(&content, &project)?)?
+            };
+            cmd_scan(scanner.with_config(&config).with_filter(bounds.to_filter()?), output, format, filter, audit_access, audit_output)
+        }
+        Commands::Cache { command, build } => match command {
+            Some(CacheCommand::Compare { caches, output, format }) => {
+                cmd_cache_compare(caches, output, format)
+            }
+            Some(CacheCommand::Update { cache, project, log, .. }) => cmd_cache_update(cache, project, log),
+            Some(CacheCommand::Log { cache, limit }) => cmd_cache_log(cache, limit),
+            Some(CacheCommand::Shard { cache, output }) => cmd_cache_shard(cache, output),
+            Some(CacheCommand::Pull { from, profile, output }) => cmd_cache_pull(from, profile, output),
+            None => {
+                match build.project.clone() {
+                    Some(project) => cmd_cache(project, build.output.clone(), build),
+                    None => anyhow::bail!("cache requires --project"),
+                }
+            }
+        },
+        Commands::Verify { args } => cmd_verify(args, print),
+        Commands::Stats { cache } => {
+            cmd_stats(cache, print)
+        }
+        Commands::Graph { project, output, startup_only, include_soft, editor_data, export } => {
+            cmd_graph(project, output, startup_only, include_soft, editor_data, export, print)
+        }
+        Commands::Deps { args } => cmd_deps(args, print),
+        Commands::Attribution { project, output } => {
+            cmd_attribution(project, output)
+        }
+        Commands::PredictCook { project, changes, output, format, editor_data } => {
+            cmd_predict_cook(project, changes, output, format, editor_data)
+        }
+        Commands::Simulate(args) => cmd_simulate(args),
+        Commands::CompareStrategies(args) => cmd_compare_strategies(args),
+        Commands::Watch { project, cache, debounce_ms, log } => {
+            cmd_watch(project, cache, debounce_ms, log)
+        }
+        Commands::Warmup { project, status, storage, output, format } => {
+            cmd_warmup(project, status, storage, output, format)
+        }
+        Commands::Crashes { project, cache, crashes, startup_seconds, window_hours, output, format } => {
+            cmd_crashes(project, cache, crashes, startup_seconds, window_hours, output, format)
+        }
+        Commands::Bench { project, iterations, direct_io, cold } => {
+            cmd_bench(project, iterations, direct_io, cold, print)
+        }
+        Commands::Turbo { project, output, strategy } => {
+            cmd_turbo(project, output, strategy)
+        }
+        Commands::QuickVerify { cache, project, since_last } => {
+            cmd_quick_verify(cache, project, since_last, print)
+        }
+        Commands::Calibrate { project, log, insights_csv, run, storage, dry_run } => {
+            cmd_calibrate(project, log, insights_csv, run, storage, dry_run)
+        }
+        Commands::PakOrder { project, build, output, format } => {
+            cmd_pak_order(project, build, output, format)
+        }
+        Commands::StoreManifest { manifest, base, output, format } => {
+            cmd_store_manifest(manifest, base, output, format)
+        }
+        Commands::Projects { command } => match command {
+            ProjectsCommand::Discover { root, roots_only, output } => {
+                cmd_projects_discover(root, roots_only, output)
+            }
+            ProjectsCommand::Validate { project } => cmd_projects_validate(project),
+        },
+        Commands::Schema { kind, output } => cmd_schema(kind, output),
+        Commands::Telemetry { command } => cmd_telemetry(command),
+        Commands::Init { project, defaults } => cmd_init(project, defaults),
+        Commands::Tags { command } => match command {
+            TagsCommand::Import { project, csv, no_collections } => {
+                cmd_tags_import(project, csv, no_collections)
+            }
+        },
+        Commands::Ue { command } => match command {
+            UeCommand::Locate { project, engine } => cmd_ue_locate(project, engine),
+            UeCommand::Analyze { project, engine, output, format, dry_run } => {
+                cmd_ue_analyze(project, engine, output, format, dry_run)
+            }
+            UeCommand::Resave { project, engine, dry_run } => cmd_ue_resave(project, engine, dry_run),
+            UeCommand::Run { commandlet, project, engine, dry_run, args } => {
+                cmd_ue_run(commandlet, project, engine, dry_run, args)
+            }
+        },
+        Commands::ExportEditorScript { output, data } => cmd_export_editor_script(output, data),
+        Commands::Script { command } => match command {
+            ScriptCommand::Run { script, project, output, format, args } => {
+                cmd_script_run(script, project, output, format, args)
+            }
+        },
+        Commands::Testgen { output, assets, maps, fan_out, min_size, max_size, seed } => {
+            let spec = TestProjectSpec {
+                asset_count: assets,
+                map_count: maps,
+                fan_out,
+                min_size: parse_size(&min_size)?,
+                max_size: parse_size(&max_size)?,
+                seed,
+            };
+            cmd_testgen(output, spec)
+        }
+    }
+}
+
+fn cmd_analyze(args: AnalyzeArgs) -> Result<()> {
+    let AnalyzeArgs {
+        project,
+        projects,
+        plugins,
+        output,
+        format,
+        shaders,
+        source,
+        sample,
+        seed,
+        record,
+        notify,
+        resave_list,
+        explain,
+        editor_data,
+        editor_open,
+        redact,
+        lang,
+        content,
+        base,
+        patch,
+        bounds,
+    } = args;
+    let filter = bounds.to_filter()?;
+    if let Some(list) = projects {
+        return cmd_analyze_projects(&list, plugins, filter, lang, shaders, output, format);
+    }
+    let project = project.expect("clap requires --project without --projects");
+    info!("Analyzing project: {}", project.display());
+    let output = match output {
+        Some(output) => Some(output),
+        None => ProjectConfig::load(&project)?.output.report_path(&project),
+    };
+    if let Some(parent) = output.as_deref().and_then(Path::parent) {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut analyzer = project_analyzer(&project, filter, lang)?.with_source_scan(source);
+    if let Some(fraction) = sample {
+        analyzer = analyzer.with_sample(AssetSample::new(fraction, seed)?);
+    }
+    if !content.is_empty() {
+        analyzer = analyzer.with_content(open_layers(&content, &project)?);
+    }
+    if let Some(path) = editor_data {
+        analyzer = analyzer.with_editor_data(load_editor_data(&project, path)?);
+    }
+    if let (Some(base), Some(patch)) = (base, patch) {
+        return cmd_patch_diff(&analyzer, &project, &base, &patch, shaders, output, format);
+    }
+    if !editor_open.is_empty() {
+        return cmd_editor_open(&analyzer, &editor_open, output, format);
+    }
+    let report = analyzer.analyze(shaders)?;
+
+    info!("Analysis complete:");
+    info!("  Total assets: {}", report.total_assets);
+    info!("  Startup assets: {}", report.startup_assets);
+    let e = &report.startup_closure_size_bytes;
+    info!(
+        "  Startup closure: ~{:.1} MB [{:.1} - {:.1}]",
+        e.value / MB, e.lower / MB, e.upper / MB
+    );
+    let memory = &report.resident_memory;
+    info!(
+        "  Resident memory: ~{:.1} MB ({} assets priced from registry tags)",
+        memory.total_bytes as f64 / MB,
+        memory.from_metadata()
+    );
+    info!("  Estimated savings: {:.1}s", report.estimated_savings_seconds);
+    for scenario in &report.scenarios {
+        let e = &scenario.startup_size_estimate;
+        info!(
+            "  Scenario {}: {} assets, ~{:.1} MB [{:.1} - {:.1}]",
+            scenario.name, scenario.startup_assets, e.value / MB, e.lower / MB, e.upper / MB
+        );
+    }
+    if !report.shared_subtrees.is_empty() {
+        info!("  Shared startup subtrees: {}", report.shared_subtrees.len());
+    }
+    if !report.late_chunks.is_empty() {
+        info!("  Startup assets in chunks after 0: {}", report.late_chunks.len());
+    }
+    if let Some(matrix) = &report.version_matrix {
+        info!(
+            "  Engine {}: {} older / {} newer / {} unversioned packages",
+            matrix.engine_association,
+            matrix.older.len(),
+            matrix.newer.len(),
+            matrix.unversioned_assets
+        );
+    }
+    let coverage = &report.parser_coverage;
+    if coverage.editor_packages > 0 {
+        info!(
+            "  From editor data: {} packages, {} edges ({} packages parsed)",
+            coverage.editor_packages, coverage.registry_edges, coverage.parsed
+        );
+    }
+    for asset in report.never_cooked.iter().take(5) {
+        warn!(
+            "{} {} is in the startup closure but never cooked for shipping ({})",
+            glyph(Glyph::Warn),
+            asset.relative_path,
+            asset.rule
+        );
+    }
+    for asset in report.late_chunks.iter().take(5) {
+        warn!(
+            "{} {} is in the startup closure but assigned to chunk {} ({})",
+            glyph(Glyph::Warn),
+            asset.relative_path,
+            asset.chunk_id,
+            asset.source
+        );
+    }
+    if !report.config_preloads.is_empty() {
+        let forced: usize = report.startup_asset_list.iter().filter(|a| a.preloaded_by.is_some()).count();
+        info!("  Config preloads: {} entries forcing {} startup assets", report.config_preloads.len(), forced);
+        for preload in report.config_preloads.iter().filter(|p| p.assets.is_empty()) {
+            warn!("{} {} names {}, which matches no asset", glyph(Glyph::Warn), preload.source, preload.path);
+        }
+    }
+    if let Some(scan) = &report.source {
+        info!(
+            "  C++ sources: {} files, {} StartupModule implementations ({} doing blocking work), {} module loads",
+            scan.files_scanned,
+            scan.startup_modules.len(),
+            scan.hotspots().count(),
+            scan.module_loads.len()
+        );
+    }
+    if let Some(sample) = &report.sample {
+        info!("Extrapolated from {:.1}% sample (95% CI):", sample.fraction * 100.0);
+        let e = &sample.estimated_total_assets;
+        info!("  Total assets: ~{:.0} [{:.0} - {:.0}]", e.value, e.lower, e.upper);
+        let e = &sample.estimated_startup_assets;
+        info!("  Startup assets: ~{:.0} [{:.0} - {:.0}]", e.value, e.lower, e.upper);
+        let e = &sample.estimated_startup_size_bytes;
+        info!(
+            "  Startup size: ~{:.1} MB [{:.1} - {:.1}]",
+            e.value / MB, e.lower / MB, e.upper / MB
+        );
+    }
+
+    if let Some(output_path) = output {
+        if redact {
+            let mut redactor = Redactor::load(&project);
+            write_report(&redactor.redact_report(&report)?, format, &output_path)?;
+            let mapping = redactor.save(&project)?;
+            info!("Redacted report saved to: {} ({})", output_path.display(), format);
+            info!("  Pseudonym mapping (keep private): {} ({} names)", mapping.display(), redactor.len());
+        } else {
+            write_report(&report, format, &output_path)?;
+            info!("Report saved to: {} ({})", output_path.display(), format);
+        }
+    }
+
+    if let Some(list_path) = resave_list {
+        match &report.version_matrix {
+            Some(matrix) => {
+                let count = matrix.write_resave_list(&list_path)?;
+                info!("Resave list ({} packages) saved to: {}", count, list_path.display());
+                info!(
+                    "  Run: UnrealEditor-Cmd <project>.uproject -run=ResavePackages -PackageList={}",
+                    list_path.display()
+                );
+            }
+            None => info!("No .uproject engine association; cannot build resave list"),
+        }
+    }
+
+    if record {
+        let previous = latest_recorded(&project)?;
+        let path = record_report(&project, &report)?;
+        info!("Recorded to history: {}", path.display());
+
+        if notify {
+            let check = CheckReport::evaluate(&report, previous.as_ref(), &Budgets::default());
+            let mut notification = Notification::from_check(&check);
+            notification.status = "regression".to_string();
+            send_notification(&project, &notification, false)?;
+        }
+    } else if notify {
+        info!("--notify requires --record to compare against the previous run");
+    }
+
+    print_next_steps(&next_steps::from_report(&report), explain.as_deref(), &report.recommendations);
+    Ok(())
+}
+
+/// Analyzer configured from the project's `.uefast.toml` and hardware
+fn project_analyzer(
+    project: &Path,
+    filter: AssetFilter,
+    lang: Option<Locale>,
+) -> ue5_fast_startup::Result<StartupAnalyzer> {
+    let config = ProjectConfig::load(project)?;
+    let hardware = config.hardware_profile(project);
+    info!("Hardware: {}", hardware.describe());
+    let rule_packs = rule_packs::load_all(project, &config.rule_packs)?;
+    Ok(StartupAnalyzer::new(project)?
+        .with_rule_packs(rule_packs)
+        .with_model(config.model_for_profile(&hardware))
+        .with_hardware(hardware)
+        .with_filter(filter)
+        .with_locale(config.locale(lang))
+        .with_config(&config)
+        .with_scenarios(config.scenarios))
+}
+
+/// Editor data at `path`; relative paths missing from the working
+/// directory are those the export script reports, under the project
+fn load_editor_data(project: &Path, path: PathBuf) -> ue5_fast_startup::Result<EditorData> {
+    let path = if path.is_relative() && !path.exists() { project.join(path) } else { path };
+    let data = EditorData::load(&path)?;
+    info!(
+        "Editor data: {} packages, engine {}, exported {}",
+        data.packages.len(),
+        if data.engine_version.is_empty() { "?" } else { &data.engine_version },
+        if data.exported_at.is_empty() { "?" } else { &data.exported_at }
+    );
+    Ok(data)
+}
+
+fn cmd_analyze_projects(
+    list: &Path,
+    plugins: bool,
+    filter: AssetFilter,
+    lang: Option<Locale>,
+    shaders: bool,
+    output: Option<PathBuf>,
+    format: OutputFormat,
+) -> Result<()> {
+    let mut roots = portfolio::read_project_list(list)?;
+    if plugins {
+        roots = portfolio::with_plugins(&roots);
+    }
+    let report = PortfolioReport::analyze(&roots, filter.clone(), shaders, |root| project_analyzer(root, filter.clone(), lang));
+
+    info!("Multi-project analysis complete:");
+    for project in &report.projects {
+        match &project.error {
+            Some(e) => warn!("{} {}: {}", glyph(Glyph::Warn), project.project_name, e),
+            None => info!(
+                "  {}: {} assets, {} startup ({:.1} MB)",
+                project.project_name,
+                project.total_assets,
+                project.startup_assets,
+                project.startup_size_bytes as f64 / MB
+            ),
+        }
+    }
+    info!(
+        "  Shared across projects: {} files, {:.1} MB redundant",
+        report.shared.len(),
+        report.redundant_bytes() as f64 / MB
+    );
+    for overlap in report.plugin_candidates() {
+        warn!(
+            "{} {} and {} share {} files ({:.1} MB); move {} into a shared plugin",
+            glyph(Glyph::Warn),
+            overlap.first,
+            overlap.second,
+            overlap.shared_files,
+            overlap.shared_bytes as f64 / MB,
+            overlap.folder.as_deref().unwrap_or("them")
+        );
+    }
+
+    if let Some(output_path) = output {
+        write_report(&report, format, &output_path)?;
+        info!("Report saved to: {} ({})", output_path.display(), format);
+    }
+    if report.failed_projects() > 0 {
+        anyhow::bail!("{} of {} projects failed to analyze", report.failed_projects(), report.projects.len());
+    }
+    Ok(())
+}
+
+fn cmd_editor_open(
+    analyzer: &StartupAnalyzer,
+    targets: &[String],
+    output: Option<PathBuf>,
+    format: OutputFormat,
+) -> Result<()> {
+    let report = analyzer.editor_open(targets)?;
+
+    for opened in &report.opened {
+        info!(
+            "Opening {}: {} assets, {:.1} MB, ~{:.2}s",
+            opened.relative_path,
+            opened.loaded_assets,
+            opened.loaded_bytes as f64 / MB,
+            opened.estimated_seconds
+        );
+        for dependency in opened.heaviest.iter().take(5) {
+            info!("    {} ({:.1} MB)", dependency.relative_path, dependency.size_bytes as f64 / MB);
+        }
+    }
+    for target in &report.unmatched {
+        warn!("{} {} matches no package", glyph(Glyph::Warn), target);
+    }
+
+    if let Some(output_path) = output {
+        write_report(&report, format, &output_path)?;
+        info!("Report saved to: {} ({})", output_path.display(), format);
+    }
+    Ok(())
+}
+
+fn cmd_patch_diff(
+    analyzer: &StartupAnalyzer,
+    project: &Path,
+    base: &str,
+    patch: &str,
+    shaders: bool,
+    output: Option<PathBuf>,
+    format: OutputFormat,
+) -> Result<()> {
+    info!("Comparing {} with {} layered on top", base, patch);
+    let diff = patch_diff::compare(analyzer, project, base, patch, shaders)?;
+
+    info!("Patch startup diff:");
+    info!("  Startup assets: {} -> {}", diff.base_startup_assets, diff.patched_startup_assets);
+    info!(
+        "  Startup size: {:.1} -> {:.1} MB ({:+.1} MB)",
+        diff.base_startup_size_bytes as f64 / MB,
+        diff.patched_startup_size_bytes as f64 / MB,
+        diff.startup_growth_bytes() as f64 / MB
+    );
+    info!("  Overridden startup assets: {}", diff.overridden.len());
+    for asset in diff.overridden.iter().take(10) {
+        info!("    {}", asset.relative_path);
+    }
+    info!("  Added startup assets: {}", diff.added.len());
+    info!("  Removed startup assets: {}", diff.removed.len());
+    if diff.layer_switches > 0 {
+        warn!(
+            "{} Startup reads switch between base and patch {} times",
+            glyph(Glyph::Warn),
+            diff.layer_switches
+        );
+    }
+
+    if let Some(output_path) = output {
+        write_report(&diff, format, &output_path)?;
+        info!("Report saved to: {} ({})", output_path.display(), format);
+    }
+    Ok(())
+}
+
+/// Ranked exit summary, or the explanation of one rule
+fn print_next_steps(actions: &[next_steps::NextAction], explain: Option<&str>, recommendations: &[Recommendation]) {
+    if let Some(rule) = explain {
+        match next_steps::explain(rule, recommendations) {
+            Some(lines) => lines.iter().for_each(|line| info!("{}", line)),
+            None => warn!(
+                "Unknown rule '{}'; known rules: {}",
+                rule,
+                next_steps::rule_names().collect::<Vec<_>>().join(", ")
+            ),
+        }
+        return;
+    }
+    if actions.is_empty() {
+        return;
+    }
+
+    info!("Next actions:");
+    // High priority goes out as a warning so colored logs flag it
+    for (action, line) in actions.iter().zip(next_steps::render(actions, next_steps::MAX_NEXT_ACTIONS)) {
+        let label = format!("{:?}", action.priority).to_uppercase();
+        match action.priority {
+            Priority::High => warn!("  {:<6} {}", label, line),
+            _ => info!("  {:<6} {}", label, line),
+        }
+    }
+    if let Some(first) = actions.first() {
+        info!("  (details: --explain {})", first.rule);
+    }
+}
+
+fn cmd_optimize(project: PathBuf, output: Option<PathBuf>, shaders: bool) -> Result<()> {
+    const STEPS: [&str; 6] = ["scan", "graph", "analyze", "cache", "load order", "ini hints"];
+
+    info!("Optimizing project: {}", project.display());
+    let output_dir = output.unwrap_or_else(|| project.join(ue5_fast_startup::STATE_DIR).join("optimize"));
+    std::fs::create_dir_all(&output_dir)?;
+
+    let config = ProjectConfig::load(&project)?;
+    let hardware = config.hardware_profile(&project);
+    let progress = ProgressBar::new(STEPS.len() as u64);
+    progress.set_style(
+        ProgressStyle::with_template("{spinner} [{bar:24}] {pos}/{len} {msg}")?
+            .progress_chars("=> ")
+            .tick_chars(term::style().spinner()),
+    );
+    progress.enable_steady_tick(std::time::Duration::from_millis(120));
+    let step = |i: usize| {
+        progress.set_position(i as u64);
+        progress.set_message(STEPS[i]);
+    };
+
+    // One scan and one graph feed every later step
+    step(0);
+    let scanner = AssetScanner::new(&project)?.with_config(&config);
+    let assets = scanner.scan_all(None)?;
+
+    step(1);
+    let mut graph = DependencyGraph::build_from_assets(&project, &assets)?;
+    graph.compute_load_order();
+
+    step(2);
+    let report = StartupAnalyzer::new(&project)?
+        .with_model(config.model_for_profile(&hardware))
+        .with_hardware(hardware)
+        .with_locale(config.locale(None))
+        .with_config(&config)
+        .with_scenarios(config.scenarios.clone())
+        .analyze_scanned(&scanner, assets.clone(), &graph, shaders)?;
+    let report_path = output_dir.join("analysis.json");
+    write_report(&report, OutputFormat::Json, &report_path)?;
+
+    step(3);
+    let cache_path = output_dir.join("startup.uefast");
+    let (mut cache, _) = CacheBuilder::new(&project)?
+        .with_config(&config)
+        .with_scenarios(config.scenarios)
+        .build_from(&assets, &graph)?;
+    cache.save(&cache_path)?;
+
+    step(4);
+    let order_path = output_dir.join(OPEN_ORDER_FILE);
+    let load_order = graph.relative_load_order(&project);
+    std::fs::write(&order_path, open_order_file(&report.project_name, &load_order))?;
+
+    step(5);
+    let hints = ini_hints::suggest(&report);
+    let hints_path = output_dir.join("IniHints.ini");
+    std::fs::write(&hints_path, ini_hints::render(&hints))?;
+
+    progress.finish_and_clear();
+    info!("{} Optimization complete:", glyph(Glyph::Ok));
+    info!("  Startup assets: {}", report.startup_assets);
+    info!("  Estimated savings: {:.1}s", report.estimated_savings_seconds);
+    info!("  Report: {}", report_path.display());
+    info!("  Cache: {} ({} assets)", cache_path.display(), cache.asset_count());
+    info!("  Open order: {} ({} entries)", order_path.display(), load_order.len());
+    info!("    Copy to Build/<Platform>/FileOpenOrder/ before packaging");
+    info!("  Ini hints: {} ({} settings)", hints_path.display(), hints.len());
+    Ok(())
+}
+
+fn cmd_check(
+    project: PathBuf,
+    baseline: Option<PathBuf>,
+    budgets: Budgets,
+    output: Option<PathBuf>,
+    format: OutputFormat,
+    notify: bool,
+) -> Result<()> {
+    info!("Checking startup budgets: {}", project.display());
+
+    let config = ProjectConfig::load(&project)?;
+    let budgets = config.budgets.overridden_by(budgets);
+    let baseline = baseline.as_deref().map(load_baseline).transpose()?;
+    let report = StartupAnalyzer::new(&project)?
+        .with_config(&config)
+        .with_scenarios(config.scenarios)
+        .analyze(false)?;
+    let check = CheckReport::evaluate(&report, baseline.as_ref(), &budgets);
+
+    match output {
+        Some(output_path) => {
+            write_report(&check, format, &output_path)?;
+            info!("Check summary saved to: {} ({})", output_path.display(), format);
+        }
+        None => {
+            let mut stdout = std::io::stdout().lock();
+            format.reporter().write(&check, &mut stdout)?;
+        }
+    }
+
+    if notify {
+        let notification = Notification::from_check(&check);
+        send_notification(&project, &notification, !check.passed)?;
+    }
+
+    if check.passed {
+        info!("{} All startup budgets met", glyph(Glyph::Ok));
+        Ok(())
+    } else {
+        info!("{} Startup budget exceeded", glyph(Glyph::Fail));
+        std::process::exit(1);
+    }
+}
+
+fn send_notification(project: &Path, notification: &Notification, breached: bool) -> Result<()> {
+    let config = ProjectConfig::load(project)?;
+    let Some(notify) = config.notify else {
+        info!("No [notify] section in {}, skipping notification", ProjectConfig::path_for(project).display());
+        return Ok(());
+    };
+
+    if notification.should_send(&notify, breached) {
+        webhook::send(&notify, notification)?;
+    }
+    Ok(())
+}
+
+fn cmd_scan(
+    scanner: AssetScanner,
+    output: Option<PathBuf>,
+    format: OutputFormat,
+    filter: Option<String>,
+    audit_access: bool,
+    audit_output: Option<PathBuf>,
+) -> Result<()> {
+    let project = scanner.project_root().to_path_buf();
+    info!("Scanning project: {}", project.display());
+
+    let start = std::time::Instant::now();
+    let assets = scanner.scan_all(filter.as_deref())?;
+    let elapsed_ms = start.elapsed().as_millis() as u64;
+
+    info!("Found {} assets in {}ms", assets.len(), elapsed_ms);
+
+    let collisions = find_case_collisions(assets.iter().map(|a| a.relative_path.as_str()));
+    for collision in &collisions {
+        warn!("Paths differ only by case: {}", collision.paths.join(", "));
+    }
+
+    if let Some(output_path) = output {
+        write_report(&ScanReport::from_assets(&assets, elapsed_ms), format, &output_path)?;
+        info!("Asset list saved to: {} ({})", output_path.display(), format);
+    }
+
+    if audit_access {
+        let audit = AccessAudit::run(&project);
+        if audit.is_clean() {
+            info!("{} All {} files are readable", glyph(Glyph::Ok), audit.files_checked);
+        } else {
+            warn!("{} {} of {} files could not be read", glyph(Glyph::Fail), audit.failures.len(), audit.files_checked);
+            for (directory, summary) in &audit.by_directory {
+                warn!("  {}: {} files", directory, summary.failures);
+                for (error, count) in &summary.errors {
+                    warn!("    {} x {}", count, error);
+                }
+            }
+        }
+
+        if let Some(audit_path) = audit_output {
+            write_report(&audit, format, &audit_path)?;
+            info!("Access audit saved to: {} ({})", audit_path.display(), format);
+        }
+    }
+
+    Ok(())
+}
+
+fn cmd_cache(project: PathBuf, output: Option<PathBuf>, args: CacheBuildArgs) -> Result<()> {
+    info!("Building cache for: {}", project.display());
+
+    let config = ProjectConfig::load(&project)?;
+    let Some(output) = output.or_else(|| config.output.cache_path(&project)) else {
+        anyhow::bail!("cache requires --output or an [output] cache path in {}", CONFIG_FILE_NAME);
+    };
+    if let Some(parent) = output.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    if output.exists() && !args.force {
+        info!("Cache already exists. Use --force to rebuild.");
+        return Ok(());
+    }
+
+    let log = args.log || cache_log::log_path(&output).exists();
+    let previous = if log && output.exists() {
+        StartupCache::load(&output).ok()
+    } else {
+        None
+    };
+
+    let load_order_strategy = args
+        .load_order_strategy
+        .or(config.load_order_strategy)
+        .unwrap_or(OrderStrategy::Computed);
+    if load_order_strategy == OrderStrategy::File {
+        anyhow::bail!("The file load order strategy needs an order file; compare it with `simulate --order`");
+    }
+    let mut builder = CacheBuilder::new(&project)?
+        .with_config(&config)
+        .with_scenarios(config.scenarios)
+        .with_tags(args.tag)
+        .with_metadata(args.meta.into_iter().collect())
+        .with_hash_backend(args.hash_backend)
+        .with_hash_algorithm(args.hash_algorithm.or(config.hash_algorithm).unwrap_or_default())
+        .with_load_order_strategy(load_order_strategy)
+        .with_direct_io(args.direct_io);
+    if !args.content.is_empty() {
+        builder = builder.with_content(open_layers(&args.content, &project)?);
+    }
+    let (mut cache, metrics) = builder.build_instrumented()?;
+    cache.save(&output)?;
+
+    if log {
+        let changes = CacheChanges::between(previous.as_ref(), &cache);
+        let path = cache_log::append(&output, &CacheLogEntry::new("build", &cache, changes))?;
+        info!("Change log: {}", path.display());
+    }
+
+    info!("Cache saved to: {}", output.display());
+    info!("  Assets cached: {}", cache.asset_count());
+    info!("  Cache size: {} KB", cache.size_bytes() / 1024);
+    for phase in &metrics {
+        phase.emit();
+    }
+
+    Ok(())
+}
+
+fn cmd_cache_compare(paths: Vec<PathBuf>, output: Option<PathBuf>, format: OutputFormat) -> Result<()> {
+    info!("Comparing {} caches", paths.len());
+
+    let mut caches = Vec::new();
+    for path in &paths {
+        let cache = StartupCache::load(path)?;
+        let label = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.display().to_string());
+        info!("  {}: {} assets, built {}", label, cache.asset_count(), cache.created_at.to_rfc3339());
+        caches.push((label, cache));
+    }
+    // Hashes of different algorithms never match
+    if let Some((label, cache)) = caches.iter().find(|(_, c)| c.hash_algorithm != caches[0].1.hash_algorithm) {
+        anyhow::bail!(
+            "{} was hashed with {} but {} with {}; rebuild them with the same --hash-algorithm",
+            label, cache.hash_algorithm, caches[0].0, caches[0].1.hash_algorithm
+        );
+    }
+
+    let comparison = CacheComparison::compare(&caches);
+    for pair in &comparison.pairs {
+        info!(
+            "  {} vs {}: {:.1}% agree ({} differ, {} only in first, {} only in second)",
+            comparison.caches[pair.a],
+            comparison.caches[pair.b],
+            pair.ratio() * 100.0,
+            pair.differing,
+            pair.only_in_a,
+            pair.only_in_b
+        );
+    }
+
+    if comparison.is_identical() {
+        info!("{} All caches agree", glyph(Glyph::Ok));
+    } else {
+        warn!("{} {} assets diverge", glyph(Glyph::Fail), comparison.divergent.len());
+        for asset in comparison.divergent.iter().take(10) {
+            warn!("    - {} ({})", asset.relative_path, asset.cause.as_str());
+        }
+        if comparison.divergent.len() > 10 {
+            warn!("    ... and {} more", comparison.divergent.len() - 10);
+        }
+    }
+
+    if let Some(output_path) = output {
+        write_report(&comparison, format, &output_path)?;
+        info!("Comparison saved to: {} ({})", output_path.display(), format);
+    }
+
+    Ok(())
+}
+
+fn cmd_cache_update(cache_path: PathBuf, project: PathBuf, log: bool) -> Result<()> {
+    info!("Updating cache: {}", cache_path.display());
+
+    let config = ProjectConfig::load(&project)?;
+    let mut cache = StartupCache::load(&cache_path)?;
+    let changes = cache.update(&project, &config)?;
+
+    for rename in &changes.renamed {
+        info!("  {} -> {}", rename.from, rename.to);
+    }
+    if changes.total() == 0 {
+        info!("{} Cache is up to date", glyph(Glyph::Ok));
+    } else {
+        info!(
+            "{} {} changed, {} added, {} removed, {} renamed",
+            glyph(Glyph::Ok),
+            changes.changed.len(),
+            changes.added.len(),
+            changes.removed.len(),
+            changes.renamed.len()
+        );
+        if !changes.added.is_empty() {
+            info!("  New assets are appended to the load order; rebuild to re-rank them");
+        }
+    }
+    cache.save(&cache_path)?;
+
+    if log || cache_log::log_path(&cache_path).exists() {
+        cache_log::append(&cache_path, &CacheLogEntry::new("update", &cache, changes))?;
+    }
+    Ok(())
+}
+
+fn cmd_cache_log(cache_path: PathBuf, limit: Option<usize>) -> Result<()> {
+    let entries = cache_log::read(&cache_path)?;
+    let skip = limit.map(|n| entries.len().saturating_sub(n)).unwrap_or(0);
+
+    info!("Change log of {}: {} entries", cache_path.display(), entries.len());
+    for entry in entries.iter().skip(skip) {
+        let changes = &entry.changes;
+        info!(
+            "  {} {}@{} {}: {} assets, +{} ~{} -{} renamed {}{}",
+            entry.timestamp,
+            entry.user,
+            entry.host,
+            entry.operation,
+            entry.asset_count,
+            changes.added.len(),
+            changes.changed.len(),
+            changes.removed.len(),
+            changes.renamed.len(),
+            if entry.tags.is_empty() { String::new() } else { format!(" [{}]", entry.tags.join(", ")) }
+        );
+    }
+
+    Ok(())
+}
+
+fn cmd_cache_shard(cache_path: PathBuf, output: PathBuf) -> Result<()> {
+    info!("Sharding cache: {}", cache_path.display());
+
+    let cache = ue5_fast_startup::cache::StartupCache::load(&cache_path)?;
+    let manifest = shards::write_shards(&cache, &output)?;
+    for shard in &manifest.shards {
+        info!(
+            "  {}: {} assets, {} KB{}",
+            shard.name,
+            shard.assets,
+            shard.size_bytes / 1024,
+            if shard.profiles.is_empty() { String::new() } else { format!(" ({})", shard.profiles.join(", ")) }
+        );
+    }
+    info!("{} Shards saved to: {}", glyph(Glyph::Ok), output.join(shards::SHARD_MANIFEST).display());
+    Ok(())
+}
+
+fn cmd_cache_pull(from: String, profile: Option<String>, output: PathBuf) -> Result<()> {
+    info!("Pulling cache shards from {}{}", from, profile.as_ref().map(|p| format!(" for {}", p)).unwrap_or_default());
+
+    let (mut cache, stats) = shards::pull(&from, profile.as_deref())?;
+    cache.save(&output)?;
+    info!(
+        "{} {} shards, {} assets: fetched {} of {} KB",
+        glyph(Glyph::Ok),
+        stats.shards,
+        cache.asset_count(),
+        stats.fetched_bytes / 1024,
+        stats.total_bytes / 1024
+    );
+    Ok(())
+}
+
+fn cmd_verify(args: VerifyArgs, print: PrintFormat) -> Result<()> {
+    let VerifyArgs { cache: cache_path, cache_url, project, output, format, explain, hash_algorithm } = args;
+    let cache = match (cache_path, cache_url) {
+        (_, Some(url)) => {
+            info!("Verifying remote cache: {}", url);
+            let (cache, reader) = remote_cache::load_index(&url)?;
+            info!(
+                "  Fetched {:.1} of {} MB in {} requests",
+                reader.fetched_bytes() as f64 / (1024.0 * 1024.0),
+                reader
+                    .total_bytes()
+                    .map(|total| format!("{:.1}", total as f64 / (1024.0 * 1024.0)))
+                    .unwrap_or_else(|| "?".to_string()),
+                reader.requests()
+            );
+            cache
+        }
+        (Some(cache_path), None) => {
+            info!("Verifying cache: {}", cache_path.display());
+            ue5_fast_startup::cache::StartupCache::load(&cache_path)?
+        }
+        (None, None) => anyhow::bail!("verify requires --cache or --cache-url"),
+    };
+    if let Some(expected) = hash_algorithm {
+        cache.require_algorithm(expected)?;
+    }
+    let result = cache.verify(&project, &ProjectConfig::load(&project)?)?;
+
+    if result.is_valid {
+        info!("{} Cache is valid", glyph(Glyph::Ok));
+        info!("  Matching assets: {}/{}", result.matching_assets, result.total_assets);
+    } else {
+        info!("{} Cache is invalid", glyph(Glyph::Fail));
+        info!("  Changed assets: {}", result.changed_assets.len());
+        for asset in result.changed_assets.iter().take(10) {
+            info!("    - {}", asset);
+        }
+        if result.changed_assets.len() > 10 {
+            info!("    ... and {} more", result.changed_assets.len() - 10);
+        }
+        for rename in &result.renamed_assets {
+            info!("  Renamed: {} -> {}", rename.from, rename.to);
+        }
+        for anomaly in &result.anomalies {
+            warn!("  {} ({})", anomaly.relative_path, anomaly.kind.as_str());
+        }
+    }
+
+    if let Some(output_path) = output {
+        write_report(&result, format, &output_path)?;
+        info!("Result saved to: {} ({})", output_path.display(), format);
+    }
+
+    print_next_steps(&next_steps::from_verify(&result), explain.as_deref(), &[]);
+    print_report(&result, print)?;
+    Ok(())
+}
+
+fn cmd_stats(cache_path: PathBuf, print: PrintFormat) -> Result<()> {
+    info!("Cache statistics: {}", cache_path.display());
+
+    let cache = ue5_fast_startup::cache::StartupCache::load(&cache_path)?;
+    let stats = cache.statistics();
+
+    info!("  Version: {}", stats.version);
+    info!("  Created: {}", stats.created_at);
+    info!("  Assets: {} ({} startup-critical)", stats.asset_count, stats.startup_assets);
+    info!("  Size: {} KB", stats.size_bytes / 1024);
+    info!("  Hash algorithm: {}", stats.hash_algorithm);
+    if !stats.tags.is_empty() {
+        info!("  Tags: {}", stats.tags.join(", "));
+    }
+    for (key, value) in &stats.metadata {
+        info!("  {}: {}", key, value);
+    }
+    for (name, count) in &stats.scenarios {
+        info!("  Scenario {}: {} startup assets", name, count);
+    }
+
+    print_report(&stats, print)?;
+    Ok(())
+}
+
+fn cmd_graph(
+    project: PathBuf,
+    output: Option<PathBuf>,
+    startup_only: bool,
+    include_soft: bool,
+    editor_data: Option<PathBuf>,
+    export: GraphExportArgs,
+    print: PrintFormat,
+) -> Result<()> {
+    info!("Building dependency graph: {}", project.display());
+
+    let editor_data = editor_data.map(|path| load_editor_data(&project, path)).transpose()?;
+    let config = ProjectConfig::load(&project)?;
+    let assets = AssetScanner::new(&project)?.with_config(&config).scan_all(None)?;
+    let roots: HashSet<PathBuf> = export
+        .root
+        .iter()
+        .map(|root| deps::resolve_asset(&project, &assets, root))
+        .collect::<ue5_fast_startup::Result<_>>()?;
+    let graph = if include_soft {
+        DependencyGraph::build_with_soft_references(&project, &assets, editor_data.as_ref())?
+    } else {
+        DependencyGraph::build_with_editor_data(&project, &assets, editor_data.as_ref())?
+    };
+
+    let filtered = if startup_only {
+        graph.filter_startup_critical()
+    } else {
+        graph
+    };
+    let filtered = if roots.is_empty() {
+        filtered
+    } else {
+        info!(
+            "Keeping {} within {} edges of {}",
+            export.direction.describe(),
+            export.depth.map(|d| d.to_string()).unwrap_or_else(|| "any number of".to_string()),
+            export.root.join(", ")
+        );
+        filtered.subgraph(&roots, export.depth, export.direction)
+    };
+
+    info!("Graph built:");
+    info!("  Nodes: {}", filtered.node_count());
+    info!("  Edges: {}", filtered.edge_count());
+    let coverage = filtered.coverage();
+    info!(
+        "  Parsed packages: {}/{} ({:.1}%, {} from import cache)",
+        coverage.parsed,
+        coverage.packages,
+        coverage.parsed_ratio() * 100.0,
+        coverage.reused
+    );
+    if coverage.failed() > 0 {
+        info!("  Parse failures: {}", coverage.describe_failures());
+    }
+    if coverage.soft_edges > 0 {
+        info!("  Soft references: {} (dotted in DOT output)", coverage.soft_edges);
+    }
+    if coverage.heuristic_edges > 0 {
+        info!("  Inferred edges: {} (dashed in DOT output)", coverage.heuristic_edges);
+    }
+    if coverage.editor_packages > 0 {
+        info!(
+            "  From editor data: {} packages, {} edges (dotted in DOT output)",
+            coverage.editor_packages, coverage.registry_edges
+        );
+    }
+    let cycles = filtered.find_cycles();
+    if !cycles.is_empty() {
+        warn!(
+            "{} {} reference cycles ({} hard), largest first:",
+            glyph(Glyph::Warn),
+            cycles.len(),
+            filtered.find_hard_cycles().len()
+        );
+        for cycle in cycles.iter().take(MAX_LISTED_CYCLES) {
+            let names: Vec<String> =
+                cycle.iter().map(|path| path.strip_prefix(&project).unwrap_or(path).display().to_string()).collect();
+            warn!("  {}", names.join(" <-> "));
+        }
+    }
+
+    if let Some(output_path) = output {
+        let format = export.graph_format.unwrap_or_else(|| GraphFormat::from_path(&output_path));
+        let summary = GraphExporter::new(&filtered, &project)
+            .with_max_nodes(export.max_nodes)
+            .with_max_edges(export.max_edges)
+            .with_under(export.under)
+            .with_asset_types(export.asset_type.iter().map(|ext| AssetType::from_extension(ext)).collect())
+            .with_hard_only(export.hard_only)
+            .write_file(&output_path, format)?;
+        info!("Graph saved to: {} ({})", output_path.display(), format.as_str());
+        if summary.skipped_nodes > 0 || summary.skipped_edges > 0 {
+            info!(
+                "  Wrote {} nodes and {} edges; caps and filters left out {} nodes and {} edges",
+                summary.nodes, summary.edges, summary.skipped_nodes, summary.skipped_edges
+            );
+        }
+    }
+
+    if print.is_structured() {
+        print_report(&filtered.report(&project), print)?;
+    }
+    Ok(())
+}
+
+fn cmd_deps(args: DepsArgs, print: PrintFormat) -> Result<()> {
+    let DepsArgs { project, of, reverse, hard_only, include_soft, max_depth, output, format } = args;
+    let config = ProjectConfig::load(&project)?;
+    let assets = AssetScanner::new(&project)?.with_config(&config).scan_all(None)?;
+    let asset = deps::resolve_asset(&project, &assets, &of)?;
+
+    let mut graph = if include_soft {
+        DependencyGraph::build_with_soft_references(&project, &assets, None)?
+    } else {
+        DependencyGraph::build_from_assets(&project, &assets)?
+    };
+    graph.mark_startup_roots(&attribution::startup_roots(&project, &assets, &config.startup));
+    let query = DependencyQuery::run(&graph, &project, &asset, reverse, hard_only, max_depth);
+
+    let noun = if reverse { "dependents" } else { "dependencies" };
+    info!(
+        "{}: {} direct, {} in all, {} startup-critical",
+        query.asset,
+        query.direct().count(),
+        query.entries.len(),
+        query.startup_critical().count()
+    );
+    for depth in 1..=query.max_depth() {
+        info!("  Depth {}:", depth);
+        for entry in query.entries.iter().filter(|e| e.depth == depth) {
+            info!(
+                "    {}{}{}",
+                entry.relative_path,
+                if depth > 1 { format!(" (via {})", entry.via) } else { String::new() },
+                if entry.is_startup_critical { " [startup]" } else { "" }
+            );
+        }
+    }
+    if query.entries.is_empty() {
+        info!("{} No {}", glyph(Glyph::Ok), noun);
+    } else if reverse && query.startup_critical().count() > 0 {
+        warn!(
+            "{} Changing {} affects {} startup-critical assets",
+            glyph(Glyph::Warn),
+            query.asset,
+            query.startup_critical().count()
+        );
+    }
+
+    if let Some(output_path) = output {
+        write_report(&query, format, &output_path)?;
+        info!("Dependency list saved to: {} ({})", output_path.display(), format);
+    }
+    print_report(&query, print)?;
+    Ok(())
+}
+
+fn cmd_attribution(project: PathBuf, output: PathBuf) -> Result<()> {
+    info!("Attributing startup cost: {}", project.display());
+
+    let config = ProjectConfig::load(&project)?;
+    let hardware = config.hardware_profile(&project);
+    let assets = AssetScanner::new(&project)?.with_config(&config).scan_all(None)?;
+    let graph = DependencyGraph::build_from_assets(&project, &assets)?;
+    let roots = attribution::startup_roots(&project, &assets, &config.startup);
+    let name = project.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let tree = AttributionTree::build(&name, &graph, &assets, &roots, &config.model_for_profile(&hardware));
+
+    info!(
+        "Startup: {} assets, {:.1} MB, ~{:.2}s",
+        tree.total_assets,
+        tree.total_bytes as f64 / (1024.0 * 1024.0),
+        tree.total_seconds
+    );
+    info!("Largest subtrees:");
+    for node in tree.roots.iter().take(5) {
+        info!(
+            "  {} ({} assets, {:.1} MB, ~{:.2}s)",
+            node.name,
+            node.retained_assets,
+            node.retained_bytes as f64 / (1024.0 * 1024.0),
+            node.retained_seconds
+        );
+    }
+    info!("Largest folders:");
+    for folder in tree.folders.iter().take(5) {
+        info!("  {}/ ({} assets, ~{:.2}s)", folder.folder, folder.assets, folder.seconds);
+    }
+
+    tree.write(&output)?;
+    info!("Attribution saved to: {}", output.display());
+    Ok(())
+}
+
+fn cmd_predict_cook(
+    project: PathBuf,
+    changes: PathBuf,
+    output: Option<PathBuf>,
+    format: OutputFormat,
+    editor_data: Option<PathBuf>,
+) -> Result<()> {
+    info!("Predicting cook time: {}", project.display());
+
+    let changes = read_change_list(&changes)?;
+    let config = ProjectConfig::load(&project)?;
+    let editor_data = editor_data.map(|path| load_editor_data(&project, path)).transpose()?;
+    let assets = AssetScanner::new(&project)?.with_config(&config).scan_all(None)?;
+    let graph = DependencyGraph::build_with_editor_data(&project, &assets, editor_data.as_ref())?;
+    let name = project.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let prediction = CookPrediction::predict(
+        &name,
+        &project,
+        &graph,
+        &assets,
+        editor_data.as_ref(),
+        &changes,
+        &config.cook.unwrap_or_default(),
+    );
+
+    info!(
+        "{} changed packages invalidate {} of {} ({:.1}%)",
+        prediction.changed_packages,
+        prediction.recooked.len(),
+        prediction.total_packages,
+        prediction.recook_ratio() * 100.0
+    );
+    info!(
+        "  Incremental cook: ~{:.0}s (full cook ~{:.0}s)",
+        prediction.incremental_seconds, prediction.full_cook_seconds
+    );
+    info!("Slowest recooks:");
+    for package in prediction.recooked.iter().take(5) {
+        info!("  {} (~{:.1}s)", package.relative_path, package.seconds);
+    }
+    for entry in &prediction.unmatched {
+        warn!("{} {} matches no package", glyph(Glyph::Warn), entry);
+    }
+
+    if let Some(output_path) = output {
+        write_report(&prediction, format, &output_path)?;
+        info!("Prediction saved to: {} ({})", output_path.display(), format);
+    }
+    Ok(())
+}
+
+fn cmd_simulate(args: SimulateArgs) -> Result<()> {
+    let project = args.project;
+    info!("Simulating startup loads: {}", project.display());
+
+    let config = ProjectConfig::load(&project)?;
+    let storage = args
+        .storage
+        .or(config.storage)
+        .unwrap_or_else(|| HardwareProfile::detect(&project).storage);
+    let flags = IoOverrides { seek_ms: args.seek_ms, bandwidth_mb_s: args.bandwidth_mb_s, queue_depth: args.queue_depth };
+    let io = flags.apply(config.io.unwrap_or_default().apply(IoModel::for_tier(storage)));
+    info!("  I/O model: {} ({} preset)", io.describe(), storage);
+
+    let order_file = args.order.as_deref().map(read_order_file).transpose()?.unwrap_or_default();
+    let mut strategies = args.strategy;
+    if strategies.is_empty() {
+        strategies = OrderStrategy::BUILTIN.to_vec();
+    }
+    if args.order.is_some() && !strategies.contains(&OrderStrategy::File) {
+        strategies.push(OrderStrategy::File);
+    }
+    if strategies.contains(&OrderStrategy::File) && args.order.is_none() {
+        anyhow::bail!("The file strategy needs --order <GameOpenOrder.txt>");
+    }
+
+    let assets = AssetScanner::new(&project)?.with_config(&config).scan_all(None)?;
+    let mut graph = DependencyGraph::build_from_assets(&project, &assets)?.filter_startup_critical();
+    graph.compute_load_order();
+    let load_order = graph.relative_load_order(&project);
+    let name = project.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let simulation = LoadSimulation::run(&name, &load_order, &assets, io, &strategies, &order_file);
+
+    info!(
+        "{} startup packages, {:.1} MB",
+        simulation.packages,
+        simulation.total_bytes as f64 / MB
+    );
+    let fastest = simulation.fastest().map(|o| o.total_ms).unwrap_or(0.0);
+    for order in &simulation.orders {
+        info!(
+            "  {:<12} {:>9.1} ms  {:>6} seeks  ({:.2}x)",
+            order.strategy.as_str(),
+            order.total_ms,
+            order.seeks,
+            if fastest > 0.0 { order.total_ms / fastest } else { 1.0 }
+        );
+    }
+    if !simulation.unmatched.is_empty() {
+        warn!(
+            "{} {} order file entries are not startup packages",
+            glyph(Glyph::Warn),
+            simulation.unmatched.len()
+        );
+    }
 
-    // Setup logging
-    let level = if cli.verbose { Level::DEBUG } else { Level::INFO };
-    let subscriber = FmtSubscriber::builder()
-        .with_max_level(level)
-        .with_target(false)
-        .finish();
-    tracing::subscriber::set_global_default(subscriber)?;
+    if let Some(output_path) = args.output {
+        write_report(&simulation, args.format, &output_path)?;
+        info!("Simulation saved to: {} ({})", output_path.display(), args.format);
+    }
+    Ok(())
+}
 
-    // Configure thread pool
-    if cli.threads > 0 {
-        rayon::ThreadPoolBuilder::new()
-            .num_threads(cli.threads)
-            .build_global()?;
+fn cmd_compare_strategies(args: CompareStrategiesArgs) -> Result<()> {
+    let project = args.project;
+    info!("Comparing cache strategies: {}", project.display());
+    if args.strategy.len() < 2 {
+        anyhow::bail!("Give at least two --strategy values to compare");
     }
 
-    info!("UE5 Fast Startup Accelerator v0.1.0");
+    let config = ProjectConfig::load(&project)?;
+    let storage = args
+        .storage
+        .or(config.storage)
+        .unwrap_or_else(|| HardwareProfile::detect(&project).storage);
+    let flags = IoOverrides { seek_ms: args.seek_ms, bandwidth_mb_s: args.bandwidth_mb_s, queue_depth: args.queue_depth };
+    let io = flags.apply(config.io.unwrap_or_default().apply(IoModel::for_tier(storage)));
+    info!("  I/O model: {} ({} preset)", io.describe(), storage);
+
+    let assets = AssetScanner::new(&project)?.with_config(&config).scan_all(None)?;
+    let mut graph = DependencyGraph::build_from_assets(&project, &assets)?;
+    graph.compute_load_order();
+    let mut startup = DependencyGraph::build_from_assets(&project, &assets)?.filter_startup_critical();
+    startup.compute_load_order();
+    let startup_order = startup.relative_load_order(&project);
+
+    let comparison = StrategyComparison::run(
+        &project,
+        &config,
+        &assets,
+        &graph,
+        &startup_order,
+        &args.strategy,
+        io,
+        args.output_dir.as_deref(),
+    )?;
+
+    let baseline = comparison.baseline().map(|r| r.startup_ms()).unwrap_or(0.0);
+    for result in &comparison.results {
+        info!(
+            "  {:<20} build {:>8.1} ms  load {:>6.1} ms  verify {:>7.1} ms  simulated {:>8.1} ms  ({:+.1} ms)",
+            result.strategy,
+            result.build_ms,
+            result.load_ms,
+            result.verify_ms,
+            result.simulated_ms,
+            result.startup_ms() - baseline
+        );
+    }
+    if let Some(best) = comparison.best() {
+        info!("{} Fastest startup: {}", glyph(Glyph::Ok), best.strategy);
+    }
 
-    match cli.command {
-        Commands::Analyze { project, output, shaders } => {
-            cmd_analyze(project, output, shaders)
-        }
-        Commands::Scan { project, output, filter } => {
-            cmd_scan(project, output, filter)
-        }
-        Commands::Cache { project, output, force } => {
-            cmd_cache(project, output, force)
-        }
-        Commands::Verify { cache, project } => {
-            cmd_verify(cache, project)
-        }
-        Commands::Stats { cache } => {
-            cmd_stats(cache)
-        }
-        Commands::Graph { project, output, startup_only } => {
-            cmd_graph(project, output, startup_only)
-        }
-        Commands::Bench { project, iterations } => {
-            cmd_bench(project, iterations)
-        }
-        Commands::Turbo { project, output } => {
-            cmd_turbo(project, output)
-        }
-        Commands::QuickVerify { cache, project } => {
-            cmd_quick_verify(cache, project)
-        }
+    if let Some(output_path) = args.output {
+        write_report(&comparison, args.format, &output_path)?;
+        info!("Comparison saved to: {} ({})", output_path.display(), args.format);
     }
+    Ok(())
 }
 
-fn cmd_analyze(project: PathBuf, output: Option<PathBuf>, include_shaders: bool) -> Result<()> {
-    info!("Analyzing project: {}", project.display());
+fn cmd_watch(project: PathBuf, cache: PathBuf, debounce_ms: u64, log: bool) -> Result<()> {
+    info!("Keeping {} current with {}", cache.display(), project.display());
+    CacheWatcher::new(&project, &cache)?
+        .with_config(&ProjectConfig::load(&project)?)
+        .with_debounce(std::time::Duration::from_millis(debounce_ms))
+        .with_log(log)
+        .run()?;
+    Ok(())
+}
 
-    let analyzer = StartupAnalyzer::new(&project)?;
-    let report = analyzer.analyze(include_shaders)?;
+fn cmd_crashes(
+    project: PathBuf,
+    cache_path: PathBuf,
+    crash_dir: Option<PathBuf>,
+    startup_seconds: f64,
+    window_hours: i64,
+    output: Option<PathBuf>,
+    format: OutputFormat,
+) -> Result<()> {
+    let crash_dir = crash_dir.unwrap_or_else(|| project.join("Saved").join("Crashes"));
+    info!("Reading crash reports: {}", crash_dir.display());
+    if !crash_dir.is_dir() {
+        anyhow::bail!("{} does not exist; pass --crashes for a packaged build's reports", crash_dir.display());
+    }
+    let reports = crashes::read_crashes(&crash_dir, startup_seconds)?;
 
-    info!("Analysis complete:");
-    info!("  Total assets: {}", report.total_assets);
-    info!("  Startup assets: {}", report.startup_assets);
-    info!("  Estimated savings: {:.1}s", report.estimated_savings_seconds);
+    let log = if cache_log::log_path(&cache_path).exists() {
+        cache_log::read(&cache_path)?
+    } else {
+        warn!(
+            "{} {} has no change log; build and update it with --log to date changes",
+            glyph(Glyph::Warn),
+            cache_path.display()
+        );
+        Vec::new()
+    };
+    let config = ProjectConfig::load(&project)?;
+    let assets = AssetScanner::new(&project)?.with_config(&config).scan_all(None)?;
+    let mut graph = DependencyGraph::build_from_assets(&project, &assets)?.filter_startup_critical();
+    graph.compute_load_order();
+    let load_order = graph.relative_load_order(&project);
+    let name = project.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let correlation = CrashCorrelation::correlate(&name, reports, &load_order, &log, startup_seconds, window_hours);
+
+    info!("Reports: {} ({} during startup)", correlation.crashes.len(), correlation.startup_crashes().count());
+    for crash in correlation.startup_crashes() {
+        info!(
+            "  {} {} at {}: {}",
+            crash.time.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            crash.crash_type,
+            crash.seconds_since_start.map(|s| format!("{:.1}s", s)).unwrap_or_else(|| "boot".to_string()),
+            crash.headline()
+        );
+        for suspect in crash.suspects.iter().take(5) {
+            info!(
+                "    {} {} {} by {}{}",
+                suspect.change,
+                suspect.relative_path,
+                suspect.changed_at.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+                suspect.changed_by,
+                if suspect.mentioned { " (named in the report)" } else { "" }
+            );
+        }
+    }
+    if correlation.startup_crashes().next().is_none() {
+        info!("{} No startup crashes reported", glyph(Glyph::Ok));
+    } else if correlation.startup_crashes().any(|c| c.suspects.iter().any(|s| s.mentioned)) {
+        warn!("{} A startup crash names a recently changed startup asset", glyph(Glyph::Fail));
+    }
 
     if let Some(output_path) = output {
-        let json = serde_json::to_string_pretty(&report)?;
-        std::fs::write(&output_path, json)?;
-        info!("Report saved to: {}", output_path.display());
+        write_report(&correlation, format, &output_path)?;
+        info!("Report saved to: {} ({})", output_path.display(), format);
     }
 
     Ok(())
 }
 
-fn cmd_scan(project: PathBuf, output: Option<PathBuf>, filter: Option<String>) -> Result<()> {
-    info!("Scanning project: {}", project.display());
-
-    let scanner = AssetScanner::new(&project)?;
-    let assets = scanner.scan_all(filter.as_deref())?;
+fn cmd_warmup(
+    project: PathBuf,
+    status_only: bool,
+    storage: Option<StorageTier>,
+    output: Option<PathBuf>,
+    format: OutputFormat,
+) -> Result<()> {
+    let config = ProjectConfig::load(&project)?;
+    let storage = storage.or(config.storage).unwrap_or_else(|| HardwareProfile::detect(&project).storage);
+    let io = config.io.unwrap_or_default().apply(IoModel::for_tier(storage));
+
+    let assets = AssetScanner::new(&project)?.with_config(&config).scan_all(None)?;
+    let mut graph = DependencyGraph::build_from_assets(&project, &assets)?.filter_startup_critical();
+    graph.compute_load_order();
+    let load_order = graph.relative_load_order(&project);
+    let name = project.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+
+    if !status_only {
+        let started = std::time::Instant::now();
+        let (files, bytes) = warmup::warm(&project, &load_order, &assets)?;
+        info!(
+            "{} Read {} startup files ({:.1} MB) in {:.2}s",
+            glyph(Glyph::Ok),
+            files,
+            bytes as f64 / MB,
+            started.elapsed().as_secs_f64()
+        );
+    }
 
-    info!("Found {} assets", assets.len());
+    let status = WarmupStatus::sample(&name, &project, &load_order, &assets, io);
+    match status.resident_ratio() {
+        Some(ratio) => info!(
+            "Page cache: {} ({:.0}% of {} sampled files resident)",
+            status.state.as_str(),
+            ratio * 100.0,
+            status.sampled_files
+        ),
+        None => warn!("{} Page cache residency cannot be sampled on this platform", glyph(Glyph::Warn)),
+    }
+    info!("  Startup set: {} files, {:.1} MB", status.startup_files, status.startup_bytes as f64 / MB);
+    info!(
+        "  Startup reads: ~{:.0} ms expected (cold {:.0} ms, warm {:.0} ms, {})",
+        status.expected_ms,
+        status.cold_ms,
+        status.warm_ms,
+        storage
+    );
 
     if let Some(output_path) = output {
-        let json = serde_json::to_string_pretty(&assets)?;
-        std::fs::write(&output_path, json)?;
-        info!("Asset list saved to: {}", output_path.display());
+        write_report(&status, format, &output_path)?;
+        info!("Status saved to: {} ({})", output_path.display(), format);
     }
-
     Ok(())
 }
 
-fn cmd_cache(project: PathBuf, output: PathBuf, force: bool) -> Result<()> {
-    info!("Building cache for: {}", project.display());
+/// Project and engine for a `ue` command
+fn ue_engine(project: &Path, engine: Option<PathBuf>) -> Result<(Project, EngineInstall)> {
+    let project = Project::open(project)?;
+    let install = EngineInstall::locate(&project, engine.as_deref())?;
+    info!(
+        "Engine: {} ({}{})",
+        install.root.display(),
+        install.source.as_str(),
+        if install.association.is_empty() { String::new() } else { format!(", {}", install.association) }
+    );
+    Ok((project, install))
+}
 
-    if output.exists() && !force {
-        info!("Cache already exists. Use --force to rebuild.");
-        return Ok(());
+/// Run `commandlet`, or only print it with `dry_run`; `None` when dry
+fn ue_commandlet(
+    project: &Project,
+    install: &EngineInstall,
+    commandlet: &str,
+    args: &[String],
+    dry_run: bool,
+) -> Result<Option<CommandletRun>> {
+    let log_path = project
+        .root
+        .join(ue5_fast_startup::STATE_DIR)
+        .join("logs")
+        .join(format!("{}.log", commandlet));
+    if dry_run {
+        info!("Would run: {:?}", install.commandlet(project, commandlet, args, &log_path));
+        return Ok(None);
     }
 
-    let builder = CacheBuilder::new(&project)?;
-    let cache = builder.build()?;
-    cache.save(&output)?;
-
-    info!("Cache saved to: {}", output.display());
-    info!("  Assets cached: {}", cache.asset_count());
-    info!("  Cache size: {} KB", cache.size_bytes() / 1024);
+    info!("Running {} commandlet (log: {})", commandlet, log_path.display());
+    let run = install.run_commandlet(project, commandlet, args, &log_path)?;
+    info!(
+        "{} finished in {:.1}s: {} errors, {} warnings",
+        commandlet, run.seconds, run.errors, run.warnings
+    );
+    if !run.succeeded() {
+        anyhow::bail!(
+            "{} commandlet failed (exit code {}); see {}",
+            commandlet,
+            run.exit_code.map(|c| c.to_string()).unwrap_or_else(|| "none".to_string()),
+            run.log_path.display()
+        );
+    }
+    Ok(Some(run))
+}
 
+fn cmd_ue_locate(project: PathBuf, engine: Option<PathBuf>) -> Result<()> {
+    let (project, install) = ue_engine(&project, engine)?;
+    let editor = install.editor_cmd();
+    if editor.is_file() {
+        info!("{} Editor: {}", glyph(Glyph::Ok), editor.display());
+    } else {
+        warn!("{} Editor binary not built: {}", glyph(Glyph::Warn), editor.display());
+    }
+    info!("Project: {}", project.uproject_path.display());
     Ok(())
 }
 
-fn cmd_verify(cache_path: PathBuf, project: PathBuf) -> Result<()> {
-    info!("Verifying cache: {}", cache_path.display());
-
-    let cache = ue5_fast_startup::cache::StartupCache::load(&cache_path)?;
-    let result = cache.verify(&project)?;
+fn cmd_ue_analyze(
+    project: PathBuf,
+    engine: Option<PathBuf>,
+    output: Option<PathBuf>,
+    format: OutputFormat,
+    dry_run: bool,
+) -> Result<()> {
+    let (uproject, install) = ue_engine(&project, engine)?;
+    let data_path = uproject.root.join(editor_data::DEFAULT_EDITOR_DATA);
+    let script = data_path.with_file_name("uefast_export.py");
+    if let Some(parent) = script.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&script, editor_data::editor_script(&data_path.to_string_lossy()))?;
 
-    if result.is_valid {
-        info!("✓ Cache is valid");
-        info!("  Matching assets: {}/{}", result.matching_assets, result.total_assets);
-    } else {
-        info!("✗ Cache is invalid");
-        info!("  Changed assets: {}", result.changed_assets.len());
-        for asset in result.changed_assets.iter().take(10) {
-            info!("    - {}", asset);
-        }
-        if result.changed_assets.len() > 10 {
-            info!("    ... and {} more", result.changed_assets.len() - 10);
-        }
+    let args = [format!("-script={}", script.display())];
+    if ue_commandlet(&uproject, &install, "pythonscript", &args, dry_run)?.is_none() {
+        return Ok(());
     }
 
+    let data = load_editor_data(&project, data_path)?;
+    let report = project_analyzer(&project, AssetFilter::default(), None)?
+        .with_editor_data(data)
+        .analyze(false)?;
+    let coverage = &report.parser_coverage;
+    info!("Analysis with editor data complete:");
+    info!("  Startup assets: {}", report.startup_assets);
+    info!(
+        "  From editor data: {} packages, {} edges ({} packages parsed)",
+        coverage.editor_packages, coverage.registry_edges, coverage.parsed
+    );
+    for asset in report.never_cooked.iter().take(5) {
+        warn!("{} {} is in the startup closure but never cooked for shipping ({})", glyph(Glyph::Warn), asset.relative_path, asset.rule);
+    }
+    for asset in report.late_chunks.iter().take(5) {
+        warn!("{} {} is in the startup closure but assigned to chunk {} ({})", glyph(Glyph::Warn), asset.relative_path, asset.chunk_id, asset.source);
+    }
+    if let Some(output) = output {
+        write_report(&report, format, &output)?;
+        info!("Report saved to: {} ({})", output.display(), format);
+    }
     Ok(())
 }
 
-fn cmd_stats(cache_path: PathBuf) -> Result<()> {
-    info!("Cache statistics: {}", cache_path.display());
+fn cmd_ue_resave(project: PathBuf, engine: Option<PathBuf>, dry_run: bool) -> Result<()> {
+    let (uproject, install) = ue_engine(&project, engine)?;
+    let outdated = || -> Result<VersionMatrix> {
+        let config = ProjectConfig::load(&uproject.root)?;
+        let assets = AssetScanner::new(&uproject.root)?.with_config(&config).scan_all(None)?;
+        Ok(VersionMatrix::build(uproject.engine_association(), &assets))
+    };
 
-    let cache = ue5_fast_startup::cache::StartupCache::load(&cache_path)?;
-    let stats = cache.statistics();
+    let matrix = outdated()?;
+    let list = uproject.root.join(ue5_fast_startup::STATE_DIR).join("resave_list.txt");
+    if let Some(parent) = list.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let count = matrix.write_resave_list(&list)?;
+    if count == 0 {
+        info!("{} No packages older than engine {}", glyph(Glyph::Ok), matrix.engine_association);
+        return Ok(());
+    }
+    info!("Resave list ({} packages) saved to: {}", count, list.display());
 
-    info!("  Version: {}", stats.version);
-    info!("  Created: {}", stats.created_at);
-    info!("  Assets: {}", stats.asset_count);
-    info!("  Size: {} KB", stats.size_bytes / 1024);
-    info!("  Hash algorithm: {}", stats.hash_algorithm);
+    let args = [format!("-PackageList={}", list.display())];
+    if ue_commandlet(&uproject, &install, "ResavePackages", &args, dry_run)?.is_none() {
+        return Ok(());
+    }
 
+    let remaining = outdated()?.resave_candidates().len();
+    if remaining == 0 {
+        info!("{} All {} packages resaved", glyph(Glyph::Ok), count);
+    } else {
+        warn!("{} {} of {} packages are still outdated", glyph(Glyph::Warn), remaining, count);
+    }
     Ok(())
 }
 
-fn cmd_graph(project: PathBuf, output: Option<PathBuf>, startup_only: bool) -> Result<()> {
-    info!("Building dependency graph: {}", project.display());
-
-    let graph = DependencyGraph::build(&project)?;
-    
-    let filtered = if startup_only {
-        graph.filter_startup_critical()
-    } else {
-        graph
-    };
+fn cmd_ue_run(
+    commandlet: String,
+    project: PathBuf,
+    engine: Option<PathBuf>,
+    dry_run: bool,
+    args: Vec<String>,
+) -> Result<()> {
+    let (uproject, install) = ue_engine(&project, engine)?;
+    ue_commandlet(&uproject, &install, &commandlet, &args, dry_run)?;
+    Ok(())
+}
 
-    info!("Graph built:");
-    info!("  Nodes: {}", filtered.node_count());
-    info!("  Edges: {}", filtered.edge_count());
+fn cmd_export_editor_script(output: PathBuf, data: String) -> Result<()> {
+    std::fs::write(&output, editor_data::editor_script(&data))?;
+    info!("{} Editor export script saved to: {}", glyph(Glyph::Ok), output.display());
+    info!("Run it in the editor (Tools > Execute Python Script) or headless:");
+    info!("  UnrealEditor-Cmd <Project>.uproject -run=pythonscript -script=\"{}\"", output.display());
+    info!("Then: ue5-fast-startup analyze --project <Project> --editor-data {}", data);
+    Ok(())
+}
 
-    if let Some(output_path) = output {
-        let dot = filtered.to_dot();
-        std::fs::write(&output_path, dot)?;
-        info!("Graph saved to: {}", output_path.display());
+fn cmd_script_run(
+    script: PathBuf,
+    project: PathBuf,
+    output: Option<PathBuf>,
+    format: OutputFormat,
+    args: Vec<String>,
+) -> Result<()> {
+    info!("Running script {} on {}", script.display(), project.display());
+
+    let config = ProjectConfig::load(&project)?;
+    let assets = AssetScanner::new(&project)?.with_config(&config).scan_all(None)?;
+    let graph = DependencyGraph::build_from_assets(&project, &assets)?;
+    let roots = attribution::startup_roots(&project, &assets, &config.startup);
+    let name = project.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    let report = ScriptContext::build(&name, &graph, &assets, &roots).run_file(&script, &args)?;
+
+    for line in &report.output {
+        println!("{}", line);
+    }
+    for finding in &report.findings {
+        let location = finding.location.as_deref().map(|l| format!(" ({})", l)).unwrap_or_default();
+        match finding.severity {
+            Severity::Error => warn!("{} {}{}", glyph(Glyph::Fail), finding.message, location),
+            Severity::Warning => warn!("{} {}{}", glyph(Glyph::Warn), finding.message, location),
+            Severity::Note => info!("{}{}", finding.message, location),
+        }
+    }
+    if let Some(output) = output {
+        write_report(&report, format, &output)?;
+        info!("Script report saved to: {}", output.display());
     }
 
+    let errors = report.errors();
+    if errors > 0 {
+        anyhow::bail!("{} reported {} error finding(s)", script.display(), errors);
+    }
+    info!("{} Script finished with {} finding(s)", glyph(Glyph::Ok), report.findings.len());
     Ok(())
 }
 
-fn cmd_bench(project: PathBuf, iterations: u32) -> Result<()> {
+fn cmd_bench(project: PathBuf, iterations: u32, direct_io: bool, cold: bool, print: PrintFormat) -> Result<()> {
     info!("Benchmarking with {} iterations", iterations);
+    if direct_io {
+        info!("Direct I/O: hashing bypasses the OS page cache");
+    }
+    let config = ProjectConfig::load(&project)?;
+
+    // Files touched by the hash benchmark, evicted when system-wide flushing is denied
+    let cold_paths: Vec<PathBuf> = if cold {
+        AssetScanner::new(&project)?
+            .with_config(&config)
+            .scan_all(None)?
+            .into_iter()
+            .take(100)
+            .map(|a| a.path)
+            .collect()
+    } else {
+        Vec::new()
+    };
 
-    let mut scan_times = Vec::new();
-    let mut hash_times = Vec::new();
+    let mut report = BenchReport {
+        project_name: project.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default(),
+        hashed_files: 0,
+        direct_io,
+        cold,
+        iterations: Vec::new(),
+    };
 
     for i in 1..=iterations {
         info!("Iteration {}/{}", i, iterations);
+        if cold {
+            let paths: Vec<&Path> = cold_paths.iter().map(|p| p.as_path()).collect();
+            let dropped = drop_page_cache(&paths);
+            info!("  Cold start: {}", dropped.describe());
+            if dropped == CacheDrop::Unsupported && !direct_io {
+                warn!("  Results include OS cache hits; add --direct-io for cold reads");
+            }
+        }
 
         // Benchmark scanning
         let start = std::time::Instant::now();
-        let scanner = AssetScanner::new(&project)?;
+        let scanner = AssetScanner::new(&project)?.with_config(&config);
         let assets = scanner.scan_all(None)?;
         let scan_time = start.elapsed();
-        scan_times.push(scan_time);
 
         // Benchmark hashing
         let start = std::time::Instant::now();
-        for asset in assets.iter().take(100) {
-            let _ = ue5_fast_startup::hash::hash_file(&asset.path);
+        let hashed = &assets[..assets.len().min(100)];
+        for asset in hashed {
+            let _ = if direct_io {
+                ue5_fast_startup::hash::hash_file_direct(&asset.path)
+            } else {
+                hash_file(&asset.path)
+            };
         }
         let hash_time = start.elapsed();
-        hash_times.push(hash_time);
+        report.hashed_files = hashed.len();
+        report.iterations.push(BenchIteration {
+            iteration: i,
+            assets: assets.len(),
+            scan_secs: scan_time.as_secs_f64(),
+            hash_secs: hash_time.as_secs_f64(),
+        });
     }
 
-    let avg_scan: f64 = scan_times.iter().map(|d| d.as_secs_f64()).sum::<f64>() / iterations as f64;
-    let avg_hash: f64 = hash_times.iter().map(|d| d.as_secs_f64()).sum::<f64>() / iterations as f64;
-
     info!("Results:");
-    info!("  Average scan time: {:.3}s", avg_scan);
-    info!("  Average hash time (100 assets): {:.3}s", avg_hash);
+    info!("  Average scan time: {:.3}s", report.average_scan_secs());
+    info!("  Average hash time ({} assets): {:.3}s", report.hashed_files, report.average_hash_secs());
 
+    print_report(&report, print)?;
     Ok(())
 }
 
 /// TURBO mode - ultra-fast cache building with sampling
-fn cmd_turbo(project: PathBuf, output: PathBuf) -> Result<()> {
+fn cmd_turbo(project: PathBuf, output: PathBuf, strategy: Option<TurboStrategy>) -> Result<()> {
     use rayon::prelude::*;
     use std::time::Instant;
 
-    info!("⚡ TURBO MODE - Ultra-fast cache building");
+    info!("{} TURBO MODE - Ultra-fast cache building", glyph(Glyph::Turbo));
     info!("Project: {}", project.display());
 
     let start = Instant::now();
 
     // Step 1: Fast path-only scan
     info!("[1/3] Turbo scanning...");
-    let scanner = AssetScanner::new(&project)?;
+    let scanner = AssetScanner::new(&project)?.with_config(&ProjectConfig::load(&project)?);
     let paths = scanner.scan_paths_only()?;
     info!("  Found {} assets in {:.2}ms", paths.len(), start.elapsed().as_millis());
 
     // Step 2: Parallel turbo hashing with sampling
-    info!("[2/3] Turbo hashing with sampling...");
+    let strategy = strategy
+        .unwrap_or_else(|| TurboStrategy::for_storage(HardwareProfile::detect(&project).storage));
+    info!("[2/3] Turbo hashing with sampling ({})...", strategy.algorithm());
     let hash_start = Instant::now();
     
     let hashes: Vec<_> = paths
         .par_iter()
         .filter_map(|path| {
-            ue5_fast_startup::hash::turbo_hash(path)
+            turbo_hash_with(path, strategy)
                 .ok()
                 .map(|h| (path.clone(), h.as_u64()))
         })
@@ -390,7 +3169,8 @@ fn cmd_turbo(project: PathBuf, output: PathBuf) -> Result<()> {
         .unwrap_or_else(|| "Unknown".to_string());
 
     let mut cache = ue5_fast_startup::cache::StartupCache::new(&project_name);
-    
+    cache.hash_algorithm = strategy.algorithm().to_string();
+
     for (path, hash) in hashes {
         let relative = path.strip_prefix(&project)
             .unwrap_or(&path)
@@ -403,7 +3183,7 @@ fn cmd_turbo(project: PathBuf, output: PathBuf) -> Result<()> {
         
         cache.assets.push(ue5_fast_startup::cache::CachedAsset {
             relative_path: relative,
-            asset_type: ue5_fast_startup::scanner::AssetType::from_extension(ext),
+            asset_type: AssetType::from_extension(ext),
             content_hash: hash,
             size_bytes: std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0),
             load_order: 0,
@@ -414,7 +3194,7 @@ fn cmd_turbo(project: PathBuf, output: PathBuf) -> Result<()> {
     cache.save(&output)?;
 
     let total_time = start.elapsed();
-    info!("⚡ TURBO COMPLETE in {:.2}ms", total_time.as_millis());
+    info!("{} TURBO COMPLETE in {:.2}ms", glyph(Glyph::Turbo), total_time.as_millis());
     info!("  Assets: {}", cache.asset_count());
     info!("  Cache size: {} KB", cache.size_bytes() / 1024);
     info!("  Throughput: {:.0} assets/sec", cache.asset_count() as f64 / total_time.as_secs_f64());
@@ -423,53 +3203,86 @@ fn cmd_turbo(project: PathBuf, output: PathBuf) -> Result<()> {
 }
 
 /// Quick verify - fast change detection using turbo hashing
-fn cmd_quick_verify(cache_path: PathBuf, project: PathBuf) -> Result<()> {
+fn cmd_quick_verify(cache_path: PathBuf, project: PathBuf, since_last: bool, print: PrintFormat) -> Result<()> {
     use rayon::prelude::*;
     use std::time::Instant;
 
-    info!("⚡ Quick verify: {}", cache_path.display());
+    info!("{} Quick verify: {}", glyph(Glyph::Turbo), cache_path.display());
 
     let start = Instant::now();
+    let started_at = chrono::Utc::now().timestamp().max(0) as u64;
 
     let cache = ue5_fast_startup::cache::StartupCache::load(&cache_path)?;
-    
-    // Build hash map of cached assets
-    let cached_hashes: std::collections::HashMap<_, _> = cache.assets
-        .iter()
-        .map(|a| (a.relative_path.clone(), a.content_hash))
-        .collect();
+    let turbo = TurboStrategy::from_algorithm(&cache.hash_algorithm);
+    let algorithm = if turbo.is_some() { HashAlgorithm::default() } else { cache.algorithm()? };
+
+    let last = if since_last {
+        let last = VerifyState::load(&project, &cache_path);
+        match &last {
+            Some(last) => info!(
+                "Reusing {} results from {}",
+                last.len(),
+                chrono::DateTime::from_timestamp(last.verified_at() as i64, 0)
+                    .map(|t| t.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+                    .unwrap_or_default()
+            ),
+            None => info!("No earlier quick verify of this cache, checking every file"),
+        }
+        last
+    } else {
+        None
+    };
 
     // Quick scan current assets
-    let scanner = AssetScanner::new(&project)?;
+    let scanner = AssetScanner::new(&project)?.with_config(&ProjectConfig::load(&project)?);
     let paths = scanner.scan_paths_only()?;
 
-    // Parallel quick hash and compare
-    let changes: Vec<_> = paths
+    // Parallel quick hash and compare, skipping files the last run already
+    // judged and nobody touched since
+    let verdicts: Vec<(String, FileStamp, bool, bool)> = paths
         .par_iter()
         .filter_map(|path| {
-            let relative = path.strip_prefix(&project)
-                .ok()?
-                .to_string_lossy()
-                .to_string();
-            
-            let current_hash = ue5_fast_startup::hash::turbo_hash(path).ok()?.as_u64();
-            
-            match cached_hashes.get(&relative) {
-                Some(&cached_hash) if cached_hash != current_hash => {
-                    Some(relative)
-                }
-                None => Some(relative), // New file
-                _ => None, // Unchanged
+            let relative = path.strip_prefix(&project).ok()?.to_string_lossy().to_string();
+            let stamp = FileStamp::of(path)?;
+            if let Some(changed) = last.as_ref().and_then(|last| last.verdict(&relative, stamp)) {
+                return Some((relative, stamp, changed, false));
+            }
+            if !cache.may_contain(Path::new(&relative)) {
+                return Some((relative, stamp, true, true)); // New file
             }
+
+            // Rehash the way the cache was hashed
+            let current_hash = match turbo {
+                Some(strategy) => turbo_hash_with(path, strategy),
+                None => hash_file_with(path, algorithm),
+            }
+            .ok()?
+            .as_u64();
+
+            let changed = !matches!(cache.find(Path::new(&relative)), Some(cached) if cached.content_hash == current_hash);
+            Some((relative, stamp, changed, true))
         })
         .collect();
 
+    let mut state = VerifyState::new(&cache_path, started_at);
+    let mut changes = Vec::new();
+    let mut inspected = 0;
+    for (relative, stamp, changed, rehashed) in verdicts {
+        inspected += rehashed as usize;
+        if changed {
+            changes.push(relative.clone());
+        }
+        state.record(relative, stamp, changed);
+    }
+    changes.sort();
+
     let elapsed = start.elapsed();
+    let files = state.len();
 
     if changes.is_empty() {
-        info!("✓ No changes detected in {:.2}ms", elapsed.as_millis());
+        info!("{} No changes detected in {:.2}ms", glyph(Glyph::Ok), elapsed.as_millis());
     } else {
-        info!("⚠ {} changes detected in {:.2}ms", changes.len(), elapsed.as_millis());
+        info!("{} {} changes detected in {:.2}ms", glyph(Glyph::Warn), changes.len(), elapsed.as_millis());
         for change in changes.iter().take(10) {
             info!("  - {}", change);
         }
@@ -477,6 +3290,394 @@ fn cmd_quick_verify(cache_path: PathBuf, project: PathBuf) -> Result<()> {
             info!("  ... and {} more", changes.len() - 10);
         }
     }
+    if since_last {
+        info!("  Rehashed {} of {} files", inspected, files);
+    }
+
+    // Every run leaves results for the next --since-last
+    if let Err(e) = state.save(&project) {
+        warn!("{} Could not save quick verify results: {}", glyph(Glyph::Warn), e);
+    }
+
+    let result = QuickVerifyResult {
+        cache: cache_path,
+        changed_assets: changes,
+        files,
+        rehashed: inspected,
+        elapsed_ms: elapsed.as_secs_f64() * 1000.0,
+    };
+    print_report(&result, print)?;
+    Ok(())
+}
+
+fn cmd_calibrate(
+    project: PathBuf,
+    log: Option<PathBuf>,
+    insights_csv: Option<PathBuf>,
+    run: Option<String>,
+    storage: Option<StorageTier>,
+    dry_run: bool,
+) -> Result<()> {
+    let storage = storage.unwrap_or_else(|| HardwareProfile::detect(&project).storage);
+    info!("Calibrating load time estimates: {} ({})", project.display(), storage);
+
+    let mut timings: Vec<Timing> = Vec::new();
+    if let Some(command) = run {
+        let log_path = project.join(ue5_fast_startup::STATE_DIR).join("calibrate.log");
+        info!("Running measured startup: {}", command);
+        timings.extend(calibration::parse_log(&calibration::run_measured_startup(&command, &log_path)?));
+    }
+    if let Some(path) = log {
+        timings.extend(calibration::parse_log(&std::fs::read_to_string(&path)?));
+    }
+    if let Some(path) = insights_csv {
+        timings.extend(calibration::parse_insights_csv(&std::fs::read_to_string(&path)?)?);
+    }
+    if timings.is_empty() {
+        anyhow::bail!("No timings found; pass --log, --insights-csv or --run");
+    }
+
+    let mut config = ProjectConfig::load(&project)?;
+    let scanner = AssetScanner::new(&project)?.with_config(&config);
+    let assets = scanner.scan_all(None)?;
+    let graph = DependencyGraph::build_from_assets(&project, &assets)?;
+    let observations = calibration::build_observations(&project, &assets, &graph, &timings, &config.startup);
+    if observations.is_empty() {
+        anyhow::bail!("None of the {} timings matched packages in this project", timings.len());
+    }
+    info!("Fitting {} of {} timings", observations.len(), timings.len());
+
+    let before = config.load_time_model(Some(storage));
+    let after = calibration::fit(&observations, &before);
+
+    for fit in calibration::compare(&observations, &before, &after).iter().take(10) {
+        info!(
+            "  {}: measured {:.0} ms, estimated {:.0} -> {:.0} ms",
+            fit.label, fit.measured_ms, fit.estimated_before_ms, fit.estimated_after_ms
+        );
+    }
+    info!("  ms per asset: {:.2}", after.ms_per_asset);
+    for (asset_type, ms) in &after.ms_per_mb {
+        info!("  ms per MB ({}): {:.2}", asset_type, ms);
+    }
+
+    if dry_run {
+        info!("Dry run, configuration not written");
+    } else {
+        config.estimator.insert(storage, after);
+        let path = config.save(&project)?;
+        info!("Calibration saved to: {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn cmd_pak_order(
+    project: PathBuf,
+    build: PathBuf,
+    output: Option<PathBuf>,
+    format: OutputFormat,
+) -> Result<()> {
+    info!("Validating pak ordering: {}", build.display());
+
+    let mut paks = Vec::new();
+    for path in find_pak_files(&build) {
+        match PakFile::open(&path) {
+            Ok(pak) => {
+                info!("  {} (v{}, {} entries)", path.display(), pak.version, pak.entries.len());
+                paks.push(pak);
+            }
+            Err(e) => warn!("  Skipping {}: {}", path.display(), e),
+        }
+    }
+    if paks.is_empty() {
+        anyhow::bail!("No readable .pak files found in {}", build.display());
+    }
+
+    let mut graph = DependencyGraph::build(&project, &ProjectConfig::load(&project)?)?.filter_startup_critical();
+    graph.compute_load_order();
+    let load_order = graph.relative_load_order(&project);
+
+    let project_name = project
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let result = OrderValidation::evaluate(&project_name, &paks, &load_order);
+
+    info!("Startup assets placed: {}/{}", result.placed.len(), load_order.len());
+    info!("  Seek distance: {:.1} MB", result.total_seek_bytes as f64 / MB);
+    info!("  Backward seeks: {}", result.backward_seeks);
+    info!("  Pak switches: {}", result.pak_switches);
+    info!("  In-order reads: {:.1}%", result.in_order_ratio * 100.0);
+    if result.ordering_applied {
+        info!("{} Startup reads follow the computed load order", glyph(Glyph::Ok));
+    } else {
+        warn!("{} Physical order does not follow the computed load order; was the ordering file applied?", glyph(Glyph::Fail));
+    }
+
+    if let Some(output_path) = output {
+        write_report(&result, format, &output_path)?;
+        info!("Result saved to: {} ({})", output_path.display(), format);
+    }
+
+    Ok(())
+}
+
+fn cmd_store_manifest(
+    manifest: PathBuf,
+    base: Option<PathBuf>,
+    output: Option<PathBuf>,
+    format: OutputFormat,
+) -> Result<()> {
+    info!("Reading store manifest: {}", manifest.display());
+    let patched = StoreManifest::open(&manifest)?;
+    let base = base.map(|path| StoreManifest::open(&path)).transpose()?;
+    let report = ManifestReport::new(patched, base.as_ref());
+
+    let manifest = &report.manifest;
+    info!("{} {} build {}", manifest.store.display_name(), manifest.app, manifest.build);
+    info!("  Files: {} ({:.1} MB installed)", manifest.files.len(), manifest.install_bytes() as f64 / MB);
+    info!("  Chunks: {} ({:.1} MB download)", manifest.chunk_count(), manifest.download_bytes() as f64 / MB);
+    info!("  Containers: {}", report.containers().count());
+    if let Some(delta) = &report.delta {
+        info!(
+            "Patch from {}: {} changed, {} added, {} removed",
+            delta.base_build,
+            delta.changed.len(),
+            delta.added.len(),
+            delta.removed.len()
+        );
+        info!("  Download: {:.1} MB", delta.download_bytes as f64 / MB);
+    }
+    let loose = report.loose_packages();
+    if loose == 0 {
+        info!("{} Cooked content ships in containers", glyph(Glyph::Ok));
+    } else {
+        warn!("{} {} cooked packages ship as loose files", glyph(Glyph::Warn), loose);
+    }
+
+    if let Some(output_path) = output {
+        write_report(&report, format, &output_path)?;
+        info!("Report saved to: {} ({})", output_path.display(), format);
+    }
+
+    Ok(())
+}
+
+fn cmd_projects_discover(roots: Vec<PathBuf>, roots_only: bool, output: Option<PathBuf>) -> Result<()> {
+    info!("Discovering UE projects...");
+
+    let projects = ProjectDiscovery::new()
+        .with_roots(roots)
+        .include_system(!roots_only)
+        .discover()?;
+
+    info!("Found {} projects", projects.len());
+    for project in &projects {
+        info!("  {} [{}] {}", project.name, project.source.as_str(), project.uproject.display());
+    }
+
+    if let Some(output_path) = output {
+        let json = serde_json::to_string_pretty(&projects)?;
+        std::fs::write(&output_path, json)?;
+        info!("Project list saved to: {}", output_path.display());
+    }
+
+    Ok(())
+}
+
+fn cmd_projects_validate(path: PathBuf) -> Result<()> {
+    let project = Project::open(&path)?;
+
+    info!("{} Valid project: {}", glyph(Glyph::Ok), project.name());
+    info!("  Descriptor: {}", project.uproject_path.display());
+    info!("  Engine association: {}", project.engine_association());
+    info!("  Modules: {}", project.descriptor.modules.len());
+    for module in &project.descriptor.modules {
+        info!(
+            "    - {} ({}, {})",
+            module.name,
+            module.module_type,
+            module.loading_phase.as_deref().unwrap_or("Default")
+        );
+    }
+    info!("  Enabled plugins: {}", project.enabled_plugins().count());
+
+    let issues = project.issues();
+    if !issues.is_empty() {
+        info!("{} {} issues:", glyph(Glyph::Warn), issues.len());
+        for issue in &issues {
+            info!("  - {}", issue);
+        }
+    }
+
+    Ok(())
+}
+
+fn cmd_schema(kind: Option<SchemaKind>, output: Option<PathBuf>) -> Result<()> {
+    let schema = match kind {
+        Some(kind) => kind.schema(),
+        None => schema::all_schemas(),
+    };
+    let json = serde_json::to_string_pretty(&schema)?;
+    match output {
+        Some(path) => {
+            std::fs::write(&path, json + "\n")?;
+            info!("Schema v{} saved to: {}", schema::SCHEMA_VERSION, path.display());
+        }
+        None => println!("{}", json),
+    }
+    Ok(())
+}
+
+fn cmd_init(project: Option<PathBuf>, defaults: bool) -> Result<()> {
+    let project = match project {
+        Some(project) => Project::open(&project)?,
+        None => match Project::open_optional(Path::new("."))? {
+            Some(project) => project,
+            None => {
+                let discovered = ProjectDiscovery::new().discover()?;
+                let Some(first) = discovered.first() else {
+                    anyhow::bail!("No .uproject here and none discovered; pass --project");
+                };
+                info!("Using discovered project {} ({} found)", first.uproject.display(), discovered.len());
+                Project::open(&first.uproject)?
+            }
+        },
+    };
+    info!("Setting up {} at {}", project.name(), project.root.display());
+
+    let mut config = ProjectConfig::load(&project.root)?;
+    let storage = match config.hardware_profile(&project.root).storage {
+        StorageTier::Unknown => None,
+        tier => Some(tier),
+    };
+    let detected = InitAnswers {
+        storage,
+        startup_maps: match config.scenarios.get(wizard::STARTUP_SCENARIO) {
+            Some(scenario) => scenario.roots.clone(),
+            None => wizard::detect_startup_maps(&project.root),
+        },
+        max_startup_mb: config.budgets.max_startup_bytes.map(|b| b as f64 / MB),
+        max_startup_assets: config.budgets.max_startup_assets,
+    };
+
+    let stdin = std::io::stdin();
+    let answers = Prompter::new(stdin.lock(), std::io::stdout(), defaults).run(&detected)?;
+    answers.apply(&mut config);
+    let path = config.save(&project.root)?;
+    info!("{} Config saved to: {}", glyph(Glyph::Ok), path.display());
+
+    let commands = wizard::recommended_commands(&project.root);
+    let commands_path = project.root.join(ue5_fast_startup::STATE_DIR).join("commands.txt");
+    if let Some(parent) = commands_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&commands_path, commands.join("\n") + "\n")?;
+    info!("Recommended commands (saved to {}):", commands_path.display());
+    for command in &commands {
+        info!("  {}", command);
+    }
+    Ok(())
+}
+
+fn cmd_telemetry(command: TelemetryCommand) -> Result<()> {
+    let dir = usage::user_dir().ok_or_else(|| {
+        FastStartupError::ConfigError(format!("No home directory; set {}", usage::HOME_ENV))
+    })?;
+    let mut settings = TelemetrySettings::load(&dir)?;
+
+    match command {
+        TelemetryCommand::Enable { upload_url } => {
+            settings.enabled = true;
+            if upload_url.is_some() {
+                settings.upload_url = upload_url;
+            }
+            let path = settings.save(&dir)?;
+            info!("{} Usage recording enabled ({})", glyph(Glyph::Ok), path.display());
+            info!("  Records: {}", dir.join(usage::USAGE_FILE).display());
+        }
+        TelemetryCommand::Disable => {
+            settings.enabled = false;
+            settings.save(&dir)?;
+            info!("Usage recording disabled");
+        }
+        TelemetryCommand::Status => {
+            let env = std::env::var(usage::TELEMETRY_ENV).ok();
+            let enabled = settings.is_enabled(env.as_deref());
+            info!("Usage recording: {}", if enabled { "enabled" } else { "disabled" });
+            if let Some(url) = &settings.upload_url {
+                info!("  Upload URL: {}", url);
+            }
+            let records = usage::load(&dir)?;
+            info!("  Records: {} in {}", records.len(), dir.join(usage::USAGE_FILE).display());
+            for (command, summary) in usage::summarize(&records) {
+                info!(
+                    "  {}: {} runs, {} failed, median {} ms, max {} ms",
+                    command, summary.runs, summary.failures, summary.median_ms, summary.max_ms
+                );
+            }
+        }
+        TelemetryCommand::Upload { url } => {
+            let Some(url) = url.or(settings.upload_url) else {
+                anyhow::bail!("No upload URL; pass --url or `telemetry enable --upload-url`");
+            };
+            let records = usage::load(&dir)?;
+            if records.is_empty() {
+                info!("No usage records to upload");
+                return Ok(());
+            }
+            usage::upload(&url, &records)?;
+            usage::clear(&dir)?;
+            info!("{} Uploaded {} usage records", glyph(Glyph::Ok), records.len());
+        }
+        TelemetryCommand::Clear => {
+            usage::clear(&dir)?;
+            info!("Usage records deleted");
+        }
+    }
+    Ok(())
+}
+
+fn cmd_tags_import(project: PathBuf, csvs: Vec<PathBuf>, no_collections: bool) -> Result<()> {
+    let mut imported = AssetTags::default();
+
+    if !no_collections {
+        let collections = tags::load_collections(&project)?;
+        info!("Collections: {} tagged packages", collections.len());
+        imported.merge(collections);
+    }
+    for csv in &csvs {
+        let text = std::fs::read_to_string(csv)?;
+        let parsed = tags::parse_tag_csv(&text)?;
+        info!("{}: {} tagged packages", csv.display(), parsed.len());
+        imported.merge(parsed);
+    }
+
+    let path = imported.save(&project)?;
+    let names = imported.tag_names();
+    info!("{} {} packages tagged with {} tags", glyph(Glyph::Ok), imported.len(), names.len());
+    for name in names {
+        info!("  - {}", name);
+    }
+    info!("Saved to: {}", path.display());
+    Ok(())
+}
+
+fn cmd_testgen(output: PathBuf, spec: TestProjectSpec) -> Result<()> {
+    if output.exists() {
+        anyhow::bail!("{} already exists", output.display());
+    }
+
+    info!("Generating synthetic project: {}", output.display());
+    let start = std::time::Instant::now();
+    let project = testgen::generate(&output, &spec)?;
+
+    info!("{} Generated in {:.2}s", glyph(Glyph::Ok), start.elapsed().as_secs_f64());
+    info!("  Assets: {}", project.assets);
+    info!("  Maps: {}", project.maps);
+    info!("  Dependencies: {}", project.dependencies);
+    info!("  Total size: {:.2} MB", project.total_bytes as f64 / (1024.0 * 1024.0));
 
     Ok(())
 }
@@ -0,0 +1,110 @@
+//! Memory Budget Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Chooses between parsing every package of the dependency graph at once
+//! and streaming them in bounded shards, from the memory available when the
+//! build starts
+
+use crate::hardware::available_ram_bytes;
+
+/// Memory budget in MB, overriding detection (e.g. to test streaming mode)
+pub const MEMORY_ENV: &str = "UEFAST_MEMORY_MB";
+
+/// Rough peak bytes per package while its name table and imports are held
+pub const BYTES_PER_PACKAGE: u64 = 64 * 1024;
+
+/// Share of available memory the graph build may plan to use
+const BUDGET_SHARE: f64 = 0.5;
+
+/// Smallest shard worth a parallel pass
+pub const MIN_SHARD_PACKAGES: usize = 512;
+
+const MB: u64 = 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryPlan {
+    /// Packages parsed before their imports are turned into edges
+    pub shard_size: usize,
+    pub threads: usize,
+    /// Memory available when planned, if known
+    pub available_bytes: Option<u64>,
+}
+
+impl MemoryPlan {
+    /// Plan for `packages` with this machine's available memory and the
+    /// current thread pool
+    pub fn detect(packages: usize) -> Self {
+        Self::for_budget(packages, available_bytes(), rayon::current_num_threads())
+    }
+
+    /// One pass when the estimate fits half of `available` (or memory is
+    /// unknown), else shards sized to that half with half the threads
+    pub fn for_budget(packages: usize, available: Option<u64>, threads: usize) -> Self {
+        let one_pass = Self {
+            shard_size: packages.max(1),
+            threads,
+            available_bytes: available,
+        };
+        let Some(available) = available else {
+            return one_pass;
+        };
+
+        let budget = (available as f64 * BUDGET_SHARE) as u64;
+        if Self::estimate_bytes(packages) <= budget {
+            return one_pass;
+        }
+        Self {
+            shard_size: ((budget / BYTES_PER_PACKAGE) as usize).max(MIN_SHARD_PACKAGES),
+            threads: (threads / 2).max(1),
+            available_bytes: Some(available),
+        }
+    }
+
+    pub fn estimate_bytes(packages: usize) -> u64 {
+        packages as u64 * BYTES_PER_PACKAGE
+    }
+
+    pub fn is_streaming(&self, packages: usize) -> bool {
+        self.shard_size < packages
+    }
+
+    pub fn describe(&self) -> String {
+        let available = self
+            .available_bytes
+            .map(|b| format!("{} MB available", b / MB))
+            .unwrap_or_else(|| "unknown memory".to_string());
+        format!("shards of {} packages on {} threads ({})", self.shard_size, self.threads, available)
+    }
+}
+
+/// `UEFAST_MEMORY_MB`, else the detected available memory
+pub fn available_bytes() -> Option<u64> {
+    std::env::var(MEMORY_ENV)
+        .ok()
+        .and_then(|mb| mb.trim().parse::<u64>().ok())
+        .map(|mb| mb * MB)
+        .or_else(available_ram_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_streams_when_over_budget() {
+        const GB: u64 = 1024 * MB;
+        let fits = MemoryPlan::for_budget(10_000, Some(8 * GB), 16);
+        assert!(!fits.is_streaming(10_000));
+        assert_eq!(fits.threads, 16);
+
+        let tight = MemoryPlan::for_budget(200_000, Some(2 * GB), 16);
+        assert!(tight.is_streaming(200_000));
+        assert_eq!(tight.shard_size, 16_384);
+        assert_eq!(tight.threads, 8);
+
+        let unknown = MemoryPlan::for_budget(200_000, None, 4);
+        assert!(!unknown.is_streaming(200_000));
+        assert_eq!(MemoryPlan::for_budget(0, Some(0), 1).threads, 1);
+    }
+}
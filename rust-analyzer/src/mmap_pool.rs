@@ -0,0 +1,191 @@
+//! Mmap Pool Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Shared file mappings with a cap on how many are mapped at once, reused
+//! across the hash and parse passes over the same package
+
+use crate::Result;
+use memmap2::Mmap;
+use std::collections::{HashMap, VecDeque};
+use std::fs::File;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+use std::time::SystemTime;
+
+/// Mappings kept by the global pool
+pub const DEFAULT_MAX_MAPPED: usize = 256;
+
+struct Entry {
+    mmap: Arc<Mmap>,
+    len: u64,
+    modified: Option<SystemTime>,
+}
+
+#[derive(Default)]
+struct PoolState {
+    entries: HashMap<PathBuf, Entry>,
+    /// Least recently used first
+    recency: VecDeque<PathBuf>,
+    hits: u64,
+    misses: u64,
+}
+
+impl PoolState {
+    fn touch(&mut self, path: &Path) {
+        if let Some(pos) = self.recency.iter().position(|p| p == path) {
+            let path = self.recency.remove(pos).expect("position is in range");
+            self.recency.push_back(path);
+        }
+    }
+
+    /// Drop the least recently used mapping nobody is reading
+    fn evict_idle(&mut self) -> bool {
+        let idle = self.recency.iter().position(|path| {
+            self.entries.get(path).is_some_and(|e| Arc::strong_count(&e.mmap) == 1)
+        });
+        match idle {
+            Some(pos) => {
+                let path = self.recency.remove(pos).expect("position is in range");
+                self.entries.remove(&path);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+pub struct MmapPool {
+    max_mapped: usize,
+    state: Mutex<PoolState>,
+    released: Condvar,
+}
+
+/// A pooled mapping; the pool may unmap the file once every handle is dropped
+pub struct MappedFile<'a> {
+    /// Always `Some` until dropped
+    mmap: Option<Arc<Mmap>>,
+    pool: &'a MmapPool,
+}
+
+impl Deref for MappedFile<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.mmap.as_deref().expect("mapping is present until drop")
+    }
+}
+
+impl Drop for MappedFile<'_> {
+    fn drop(&mut self) {
+        // Release the mapping before waking waiters, and notify under the
+        // lock so a waiter cannot miss it between its check and its wait
+        self.mmap.take();
+        let _state = self.pool.state.lock();
+        self.pool.released.notify_all();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolStats {
+    pub mapped: usize,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl MmapPool {
+    /// Pool holding at most `max_mapped` mappings (at least one)
+    pub fn new(max_mapped: usize) -> Self {
+        Self {
+            max_mapped: max_mapped.max(1),
+            state: Mutex::new(PoolState::default()),
+            released: Condvar::new(),
+        }
+    }
+
+    /// Process-wide pool, sized so every rayon worker can hold a mapping
+    pub fn global() -> &'static MmapPool {
+        static POOL: OnceLock<MmapPool> = OnceLock::new();
+        POOL.get_or_init(|| MmapPool::new(DEFAULT_MAX_MAPPED.max(rayon::current_num_threads() * 2)))
+    }
+
+    /// Mapping of `path`, reused if the file has not changed since it was
+    /// mapped. Blocks while the pool is full of mappings in use.
+    pub fn map(&self, path: &Path) -> Result<MappedFile<'_>> {
+        let file = File::open(path)?;
+        let metadata = file.metadata()?;
+        let (len, modified) = (metadata.len(), metadata.modified().ok());
+
+        let mut state = self.state.lock().expect("mmap pool poisoned");
+        if let Some(entry) = state.entries.get(path) {
+            if entry.len == len && entry.modified == modified {
+                let mmap = Arc::clone(&entry.mmap);
+                state.hits += 1;
+                state.touch(path);
+                return Ok(MappedFile { mmap: Some(mmap), pool: self });
+            }
+            state.entries.remove(path);
+            state.recency.retain(|p| p != path);
+        }
+
+        while state.entries.len() >= self.max_mapped && !state.evict_idle() {
+            state = self.released.wait(state).expect("mmap pool poisoned");
+        }
+
+        let mmap = Arc::new(unsafe { Mmap::map(&file)? });
+        state.misses += 1;
+        state.entries.insert(path.to_path_buf(), Entry { mmap: Arc::clone(&mmap), len, modified });
+        state.recency.push_back(path.to_path_buf());
+        Ok(MappedFile { mmap: Some(mmap), pool: self })
+    }
+
+    /// Unmap everything not currently in use
+    pub fn clear(&self) {
+        let mut state = self.state.lock().expect("mmap pool poisoned");
+        while state.evict_idle() {}
+    }
+
+    pub fn stats(&self) -> PoolStats {
+        let state = self.state.lock().expect("mmap pool poisoned");
+        PoolStats {
+            mapped: state.entries.len(),
+            hits: state.hits,
+            misses: state.misses,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pool_reuses_and_caps_mappings() {
+        let dir = std::env::temp_dir().join(format!("uefast_mmap_pool_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let paths: Vec<PathBuf> = (0..3)
+            .map(|i| {
+                let path = dir.join(format!("{}.bin", i));
+                std::fs::write(&path, vec![i as u8; 64]).unwrap();
+                path
+            })
+            .collect();
+
+        let pool = MmapPool::new(2);
+        {
+            let first = pool.map(&paths[0]).unwrap();
+            let again = pool.map(&paths[0]).unwrap();
+            assert_eq!(first[0], 0);
+            assert_eq!(again.len(), 64);
+        }
+        pool.map(&paths[1]).unwrap();
+        // Full pool: the idle least recently used mapping (0) is evicted
+        assert_eq!(pool.map(&paths[2]).unwrap()[0], 2);
+        assert_eq!(pool.stats(), PoolStats { mapped: 2, hits: 1, misses: 3 });
+
+        pool.clear();
+        assert_eq!(pool.stats().mapped, 0);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
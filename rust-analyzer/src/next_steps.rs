@@ -0,0 +1,273 @@
+//! Next Steps Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Short ranked "next actions" printed after `analyze` and `verify`, one per
+//! recommendation rule, with a longer explanation per rule for `--explain`
+
+use crate::analyzer::{AnalysisReport, Priority, Recommendation};
+use crate::cache::VerifyResult;
+use std::collections::BTreeMap;
+
+/// Actions shown in the exit summary
+pub const MAX_NEXT_ACTIONS: usize = 5;
+
+/// Rule name (the recommendation category), what to do, and why it helps
+const RULES: &[(&str, &str, &str)] = &[
+    (
+        "Startup",
+        "defer assets that need not load at boot",
+        "A large share of the project is loaded before the first frame. Move assets that are \
+         only needed later behind soft references or async loads so the boot path shrinks.",
+    ),
+    (
+        "Preload",
+        "aggregate shared startup subtrees into preload assets",
+        "Several startup assets hard-reference the same heavy subtree. Referencing it from one \
+         primary data asset loaded first lets the subtree stream in once, ahead of its users.",
+    ),
+    (
+        "StaleImport",
+        "remove stale hard imports",
+        "These packages import other packages that none of their exports use, usually left \
+         behind by refactors. Resaving them or replacing the reference with a soft one drops \
+         the dead content from the startup closure.",
+    ),
+    (
+        "CaseCollision",
+        "rename assets whose paths differ only by case",
+        "Paths that differ only by case collide on case-sensitive platforms and break cooking. \
+         Rename one side of each pair with a redirector fixup.",
+    ),
+    (
+        "LfsPointer",
+        "fetch Git LFS content",
+        "Some assets are LFS pointer files instead of real content and fail to load. Run \
+         `git lfs pull` and make sure the LFS filter is installed.",
+    ),
+    (
+        "NeverCooked",
+        "fix cook rules of startup assets",
+        "The Asset Manager's primary asset rules keep these startup assets out of shipping \
+         cooks, so they load in the editor and fail in packaged builds. Change the cook rule \
+         or replace the references.",
+    ),
+    (
+        "ChunkAssignment",
+        "move startup assets to chunk 0",
+        "Startup assets assigned to later chunks are missing from the base install and mount \
+         after chunk 0, breaking the pak order the load order assumes. Label them into \
+         chunk 0 with a PrimaryAssetLabel or change the rule's ChunkId.",
+    ),
+    (
+        "LineEndings",
+        "normalize line endings",
+        "Text files with mixed or divergent line endings hash differently across machines, \
+         which makes cache verification report spurious changes. Add a .gitattributes rule.",
+    ),
+    (
+        "DataTable",
+        "split or async-load large startup tables",
+        "Data and curve tables are deserialized in full when loaded. Split large tables by \
+         feature or load them after boot.",
+    ),
+    (
+        "Localization",
+        "load string tables on demand",
+        "String tables referenced at startup are loaded for every culture. Reference them \
+         from the screens that need them instead.",
+    ),
+    (
+        "UI",
+        "soft-reference art for screens not shown at boot",
+        "Widget Blueprints in the startup closure pull in their textures and fonts. Keep the \
+         boot UI light and load other screens' art when they open.",
+    ),
+    (
+        "Modules",
+        "defer blocking work in C++ StartupModule",
+        "Module startup runs before the first frame. Synchronous asset loads, registry scans \
+         and eagerly loaded modules there add directly to boot time; move them to first use \
+         or async loads.",
+    ),
+    (
+        "Textures",
+        "enable texture streaming",
+        "Many textures are loaded in full. Streaming loads only the mips that are visible.",
+    ),
+    (
+        "Blueprints",
+        "move hot Blueprint logic to C++",
+        "A large Blueprint count slows loading and ticking. Move frequently used logic to \
+         native code.",
+    ),
+    (
+        "EngineVersion",
+        "resave packages for the project engine version",
+        "Packages saved with another engine version are converted on every load. Resave them \
+         with the `analyze --resave-list` file.",
+    ),
+    (
+        "StaleCache",
+        "rebuild the startup cache",
+        "Cached assets changed or disappeared since the cache was built, so the cached load \
+         order no longer matches the project. Rebuild it with `cache`.",
+    ),
+];
+
+/// One line of the exit summary
+#[derive(Debug, Clone, PartialEq)]
+pub struct NextAction {
+    /// Rule name accepted by `--explain`
+    pub rule: String,
+    pub priority: Priority,
+    pub findings: usize,
+    pub saves_seconds: f64,
+}
+
+impl NextAction {
+    pub fn describe(&self) -> String {
+        let action = rule(&self.rule).map(|(_, action, _)| *action).unwrap_or("review findings");
+        let mut line = format!("{} ({} finding{})", action, self.findings, if self.findings == 1 { "" } else { "s" });
+        if self.saves_seconds >= 0.05 {
+            line.push_str(&format!(": saves ~{:.1}s", self.saves_seconds));
+        }
+        line
+    }
+}
+
+/// Recommendations grouped by rule, highest priority and impact first
+pub fn from_recommendations(recommendations: &[Recommendation]) -> Vec<NextAction> {
+    let mut by_rule: BTreeMap<&str, NextAction> = BTreeMap::new();
+    for rec in recommendations {
+        let action = by_rule.entry(&rec.category).or_insert_with(|| NextAction {
+            rule: rec.category.clone(),
+            priority: rec.priority,
+            findings: 0,
+            saves_seconds: 0.0,
+        });
+        action.priority = action.priority.min(rec.priority);
+        action.findings += 1;
+        action.saves_seconds += rec.estimated_impact_seconds;
+    }
+
+    let mut actions: Vec<NextAction> = by_rule.into_values().collect();
+    actions.sort_by(|a, b| {
+        a.priority
+            .cmp(&b.priority)
+            .then(b.saves_seconds.total_cmp(&a.saves_seconds))
+    });
+    actions
+}
+
+pub fn from_report(report: &AnalysisReport) -> Vec<NextAction> {
+    from_recommendations(&report.recommendations)
+}
+
+pub fn from_verify(result: &VerifyResult) -> Vec<NextAction> {
+    let mut actions = Vec::new();
+    let stale = result.changed_assets.len() + result.missing_assets.len();
+    if stale > 0 {
+        actions.push(NextAction {
+            rule: "StaleCache".to_string(),
+            priority: Priority::High,
+            findings: stale,
+            saves_seconds: 0.0,
+        });
+    }
+
+    let lfs = result.anomalies.iter().filter(|a| a.kind.is_broken_asset()).count();
+    if lfs > 0 {
+        actions.push(NextAction {
+            rule: "LfsPointer".to_string(),
+            priority: Priority::High,
+            findings: lfs,
+            saves_seconds: 0.0,
+        });
+    }
+    let line_endings = result.anomalies.len() - lfs;
+    if line_endings > 0 {
+        actions.push(NextAction {
+            rule: "LineEndings".to_string(),
+            priority: Priority::Low,
+            findings: line_endings,
+            saves_seconds: 0.0,
+        });
+    }
+    actions
+}
+
+/// Numbered block of at most `limit` actions
+pub fn render(actions: &[NextAction], limit: usize) -> Vec<String> {
+    actions
+        .iter()
+        .take(limit)
+        .enumerate()
+        .map(|(i, action)| format!("{}. [{}] {}", i + 1, action.rule, action.describe()))
+        .collect()
+}
+
+/// Why `name` matters, followed by every matching recommendation
+pub fn explain(name: &str, recommendations: &[Recommendation]) -> Option<Vec<String>> {
+    let (rule, action, why) = rule(name)?;
+    let mut lines = vec![format!("{}: {}", rule, action), why.to_string()];
+    lines.extend(
+        recommendations
+            .iter()
+            .filter(|r| r.category == *rule)
+            .map(|r| format!("  - {}", r.message)),
+    );
+    Some(lines)
+}
+
+/// Every rule name accepted by `--explain`
+pub fn rule_names() -> impl Iterator<Item = &'static str> {
+    RULES.iter().map(|(name, _, _)| *name)
+}
+
+fn rule(name: &str) -> Option<&'static (&'static str, &'static str, &'static str)> {
+    RULES.iter().find(|(rule, _, _)| rule.eq_ignore_ascii_case(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rec(priority: Priority, category: &str, seconds: f64) -> Recommendation {
+        Recommendation {
+            priority,
+            category: category.to_string(),
+            message: format!("{} finding", category),
+            estimated_impact_seconds: seconds,
+        }
+    }
+
+    #[test]
+    fn test_ranked_by_priority_then_savings() {
+        let recs = [
+            rec(Priority::Low, "StaleImport", 2.1),
+            rec(Priority::Medium, "UI", 0.4),
+            rec(Priority::High, "Preload", 0.3),
+            rec(Priority::Medium, "Preload", 0.5),
+            rec(Priority::Medium, "DataTable", 1.0),
+        ];
+        let actions = from_recommendations(&recs);
+        let rules: Vec<&str> = actions.iter().map(|a| a.rule.as_str()).collect();
+        assert_eq!(rules, ["Preload", "DataTable", "UI", "StaleImport"]);
+        assert_eq!(actions[0].findings, 2);
+        assert_eq!(
+            render(&actions, 1),
+            ["1. [Preload] aggregate shared startup subtrees into preload assets (2 findings): saves ~0.8s"]
+        );
+    }
+
+    #[test]
+    fn test_explain_is_case_insensitive() {
+        let recs = [rec(Priority::Low, "StaleImport", 2.1), rec(Priority::High, "UI", 1.0)];
+        let lines = explain("staleimport", &recs).unwrap();
+        assert_eq!(lines[0], "StaleImport: remove stale hard imports");
+        assert_eq!(lines[2], "  - StaleImport finding");
+        assert_eq!(lines.len(), 3);
+        assert!(explain("nope", &recs).is_none());
+    }
+}
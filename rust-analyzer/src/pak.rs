@@ -0,0 +1,536 @@
+//! Pak Container Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Reads physical entry order from .pak files and validates it against the
+//! computed startup load order
+
+use crate::report::{Finding, ReportData, Severity, Table};
+use crate::{FastStartupError, Result};
+use memmap2::Mmap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+const PAK_MAGIC: u32 = 0x5A6F12E1;
+
+/// `EPakFileVersion` values that change the index layout
+const PAK_VERSION_DELETE_RECORDS: i32 = 7;
+const PAK_VERSION_FNAME_BASED_COMPRESSION: i32 = 8;
+const PAK_VERSION_PATH_HASH_INDEX: i32 = 10;
+
+/// Startup reads in increasing offset order above this ratio mean the
+/// ordering file was applied when the containers were built
+pub const ORDER_APPLIED_THRESHOLD: f64 = 0.9;
+
+const MB: f64 = 1024.0 * 1024.0;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PakEntry {
+    /// Path below the pak mount point, e.g. `Shooter/Content/UI/W_Main.uasset`
+    pub path: String,
+    pub offset: u64,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PakFile {
+    pub path: PathBuf,
+    pub version: i32,
+    pub mount_point: String,
+    /// Entries sorted by physical offset
+    pub entries: Vec<PakEntry>,
+}
+
+impl PakFile {
+    pub fn open(path: &Path) -> Result<Self> {
+        let file = File::open(path)?;
+        let mmap = unsafe { Mmap::map(&file)? };
+        let data: &[u8] = &mmap;
+
+        let (version, encrypted, index_offset, index_size) = read_footer(data).ok_or_else(|| {
+            FastStartupError::AssetError(format!("{}: no pak footer found", path.display()))
+        })?;
+        if encrypted {
+            return Err(FastStartupError::AssetError(format!(
+                "{}: pak index is encrypted",
+                path.display()
+            )));
+        }
+
+        let index = usize::try_from(index_offset)
+            .ok()
+            .zip(usize::try_from(index_size).ok())
+            .and_then(|(start, len)| data.get(start..start.checked_add(len)?))
+            .ok_or_else(|| {
+                FastStartupError::AssetError(format!("{}: index out of bounds", path.display()))
+            })?;
+
+        let parsed = if version >= PAK_VERSION_PATH_HASH_INDEX {
+            read_path_hash_index(data, index, version)
+        } else {
+            read_legacy_index(index, version)
+        };
+        let (mount_point, mut entries) = parsed.ok_or_else(|| {
+            FastStartupError::AssetError(format!(
+                "{}: unsupported or corrupt pak index (version {})",
+                path.display(),
+                version
+            ))
+        })?;
+
+        entries.sort_by_key(|e| e.offset);
+        Ok(Self {
+            path: path.to_path_buf(),
+            version,
+            mount_point,
+            entries,
+        })
+    }
+}
+
+/// Every `.pak` below a packaged build directory, sorted by path
+pub fn find_pak_files(build_dir: &Path) -> Vec<PathBuf> {
+    let mut paks: Vec<PathBuf> = WalkDir::new(build_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.into_path())
+        .filter(|p| p.extension().map(|e| e.eq_ignore_ascii_case("pak")).unwrap_or(false))
+        .collect();
+    paks.sort();
+    paks
+}
+
+/// Map a pak entry path to the project-relative `Content/...` path
+pub fn content_relative_path(pak_path: &str) -> Option<String> {
+    let normalized = pak_path.replace('\\', "/");
+    let start = if normalized.starts_with("Content/") {
+        0
+    } else {
+        normalized.find("/Content/")? + 1
+    };
+    Some(normalized[start..].to_string())
+}
+
+/// Name UnrealPak picks up from `Build/<Platform>/FileOpenOrder/`
+pub const OPEN_ORDER_FILE: &str = "GameOpenOrder.txt";
+
+/// UnrealPak open order file: one `"../../../<Project>/<path>" <n>` line per
+/// project-relative path, so containers are laid out in load order
+pub fn open_order_file(project_name: &str, load_order: &[String]) -> String {
+    load_order
+        .iter()
+        .enumerate()
+        .map(|(i, path)| format!("\"../../../{}/{}\" {}\n", project_name, path.replace('\\', "/"), i + 1))
+        .collect()
+}
+
+/// Footer: encryption key guid, encrypted flag, magic, version, index offset
+/// and size. The magic is located by scanning since its distance from the end
+/// varies with version (frozen index flag, compression method slots).
+fn read_footer(data: &[u8]) -> Option<(i32, bool, u64, u64)> {
+    let tail_start = data.len().saturating_sub(256);
+    let magic = PAK_MAGIC.to_le_bytes();
+
+    let pos = (tail_start..data.len().saturating_sub(24))
+        .rev()
+        .find(|&i| data[i..i + 4] == magic)?;
+
+    let version = i32::from_le_bytes(data.get(pos + 4..pos + 8)?.try_into().ok()?);
+    let index_offset = u64::from_le_bytes(data.get(pos + 8..pos + 16)?.try_into().ok()?);
+    let index_size = u64::from_le_bytes(data.get(pos + 16..pos + 24)?.try_into().ok()?);
+    let encrypted = pos > 0 && data[pos - 1] != 0 && version >= PAK_VERSION_DELETE_RECORDS;
+    Some((version, encrypted, index_offset, index_size))
+}
+
+struct Cursor<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        let slice = self.data.get(self.pos..self.pos.checked_add(len)?)?;
+        self.pos += len;
+        Some(slice)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        Some(self.bytes(1)?[0])
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        Some(u32::from_le_bytes(self.bytes(4)?.try_into().ok()?))
+    }
+
+    fn i32(&mut self) -> Option<i32> {
+        Some(i32::from_le_bytes(self.bytes(4)?.try_into().ok()?))
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        Some(u64::from_le_bytes(self.bytes(8)?.try_into().ok()?))
+    }
+
+    fn fstring(&mut self) -> Option<String> {
+        let len = self.i32()?;
+        if len == 0 {
+            return Some(String::new());
+        }
+        if len > 0 {
+            let bytes = self.bytes(len as usize)?;
+            let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+            Some(String::from_utf8_lossy(&bytes[..end]).to_string())
+        } else {
+            let bytes = self.bytes(len.unsigned_abs() as usize * 2)?;
+            let utf16: Vec<u16> = bytes
+                .chunks(2)
+                .map(|c| u16::from_le_bytes([c[0], c[1]]))
+                .take_while(|&c| c != 0)
+                .collect();
+            Some(String::from_utf16_lossy(&utf16))
+        }
+    }
+
+    /// Serialized `FPakEntry`; returns offset and size on disk
+    fn pak_entry(&mut self, version: i32) -> Option<(u64, u64)> {
+        let offset = self.u64()?;
+        let size = self.u64()?;
+        let _uncompressed_size = self.u64()?;
+        let compression = if version >= PAK_VERSION_FNAME_BASED_COMPRESSION {
+            self.u32()?
+        } else {
+            self.i32()? as u32
+        };
+        if version <= 1 {
+            self.u64()?; // Timestamp
+        }
+        self.bytes(20)?; // SHA1
+        if version >= 3 {
+            if compression != 0 {
+                let blocks = usize::try_from(self.i32()?).ok()?;
+                self.bytes(blocks.checked_mul(16)?)?;
+            }
+            self.u8()?; // Encrypted flag
+            self.u32()?; // Compression block size
+        }
+        Some((offset, size))
+    }
+}
+
+fn read_legacy_index(index: &[u8], version: i32) -> Option<(String, Vec<PakEntry>)> {
+    let mut cursor = Cursor::new(index);
+    let mount_point = cursor.fstring()?;
+    let count = usize::try_from(cursor.i32()?).ok()?;
+
+    let mut entries = Vec::with_capacity(count.min(index.len() / 40));
+    for _ in 0..count {
+        let path = cursor.fstring()?;
+        let (offset, size) = cursor.pak_entry(version)?;
+        entries.push(PakEntry { path, offset, size });
+    }
+    Some((mount_point, entries))
+}
+
+/// UE4.26+ index: entries are bit-packed and names live in a separate full
+/// directory index elsewhere in the file
+fn read_path_hash_index(data: &[u8], index: &[u8], version: i32) -> Option<(String, Vec<PakEntry>)> {
+    let mut cursor = Cursor::new(index);
+    let mount_point = cursor.fstring()?;
+    let _count = cursor.i32()?;
+    let _path_hash_seed = cursor.u64()?;
+
+    if cursor.u32()? != 0 {
+        cursor.bytes(8 + 8 + 20)?; // Path hash index offset, size, hash
+    }
+    if cursor.u32()? == 0 {
+        // Without the full directory index there are no file names
+        return None;
+    }
+    let directory_offset = usize::try_from(cursor.u64()?).ok()?;
+    let directory_size = usize::try_from(cursor.u64()?).ok()?;
+    cursor.bytes(20)?;
+
+    let encoded_size = usize::try_from(cursor.i32()?).ok()?;
+    let encoded = cursor.bytes(encoded_size)?;
+
+    let non_encoded_count = usize::try_from(cursor.i32()?).ok()?;
+    let mut non_encoded = Vec::with_capacity(non_encoded_count.min(index.len() / 40));
+    for _ in 0..non_encoded_count {
+        non_encoded.push(cursor.pak_entry(version)?);
+    }
+
+    let directory = data.get(directory_offset..directory_offset.checked_add(directory_size)?)?;
+    let mut cursor = Cursor::new(directory);
+    let mut entries = Vec::new();
+
+    for _ in 0..cursor.i32()? {
+        let dir = cursor.fstring()?;
+        for _ in 0..cursor.i32()? {
+            let file = cursor.fstring()?;
+            let location = cursor.i32()?;
+            let (offset, size) = if location >= 0 {
+                decode_entry(encoded.get(location as usize..)?)?
+            } else {
+                *non_encoded.get((-(location as i64) - 1) as usize)?
+            };
+            entries.push(PakEntry {
+                path: format!("{}{}", dir.trim_start_matches('/'), file),
+                offset,
+                size,
+            });
+        }
+    }
+
+    Some((mount_point, entries))
+}
+
+/// Bit-packed `FPakEntry`: flags word, then offset, uncompressed size and
+/// (when compressed) compressed size as 32 or 64 bit values
+fn decode_entry(encoded: &[u8]) -> Option<(u64, u64)> {
+    let mut cursor = Cursor::new(encoded);
+    let flags = cursor.u32()?;
+    let mut value = |is_32bit: bool| -> Option<u64> {
+        if is_32bit {
+            cursor.u32().map(u64::from)
+        } else {
+            cursor.u64()
+        }
+    };
+
+    let offset = value(flags & (1 << 31) != 0)?;
+    let uncompressed = value(flags & (1 << 30) != 0)?;
+    let compression_method = (flags >> 23) & 0x3F;
+    let size = if compression_method != 0 {
+        value(flags & (1 << 29) != 0)?
+    } else {
+        uncompressed
+    };
+    Some((offset, size))
+}
+
+/// One startup read resolved to its container location
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlacedRead {
+    pub relative_path: String,
+    pub pak: String,
+    pub offset: u64,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderValidation {
+    pub project_name: String,
+    pub paks: Vec<String>,
+    /// Startup assets in computed load order found in a container
+    pub placed: Vec<PlacedRead>,
+    /// Startup assets in computed load order absent from every container
+    pub missing: Vec<String>,
+    /// Bytes skipped or rewound between consecutive reads in the same pak
+    pub total_seek_bytes: u64,
+    pub backward_seeks: usize,
+    pub pak_switches: usize,
+    /// Fraction of consecutive same-pak reads that move forward
+    pub in_order_ratio: f64,
+    pub ordering_applied: bool,
+}
+
+impl OrderValidation {
+    /// Walk `load_order` (project-relative paths) through the physical layout
+    pub fn evaluate(project_name: &str, paks: &[PakFile], load_order: &[String]) -> Self {
+        let mut locations: HashMap<String, PlacedRead> = HashMap::new();
+        for pak in paks {
+            let pak_name = pak.path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            for entry in &pak.entries {
+                let full = format!("{}{}", pak.mount_point.trim_start_matches("../"), entry.path);
+                if let Some(relative) = content_relative_path(&full) {
+                    locations.entry(relative.clone()).or_insert(PlacedRead {
+                        relative_path: relative,
+                        pak: pak_name.clone(),
+                        offset: entry.offset,
+                        size: entry.size,
+                    });
+                }
+            }
+        }
+
+        let mut placed = Vec::new();
+        let mut missing = Vec::new();
+        for path in load_order {
+            match locations.get(&path.replace('\\', "/")) {
+                Some(read) => placed.push(read.clone()),
+                None => missing.push(path.clone()),
+            }
+        }
+
+        let mut total_seek_bytes = 0;
+        let mut backward_seeks = 0;
+        let mut pak_switches = 0;
+        let mut forward = 0;
+        let mut same_pak_pairs = 0;
+        for pair in placed.windows(2) {
+            let (prev, next) = (&pair[0], &pair[1]);
+            if prev.pak != next.pak {
+                pak_switches += 1;
+                continue;
+            }
+            same_pak_pairs += 1;
+            let prev_end = prev.offset + prev.size;
+            total_seek_bytes += next.offset.abs_diff(prev_end);
+            if next.offset >= prev_end {
+                forward += 1;
+            } else {
+                backward_seeks += 1;
+            }
+        }
+
+        let in_order_ratio = if same_pak_pairs == 0 {
+            1.0
+        } else {
+            forward as f64 / same_pak_pairs as f64
+        };
+
+        Self {
+            ordering_applied: !placed.is_empty() && in_order_ratio >= ORDER_APPLIED_THRESHOLD,
+            project_name: project_name.to_string(),
+            paks: paks.iter().map(|p| p.path.display().to_string()).collect(),
+            placed,
+            missing,
+            total_seek_bytes,
+            backward_seeks,
+            pak_switches,
+            in_order_ratio,
+        }
+    }
+}
+
+impl ReportData for OrderValidation {
+    fn title(&self) -> String {
+        format!("Pak ordering validation: {}", self.project_name)
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        serde_json::to_value(self).map_err(|e| FastStartupError::SerializationError(e.to_string()))
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        vec![
+            ("Containers".to_string(), self.paks.len().to_string()),
+            ("Startup assets placed".to_string(), self.placed.len().to_string()),
+            ("Startup assets missing".to_string(), self.missing.len().to_string()),
+            ("Seek distance".to_string(), format!("{:.1} MB", self.total_seek_bytes as f64 / MB)),
+            ("Backward seeks".to_string(), self.backward_seeks.to_string()),
+            ("Pak switches".to_string(), self.pak_switches.to_string()),
+            ("In-order reads".to_string(), format!("{:.1}%", self.in_order_ratio * 100.0)),
+            (
+                "Ordering applied".to_string(),
+                if self.ordering_applied { "yes" } else { "no" }.to_string(),
+            ),
+        ]
+    }
+
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["relative_path", "pak", "offset", "size_bytes"]);
+        for read in &self.placed {
+            table.push(vec![
+                read.relative_path.clone(),
+                read.pak.clone(),
+                read.offset.to_string(),
+                read.size.to_string(),
+            ]);
+        }
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        if !self.ordering_applied {
+            findings.push(Finding {
+                rule_id: "PakOrder".to_string(),
+                severity: Severity::Warning,
+                message: format!(
+                    "Only {:.1}% of startup reads move forward ({} backward seeks, {:.1} MB seek distance); \
+                     the ordering file does not appear to have been applied",
+                    self.in_order_ratio * 100.0,
+                    self.backward_seeks,
+                    self.total_seek_bytes as f64 / MB
+                ),
+                location: None,
+            });
+        }
+        findings.extend(self.missing.iter().map(|path| Finding {
+            rule_id: "PakMissing".to_string(),
+            severity: Severity::Note,
+            message: "Startup asset not found in any container".to_string(),
+            location: Some(path.clone()),
+        }));
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pak(entries: &[(&str, u64, u64)]) -> PakFile {
+        PakFile {
+            path: PathBuf::from("Game-Windows.pak"),
+            version: 11,
+            mount_point: "../../../".to_string(),
+            entries: entries
+                .iter()
+                .map(|(p, offset, size)| PakEntry { path: p.to_string(), offset: *offset, size: *size })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_content_relative_path() {
+        assert_eq!(
+            content_relative_path("Shooter/Content/UI/W_Main.uasset").as_deref(),
+            Some("Content/UI/W_Main.uasset")
+        );
+        assert_eq!(content_relative_path("Engine/Config/Base.ini"), None);
+    }
+
+    #[test]
+    fn test_open_order_file() {
+        let order = ["Content/Maps/Entry.umap", "Content\\UI\\W_Main.uasset"].map(String::from);
+        assert_eq!(
+            open_order_file("Shooter", &order),
+            "\"../../../Shooter/Content/Maps/Entry.umap\" 1\n\"../../../Shooter/Content/UI/W_Main.uasset\" 2\n"
+        );
+    }
+
+    #[test]
+    fn test_evaluate_order() {
+        let paks = [pak(&[
+            ("Game/Content/A.uasset", 0, 100),
+            ("Game/Content/B.uasset", 100, 100),
+            ("Game/Content/C.uasset", 200, 100),
+        ])];
+
+        let ordered = ["Content/A.uasset", "Content/B.uasset", "Content/C.uasset"]
+            .map(String::from);
+        let result = OrderValidation::evaluate("Game", &paks, &ordered);
+        assert!(result.ordering_applied);
+        assert_eq!(result.total_seek_bytes, 0);
+
+        let reversed = ["Content/C.uasset", "Content/B.uasset", "Content/A.uasset", "Content/D.uasset"]
+            .map(String::from);
+        let result = OrderValidation::evaluate("Game", &paks, &reversed);
+        assert!(!result.ordering_applied);
+        assert_eq!(result.backward_seeks, 2);
+        assert_eq!(result.missing, vec!["Content/D.uasset"]);
+    }
+}
@@ -0,0 +1,213 @@
+//! Adaptive Parallelism Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Feedback controller for the number of files in flight. Network shares
+//! want many outstanding requests to hide latency while local NVMe saturates
+//! with few, so the limit is tuned from observed throughput instead of fixed.
+
+use rayon::prelude::*;
+use std::sync::{Condvar, Mutex};
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+/// Completions per adjustment window, per unit of the current limit
+const WINDOW_OPS_PER_SLOT: usize = 4;
+const MIN_WINDOW_OPS: usize = 16;
+
+/// Relative throughput change treated as signal rather than noise
+const THROUGHPUT_TOLERANCE: f64 = 0.05;
+
+/// Upper bound of the limit as a multiple of the worker threads
+const MAX_IN_FLIGHT_PER_THREAD: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+}
+
+/// Hill-climbing step: keep moving while throughput improves, reverse when
+/// it drops, hold when it is flat
+pub fn next_limit(
+    limit: usize,
+    direction: Direction,
+    previous_throughput: f64,
+    throughput: f64,
+    bounds: (usize, usize),
+) -> (usize, Direction) {
+    let direction = if previous_throughput <= 0.0 || throughput > previous_throughput * (1.0 + THROUGHPUT_TOLERANCE) {
+        direction
+    } else if throughput < previous_throughput * (1.0 - THROUGHPUT_TOLERANCE) {
+        match direction {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+        }
+    } else {
+        return (limit, direction);
+    };
+
+    let step = (limit / 4).max(1);
+    let limit = match direction {
+        Direction::Up => limit + step,
+        Direction::Down => limit.saturating_sub(step),
+    };
+    (limit.clamp(bounds.0, bounds.1), direction)
+}
+
+struct State {
+    limit: usize,
+    in_flight: usize,
+    direction: Direction,
+    window_started: Instant,
+    window_ops: usize,
+    window_bytes: u64,
+    previous_throughput: f64,
+}
+
+/// Gate limiting how many operations run at once, retuned every window
+pub struct AdaptiveLimiter {
+    state: Mutex<State>,
+    released: Condvar,
+    min: usize,
+    max: usize,
+}
+
+impl AdaptiveLimiter {
+    /// Start at `initial` in flight, adjusting within `min..=max`
+    pub fn new(initial: usize, min: usize, max: usize) -> Self {
+        let min = min.max(1);
+        let max = max.max(min);
+        Self {
+            state: Mutex::new(State {
+                limit: initial.clamp(min, max),
+                in_flight: 0,
+                direction: Direction::Up,
+                window_started: Instant::now(),
+                window_ops: 0,
+                window_bytes: 0,
+                previous_throughput: 0.0,
+            }),
+            released: Condvar::new(),
+            min,
+            max,
+        }
+    }
+
+    /// Moderate start (one per worker thread) with room to grow for
+    /// high-latency storage
+    pub fn for_current_pool() -> Self {
+        let threads = rayon::current_num_threads();
+        Self::new(threads, 1, threads * MAX_IN_FLIGHT_PER_THREAD)
+    }
+
+    pub fn limit(&self) -> usize {
+        self.state.lock().unwrap().limit
+    }
+
+    pub fn max(&self) -> usize {
+        self.max
+    }
+
+    /// Run `work` once a slot is free. `bytes` is the amount of data it
+    /// reads; 0 measures throughput in operations instead.
+    pub fn run<T>(&self, bytes: u64, work: impl FnOnce() -> T) -> T {
+        {
+            let mut state = self.state.lock().unwrap();
+            while state.in_flight >= state.limit {
+                state = self.released.wait(state).unwrap();
+            }
+            state.in_flight += 1;
+        }
+
+        let result = work();
+
+        let mut state = self.state.lock().unwrap();
+        state.in_flight -= 1;
+        state.window_ops += 1;
+        state.window_bytes += bytes;
+        if state.window_ops >= (state.limit * WINDOW_OPS_PER_SLOT).max(MIN_WINDOW_OPS) {
+            self.adjust(&mut state);
+        }
+        drop(state);
+        self.released.notify_all();
+        result
+    }
+
+    fn adjust(&self, state: &mut State) {
+        let elapsed = state.window_started.elapsed().max(Duration::from_micros(1)).as_secs_f64();
+        let amount = if state.window_bytes > 0 {
+            state.window_bytes as f64
+        } else {
+            state.window_ops as f64
+        };
+        let throughput = amount / elapsed;
+
+        let (limit, direction) = next_limit(
+            state.limit,
+            state.direction,
+            state.previous_throughput,
+            throughput,
+            (self.min, self.max),
+        );
+        if limit != state.limit {
+            debug!("Adaptive parallelism: {} -> {} in flight", state.limit, limit);
+        }
+        state.limit = limit;
+        state.direction = direction;
+        state.previous_throughput = throughput;
+        state.window_started = Instant::now();
+        state.window_ops = 0;
+        state.window_bytes = 0;
+    }
+}
+
+/// Apply `f` to every item with the in-flight count governed by `limiter`,
+/// keeping input order. `size` gives the bytes each item reads.
+pub fn adaptive_filter_map<T, R, S, F>(items: &[T], limiter: &AdaptiveLimiter, size: S, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    S: Fn(&T) -> u64 + Sync,
+    F: Fn(&T) -> Option<R> + Sync,
+{
+    let run = || -> Vec<R> {
+        items
+            .par_iter()
+            .filter_map(|item| limiter.run(size(item), || f(item)))
+            .collect()
+    };
+
+    // Enough workers to reach the limiter's ceiling; they block in the
+    // limiter rather than on the storage when it is not needed
+    match rayon::ThreadPoolBuilder::new().num_threads(limiter.max()).build() {
+        Ok(pool) => pool.install(run),
+        Err(_) => run(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_limit() {
+        let bounds = (1, 32);
+        // Improving keeps climbing
+        assert_eq!(next_limit(8, Direction::Up, 100.0, 150.0, bounds), (10, Direction::Up));
+        // Dropping reverses
+        assert_eq!(next_limit(8, Direction::Up, 100.0, 50.0, bounds), (6, Direction::Down));
+        // Flat holds
+        assert_eq!(next_limit(8, Direction::Down, 100.0, 102.0, bounds), (8, Direction::Down));
+        // Clamped to bounds
+        assert_eq!(next_limit(32, Direction::Up, 100.0, 200.0, bounds), (32, Direction::Up));
+    }
+
+    #[test]
+    fn test_adaptive_filter_map_keeps_order() {
+        let items: Vec<u64> = (0..100).collect();
+        let limiter = AdaptiveLimiter::new(2, 1, 4);
+        let evens = adaptive_filter_map(&items, &limiter, |_| 0, |i| (i % 2 == 0).then_some(*i));
+        assert_eq!(evens, (0..100).step_by(2).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,345 @@
+//! Patch Diff Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Startup analysis of a base build against the same build with a patch or
+//! DLC layered on top: which startup assets the patch replaces, how much
+//! the startup set grows, and how often startup reads switch layers
+
+use crate::analyzer::{AnalysisReport, StartupAnalyzer};
+use crate::content::{open_provider, ContentProvider, LayeredContent};
+use crate::report::{Finding, ReportData, Severity, Table};
+use crate::scanner::AssetScanner;
+use crate::{FastStartupError, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+use tracing::info;
+
+const MB: f64 = 1024.0 * 1024.0;
+
+/// Startup asset served by the patch layer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatchedAsset {
+    pub relative_path: String,
+    /// Size in the base layer; `None` when the patch adds the asset
+    pub base_size_bytes: Option<u64>,
+    pub patched_size_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatchDiffReport {
+    pub project_name: String,
+    pub base: String,
+    pub patch: String,
+    pub base_startup_assets: usize,
+    pub patched_startup_assets: usize,
+    pub base_startup_size_bytes: u64,
+    pub patched_startup_size_bytes: u64,
+    pub base_closure_size_bytes: f64,
+    pub patched_closure_size_bytes: f64,
+    /// Base startup assets the patch replaces, largest first
+    pub overridden: Vec<PatchedAsset>,
+    /// Startup assets only the patched build loads, largest first
+    pub added: Vec<PatchedAsset>,
+    /// Startup assets of the base build the patched build no longer loads
+    pub removed: Vec<String>,
+    /// Changes between base and patch layer along the startup load order;
+    /// each one is a seek into another container or directory
+    pub layer_switches: usize,
+}
+
+impl PatchDiffReport {
+    /// Compare two analyses. `patch_paths` are the files the patch layer
+    /// provides and `load_order` the patched build's startup load order.
+    pub fn between(
+        base: &AnalysisReport,
+        patched: &AnalysisReport,
+        patch_paths: &HashSet<String>,
+        load_order: &[String],
+    ) -> Self {
+        let base_sizes: HashMap<&str, u64> = base
+            .startup_asset_list
+            .iter()
+            .map(|a| (a.relative_path.as_str(), a.size_bytes))
+            .collect();
+
+        let mut overridden = Vec::new();
+        let mut added = Vec::new();
+        for asset in &patched.startup_asset_list {
+            let base_size = base_sizes.get(asset.relative_path.as_str()).copied();
+            let entry = PatchedAsset {
+                relative_path: asset.relative_path.clone(),
+                base_size_bytes: base_size,
+                patched_size_bytes: asset.size_bytes,
+            };
+            match base_size {
+                None => added.push(entry),
+                Some(_) if patch_paths.contains(&asset.relative_path) => overridden.push(entry),
+                Some(_) => {}
+            }
+        }
+
+        let patched_paths: HashSet<&str> = patched.startup_asset_list.iter().map(|a| a.relative_path.as_str()).collect();
+        let removed = base
+            .startup_asset_list
+            .iter()
+            .filter(|a| !patched_paths.contains(a.relative_path.as_str()))
+            .map(|a| a.relative_path.clone())
+            .collect();
+
+        let layers: Vec<bool> = load_order
+            .iter()
+            .filter(|path| patched_paths.contains(path.as_str()))
+            .map(|path| patch_paths.contains(path))
+            .collect();
+        let layer_switches = layers.windows(2).filter(|w| w[0] != w[1]).count();
+
+        Self {
+            project_name: patched.project_name.clone(),
+            base: String::new(),
+            patch: String::new(),
+            base_startup_assets: base.startup_assets,
+            patched_startup_assets: patched.startup_assets,
+            base_startup_size_bytes: base.startup_size_bytes,
+            patched_startup_size_bytes: patched.startup_size_bytes,
+            base_closure_size_bytes: base.startup_closure_size_bytes.value,
+            patched_closure_size_bytes: patched.startup_closure_size_bytes.value,
+            overridden,
+            added,
+            removed,
+            layer_switches,
+        }
+    }
+
+    pub fn startup_growth_bytes(&self) -> i64 {
+        self.patched_startup_size_bytes as i64 - self.base_startup_size_bytes as i64
+    }
+}
+
+/// Analyze `base` alone and with `patch` layered on top. Both are content
+/// specs (see `content::open_provider`).
+pub fn compare(
+    analyzer: &StartupAnalyzer,
+    project_root: &Path,
+    base: &str,
+    patch: &str,
+    include_shaders: bool,
+) -> Result<PatchDiffReport> {
+    let base_content = open_provider(base, project_root)?;
+    let patch_content = open_provider(patch, project_root)?;
+    let patch_paths: HashSet<String> = patch_content
+        .list(&|_: &str| true)?
+        .into_iter()
+        .map(|e| e.relative_path)
+        .collect();
+    info!("Patch layer provides {} files", patch_paths.len());
+
+    info!("Analyzing base layer...");
+    let (base_report, _) = analyze_content(analyzer, project_root, base_content.clone(), include_shaders)?;
+    info!("Analyzing patched layers...");
+    let layered: Arc<dyn ContentProvider> = Arc::new(LayeredContent::new(vec![base_content, patch_content]));
+    let (patched_report, load_order) = analyze_content(analyzer, project_root, layered, include_shaders)?;
+
+    let mut report = PatchDiffReport::between(&base_report, &patched_report, &patch_paths, &load_order);
+    report.base = base.to_string();
+    report.patch = patch.to_string();
+    Ok(report)
+}
+
+/// Analysis of `content` and its load order as project-relative paths
+fn analyze_content(
+    analyzer: &StartupAnalyzer,
+    project_root: &Path,
+    content: Arc<dyn ContentProvider>,
+    include_shaders: bool,
+) -> Result<(AnalysisReport, Vec<String>)> {
+    let scanner = AssetScanner::from_provider(project_root, content)?.with_config(analyzer.config());
+    let assets = scanner.scan_all(None)?;
+    let mut graph = analyzer.build_graph(&assets)?;
+    graph.compute_load_order();
+
+    // Nodes carry the physical path, which differs per layer
+    let relative: HashMap<&Path, &str> = assets
+        .iter()
+        .map(|a| (a.path.as_path(), a.relative_path.as_str()))
+        .collect();
+    let load_order: Vec<String> = graph
+        .get_load_order()
+        .iter()
+        .filter_map(|n| relative.get(n.path.as_path()).map(|p| p.to_string()))
+        .collect();
+
+    let report = analyzer.analyze_scanned(&scanner, assets, &graph, include_shaders)?;
+    Ok((report, load_order))
+}
+
+fn size_mb(bytes: Option<u64>) -> String {
+    bytes.map(|b| format!("{:.2}", b as f64 / MB)).unwrap_or_default()
+}
+
+impl ReportData for PatchDiffReport {
+    fn title(&self) -> String {
+        format!("Patch startup diff: {}", self.project_name)
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        serde_json::to_value(self).map_err(|e| FastStartupError::SerializationError(e.to_string()))
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        vec![
+            ("Base".to_string(), self.base.clone()),
+            ("Patch".to_string(), self.patch.clone()),
+            (
+                "Startup assets".to_string(),
+                format!("{} -> {}", self.base_startup_assets, self.patched_startup_assets),
+            ),
+            (
+                "Startup size".to_string(),
+                format!(
+                    "{:.1} -> {:.1} MB ({:+.1} MB)",
+                    self.base_startup_size_bytes as f64 / MB,
+                    self.patched_startup_size_bytes as f64 / MB,
+                    self.startup_growth_bytes() as f64 / MB
+                ),
+            ),
+            (
+                "Startup closure".to_string(),
+                format!(
+                    "{:.1} -> {:.1} MB",
+                    self.base_closure_size_bytes / MB,
+                    self.patched_closure_size_bytes / MB
+                ),
+            ),
+            ("Overridden startup assets".to_string(), self.overridden.len().to_string()),
+            ("Added startup assets".to_string(), self.added.len().to_string()),
+            ("Removed startup assets".to_string(), self.removed.len().to_string()),
+            ("Layer switches".to_string(), self.layer_switches.to_string()),
+        ]
+    }
+
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["relative_path", "change", "base_mb", "patched_mb"]);
+        let rows = self
+            .overridden
+            .iter()
+            .map(|a| (a, "overridden"))
+            .chain(self.added.iter().map(|a| (a, "added")));
+        for (asset, change) in rows {
+            table.push(vec![
+                asset.relative_path.clone(),
+                change.to_string(),
+                size_mb(asset.base_size_bytes),
+                size_mb(Some(asset.patched_size_bytes)),
+            ]);
+        }
+        for path in &self.removed {
+            table.push(vec![path.clone(), "removed".to_string(), String::new(), String::new()]);
+        }
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        let mut findings: Vec<Finding> = self
+            .added
+            .iter()
+            .map(|a| Finding {
+                rule_id: "PatchStartupAsset".to_string(),
+                severity: Severity::Warning,
+                message: format!("Patch adds a startup asset ({:.2} MB)", a.patched_size_bytes as f64 / MB),
+                location: Some(a.relative_path.clone()),
+            })
+            .collect();
+        if self.layer_switches > 0 {
+            findings.push(Finding {
+                rule_id: "PatchLayerSeeks".to_string(),
+                severity: Severity::Note,
+                message: format!(
+                    "Startup reads switch between base and patch {} times; {} overridden assets are \
+                     read from the patch out of load order",
+                    self.layer_switches,
+                    self.overridden.len()
+                ),
+                location: None,
+            });
+        }
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::StartupAssetEntry;
+
+    fn report(entries: &[(&str, u64)]) -> AnalysisReport {
+        let list: Vec<StartupAssetEntry> = entries
+            .iter()
+            .map(|(p, s)| StartupAssetEntry { relative_path: p.to_string(), size_bytes: *s, tags: Vec::new(), preloaded_by: None })
+            .collect();
+
+        AnalysisReport {
+            project_name: "Game".to_string(),
+            total_assets: list.len(),
+            startup_assets: list.len(),
+            total_size_bytes: list.iter().map(|a| a.size_bytes).sum(),
+            startup_size_bytes: list.iter().map(|a| a.size_bytes).sum(),
+            startup_asset_list: list,
+            by_type: Default::default(),
+            by_tag: Default::default(),
+            dependency_count: 0,
+            duplicate_count: 0,
+            duplicates: Vec::new(),
+            shader_analysis: None,
+            shared_subtrees: Vec::new(),
+            stale_imports: Vec::new(),
+            content_anomalies: Vec::new(),
+            case_collisions: Vec::new(),
+            dependency_cycles: Vec::new(),
+            data_tables: Vec::new(),
+            string_tables: Vec::new(),
+            ui: Default::default(),
+            late_chunks: Vec::new(),
+            resident_memory: Default::default(),
+            source: None,
+            config_preloads: Vec::new(),
+            hardware: None,
+            parser_coverage: Default::default(),
+            startup_closure_size_bytes: Default::default(),
+            scenarios: Vec::new(),
+            engine_association: None,
+            version_matrix: None,
+            sample: None,
+            estimated_savings_seconds: 0.0,
+            recommendations: Vec::new(),
+            rule_hits: Vec::new(),
+            never_cooked: Vec::new(),
+            language: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_patch_diff_between() {
+        let base = report(&[("Content/UI/Menu.uasset", 10), ("Content/Maps/Entry.umap", 50), ("Content/Old.uasset", 5)]);
+        let patched = report(&[("Content/UI/Menu.uasset", 12), ("Content/Maps/Entry.umap", 50), ("Content/UI/Event.uasset", 8)]);
+        let patch_paths: HashSet<String> = ["Content/UI/Menu.uasset", "Content/UI/Event.uasset"]
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        let load_order: Vec<String> = ["Content/UI/Menu.uasset", "Content/Maps/Entry.umap", "Content/UI/Event.uasset"]
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+
+        let diff = PatchDiffReport::between(&base, &patched, &patch_paths, &load_order);
+        assert_eq!(diff.overridden.len(), 1);
+        assert_eq!(diff.overridden[0].base_size_bytes, Some(10));
+        assert_eq!(diff.added[0].relative_path, "Content/UI/Event.uasset");
+        assert_eq!(diff.removed, ["Content/Old.uasset"]);
+        assert_eq!(diff.startup_growth_bytes(), 5);
+        assert_eq!(diff.layer_switches, 2);
+    }
+}
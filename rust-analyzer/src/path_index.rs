@@ -0,0 +1,96 @@
+//! Path Index Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Open-addressing table from a hash of an asset's relative path to its
+//! record, persisted in the cache so lookups need no per-run map or owned keys
+
+use serde::{Deserialize, Serialize};
+use xxhash_rust::xxh3::xxh3_64;
+
+/// Marks an empty slot; real hashes of 0 are remapped
+const EMPTY: u64 = 0;
+
+/// Hash of a relative path as stored in the index
+pub fn path_hash(relative_path: &[u8]) -> u64 {
+    match xxh3_64(relative_path) {
+        EMPTY => 1,
+        hash => hash,
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PathIndex {
+    /// Path hash per slot, `EMPTY` if unused. Length is a power of two.
+    hashes: Vec<u64>,
+    /// Record offset per slot
+    records: Vec<u32>,
+    len: usize,
+}
+
+impl PathIndex {
+    /// Index `paths`, where the i-th path is record i
+    pub fn build<'a>(paths: impl ExactSizeIterator<Item = &'a [u8]>) -> Self {
+        let len = paths.len();
+        // Load factor of at most 1/2 keeps probe sequences short
+        let capacity = (len * 2).next_power_of_two().max(8);
+        let mut index = Self {
+            hashes: vec![EMPTY; capacity],
+            records: vec![0; capacity],
+            len,
+        };
+
+        let mask = capacity - 1;
+        for (record, path) in paths.enumerate() {
+            let hash = path_hash(path);
+            let mut slot = hash as usize & mask;
+            while index.hashes[slot] != EMPTY {
+                slot = (slot + 1) & mask;
+            }
+            index.hashes[slot] = hash;
+            index.records[slot] = record as u32;
+        }
+        index
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Records whose path hashes like `relative_path`. Callers compare the
+    /// record's path to rule out collisions.
+    pub fn candidates(&self, relative_path: &[u8]) -> impl Iterator<Item = usize> + '_ {
+        let hash = path_hash(relative_path);
+        let mask = self.hashes.len().wrapping_sub(1);
+        let start = hash as usize & mask;
+        let slots = if self.hashes.is_empty() { 0 } else { self.hashes.len() };
+
+        (0..slots)
+            .map(move |probe| (start + probe) & mask)
+            .take_while(|slot| self.hashes[*slot] != EMPTY)
+            .filter(move |slot| self.hashes[*slot] == hash)
+            .map(|slot| self.records[slot] as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_index_lookup() {
+        let paths: Vec<String> = (0..100).map(|i| format!("Content/A_{}.uasset", i)).collect();
+        let index = PathIndex::build(paths.iter().map(|p| p.as_bytes()));
+
+        assert_eq!(index.len(), 100);
+        for (record, path) in paths.iter().enumerate() {
+            assert!(index.candidates(path.as_bytes()).any(|r| r == record));
+        }
+        assert_eq!(index.candidates(b"Content/Missing.uasset").count(), 0);
+        assert_eq!(PathIndex::default().candidates(b"Content/A_0.uasset").count(), 0);
+    }
+}
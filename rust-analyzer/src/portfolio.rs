@@ -0,0 +1,479 @@
+//! Portfolio Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Side-by-side analysis of several projects, e.g. titles built from one
+//! shared content library: startup size and asset counts per project, and
+//! identical content shipped by more than one of them
+
+use crate::analyzer::{AnalysisReport, StartupAnalyzer};
+use crate::config::ProjectConfig;
+use crate::hash::HashAlgorithm;
+use crate::report::{Finding, ReportData, Severity, Table};
+use crate::scanner::{AssetFilter, AssetScanner};
+use crate::{FastStartupError, Result};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+use walkdir::WalkDir;
+
+const MB: f64 = 1024.0 * 1024.0;
+
+/// Shared content listed as findings, largest savings first
+pub const MAX_SHARED_FINDINGS: usize = 20;
+
+/// Project pairs sharing less than this are not worth a shared plugin
+pub const SHARED_PLUGIN_MIN_BYTES: u64 = 1024 * 1024;
+
+/// Project roots in a list file: one per line, `#` starts a comment.
+/// Relative roots are resolved against the list file's directory.
+pub fn read_project_list(path: &Path) -> Result<Vec<PathBuf>> {
+    let text = std::fs::read_to_string(path)?;
+    let base = path.parent().unwrap_or(Path::new("."));
+    let projects: Vec<PathBuf> = text
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or("").trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| base.join(line))
+        .collect();
+    if projects.is_empty() {
+        return Err(FastStartupError::InvalidArgument(format!(
+            "{} lists no projects",
+            path.display()
+        )));
+    }
+    Ok(projects)
+}
+
+/// `projects` followed by the content plugins under each one's `Plugins`
+/// directory, so plugins are compared as units of their own
+pub fn with_plugins(projects: &[PathBuf]) -> Vec<PathBuf> {
+    let mut roots = projects.to_vec();
+    for project in projects {
+        let mut plugins: Vec<PathBuf> = WalkDir::new(project.join("Plugins"))
+            .max_depth(4)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().extension().is_some_and(|ext| ext.eq_ignore_ascii_case("uplugin")))
+            .filter_map(|e| e.path().parent().map(Path::to_path_buf))
+            .filter(|dir| dir.join("Content").is_dir())
+            .collect();
+        plugins.sort();
+        roots.extend(plugins);
+    }
+    roots
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProjectSummary {
+    pub project_name: String,
+    pub project_root: String,
+    pub total_assets: usize,
+    pub total_size_bytes: u64,
+    pub startup_assets: usize,
+    pub startup_size_bytes: u64,
+    pub startup_closure_size_bytes: f64,
+    /// Duplicate groups within the project itself
+    pub duplicate_count: usize,
+    /// Content also shipped by another listed project
+    #[serde(default)]
+    pub shared_size_bytes: u64,
+    /// Why the project could not be analyzed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ProjectSummary {
+    fn from_report(root: &Path, report: &AnalysisReport) -> Self {
+        Self {
+            project_name: report.project_name.clone(),
+            project_root: root.display().to_string(),
+            total_assets: report.total_assets,
+            total_size_bytes: report.total_size_bytes,
+            startup_assets: report.startup_assets,
+            startup_size_bytes: report.startup_size_bytes,
+            startup_closure_size_bytes: report.startup_closure_size_bytes.value,
+            duplicate_count: report.duplicate_count,
+            shared_size_bytes: 0,
+            error: None,
+        }
+    }
+
+    fn failed(root: &Path, error: String) -> Self {
+        Self {
+            project_name: root
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_else(|| root.display().to_string()),
+            project_root: root.display().to_string(),
+            error: Some(error),
+            ..Default::default()
+        }
+    }
+}
+
+/// Identical content found in more than one project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SharedContent {
+    pub hash: u64,
+    pub size_bytes: u64,
+    /// Projects shipping it, in name order
+    pub projects: Vec<String>,
+    /// Paths it is stored under across those projects
+    pub paths: Vec<String>,
+}
+
+impl SharedContent {
+    /// Bytes saved by keeping one copy in a shared library
+    pub fn redundant_bytes(&self) -> u64 {
+        self.size_bytes * (self.projects.len() as u64).saturating_sub(1)
+    }
+}
+
+/// Identical content two projects both ship
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectOverlap {
+    pub first: String,
+    pub second: String,
+    pub shared_files: usize,
+    /// Size of one copy of the shared files
+    pub shared_bytes: u64,
+    /// Folder holding most of the shared bytes, the natural candidate to
+    /// move into a shared plugin
+    pub folder: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortfolioReport {
+    /// In list order
+    pub projects: Vec<ProjectSummary>,
+    /// Largest redundancy first
+    pub shared: Vec<SharedContent>,
+    /// Project pairs with shared content, most shared bytes first
+    #[serde(default)]
+    pub overlaps: Vec<ProjectOverlap>,
+}
+
+/// Content hash, size and relative path of one project's asset
+type HashedAsset = (u64, u64, String);
+
+impl PortfolioReport {
+    /// Analyze every project concurrently. `make_analyzer` configures the
+    /// analyzer per project (its own `.uefast.toml`, hardware, ...); a
+    /// project that fails is reported with its error instead of aborting
+    /// the others.
+    pub fn analyze<F>(projects: &[PathBuf], filter: AssetFilter, include_shaders: bool, make_analyzer: F) -> Self
+    where
+        F: Fn(&Path) -> Result<StartupAnalyzer> + Sync,
+    {
+        info!("Analyzing {} projects...", projects.len());
+        let results: Vec<(ProjectSummary, Vec<HashedAsset>)> = projects
+            .par_iter()
+            .map(|root| {
+                let analyzed = make_analyzer(root).and_then(|analyzer| {
+                    let report = analyzer.analyze(include_shaders)?;
+                    Ok((report, hash_assets(root, analyzer.config(), filter.clone())?))
+                });
+                match analyzed {
+                    Ok((report, hashes)) => (ProjectSummary::from_report(root, &report), hashes),
+                    Err(e) => {
+                        warn!("Analysis of {} failed: {}", root.display(), e);
+                        (ProjectSummary::failed(root, e.to_string()), Vec::new())
+                    }
+                }
+            })
+            .collect();
+
+        let (mut summaries, hashes): (Vec<_>, Vec<_>) = results.into_iter().unzip();
+        // Titles built from one template can share a name; shared content
+        // is attributed by name, so tell them apart by root
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        for summary in &summaries {
+            *seen.entry(summary.project_name.clone()).or_default() += 1;
+        }
+        for summary in &mut summaries {
+            if seen[&summary.project_name] > 1 {
+                summary.project_name = format!("{} ({})", summary.project_name, summary.project_root);
+            }
+        }
+        let named: Vec<(&str, Vec<HashedAsset>)> = summaries
+            .iter()
+            .map(|s| s.project_name.as_str())
+            .zip(hashes)
+            .collect();
+        let shared = find_shared(&named);
+        info!("Found {} files shared across projects", shared.len());
+
+        let mut shared_bytes: HashMap<&str, u64> = HashMap::new();
+        for content in &shared {
+            for project in &content.projects {
+                *shared_bytes.entry(project.as_str()).or_default() += content.size_bytes;
+            }
+        }
+        let shared_bytes: Vec<u64> = summaries
+            .iter()
+            .map(|s| shared_bytes.get(s.project_name.as_str()).copied().unwrap_or(0))
+            .collect();
+        for (summary, bytes) in summaries.iter_mut().zip(shared_bytes) {
+            summary.shared_size_bytes = bytes;
+        }
+
+        let overlaps = overlaps(&shared);
+        Self { projects: summaries, shared, overlaps }
+    }
+
+    /// Pairs sharing enough content to move it into a shared plugin
+    pub fn plugin_candidates(&self) -> impl Iterator<Item = &ProjectOverlap> {
+        self.overlaps.iter().filter(|o| o.shared_bytes >= SHARED_PLUGIN_MIN_BYTES)
+    }
+
+    pub fn failed_projects(&self) -> usize {
+        self.projects.iter().filter(|p| p.error.is_some()).count()
+    }
+
+    pub fn redundant_bytes(&self) -> u64 {
+        self.shared.iter().map(SharedContent::redundant_bytes).sum()
+    }
+}
+
+fn hash_assets(root: &Path, config: &ProjectConfig, filter: AssetFilter) -> Result<Vec<HashedAsset>> {
+    let scanner = AssetScanner::new(root)?.with_config(config).with_filter(filter);
+    let assets = scanner.scan_all(None)?;
+    let content = scanner.content();
+    Ok(assets
+        .par_iter()
+        .filter_map(|asset| {
+            let hash = content.hash(asset, HashAlgorithm::default()).ok()?;
+            Some((hash.as_u64(), asset.size_bytes, asset.relative_path.clone()))
+        })
+        .collect())
+}
+
+/// Content present in at least two of `projects`
+fn find_shared(projects: &[(&str, Vec<HashedAsset>)]) -> Vec<SharedContent> {
+    let mut by_hash: HashMap<u64, (u64, BTreeSet<&str>, BTreeSet<&str>)> = HashMap::new();
+    for (name, assets) in projects {
+        for (hash, size, path) in assets {
+            let (_, names, paths) = by_hash.entry(*hash).or_insert_with(|| (*size, BTreeSet::new(), BTreeSet::new()));
+            names.insert(name);
+            paths.insert(path);
+        }
+    }
+
+    let mut shared: Vec<SharedContent> = by_hash
+        .into_iter()
+        .filter(|(_, (_, names, _))| names.len() > 1)
+        .map(|(hash, (size_bytes, names, paths))| SharedContent {
+            hash,
+            size_bytes,
+            projects: names.into_iter().map(str::to_string).collect(),
+            paths: paths.into_iter().map(str::to_string).collect(),
+        })
+        .collect();
+    shared.sort_by(|a, b| {
+        b.redundant_bytes()
+            .cmp(&a.redundant_bytes())
+            .then_with(|| a.paths.cmp(&b.paths))
+    });
+    shared
+}
+
+/// Shared content per project pair
+fn overlaps(shared: &[SharedContent]) -> Vec<ProjectOverlap> {
+    // (files, bytes, bytes per folder) per pair
+    type PairTotals<'a> = (usize, u64, HashMap<&'a str, u64>);
+    let mut pairs: BTreeMap<(&str, &str), PairTotals> = BTreeMap::new();
+    for content in shared {
+        for (i, first) in content.projects.iter().enumerate() {
+            for second in &content.projects[i + 1..] {
+                let (files, bytes, folders) = pairs.entry((first, second)).or_default();
+                *files += 1;
+                *bytes += content.size_bytes;
+                if let Some(folder) = content.paths.first().map(|p| folder_of(p)) {
+                    *folders.entry(folder).or_default() += content.size_bytes;
+                }
+            }
+        }
+    }
+
+    let mut overlaps: Vec<ProjectOverlap> = pairs
+        .into_iter()
+        .map(|((first, second), (shared_files, shared_bytes, folders))| ProjectOverlap {
+            first: first.to_string(),
+            second: second.to_string(),
+            shared_files,
+            shared_bytes,
+            folder: folders
+                .into_iter()
+                .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(a.0)))
+                .map(|(folder, _)| folder.to_string()),
+        })
+        .collect();
+    overlaps.sort_by_key(|o| std::cmp::Reverse(o.shared_bytes));
+    overlaps
+}
+
+/// First two directories of a relative path, e.g. `Content/Environment`
+fn folder_of(path: &str) -> &str {
+    let mut separators = path.match_indices('/').map(|(i, _)| i);
+    match (separators.next(), separators.next()) {
+        (_, Some(end)) => &path[..end],
+        (Some(end), None) => &path[..end],
+        _ => path,
+    }
+}
+
+impl ReportData for PortfolioReport {
+    fn title(&self) -> String {
+        format!("Multi-project analysis ({} projects)", self.projects.len())
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        serde_json::to_value(self).map_err(|e| FastStartupError::SerializationError(e.to_string()))
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        let analyzed: Vec<&ProjectSummary> = self.projects.iter().filter(|p| p.error.is_none()).collect();
+        vec![
+            ("Projects".to_string(), self.projects.len().to_string()),
+            ("Failed".to_string(), self.failed_projects().to_string()),
+            (
+                "Total assets".to_string(),
+                analyzed.iter().map(|p| p.total_assets).sum::<usize>().to_string(),
+            ),
+            (
+                "Startup size".to_string(),
+                format!(
+                    "{:.1} MB",
+                    analyzed.iter().map(|p| p.startup_size_bytes).sum::<u64>() as f64 / MB
+                ),
+            ),
+            ("Shared files".to_string(), self.shared.len().to_string()),
+            ("Shared plugin candidates".to_string(), self.plugin_candidates().count().to_string()),
+            (
+                "Redundant shared content".to_string(),
+                format!("{:.1} MB", self.redundant_bytes() as f64 / MB),
+            ),
+        ]
+    }
+
+    fn table(&self) -> Table {
+        let mut table = Table::new(&[
+            "project",
+            "total_assets",
+            "total_mb",
+            "startup_assets",
+            "startup_mb",
+            "closure_mb",
+            "duplicates",
+            "shared_mb",
+            "error",
+        ]);
+        for p in &self.projects {
+            table.push(vec![
+                p.project_name.clone(),
+                p.total_assets.to_string(),
+                format!("{:.2}", p.total_size_bytes as f64 / MB),
+                p.startup_assets.to_string(),
+                format!("{:.2}", p.startup_size_bytes as f64 / MB),
+                format!("{:.2}", p.startup_closure_size_bytes / MB),
+                p.duplicate_count.to_string(),
+                format!("{:.2}", p.shared_size_bytes as f64 / MB),
+                p.error.clone().unwrap_or_default(),
+            ]);
+        }
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        let failed = self.projects.iter().filter_map(|p| {
+            p.error.as_ref().map(|e| Finding {
+                rule_id: "ProjectFailed".to_string(),
+                severity: Severity::Error,
+                message: format!("Analysis failed: {}", e),
+                location: Some(p.project_root.clone()),
+            })
+        });
+        let shared = self.shared.iter().take(MAX_SHARED_FINDINGS).map(|s| Finding {
+            rule_id: "SharedContent".to_string(),
+            severity: Severity::Note,
+            message: format!(
+                "Identical content ({:.2} MB) shipped by {}; a shared library saves {:.2} MB",
+                s.size_bytes as f64 / MB,
+                s.projects.join(", "),
+                s.redundant_bytes() as f64 / MB
+            ),
+            location: s.paths.first().cloned(),
+        });
+        let plugins = self.plugin_candidates().map(|o| Finding {
+            rule_id: "SharedPlugin".to_string(),
+            severity: Severity::Warning,
+            message: format!(
+                "{} and {} ship {} identical files ({:.1} MB each); move them into a shared content plugin",
+                o.first,
+                o.second,
+                o.shared_files,
+                o.shared_bytes as f64 / MB
+            ),
+            location: o.folder.clone(),
+        });
+        failed.chain(plugins).chain(shared).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_shared() {
+        let asset = |hash: u64, size: u64, path: &str| (hash, size, path.to_string());
+        let projects = vec![
+            ("Alpha", vec![asset(1, 100, "Content/Lib/Rock.uasset"), asset(2, 50, "Content/A.uasset")]),
+            ("Beta", vec![asset(1, 100, "Content/Shared/Rock.uasset"), asset(3, 10, "Content/Lib/Tree.uasset")]),
+            ("Gamma", vec![asset(1, 100, "Content/Lib/Rock.uasset"), asset(3, 10, "Content/Lib/Tree.uasset")]),
+        ];
+
+        let shared = find_shared(&projects);
+        assert_eq!(shared.len(), 2);
+        assert_eq!(shared[0].hash, 1);
+        assert_eq!(shared[0].projects, ["Alpha", "Beta", "Gamma"]);
+        assert_eq!(shared[0].paths, ["Content/Lib/Rock.uasset", "Content/Shared/Rock.uasset"]);
+        assert_eq!(shared[0].redundant_bytes(), 200);
+        assert_eq!(shared[1].projects, ["Beta", "Gamma"]);
+
+        let overlaps = overlaps(&shared);
+        assert_eq!(overlaps.len(), 3);
+        assert_eq!(
+            overlaps[0],
+            ProjectOverlap {
+                first: "Beta".to_string(),
+                second: "Gamma".to_string(),
+                shared_files: 2,
+                shared_bytes: 110,
+                folder: Some("Content/Lib".to_string()),
+            }
+        );
+        assert_eq!(overlaps[1].shared_bytes, 100);
+        assert_eq!(folder_of("Content/Lib/Trees/Oak.uasset"), "Content/Lib");
+        assert_eq!(folder_of("Content/Rock.uasset"), "Content");
+    }
+
+    #[test]
+    fn test_read_project_list() {
+        let dir = std::env::temp_dir().join(format!("uefast_portfolio_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let list = dir.join("projects.txt");
+        std::fs::write(&list, "# titles\nShooter\n\n  Racer  # spin-off\n").unwrap();
+
+        let projects = read_project_list(&list).unwrap();
+        assert_eq!(projects, [dir.join("Shooter"), dir.join("Racer")]);
+
+        std::fs::write(&list, "# nothing yet\n").unwrap();
+        assert!(read_project_list(&list).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,154 @@
+//! Process Priority Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Lower scheduling priority and CPU pinning for long runs sharing the
+//! machine with the editor or a cook. Both are applied to the main thread
+//! before any worker thread starts, so every worker inherits them.
+
+use crate::{FastStartupError, Result};
+
+/// Highest (least favorable) Unix nice value
+pub const MAX_NICE: i32 = 19;
+
+/// CPUs from a list such as `0-3,6`, sorted and deduplicated
+pub fn parse_cpu_list(s: &str) -> Result<Vec<usize>> {
+    let invalid = || {
+        FastStartupError::InvalidArgument(format!("Invalid CPU list '{}' (expected e.g. 0-3,6)", s))
+    };
+
+    let mut cpus = Vec::new();
+    for part in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        match part.split_once('-') {
+            Some((first, last)) => {
+                let first: usize = first.trim().parse().map_err(|_| invalid())?;
+                let last: usize = last.trim().parse().map_err(|_| invalid())?;
+                if first > last {
+                    return Err(invalid());
+                }
+                cpus.extend(first..=last);
+            }
+            None => cpus.push(part.parse().map_err(|_| invalid())?),
+        }
+    }
+    if cpus.is_empty() {
+        return Err(invalid());
+    }
+    cpus.sort_unstable();
+    cpus.dedup();
+    Ok(cpus)
+}
+
+/// Lower this process's priority: a Unix nice value from 0 (normal) to 19
+/// (idle). Windows maps 1-9 to below normal and 10-19 to idle.
+pub fn set_nice(nice: i32) -> Result<()> {
+    if !(0..=MAX_NICE).contains(&nice) {
+        return Err(FastStartupError::InvalidArgument(format!(
+            "--nice must be between 0 and {}, got {}",
+            MAX_NICE, nice
+        )));
+    }
+
+    #[cfg(unix)]
+    {
+        // Linux applies this to the calling thread; threads started later
+        // inherit it
+        let result = unsafe { libc::setpriority(libc::PRIO_PROCESS, 0, nice) };
+        if result != 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    {
+        const NORMAL_PRIORITY_CLASS: u32 = 0x0000_0020;
+        const BELOW_NORMAL_PRIORITY_CLASS: u32 = 0x0000_4000;
+        const IDLE_PRIORITY_CLASS: u32 = 0x0000_0040;
+        let class = match nice {
+            0 => NORMAL_PRIORITY_CLASS,
+            1..=9 => BELOW_NORMAL_PRIORITY_CLASS,
+            _ => IDLE_PRIORITY_CLASS,
+        };
+        if unsafe { win32::SetPriorityClass(win32::GetCurrentProcess(), class) } == 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        Ok(())
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    {
+        Err(FastStartupError::InvalidArgument("--nice is not supported on this platform".to_string()))
+    }
+}
+
+/// Restrict this process to `cpus`
+pub fn set_affinity(cpus: &[usize]) -> Result<()> {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        let mut set: libc::cpu_set_t = unsafe { std::mem::zeroed() };
+        let max = libc::CPU_SETSIZE as usize;
+        for &cpu in cpus {
+            if cpu >= max {
+                return Err(FastStartupError::InvalidArgument(format!("CPU {} is out of range", cpu)));
+            }
+            unsafe { libc::CPU_SET(cpu, &mut set) };
+        }
+        // Applies to the calling thread; threads started later inherit it
+        let result = unsafe { libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set) };
+        if result != 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    {
+        let mut mask: usize = 0;
+        for &cpu in cpus {
+            if cpu >= usize::BITS as usize {
+                return Err(FastStartupError::InvalidArgument(format!("CPU {} is out of range", cpu)));
+            }
+            mask |= 1 << cpu;
+        }
+        if unsafe { win32::SetProcessAffinityMask(win32::GetCurrentProcess(), mask) } == 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        Ok(())
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android", windows)))]
+    {
+        let _ = cpus;
+        Err(FastStartupError::InvalidArgument(
+            "--affinity is not supported on this platform".to_string(),
+        ))
+    }
+}
+
+#[cfg(windows)]
+mod win32 {
+    use std::ffi::c_void;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        pub fn GetCurrentProcess() -> *mut c_void;
+        pub fn SetPriorityClass(process: *mut c_void, priority_class: u32) -> i32;
+        pub fn SetProcessAffinityMask(process: *mut c_void, affinity_mask: usize) -> i32;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cpu_list() {
+        assert_eq!(parse_cpu_list("0-3,6").unwrap(), [0, 1, 2, 3, 6]);
+        assert_eq!(parse_cpu_list("4, 2,2").unwrap(), [2, 4]);
+        assert!(parse_cpu_list("3-1").is_err());
+        assert!(parse_cpu_list("").is_err());
+        assert!(parse_cpu_list("a").is_err());
+        assert!(set_nice(20).is_err());
+    }
+}
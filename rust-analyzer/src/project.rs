@@ -0,0 +1,369 @@
+//! Project Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! `.uproject` discovery, parsing and project layout validation, and the
+//! content roots the project mounts (its own and its plugins')
+
+use crate::{FastStartupError, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Mount point of the project's own `Content` directory
+pub const GAME_MOUNT: &str = "/Game";
+
+/// How deep below `Plugins/` `.uplugin` files are looked for
+/// (`Plugins/<Category>/<Plugin>/<Plugin>.uplugin`)
+const MAX_PLUGIN_DEPTH: usize = 4;
+
+/// Parsed `.uproject` descriptor (JSON)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ProjectDescriptor {
+    pub file_version: u32,
+    pub engine_association: String,
+    pub category: String,
+    pub description: String,
+    pub modules: Vec<ModuleDescriptor>,
+    pub plugins: Vec<PluginReference>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct ModuleDescriptor {
+    pub name: String,
+    #[serde(rename = "Type")]
+    pub module_type: String,
+    pub loading_phase: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct PluginReference {
+    pub name: String,
+    pub enabled: bool,
+    pub optional: bool,
+}
+
+/// The parts of a `.uplugin` descriptor that decide whether its content mounts
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct PluginDescriptor {
+    pub friendly_name: String,
+    pub enabled_by_default: Option<bool>,
+    pub can_contain_content: bool,
+}
+
+/// A content directory and the package path it is mounted at
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContentRoot {
+    /// `/Game`, or `/<Plugin>` for plugin content
+    pub mount_point: String,
+    /// Project-relative, e.g. `Content` or `Plugins/Weapons/Content`
+    pub relative_dir: String,
+}
+
+impl ContentRoot {
+    /// Whether the project-relative `relative_path` lies below this root
+    pub fn contains(&self, relative_path: &str) -> bool {
+        relative_path
+            .replace('\\', "/")
+            .strip_prefix(&self.relative_dir)
+            .is_some_and(|rest| rest.starts_with('/'))
+    }
+}
+
+/// A validated UE project root
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Project {
+    pub root: PathBuf,
+    pub uproject_path: PathBuf,
+    pub descriptor: ProjectDescriptor,
+}
+
+impl Project {
+    /// Open a project from its root directory or its `.uproject` file
+    pub fn open(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Err(FastStartupError::ProjectNotFound(path.display().to_string()));
+        }
+
+        let (root, uproject_path) = if path.is_file() {
+            if !has_extension(path, "uproject") {
+                return Err(FastStartupError::InvalidProject(format!(
+                    "{} is not a .uproject file",
+                    path.display()
+                )));
+            }
+            (path.parent().map(Path::to_path_buf).unwrap_or_default(), path.to_path_buf())
+        } else {
+            let uproject = find_uproject(path)?;
+            (path.to_path_buf(), uproject)
+        };
+
+        let text = std::fs::read_to_string(&uproject_path)?;
+        let descriptor: ProjectDescriptor = serde_json::from_str(&text).map_err(|e| {
+            FastStartupError::InvalidProject(format!(
+                "Failed to parse {}: {}",
+                uproject_path.display(),
+                e
+            ))
+        })?;
+
+        let content_dir = root.join("Content");
+        if !content_dir.is_dir() {
+            return Err(FastStartupError::InvalidProject(format!(
+                "{} found but Content directory is missing (partially synced workspace?)",
+                uproject_path.display()
+            )));
+        }
+
+        Ok(Self {
+            root,
+            uproject_path,
+            descriptor,
+        })
+    }
+
+    /// Like `open`, but a directory without any `.uproject` yields `None`
+    /// instead of an error (loose Content folders, packaged builds)
+    pub fn open_optional(root: &Path) -> Result<Option<Self>> {
+        if root.is_dir() && !has_uproject(root) {
+            return Ok(None);
+        }
+        Self::open(root).map(Some)
+    }
+
+    pub fn name(&self) -> String {
+        self.uproject_path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default()
+    }
+
+    pub fn content_dir(&self) -> PathBuf {
+        self.root.join("Content")
+    }
+
+    pub fn engine_association(&self) -> &str {
+        &self.descriptor.engine_association
+    }
+
+    pub fn enabled_plugins(&self) -> impl Iterator<Item = &PluginReference> {
+        self.descriptor.plugins.iter().filter(|p| p.enabled)
+    }
+
+    /// `Content`, then the `Content` directory of every enabled project
+    /// plugin that can contain content. Plugins the `.uproject` does not
+    /// list follow their `EnabledByDefault`, which defaults to on.
+    pub fn content_roots(&self) -> Vec<ContentRoot> {
+        let mut roots = vec![ContentRoot { mount_point: GAME_MOUNT.to_string(), relative_dir: "Content".to_string() }];
+        let mut plugins: Vec<ContentRoot> = WalkDir::new(self.root.join("Plugins"))
+            .max_depth(MAX_PLUGIN_DEPTH)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file() && has_extension(e.path(), "uplugin"))
+            .filter_map(|e| {
+                let name = e.path().file_stem()?.to_string_lossy().to_string();
+                let dir = e.path().parent()?;
+                let descriptor: PluginDescriptor = serde_json::from_str(&std::fs::read_to_string(e.path()).ok()?).ok()?;
+                let enabled = match self.descriptor.plugins.iter().find(|p| p.name.eq_ignore_ascii_case(&name)) {
+                    Some(reference) => reference.enabled,
+                    None => descriptor.enabled_by_default.unwrap_or(true),
+                };
+                if !enabled || !descriptor.can_contain_content || !dir.join("Content").is_dir() {
+                    return None;
+                }
+                let relative = dir.join("Content").strip_prefix(&self.root).ok()?.to_string_lossy().replace('\\', "/");
+                Some(ContentRoot { mount_point: format!("/{}", name), relative_dir: relative })
+            })
+            .collect();
+        plugins.sort_by(|a, b| a.mount_point.cmp(&b.mount_point));
+        roots.extend(plugins);
+        roots
+    }
+
+    /// Non-fatal layout problems, e.g. declared modules without sources
+    pub fn issues(&self) -> Vec<String> {
+        let mut issues = Vec::new();
+
+        if self.descriptor.engine_association.is_empty() {
+            issues.push("EngineAssociation is empty; engine version cannot be determined".to_string());
+        }
+
+        let source_dir = self.root.join("Source");
+        for module in &self.descriptor.modules {
+            if !source_dir.join(&module.name).is_dir() {
+                issues.push(format!(
+                    "Module '{}' declared but Source/{} is missing",
+                    module.name, module.name
+                ));
+            }
+        }
+
+        issues
+    }
+}
+
+/// Content roots of the project at `root`; just `Content` when there is no
+/// `.uproject` to name plugins
+pub fn content_roots(root: &Path) -> Vec<ContentRoot> {
+    match Project::open_optional(root) {
+        Ok(Some(project)) => project.content_roots(),
+        _ => vec![ContentRoot { mount_point: GAME_MOUNT.to_string(), relative_dir: "Content".to_string() }],
+    }
+}
+
+/// Root of `roots` holding the project-relative `relative_path`, the
+/// innermost when roots nest
+pub fn content_root_of<'a>(roots: &'a [ContentRoot], relative_path: &str) -> Option<&'a ContentRoot> {
+    roots.iter().filter(|r| r.contains(relative_path)).max_by_key(|r| r.relative_dir.len())
+}
+
+/// Explain why `path` is not a usable project root, when there is no Content dir
+pub fn diagnose_missing_content(path: &Path) -> FastStartupError {
+    // Pointed at the Content directory itself
+    if path.file_name().map(|n| n == "Content").unwrap_or(false) {
+        if let Some(parent) = path.parent() {
+            if find_uproject(parent).is_ok() {
+                return FastStartupError::InvalidProject(format!(
+                    "{} is a Content directory; pass the project root {} instead",
+                    path.display(),
+                    parent.display()
+                ));
+            }
+        }
+    }
+
+    // Pointed at a folder that contains the project one level down
+    if let Ok(entries) = std::fs::read_dir(path) {
+        let nested: Vec<PathBuf> = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.is_dir() && find_uproject(p).is_ok())
+            .collect();
+        if !nested.is_empty() {
+            let names: Vec<_> = nested.iter().map(|p| p.display().to_string()).collect();
+            return FastStartupError::InvalidProject(format!(
+                "No project at {}; found project directories below it: {}",
+                path.display(),
+                names.join(", ")
+            ));
+        }
+    }
+
+    if find_uproject(path).is_ok() {
+        return FastStartupError::InvalidProject(format!(
+            ".uproject found in {} but Content directory is missing (partially synced workspace?)",
+            path.display()
+        ));
+    }
+
+    FastStartupError::InvalidProject(format!(
+        "Content directory not found: {}",
+        path.join("Content").display()
+    ))
+}
+
+fn find_uproject(dir: &Path) -> Result<PathBuf> {
+    let mut found: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && has_extension(p, "uproject"))
+        .collect();
+
+    match found.len() {
+        0 => Err(FastStartupError::InvalidProject(format!(
+            "No .uproject file in {}",
+            dir.display()
+        ))),
+        1 => Ok(found.remove(0)),
+        _ => {
+            found.sort();
+            let names: Vec<_> = found.iter().map(|p| p.display().to_string()).collect();
+            Err(FastStartupError::InvalidProject(format!(
+                "Multiple .uproject files in {}: {}",
+                dir.display(),
+                names.join(", ")
+            )))
+        }
+    }
+}
+
+fn has_uproject(dir: &Path) -> bool {
+    std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .any(|e| has_extension(&e.path(), "uproject"))
+        })
+        .unwrap_or(false)
+}
+
+fn has_extension(path: &Path, ext: &str) -> bool {
+    path.extension()
+        .map(|e| e.eq_ignore_ascii_case(ext))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_descriptor() {
+        let json = r#"{
+            "FileVersion": 3,
+            "EngineAssociation": "5.3",
+            "Modules": [{ "Name": "Shooter", "Type": "Runtime", "LoadingPhase": "Default" }],
+            "Plugins": [
+                { "Name": "ModelingToolsEditorMode", "Enabled": true },
+                { "Name": "Bridge", "Enabled": false }
+            ]
+        }"#;
+
+        let descriptor: ProjectDescriptor = serde_json::from_str(json).unwrap();
+        assert_eq!(descriptor.engine_association, "5.3");
+        assert_eq!(descriptor.modules[0].module_type, "Runtime");
+        assert_eq!(descriptor.plugins.iter().filter(|p| p.enabled).count(), 1);
+    }
+
+    #[test]
+    fn test_plugin_content_roots() {
+        let root = std::env::temp_dir().join(format!("uefast-plugins-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        let plugin = |dir: &str, name: &str, json: &str| {
+            let dir = root.join("Plugins").join(dir);
+            std::fs::create_dir_all(dir.join("Content")).unwrap();
+            std::fs::write(dir.join(format!("{}.uplugin", name)), json).unwrap();
+        };
+        std::fs::create_dir_all(root.join("Content")).unwrap();
+        std::fs::write(
+            root.join("Game.uproject"),
+            r#"{"FileVersion": 3, "Plugins": [{"Name": "Weapons", "Enabled": true}, {"Name": "Legacy", "Enabled": false}]}"#,
+        )
+        .unwrap();
+        plugin("Gameplay/Weapons", "Weapons", r#"{"CanContainContent": true, "EnabledByDefault": false}"#);
+        plugin("Legacy", "Legacy", r#"{"CanContainContent": true}"#);
+        plugin("Vehicles", "Vehicles", r#"{"CanContainContent": true}"#);
+        plugin("CodeOnly", "CodeOnly", r#"{"CanContainContent": false}"#);
+
+        let roots = content_roots(&root);
+        let mounts: Vec<(&str, &str)> = roots.iter().map(|r| (r.mount_point.as_str(), r.relative_dir.as_str())).collect();
+        assert_eq!(
+            mounts,
+            [("/Game", "Content"), ("/Vehicles", "Plugins/Vehicles/Content"), ("/Weapons", "Plugins/Gameplay/Weapons/Content")]
+        );
+        let weapon = content_root_of(&roots, "Plugins/Gameplay/Weapons/Content/Rifle.uasset").unwrap();
+        assert_eq!(weapon.mount_point, "/Weapons");
+        assert!(content_root_of(&roots, "Plugins/Legacy/Content/Old.uasset").is_none());
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_open_missing_project() {
+        let result = Project::open(Path::new("/nonexistent/project/path"));
+        assert!(matches!(result, Err(FastStartupError::ProjectNotFound(_))));
+    }
+}
@@ -0,0 +1,247 @@
+//! Tagged Property Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Reader for versioned (editor) tagged property streams in export data
+
+/// UE5 object versions that change the property tag layout
+const VER_UE5_PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION: i32 = 1011;
+const VER_UE5_PROPERTY_TAG_COMPLETE_TYPE_NAME: i32 = 1012;
+
+/// `EPropertyTagFlags` in the complete-type-name tag layout
+const TAG_HAS_ARRAY_INDEX: u8 = 0x01;
+const TAG_HAS_PROPERTY_GUID: u8 = 0x02;
+const TAG_HAS_PROPERTY_EXTENSIONS: u8 = 0x04;
+
+/// `EPropertyTagExtension::OverridableInformation`
+const EXTENSION_OVERRIDABLE_INFORMATION: u8 = 0x02;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyTag {
+    pub name: String,
+    /// Outer property type, e.g. `ObjectProperty`
+    pub type_name: String,
+    /// Value size in bytes following the tag
+    pub size: usize,
+    /// Value start offset within the reader's data
+    pub value_offset: usize,
+}
+
+/// Sequential reader over one export's serialized data. Every read returns
+/// `None` past the end, so truncated or unexpected layouts stop cleanly.
+pub struct PropertyReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+    names: &'a [String],
+    ue5_version: i32,
+}
+
+impl<'a> PropertyReader<'a> {
+    pub fn new(data: &'a [u8], names: &'a [String], ue5_version: i32) -> Self {
+        Self {
+            data,
+            pos: 0,
+            names,
+            ue5_version,
+        }
+    }
+
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    pub fn read_u8(&mut self) -> Option<u8> {
+        let value = *self.data.get(self.pos)?;
+        self.pos += 1;
+        Some(value)
+    }
+
+    pub fn read_i32(&mut self) -> Option<i32> {
+        let bytes = self.data.get(self.pos..self.pos + 4)?;
+        self.pos += 4;
+        Some(i32::from_le_bytes(bytes.try_into().ok()?))
+    }
+
+    pub fn skip(&mut self, len: usize) -> Option<()> {
+        if self.pos + len > self.data.len() {
+            return None;
+        }
+        self.pos += len;
+        Some(())
+    }
+
+    /// FString: signed length, negative for UTF-16
+    pub fn skip_fstring(&mut self) -> Option<()> {
+        let len = self.read_i32()?;
+        let bytes = if len < 0 {
+            len.unsigned_abs() as usize * 2
+        } else {
+            len as usize
+        };
+        self.skip(bytes)
+    }
+
+    /// FName: name map index plus instance number (`Name_N-1` when non-zero)
+    pub fn read_fname(&mut self) -> Option<String> {
+        let index = self.read_i32()?;
+        let number = self.read_i32()?;
+        let base = self.names.get(usize::try_from(index).ok()?)?;
+        Some(if number > 0 {
+            format!("{}_{}", base, number - 1)
+        } else {
+            base.clone()
+        })
+    }
+
+    /// Skip the object-level serialization control byte written before the
+    /// tagged properties of UE5.4+ packages
+    pub fn begin_properties(&mut self) -> Option<()> {
+        if self.ue5_version >= VER_UE5_PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION {
+            let control = self.read_u8()?;
+            if control & EXTENSION_OVERRIDABLE_INFORMATION != 0 {
+                self.skip(1)?;
+            }
+        }
+        Some(())
+    }
+
+    /// Read the next tag and leave the reader at its value. `Ok(None)` marks
+    /// the terminating `None` tag.
+    pub fn next_tag(&mut self) -> Option<Option<PropertyTag>> {
+        let name = self.read_fname()?;
+        if name == "None" {
+            return Some(None);
+        }
+
+        let (type_name, size) = if self.ue5_version >= VER_UE5_PROPERTY_TAG_COMPLETE_TYPE_NAME {
+            let type_name = self.read_type_name()?;
+            let size = self.read_i32()?;
+            let flags = self.read_u8()?;
+            if flags & TAG_HAS_ARRAY_INDEX != 0 {
+                self.skip(4)?;
+            }
+            if flags & TAG_HAS_PROPERTY_GUID != 0 {
+                self.skip(16)?;
+            }
+            if flags & TAG_HAS_PROPERTY_EXTENSIONS != 0 {
+                self.skip_tag_extensions()?;
+            }
+            (type_name, size)
+        } else {
+            let type_name = self.read_fname()?;
+            let size = self.read_i32()?;
+            self.skip(4)?; // ArrayIndex
+
+            match type_name.as_str() {
+                "StructProperty" => {
+                    self.read_fname()?;
+                    self.skip(16)?;
+                }
+                "BoolProperty" => self.skip(1)?,
+                "ByteProperty" | "EnumProperty" | "ArrayProperty" | "SetProperty"
+                | "OptionalProperty" => {
+                    self.read_fname()?;
+                }
+                "MapProperty" => {
+                    self.read_fname()?;
+                    self.read_fname()?;
+                }
+                _ => {}
+            }
+
+            if self.read_u8()? != 0 {
+                self.skip(16)?;
+            }
+            if self.ue5_version >= VER_UE5_PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION {
+                self.skip_tag_extensions()?;
+            }
+            (type_name, size)
+        };
+
+        Some(Some(PropertyTag {
+            name,
+            type_name,
+            size: usize::try_from(size).ok()?,
+            value_offset: self.pos,
+        }))
+    }
+
+    /// Skip over the value of the tag just read
+    pub fn skip_value(&mut self, tag: &PropertyTag) -> Option<()> {
+        self.pos = tag.value_offset;
+        self.skip(tag.size)
+    }
+
+    /// Read every tag up to and including the terminating `None`, leaving the
+    /// reader at the native data that follows
+    pub fn read_tags(&mut self) -> Option<Vec<PropertyTag>> {
+        self.begin_properties()?;
+        let mut tags = Vec::new();
+        while let Some(tag) = self.next_tag()? {
+            self.skip_value(&tag)?;
+            tags.push(tag);
+        }
+        Some(tags)
+    }
+
+    /// Read an `ObjectProperty` value as a package index
+    pub fn object_index(&self, tag: &PropertyTag) -> Option<i32> {
+        let bytes = self.data.get(tag.value_offset..tag.value_offset + 4)?;
+        Some(i32::from_le_bytes(bytes.try_into().ok()?))
+    }
+
+    /// `FPropertyTypeName`: a name followed by its parameter count, recursively.
+    /// Only the outermost name is kept.
+    fn read_type_name(&mut self) -> Option<String> {
+        let name = self.read_fname()?;
+        let mut pending = self.read_i32()?;
+        while pending > 0 {
+            self.read_fname()?;
+            pending += self.read_i32()? - 1;
+        }
+        Some(name)
+    }
+
+    fn skip_tag_extensions(&mut self) -> Option<()> {
+        let extensions = self.read_u8()?;
+        if extensions & EXTENSION_OVERRIDABLE_INFORMATION != 0 {
+            // Overridable operation (u8) and experimental-logic flag (UBOOL)
+            self.skip(5)?;
+        }
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fname(out: &mut Vec<u8>, index: i32) {
+        out.extend_from_slice(&index.to_le_bytes());
+        out.extend_from_slice(&0i32.to_le_bytes());
+    }
+
+    #[test]
+    fn test_read_legacy_tags() {
+        let names: Vec<String> = ["None", "RowStruct", "ObjectProperty"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+
+        let mut data = Vec::new();
+        fname(&mut data, 1);
+        fname(&mut data, 2);
+        data.extend_from_slice(&4i32.to_le_bytes()); // Size
+        data.extend_from_slice(&0i32.to_le_bytes()); // ArrayIndex
+        data.push(0); // HasPropertyGuid
+        data.extend_from_slice(&(-3i32).to_le_bytes());
+        fname(&mut data, 0);
+
+        let mut reader = PropertyReader::new(&data, &names, 1010);
+        let tags = reader.read_tags().unwrap();
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].type_name, "ObjectProperty");
+        assert_eq!(reader.object_index(&tags[0]), Some(-3));
+        assert_eq!(reader.position(), data.len());
+    }
+}
@@ -0,0 +1,205 @@
+//! Redact Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Replace content names in reports with stable pseudonyms for sharing
+//! outside the studio. Every folder and asset name below `Content/`,
+//! `/Game/` or `Plugins/` is swapped per path segment, so the folder
+//! structure survives; the mapping back stays in `.uefast/redaction.json`.
+
+use crate::analyzer::AnalysisReport;
+use crate::{FastStartupError, Result, STATE_DIR};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use xxhash_rust::xxh3::{xxh3_64, xxh3_64_with_seed};
+
+pub const REDACTION_FILE: &str = "redaction.json";
+
+/// Where redacted paths start; the marker itself is kept
+const MARKERS: &[&str] = &["Content/", "Content\\", "/Game/", "Plugins/", "Plugins\\"];
+
+/// Extensions kept as they are, since they only tell the asset kind
+const KEEP_EXTENSIONS: &[&str] = &["uasset", "umap", "uexp", "ubulk", "uptnl", "uplugin", "png", "jpg", "tga"];
+
+/// Stands in for the project root in absolute paths
+const PROJECT_ROOT: &str = "<project>";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Redactor {
+    /// Per-project secret, so pseudonyms cannot be matched against guessed names
+    salt: u64,
+    /// Pseudonym -> original name
+    names: BTreeMap<String, String>,
+    #[serde(skip)]
+    project_root: String,
+    #[serde(skip)]
+    project_name: String,
+}
+
+impl Redactor {
+    pub fn path(project_root: &Path) -> PathBuf {
+        project_root.join(STATE_DIR).join(REDACTION_FILE)
+    }
+
+    /// Mapping of `project_root`, or a fresh one with a new salt
+    pub fn load(project_root: &Path) -> Self {
+        let mut redactor = std::fs::read_to_string(Self::path(project_root))
+            .ok()
+            .and_then(|text| serde_json::from_str::<Self>(&text).ok())
+            .unwrap_or_else(|| Self {
+                salt: xxh3_64(format!("{:?}{}", std::time::SystemTime::now(), std::process::id()).as_bytes()),
+                ..Self::default()
+            });
+        redactor.project_root = project_root.to_string_lossy().trim_end_matches(['/', '\\']).to_string();
+        redactor.project_name = project_root
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        redactor
+    }
+
+    pub fn save(&self, project_root: &Path) -> Result<PathBuf> {
+        let path = Self::path(project_root);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| FastStartupError::SerializationError(e.to_string()))?;
+        std::fs::write(&path, json)?;
+        Ok(path)
+    }
+
+    /// Original name of a pseudonym
+    pub fn reveal(&self, pseudonym: &str) -> Option<&str> {
+        self.names.get(pseudonym).map(|n| n.as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    fn pseudonym(&mut self, name: &str) -> String {
+        let pseudonym = format!("n{:010x}", xxh3_64_with_seed(name.as_bytes(), self.salt) & 0xff_ffff_ffff);
+        self.names.entry(pseudonym.clone()).or_insert_with(|| name.to_string());
+        pseudonym
+    }
+
+    /// `Content/Maps/Entry.umap` -> `Content/n…/n….umap`, keeping the marker
+    /// and known extensions; object names after a `.` are swapped too
+    fn redact_path(&mut self, marker: &str, rest: &str) -> String {
+        let mut out = marker.to_string();
+        for segment in rest.split_inclusive(['/', '\\']) {
+            let (name, separator) = match segment.char_indices().last() {
+                Some((at, '/' | '\\')) => (&segment[..at], &segment[at..]),
+                _ => (segment, ""),
+            };
+            for (j, part) in name.split('.').enumerate() {
+                if j > 0 {
+                    out.push('.');
+                }
+                let keep = part.is_empty() || (j > 0 && KEEP_EXTENSIONS.contains(&part.to_ascii_lowercase().as_str()));
+                if keep {
+                    out.push_str(part);
+                } else {
+                    out.push_str(&self.pseudonym(part));
+                }
+            }
+            out.push_str(separator);
+        }
+        out
+    }
+
+    /// Redact every content path in free text
+    pub fn redact_text(&mut self, text: &str) -> String {
+        let text = if self.project_root.is_empty() {
+            text.to_string()
+        } else {
+            text.replace(&self.project_root, PROJECT_ROOT)
+                .replace(&self.project_root.replace('/', "\\"), PROJECT_ROOT)
+        };
+        if !self.project_name.is_empty() && text == self.project_name {
+            return self.pseudonym(&text);
+        }
+
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text.as_str();
+        while let Some((start, marker)) = MARKERS
+            .iter()
+            .filter_map(|m| rest.find(m).map(|i| (i, *m)))
+            .min_by_key(|(i, _)| *i)
+        {
+            out.push_str(&rest[..start]);
+            let tail = &rest[start + marker.len()..];
+            let mut end = tail
+                .find(|c: char| c.is_whitespace() || "\"'`,;()[]<>|*".contains(c))
+                .unwrap_or(tail.len());
+            // Sentence punctuation and `file:line` suffixes are not part of the name
+            while end > 0 && tail[..end].ends_with(['.', ':']) {
+                end -= 1;
+            }
+            if let Some(colon) = tail[..end].find(':') {
+                end = colon;
+            }
+            out.push_str(&self.redact_path(marker, &tail[..end]));
+            rest = &tail[end..];
+        }
+        out.push_str(rest);
+        out
+    }
+
+    fn redact_value(&mut self, value: &mut Value) {
+        match value {
+            Value::String(text) => *text = self.redact_text(text),
+            Value::Array(items) => items.iter_mut().for_each(|item| self.redact_value(item)),
+            Value::Object(map) => {
+                let entries = std::mem::take(map);
+                for (key, mut item) in entries {
+                    self.redact_value(&mut item);
+                    map.insert(self.redact_text(&key), item);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Copy of `report` with every content path and the project name redacted
+    pub fn redact_report(&mut self, report: &AnalysisReport) -> Result<AnalysisReport> {
+        let mut value = serde_json::to_value(report)
+            .map_err(|e| FastStartupError::SerializationError(e.to_string()))?;
+        self.redact_value(&mut value);
+        serde_json::from_value(value).map_err(|e| FastStartupError::SerializationError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_text() {
+        let mut redactor = Redactor::load(Path::new("/work/SecretGame"));
+        let text = "Preload Content/Maps/Hero_Reveal.umap (from /Game/Maps/Hero_Reveal.Hero_Reveal) at /work/SecretGame/Content/UI/Boot.uasset.";
+        let redacted = redactor.redact_text(text);
+
+        assert!(!redacted.contains("Hero_Reveal"));
+        assert!(!redacted.contains("SecretGame"));
+        assert!(redacted.starts_with("Preload Content/"));
+        assert!(redacted.contains(".umap (from /Game/"));
+        assert!(redacted.contains(" at <project>/Content/"));
+        assert!(redacted.ends_with(".uasset."));
+
+        // Stable: the same folder gets the same pseudonym everywhere
+        let maps = redactor.redact_text("Content/Maps/");
+        assert!(redacted.contains(&maps));
+        let pseudonym = maps.trim_start_matches("Content/").trim_end_matches('/');
+        assert_eq!(redactor.reveal(pseudonym), Some("Maps"));
+        assert_eq!(redactor.redact_text("SecretGame"), redactor.redact_text("SecretGame"));
+        assert_eq!(redactor.redact_text("Config/DefaultEngine.ini:3"), "Config/DefaultEngine.ini:3");
+    }
+}
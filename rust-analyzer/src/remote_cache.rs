@@ -0,0 +1,206 @@
+//! Remote Cache Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Read a cache straight from an HTTP store with range requests, fetching
+//! only the header and asset records `verify` needs instead of the whole
+//! artifact
+
+use crate::cache::StartupCache;
+use crate::{FastStartupError, Result};
+use std::io::{self, Read};
+
+/// Bytes requested per range request
+pub const RANGE_CHUNK_BYTES: u64 = 4 * 1024 * 1024;
+
+/// Sequential reader over a URL, one `Range` request per chunk. Servers that
+/// ignore ranges are streamed from a single plain GET instead.
+pub struct RangeReader {
+    url: String,
+    chunk_bytes: u64,
+    offset: u64,
+    buffer: Vec<u8>,
+    position: usize,
+    /// Full object size, once the server reported it
+    total_bytes: Option<u64>,
+    fetched_bytes: u64,
+    requests: usize,
+    stream: Option<Box<dyn Read + Send + Sync>>,
+}
+
+impl RangeReader {
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            chunk_bytes: RANGE_CHUNK_BYTES,
+            offset: 0,
+            buffer: Vec::new(),
+            position: 0,
+            total_bytes: None,
+            fetched_bytes: 0,
+            requests: 0,
+            stream: None,
+        }
+    }
+
+    pub fn with_chunk_bytes(mut self, chunk_bytes: u64) -> Self {
+        self.chunk_bytes = chunk_bytes.max(1);
+        self
+    }
+
+    /// Bytes downloaded so far
+    pub fn fetched_bytes(&self) -> u64 {
+        self.fetched_bytes
+    }
+
+    pub fn total_bytes(&self) -> Option<u64> {
+        self.total_bytes
+    }
+
+    pub fn requests(&self) -> usize {
+        self.requests
+    }
+
+    /// Fetch the next chunk; `false` at the end of the object
+    fn fill(&mut self) -> io::Result<bool> {
+        if self.total_bytes.is_some_and(|total| self.offset >= total) {
+            return Ok(false);
+        }
+        let end = self.offset + self.chunk_bytes - 1;
+        self.requests += 1;
+        let response = match ureq::get(&self.url)
+            .set("Range", &format!("bytes={}-{}", self.offset, end))
+            .call()
+        {
+            Ok(response) => response,
+            Err(ureq::Error::Status(416, _)) => return Ok(false),
+            Err(e) => return Err(io::Error::other(e.to_string())),
+        };
+
+        if response.status() != 206 {
+            if self.offset > 0 {
+                return Err(io::Error::other(format!("{}: range request answered without a range", self.url)));
+            }
+            self.total_bytes = response.header("Content-Length").and_then(|len| len.trim().parse().ok());
+            self.stream = Some(Box::new(response.into_reader()));
+            return Ok(true);
+        }
+        if let Some(total) = response
+            .header("Content-Range")
+            .and_then(|range| range.rsplit_once('/'))
+            .and_then(|(_, total)| total.trim().parse().ok())
+        {
+            self.total_bytes = Some(total);
+        }
+
+        self.buffer.clear();
+        self.position = 0;
+        response.into_reader().take(self.chunk_bytes).read_to_end(&mut self.buffer)?;
+        self.offset += self.buffer.len() as u64;
+        self.fetched_bytes += self.buffer.len() as u64;
+        Ok(!self.buffer.is_empty())
+    }
+}
+
+impl Read for RangeReader {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        if self.stream.is_none() && self.position >= self.buffer.len() && !self.fill()? {
+            return Ok(0);
+        }
+        if let Some(stream) = &mut self.stream {
+            let read = stream.read(out)?;
+            self.fetched_bytes += read as u64;
+            return Ok(read);
+        }
+        let read = out.len().min(self.buffer.len() - self.position);
+        out[..read].copy_from_slice(&self.buffer[self.position..self.position + read]);
+        self.position += read;
+        Ok(read)
+    }
+}
+
+/// Header and asset records of the cache at `url`, with the reader that
+/// fetched them for transfer statistics
+pub fn load_index(url: &str) -> Result<(StartupCache, RangeReader)> {
+    let mut reader = RangeReader::new(url);
+    let cache = StartupCache::load_index(&mut reader).map_err(|e| match e {
+        FastStartupError::IoError(e) => FastStartupError::CacheError(e.to_string()),
+        e => e,
+    })?;
+    Ok((cache, reader))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::CachedAsset;
+    use crate::scanner::AssetType;
+    use std::io::Write;
+    use std::net::TcpListener;
+    use std::path::Path;
+
+    /// Minimal HTTP server answering `Range` requests over `data`
+    fn serve(data: Vec<u8>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/Game.uefast", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut request = [0u8; 4096];
+                let read = stream.read(&mut request).unwrap();
+                let request = String::from_utf8_lossy(&request[..read]).to_string();
+                let range = request
+                    .lines()
+                    .find_map(|l| l.strip_prefix("Range: bytes="))
+                    .and_then(|r| r.split_once('-'))
+                    .map(|(a, b)| (a.parse::<usize>().unwrap(), b.trim().parse::<usize>().unwrap()))
+                    .unwrap();
+                let start = range.0.min(data.len());
+                let end = (range.1 + 1).min(data.len());
+                let body = &data[start..end];
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 206 Partial Content\r\nContent-Length: {}\r\nContent-Range: bytes {}-{}/{}\r\nConnection: close\r\n\r\n",
+                    body.len(),
+                    start,
+                    end.saturating_sub(1),
+                    data.len()
+                );
+                let _ = stream.write_all(body);
+            }
+        });
+        url
+    }
+
+    #[test]
+    fn test_remote_index_fetches_prefix() {
+        let mut cache = StartupCache::new("Game");
+        for i in 0..3 {
+            cache.assets.push(CachedAsset {
+                relative_path: format!("Content/A{}.uasset", i),
+                asset_type: AssetType::UAsset,
+                content_hash: i,
+                size_bytes: 10,
+                load_order: i as u32,
+                is_startup_critical: false,
+            });
+        }
+        // Stands in for a large tail the verify never needs
+        cache.load_order = (0..200_000).map(|i| i % 3).collect();
+
+        let path = std::env::temp_dir().join(format!("uefast_remote_{}.uefast", std::process::id()));
+        cache.save(&path).unwrap();
+        let data = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let url = serve(data.clone());
+        let mut reader = RangeReader::new(&url).with_chunk_bytes(256);
+        let remote = StartupCache::load_index(&mut reader).unwrap();
+
+        assert_eq!(remote.project_name, "Game");
+        assert_eq!(remote.assets.len(), 3);
+        assert!(remote.find(Path::new("Content/A2.uasset")).is_some());
+        assert_eq!(reader.total_bytes(), Some(data.len() as u64));
+        assert!(reader.fetched_bytes() < data.len() as u64 / 10);
+    }
+}
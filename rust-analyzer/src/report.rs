@@ -0,0 +1,1165 @@
+//! Report Output Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Multi-format report writers shared by every command that emits results
+
+use crate::analyzer::{AnalysisReport, Priority};
+use crate::anomalies::{AnomalyKind, ContentAnomaly};
+use crate::audit::AccessAudit;
+use crate::cache::{CacheComparison, CacheStats, DivergenceCause, VerifyResult};
+use crate::graph::{GraphReport, ParserCoverage};
+use crate::i18n::{tr, Locale};
+use crate::sampling::Estimate;
+use crate::schema::stamp;
+use crate::scanner::{find_case_collisions, CaseCollision, ScanReport};
+use crate::telemetry::BenchReport;
+use crate::verify_state::QuickVerifyResult;
+use crate::{FastStartupError, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Json,
+    Ndjson,
+    Csv,
+    Html,
+    Markdown,
+    Sarif,
+}
+
+impl OutputFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutputFormat::Json => "json",
+            OutputFormat::Ndjson => "ndjson",
+            OutputFormat::Csv => "csv",
+            OutputFormat::Html => "html",
+            OutputFormat::Markdown => "markdown",
+            OutputFormat::Sarif => "sarif",
+        }
+    }
+
+    pub fn reporter(&self) -> Box<dyn Reporter> {
+        match self {
+            OutputFormat::Json => Box::new(JsonReporter),
+            OutputFormat::Ndjson => Box::new(NdjsonReporter),
+            OutputFormat::Csv => Box::new(CsvReporter),
+            OutputFormat::Html => Box::new(HtmlReporter),
+            OutputFormat::Markdown => Box::new(MarkdownReporter),
+            OutputFormat::Sarif => Box::new(SarifReporter),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(OutputFormat::Json),
+            "ndjson" | "jsonl" => Ok(OutputFormat::Ndjson),
+            "csv" => Ok(OutputFormat::Csv),
+            "html" => Ok(OutputFormat::Html),
+            "markdown" | "md" => Ok(OutputFormat::Markdown),
+            "sarif" => Ok(OutputFormat::Sarif),
+            other => Err(format!(
+                "unknown format '{}' (expected json, ndjson, csv, html, markdown, sarif)",
+                other
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// What a command prints to stdout: its log (`text`), or its result as JSON
+/// or NDJSON with the log moved to stderr
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PrintFormat {
+    #[default]
+    Text,
+    Json,
+    Ndjson,
+}
+
+impl PrintFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PrintFormat::Text => "text",
+            PrintFormat::Json => "json",
+            PrintFormat::Ndjson => "ndjson",
+        }
+    }
+
+    /// stdout carries the result, not the log
+    pub fn is_structured(&self) -> bool {
+        *self != PrintFormat::Text
+    }
+}
+
+impl FromStr for PrintFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(PrintFormat::Text),
+            "json" => Ok(PrintFormat::Json),
+            "ndjson" | "jsonl" => Ok(PrintFormat::Ndjson),
+            other => Err(format!("unknown print format '{}' (expected text, json, ndjson)", other)),
+        }
+    }
+}
+
+impl std::fmt::Display for PrintFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Severity of a finding, mapped onto SARIF levels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Note => "note",
+        }
+    }
+}
+
+impl From<Priority> for Severity {
+    fn from(priority: Priority) -> Self {
+        match priority {
+            Priority::High => Severity::Error,
+            Priority::Medium => Severity::Warning,
+            Priority::Low => Severity::Note,
+        }
+    }
+}
+
+/// A single actionable result, optionally tied to an asset path
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub rule_id: String,
+    pub severity: Severity,
+    pub message: String,
+    pub location: Option<String>,
+}
+
+/// Tabular view of a report's records
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(columns: &[&str]) -> Self {
+        Self {
+            columns: columns.iter().map(|c| c.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+}
+
+/// Data a command can hand to any `Reporter`
+pub trait ReportData {
+    fn title(&self) -> String;
+    fn to_json(&self) -> Result<Value>;
+    fn summary(&self) -> Vec<(String, String)>;
+    fn table(&self) -> Table;
+    fn findings(&self) -> Vec<Finding>;
+
+    /// Language of the headings around the summary and findings
+    fn locale(&self) -> Locale {
+        Locale::En
+    }
+}
+
+/// Output adapter writing `ReportData` in one format
+pub trait Reporter {
+    fn write(&self, data: &dyn ReportData, out: &mut dyn Write) -> Result<()>;
+}
+
+/// Render `data` with the reporter for `format` into `path`
+pub fn write_report(data: &dyn ReportData, format: OutputFormat, path: &Path) -> Result<()> {
+    let mut file = std::io::BufWriter::new(std::fs::File::create(path)?);
+    format.reporter().write(data, &mut file)?;
+    file.flush()?;
+    Ok(())
+}
+
+/// Print `data` to stdout as JSON or NDJSON; nothing for `text`, whose
+/// output is the log
+pub fn print_report(data: &dyn ReportData, format: PrintFormat) -> Result<()> {
+    let reporter = match format {
+        PrintFormat::Text => return Ok(()),
+        PrintFormat::Json => OutputFormat::Json.reporter(),
+        PrintFormat::Ndjson => OutputFormat::Ndjson.reporter(),
+    };
+    let mut stdout = std::io::stdout().lock();
+    reporter.write(data, &mut stdout)?;
+    stdout.flush()?;
+    Ok(())
+}
+
+fn anomaly_finding(anomaly: &ContentAnomaly) -> Finding {
+    let (severity, message) = match anomaly.kind {
+        AnomalyKind::LfsPointer => (Severity::Error, "Unsmudged Git LFS pointer, the asset will fail to load"),
+        AnomalyKind::MixedLineEndings => (Severity::Note, "Mixed CRLF/LF line endings, hash differs between syncs"),
+        AnomalyKind::DivergentLineEndings => {
+            (Severity::Note, "Line endings differ from other text files, hash differs between syncs")
+        }
+    };
+    Finding {
+        rule_id: match anomaly.kind {
+            AnomalyKind::LfsPointer => "LfsPointer",
+            _ => "LineEndings",
+        }
+        .to_string(),
+        severity,
+        message: message.to_string(),
+        location: Some(anomaly.relative_path.clone()),
+    }
+}
+
+fn case_collision_finding(collision: &CaseCollision) -> Finding {
+    Finding {
+        rule_id: "CaseCollision".to_string(),
+        severity: Severity::Error,
+        message: format!("Paths differ only by case: {}", collision.paths.join(", ")),
+        location: collision.paths.first().cloned(),
+    }
+}
+
+fn to_value<T: serde::Serialize>(value: &T) -> Result<Value> {
+    serde_json::to_value(value).map_err(|e| FastStartupError::SerializationError(e.to_string()))
+}
+
+fn write_json(out: &mut dyn Write, value: &Value, pretty: bool) -> Result<()> {
+    let result = if pretty {
+        serde_json::to_writer_pretty(&mut *out, value)
+    } else {
+        serde_json::to_writer(&mut *out, value)
+    };
+    result.map_err(|e| FastStartupError::SerializationError(e.to_string()))?;
+    writeln!(out)?;
+    Ok(())
+}
+
+pub struct JsonReporter;
+
+impl Reporter for JsonReporter {
+    fn write(&self, data: &dyn ReportData, out: &mut dyn Write) -> Result<()> {
+        write_json(out, &stamp(data.to_json()?), true)
+    }
+}
+
+/// One JSON object per table row, keyed by column name
+pub struct NdjsonReporter;
+
+impl Reporter for NdjsonReporter {
+    fn write(&self, data: &dyn ReportData, out: &mut dyn Write) -> Result<()> {
+        let table = data.table();
+        for row in &table.rows {
+            let record: serde_json::Map<String, Value> = table
+                .columns
+                .iter()
+                .cloned()
+                .zip(row.iter().map(|v| Value::String(v.clone())))
+                .collect();
+            write_json(out, &stamp(Value::Object(record)), false)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct CsvReporter;
+
+impl Reporter for CsvReporter {
+    fn write(&self, data: &dyn ReportData, out: &mut dyn Write) -> Result<()> {
+        let table = data.table();
+        let line = |cells: &[String]| {
+            cells.iter().map(|c| csv_escape(c)).collect::<Vec<_>>().join(",")
+        };
+
+        writeln!(out, "{}", line(&table.columns))?;
+        for row in &table.rows {
+            writeln!(out, "{}", line(row))?;
+        }
+        Ok(())
+    }
+}
+
+pub struct HtmlReporter;
+
+impl Reporter for HtmlReporter {
+    fn write(&self, data: &dyn ReportData, out: &mut dyn Write) -> Result<()> {
+        let title = html_escape(&data.title());
+        let locale = data.locale();
+        writeln!(out, "<!DOCTYPE html>")?;
+        writeln!(out, "<html lang=\"{}\"><head><meta charset=\"utf-8\"><title>{}</title></head><body>", locale, title)?;
+        writeln!(out, "<h1>{}</h1>", title)?;
+
+        writeln!(out, "<table>")?;
+        for (key, value) in data.summary() {
+            writeln!(out, "<tr><th>{}</th><td>{}</td></tr>", html_escape(&key), html_escape(&value))?;
+        }
+        writeln!(out, "</table>")?;
+
+        let findings = data.findings();
+        if !findings.is_empty() {
+            writeln!(out, "<h2>{}</h2><ul>", tr(locale, "report.findings", &[]))?;
+            for f in &findings {
+                writeln!(
+                    out,
+                    "<li class=\"{}\"><b>[{}] {}</b>: {}{}</li>",
+                    f.severity.as_str(),
+                    f.severity.as_str(),
+                    html_escape(&f.rule_id),
+                    html_escape(&f.message),
+                    f.location
+                        .as_ref()
+                        .map(|l| format!(" <code>{}</code>", html_escape(l)))
+                        .unwrap_or_default()
+                )?;
+            }
+            writeln!(out, "</ul>")?;
+        }
+
+        let table = data.table();
+        if !table.rows.is_empty() {
+            writeln!(out, "<h2>{}</h2><table>", tr(locale, "report.details", &[]))?;
+            let header: String = table.columns.iter()
+                .map(|c| format!("<th>{}</th>", html_escape(c)))
+                .collect();
+            writeln!(out, "<tr>{}</tr>", header)?;
+            for row in &table.rows {
+                let cells: String = row.iter()
+                    .map(|c| format!("<td>{}</td>", html_escape(c)))
+                    .collect();
+                writeln!(out, "<tr>{}</tr>", cells)?;
+            }
+            writeln!(out, "</table>")?;
+        }
+
+        writeln!(out, "</body></html>")?;
+        Ok(())
+    }
+}
+
+pub struct MarkdownReporter;
+
+impl Reporter for MarkdownReporter {
+    fn write(&self, data: &dyn ReportData, out: &mut dyn Write) -> Result<()> {
+        let locale = data.locale();
+        writeln!(out, "## {}", data.title())?;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "| {} | {} |",
+            tr(locale, "report.metric", &[]),
+            tr(locale, "report.value", &[])
+        )?;
+        writeln!(out, "|---|---|")?;
+        for (key, value) in data.summary() {
+            writeln!(out, "| {} | {} |", md_escape(&key), md_escape(&value))?;
+        }
+
+        let findings = data.findings();
+        if !findings.is_empty() {
+            writeln!(out)?;
+            writeln!(out, "### {}", tr(locale, "report.findings", &[]))?;
+            writeln!(out)?;
+            for f in &findings {
+                match &f.location {
+                    Some(loc) => writeln!(
+                        out,
+                        "- **{}** `{}`: {} (`{}`)",
+                        f.severity.as_str(), f.rule_id, f.message, loc
+                    )?,
+                    None => writeln!(
+                        out,
+                        "- **{}** `{}`: {}",
+                        f.severity.as_str(), f.rule_id, f.message
+                    )?,
+                }
+            }
+        }
+
+        let table = data.table();
+        if !table.rows.is_empty() {
+            writeln!(out)?;
+            writeln!(out, "### {}", tr(locale, "report.details", &[]))?;
+            writeln!(out)?;
+            writeln!(out, "| {} |", table.columns.join(" | "))?;
+            writeln!(out, "|{}", "---|".repeat(table.columns.len()))?;
+            for row in &table.rows {
+                let cells: Vec<_> = row.iter().map(|c| md_escape(c)).collect();
+                writeln!(out, "| {} |", cells.join(" | "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// SARIF 2.1.0 log so findings show up in code-review UIs
+pub struct SarifReporter;
+
+impl Reporter for SarifReporter {
+    fn write(&self, data: &dyn ReportData, out: &mut dyn Write) -> Result<()> {
+        let findings = data.findings();
+
+        let mut rule_ids: Vec<&str> = findings.iter().map(|f| f.rule_id.as_str()).collect();
+        rule_ids.sort_unstable();
+        rule_ids.dedup();
+
+        let rules: Vec<Value> = rule_ids
+            .iter()
+            .map(|id| json!({ "id": id, "name": id }))
+            .collect();
+
+        let results: Vec<Value> = findings
+            .iter()
+            .map(|f| {
+                let mut result = json!({
+                    "ruleId": f.rule_id,
+                    "level": f.severity.as_str(),
+                    "message": { "text": f.message },
+                });
+                if let Some(loc) = &f.location {
+                    result["locations"] = json!([{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": loc.replace('\\', "/") }
+                        }
+                    }]);
+                }
+                result
+            })
+            .collect();
+
+        let log = json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "ue5-fast-startup",
+                        "version": crate::VERSION,
+                        "rules": rules,
+                    }
+                },
+                "results": results,
+            }]
+        });
+
+        write_json(out, &log, true)
+    }
+}
+
+fn csv_escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+pub(crate) fn html_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn md_escape(value: &str) -> String {
+    value.replace('|', "\\|")
+}
+
+// ---------------------------------------------------------------------------
+// ReportData implementations
+// ---------------------------------------------------------------------------
+
+impl ReportData for AnalysisReport {
+    fn title(&self) -> String {
+        tr(self.language, "report.title", &[("project", &self.project_name)])
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        to_value(self)
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        let label = |key: &str| tr(self.language, key, &[]);
+        let mut rows = vec![
+            (label("report.total_assets"), self.total_assets.to_string()),
+            (label("report.startup_assets"), self.startup_assets.to_string()),
+            (label("report.total_size_mb"), format!("{:.1}", self.total_size_bytes as f64 / MB)),
+            (label("report.startup_size_mb"), format!("{:.1}", self.startup_size_bytes as f64 / MB)),
+            (label("report.closure_mb"), estimate_mb(&self.startup_closure_size_bytes)),
+            (
+                label("report.resident_mb"),
+                tr(
+                    self.language,
+                    "report.resident_value",
+                    &[
+                        ("mb", &format!("{:.1}", self.resident_memory.total_bytes as f64 / MB)),
+                        ("priced", &self.resident_memory.from_metadata()),
+                    ],
+                ),
+            ),
+            (label("report.dependencies"), self.dependency_count.to_string()),
+            (label("report.parsed_packages"), coverage_summary(&self.parser_coverage)),
+            (label("report.duplicate_groups"), self.duplicate_count.to_string()),
+            (label("report.savings_s"), format!("{:.1}", self.estimated_savings_seconds)),
+        ];
+        if let Some(hardware) = &self.hardware {
+            rows.push((label("report.hardware"), hardware.describe()));
+        }
+        for scenario in &self.scenarios {
+            rows.push((
+                tr(self.language, "report.scenario", &[("name", &scenario.name)]),
+                tr(
+                    self.language,
+                    "report.scenario_value",
+                    &[
+                        ("mb", &estimate_mb(&scenario.startup_size_estimate)),
+                        ("assets", &scenario.startup_assets),
+                        ("roots", &scenario.root_assets),
+                    ],
+                ),
+            ));
+        }
+        for (tag, stats) in &self.by_tag {
+            rows.push((
+                tr(self.language, "report.tag", &[("name", tag)]),
+                tr(
+                    self.language,
+                    "report.tag_value",
+                    &[
+                        ("mb", &format!("{:.1}", stats.startup_size_bytes as f64 / MB)),
+                        ("startup", &stats.startup_assets),
+                        ("assets", &stats.assets),
+                    ],
+                ),
+            ));
+        }
+        rows
+    }
+
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["priority", "category", "message", "estimated_impact_seconds"]);
+        for rec in &self.recommendations {
+            table.push(vec![
+                format!("{:?}", rec.priority),
+                rec.category.clone(),
+                rec.message.clone(),
+                format!("{:.2}", rec.estimated_impact_seconds),
+            ]);
+        }
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        let mut findings: Vec<Finding> = self.recommendations
+            .iter()
+            .map(|rec| Finding {
+                rule_id: rec.category.clone(),
+                severity: rec.priority.into(),
+                message: rec.message.clone(),
+                location: None,
+            })
+            .collect();
+
+        for hit in &self.rule_hits {
+            findings.push(Finding {
+                rule_id: format!("{}/{}", hit.pack, hit.rule_id),
+                severity: hit.severity,
+                message: hit.message.clone(),
+                location: hit.location.clone(),
+            });
+        }
+
+        for group in &self.duplicates {
+            for file in group.files.iter().skip(1) {
+                findings.push(Finding {
+                    rule_id: "Duplicate".to_string(),
+                    severity: Severity::Note,
+                    message: tr(self.language, "report.duplicate", &[("path", &group.files[0])]),
+                    location: Some(file.clone()),
+                });
+            }
+        }
+
+        for stale in &self.stale_imports {
+            findings.push(Finding {
+                rule_id: "StaleImport".to_string(),
+                severity: Severity::Note,
+                message: tr(self.language, "report.stale_import", &[("package", &stale.package)]),
+                location: Some(stale.relative_path.clone()),
+            });
+        }
+
+        for asset in &self.late_chunks {
+            findings.push(Finding {
+                rule_id: "LateChunk".to_string(),
+                severity: Severity::Warning,
+                message: format!("Startup asset is assigned to chunk {} ({})", asset.chunk_id, asset.source),
+                location: Some(asset.relative_path.clone()),
+            });
+        }
+
+        for asset in &self.never_cooked {
+            findings.push(Finding {
+                rule_id: "NeverCooked".to_string(),
+                severity: Severity::Error,
+                message: format!("Startup asset is not cooked for shipping ({}: {})", asset.rule, asset.cook_rule),
+                location: Some(asset.relative_path.clone()),
+            });
+        }
+
+        for preload in self.config_preloads.iter().filter(|p| p.assets.is_empty()) {
+            findings.push(Finding {
+                rule_id: "ConfigPreloadMissing".to_string(),
+                severity: Severity::Warning,
+                message: format!("{} names {}, which matches no scanned asset", preload.key, preload.path),
+                location: Some(preload.source.clone()),
+            });
+        }
+
+        for startup in self.source.iter().flat_map(|s| s.hotspots()) {
+            findings.extend(startup.blocking_calls.iter().map(|call| Finding {
+                rule_id: "ModuleStartup".to_string(),
+                severity: Severity::Note,
+                message: format!("{} ({}) in {} StartupModule", call.call, call.kind, startup.module),
+                location: Some(call.location.clone()),
+            }));
+        }
+
+        findings.extend(self.case_collisions.iter().map(case_collision_finding));
+        findings.extend(self.dependency_cycles.iter().map(|cycle| Finding {
+            rule_id: "DependencyCycle".to_string(),
+            severity: Severity::Warning,
+            message: format!("Hard reference cycle: {}", cycle.join(" <-> ")),
+            location: cycle.first().cloned(),
+        }));
+        findings.extend(self.content_anomalies.iter().map(anomaly_finding));
+        findings.extend(coverage_finding(&self.parser_coverage));
+        findings
+    }
+
+    fn locale(&self) -> Locale {
+        self.language
+    }
+}
+
+/// e.g. "~12.5 [10.0 - 15.0]"
+fn estimate_mb(estimate: &Estimate) -> String {
+    format!(
+        "~{:.1} [{:.1} - {:.1}]",
+        estimate.value / MB,
+        estimate.lower / MB,
+        estimate.upper / MB
+    )
+}
+
+/// e.g. "980/1000 (98.0%), 1200/1250 edges parsed, 20 packages and 40 edges from editor data"
+fn coverage_summary(coverage: &ParserCoverage) -> String {
+    let mut summary = format!(
+        "{}/{} ({:.1}%), {}/{} edges parsed",
+        coverage.parsed,
+        coverage.packages,
+        coverage.parsed_ratio() * 100.0,
+        coverage.parsed_edges,
+        coverage.parsed_edges + coverage.heuristic_edges
+    );
+    if coverage.editor_packages > 0 || coverage.registry_edges > 0 {
+        summary.push_str(&format!(
+            ", {} packages and {} edges from editor data",
+            coverage.editor_packages, coverage.registry_edges
+        ));
+    }
+    summary
+}
+
+/// Warn when too few packages parsed for the graph to be trusted
+fn coverage_finding(coverage: &ParserCoverage) -> Option<Finding> {
+    (coverage.parsed_ratio() < MIN_PARSED_RATIO).then(|| Finding {
+        rule_id: "ParserCoverage".to_string(),
+        severity: Severity::Warning,
+        message: format!(
+            "Only {:.1}% of {} packages could be parsed ({}); the dependency graph is incomplete",
+            coverage.parsed_ratio() * 100.0,
+            coverage.packages,
+            coverage.describe_failures()
+        ),
+        location: None,
+    })
+}
+
+impl ReportData for ScanReport {
+    fn title(&self) -> String {
+        "Asset scan".to_string()
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        to_value(self)
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        vec![
+            ("Assets".to_string(), self.total_assets.to_string()),
+            ("Total size (MB)".to_string(), format!("{:.1}", self.total_size_bytes as f64 / MB)),
+            ("Scan time (ms)".to_string(), self.scan_duration_ms.to_string()),
+        ]
+    }
+
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["relative_path", "asset_type", "size_bytes", "modified"]);
+        for asset in &self.assets {
+            table.push(vec![
+                asset.relative_path.clone(),
+                asset.asset_type.as_str().to_string(),
+                asset.size_bytes.to_string(),
+                asset.modified.to_string(),
+            ]);
+        }
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        find_case_collisions(self.assets.iter().map(|a| a.relative_path.as_str()))
+            .iter()
+            .map(case_collision_finding)
+            .collect()
+    }
+}
+
+impl ReportData for VerifyResult {
+    fn title(&self) -> String {
+        "Cache verification".to_string()
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        to_value(self)
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        vec![
+            ("Valid".to_string(), self.is_valid.to_string()),
+            ("Total assets".to_string(), self.total_assets.to_string()),
+            ("Matching assets".to_string(), self.matching_assets.to_string()),
+            ("Changed assets".to_string(), self.changed_assets.len().to_string()),
+            ("Missing assets".to_string(), self.missing_assets.len().to_string()),
+            ("Renamed assets".to_string(), self.renamed_assets.len().to_string()),
+            ("Content anomalies".to_string(), self.anomalies.len().to_string()),
+        ]
+    }
+
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["relative_path", "status"]);
+        for path in &self.changed_assets {
+            table.push(vec![path.clone(), "changed".to_string()]);
+        }
+        for path in &self.missing_assets {
+            table.push(vec![path.clone(), "missing".to_string()]);
+        }
+        for rename in &self.renamed_assets {
+            table.push(vec![rename.to.clone(), format!("renamed from {}", rename.from)]);
+        }
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        let changed = self.changed_assets.iter().map(|p| Finding {
+            rule_id: "CacheChanged".to_string(),
+            severity: Severity::Warning,
+            message: "Asset content differs from cache".to_string(),
+            location: Some(p.clone()),
+        });
+        let missing = self.missing_assets.iter().map(|p| Finding {
+            rule_id: "CacheMissing".to_string(),
+            severity: Severity::Warning,
+            message: "Cached asset no longer exists".to_string(),
+            location: Some(p.clone()),
+        });
+        let renamed = self.renamed_assets.iter().map(|r| Finding {
+            rule_id: "CacheRenamed".to_string(),
+            severity: Severity::Note,
+            message: format!("Moved from {}; run `cache update`", r.from),
+            location: Some(r.to.clone()),
+        });
+        let anomalies = self.anomalies.iter().map(anomaly_finding);
+        changed.chain(missing).chain(renamed).chain(anomalies).collect()
+    }
+}
+
+impl ReportData for AccessAudit {
+    fn title(&self) -> String {
+        "Access audit".to_string()
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        to_value(self)
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        let mut rows = vec![
+            ("Files checked".to_string(), self.files_checked.to_string()),
+            ("Unreadable".to_string(), self.failures.len().to_string()),
+        ];
+        for (directory, summary) in &self.by_directory {
+            let errors: Vec<String> = summary.errors
+                .iter()
+                .map(|(error, count)| format!("{} x {}", count, error))
+                .collect();
+            rows.push((directory.clone(), errors.join("; ")));
+        }
+        rows
+    }
+
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["relative_path", "operation", "os_code", "error"]);
+        for failure in &self.failures {
+            table.push(vec![
+                failure.relative_path.clone(),
+                failure.operation.as_str().to_string(),
+                failure.os_code.map(|c| c.to_string()).unwrap_or_default(),
+                failure.error.clone(),
+            ]);
+        }
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        self.failures
+            .iter()
+            .map(|failure| Finding {
+                rule_id: "AccessDenied".to_string(),
+                severity: Severity::Error,
+                message: format!("Cannot {} file: {}", failure.operation.as_str(), failure.error),
+                location: Some(failure.relative_path.clone()),
+            })
+            .collect()
+    }
+}
+
+impl ReportData for CacheComparison {
+    fn title(&self) -> String {
+        "Cache comparison".to_string()
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        to_value(self)
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        let mut rows = vec![
+            ("Caches".to_string(), self.caches.join(", ")),
+            ("Divergent assets".to_string(), self.divergent.len().to_string()),
+        ];
+        for pair in &self.pairs {
+            rows.push((
+                format!("{} vs {}", self.caches[pair.a], self.caches[pair.b]),
+                format!(
+                    "{:.1}% agree ({} differ, {} only in first, {} only in second)",
+                    pair.ratio() * 100.0,
+                    pair.differing,
+                    pair.only_in_a,
+                    pair.only_in_b
+                ),
+            ));
+        }
+        rows
+    }
+
+    fn table(&self) -> Table {
+        let mut columns = vec!["relative_path", "cause"];
+        columns.extend(self.caches.iter().map(|c| c.as_str()));
+        let mut table = Table::new(&columns);
+        for asset in &self.divergent {
+            let mut row = vec![asset.relative_path.clone(), asset.cause.as_str().to_string()];
+            row.extend(asset.hashes.iter().map(|h| match h {
+                Some(hash) => format!("{:016x}", hash),
+                None => "-".to_string(),
+            }));
+            table.push(row);
+        }
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        self.divergent
+            .iter()
+            .map(|asset| Finding {
+                rule_id: "CacheDivergence".to_string(),
+                severity: Severity::Warning,
+                message: match asset.cause {
+                    DivergenceCause::Missing => "Asset is missing from some syncs".to_string(),
+                    DivergenceCause::LfsPointer => "Asset is an unsmudged Git LFS pointer in some syncs".to_string(),
+                    DivergenceCause::LineEndings => "Text file differs in size, likely CRLF/LF conversion".to_string(),
+                    DivergenceCause::Content => "Asset content differs between syncs".to_string(),
+                },
+                location: Some(asset.relative_path.clone()),
+            })
+            .collect()
+    }
+}
+
+impl ReportData for CacheStats {
+    fn title(&self) -> String {
+        "Cache statistics".to_string()
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        to_value(self)
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        let mut rows = vec![
+            ("Version".to_string(), self.version.clone()),
+            ("Created".to_string(), self.created_at.clone()),
+            ("Assets".to_string(), self.asset_count.to_string()),
+            ("Startup-critical".to_string(), self.startup_assets.to_string()),
+            ("Size".to_string(), format!("{} KB", self.size_bytes / 1024)),
+            ("Hash algorithm".to_string(), self.hash_algorithm.clone()),
+        ];
+        if !self.tags.is_empty() {
+            rows.push(("Tags".to_string(), self.tags.join(", ")));
+        }
+        rows.extend(self.metadata.iter().map(|(key, value)| (key.clone(), value.clone())));
+        rows
+    }
+
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["scenario", "startup_assets"]);
+        for (name, count) in &self.scenarios {
+            table.push(vec![name.clone(), count.to_string()]);
+        }
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        Vec::new()
+    }
+}
+
+impl ReportData for GraphReport {
+    fn title(&self) -> String {
+        format!("Dependency graph: {}", self.project_name)
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        to_value(self)
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        vec![
+            ("Nodes".to_string(), self.node_count.to_string()),
+            ("Edges".to_string(), self.edge_count.to_string()),
+            ("Startup-critical".to_string(), self.startup_critical_count.to_string()),
+            ("Parser coverage".to_string(), coverage_summary(&self.coverage)),
+            ("Cycles".to_string(), self.cycles.len().to_string()),
+        ]
+    }
+
+    fn table(&self) -> Table {
+        let mut table = Table::new(&[
+            "id",
+            "relative_path",
+            "asset_type",
+            "size_bytes",
+            "startup_critical",
+            "dependencies",
+            "dependents",
+        ]);
+        for node in &self.nodes {
+            table.push(vec![
+                node.id.clone(),
+                node.relative_path.clone(),
+                node.asset_type.as_str().to_string(),
+                node.size_bytes.to_string(),
+                node.is_startup_critical.to_string(),
+                node.dependencies.to_string(),
+                node.dependents.to_string(),
+            ]);
+        }
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        let mut findings: Vec<Finding> = coverage_finding(&self.coverage).into_iter().collect();
+        findings.extend(self.cycles.iter().map(|cycle| Finding {
+            rule_id: "DependencyCycle".to_string(),
+            severity: Severity::Warning,
+            message: format!("Reference cycle: {}", cycle.join(" <-> ")),
+            location: cycle.first().cloned(),
+        }));
+        findings
+    }
+}
+
+impl ReportData for BenchReport {
+    fn title(&self) -> String {
+        format!("Benchmark: {}", self.project_name)
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        let mut json = to_value(self)?;
+        json["average_scan_secs"] = json!(self.average_scan_secs());
+        json["average_hash_secs"] = json!(self.average_hash_secs());
+        Ok(json)
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        vec![
+            ("Iterations".to_string(), self.iterations.len().to_string()),
+            ("Average scan time".to_string(), format!("{:.3}s", self.average_scan_secs())),
+            (
+                format!("Average hash time ({} assets)", self.hashed_files),
+                format!("{:.3}s", self.average_hash_secs()),
+            ),
+            ("Direct I/O".to_string(), self.direct_io.to_string()),
+            ("Cold".to_string(), self.cold.to_string()),
+        ]
+    }
+
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["iteration", "assets", "scan_secs", "hash_secs"]);
+        for iteration in &self.iterations {
+            table.push(vec![
+                iteration.iteration.to_string(),
+                iteration.assets.to_string(),
+                format!("{:.6}", iteration.scan_secs),
+                format!("{:.6}", iteration.hash_secs),
+            ]);
+        }
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        Vec::new()
+    }
+}
+
+impl ReportData for QuickVerifyResult {
+    fn title(&self) -> String {
+        "Quick verification".to_string()
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        let mut json = to_value(self)?;
+        json["is_valid"] = json!(self.is_valid());
+        Ok(json)
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        vec![
+            ("Valid".to_string(), self.is_valid().to_string()),
+            ("Files".to_string(), self.files.to_string()),
+            ("Rehashed".to_string(), self.rehashed.to_string()),
+            ("Changed assets".to_string(), self.changed_assets.len().to_string()),
+            ("Elapsed".to_string(), format!("{:.1} ms", self.elapsed_ms)),
+        ]
+    }
+
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["relative_path", "status"]);
+        for path in &self.changed_assets {
+            table.push(vec![path.clone(), "changed".to_string()]);
+        }
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        self.changed_assets
+            .iter()
+            .map(|p| Finding {
+                rule_id: "CacheChanged".to_string(),
+                severity: Severity::Warning,
+                message: "Asset is new or differs from cache".to_string(),
+                location: Some(p.clone()),
+            })
+            .collect()
+    }
+}
+
+const MB: f64 = 1024.0 * 1024.0;
+
+/// Below this share of parsed packages the graph gets a coverage warning
+const MIN_PARSED_RATIO: f64 = 0.95;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_verify() -> VerifyResult {
+        VerifyResult {
+            is_valid: false,
+            total_assets: 2,
+            matching_assets: 1,
+            changed_assets: vec!["Content/A, B.uasset".to_string()],
+            missing_assets: Vec::new(),
+            renamed_assets: Vec::new(),
+            anomalies: Vec::new(),
+        }
+    }
+
+    fn render(format: OutputFormat, data: &dyn ReportData) -> String {
+        let mut out = Vec::new();
+        format.reporter().write(data, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_format_from_str() {
+        assert_eq!("md".parse::<OutputFormat>(), Ok(OutputFormat::Markdown));
+        assert_eq!("SARIF".parse::<OutputFormat>(), Ok(OutputFormat::Sarif));
+        assert!("xml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn test_print_format_parse() {
+        assert_eq!("jsonl".parse::<PrintFormat>(), Ok(PrintFormat::Ndjson));
+        assert!(!PrintFormat::default().is_structured());
+        assert!("csv".parse::<PrintFormat>().is_err());
+    }
+
+    #[test]
+    fn test_csv_escaping() {
+        let csv = render(OutputFormat::Csv, &sample_verify());
+        assert!(csv.contains("\"Content/A, B.uasset\",changed"));
+    }
+
+    #[test]
+    fn test_sarif_results() {
+        let sarif = render(OutputFormat::Sarif, &sample_verify());
+        let log: Value = serde_json::from_str(&sarif).unwrap();
+        let results = &log["runs"][0]["results"];
+        assert_eq!(results.as_array().unwrap().len(), 1);
+        assert_eq!(results[0]["ruleId"], "CacheChanged");
+        assert_eq!(results[0]["level"], "warning");
+    }
+}
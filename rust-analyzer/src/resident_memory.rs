@@ -0,0 +1,241 @@
+//! Resident Memory Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Memory the startup closure keeps resident at the main menu. Textures are
+//! priced from their dimensions and pixel format, meshes from their vertex
+//! and index buffers and sounds from their decompressed PCM size, all read
+//! from the asset registry tags in the editor data; packages without tags
+//! count with their bytes on disk.
+
+use crate::editor_data::EditorData;
+use crate::scanner::{package_stem, AssetInfo, AssetType};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+/// Largest resident packages listed
+pub const MAX_LARGEST: usize = 20;
+
+/// Position, tangent basis, one UV set and a vertex color
+const STATIC_VERTEX_BYTES: u64 = 32;
+/// Static vertex plus bone indices and weights
+const SKINNED_VERTEX_BYTES: u64 = 48;
+/// 32-bit indices
+const INDEX_BYTES: u64 = 4;
+/// 16-bit PCM
+const PCM_SAMPLE_BYTES: f64 = 2.0;
+const DEFAULT_SAMPLE_RATE: f64 = 48000.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MemoryCategory {
+    Texture,
+    Mesh,
+    Audio,
+    Other,
+}
+
+impl MemoryCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MemoryCategory::Texture => "texture",
+            MemoryCategory::Mesh => "mesh",
+            MemoryCategory::Audio => "audio",
+            MemoryCategory::Other => "other",
+        }
+    }
+
+    fn of(class: Option<&str>, asset_type: AssetType) -> Self {
+        match class {
+            Some(class) if class.starts_with("Texture") => MemoryCategory::Texture,
+            Some(class) if class.ends_with("Mesh") => MemoryCategory::Mesh,
+            Some(class) if class.starts_with("SoundWave") => MemoryCategory::Audio,
+            _ => match asset_type {
+                AssetType::Texture => MemoryCategory::Texture,
+                AssetType::Audio => MemoryCategory::Audio,
+                _ => MemoryCategory::Other,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CategoryMemory {
+    pub assets: usize,
+    pub resident_bytes: u64,
+    /// Assets priced from their registry tags rather than disk size
+    pub from_metadata: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResidentAsset {
+    pub relative_path: String,
+    pub category: MemoryCategory,
+    pub resident_bytes: u64,
+    pub disk_bytes: u64,
+    pub from_metadata: bool,
+}
+
+/// Estimated resident memory of the startup closure
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ResidentMemory {
+    pub total_bytes: u64,
+    /// Keyed by `MemoryCategory::as_str`
+    pub by_category: BTreeMap<String, CategoryMemory>,
+    /// Largest first, at most `MAX_LARGEST`
+    pub largest: Vec<ResidentAsset>,
+}
+
+impl ResidentMemory {
+    /// Price the packages of `closure`; companion `.uexp`/`.ubulk` bytes are
+    /// looked up in `assets`
+    pub fn build(closure: &[AssetInfo], assets: &[AssetInfo], editor_data: Option<&EditorData>) -> Self {
+        let stem = |relative: &str| package_stem(Path::new(""), relative);
+        let mut companions: HashMap<String, u64> = HashMap::new();
+        for asset in assets.iter().filter(|a| matches!(a.asset_type, AssetType::UExp | AssetType::UBulk)) {
+            *companions.entry(stem(&asset.relative_path)).or_default() += asset.size_bytes;
+        }
+
+        let mut memory = Self::default();
+        let mut resident: Vec<ResidentAsset> = Vec::new();
+        for asset in closure.iter().filter(|a| !matches!(a.asset_type, AssetType::UExp | AssetType::UBulk)) {
+            let disk_bytes = asset.size_bytes + companions.get(&stem(&asset.relative_path)).copied().unwrap_or(0);
+            let class = editor_data.and_then(|d| d.class_of(asset));
+            let category = MemoryCategory::of(class, asset.asset_type);
+            let priced = editor_data
+                .and_then(|d| d.tags_of(asset))
+                .and_then(|tags| resident_bytes(category, class.unwrap_or(""), tags));
+
+            let entry = memory.by_category.entry(category.as_str().to_string()).or_default();
+            entry.assets += 1;
+            entry.resident_bytes += priced.unwrap_or(disk_bytes);
+            entry.from_metadata += usize::from(priced.is_some());
+            memory.total_bytes += priced.unwrap_or(disk_bytes);
+            resident.push(ResidentAsset {
+                relative_path: asset.relative_path.replace('\\', "/"),
+                category,
+                resident_bytes: priced.unwrap_or(disk_bytes),
+                disk_bytes,
+                from_metadata: priced.is_some(),
+            });
+        }
+        resident.sort_by(|a, b| b.resident_bytes.cmp(&a.resident_bytes).then(a.relative_path.cmp(&b.relative_path)));
+        resident.truncate(MAX_LARGEST);
+        memory.largest = resident;
+        memory
+    }
+
+    /// Assets priced from their registry tags
+    pub fn from_metadata(&self) -> usize {
+        self.by_category.values().map(|c| c.from_metadata).sum()
+    }
+}
+
+/// Resident bytes of a `category` asset of editor `class` from its registry
+/// tags, `None` when the tags it needs are missing
+pub fn resident_bytes(category: MemoryCategory, class: &str, tags: &BTreeMap<String, String>) -> Option<u64> {
+    let number = |name: &str| tags.get(name).and_then(|v| v.trim().parse::<f64>().ok());
+    match category {
+        MemoryCategory::Texture => {
+            // "2048x1024", volumes and arrays add a depth: "256x256x64"
+            let texels = tags
+                .get("Dimensions")?
+                .split('x')
+                .map(|d| d.trim().parse::<u64>().ok())
+                .product::<Option<u64>>()?;
+            let faces = if class == "TextureCube" { 6 } else { 1 };
+            let bits = texels * faces * pixel_format_bits(tags.get("Format").map(String::as_str).unwrap_or(""));
+            // Full mip chain adds a third
+            Some(bits / 8 * 4 / 3)
+        }
+        MemoryCategory::Mesh => {
+            let vertices = number("Vertices")? as u64;
+            let triangles = number("Triangles").unwrap_or(0.0) as u64;
+            let vertex_bytes = if class.starts_with("Skeletal") { SKINNED_VERTEX_BYTES } else { STATIC_VERTEX_BYTES };
+            Some(vertices * vertex_bytes + triangles * 3 * INDEX_BYTES)
+        }
+        MemoryCategory::Audio => {
+            let duration = number("Duration")?;
+            let sample_rate = number("SampleRate").or_else(|| number("ImportedSampleRate")).unwrap_or(DEFAULT_SAMPLE_RATE);
+            let channels = number("NumChannels").or_else(|| number("Channels")).unwrap_or(1.0);
+            Some((duration * sample_rate * channels * PCM_SAMPLE_BYTES) as u64)
+        }
+        MemoryCategory::Other => None,
+    }
+}
+
+/// Bits per texel of an `EPixelFormat` name (`PF_DXT1`, `PF_B8G8R8A8`, ...)
+pub fn pixel_format_bits(format: &str) -> u64 {
+    match format.trim_start_matches("PF_") {
+        "DXT1" | "BC4" | "ETC1" | "ETC2_RGB" | "ASTC_6x6" | "ASTC_8x8" => 4,
+        "DXT3" | "DXT5" | "BC5" | "BC6H" | "BC7" | "ETC2_RGBA" | "ASTC_4x4" | "G8" | "A8" | "R8" => 8,
+        "G16" | "R16F" | "V8U8" | "R5G6B5_UNORM" => 16,
+        "FloatRGBA" | "A16B16G16R16" | "R16G16B16A16_UNORM" => 64,
+        "A32B32G32R32F" => 128,
+        _ => 32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::editor_data::PackageDependencies;
+
+    fn asset(relative_path: &str, size_bytes: u64) -> AssetInfo {
+        AssetInfo {
+            path: Path::new("/p").join(relative_path),
+            relative_path: relative_path.to_string(),
+            asset_type: AssetType::from_extension(relative_path.rsplit('.').next().unwrap_or("")),
+            size_bytes,
+            modified: 0,
+            mount_point: "/Game".to_string(),
+        }
+    }
+
+    fn package(class: &str, tags: &[(&str, &str)]) -> PackageDependencies {
+        PackageDependencies {
+            class: class.to_string(),
+            tags: tags.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_resident_memory() {
+        let assets = vec![
+            asset("Content/UI/Logo.uasset", 1000),
+            asset("Content/UI/Logo.ubulk", 9000),
+            asset("Content/Props/Crate.uasset", 500),
+            asset("Content/Audio/Theme.uasset", 300),
+            asset("Content/UI/Menu.uasset", 2000),
+        ];
+        let mut editor_data = EditorData::default();
+        editor_data.packages.insert(
+            "/Game/UI/Logo".to_string(),
+            package("Texture2D", &[("Dimensions", "1024x1024"), ("Format", "PF_DXT5")]),
+        );
+        editor_data
+            .packages
+            .insert("/Game/Props/Crate".to_string(), package("StaticMesh", &[("Vertices", "1000"), ("Triangles", "500")]));
+        editor_data.packages.insert(
+            "/Game/Audio/Theme".to_string(),
+            package("SoundWave", &[("Duration", "10.0"), ("SampleRate", "44100"), ("NumChannels", "2")]),
+        );
+
+        let closure: Vec<AssetInfo> = assets.iter().filter(|a| !a.relative_path.ends_with(".ubulk")).cloned().collect();
+        let memory = ResidentMemory::build(&closure, &assets, Some(&editor_data));
+
+        assert_eq!(memory.by_category["texture"].resident_bytes, 1024 * 1024 * 4 / 3);
+        assert_eq!(memory.by_category["mesh"].resident_bytes, 1000 * 32 + 500 * 3 * 4);
+        assert_eq!(memory.by_category["audio"].resident_bytes, 10 * 44100 * 2 * 2);
+        assert_eq!(memory.by_category["other"].resident_bytes, 2000);
+        assert_eq!(memory.from_metadata(), 3);
+        assert_eq!(memory.largest[0].relative_path, "Content/Audio/Theme.uasset");
+        assert_eq!(memory.largest.iter().find(|a| a.category == MemoryCategory::Texture).unwrap().disk_bytes, 10000);
+
+        let without = ResidentMemory::build(&closure, &assets, None);
+        assert_eq!(without.total_bytes, 10000 + 500 + 300 + 2000);
+        assert_eq!(without.from_metadata(), 0);
+    }
+}
@@ -0,0 +1,354 @@
+//! Rule Packs Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! External validation and recommendation rules in TOML or JSON files,
+//! loaded from a directory, a file or a URL listed under `[[rule_packs]]`
+//! in `.uefast.toml`, optionally pinned to a pack version
+
+use crate::analyzer::{AnalysisReport, Priority, Recommendation};
+use crate::report::Severity;
+use crate::scanner::package_name_from_relative;
+use crate::scenarios::matches_pattern;
+use crate::{FastStartupError, Result, STATE_DIR};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+use xxhash_rust::xxh3::xxh3_64;
+
+/// Downloaded packs, reused when the URL cannot be reached
+pub const RULE_PACK_CACHE_DIR: &str = "rule_packs";
+
+const MB: f64 = 1024.0 * 1024.0;
+
+/// Report values a metric rule can bound
+pub const METRICS: &[&str] = &[
+    "startup_assets",
+    "startup_mb",
+    "startup_closure_mb",
+    "resident_mb",
+    "total_assets",
+    "total_mb",
+    "duplicates",
+    "stale_imports",
+    "dependencies",
+    "estimated_savings_seconds",
+];
+
+/// One `[[rule_packs]]` entry of `.uefast.toml`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RulePackSource {
+    /// Directory of `.toml`/`.json` packs, a single pack file (relative to
+    /// the project root) or an `http(s)://` URL
+    pub source: String,
+    /// Fail unless the pack declares exactly this version
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RulePack {
+    pub name: String,
+    pub version: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub rules: Vec<Rule>,
+}
+
+/// A rule matches either startup assets (`path`, `min_size_mb`, `tag`,
+/// `preloaded`, all given conditions must hold) or a report `metric` above
+/// `max`. Each match is a finding; `recommendation` adds one recommendation
+/// per rule that matched.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Rule {
+    pub id: String,
+    #[serde(default = "default_severity")]
+    pub severity: Severity,
+    /// Finding text; `{path}`, `{size_mb}`, `{value}` and `{max}` are filled in
+    pub message: String,
+    /// Package pattern as in scenarios: `/Game/Movies/`, `/Game/UI/WBP_*`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_size_mb: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    /// Only assets a config preload forces (`true`) or does not (`false`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preloaded: Option<bool>,
+    /// One of `METRICS`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metric: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    /// Recommendation text; `{count}` is the number of matches
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recommendation: Option<String>,
+    #[serde(default = "default_priority")]
+    pub priority: Priority,
+    #[serde(default)]
+    pub impact_seconds: f64,
+}
+
+fn default_severity() -> Severity {
+    Severity::Warning
+}
+
+fn default_priority() -> Priority {
+    Priority::Medium
+}
+
+/// A rule matching the report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleHit {
+    pub pack: String,
+    pub rule_id: String,
+    pub severity: Severity,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+impl Rule {
+    fn is_asset_rule(&self) -> bool {
+        self.path.is_some() || self.min_size_mb.is_some() || self.tag.is_some() || self.preloaded.is_some()
+    }
+
+    fn validate(&self, pack: &str) -> Result<()> {
+        let invalid = |reason: String| FastStartupError::ConfigError(format!("rule {}/{}: {}", pack, self.id, reason));
+        match (&self.metric, self.is_asset_rule()) {
+            (Some(_), true) => Err(invalid("a rule has either a metric or asset conditions, not both".to_string())),
+            (None, false) => Err(invalid("no metric or asset condition".to_string())),
+            (Some(metric), false) if !METRICS.contains(&metric.as_str()) => {
+                Err(invalid(format!("unknown metric {}; known: {}", metric, METRICS.join(", "))))
+            }
+            (Some(_), false) if self.max.is_none() => Err(invalid("a metric rule needs max".to_string())),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl RulePack {
+    /// Parse a pack; `.json` files are JSON, everything else TOML
+    pub fn parse(text: &str, name_hint: &str) -> Result<Self> {
+        let pack: Self = if name_hint.to_ascii_lowercase().ends_with(".json") {
+            serde_json::from_str(text).map_err(|e| FastStartupError::ConfigError(format!("{}: {}", name_hint, e)))?
+        } else {
+            toml::from_str(text).map_err(|e| FastStartupError::ConfigError(format!("{}: {}", name_hint, e)))?
+        };
+        for rule in &pack.rules {
+            rule.validate(&pack.name)?;
+        }
+        Ok(pack)
+    }
+}
+
+/// Every pack listed in the config, in order
+pub fn load_all(project_root: &Path, sources: &[RulePackSource]) -> Result<Vec<RulePack>> {
+    let mut packs = Vec::new();
+    for source in sources {
+        let loaded = load(project_root, &source.source)?;
+        for pack in &loaded {
+            if let Some(pin) = &source.version {
+                if &pack.version != pin {
+                    return Err(FastStartupError::ConfigError(format!(
+                        "rule pack {} from {} is version {}, config pins {}",
+                        pack.name, source.source, pack.version, pin
+                    )));
+                }
+            }
+            info!("Rule pack {} {}: {} rules", pack.name, pack.version, pack.rules.len());
+        }
+        packs.extend(loaded);
+    }
+    Ok(packs)
+}
+
+fn load(project_root: &Path, source: &str) -> Result<Vec<RulePack>> {
+    if source.starts_with("http://") || source.starts_with("https://") {
+        return Ok(vec![fetch(project_root, source)?]);
+    }
+
+    let path = project_root.join(source);
+    let files: Vec<PathBuf> = if path.is_dir() {
+        let mut files: Vec<PathBuf> = std::fs::read_dir(&path)?
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| {
+                p.extension()
+                    .is_some_and(|e| e.eq_ignore_ascii_case("toml") || e.eq_ignore_ascii_case("json"))
+            })
+            .collect();
+        files.sort();
+        files
+    } else {
+        vec![path]
+    };
+    files
+        .iter()
+        .map(|file| {
+            let text = std::fs::read_to_string(file)
+                .map_err(|e| FastStartupError::ConfigError(format!("{}: {}", file.display(), e)))?;
+            RulePack::parse(&text, &file.to_string_lossy())
+        })
+        .collect()
+}
+
+/// Download a pack, keeping a copy for when the URL is unreachable
+fn fetch(project_root: &Path, url: &str) -> Result<RulePack> {
+    let extension = if url.to_ascii_lowercase().ends_with(".json") { "json" } else { "toml" };
+    let cached = project_root
+        .join(STATE_DIR)
+        .join(RULE_PACK_CACHE_DIR)
+        .join(format!("{:016x}.{}", xxh3_64(url.as_bytes()), extension));
+
+    match ureq::get(url).call().map_err(|e| e.to_string()).and_then(|r| r.into_string().map_err(|e| e.to_string())) {
+        Ok(text) => {
+            let pack = RulePack::parse(&text, url)?;
+            if let Some(parent) = cached.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&cached, text)?;
+            Ok(pack)
+        }
+        Err(e) => {
+            let text = std::fs::read_to_string(&cached)
+                .map_err(|_| FastStartupError::ConfigError(format!("{}: {}", url, e)))?;
+            warn!("Rule pack {} unreachable ({}), using the copy from the last download", url, e);
+            RulePack::parse(&text, url)
+        }
+    }
+}
+
+fn metric(report: &AnalysisReport, name: &str) -> f64 {
+    match name {
+        "startup_assets" => report.startup_assets as f64,
+        "startup_mb" => report.startup_size_bytes as f64 / MB,
+        "startup_closure_mb" => report.startup_closure_size_bytes.value / MB,
+        "resident_mb" => report.resident_memory.total_bytes as f64 / MB,
+        "total_assets" => report.total_assets as f64,
+        "total_mb" => report.total_size_bytes as f64 / MB,
+        "duplicates" => report.duplicate_count as f64,
+        "stale_imports" => report.stale_imports.len() as f64,
+        "dependencies" => report.dependency_count as f64,
+        "estimated_savings_seconds" => report.estimated_savings_seconds,
+        _ => 0.0,
+    }
+}
+
+/// Findings and recommendations of `packs` for `report`
+pub fn evaluate(packs: &[RulePack], report: &AnalysisReport) -> (Vec<RuleHit>, Vec<Recommendation>) {
+    let mut hits = Vec::new();
+    let mut recommendations = Vec::new();
+    for pack in packs {
+        for rule in &pack.rules {
+            let hit = |message: String, location: Option<String>| RuleHit {
+                pack: pack.name.clone(),
+                rule_id: rule.id.clone(),
+                severity: rule.severity,
+                message,
+                location,
+            };
+            let before = hits.len();
+            match &rule.metric {
+                Some(name) => {
+                    let value = metric(report, name);
+                    let max = rule.max.unwrap_or(f64::INFINITY);
+                    if value > max {
+                        let message = rule
+                            .message
+                            .replace("{value}", &format!("{:.1}", value))
+                            .replace("{max}", &format!("{:.1}", max));
+                        hits.push(hit(message, None));
+                    }
+                }
+                None => {
+                    for asset in &report.startup_asset_list {
+                        let matches = rule.path.as_ref().is_none_or(|pattern| {
+                            package_name_from_relative(&asset.relative_path)
+                                .is_some_and(|package| matches_pattern(pattern, &package))
+                        }) && rule.min_size_mb.is_none_or(|min| asset.size_bytes as f64 / MB >= min)
+                            && rule.tag.as_ref().is_none_or(|tag| asset.tags.contains(tag))
+                            && rule.preloaded.is_none_or(|preloaded| asset.preloaded_by.is_some() == preloaded);
+                        if matches {
+                            let message = rule
+                                .message
+                                .replace("{path}", &asset.relative_path)
+                                .replace("{size_mb}", &format!("{:.1}", asset.size_bytes as f64 / MB));
+                            hits.push(hit(message, Some(asset.relative_path.clone())));
+                        }
+                    }
+                }
+            }
+
+            let count = hits.len() - before;
+            if let (Some(text), true) = (&rule.recommendation, count > 0) {
+                recommendations.push(Recommendation {
+                    priority: rule.priority,
+                    category: rule.id.clone(),
+                    message: text.replace("{count}", &count.to_string()),
+                    estimated_impact_seconds: rule.impact_seconds,
+                });
+            }
+        }
+    }
+    (hits, recommendations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PACK: &str = r#"
+name = "frontend"
+version = "1.2.0"
+
+[[rules]]
+id = "NoMoviesAtStartup"
+message = "Movie {path} ({size_mb} MB) loads at startup"
+path = "/Game/Movies/"
+min_size_mb = 1.0
+recommendation = "Stream the {count} startup movies on demand"
+priority = "High"
+
+[[rules]]
+id = "ClosureBudget"
+severity = "error"
+metric = "startup_closure_mb"
+max = 300.0
+message = "Startup closure is {value} MB, over {max} MB"
+"#;
+
+    #[test]
+    fn test_parse_and_validate() {
+        let pack = RulePack::parse(PACK, "frontend.toml").unwrap();
+        assert_eq!(pack.rules.len(), 2);
+        assert_eq!(pack.rules[1].severity, Severity::Error);
+
+        let bad = "name = \"x\"\nversion = \"1\"\n[[rules]]\nid = \"R\"\nmessage = \"m\"\nmetric = \"startup_size\"\nmax = 1.0\n";
+        assert!(RulePack::parse(bad, "x.toml").unwrap_err().to_string().contains("unknown metric"));
+        let empty = "name = \"x\"\nversion = \"1\"\n[[rules]]\nid = \"R\"\nmessage = \"m\"\n";
+        assert!(RulePack::parse(empty, "x.toml").is_err());
+    }
+
+    #[test]
+    fn test_load_pinned_directory() {
+        let root = std::env::temp_dir().join(format!("uefast_rule_packs_{}", std::process::id()));
+        std::fs::create_dir_all(root.join("rules")).unwrap();
+        std::fs::write(root.join("rules/frontend.toml"), PACK).unwrap();
+        std::fs::write(root.join("rules/README.md"), "not a pack").unwrap();
+
+        let source = |version: Option<&str>| RulePackSource {
+            source: "rules".to_string(),
+            version: version.map(String::from),
+        };
+        assert_eq!(load_all(&root, &[source(Some("1.2.0"))]).unwrap().len(), 1);
+        assert!(load_all(&root, &[source(Some("1.3.0"))]).is_err());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,143 @@
+//! Sampling Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Reproducible asset sampling and extrapolation for quick analysis runs
+
+use crate::hash::hash_bytes;
+use crate::scanner::AssetInfo;
+use crate::{FastStartupError, Result};
+use serde::{Deserialize, Serialize};
+
+/// z-score for a 95% confidence interval
+const Z_95: f64 = 1.96;
+
+pub const DEFAULT_SAMPLE_SEED: u64 = 0x5EED;
+
+/// Seeded Bernoulli sample over relative asset paths
+#[derive(Debug, Clone, Copy)]
+pub struct AssetSample {
+    fraction: f64,
+    seed: u64,
+}
+
+impl AssetSample {
+    pub fn new(fraction: f64, seed: u64) -> Result<Self> {
+        if !(fraction > 0.0 && fraction <= 1.0) {
+            return Err(FastStartupError::InvalidArgument(format!(
+                "Sample fraction must be in (0, 1], got {}",
+                fraction
+            )));
+        }
+
+        Ok(Self { fraction, seed })
+    }
+
+    pub fn fraction(&self) -> f64 {
+        self.fraction
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Whether an asset belongs to the sample. Depends only on the relative
+    /// path and seed, so the same project yields the same sample every run.
+    pub fn contains(&self, asset: &AssetInfo) -> bool {
+        let mut key = Vec::with_capacity(asset.relative_path.len() + 8);
+        key.extend_from_slice(&self.seed.to_le_bytes());
+        key.extend_from_slice(asset.relative_path.as_bytes());
+
+        let unit = hash_bytes(&key).as_u64() as f64 / u64::MAX as f64;
+        unit < self.fraction
+    }
+
+    pub fn select(&self, assets: Vec<AssetInfo>) -> Vec<AssetInfo> {
+        assets.into_iter().filter(|a| self.contains(a)).collect()
+    }
+
+    /// Horvitz-Thompson estimate of a population total from per-item sample values
+    pub fn extrapolate(&self, values: impl IntoIterator<Item = f64>) -> Estimate {
+        let p = self.fraction;
+        let (sum, sum_sq) = values
+            .into_iter()
+            .fold((0.0, 0.0), |(s, sq), v| (s + v, sq + v * v));
+
+        let value = sum / p;
+        let variance = (1.0 - p) / (p * p) * sum_sq;
+        let margin = Z_95 * variance.sqrt();
+
+        Estimate {
+            value,
+            lower: (value - margin).max(sum),
+            upper: value + margin,
+        }
+    }
+}
+
+/// Estimated value with bounds, a 95% confidence interval when extrapolated
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct Estimate {
+    pub value: f64,
+    pub lower: f64,
+    pub upper: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SampleSummary {
+    pub fraction: f64,
+    pub seed: u64,
+    pub sampled_assets: usize,
+    pub estimated_total_assets: Estimate,
+    pub estimated_total_size_bytes: Estimate,
+    pub estimated_startup_assets: Estimate,
+    pub estimated_startup_size_bytes: Estimate,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetType;
+    use std::path::PathBuf;
+
+    fn asset(i: usize) -> AssetInfo {
+        AssetInfo {
+            path: PathBuf::from(format!("Content/A{}.uasset", i)),
+            relative_path: format!("Content/A{}.uasset", i),
+            asset_type: AssetType::UAsset,
+            size_bytes: 100,
+            modified: 0,
+            mount_point: "/Game".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_sample_is_reproducible() {
+        let assets: Vec<_> = (0..1000).map(asset).collect();
+        let sample = AssetSample::new(0.1, 7).unwrap();
+
+        let paths = |sample: &AssetSample| -> Vec<String> {
+            sample.select(assets.clone()).into_iter().map(|a| a.relative_path).collect()
+        };
+        let a = paths(&sample);
+        assert_eq!(a, paths(&sample));
+        assert!(a.len() > 50 && a.len() < 150);
+        // Another seed picks another subset
+        assert_ne!(a, paths(&AssetSample::new(0.1, 8).unwrap()));
+    }
+
+    #[test]
+    fn test_extrapolate_full_sample_is_exact() {
+        let sample = AssetSample::new(1.0, 0).unwrap();
+        let estimate = sample.extrapolate([1.0, 2.0, 3.0]);
+        assert_eq!(estimate.value, 6.0);
+        assert_eq!(estimate.lower, 6.0);
+        assert_eq!(estimate.upper, 6.0);
+    }
+
+    #[test]
+    fn test_invalid_fraction() {
+        assert!(AssetSample::new(0.0, 0).is_err());
+        assert!(AssetSample::new(1.5, 0).is_err());
+    }
+}
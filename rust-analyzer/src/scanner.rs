@@ -2,15 +2,23 @@
 //! Copyright 2026 Eddi Andreé Salazar Matos
 //! Licensed under Apache 2.0
 //!
-//! Parallel asset discovery for UE5 projects with aggressive prefetch
+//! Parallel asset discovery for UE5 projects with aggressive prefetch, over
+//! the project's `Content` and the `Content` of its enabled plugins. Once a
+//! scan index exists, local scans only revisit directories that changed.
 
+pub mod index;
+
+use crate::config::ProjectConfig;
+use crate::content::{ContentEntry, ContentProvider, LocalContent};
+use crate::project::{content_root_of, content_roots, diagnose_missing_content, ContentRoot, Project, GAME_MOUNT};
 use crate::{FastStartupError, Result};
-use rayon::prelude::*;
+use index::ScanIndex;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use walkdir::WalkDir;
-use tracing::info;
+use tracing::{debug, info, warn};
 
 /// Global counter for progress tracking
 static SCAN_PROGRESS: AtomicUsize = AtomicUsize::new(0);
@@ -22,6 +30,29 @@ pub struct AssetInfo {
     pub asset_type: AssetType,
     pub size_bytes: u64,
     pub modified: u64,
+    /// Package root the asset is mounted under: `/Game`, or `/<Plugin>`
+    /// for plugin content
+    #[serde(default = "game_mount")]
+    pub mount_point: String,
+}
+
+fn game_mount() -> String {
+    GAME_MOUNT.to_string()
+}
+
+impl AssetInfo {
+    /// Long package name under `mount_point`, the mount of the content root
+    /// the scanner found the asset in: "Plugins/Gameplay/WeaponPack/Content/AK.uasset"
+    /// of a plugin mounted at `/Weapons` is "/Weapons/AK"
+    pub fn package_name(&self) -> Option<String> {
+        let normalized = self.relative_path.replace('\\', "/");
+        let rest = match normalized.strip_prefix("Content/") {
+            Some(rest) => rest,
+            None => normalized.strip_prefix("Plugins/")?.split_once("/Content/")?.1,
+        };
+        let stem = rest.rsplit_once('.').map(|(stem, _)| stem).unwrap_or(rest);
+        Some(format!("{}/{}", self.mount_point.trim_end_matches('/'), stem))
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -71,115 +102,335 @@ impl AssetType {
     }
 }
 
+/// Size, age and path bounds applied while scanning
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AssetFilter {
+    pub min_size: Option<u64>,
+    pub max_size: Option<u64>,
+    /// Unix seconds; older files are skipped
+    pub modified_since: Option<u64>,
+    /// Globs over project-relative paths that are skipped, see `glob_matches`
+    pub exclude: Vec<String>,
+}
+
+impl AssetFilter {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn matches(&self, size_bytes: u64, modified: u64) -> bool {
+        self.min_size.is_none_or(|min| size_bytes >= min)
+            && self.max_size.is_none_or(|max| size_bytes <= max)
+            && self.modified_since.is_none_or(|since| modified >= since)
+    }
+
+    pub fn excludes(&self, relative_path: &str) -> bool {
+        self.exclude.iter().any(|pattern| glob_matches(pattern, relative_path))
+    }
+}
+
+/// Whether project-relative `path` matches `pattern`: `*` within one path
+/// segment, `**` across segments, `?` one character, and a trailing `/`
+/// everything below. Case is ignored, like on Windows.
+pub fn glob_matches(pattern: &str, path: &str) -> bool {
+    fn segments(pattern: &[&str], path: &[&str]) -> bool {
+        match pattern.split_first() {
+            None => path.is_empty(),
+            Some((&"**", rest)) => (0..=path.len()).any(|skip| segments(rest, &path[skip..])),
+            Some((first, rest)) => path
+                .split_first()
+                .is_some_and(|(segment, tail)| wildcard(first.as_bytes(), segment.as_bytes()) && segments(rest, tail)),
+        }
+    }
+    fn wildcard(pattern: &[u8], text: &[u8]) -> bool {
+        match pattern.split_first() {
+            None => text.is_empty(),
+            Some((b'*', rest)) => (0..=text.len()).any(|skip| wildcard(rest, &text[skip..])),
+            Some((b'?', rest)) => !text.is_empty() && wildcard(rest, &text[1..]),
+            Some((c, rest)) => text.first().is_some_and(|t| t.eq_ignore_ascii_case(c)) && wildcard(rest, &text[1..]),
+        }
+    }
+
+    let mut pattern = pattern.replace('\\', "/").trim_start_matches("./").trim_start_matches('/').to_string();
+    if pattern.ends_with('/') {
+        pattern.push_str("**");
+    }
+    let path = path.replace('\\', "/");
+    segments(&pattern.split('/').collect::<Vec<_>>(), &path.split('/').collect::<Vec<_>>())
+}
+
+/// "10MB", "512KB", "2GB" or plain bytes (binary units)
+pub fn parse_size(s: &str) -> Result<u64> {
+    let s = s.trim();
+    let split = s.find(|c: char| c.is_ascii_alphabetic()).unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+    let multiplier: u64 = match unit.trim().to_ascii_uppercase().as_str() {
+        "" | "B" => 1,
+        "K" | "KB" => 1024,
+        "M" | "MB" => 1024 * 1024,
+        "G" | "GB" => 1024 * 1024 * 1024,
+        other => {
+            return Err(FastStartupError::InvalidArgument(format!("Unknown size unit '{}'", other)));
+        }
+    };
+    let number: f64 = number.trim().parse().map_err(|_| {
+        FastStartupError::InvalidArgument(format!("Invalid size '{}' (expected e.g. 10MB)", s))
+    })?;
+    Ok((number * multiplier as f64) as u64)
+}
+
+/// "2026-03-01", an RFC 3339 timestamp, or "14d" (days ago), as Unix seconds
+pub fn parse_since(s: &str) -> Result<u64> {
+    let s = s.trim();
+    let invalid = || {
+        FastStartupError::InvalidArgument(format!(
+            "Invalid date '{}' (expected YYYY-MM-DD, RFC 3339 or a day count like 14d)",
+            s
+        ))
+    };
+
+    let timestamp = if let Some(days) = s.strip_suffix('d') {
+        let days: i64 = days.parse().map_err(|_| invalid())?;
+        chrono::Utc::now().timestamp() - days * 86_400
+    } else if let Ok(date) = chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        date.and_hms_opt(0, 0, 0).ok_or_else(invalid)?.and_utc().timestamp()
+    } else {
+        chrono::DateTime::parse_from_rfc3339(s).map_err(|_| invalid())?.timestamp()
+    };
+    Ok(timestamp.max(0) as u64)
+}
+
 pub struct AssetScanner {
     project_root: PathBuf,
     content_dir: PathBuf,
+    project: Option<Project>,
+    content_roots: Vec<ContentRoot>,
+    filter: AssetFilter,
+    /// `exclude` globs of the project's `.uefast.toml`, on top of the filter's
+    exclude: Vec<String>,
+    /// `[startup]` table of the project's `.uefast.toml`
+    startup: StartupHeuristics,
+    content: Arc<dyn ContentProvider>,
+    /// List local content through the scan index
+    index: bool,
 }
 
 impl AssetScanner {
     pub fn new(project_root: &Path) -> Result<Self> {
+        if !project_root.exists() {
+            return Err(FastStartupError::ProjectNotFound(
+                project_root.display().to_string()
+            ));
+        }
+
         let project_root = project_root.to_path_buf();
         
         // Find Content directory
         let content_dir = project_root.join("Content");
         if !content_dir.exists() {
-            return Err(FastStartupError::InvalidProject(
-                format!("Content directory not found: {}", content_dir.display())
-            ));
+            return Err(diagnose_missing_content(&project_root));
+        }
+
+        // Validate the .uproject when present; loose Content folders still scan
+        let project = Project::open_optional(&project_root)?;
+        if project.is_none() {
+            debug!("No .uproject in {}, scanning Content only", project_root.display());
+        }
+
+        let content_roots = project.as_ref().map(Project::content_roots).unwrap_or_else(|| content_roots(&project_root));
+        if content_roots.len() > 1 {
+            debug!("Scanning {} plugin content roots", content_roots.len() - 1);
         }
 
         Ok(Self {
+            content: Arc::new(LocalContent::new(&project_root)),
+            index: ScanIndex::exists(&project_root),
+            exclude: Vec::new(),
+            startup: StartupHeuristics::default(),
             project_root,
             content_dir,
+            project,
+            content_roots,
+            filter: AssetFilter::default(),
+        })
+    }
+
+    /// Scan through `content` instead of the local `Content` directory. The
+    /// root only needs to exist; its `.uproject` is used when present.
+    pub fn from_provider(project_root: &Path, content: Arc<dyn ContentProvider>) -> Result<Self> {
+        if !project_root.exists() {
+            return Err(FastStartupError::ProjectNotFound(
+                project_root.display().to_string()
+            ));
+        }
+
+        Ok(Self {
+            project_root: project_root.to_path_buf(),
+            content_dir: project_root.join("Content"),
+            project: Project::open_optional(project_root)?,
+            content_roots: content_roots(project_root),
+            filter: AssetFilter::default(),
+            exclude: Vec::new(),
+            startup: StartupHeuristics::default(),
+            content,
+            index: false,
         })
     }
 
+    /// Apply the exclude globs and startup heuristics of the project's
+    /// `.uefast.toml`, as loaded by the caller
+    pub fn with_config(mut self, config: &ProjectConfig) -> Self {
+        self.exclude = config.exclude.clone();
+        self.startup = config.startup.clone();
+        self
+    }
+
+    /// Skip assets outside the size, age and path bounds of `filter`; its
+    /// exclude globs add to the project's
+    pub fn with_filter(mut self, filter: AssetFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Keep a scan index for local content, creating it if needed. Local
+    /// scans use the index by default once one exists.
+    pub fn with_index(mut self, index: bool) -> Self {
+        self.index = index;
+        self
+    }
+
+    /// Whether `scan_all` lists through the scan index
+    pub fn uses_index(&self) -> bool {
+        self.index
+    }
+
+    /// Heuristics `scan_startup_critical` applies
+    pub fn startup_heuristics(&self) -> &StartupHeuristics {
+        &self.startup
+    }
+
     /// ULTRA-OPTIMIZED parallel scan with prefetch and batch processing
     pub fn scan_all(&self, filter: Option<&str>) -> Result<Vec<AssetInfo>> {
-        info!("Scanning assets in: {}", self.content_dir.display());
+        info!("Scanning assets in: {}", self.content.describe());
 
         // Reset progress counter
         SCAN_PROGRESS.store(0, Ordering::Relaxed);
 
-        // OPTIMIZATION 1: Use parallel iterator for directory walking
-        // Collect entries with minimal allocations
-        let entries: Vec<_> = WalkDir::new(&self.content_dir)
-            .follow_links(true)
-            .max_open(128) // Increase file descriptor limit for speed
-            .into_iter()
-            .filter_map(|e| e.ok())
-            .filter(|e| e.file_type().is_file())
-            .collect();
-
-        let total = entries.len();
-        info!("Found {} files, processing with {} threads...", total, rayon::current_num_threads());
-
-        // OPTIMIZATION 2: Pre-filter by extension before parallel processing
-        let filtered_entries: Vec<_> = if let Some(filter_ext) = filter {
-            entries.into_iter()
-                .filter(|e| {
-                    e.path().extension()
-                        .and_then(|ext| ext.to_str())
-                        .map(|ext| ext.eq_ignore_ascii_case(filter_ext))
-                        .unwrap_or(false)
-                })
-                .collect()
-        } else {
-            entries
+        // OPTIMIZATION 1: Pre-filter by extension before any metadata is fetched,
+        // skipping non-asset files unless explicitly filtered
+        let accept = |relative_path: &str| {
+            let Some(ext) = Path::new(relative_path).extension().and_then(|ext| ext.to_str()) else {
+                return false;
+            };
+            if self.excludes(relative_path) {
+                return false;
+            }
+            match filter {
+                Some(filter_ext) => ext.eq_ignore_ascii_case(filter_ext),
+                None => !matches!(AssetType::from_extension(ext), AssetType::Other),
+            }
+        };
+        let entries = match self.index.then(|| self.list_indexed(&accept)) {
+            Some(Ok(entries)) => entries,
+            Some(Err(e)) => {
+                warn!("Scan index unusable, listing every directory: {}", e);
+                self.content.list(&accept)?
+            }
+            None => self.content.list(&accept)?,
         };
+        info!("Found {} files", entries.len());
 
-        // OPTIMIZATION 3: Use chunk-based parallel processing for better cache locality
-        let chunk_size = (filtered_entries.len() / rayon::current_num_threads()).max(64);
-        
-        let assets: Vec<AssetInfo> = filtered_entries
-            .par_chunks(chunk_size)
-            .flat_map(|chunk| {
-                chunk.iter().filter_map(|entry| {
-                    let path = entry.path();
-                    let ext = path.extension()?.to_str()?;
-
-                    let asset_type = AssetType::from_extension(ext);
-                    
-                    // Skip non-asset files unless explicitly filtered
-                    if filter.is_none() && matches!(asset_type, AssetType::Other) {
-                        return None;
-                    }
-
-                    // OPTIMIZATION 4: Use cached metadata from walkdir
-                    let metadata = entry.metadata().ok()?;
-                    let modified = metadata.modified().ok()?
-                        .duration_since(std::time::UNIX_EPOCH).ok()?
-                        .as_secs();
-
-                    let relative_path = path.strip_prefix(&self.project_root)
-                        .ok()?
-                        .to_string_lossy()
-                        .to_string();
-
-                    // Update progress
-                    SCAN_PROGRESS.fetch_add(1, Ordering::Relaxed);
-
-                    Some(AssetInfo {
-                        path: path.to_path_buf(),
-                        relative_path,
-                        asset_type,
-                        size_bytes: metadata.len(),
-                        modified,
-                    })
-                }).collect::<Vec<_>>()
+        let assets: Vec<AssetInfo> = entries
+            .into_iter()
+            .filter(|entry| self.filter.matches(entry.size_bytes, entry.modified))
+            .map(|entry| {
+                // Update progress
+                SCAN_PROGRESS.fetch_add(1, Ordering::Relaxed);
+
+                let ext = Path::new(&entry.relative_path)
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .unwrap_or_default();
+                let mount_point = content_root_of(&self.content_roots, &entry.relative_path)
+                    .map(|root| root.mount_point.clone())
+                    .unwrap_or_else(game_mount);
+                AssetInfo {
+                    path: entry.local_path.unwrap_or_else(|| self.project_root.join(&entry.relative_path)),
+                    asset_type: AssetType::from_extension(ext),
+                    relative_path: entry.relative_path,
+                    size_bytes: entry.size_bytes,
+                    modified: entry.modified,
+                    mount_point,
+                }
             })
             .collect();
 
         info!("Processed {} assets", assets.len());
+        crate::usage::observe_scale(assets.len(), assets.iter().map(|a| a.size_bytes).sum());
         Ok(assets)
     }
 
+    /// Local listing through the scan index, which is updated as it goes
+    fn list_indexed(&self, accept: &(dyn Fn(&str) -> bool + Sync)) -> Result<Vec<ContentEntry>> {
+        let mut index = ScanIndex::open(&self.project_root)?;
+        // The same roots the plain local listing walks
+        let dirs: Vec<String> = content_roots(&self.project_root).into_iter().map(|root| root.relative_dir).collect();
+        let entries = index.list(&self.project_root, &dirs, accept)?;
+        let stats = index.stats();
+        info!(
+            "Scan index: {} of {} directories unchanged, {} files re-stat'ed",
+            stats.dirs_reused,
+            stats.dirs_reused + stats.dirs_listed,
+            stats.files_stated
+        );
+        index.save()?;
+        Ok(entries)
+    }
+
+    /// Whether the filter or the project's exclude globs drop `relative_path`
+    fn excludes(&self, relative_path: &str) -> bool {
+        self.filter.excludes(relative_path) || self.exclude.iter().any(|pattern| glob_matches(pattern, relative_path))
+    }
+
+    /// One local file as `scan_all` would list it; `None` when it is gone,
+    /// not an asset, outside the content roots or excluded
+    pub fn asset_info(&self, relative_path: &str) -> Option<AssetInfo> {
+        if self.excludes(relative_path) {
+            return None;
+        }
+        let root = content_root_of(&self.content_roots, relative_path)?;
+        let ext = Path::new(relative_path).extension().and_then(|ext| ext.to_str())?;
+        let asset_type = AssetType::from_extension(ext);
+        if asset_type == AssetType::Other {
+            return None;
+        }
+        let path = self.project_root.join(relative_path);
+        let metadata = std::fs::metadata(&path).ok().filter(|m| m.is_file())?;
+        let modified = metadata
+            .modified()
+            .ok()?
+            .duration_since(std::time::UNIX_EPOCH)
+            .ok()?
+            .as_secs();
+        if !self.filter.matches(metadata.len(), modified) {
+            return None;
+        }
+        Some(AssetInfo {
+            path,
+            relative_path: relative_path.to_string(),
+            asset_type,
+            size_bytes: metadata.len(),
+            modified,
+            mount_point: root.mount_point.clone(),
+        })
+    }
+
     /// TURBO scan - only returns paths without metadata for maximum speed
     pub fn scan_paths_only(&self) -> Result<Vec<PathBuf>> {
-        let paths: Vec<PathBuf> = WalkDir::new(&self.content_dir)
-            .follow_links(true)
-            .max_open(256)
-            .into_iter()
+        let paths: Vec<PathBuf> = self
+            .content_roots
+            .iter()
+            .flat_map(|root| WalkDir::new(self.project_root.join(&root.relative_dir)).follow_links(true).max_open(256))
             .filter_map(|e| e.ok())
             .filter(|e| {
                 if !e.file_type().is_file() {
@@ -211,21 +462,7 @@ impl AssetScanner {
         // Filter for assets that are typically loaded at startup
         let critical: Vec<_> = all_assets
             .into_iter()
-            .filter(|asset| {
-                // Maps are always startup-critical
-                if asset.asset_type == AssetType::UMap {
-                    return true;
-                }
-                
-                // Check for common startup paths
-                let path_lower = asset.relative_path.to_lowercase();
-                path_lower.contains("startup") ||
-                path_lower.contains("default") ||
-                path_lower.contains("core") ||
-                path_lower.contains("engine") ||
-                path_lower.contains("ui") ||
-                path_lower.contains("hud")
-            })
+            .filter(|asset| is_startup_candidate(asset, &self.startup))
             .collect();
 
         info!("Found {} startup-critical assets", critical.len());
@@ -244,6 +481,154 @@ impl AssetScanner {
     pub fn content_dir(&self) -> &Path {
         &self.content_dir
     }
+
+    /// `Content` and the plugin content directories scanned, with their
+    /// mount points
+    pub fn content_roots(&self) -> &[ContentRoot] {
+        &self.content_roots
+    }
+
+    /// Parsed `.uproject`, if the root has one
+    pub fn project(&self) -> Option<&Project> {
+        self.project.as_ref()
+    }
+
+    /// Where assets are listed and read from
+    pub fn content(&self) -> &Arc<dyn ContentProvider> {
+        &self.content
+    }
+}
+
+/// Convert a project-relative file path ("Content/Maps/Entry.umap") into a
+/// long package name ("/Game/Maps/Entry"). Plugin content mounts under the
+/// plugin directory's name: "Plugins/Gameplay/Weapons/Content/Rifle.uasset"
+/// is "/Weapons/Rifle".
+pub fn package_name_from_relative(relative_path: &str) -> Option<String> {
+    let normalized = relative_path.replace('\\', "/");
+    let (mount, rest) = match normalized.strip_prefix("Content/") {
+        Some(rest) => (GAME_MOUNT, rest),
+        None => {
+            let (plugin_dir, rest) = normalized.strip_prefix("Plugins/")?.split_once("/Content/")?;
+            (plugin_dir.rsplit('/').next()?, rest)
+        }
+    };
+    let stem = rest.rsplit_once('.').map(|(stem, _)| stem).unwrap_or(rest);
+    Some(format!("/{}/{}", mount.trim_start_matches('/'), stem))
+}
+
+/// Extensionless project-relative path an asset reference points at. The
+/// reference is a project-relative or absolute file path, or a `/Game/`
+/// package name with an optional object suffix, e.g. `/Game/UI/Icon.Icon`
+/// and `Content/UI/Icon.uasset` both give `Content/UI/Icon`.
+pub fn package_stem(project_root: &Path, reference: &str) -> String {
+    let reference = reference.replace('\\', "/");
+    let relative = if let Some(rest) = reference.strip_prefix("/Game/") {
+        format!("Content/{}", rest.split('.').next().unwrap_or(rest))
+    } else {
+        let path = Path::new(&reference);
+        path.strip_prefix(project_root)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/")
+            .trim_start_matches("./")
+            .to_string()
+    };
+    match relative.rsplit_once('.') {
+        Some((stem, ext)) if !ext.contains('/') => stem.to_string(),
+        _ => relative,
+    }
+}
+
+/// Path fragments of assets typically loaded at startup
+const DEFAULT_STARTUP_KEYWORDS: &[&str] = &["startup", "default", "core", "engine", "ui", "hud"];
+
+/// `[startup]` table of `.uefast.toml`: which assets count as loaded at
+/// startup before the dependency graph says more
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StartupHeuristics {
+    /// Case-insensitive path fragments; setting it replaces the built-in
+    /// list (startup, default, core, engine, ui, hud)
+    #[serde(default = "default_startup_keywords")]
+    pub keywords: Vec<String>,
+    /// Globs over project-relative paths that are always startup-critical
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub paths: Vec<String>,
+}
+
+fn default_startup_keywords() -> Vec<String> {
+    DEFAULT_STARTUP_KEYWORDS.iter().map(|k| k.to_string()).collect()
+}
+
+impl Default for StartupHeuristics {
+    fn default() -> Self {
+        Self {
+            keywords: default_startup_keywords(),
+            paths: Vec::new(),
+        }
+    }
+}
+
+impl StartupHeuristics {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn is_candidate(&self, asset: &AssetInfo) -> bool {
+        // Maps are always startup-critical
+        if asset.asset_type == AssetType::UMap {
+            return true;
+        }
+
+        let path_lower = asset.relative_path.to_lowercase();
+        self.keywords.iter().any(|keyword| path_lower.contains(&keyword.to_lowercase()))
+            || self.paths.iter().any(|pattern| glob_matches(pattern, &asset.relative_path))
+    }
+}
+
+/// Path/type heuristic for assets that are typically loaded at startup,
+/// with the project's `heuristics`
+pub fn is_startup_candidate(asset: &AssetInfo, heuristics: &StartupHeuristics) -> bool {
+    heuristics.is_candidate(asset)
+}
+
+/// Files or directories whose paths differ only by case. They are distinct
+/// on Linux and console filesystems but the same entry on Windows.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaseCollision {
+    /// Distinct spellings, sorted
+    pub paths: Vec<String>,
+}
+
+/// Case collisions among `relative_paths`, including their parent
+/// directories. Entries under an already colliding directory are not
+/// reported again.
+pub fn find_case_collisions<'a>(relative_paths: impl IntoIterator<Item = &'a str>) -> Vec<CaseCollision> {
+    let mut entries: std::collections::BTreeSet<String> = std::collections::BTreeSet::new();
+    for path in relative_paths {
+        let path = path.replace('\\', "/");
+        let mut end = 0;
+        for component in path.split('/') {
+            end += component.len();
+            entries.insert(path[..end].to_string());
+            end += 1;
+        }
+    }
+
+    let mut by_folded: std::collections::BTreeMap<String, Vec<&String>> = std::collections::BTreeMap::new();
+    for entry in &entries {
+        by_folded.entry(entry.to_lowercase()).or_default().push(entry);
+    }
+
+    let parent = |p: &str| p.rsplit_once('/').map(|(parent, _)| parent.to_string()).unwrap_or_default();
+    by_folded
+        .into_values()
+        .filter(|spellings| spellings.len() > 1)
+        .filter(|spellings| spellings.iter().all(|s| parent(s) == parent(spellings[0])))
+        .map(|spellings| CaseCollision {
+            paths: spellings.into_iter().cloned().collect(),
+        })
+        .collect()
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -252,6 +637,8 @@ pub struct ScanReport {
     pub by_type: std::collections::HashMap<String, usize>,
     pub total_size_bytes: u64,
     pub scan_duration_ms: u64,
+    #[serde(default)]
+    pub assets: Vec<AssetInfo>,
 }
 
 impl ScanReport {
@@ -267,6 +654,7 @@ impl ScanReport {
             by_type,
             total_size_bytes: assets.iter().map(|a| a.size_bytes).sum(),
             scan_duration_ms: duration_ms,
+            assets: assets.to_vec(),
         }
     }
 }
@@ -275,6 +663,117 @@ impl ScanReport {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_asset_filter() {
+        assert_eq!(parse_size("10MB").unwrap(), 10 * 1024 * 1024);
+        assert_eq!(parse_size("1.5k").unwrap(), 1536);
+        assert!(parse_size("10XB").is_err());
+        assert_eq!(parse_since("2026-03-01").unwrap(), 1_772_323_200);
+
+        let filter = AssetFilter {
+            min_size: Some(100),
+            max_size: None,
+            modified_since: Some(50),
+            exclude: vec!["Content/Developers/".to_string(), "**/*_Test.uasset".to_string()],
+        };
+        assert!(filter.matches(100, 50));
+        assert!(!filter.matches(99, 60));
+        assert!(!filter.matches(200, 49));
+        assert!(filter.excludes("Content/Developers/Ana/Sandbox.umap"));
+        assert!(filter.excludes("Plugins/Weapons/Content/AK_test.uasset"));
+        assert!(!filter.excludes("Content/DevelopersGuide.uasset"));
+    }
+
+    #[test]
+    fn test_glob_matches() {
+        assert!(glob_matches("Content/Maps/*.umap", "Content/Maps/Entry.umap"));
+        assert!(!glob_matches("Content/Maps/*.umap", "Content/Maps/Sub/Entry.umap"));
+        assert!(glob_matches("Content/**/*.umap", "Content/Maps/Sub/Entry.umap"));
+        assert!(glob_matches("Content/**", "Content/A.uasset"));
+        assert!(glob_matches("content\\ui\\w_?ain.uasset", "Content/UI/W_Main.uasset"));
+        assert!(!glob_matches("Content/UI", "Content/UI/W_Main.uasset"));
+    }
+
+    #[test]
+    fn test_startup_heuristics() {
+        let asset = |relative_path: &str, asset_type: AssetType| AssetInfo {
+            path: Path::new("/p").join(relative_path),
+            relative_path: relative_path.to_string(),
+            asset_type,
+            size_bytes: 1,
+            modified: 0,
+            mount_point: "/Game".to_string(),
+        };
+        let menu = asset("Content/Frontend/Menu.uasset", AssetType::UAsset);
+        let hud = asset("Content/HUD/Crosshair.uasset", AssetType::UAsset);
+        let map = asset("Content/Maps/Arena.umap", AssetType::UMap);
+        let defaults = StartupHeuristics::default();
+        assert!(defaults.is_default());
+        assert!(!defaults.is_candidate(&menu) && defaults.is_candidate(&hud) && defaults.is_candidate(&map));
+
+        let configured = StartupHeuristics {
+            keywords: Vec::new(),
+            paths: vec!["Content/Frontend/".to_string()],
+        };
+        assert!(configured.is_candidate(&menu) && !configured.is_candidate(&hud) && configured.is_candidate(&map));
+
+        // Each scanner keeps the heuristics of its own project
+        let root = std::env::temp_dir().join(format!("uefast_heuristics_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(root.join("Content/Frontend")).unwrap();
+        std::fs::write(root.join("Content/Frontend/Menu.uasset"), b"menu").unwrap();
+        let config = ProjectConfig { startup: configured, ..Default::default() };
+        let critical = |scanner: AssetScanner| scanner.scan_startup_critical().unwrap().len();
+        assert_eq!(critical(AssetScanner::new(&root).unwrap().with_config(&config)), 1);
+        assert_eq!(critical(AssetScanner::new(&root).unwrap()), 0);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_find_case_collisions() {
+        let collisions = find_case_collisions([
+            "Content/UI/W_Menu.uasset",
+            "Content/Ui/W_Menu.uasset",
+            "Content/Ui/W_Hud.uasset",
+            "Content/Maps/Entry.umap",
+            "Content/Maps/entry.umap",
+            "Content/Maps/Other.umap",
+        ]);
+
+        assert_eq!(collisions, vec![
+            CaseCollision { paths: vec!["Content/Maps/Entry.umap".to_string(), "Content/Maps/entry.umap".to_string()] },
+            CaseCollision { paths: vec!["Content/UI".to_string(), "Content/Ui".to_string()] },
+        ]);
+    }
+
+    #[test]
+    fn test_package_name_from_relative() {
+        assert_eq!(
+            package_name_from_relative("Content\\Maps\\Entry.umap").as_deref(),
+            Some("/Game/Maps/Entry")
+        );
+        assert_eq!(
+            package_name_from_relative("Plugins/Gameplay/Weapons/Content/Rifles/AK.uasset").as_deref(),
+            Some("/Weapons/Rifles/AK")
+        );
+        assert_eq!(package_name_from_relative("Plugins/Weapons/Source/Weapons.cpp"), None);
+        assert_eq!(package_name_from_relative("Config/DefaultGame.ini"), None);
+
+        // Plugins mount under their .uplugin name, not their directory's
+        let asset = |relative_path: &str, mount_point: &str| AssetInfo {
+            path: Path::new("/p").join(relative_path),
+            relative_path: relative_path.to_string(),
+            asset_type: AssetType::UAsset,
+            size_bytes: 1,
+            modified: 0,
+            mount_point: mount_point.to_string(),
+        };
+        let plugin = asset("Plugins/Gameplay/WeaponPack/Content/Rifles/AK.uasset", "/Weapons");
+        assert_eq!(plugin.package_name().as_deref(), Some("/Weapons/Rifles/AK"));
+        assert_eq!(asset("Content/Maps/Entry.umap", "/Game").package_name().as_deref(), Some("/Game/Maps/Entry"));
+        assert_eq!(asset("Config/DefaultGame.ini", "/Game").package_name(), None);
+    }
+
     #[test]
     fn test_asset_type_from_extension() {
         assert_eq!(AssetType::from_extension("uasset"), AssetType::UAsset);
@@ -0,0 +1,470 @@
+//! Scan Index Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Directory listings, file stamps and content hashes persisted in
+//! `.uefast/scan_index.sqlite`, so repeated scans of a large Content tree only
+//! revisit what changed. A directory whose modification time matches the
+//! index is not listed again; its files and subdirectories come from the
+//! index. Creating, deleting or renaming a file changes its directory's time.
+//! Rewriting a file in place does not, so the files of an unchanged
+//! directory are still stat'ed, and a recorded hash is only reused while the
+//! size and nanosecond modification time match; `scan --reindex` starts over.
+
+use crate::content::ContentEntry;
+use crate::hash::HashAlgorithm;
+use crate::{FastStartupError, Result, STATE_DIR};
+use rayon::prelude::*;
+use rusqlite::{params, Connection, OptionalExtension};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::Metadata;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use tracing::debug;
+
+pub const SCAN_INDEX_FILE: &str = "scan_index.sqlite";
+
+/// Bumped whenever the schema or the meaning of a column changes
+const SCAN_INDEX_VERSION: i64 = 2;
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS meta (key TEXT PRIMARY KEY, value INTEGER NOT NULL);
+    CREATE TABLE IF NOT EXISTS dirs (
+        path TEXT PRIMARY KEY,
+        parent TEXT,
+        modified_ns INTEGER NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS files (
+        path TEXT PRIMARY KEY,
+        dir TEXT NOT NULL,
+        size_bytes INTEGER NOT NULL,
+        modified_ns INTEGER NOT NULL,
+        hash_algorithm TEXT,
+        hash INTEGER
+    );
+    CREATE INDEX IF NOT EXISTS files_by_dir ON files (dir);
+";
+
+#[derive(Debug, Clone)]
+struct IndexedFile {
+    size_bytes: u64,
+    modified_ns: i64,
+    hash: Option<(HashAlgorithm, u64)>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct IndexedDir {
+    parent: Option<String>,
+    modified_ns: i64,
+    subdirs: Vec<String>,
+    files: BTreeMap<String, IndexedFile>,
+}
+
+/// How much of the last listing came from the index
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IndexStats {
+    pub dirs_listed: usize,
+    pub dirs_reused: usize,
+    pub files_stated: usize,
+    /// Stat'ed files of unchanged directories whose size and time matched
+    pub files_reused: usize,
+}
+
+pub struct ScanIndex {
+    conn: Connection,
+    dirs: HashMap<String, IndexedDir>,
+    /// Directories whose rows must be rewritten on save
+    dirty: HashSet<String>,
+    /// Directories that no longer exist
+    removed: HashSet<String>,
+    stats: IndexStats,
+}
+
+impl ScanIndex {
+    pub fn path(project_root: &Path) -> PathBuf {
+        project_root.join(STATE_DIR).join(SCAN_INDEX_FILE)
+    }
+
+    pub fn exists(project_root: &Path) -> bool {
+        Self::path(project_root).is_file()
+    }
+
+    /// Delete the index so the next indexed scan lists everything again
+    pub fn remove(project_root: &Path) -> Result<()> {
+        match std::fs::remove_file(Self::path(project_root)) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Open the index of `project_root`, creating it if missing; an index
+    /// written by another version is emptied
+    pub fn open(project_root: &Path) -> Result<Self> {
+        let path = Self::path(project_root);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let conn = Connection::open(&path).map_err(index_error)?;
+        conn.execute_batch(SCHEMA).map_err(index_error)?;
+
+        let version: Option<i64> = conn
+            .query_row("SELECT value FROM meta WHERE key = 'version'", [], |row| row.get(0))
+            .optional()
+            .map_err(index_error)?;
+        if version != Some(SCAN_INDEX_VERSION) {
+            debug!("Scan index version {:?}, starting over", version);
+            conn.execute_batch("DROP TABLE IF EXISTS dirs; DROP TABLE IF EXISTS files;").map_err(index_error)?;
+            conn.execute_batch(SCHEMA).map_err(index_error)?;
+            conn.execute(
+                "INSERT OR REPLACE INTO meta (key, value) VALUES ('version', ?1)",
+                params![SCAN_INDEX_VERSION],
+            )
+            .map_err(index_error)?;
+        }
+
+        let mut index = Self {
+            conn,
+            dirs: HashMap::new(),
+            dirty: HashSet::new(),
+            removed: HashSet::new(),
+            stats: IndexStats::default(),
+        };
+        index.load()?;
+        Ok(index)
+    }
+
+    /// Open the index only if a scan already created it
+    pub fn open_existing(project_root: &Path) -> Option<Self> {
+        if !Self::exists(project_root) {
+            return None;
+        }
+        Self::open(project_root)
+            .map_err(|e| debug!("Ignoring scan index: {}", e))
+            .ok()
+    }
+
+    fn load(&mut self) -> Result<()> {
+        let mut dirs = self.conn.prepare("SELECT path, parent, modified_ns FROM dirs").map_err(index_error)?;
+        let rows = dirs
+            .query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, Option<String>>(1)?, row.get(2)?)))
+            .map_err(index_error)?;
+        for row in rows {
+            let (path, parent, modified_ns) = row.map_err(index_error)?;
+            self.dirs.insert(path, IndexedDir { parent, modified_ns, ..IndexedDir::default() });
+        }
+        let children: Vec<(String, String)> = self
+            .dirs
+            .iter()
+            .filter_map(|(path, dir)| Some((dir.parent.clone()?, path.clone())))
+            .collect();
+        for (parent, child) in children {
+            if let Some(parent) = self.dirs.get_mut(&parent) {
+                parent.subdirs.push(child);
+            }
+        }
+
+        let mut files = self
+            .conn
+            .prepare("SELECT path, dir, size_bytes, modified_ns, hash_algorithm, hash FROM files")
+            .map_err(index_error)?;
+        let rows = files
+            .query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, i64>(2)?,
+                    row.get::<_, i64>(3)?,
+                    row.get::<_, Option<String>>(4)?,
+                    row.get::<_, Option<i64>>(5)?,
+                ))
+            })
+            .map_err(index_error)?;
+        for row in rows {
+            let (path, dir, size_bytes, modified_ns, algorithm, hash) = row.map_err(index_error)?;
+            let hash = algorithm.as_deref().and_then(HashAlgorithm::from_name).zip(hash.map(|h| h as u64));
+            if let Some(dir) = self.dirs.get_mut(&dir) {
+                let file = IndexedFile { size_bytes: size_bytes as u64, modified_ns, hash };
+                dir.files.insert(path, file);
+            }
+        }
+        Ok(())
+    }
+
+    /// Every file under `content_dirs` (project-relative) that `accept`
+    /// keeps, listing only directories that changed since the last scan
+    pub fn list(
+        &mut self,
+        project_root: &Path,
+        content_dirs: &[String],
+        accept: &(dyn Fn(&str) -> bool + Sync),
+    ) -> Result<Vec<ContentEntry>> {
+        self.stats = IndexStats::default();
+        let mut entries = Vec::new();
+        let mut seen: HashSet<String> = HashSet::new();
+        let mut pending: Vec<(String, Option<String>)> = content_dirs.iter().map(|dir| (dir.clone(), None)).collect();
+
+        while let Some((dir, parent)) = pending.pop() {
+            // Symlinked directories can lead back to one already visited
+            if !seen.insert(dir.clone()) {
+                continue;
+            }
+            let modified_ns = std::fs::metadata(project_root.join(&dir))
+                .ok()
+                .filter(|metadata| metadata.is_dir())
+                .and_then(|metadata| modified_nanos(&metadata));
+            let Some(modified_ns) = modified_ns else {
+                seen.remove(&dir);
+                continue;
+            };
+
+            match self.dirs.get(&dir) {
+                Some(indexed) if indexed.modified_ns == modified_ns => {
+                    self.stats.dirs_reused += 1;
+                    self.restat(project_root, &dir);
+                }
+                _ => self.relist(project_root, &dir, parent, modified_ns)?,
+            }
+
+            let indexed = &self.dirs[&dir];
+            pending.extend(indexed.subdirs.iter().map(|subdir| (subdir.clone(), Some(dir.clone()))));
+            entries.extend(indexed.files.iter().filter(|(path, _)| accept(path)).map(|(path, file)| ContentEntry {
+                relative_path: path.clone(),
+                size_bytes: file.size_bytes,
+                modified: file.modified_ns.max(0) as u64 / 1_000_000_000,
+                local_path: Some(project_root.join(path)),
+            }));
+        }
+
+        let gone: Vec<String> = self.dirs.keys().filter(|dir| !seen.contains(*dir)).cloned().collect();
+        for dir in gone {
+            self.dirs.remove(&dir);
+            self.dirty.remove(&dir);
+            self.removed.insert(dir);
+        }
+        debug!(
+            "Scan index: listed {} directories, reused {}",
+            self.stats.dirs_listed, self.stats.dirs_reused
+        );
+        Ok(entries)
+    }
+
+    /// Stat the indexed files of an unchanged `dir`; files rewritten in
+    /// place get their new size and time and lose their hash
+    fn restat(&mut self, project_root: &Path, dir: &str) {
+        let Some(indexed) = self.dirs.get_mut(dir) else {
+            return;
+        };
+        let stamps: Vec<Option<(u64, i64)>> = indexed
+            .files
+            .par_iter()
+            .map(|(relative, _)| stamp(&project_root.join(relative)))
+            .collect();
+
+        let mut changed = false;
+        let mut gone = Vec::new();
+        for ((relative, file), stamp) in indexed.files.iter_mut().zip(stamps) {
+            match stamp {
+                Some((size_bytes, modified_ns)) if (size_bytes, modified_ns) == (file.size_bytes, file.modified_ns) => {
+                    self.stats.files_reused += 1;
+                }
+                Some((size_bytes, modified_ns)) => {
+                    *file = IndexedFile { size_bytes, modified_ns, hash: None };
+                    changed = true;
+                }
+                None => gone.push(relative.clone()),
+            }
+        }
+        self.stats.files_stated += indexed.files.len();
+        for relative in &gone {
+            indexed.files.remove(relative);
+        }
+        if changed || !gone.is_empty() {
+            self.dirty.insert(dir.to_string());
+        }
+    }
+
+    /// Read `dir` from disk, keeping the hashes of files whose size and
+    /// time did not change
+    fn relist(&mut self, project_root: &Path, dir: &str, parent: Option<String>, modified_ns: i64) -> Result<()> {
+        let previous = self.dirs.remove(dir).unwrap_or_default();
+        let children: Vec<(String, PathBuf)> = std::fs::read_dir(project_root.join(dir))?
+            .filter_map(|entry| entry.ok())
+            .map(|entry| (format!("{}/{}", dir, entry.file_name().to_string_lossy()), entry.path()))
+            .collect();
+        // Follows symlinks, as the plain listing does
+        let stated: Vec<(String, Metadata)> = children
+            .into_par_iter()
+            .filter_map(|(relative, path)| Some((relative, std::fs::metadata(path).ok()?)))
+            .collect();
+
+        let mut indexed = IndexedDir { parent, modified_ns, ..IndexedDir::default() };
+        for (relative, metadata) in stated {
+            if metadata.is_dir() {
+                indexed.subdirs.push(relative);
+                continue;
+            }
+            if !metadata.is_file() {
+                continue;
+            }
+            let size_bytes = metadata.len();
+            let modified_ns = modified_nanos(&metadata).unwrap_or_default();
+            let hash = previous
+                .files
+                .get(&relative)
+                .filter(|file| file.size_bytes == size_bytes && file.modified_ns == modified_ns)
+                .and_then(|file| file.hash);
+            indexed.files.insert(relative, IndexedFile { size_bytes, modified_ns, hash });
+        }
+
+        self.stats.dirs_listed += 1;
+        self.stats.files_stated += indexed.files.len();
+        self.dirs.insert(dir.to_string(), indexed);
+        self.dirty.insert(dir.to_string());
+        self.removed.remove(dir);
+        Ok(())
+    }
+
+    pub fn stats(&self) -> IndexStats {
+        self.stats
+    }
+
+    /// Hash of `relative_path` with `algorithm`, if recorded while the file
+    /// at `local_path` had its current size and modification time
+    pub fn hash(&self, relative_path: &str, local_path: &Path, algorithm: HashAlgorithm) -> Option<u64> {
+        let (dir, _) = relative_path.rsplit_once('/')?;
+        let file = self.dirs.get(dir)?.files.get(relative_path)?;
+        if stamp(local_path)? != (file.size_bytes, file.modified_ns) {
+            return None;
+        }
+        file.hash.filter(|(recorded, _)| *recorded == algorithm).map(|(_, hash)| hash)
+    }
+
+    /// Remember the hash of an indexed file
+    pub fn record_hash(&mut self, relative_path: &str, algorithm: HashAlgorithm, hash: u64) {
+        let Some((dir, _)) = relative_path.rsplit_once('/') else {
+            return;
+        };
+        let Some(file) = self.dirs.get_mut(dir).and_then(|indexed| indexed.files.get_mut(relative_path)) else {
+            return;
+        };
+        if file.hash != Some((algorithm, hash)) {
+            file.hash = Some((algorithm, hash));
+            self.dirty.insert(dir.to_string());
+        }
+    }
+
+    /// Write the directories that changed in one transaction
+    pub fn save(&mut self) -> Result<()> {
+        if self.dirty.is_empty() && self.removed.is_empty() {
+            return Ok(());
+        }
+        let tx = self.conn.transaction().map_err(index_error)?;
+        {
+            let mut delete_dir = tx.prepare("DELETE FROM dirs WHERE path = ?1").map_err(index_error)?;
+            let mut delete_files = tx.prepare("DELETE FROM files WHERE dir = ?1").map_err(index_error)?;
+            let mut insert_dir = tx
+                .prepare("INSERT OR REPLACE INTO dirs (path, parent, modified_ns) VALUES (?1, ?2, ?3)")
+                .map_err(index_error)?;
+            let mut insert_file = tx
+                .prepare(
+                    "INSERT OR REPLACE INTO files (path, dir, size_bytes, modified_ns, hash_algorithm, hash)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                )
+                .map_err(index_error)?;
+
+            for dir in &self.removed {
+                delete_dir.execute(params![dir]).map_err(index_error)?;
+                delete_files.execute(params![dir]).map_err(index_error)?;
+            }
+            for dir in &self.dirty {
+                let Some(indexed) = self.dirs.get(dir) else {
+                    continue;
+                };
+                insert_dir.execute(params![dir, indexed.parent, indexed.modified_ns]).map_err(index_error)?;
+                delete_files.execute(params![dir]).map_err(index_error)?;
+                for (path, file) in &indexed.files {
+                    let (algorithm, hash) = match file.hash {
+                        Some((algorithm, hash)) => (Some(algorithm.as_str()), Some(hash as i64)),
+                        None => (None, None),
+                    };
+                    insert_file
+                        .execute(params![path, dir, file.size_bytes as i64, file.modified_ns, algorithm, hash])
+                        .map_err(index_error)?;
+                }
+            }
+        }
+        tx.commit().map_err(index_error)?;
+        self.dirty.clear();
+        self.removed.clear();
+        Ok(())
+    }
+}
+
+/// Size and nanosecond modification time of the file at `path`
+fn stamp(path: &Path) -> Option<(u64, i64)> {
+    let metadata = std::fs::metadata(path).ok().filter(|metadata| metadata.is_file())?;
+    Some((metadata.len(), modified_nanos(&metadata)?))
+}
+
+fn modified_nanos(metadata: &Metadata) -> Option<i64> {
+    Some(metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?.as_nanos() as i64)
+}
+
+fn index_error(e: rusqlite::Error) -> FastStartupError {
+    FastStartupError::IndexError(e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_reuses_unchanged_directories() {
+        let root = std::env::temp_dir().join(format!("uefast_scan_index_{}", std::process::id()));
+        std::fs::create_dir_all(root.join("Content/UI")).unwrap();
+        std::fs::create_dir_all(root.join("Content/Maps")).unwrap();
+        std::fs::write(root.join("Content/UI/Menu.uasset"), b"menu").unwrap();
+        std::fs::write(root.join("Content/Maps/Entry.umap"), b"entry").unwrap();
+        let roots = ["Content".to_string()];
+        let everything = |_: &str| true;
+
+        let mut index = ScanIndex::open(&root).unwrap();
+        assert_eq!(index.list(&root, &roots, &everything).unwrap().len(), 2);
+        assert_eq!(index.stats().dirs_listed, 3);
+        index.record_hash("Content/UI/Menu.uasset", HashAlgorithm::Xxh3, 42);
+        index.save().unwrap();
+
+        std::fs::write(root.join("Content/Maps/Second.umap"), b"second").unwrap();
+        let mut index = ScanIndex::open(&root).unwrap();
+        let mut listed: Vec<String> =
+            index.list(&root, &roots, &everything).unwrap().into_iter().map(|e| e.relative_path).collect();
+        listed.sort();
+        assert_eq!(listed, ["Content/Maps/Entry.umap", "Content/Maps/Second.umap", "Content/UI/Menu.uasset"]);
+        assert_eq!((index.stats().dirs_listed, index.stats().dirs_reused), (1, 2));
+
+        let menu = root.join("Content/UI/Menu.uasset");
+        assert_eq!(index.hash("Content/UI/Menu.uasset", &menu, HashAlgorithm::Xxh3), Some(42));
+        assert_eq!(index.hash("Content/UI/Menu.uasset", &menu, HashAlgorithm::Blake3), None);
+
+        // Rewritten in place within the same second: the directory keeps its
+        // time, but the file is stat'ed and its hash dropped
+        let modified = std::fs::metadata(&menu).unwrap().modified().unwrap();
+        std::fs::write(&menu, b"MENU").unwrap();
+        let file = std::fs::File::options().write(true).open(&menu).unwrap();
+        file.set_modified(modified + std::time::Duration::from_nanos(1)).unwrap();
+        drop(file);
+        assert_eq!(index.hash("Content/UI/Menu.uasset", &menu, HashAlgorithm::Xxh3), None);
+        index.save().unwrap();
+        let mut index = ScanIndex::open(&root).unwrap();
+        assert_eq!(index.list(&root, &roots, &everything).unwrap().len(), 3);
+        assert_eq!(index.stats().dirs_listed, 0);
+        assert_eq!(index.stats().files_reused, 2);
+        assert_eq!(index.hash("Content/UI/Menu.uasset", &menu, HashAlgorithm::Xxh3), None);
+
+        std::fs::remove_dir_all(root.join("Content/UI")).unwrap();
+        assert_eq!(index.list(&root, &roots, &everything).unwrap().len(), 2);
+        index.save().unwrap();
+        assert!(!ScanIndex::open(&root).unwrap().dirs.contains_key("Content/UI"));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,191 @@
+//! Startup Scenarios Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Named startup sets (e.g. "FrontendOnly", "DediServer") configured in
+//! `.uefast.toml`, each the hard closure of its roots minus its exclusions
+
+use crate::graph::DependencyGraph;
+use crate::sampling::Estimate;
+use crate::scanner::{package_name_from_relative, AssetInfo};
+use crate::tags::AssetTags;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// One `[scenarios.<name>]` table. Patterns are package paths such as
+/// `/Game/Maps/Frontend`; a trailing `/` or `*` matches everything below.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioConfig {
+    /// Assets loaded first in this scenario
+    #[serde(default)]
+    pub roots: Vec<String>,
+    /// Feature tags whose assets are roots too (see `tags import`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub root_tags: Vec<String>,
+    /// Assets cut from the scenario along with anything only they pull in
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+}
+
+impl ScenarioConfig {
+    fn is_root(&self, relative_path: &str, tags: &AssetTags) -> bool {
+        matches_any(&self.roots, relative_path)
+            || tags.tags_of(relative_path).any(|t| self.root_tags.iter().any(|r| r == t))
+    }
+
+    fn is_excluded(&self, relative_path: &str) -> bool {
+        matches_any(&self.exclude, relative_path)
+    }
+
+    /// Absolute paths of the assets this scenario loads: its roots and
+    /// their hard dependencies, never entering an excluded asset
+    pub fn resolve(
+        &self,
+        project_root: &Path,
+        graph: &DependencyGraph,
+        assets: &[AssetInfo],
+        tags: &AssetTags,
+    ) -> ScenarioSet {
+        let roots: HashSet<PathBuf> = assets
+            .iter()
+            .filter(|a| self.is_root(&a.relative_path, tags) && !self.is_excluded(&a.relative_path))
+            .map(|a| a.path.clone())
+            .collect();
+
+        let keep = |path: &Path| {
+            let relative = path.strip_prefix(project_root).unwrap_or(path).to_string_lossy().replace('\\', "/");
+            !self.is_excluded(&relative)
+        };
+        let members = graph.hard_closure_where(&roots, keep);
+        let size_estimate = graph.closure_size_estimate_where(&roots, keep);
+        ScenarioSet { roots, members, size_estimate }
+    }
+}
+
+/// Assets of a resolved scenario
+#[derive(Debug, Clone, Default)]
+pub struct ScenarioSet {
+    pub roots: HashSet<PathBuf>,
+    /// Roots plus their hard dependencies
+    pub members: HashSet<PathBuf>,
+    pub size_estimate: Estimate,
+}
+
+/// Per-scenario totals in an analysis report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioReport {
+    pub name: String,
+    pub root_assets: usize,
+    pub startup_assets: usize,
+    pub startup_size_bytes: u64,
+    /// Startup size bounded by edge confidence
+    pub startup_size_estimate: Estimate,
+}
+
+impl ScenarioReport {
+    pub fn new(name: &str, set: &ScenarioSet, assets: &[AssetInfo]) -> Self {
+        Self {
+            name: name.to_string(),
+            root_assets: set.roots.len(),
+            startup_assets: set.members.len(),
+            startup_size_bytes: assets
+                .iter()
+                .filter(|a| set.members.contains(&a.path))
+                .map(|a| a.size_bytes)
+                .sum(),
+            startup_size_estimate: set.size_estimate,
+        }
+    }
+}
+
+/// Resolve every configured scenario, in name order
+pub fn resolve_all(
+    scenarios: &BTreeMap<String, ScenarioConfig>,
+    project_root: &Path,
+    graph: &DependencyGraph,
+    assets: &[AssetInfo],
+    tags: &AssetTags,
+) -> Vec<(String, ScenarioSet)> {
+    scenarios
+        .iter()
+        .map(|(name, config)| (name.clone(), config.resolve(project_root, graph, assets, tags)))
+        .collect()
+}
+
+fn matches_any(patterns: &[String], relative_path: &str) -> bool {
+    let Some(package) = package_name_from_relative(relative_path) else {
+        return false;
+    };
+    patterns.iter().any(|pattern| matches_pattern(pattern, &package))
+}
+
+pub(crate) fn matches_pattern(pattern: &str, package: &str) -> bool {
+    let pattern = pattern.split('.').next().unwrap_or(pattern);
+    match pattern.strip_suffix('*') {
+        Some(prefix) => package.starts_with(prefix),
+        None if pattern.ends_with('/') => package.starts_with(pattern),
+        None => package == pattern,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::DependencyType;
+    use crate::scanner::AssetType;
+
+    fn asset(relative_path: &str, size_bytes: u64) -> AssetInfo {
+        AssetInfo {
+            path: Path::new("/P").join(relative_path),
+            relative_path: relative_path.to_string(),
+            asset_type: AssetType::UAsset,
+            size_bytes,
+            modified: 0,
+            mount_point: "/Game".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_matches_pattern() {
+        assert!(matches_pattern("/Game/Maps/Frontend", "/Game/Maps/Frontend"));
+        assert!(matches_pattern("/Game/Maps/Frontend.Frontend", "/Game/Maps/Frontend"));
+        assert!(!matches_pattern("/Game/Maps/Frontend", "/Game/Maps/Frontend2"));
+        assert!(matches_pattern("/Game/UI/", "/Game/UI/WBP_Menu"));
+        assert!(matches_pattern("/Game/Maps/MP_*", "/Game/Maps/MP_Arena"));
+    }
+
+    #[test]
+    fn test_resolve_with_exclusions() {
+        let assets = [
+            asset("Content/Maps/Frontend.uasset", 10),
+            asset("Content/UI/WBP_Menu.uasset", 20),
+            asset("Content/Audio/Music.uasset", 300),
+            asset("Content/Audio/Stinger.uasset", 5),
+        ];
+        let mut graph = DependencyGraph::new();
+        for a in &assets {
+            graph.add_asset(a);
+        }
+        let link = |graph: &mut DependencyGraph, from: usize, to: usize| {
+            graph.add_dependency(&assets[from].path, &assets[to].path, DependencyType::Import, true);
+        };
+        link(&mut graph, 0, 1);
+        link(&mut graph, 0, 2);
+        link(&mut graph, 2, 3);
+
+        let scenario = ScenarioConfig {
+            roots: vec!["/Game/Maps/Frontend".to_string()],
+            exclude: vec!["/Game/Audio/Music".to_string()],
+            ..Default::default()
+        };
+        let set = scenario.resolve(Path::new("/P"), &graph, &assets, &AssetTags::default());
+        let report = ScenarioReport::new("FrontendOnly", &set, &assets);
+
+        assert_eq!(report.root_assets, 1);
+        assert_eq!(report.startup_assets, 2);
+        assert_eq!(report.startup_size_bytes, 30);
+        assert_eq!(report.startup_size_estimate.upper, 30.0);
+    }
+}
@@ -0,0 +1,468 @@
+//! Output Schema Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! JSON Schemas of the machine-readable outputs and the `schema_version`
+//! stamped into each of them. Bump `SCHEMA_VERSION` whenever a field is
+//! renamed, removed or changes type; adding optional fields does not.
+
+use crate::FastStartupError;
+use serde_json::{json, Map, Value};
+
+/// Version of every JSON output of this release
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Field carrying `SCHEMA_VERSION` in each JSON output
+pub const VERSION_FIELD: &str = "schema_version";
+
+const SCHEMA_ID_BASE: &str = "https://github.com/AndreeSalazar/Proyecto-UE5-Fast-Startup-Accelerator/schemas";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaKind {
+    /// `analyze` / `optimize` reports (`AnalysisReport`)
+    Analysis,
+    /// `verify` results (`VerifyResult`)
+    Verify,
+    /// `scan` summaries (`ScanReport`)
+    Scan,
+    /// `.uefast.log` entries, one JSON object per line (`CacheLogEntry`)
+    CacheLog,
+}
+
+impl SchemaKind {
+    pub const ALL: [SchemaKind; 4] = [SchemaKind::Analysis, SchemaKind::Verify, SchemaKind::Scan, SchemaKind::CacheLog];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SchemaKind::Analysis => "analysis",
+            SchemaKind::Verify => "verify",
+            SchemaKind::Scan => "scan",
+            SchemaKind::CacheLog => "cache-log",
+        }
+    }
+
+    /// JSON Schema (draft 2020-12) of this output
+    pub fn schema(&self) -> Value {
+        let (title, body) = match self {
+            SchemaKind::Analysis => ("AnalysisReport", analysis_report()),
+            SchemaKind::Verify => ("VerifyResult", verify_result()),
+            SchemaKind::Scan => ("ScanReport", scan_report()),
+            SchemaKind::CacheLog => ("CacheLogEntry", cache_log_entry()),
+        };
+        let mut schema = json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "$id": format!("{}/{}-v{}.json", SCHEMA_ID_BASE, self.as_str(), SCHEMA_VERSION),
+            "title": title,
+        });
+        if let (Some(schema), Value::Object(body)) = (schema.as_object_mut(), body) {
+            schema.extend(body);
+        }
+        schema
+    }
+}
+
+impl std::str::FromStr for SchemaKind {
+    type Err = FastStartupError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        SchemaKind::ALL
+            .into_iter()
+            .find(|kind| kind.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                FastStartupError::InvalidArgument(format!(
+                    "Unknown schema '{}' (expected analysis, verify, scan or cache-log)",
+                    s
+                ))
+            })
+    }
+}
+
+/// Every schema keyed by name, for `schema` without an argument
+pub fn all_schemas() -> Value {
+    let schemas: Map<String, Value> = SchemaKind::ALL
+        .iter()
+        .map(|kind| (kind.as_str().to_string(), kind.schema()))
+        .collect();
+    json!({ VERSION_FIELD: SCHEMA_VERSION, "schemas": schemas })
+}
+
+/// `value` with `schema_version` added when it is an object without one
+pub fn stamp(mut value: Value) -> Value {
+    if let Value::Object(map) = &mut value {
+        map.entry(VERSION_FIELD).or_insert(json!(SCHEMA_VERSION));
+    }
+    value
+}
+
+fn string() -> Value {
+    json!({ "type": "string" })
+}
+
+fn integer() -> Value {
+    json!({ "type": "integer", "minimum": 0 })
+}
+
+fn number() -> Value {
+    json!({ "type": "number" })
+}
+
+fn boolean() -> Value {
+    json!({ "type": "boolean" })
+}
+
+fn nullable(schema: Value) -> Value {
+    json!({ "anyOf": [schema, { "type": "null" }] })
+}
+
+fn array(items: Value) -> Value {
+    json!({ "type": "array", "items": items })
+}
+
+fn map_of(values: Value) -> Value {
+    json!({ "type": "object", "additionalProperties": values })
+}
+
+fn string_enum(values: &[&str]) -> Value {
+    json!({ "type": "string", "enum": values })
+}
+
+/// Object with `required` properties first, then optional ones. Unknown
+/// properties are allowed so readers tolerate newer minor additions.
+fn object(required: &[(&str, Value)], optional: &[(&str, Value)]) -> Value {
+    let properties: Map<String, Value> = required
+        .iter()
+        .chain(optional)
+        .map(|(name, schema)| (name.to_string(), schema.clone()))
+        .collect();
+    let required: Vec<&str> = required.iter().map(|(name, _)| *name).collect();
+    json!({ "type": "object", "properties": properties, "required": required })
+}
+
+fn version() -> (&'static str, Value) {
+    (VERSION_FIELD, json!({ "type": "integer", "const": SCHEMA_VERSION }))
+}
+
+fn estimate() -> Value {
+    object(&[("value", number()), ("lower", number()), ("upper", number())], &[])
+}
+
+fn loose_object() -> Value {
+    json!({ "type": "object" })
+}
+
+fn analysis_report() -> Value {
+    let recommendation = object(
+        &[
+            ("priority", string_enum(&["High", "Medium", "Low"])),
+            ("category", string()),
+            ("message", string()),
+            ("estimated_impact_seconds", number()),
+        ],
+        &[],
+    );
+    let startup_asset = object(
+        &[("relative_path", string()), ("size_bytes", integer())],
+        &[("tags", array(string()))],
+    );
+    let scenario = object(
+        &[
+            ("name", string()),
+            ("root_assets", integer()),
+            ("startup_assets", integer()),
+            ("startup_size_bytes", integer()),
+            ("startup_size_estimate", estimate()),
+        ],
+        &[],
+    );
+    let tag_stats = object(
+        &[
+            ("assets", integer()),
+            ("size_bytes", integer()),
+            ("startup_assets", integer()),
+            ("startup_size_bytes", integer()),
+        ],
+        &[],
+    );
+    let duplicate = object(
+        &[("hash", integer()), ("files", array(string())), ("wasted_bytes", integer())],
+        &[],
+    );
+    let coverage = object(
+        &[
+            ("packages", integer()),
+            ("parsed", integer()),
+            ("reused", integer()),
+            ("failures", map_of(integer())),
+        ],
+        &[("parsed_edges", integer()), ("heuristic_edges", integer()), ("registry_edges", integer()), ("editor_packages", integer())],
+    );
+    let shaders = object(
+        &[
+            ("total_shaders", integer()),
+            ("total_size_bytes", integer()),
+            ("estimated_compile_time_seconds", integer()),
+        ],
+        &[],
+    );
+    let hardware = object(
+        &[
+            ("cpu_cores", integer()),
+            ("total_ram_bytes", nullable(integer())),
+            ("storage", string()),
+            ("os", string()),
+        ],
+        &[],
+    );
+    let sample = object(
+        &[
+            ("fraction", number()),
+            ("seed", integer()),
+            ("sampled_assets", integer()),
+            ("estimated_total_assets", estimate()),
+            ("estimated_total_size_bytes", estimate()),
+            ("estimated_startup_assets", estimate()),
+            ("estimated_startup_size_bytes", estimate()),
+        ],
+        &[],
+    );
+
+    object(
+        &[
+            version(),
+            ("project_name", string()),
+            ("total_assets", integer()),
+            ("startup_assets", integer()),
+            ("total_size_bytes", integer()),
+            ("startup_size_bytes", integer()),
+            ("by_type", map_of(object(&[("count", integer()), ("total_size", integer())], &[]))),
+            ("dependency_count", integer()),
+            ("duplicate_count", integer()),
+            ("duplicates", array(duplicate)),
+            ("shader_analysis", nullable(shaders)),
+            ("engine_association", nullable(string())),
+            ("version_matrix", nullable(loose_object())),
+            ("sample", nullable(sample)),
+            ("estimated_savings_seconds", number()),
+            ("recommendations", array(recommendation)),
+        ],
+        &[
+            ("startup_closure_size_bytes", estimate()),
+            ("scenarios", array(scenario)),
+            ("startup_asset_list", array(startup_asset)),
+            ("by_tag", map_of(tag_stats)),
+            ("parser_coverage", coverage),
+            ("shared_subtrees", array(loose_object())),
+            (
+                "stale_imports",
+                array(object(&[("relative_path", string()), ("package", string())], &[])),
+            ),
+            ("content_anomalies", array(content_anomaly())),
+            ("case_collisions", array(object(&[("paths", array(string()))], &[]))),
+            ("dependency_cycles", array(array(string()))),
+            ("data_tables", array(loose_object())),
+            ("string_tables", array(loose_object())),
+            ("ui", loose_object()),
+            ("resident_memory", loose_object()),
+            ("hardware", nullable(hardware)),
+            (
+                "rule_hits",
+                array(object(
+                    &[
+                        ("pack", string()),
+                        ("rule_id", string()),
+                        ("severity", string_enum(&["error", "warning", "note"])),
+                        ("message", string()),
+                    ],
+                    &[("location", string())],
+                )),
+            ),
+            (
+                "never_cooked",
+                array(object(
+                    &[("relative_path", string()), ("rule", string()), ("cook_rule", string())],
+                    &[],
+                )),
+            ),
+            (
+                "late_chunks",
+                array(object(
+                    &[
+                        ("relative_path", string()),
+                        ("chunk_id", integer()),
+                        ("source", string()),
+                        ("size_bytes", integer()),
+                    ],
+                    &[],
+                )),
+            ),
+            ("language", string_enum(&["en", "es"])),
+        ],
+    )
+}
+
+fn content_anomaly() -> Value {
+    object(
+        &[
+            ("relative_path", string()),
+            ("kind", string_enum(&["lfs_pointer", "mixed_line_endings", "divergent_line_endings"])),
+        ],
+        &[],
+    )
+}
+
+fn rename() -> Value {
+    object(&[("from", string()), ("to", string())], &[])
+}
+
+fn verify_result() -> Value {
+    object(
+        &[
+            version(),
+            ("is_valid", boolean()),
+            ("total_assets", integer()),
+            ("matching_assets", integer()),
+            ("changed_assets", array(string())),
+            ("missing_assets", array(string())),
+        ],
+        &[("renamed_assets", array(rename())), ("anomalies", array(content_anomaly()))],
+    )
+}
+
+fn scan_report() -> Value {
+    object(
+        &[
+            version(),
+            ("total_assets", integer()),
+            ("by_type", map_of(integer())),
+            ("total_size_bytes", integer()),
+            ("scan_duration_ms", integer()),
+        ],
+        &[(
+            "assets",
+            array(object(
+                &[
+                    ("path", string()),
+                    ("relative_path", string()),
+                    ("asset_type", string()),
+                    ("size_bytes", integer()),
+                    ("modified", integer()),
+                ],
+                &[("mount_point", string())],
+            )),
+        )],
+    )
+}
+
+fn cache_log_entry() -> Value {
+    let changes = object(
+        &[
+            ("added", array(string())),
+            ("changed", array(string())),
+            ("removed", array(string())),
+        ],
+        &[("renamed", array(rename()))],
+    );
+    object(
+        &[
+            ("timestamp", string()),
+            ("user", string()),
+            ("host", string()),
+            ("operation", string()),
+            ("asset_count", integer()),
+            ("changes", changes),
+        ],
+        // Entries written before versioning carry no version
+        &[version(), ("tags", array(string()))],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::{Rename, VerifyResult};
+    use crate::scanner::ScanReport;
+
+    /// Just enough of JSON Schema to check our own outputs: types,
+    /// required properties, nested properties, items and anyOf
+    fn violations(schema: &Value, value: &Value, path: &str) -> Vec<String> {
+        if let Some(options) = schema.get("anyOf").and_then(Value::as_array) {
+            return if options.iter().any(|s| violations(s, value, path).is_empty()) {
+                Vec::new()
+            } else {
+                vec![format!("{}: matches no anyOf option", path)]
+            };
+        }
+        let type_ok = match schema.get("type").and_then(Value::as_str) {
+            Some("object") => value.is_object(),
+            Some("array") => value.is_array(),
+            Some("string") => value.is_string(),
+            Some("integer") => value.is_u64() || value.is_i64(),
+            Some("number") => value.is_number(),
+            Some("boolean") => value.is_boolean(),
+            Some("null") => value.is_null(),
+            _ => true,
+        };
+        if !type_ok {
+            return vec![format!("{}: expected {}", path, schema["type"])];
+        }
+
+        let mut out = Vec::new();
+        if let Some(required) = schema.get("required").and_then(Value::as_array) {
+            for name in required.iter().filter_map(Value::as_str) {
+                if value.get(name).is_none() {
+                    out.push(format!("{}.{}: missing", path, name));
+                }
+            }
+        }
+        if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+            for (name, property) in properties {
+                if let Some(field) = value.get(name) {
+                    out.extend(violations(property, field, &format!("{}.{}", path, name)));
+                }
+            }
+        }
+        if let (Some(items), Some(values)) = (schema.get("items"), value.as_array()) {
+            for (i, item) in values.iter().enumerate() {
+                out.extend(violations(items, item, &format!("{}[{}]", path, i)));
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_outputs_match_schemas() {
+        let scan = ScanReport {
+            total_assets: 2,
+            by_type: [("uasset".to_string(), 2)].into_iter().collect(),
+            total_size_bytes: 10,
+            scan_duration_ms: 3,
+            assets: Vec::new(),
+        };
+        let value = stamp(serde_json::to_value(&scan).unwrap());
+        assert_eq!(violations(&SchemaKind::Scan.schema(), &value, "$"), Vec::<String>::new());
+
+        let verify = VerifyResult {
+            is_valid: false,
+            total_assets: 2,
+            matching_assets: 1,
+            changed_assets: vec!["Content/A.uasset".to_string()],
+            missing_assets: Vec::new(),
+            renamed_assets: vec![Rename { from: "a".to_string(), to: "b".to_string() }],
+            anomalies: Vec::new(),
+        };
+        let value = serde_json::to_value(&verify).unwrap();
+        assert!(!violations(&SchemaKind::Verify.schema(), &value, "$").is_empty());
+        let value = stamp(value);
+        assert_eq!(violations(&SchemaKind::Verify.schema(), &value, "$"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_kinds_round_trip() {
+        for kind in SchemaKind::ALL {
+            assert_eq!(kind.as_str().parse::<SchemaKind>().unwrap(), kind);
+            assert_eq!(kind.schema()["$schema"], "https://json-schema.org/draft/2020-12/schema");
+        }
+        assert_eq!(stamp(json!({ "schema_version": 0 }))[VERSION_FIELD], 0);
+        assert_eq!(all_schemas()["schemas"].as_object().unwrap().len(), 4);
+    }
+}
@@ -0,0 +1,335 @@
+//! Scripting Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Run Rhai scripts over a read-only view of the asset database and the
+//! dependency graph, so pipeline TDs can write custom queries and checks
+//! without touching Rust. Scripts report through `print` and `finding`.
+
+use crate::graph::{AssetNode, DependencyGraph};
+use crate::report::{Finding, ReportData, Severity, Table};
+use crate::scanner::AssetInfo;
+use crate::{FastStartupError, Result};
+use rhai::{Array, Dynamic, Engine, EvalAltResult, Map, Scope, INT};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Operations a script may run before it is stopped, so a runaway loop in
+/// CI fails instead of hanging
+pub const DEFAULT_MAX_OPERATIONS: u64 = 200_000_000;
+
+/// Asset as scripts see it
+#[derive(Debug, Clone)]
+struct ScriptAsset {
+    relative_path: String,
+    asset_type: &'static str,
+    size_bytes: u64,
+    startup: bool,
+}
+
+impl ScriptAsset {
+    fn to_map(&self) -> Map {
+        let mut map = Map::new();
+        map.insert("path".into(), self.relative_path.clone().into());
+        map.insert("type".into(), self.asset_type.into());
+        map.insert("size_bytes".into(), (self.size_bytes as INT).into());
+        map.insert("startup".into(), self.startup.into());
+        map
+    }
+}
+
+#[derive(Debug, Default)]
+struct ContextData {
+    project_name: String,
+    assets: Vec<ScriptAsset>,
+    by_path: HashMap<String, usize>,
+    /// Relative path -> relative paths of its dependencies
+    dependencies: HashMap<String, Vec<String>>,
+    dependents: HashMap<String, Vec<String>>,
+}
+
+impl ContextData {
+    fn paths(&self, edges: &HashMap<String, Vec<String>>, path: &str) -> Array {
+        edges
+            .get(&path.replace('\\', "/"))
+            .map(|paths| paths.iter().map(|p| p.clone().into()).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Read-only project view shared by every script run
+#[derive(Debug, Clone)]
+pub struct ScriptContext {
+    data: Arc<ContextData>,
+    max_operations: u64,
+}
+
+/// What a script reported
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ScriptReport {
+    pub script: String,
+    pub project_name: String,
+    /// Lines passed to `print`
+    pub output: Vec<String>,
+    pub findings: Vec<ScriptFinding>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScriptFinding {
+    pub severity: Severity,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+impl ScriptReport {
+    pub fn errors(&self) -> usize {
+        self.findings.iter().filter(|f| f.severity == Severity::Error).count()
+    }
+}
+
+impl ScriptContext {
+    /// View of `assets` and `graph`, with the hard closure of `roots` flagged
+    /// as startup
+    pub fn build(
+        project_name: &str,
+        graph: &DependencyGraph,
+        assets: &[AssetInfo],
+        roots: &HashSet<PathBuf>,
+    ) -> Self {
+        let relative: HashMap<&Path, String> = assets
+            .iter()
+            .map(|a| (a.path.as_path(), a.relative_path.replace('\\', "/")))
+            .collect();
+        let startup = graph.hard_closure_of(roots);
+
+        let mut data = ContextData {
+            project_name: project_name.to_string(),
+            ..ContextData::default()
+        };
+        for asset in assets {
+            let path = relative[asset.path.as_path()].clone();
+            let edges = |nodes: Vec<&AssetNode>| -> Vec<String> {
+                let mut paths: Vec<String> = nodes
+                    .into_iter()
+                    .filter_map(|n| relative.get(n.path.as_path()).cloned())
+                    .collect();
+                paths.sort();
+                paths.dedup();
+                paths
+            };
+            data.dependencies.insert(path.clone(), edges(graph.get_dependencies(&asset.path)));
+            data.dependents.insert(path.clone(), edges(graph.get_dependents(&asset.path)));
+            data.by_path.insert(path.clone(), data.assets.len());
+            data.assets.push(ScriptAsset {
+                relative_path: path,
+                asset_type: asset.asset_type.as_str(),
+                size_bytes: asset.size_bytes,
+                startup: startup.contains(&asset.path),
+            });
+        }
+
+        Self { data: Arc::new(data), max_operations: DEFAULT_MAX_OPERATIONS }
+    }
+
+    pub fn with_max_operations(mut self, max_operations: u64) -> Self {
+        self.max_operations = max_operations;
+        self
+    }
+
+    fn engine(&self, report: &Arc<Mutex<ScriptReport>>) -> Engine {
+        let mut engine = Engine::new();
+        engine.set_max_operations(self.max_operations);
+
+        let printed = report.clone();
+        engine.on_print(move |line| printed.lock().unwrap().output.push(line.to_string()));
+        let debugged = report.clone();
+        engine.on_debug(move |line, _, position| {
+            debugged.lock().unwrap().output.push(format!("[debug {}] {}", position, line))
+        });
+
+        let data = self.data.clone();
+        engine.register_fn("project_name", move || data.project_name.clone());
+        let data = self.data.clone();
+        engine.register_fn("assets", move || -> Array {
+            data.assets.iter().map(|a| a.to_map().into()).collect()
+        });
+        let data = self.data.clone();
+        engine.register_fn("asset", move |path: &str| -> Dynamic {
+            data.by_path
+                .get(&path.replace('\\', "/"))
+                .map(|&i| data.assets[i].to_map().into())
+                .unwrap_or(Dynamic::UNIT)
+        });
+        let data = self.data.clone();
+        engine.register_fn("startup_assets", move || -> Array {
+            data.assets.iter().filter(|a| a.startup).map(|a| a.relative_path.clone().into()).collect()
+        });
+        let data = self.data.clone();
+        engine.register_fn("dependencies", move |path: &str| data.paths(&data.dependencies, path));
+        let data = self.data.clone();
+        engine.register_fn("dependents", move |path: &str| data.paths(&data.dependents, path));
+
+        let findings = report.clone();
+        let add_finding = move |severity: &str, message: &str, location: Option<String>| {
+            let severity = match severity.to_ascii_lowercase().as_str() {
+                "error" => Severity::Error,
+                "warning" | "warn" => Severity::Warning,
+                "note" | "info" => Severity::Note,
+                other => return Err(format!("unknown severity '{}', expected error, warning or note", other)),
+            };
+            findings.lock().unwrap().findings.push(ScriptFinding {
+                severity,
+                message: message.to_string(),
+                location,
+            });
+            Ok(())
+        };
+        let add = add_finding.clone();
+        engine.register_fn("finding", move |severity: &str, message: &str| -> std::result::Result<(), Box<EvalAltResult>> {
+            add(severity, message, None).map_err(Into::into)
+        });
+        engine.register_fn(
+            "finding",
+            move |severity: &str, message: &str, location: &str| -> std::result::Result<(), Box<EvalAltResult>> {
+                add_finding(severity, message, Some(location.to_string())).map_err(Into::into)
+            },
+        );
+
+        engine
+    }
+
+    /// Run `source`, named `script` in errors and the report, with `args`
+    /// bound to the `ARGS` array
+    pub fn run(&self, script: &str, source: &str, args: &[String]) -> Result<ScriptReport> {
+        let report = Arc::new(Mutex::new(ScriptReport {
+            script: script.to_string(),
+            project_name: self.data.project_name.clone(),
+            ..ScriptReport::default()
+        }));
+        let engine = self.engine(&report);
+
+        let mut scope = Scope::new();
+        scope.push_constant("ARGS", args.iter().map(|a| Dynamic::from(a.clone())).collect::<Array>());
+        let ast = engine
+            .compile(source)
+            .map_err(|e| FastStartupError::ScriptError(format!("{}: {}", script, e)))?;
+        engine
+            .run_ast_with_scope(&mut scope, &ast)
+            .map_err(|e| FastStartupError::ScriptError(format!("{}: {}", script, e)))?;
+        drop(engine);
+
+        let report = report.lock().unwrap().clone();
+        Ok(report)
+    }
+
+    /// Run the script at `path`
+    pub fn run_file(&self, path: &Path, args: &[String]) -> Result<ScriptReport> {
+        let source = std::fs::read_to_string(path)?;
+        self.run(&path.display().to_string(), &source, args)
+    }
+}
+
+impl ReportData for ScriptReport {
+    fn title(&self) -> String {
+        format!("Script {}: {}", self.script, self.project_name)
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        serde_json::to_value(self).map_err(|e| FastStartupError::SerializationError(e.to_string()))
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        vec![
+            ("Findings".to_string(), self.findings.len().to_string()),
+            ("Errors".to_string(), self.errors().to_string()),
+            ("Output lines".to_string(), self.output.len().to_string()),
+        ]
+    }
+
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["severity", "message", "location"]);
+        for finding in &self.findings {
+            table.push(vec![
+                finding.severity.as_str().to_string(),
+                finding.message.clone(),
+                finding.location.clone().unwrap_or_default(),
+            ]);
+        }
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        self.findings
+            .iter()
+            .map(|f| Finding {
+                rule_id: format!("script/{}", Path::new(&self.script).file_stem().map(|s| s.to_string_lossy()).unwrap_or_default()),
+                severity: f.severity,
+                message: f.message.clone(),
+                location: f.location.clone(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::DependencyType;
+    use crate::scanner::AssetType;
+
+    fn asset(relative_path: &str, asset_type: AssetType, size_bytes: u64) -> AssetInfo {
+        AssetInfo {
+            path: PathBuf::from("/p").join(relative_path),
+            relative_path: relative_path.to_string(),
+            asset_type,
+            size_bytes,
+            modified: 0,
+            mount_point: "/Game".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_script_queries_graph() {
+        let assets = vec![
+            asset("Content/Maps/Entry.umap", AssetType::UMap, 100),
+            asset("Content/UI/Boot.uasset", AssetType::UAsset, 3_000_000),
+            asset("Content/Props/Crate.uasset", AssetType::UAsset, 10),
+        ];
+        let mut graph = DependencyGraph::new();
+        for a in &assets {
+            graph.add_asset(a);
+        }
+        graph.add_dependency(&assets[0].path, &assets[1].path, DependencyType::Import, true);
+        let roots: HashSet<PathBuf> = [assets[0].path.clone()].into_iter().collect();
+        let context = ScriptContext::build("Game", &graph, &assets, &roots);
+
+        let script = r#"
+            print(project_name() + " " + ARGS[0]);
+            for a in assets() {
+                if a.startup && a.size_bytes > parse_int(ARGS[0]) {
+                    finding("warning", "large startup asset", a.path);
+                }
+            }
+            print(dependencies("Content/Maps/Entry.umap"));
+            print(dependents("Content/UI/Boot.uasset")[0]);
+            print(startup_assets().len());
+            if asset("Content/Missing.uasset") == () { finding("note", "missing"); }
+        "#;
+        let report = context.run("big.rhai", script, &["1000000".to_string()]).unwrap();
+        assert_eq!(report.output[0], "Game 1000000");
+        assert_eq!(report.output[1], "[\"Content/UI/Boot.uasset\"]");
+        assert_eq!(report.output[2], "Content/Maps/Entry.umap");
+        assert_eq!(report.output[3], "2");
+        assert_eq!(report.findings.len(), 2);
+        assert_eq!(report.findings[0].location.as_deref(), Some("Content/UI/Boot.uasset"));
+        assert_eq!(report.findings()[0].rule_id, "script/big");
+
+        assert!(context.run("bad.rhai", "finding(\"fatal\", \"x\");", &[]).is_err());
+        let looping = context.clone().with_max_operations(1_000);
+        assert!(looping.run("loop.rhai", "loop {}", &[]).is_err());
+    }
+}
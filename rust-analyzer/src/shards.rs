@@ -0,0 +1,271 @@
+//! Shards Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! A cache split by startup scenario into shard files plus a `shards.json`
+//! manifest, so `cache pull --profile` fetches only the shards one profile
+//! needs from a directory or HTTP store
+
+use crate::cache::{CachedAsset, StartupCache};
+use crate::{FastStartupError, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+use tracing::info;
+
+pub const SHARD_MANIFEST: &str = "shards.json";
+
+/// Assets used by more than one scenario
+pub const SHARED_SHARD: &str = "shared";
+
+/// Assets no scenario uses, and the shader variants
+pub const REST_SHARD: &str = "rest";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShardEntry {
+    pub name: String,
+    pub file: String,
+    pub assets: usize,
+    pub size_bytes: u64,
+    /// Scenarios that need this shard
+    pub profiles: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShardManifest {
+    pub project_name: String,
+    pub created_at: DateTime<Utc>,
+    pub hash_algorithm: String,
+    pub shards: Vec<ShardEntry>,
+}
+
+impl ShardManifest {
+    pub fn profiles(&self) -> BTreeSet<&str> {
+        self.shards.iter().flat_map(|s| s.profiles.iter().map(|p| p.as_str())).collect()
+    }
+
+    /// Shards needed for `profile`, or every shard without one
+    pub fn select(&self, profile: Option<&str>) -> Result<Vec<&ShardEntry>> {
+        let Some(profile) = profile else {
+            return Ok(self.shards.iter().collect());
+        };
+        if !self.profiles().contains(profile) {
+            return Err(FastStartupError::InvalidArgument(format!(
+                "unknown profile {}; available: {}",
+                profile,
+                self.profiles().into_iter().collect::<Vec<_>>().join(", ")
+            )));
+        }
+        Ok(self.shards.iter().filter(|s| s.profiles.iter().any(|p| p == profile)).collect())
+    }
+
+    pub fn size_bytes(&self) -> u64 {
+        self.shards.iter().map(|s| s.size_bytes).sum()
+    }
+}
+
+/// Split `cache` into shards: one per scenario for the assets only it
+/// uses, `shared` and `rest`. Load order is kept within each shard; every
+/// shard serving a scenario carries that scenario's full ordered list.
+pub fn split(cache: &StartupCache) -> Vec<(String, StartupCache)> {
+    let mut users: HashMap<String, Vec<&str>> = HashMap::new();
+    for (name, paths) in &cache.scenarios {
+        for path in paths {
+            users.entry(path.replace('\\', "/")).or_default().push(name);
+        }
+    }
+
+    let mut shards: BTreeMap<String, (Vec<CachedAsset>, BTreeSet<&str>)> = BTreeMap::new();
+    // Shard and offset within it of every record
+    let mut placed: Vec<(String, u32)> = Vec::with_capacity(cache.assets.len());
+    for asset in &cache.assets {
+        let relative = asset.relative_path.replace('\\', "/");
+        let scenarios = users.get(&relative).map(|s| s.as_slice()).unwrap_or_default();
+        let name = match scenarios {
+            [] => REST_SHARD.to_string(),
+            [only] => format!("profile-{}", only),
+            _ => SHARED_SHARD.to_string(),
+        };
+        let shard = shards.entry(name.clone()).or_default();
+        shard.0.push(asset.clone());
+        shard.1.extend(scenarios.iter().copied());
+        placed.push((name, shard.0.len() as u32 - 1));
+    }
+    if !cache.shader_variants.is_empty() {
+        shards.entry(REST_SHARD.to_string()).or_default();
+    }
+
+    let mut load_orders: HashMap<&str, Vec<u32>> = HashMap::new();
+    for &record in &cache.load_order {
+        if let Some((shard, offset)) = placed.get(record as usize) {
+            load_orders.entry(shard.as_str()).or_default().push(*offset);
+        }
+    }
+
+    shards
+        .iter()
+        .map(|(name, (assets, profiles))| {
+            let mut shard = StartupCache {
+                version: cache.version.clone(),
+                created_at: cache.created_at,
+                project_name: cache.project_name.clone(),
+                hash_algorithm: cache.hash_algorithm.clone(),
+                tags: cache.tags.clone(),
+                metadata: cache.metadata.clone(),
+                assets: assets.clone(),
+                load_order: load_orders.remove(name.as_str()).unwrap_or_default(),
+                ..StartupCache::new(&cache.project_name)
+            };
+            for profile in profiles {
+                shard.scenarios.insert(profile.to_string(), cache.scenarios[*profile].clone());
+            }
+            if name == REST_SHARD {
+                shard.shader_variants = cache.shader_variants.clone();
+            }
+            (name.clone(), shard)
+        })
+        .collect()
+}
+
+/// Write the shards of `cache` and their manifest into `dir`
+pub fn write_shards(cache: &StartupCache, dir: &Path) -> Result<ShardManifest> {
+    std::fs::create_dir_all(dir)?;
+    let mut manifest = ShardManifest {
+        project_name: cache.project_name.clone(),
+        created_at: cache.created_at,
+        hash_algorithm: cache.hash_algorithm.clone(),
+        shards: Vec::new(),
+    };
+    for (name, mut shard) in split(cache) {
+        let file: String = name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+            .collect::<String>()
+            + ".uefast";
+        let path = dir.join(&file);
+        shard.save(&path)?;
+        manifest.shards.push(ShardEntry {
+            profiles: shard.scenarios.keys().cloned().collect(),
+            name,
+            file,
+            assets: shard.assets.len(),
+            size_bytes: std::fs::metadata(&path)?.len(),
+        });
+    }
+
+    let json = serde_json::to_string_pretty(&manifest)
+        .map_err(|e| FastStartupError::SerializationError(e.to_string()))?;
+    std::fs::write(dir.join(SHARD_MANIFEST), json)?;
+    Ok(manifest)
+}
+
+/// Transfer of a pull
+#[derive(Debug, Clone, Default)]
+pub struct PullStats {
+    pub shards: usize,
+    pub fetched_bytes: u64,
+    pub total_bytes: u64,
+}
+
+/// Fetch the shards `profile` needs from `source`, a directory or an
+/// `http(s)://` base URL, merged into one cache
+pub fn pull(source: &str, profile: Option<&str>) -> Result<(StartupCache, PullStats)> {
+    let manifest: ShardManifest = serde_json::from_reader(open(source, SHARD_MANIFEST)?)
+        .map_err(|e| FastStartupError::SerializationError(format!("{}: {}", SHARD_MANIFEST, e)))?;
+    let selected = manifest.select(profile)?;
+
+    let mut merged: Option<StartupCache> = None;
+    for entry in &selected {
+        info!("Pulling shard {} ({} assets)", entry.name, entry.assets);
+        let shard = StartupCache::load_from(&mut open(source, &entry.file)?)?;
+        match &mut merged {
+            None => merged = Some(shard),
+            Some(cache) => {
+                let base = cache.assets.len() as u32;
+                cache.assets.extend(shard.assets);
+                cache.load_order.extend(shard.load_order.iter().map(|r| base + r));
+                cache.scenarios.extend(shard.scenarios);
+                cache.shader_variants.extend(shard.shader_variants);
+            }
+        }
+    }
+
+    let mut cache = merged.unwrap_or_else(|| StartupCache::new(&manifest.project_name));
+    cache.rebuild_index();
+    let stats = PullStats {
+        shards: selected.len(),
+        fetched_bytes: selected.iter().map(|s| s.size_bytes).sum(),
+        total_bytes: manifest.size_bytes(),
+    };
+    Ok((cache, stats))
+}
+
+fn open(source: &str, file: &str) -> Result<Box<dyn Read>> {
+    if source.starts_with("http://") || source.starts_with("https://") {
+        let url = format!("{}/{}", source.trim_end_matches('/'), file);
+        let response = ureq::get(&url)
+            .call()
+            .map_err(|e| FastStartupError::CacheError(e.to_string()))?;
+        Ok(Box::new(response.into_reader()))
+    } else {
+        Ok(Box::new(BufReader::new(File::open(Path::new(source).join(file))?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::AssetType;
+
+    fn cached(relative_path: &str) -> CachedAsset {
+        CachedAsset {
+            relative_path: relative_path.to_string(),
+            asset_type: AssetType::UAsset,
+            content_hash: 1,
+            size_bytes: 10,
+            load_order: 0,
+            is_startup_critical: false,
+        }
+    }
+
+    #[test]
+    fn test_pull_profile_shards() {
+        let mut cache = StartupCache::new("Game");
+        for path in ["Content/Menu/Title.uasset", "Content/Fonts/Main.uasset", "Content/Maps/Arena.umap", "Content/Misc/Prop.uasset"] {
+            cache.load_order.push(cache.assets.len() as u32);
+            cache.assets.push(cached(path));
+        }
+        cache.scenarios.insert(
+            "MainMenu".to_string(),
+            vec!["Content/Fonts/Main.uasset".to_string(), "Content/Menu/Title.uasset".to_string()],
+        );
+        cache.scenarios.insert(
+            "Arena".to_string(),
+            vec!["Content/Fonts/Main.uasset".to_string(), "Content/Maps/Arena.umap".to_string()],
+        );
+
+        let dir = std::env::temp_dir().join(format!("uefast_shards_{}", std::process::id()));
+        let manifest = write_shards(&cache, &dir).unwrap();
+        let names: Vec<&str> = manifest.shards.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["profile-Arena", "profile-MainMenu", "rest", "shared"]);
+        assert_eq!(manifest.shards[3].profiles, ["Arena", "MainMenu"]);
+
+        let (menu, stats) = pull(dir.to_str().unwrap(), Some("MainMenu")).unwrap();
+        assert_eq!(stats.shards, 2);
+        assert!(menu.find(Path::new("Content/Menu/Title.uasset")).is_some());
+        assert!(menu.find(Path::new("Content/Fonts/Main.uasset")).is_some());
+        assert!(menu.find(Path::new("Content/Maps/Arena.umap")).is_none());
+        assert_eq!(menu.scenarios["MainMenu"].len(), 2);
+        let order: Vec<&str> = menu.ordered_assets().map(|a| a.relative_path.as_str()).collect();
+        assert_eq!(order, ["Content/Menu/Title.uasset", "Content/Fonts/Main.uasset"]);
+
+        assert!(pull(dir.to_str().unwrap(), Some("Editor")).is_err());
+        let (all, _) = pull(dir.to_str().unwrap(), None).unwrap();
+        assert_eq!(all.assets.len(), 4);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
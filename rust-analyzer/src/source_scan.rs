@@ -0,0 +1,495 @@
+//! Source Scan Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Lexical scan of the project's and plugins' C++ `Source` folders for
+//! `FModuleManager` module loads and `StartupModule` implementations doing
+//! blocking work, with the content they load resolved against the scan
+
+use crate::project::{ModuleDescriptor, Project};
+use crate::scanner::{package_name_from_relative, AssetInfo};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+const SOURCE_EXTENSIONS: &[&str] = &["cpp", "h", "hpp", "inl"];
+
+/// Build output next to sources; generated code is not the project's
+const SKIPPED_DIRS: &[&str] = &["Intermediate", "Binaries", "Saved", "ThirdParty"];
+
+const LOAD_MODULE_CALLS: &[&str] = &["LoadModuleChecked", "LoadModulePtr", "LoadModuleWithFailureReason", "LoadModule"];
+
+/// Blocking calls worth flagging inside `StartupModule`, and their kind
+const BLOCKING_CALLS: &[(&str, &str)] = &[
+    ("LoadObject<", "asset load"),
+    ("StaticLoadObject(", "asset load"),
+    ("StaticLoadClass(", "asset load"),
+    ("LoadClass<", "asset load"),
+    ("LoadSynchronous(", "asset load"),
+    ("TryLoad(", "asset load"),
+    ("LoadPackage(", "asset load"),
+    ("FObjectFinder<", "asset load"),
+    ("FClassFinder<", "asset load"),
+    ("SearchAllAssets(", "asset registry scan"),
+    ("ScanPathsSynchronous(", "asset registry scan"),
+    ("WaitForCompletion(", "asset registry scan"),
+    ("FlushAsyncLoading(", "flush async loading"),
+    ("IterateDirectory", "file system scan"),
+    ("FindFilesRecursive(", "file system scan"),
+    ("FPlatformProcess::Sleep", "sleep"),
+];
+
+/// Loading phases that run before the engine (and its loading screen) is up
+const EARLY_PHASES: &[&str] = &[
+    "EarliestPossible",
+    "PostConfigInit",
+    "PostSplashScreen",
+    "PreEarlyLoadingScreen",
+    "PreLoadingScreen",
+    "PreDefault",
+];
+
+/// `FModuleManager` call loading a module by name
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModuleLoad {
+    /// `file:line`, project-relative
+    pub location: String,
+    pub call: String,
+    /// Module being loaded
+    pub module: String,
+    /// Inside a `StartupModule` body, i.e. loaded eagerly at module startup
+    pub in_startup_module: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlockingCall {
+    pub location: String,
+    pub call: String,
+    pub kind: String,
+}
+
+/// `StartupModule` implementation and the startup work it does
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StartupModuleInfo {
+    /// Module owning the source file (its `*.Build.cs`)
+    pub module: String,
+    pub location: String,
+    /// From the `.uproject` or `.uplugin` descriptor, when listed there
+    pub loading_phase: Option<String>,
+    pub blocking_calls: Vec<BlockingCall>,
+    /// Modules loaded from this `StartupModule`
+    pub module_loads: Vec<String>,
+    /// `/Game` content referenced from the body, as project-relative paths
+    pub assets: Vec<String>,
+    pub asset_bytes: u64,
+}
+
+impl StartupModuleInfo {
+    pub fn is_early(&self) -> bool {
+        self.loading_phase.as_deref().is_some_and(|p| EARLY_PHASES.contains(&p))
+    }
+
+    pub fn is_hotspot(&self) -> bool {
+        !self.blocking_calls.is_empty() || self.asset_bytes > 0
+    }
+
+    /// Rough boot cost: ~20ms per MB of content, ~100ms per scan, flush or
+    /// sleep and ~10ms per eagerly loaded module
+    pub fn estimated_seconds(&self) -> f64 {
+        let waits = self.blocking_calls.iter().filter(|c| c.kind != "asset load").count();
+        self.asset_bytes as f64 / (1024.0 * 1024.0) * 0.02 + waits as f64 * 0.1 + self.module_loads.len() as f64 * 0.01
+    }
+
+    /// Distinct kinds of blocking calls, e.g. "asset load, sleep"
+    pub fn blocking_kinds(&self) -> String {
+        let mut kinds: Vec<&str> = self.blocking_calls.iter().map(|c| c.kind.as_str()).collect();
+        kinds.sort_unstable();
+        kinds.dedup();
+        kinds.join(", ")
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SourceScan {
+    pub files_scanned: usize,
+    /// Every module load found, in file order
+    pub module_loads: Vec<ModuleLoad>,
+    /// Heaviest estimated cost first
+    pub startup_modules: Vec<StartupModuleInfo>,
+}
+
+impl SourceScan {
+    /// Scan `project_root`'s `Source` and `Plugins/*/Source` folders.
+    /// `assets` resolves `/Game` references to sizes.
+    pub fn scan(project_root: &Path, assets: &[AssetInfo]) -> Self {
+        let files = source_files(project_root);
+        let modules = build_modules(&files);
+        let phases = loading_phases(project_root);
+        let packages: HashMap<String, (&str, u64)> = assets
+            .iter()
+            .filter_map(|a| Some((package_name_from_relative(&a.relative_path)?, (a.relative_path.as_str(), a.size_bytes))))
+            .collect();
+
+        let per_file: Vec<(Vec<ModuleLoad>, Vec<StartupModuleInfo>)> = files
+            .par_iter()
+            .filter(|f| f.extension().is_some_and(|e| SOURCE_EXTENSIONS.iter().any(|x| e.eq_ignore_ascii_case(x))))
+            .filter_map(|file| {
+                let text = std::fs::read_to_string(file).ok()?;
+                let relative = file.strip_prefix(project_root).unwrap_or(file).to_string_lossy().replace('\\', "/");
+                let module = owning_module(&modules, file).unwrap_or_default();
+                let (loads, mut startups) = scan_source(&strip_comments(&text), &relative, &module);
+                for startup in &mut startups {
+                    startup.loading_phase = phases.get(&startup.module).cloned();
+                    let mut resolved: Vec<(&str, u64)> = startup
+                        .assets
+                        .iter()
+                        .filter_map(|package| packages.get(package).copied())
+                        .collect();
+                    resolved.sort_unstable();
+                    resolved.dedup();
+                    startup.asset_bytes = resolved.iter().map(|(_, size)| size).sum();
+                    startup.assets = resolved.into_iter().map(|(path, _)| path.to_string()).collect();
+                }
+                Some((loads, startups))
+            })
+            .collect();
+
+        let mut scan = Self { files_scanned: per_file.len(), ..Default::default() };
+        for (loads, startups) in per_file {
+            scan.module_loads.extend(loads);
+            scan.startup_modules.extend(startups);
+        }
+        scan.startup_modules.sort_by(|a, b| {
+            b.estimated_seconds()
+                .total_cmp(&a.estimated_seconds())
+                .then_with(|| a.location.cmp(&b.location))
+        });
+        scan
+    }
+
+    pub fn hotspots(&self) -> impl Iterator<Item = &StartupModuleInfo> {
+        self.startup_modules.iter().filter(|s| s.is_hotspot())
+    }
+
+    /// Modules loaded eagerly from `StartupModule` implementations
+    pub fn eager_module_loads(&self) -> Vec<&str> {
+        let mut modules: Vec<&str> = self
+            .module_loads
+            .iter()
+            .filter(|l| l.in_startup_module)
+            .map(|l| l.module.as_str())
+            .collect();
+        modules.sort_unstable();
+        modules.dedup();
+        modules
+    }
+}
+
+/// Source files under `Source` and every plugin's `Source`, sorted
+fn source_files(project_root: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = ["Source", "Plugins"]
+        .iter()
+        .flat_map(|dir| {
+            WalkDir::new(project_root.join(dir))
+                .into_iter()
+                .filter_entry(|e| !SKIPPED_DIRS.iter().any(|s| e.file_name() == *s))
+                .filter_map(|e| e.ok())
+        })
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.into_path())
+        .filter(|p| p.components().any(|c| c.as_os_str() == "Source"))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Module directories, from their `<Name>.Build.cs` rules files
+fn build_modules(files: &[PathBuf]) -> Vec<(PathBuf, String)> {
+    files
+        .iter()
+        .filter_map(|f| {
+            let name = f.file_name()?.to_str()?.strip_suffix(".Build.cs")?;
+            Some((f.parent()?.to_path_buf(), name.to_string()))
+        })
+        .collect()
+}
+
+/// Innermost module directory containing `file`
+fn owning_module(modules: &[(PathBuf, String)], file: &Path) -> Option<String> {
+    modules
+        .iter()
+        .filter(|(dir, _)| file.starts_with(dir))
+        .max_by_key(|(dir, _)| dir.components().count())
+        .map(|(_, name)| name.clone())
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "PascalCase", default)]
+struct PluginDescriptor {
+    modules: Vec<ModuleDescriptor>,
+}
+
+/// Loading phase per module, from the `.uproject` and every `.uplugin`
+fn loading_phases(project_root: &Path) -> HashMap<String, String> {
+    let mut descriptors: Vec<ModuleDescriptor> = Project::open_optional(project_root)
+        .ok()
+        .flatten()
+        .map(|p| p.descriptor.modules)
+        .unwrap_or_default();
+    for entry in WalkDir::new(project_root.join("Plugins")).max_depth(4).into_iter().filter_map(|e| e.ok()) {
+        if entry.path().extension().is_some_and(|e| e.eq_ignore_ascii_case("uplugin")) {
+            let plugin = std::fs::read_to_string(entry.path())
+                .ok()
+                .and_then(|text| serde_json::from_str::<PluginDescriptor>(&text).ok());
+            descriptors.extend(plugin.map(|p| p.modules).unwrap_or_default());
+        }
+    }
+    descriptors
+        .into_iter()
+        .filter_map(|m| Some((m.name, m.loading_phase?)))
+        .collect()
+}
+
+/// Blank out comments, keeping line breaks (so line numbers hold) and
+/// string literals (which carry module names and asset paths)
+fn strip_comments(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut last = ' ';
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                    }
+                    if last == '*' && c == '/' {
+                        break;
+                    }
+                    last = c;
+                }
+                out.push(' ');
+            }
+            '"' | '\'' => {
+                out.push(c);
+                let mut escaped = false;
+                for s in chars.by_ref() {
+                    out.push(s);
+                    if s == '\n' || (s == c && !escaped) {
+                        break;
+                    }
+                    escaped = s == '\\' && !escaped;
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// First string literal at or after `from` on `line`
+fn first_literal(line: &str, from: usize) -> Option<&str> {
+    let start = from + line[from..].find('"')? + 1;
+    let end = start + line[start..].find('"')?;
+    Some(&line[start..end])
+}
+
+/// `/Game` packages named by string literals on `line`
+fn game_packages(line: &str) -> Vec<String> {
+    let mut packages = Vec::new();
+    let mut rest = line;
+    while let Some(start) = rest.find("\"/Game/") {
+        let literal = &rest[start + 1..];
+        let end = literal.find('"').unwrap_or(literal.len());
+        // Object paths are `/Game/Dir/Asset.Asset` or `.Asset_C`
+        let path = &literal[..end];
+        let package = path.split(['.', ':']).next().unwrap_or(path);
+        packages.push(package.to_string());
+        rest = &literal[end..];
+    }
+    packages
+}
+
+/// Byte range of the `StartupModule` bodies in `text` and the line each
+/// starts on
+fn startup_bodies(text: &str) -> Vec<(usize, usize, usize)> {
+    let mut bodies = Vec::new();
+    let mut search = 0;
+    while let Some(offset) = text[search..].find("StartupModule") {
+        let name_start = search + offset;
+        search = name_start + "StartupModule".len();
+        let before = text[..name_start].chars().next_back();
+        if before.is_some_and(|c| c.is_alphanumeric() || c == '_') {
+            continue;
+        }
+        let rest = &text[search..];
+        let Some(after_params) = rest.trim_start().strip_prefix("()") else {
+            continue;
+        };
+        // Skip qualifiers up to the body; a `;` first means a declaration
+        let Some(open) = after_params.find(['{', ';']) else {
+            break;
+        };
+        if !after_params[open..].starts_with('{')
+            || !after_params[..open].split_whitespace().all(|q| matches!(q, "override" | "final" | "const"))
+        {
+            continue;
+        }
+        let body_start = text.len() - after_params.len() + open;
+        let mut depth = 0;
+        let mut in_string = false;
+        let mut end = text.len();
+        let mut prev = ' ';
+        for (i, c) in text[body_start..].char_indices() {
+            match c {
+                '"' if prev != '\\' => in_string = !in_string,
+                '{' if !in_string => depth += 1,
+                '}' if !in_string => {
+                    depth -= 1;
+                    if depth == 0 {
+                        end = body_start + i;
+                        break;
+                    }
+                }
+                _ => {}
+            }
+            prev = c;
+        }
+        let line = text[..name_start].matches('\n').count() + 1;
+        bodies.push((body_start, end, line));
+        search = end.max(search);
+    }
+    bodies
+}
+
+/// Module loads and `StartupModule` implementations in one source file
+fn scan_source(text: &str, relative: &str, module: &str) -> (Vec<ModuleLoad>, Vec<StartupModuleInfo>) {
+    let bodies = startup_bodies(text);
+    let mut startups: Vec<StartupModuleInfo> = bodies
+        .iter()
+        .map(|(_, _, line)| StartupModuleInfo {
+            module: module.to_string(),
+            location: format!("{}:{}", relative, line),
+            ..Default::default()
+        })
+        .collect();
+
+    let mut loads = Vec::new();
+    let mut offset = 0;
+    for (index, line) in text.split('\n').enumerate() {
+        let location = format!("{}:{}", relative, index + 1);
+        let body = bodies
+            .iter()
+            .position(|(start, end, _)| offset <= *end && offset + line.len() >= *start);
+        offset += line.len() + 1;
+
+        if line.contains("ModuleManager") {
+            if let Some((call, at)) = LOAD_MODULE_CALLS.iter().find_map(|call| {
+                let at = line.find(call)?;
+                line[at + call.len()..].starts_with(['(', '<']).then_some((*call, at))
+            }) {
+                if let Some(name) = first_literal(line, at) {
+                    if let Some(startup) = body.map(|b| &mut startups[b]) {
+                        startup.module_loads.push(name.to_string());
+                    }
+                    loads.push(ModuleLoad {
+                        location: location.clone(),
+                        call: call.to_string(),
+                        module: name.to_string(),
+                        in_startup_module: body.is_some(),
+                    });
+                }
+            }
+        }
+
+        let Some(startup) = body.map(|b| &mut startups[b]) else {
+            continue;
+        };
+        for (pattern, kind) in BLOCKING_CALLS {
+            if line.contains(pattern) {
+                startup.blocking_calls.push(BlockingCall {
+                    location: location.clone(),
+                    call: pattern.trim_end_matches(['(', '<']).to_string(),
+                    kind: kind.to_string(),
+                });
+            }
+        }
+        startup.assets.extend(game_packages(line));
+    }
+    (loads, startups)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODULE_CPP: &str = r#"
+#include "ShooterModule.h"
+
+// FModuleManager::LoadModuleChecked<IFoo>("Commented");
+void FShooterModule::StartupModule()
+{
+    FModuleManager::LoadModuleChecked<IAssetRegistryModule>(TEXT("AssetRegistry"));
+    IAssetRegistry& Registry = FModuleManager::Get().LoadModuleChecked<FAssetRegistryModule>("AssetRegistry").Get();
+    Registry.SearchAllAssets(true);
+    /* LoadObject<UTexture2D>(nullptr, TEXT("/Game/Ignored.Ignored")); */
+    if (true) { DefaultMenu = LoadObject<UDataAsset>(nullptr, TEXT("/Game/UI/Menu.Menu")); }
+}
+
+void FShooterModule::ShutdownModule() {}
+
+void UHud::Init()
+{
+    FModuleManager::LoadModulePtr<IUMG>("UMG");
+}
+
+IMPLEMENT_PRIMARY_GAME_MODULE(FShooterModule, Shooter, "Shooter");
+"#;
+
+    #[test]
+    fn test_scan_source() {
+        let (loads, startups) = scan_source(&strip_comments(MODULE_CPP), "Source/Shooter/Shooter.cpp", "Shooter");
+
+        let modules: Vec<(&str, bool)> = loads.iter().map(|l| (l.module.as_str(), l.in_startup_module)).collect();
+        assert_eq!(modules, [("AssetRegistry", true), ("AssetRegistry", true), ("UMG", false)]);
+        assert_eq!(loads[2].location, "Source/Shooter/Shooter.cpp:18");
+
+        assert_eq!(startups.len(), 1);
+        let startup = &startups[0];
+        assert_eq!(startup.location, "Source/Shooter/Shooter.cpp:5");
+        assert_eq!(startup.module_loads.len(), 2);
+        let calls: Vec<&str> = startup.blocking_calls.iter().map(|c| c.call.as_str()).collect();
+        assert_eq!(calls, ["SearchAllAssets", "LoadObject"]);
+        assert_eq!(startup.blocking_kinds(), "asset load, asset registry scan");
+        assert_eq!(startup.assets, ["/Game/UI/Menu"]);
+    }
+
+    #[test]
+    fn test_declarations_and_literals() {
+        let header = "class FShooterModule : public IModuleInterface {\n\
+                      virtual void StartupModule() override;\n\
+                      virtual void PreStartupModule() override {}\n};";
+        assert!(startup_bodies(header).is_empty());
+
+        let inline = "virtual void StartupModule() override { FlushAsyncLoading(); }";
+        let (_, startups) = scan_source(inline, "Plugins/Tools/Source/Tools/Tools.h", "Tools");
+        assert_eq!(startups[0].blocking_calls[0].kind, "flush async loading");
+
+        assert_eq!(
+            game_packages(r#"FSoftObjectPath(TEXT("/Game/BP/Hero.Hero_C")), "/Game/Maps/Entry""#),
+            ["/Game/BP/Hero", "/Game/Maps/Entry"]
+        );
+        assert_eq!(strip_comments("a // \"b\nc /* d\n */ \"// e\""), "a \nc \n  \"// e\"");
+    }
+}
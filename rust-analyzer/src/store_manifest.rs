@@ -0,0 +1,900 @@
+//! Store Manifest Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Readers for distribution manifests: Steam depot manifests (the binary
+//! depotcache format and the text listing DepotDownloader and SteamCMD
+//! write) and Epic build manifests (binary and JSON). A packaged build's
+//! file list, content hashes and chunking can be analyzed from these alone,
+//! and two manifests of the same app give the download size of a patch.
+
+use crate::byte_reader::ByteReader;
+use crate::report::{Finding, ReportData, Severity, Table};
+use crate::{FastStartupError, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+const MB: f64 = 1024.0 * 1024.0;
+
+/// Section magics of a binary Steam depot manifest
+const STEAM_PAYLOAD_MAGIC: u32 = 0x71F617D0;
+const STEAM_METADATA_MAGIC: u32 = 0x1F4812BE;
+const STEAM_SIGNATURE_MAGIC: u32 = 0x1B81B817;
+const STEAM_END_MAGIC: u32 = 0x32C415AB;
+
+/// `EDepotFileFlag::Directory`
+const STEAM_FLAG_DIRECTORY: u64 = 0x40;
+
+const EPIC_MANIFEST_MAGIC: u32 = 0x44BEC00C;
+const EPIC_STORED_COMPRESSED: u8 = 0x01;
+const EPIC_STORED_ENCRYPTED: u8 = 0x02;
+
+/// A patch that rewrites at least this share of a container's chunks
+/// moved content that did not change
+pub const CHURN_RATIO: f64 = 0.5;
+
+/// Extensions of cooked packages shipped as loose files
+const PACKAGE_EXTENSIONS: &[&str] = &["uasset", "umap", "uexp", "ubulk"];
+
+/// Extensions of packaged content containers
+const CONTAINER_EXTENSIONS: &[&str] = &["pak", "ucas", "utoc"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Store {
+    Steam,
+    Epic,
+}
+
+impl Store {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Store::Steam => "steam",
+            Store::Epic => "epic",
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Store::Steam => "Steam",
+            Store::Epic => "Epic",
+        }
+    }
+}
+
+/// One installed file of the build
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestFile {
+    /// Path below the install directory, with forward slashes
+    pub path: String,
+    pub size_bytes: u64,
+    /// SHA-1 of the file content, lowercase hex
+    pub sha1: String,
+    /// Chunks the file is assembled from, in order; empty when the manifest
+    /// only gives a count
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub chunks: Vec<String>,
+    pub chunk_count: usize,
+}
+
+impl ManifestFile {
+    fn extension(&self) -> String {
+        Path::new(&self.path)
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_lowercase())
+            .unwrap_or_default()
+    }
+
+    pub fn is_container(&self) -> bool {
+        CONTAINER_EXTENSIONS.contains(&self.extension().as_str())
+    }
+
+    pub fn is_loose_package(&self) -> bool {
+        PACKAGE_EXTENSIONS.contains(&self.extension().as_str())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoreManifest {
+    pub store: Store,
+    pub source: PathBuf,
+    /// Depot id on Steam, app name on Epic
+    pub app: String,
+    /// Manifest id on Steam, build version on Epic
+    pub build: String,
+    pub files: Vec<ManifestFile>,
+    /// Download size per chunk id; empty when the manifest lists no chunks
+    pub chunk_sizes: BTreeMap<String, u64>,
+    /// Total chunk count from a text listing, which names no chunks
+    pub listed_chunks: Option<usize>,
+}
+
+impl StoreManifest {
+    /// Read a manifest, detecting the store and format from its content
+    pub fn open(path: &Path) -> Result<Self> {
+        let data = std::fs::read(path)?;
+        let magic = data.get(..4).map(|m| u32::from_le_bytes([m[0], m[1], m[2], m[3]]));
+        let mut manifest = match magic {
+            Some(STEAM_PAYLOAD_MAGIC) => Self::read_steam(&data)?,
+            Some(EPIC_MANIFEST_MAGIC) => Self::read_epic(&data)?,
+            _ => {
+                let text = String::from_utf8_lossy(&data);
+                if text.trim_start().starts_with('{') {
+                    Self::read_epic_json(&text)?
+                } else if text.contains("Content Manifest for Depot") {
+                    Self::read_steam_listing(&text)?
+                } else {
+                    return Err(FastStartupError::AssetError(format!(
+                        "{} is not a Steam depot manifest or an Epic build manifest",
+                        path.display()
+                    )));
+                }
+            }
+        };
+        manifest.source = path.to_path_buf();
+        manifest.files.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(manifest)
+    }
+
+    /// Binary depotcache manifest: protobuf sections behind magic and length
+    pub fn read_steam(data: &[u8]) -> Result<Self> {
+        let mut reader = ByteReader::new(data);
+        let mut files = Vec::new();
+        let mut chunk_sizes = BTreeMap::new();
+        let mut app = String::new();
+        let mut build = String::new();
+        while reader.remaining() >= 4 {
+            let magic = reader.u32()?;
+            if magic == STEAM_END_MAGIC {
+                break;
+            }
+            let len = reader.u32()? as usize;
+            let section = reader.bytes(len)?;
+            match magic {
+                STEAM_PAYLOAD_MAGIC => {
+                    let mut payload = Proto::new(section);
+                    while let Some((field, value)) = payload.next()? {
+                        if field == 1 {
+                            if let Some(file) = steam_file_mapping(value.bytes()?, &mut chunk_sizes)? {
+                                files.push(file);
+                            }
+                        }
+                    }
+                }
+                STEAM_METADATA_MAGIC => {
+                    let mut metadata = Proto::new(section);
+                    while let Some((field, value)) = metadata.next()? {
+                        match field {
+                            1 => app = value.varint()?.to_string(),
+                            2 => build = value.varint()?.to_string(),
+                            4 if value.varint()? != 0 => {
+                                return Err(FastStartupError::AssetError(
+                                    "Depot manifest file names are encrypted; decrypt it with the depot key first"
+                                        .to_string(),
+                                ));
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                STEAM_SIGNATURE_MAGIC => {}
+                other => {
+                    return Err(FastStartupError::AssetError(format!(
+                        "Unknown depot manifest section 0x{:08X}",
+                        other
+                    )));
+                }
+            }
+        }
+        Ok(Self { store: Store::Steam, source: PathBuf::new(), app, build, files, chunk_sizes, listed_chunks: None })
+    }
+
+    /// Text listing: a header block, then `Size Chunks File SHA Flags Name`
+    /// rows
+    pub fn read_steam_listing(text: &str) -> Result<Self> {
+        let mut app = String::new();
+        let mut build = String::new();
+        let mut listed_chunks = None;
+        let mut files = Vec::new();
+        let mut in_rows = false;
+        for line in text.lines() {
+            let trimmed = line.trim();
+            if let Some(depot) = trimmed.strip_prefix("Content Manifest for Depot") {
+                app = depot.trim().to_string();
+            } else if let Some((key, value)) = trimmed.split_once(':').filter(|_| !in_rows) {
+                let value = value.trim();
+                match key.trim() {
+                    "Manifest ID / date" => build = value.split('/').next().unwrap_or("").trim().to_string(),
+                    "Total number of chunks" => listed_chunks = value.parse().ok(),
+                    _ => {}
+                }
+            } else if trimmed.starts_with("Size") && trimmed.contains("Name") {
+                in_rows = true;
+            } else if in_rows && !trimmed.is_empty() {
+                let mut columns = trimmed.split_whitespace();
+                let row = (|| {
+                    let size = columns.next()?.parse::<u64>().ok()?;
+                    let chunks = columns.next()?.parse::<usize>().ok()?;
+                    let sha1 = columns.next()?.to_lowercase();
+                    let flags = columns.next()?.parse::<u64>().ok()?;
+                    // The name is the rest of the row and may contain spaces
+                    Some((size, chunks, sha1, flags, rest_after_columns(trimmed, 4)?))
+                })();
+                let Some((size_bytes, chunk_count, sha1, flags, path)) = row else {
+                    return Err(FastStartupError::AssetError(format!("Malformed depot manifest row: {}", trimmed)));
+                };
+                if flags & STEAM_FLAG_DIRECTORY != 0 || path.is_empty() {
+                    continue;
+                }
+                files.push(ManifestFile {
+                    path: path.replace('\\', "/"),
+                    size_bytes,
+                    sha1,
+                    chunks: Vec::new(),
+                    chunk_count,
+                });
+            }
+        }
+        if !in_rows {
+            return Err(FastStartupError::AssetError("Depot manifest listing has no file rows".to_string()));
+        }
+        Ok(Self {
+            store: Store::Steam,
+            source: PathBuf::new(),
+            app,
+            build,
+            files,
+            chunk_sizes: BTreeMap::new(),
+            listed_chunks,
+        })
+    }
+
+    /// Binary build manifest: a header, then zlib-compressed meta, chunk
+    /// list and file list sections, each led by its own size
+    pub fn read_epic(data: &[u8]) -> Result<Self> {
+        let mut reader = ByteReader::new(data);
+        reader.u32()?;
+        let header_size = reader.u32()? as usize;
+        let uncompressed_size = reader.u32()? as usize;
+        let compressed_size = reader.u32()? as usize;
+        reader.skip(20)?;
+        let stored_as = reader.bytes(1)?[0];
+        if stored_as & EPIC_STORED_ENCRYPTED != 0 {
+            return Err(FastStartupError::AssetError("Build manifest is encrypted".to_string()));
+        }
+        reader.seek(header_size)?;
+        let stored = reader.bytes(if stored_as & EPIC_STORED_COMPRESSED != 0 { compressed_size } else { uncompressed_size })?;
+        let body = if stored_as & EPIC_STORED_COMPRESSED != 0 {
+            let mut body = Vec::with_capacity(uncompressed_size);
+            flate2::read::ZlibDecoder::new(stored)
+                .read_to_end(&mut body)
+                .map_err(|e| FastStartupError::AssetError(format!("Build manifest does not inflate: {}", e)))?;
+            body
+        } else {
+            stored.to_vec()
+        };
+
+        let mut reader = ByteReader::new(&body);
+        // Meta: app and build
+        let start = reader.position();
+        let size = reader.u32()? as usize;
+        reader.skip(1 + 4 + 1 + 4)?;
+        let app = reader.fstring()?;
+        let build = reader.fstring()?;
+        reader.seek(start + size)?;
+
+        // Chunk list, stored column by column
+        let start = reader.position();
+        let size = reader.u32()? as usize;
+        reader.skip(1)?;
+        let count = reader.index()?;
+        let guids = (0..count).map(|_| epic_guid(&mut reader)).collect::<Result<Vec<_>>>()?;
+        reader.skip(count * (8 + 20 + 1 + 4))?;
+        let mut chunk_sizes = BTreeMap::new();
+        for guid in guids {
+            chunk_sizes.insert(guid, reader.i64()?.max(0) as u64);
+        }
+        reader.seek(start + size)?;
+
+        // File list, also column by column
+        reader.u32()?;
+        reader.skip(1)?;
+        let count = reader.index()?;
+        let names = (0..count).map(|_| reader.fstring()).collect::<Result<Vec<_>>>()?;
+        for _ in 0..count {
+            reader.fstring()?;
+        }
+        let hashes = (0..count).map(|_| reader.bytes(20).map(to_hex)).collect::<Result<Vec<_>>>()?;
+        reader.skip(count)?;
+        for _ in 0..count {
+            for _ in 0..reader.index()? {
+                reader.fstring()?;
+            }
+        }
+        let mut files = Vec::with_capacity(count);
+        for (path, sha1) in names.into_iter().zip(hashes) {
+            let mut chunks = Vec::new();
+            let mut size_bytes = 0;
+            for _ in 0..reader.index()? {
+                let part = reader.position();
+                let part_size = reader.u32()? as usize;
+                chunks.push(epic_guid(&mut reader)?);
+                reader.u32()?;
+                size_bytes += reader.u32()? as u64;
+                reader.seek(part + part_size)?;
+            }
+            files.push(ManifestFile { path, size_bytes, sha1, chunk_count: chunks.len(), chunks });
+        }
+        Ok(Self { store: Store::Epic, source: PathBuf::new(), app, build, files, chunk_sizes, listed_chunks: None })
+    }
+
+    /// JSON build manifest; numbers and hashes are "blobs" of three decimal
+    /// digits per byte
+    pub fn read_epic_json(text: &str) -> Result<Self> {
+        let json: Value = serde_json::from_str(text).map_err(|e| FastStartupError::AssetError(e.to_string()))?;
+        let string = |value: &Value, key: &str| value.get(key).and_then(Value::as_str).unwrap_or("").to_string();
+
+        let mut files = Vec::new();
+        for file in json.get("FileManifestList").and_then(Value::as_array).into_iter().flatten() {
+            let mut chunks = Vec::new();
+            let mut size_bytes = 0;
+            for part in file.get("FileChunkParts").and_then(Value::as_array).into_iter().flatten() {
+                chunks.push(string(part, "Guid").to_uppercase());
+                size_bytes += blob_u64(&string(part, "Size"))?;
+            }
+            files.push(ManifestFile {
+                path: string(file, "Filename"),
+                size_bytes,
+                sha1: to_hex(&blob_bytes(&string(file, "FileHash"))?),
+                chunk_count: chunks.len(),
+                chunks,
+            });
+        }
+
+        let mut chunk_sizes = BTreeMap::new();
+        if let Some(sizes) = json.get("ChunkFilesizeList").and_then(Value::as_object) {
+            for (guid, size) in sizes {
+                chunk_sizes.insert(guid.to_uppercase(), blob_u64(size.as_str().unwrap_or(""))?);
+            }
+        }
+        Ok(Self {
+            store: Store::Epic,
+            source: PathBuf::new(),
+            app: string(&json, "AppNameString"),
+            build: string(&json, "BuildVersionString"),
+            files,
+            chunk_sizes,
+            listed_chunks: None,
+        })
+    }
+
+    pub fn install_bytes(&self) -> u64 {
+        self.files.iter().map(|f| f.size_bytes).sum()
+    }
+
+    /// Compressed size of every chunk; the install size when chunk sizes
+    /// are not listed
+    pub fn download_bytes(&self) -> u64 {
+        if self.chunk_sizes.is_empty() {
+            self.install_bytes()
+        } else {
+            self.chunk_sizes.values().sum()
+        }
+    }
+
+    pub fn chunk_count(&self) -> usize {
+        self.listed_chunks.unwrap_or(self.chunk_sizes.len())
+    }
+
+    fn has_chunk_ids(&self) -> bool {
+        self.files.iter().any(|f| !f.chunks.is_empty())
+    }
+}
+
+/// Container or file that differs between two manifests
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangedFile {
+    pub path: String,
+    pub size_bytes: u64,
+    /// Chunks not present in the base build, of `chunk_count`
+    pub new_chunks: usize,
+    pub chunk_count: usize,
+}
+
+impl ChangedFile {
+    pub fn churn(&self) -> f64 {
+        if self.chunk_count == 0 {
+            1.0
+        } else {
+            self.new_chunks as f64 / self.chunk_count as f64
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestDelta {
+    pub base_build: String,
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    /// Files present in both builds with different content, largest first
+    pub changed: Vec<ChangedFile>,
+    /// Bytes a client of the base build downloads: new chunks, or the
+    /// changed and added files when the manifests name no chunks
+    pub download_bytes: u64,
+}
+
+impl ManifestDelta {
+    pub fn between(base: &StoreManifest, patched: &StoreManifest) -> Self {
+        let base_files: HashMap<&str, &ManifestFile> = base.files.iter().map(|f| (f.path.as_str(), f)).collect();
+        let patched_paths: HashSet<&str> = patched.files.iter().map(|f| f.path.as_str()).collect();
+        let base_chunks: HashSet<&str> = base.files.iter().flat_map(|f| f.chunks.iter().map(String::as_str)).collect();
+        let by_chunks = base.has_chunk_ids() && patched.has_chunk_ids();
+
+        let mut added = Vec::new();
+        let mut changed = Vec::new();
+        let mut download_bytes = 0;
+        for file in &patched.files {
+            match base_files.get(file.path.as_str()) {
+                None => added.push(file.path.clone()),
+                Some(old) if old.sha1 != file.sha1 => {
+                    let new_chunks = if by_chunks {
+                        file.chunks.iter().filter(|c| !base_chunks.contains(c.as_str())).count()
+                    } else {
+                        file.chunk_count
+                    };
+                    changed.push(ChangedFile {
+                        path: file.path.clone(),
+                        size_bytes: file.size_bytes,
+                        new_chunks,
+                        chunk_count: file.chunk_count,
+                    });
+                }
+                Some(_) => continue,
+            }
+            if !by_chunks {
+                download_bytes += file.size_bytes;
+            }
+        }
+        if by_chunks {
+            let new_chunks: HashSet<&str> = patched
+                .files
+                .iter()
+                .flat_map(|f| f.chunks.iter().map(String::as_str))
+                .filter(|c| !base_chunks.contains(c))
+                .collect();
+            download_bytes = new_chunks.iter().filter_map(|c| patched.chunk_sizes.get(*c)).sum();
+        }
+        changed.sort_by(|a, b| b.size_bytes.cmp(&a.size_bytes).then_with(|| a.path.cmp(&b.path)));
+
+        let removed = base
+            .files
+            .iter()
+            .filter(|f| !patched_paths.contains(f.path.as_str()))
+            .map(|f| f.path.clone())
+            .collect();
+        Self { base_build: base.build.clone(), added, removed, changed, download_bytes }
+    }
+}
+
+/// Build-level view of a manifest, optionally against an earlier build
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestReport {
+    pub manifest: StoreManifest,
+    pub delta: Option<ManifestDelta>,
+}
+
+impl ManifestReport {
+    pub fn new(manifest: StoreManifest, base: Option<&StoreManifest>) -> Self {
+        let delta = base.map(|base| ManifestDelta::between(base, &manifest));
+        Self { manifest, delta }
+    }
+
+    pub fn containers(&self) -> impl Iterator<Item = &ManifestFile> {
+        self.manifest.files.iter().filter(|f| f.is_container())
+    }
+
+    pub fn loose_packages(&self) -> usize {
+        self.manifest.files.iter().filter(|f| f.is_loose_package()).count()
+    }
+}
+
+impl ReportData for ManifestReport {
+    fn title(&self) -> String {
+        format!("{} manifest: {} {}", self.manifest.store.display_name(), self.manifest.app, self.manifest.build)
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        serde_json::to_value(self).map_err(|e| FastStartupError::SerializationError(e.to_string()))
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        let mut summary = vec![
+            ("Files".to_string(), self.manifest.files.len().to_string()),
+            ("Install size".to_string(), format!("{:.1} MB", self.manifest.install_bytes() as f64 / MB)),
+            ("Download size".to_string(), format!("{:.1} MB", self.manifest.download_bytes() as f64 / MB)),
+            ("Chunks".to_string(), self.manifest.chunk_count().to_string()),
+            ("Containers".to_string(), self.containers().count().to_string()),
+            ("Loose packages".to_string(), self.loose_packages().to_string()),
+        ];
+        if let Some(delta) = &self.delta {
+            summary.push((
+                format!("Patch from {}", delta.base_build),
+                format!(
+                    "{} changed, {} added, {} removed, {:.1} MB to download",
+                    delta.changed.len(),
+                    delta.added.len(),
+                    delta.removed.len(),
+                    delta.download_bytes as f64 / MB
+                ),
+            ));
+        }
+        summary
+    }
+
+    fn table(&self) -> Table {
+        let changed: HashMap<&str, &ChangedFile> =
+            self.delta.iter().flat_map(|d| d.changed.iter().map(|c| (c.path.as_str(), c))).collect();
+        let added: HashSet<&str> = self.delta.iter().flat_map(|d| d.added.iter().map(String::as_str)).collect();
+        let mut table = Table::new(&["path", "size_mb", "chunks", "sha1", "patch"]);
+        for file in self.containers() {
+            let patch = match changed.get(file.path.as_str()) {
+                Some(c) => format!("{:.0}% new chunks", c.churn() * 100.0),
+                None if added.contains(file.path.as_str()) => "added".to_string(),
+                None => String::new(),
+            };
+            table.push(vec![
+                file.path.clone(),
+                format!("{:.2}", file.size_bytes as f64 / MB),
+                file.chunk_count.to_string(),
+                file.sha1.clone(),
+                patch,
+            ]);
+        }
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        let loose = self.loose_packages();
+        if loose > 0 {
+            findings.push(Finding {
+                rule_id: "store-manifest/loose-packages".to_string(),
+                severity: Severity::Warning,
+                message: format!(
+                    "{} cooked packages ship as loose files; each is opened on its own at startup instead of read from a container",
+                    loose
+                ),
+                location: None,
+            });
+        }
+        for file in self.delta.iter().flat_map(|d| &d.changed) {
+            if file.chunk_count > 1 && file.churn() >= CHURN_RATIO && CONTAINER_EXTENSIONS.iter().any(|e| file.path.ends_with(e)) {
+                findings.push(Finding {
+                    rule_id: "store-manifest/container-churn".to_string(),
+                    severity: Severity::Note,
+                    message: format!(
+                        "{:.0}% of the container's chunks are new; unchanged content moved, so keep the pak order file and \
+                         compression settings stable between builds",
+                        file.churn() * 100.0
+                    ),
+                    location: Some(file.path.clone()),
+                });
+            }
+        }
+        findings
+    }
+}
+
+fn steam_file_mapping(data: &[u8], chunk_sizes: &mut BTreeMap<String, u64>) -> Result<Option<ManifestFile>> {
+    let mut mapping = Proto::new(data);
+    let mut file = ManifestFile { path: String::new(), size_bytes: 0, sha1: String::new(), chunks: Vec::new(), chunk_count: 0 };
+    let mut flags = 0;
+    while let Some((field, value)) = mapping.next()? {
+        match field {
+            1 => file.path = String::from_utf8_lossy(value.bytes()?).replace('\\', "/"),
+            2 => file.size_bytes = value.varint()?,
+            3 => flags = value.varint()?,
+            5 => file.sha1 = to_hex(value.bytes()?),
+            6 => {
+                let mut chunk = Proto::new(value.bytes()?);
+                let mut sha = String::new();
+                let mut compressed = 0;
+                while let Some((field, value)) = chunk.next()? {
+                    match field {
+                        1 => sha = to_hex(value.bytes()?),
+                        5 => compressed = value.varint()?,
+                        _ => {}
+                    }
+                }
+                chunk_sizes.insert(sha.clone(), compressed);
+                file.chunks.push(sha);
+            }
+            _ => {}
+        }
+    }
+    file.chunk_count = file.chunks.len();
+    Ok((flags & STEAM_FLAG_DIRECTORY == 0).then_some(file))
+}
+
+/// Text after the first `columns` whitespace-separated columns
+fn rest_after_columns(line: &str, columns: usize) -> Option<String> {
+    let mut rest = line.trim_start();
+    for _ in 0..columns {
+        let end = rest.find(char::is_whitespace)?;
+        rest = rest[end..].trim_start();
+    }
+    Some(rest.trim_end().to_string())
+}
+
+/// FGuid as the four hex words the JSON manifests use
+fn epic_guid(reader: &mut ByteReader) -> Result<String> {
+    Ok(format!("{:08X}{:08X}{:08X}{:08X}", reader.u32()?, reader.u32()?, reader.u32()?, reader.u32()?))
+}
+
+fn blob_bytes(blob: &str) -> Result<Vec<u8>> {
+    blob.as_bytes()
+        .chunks(3)
+        .map(|digits| {
+            std::str::from_utf8(digits)
+                .ok()
+                .and_then(|d| d.parse::<u8>().ok())
+                .ok_or_else(|| FastStartupError::AssetError(format!("Malformed manifest blob {}", blob)))
+        })
+        .collect()
+}
+
+/// Little-endian integer blob
+fn blob_u64(blob: &str) -> Result<u64> {
+    Ok(blob_bytes(blob)?.iter().take(8).rev().fold(0, |value, &byte| (value << 8) | byte as u64))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Field of a protobuf message
+enum ProtoValue<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+    Fixed,
+}
+
+impl<'a> ProtoValue<'a> {
+    fn varint(&self) -> Result<u64> {
+        match self {
+            ProtoValue::Varint(value) => Ok(*value),
+            _ => Err(FastStartupError::AssetError("Expected a varint manifest field".to_string())),
+        }
+    }
+
+    fn bytes(&self) -> Result<&'a [u8]> {
+        match self {
+            ProtoValue::Bytes(bytes) => Ok(bytes),
+            _ => Err(FastStartupError::AssetError("Expected a length-delimited manifest field".to_string())),
+        }
+    }
+}
+
+/// Minimal protobuf wire format reader over the depot manifest sections
+struct Proto<'a> {
+    reader: ByteReader<'a>,
+}
+
+impl<'a> Proto<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { reader: ByteReader::new(data) }
+    }
+
+    fn varint(&mut self) -> Result<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.reader.bytes(1)?[0];
+            value |= ((byte & 0x7F) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(FastStartupError::AssetError(format!("Varint too long at {}", self.reader.position())))
+    }
+
+    fn next(&mut self) -> Result<Option<(u64, ProtoValue<'a>)>> {
+        if self.reader.remaining() == 0 {
+            return Ok(None);
+        }
+        let key = self.varint()?;
+        let value = match key & 7 {
+            0 => ProtoValue::Varint(self.varint()?),
+            1 => {
+                self.reader.skip(8)?;
+                ProtoValue::Fixed
+            }
+            2 => {
+                let len = self.varint()? as usize;
+                ProtoValue::Bytes(self.reader.bytes(len)?)
+            }
+            5 => {
+                self.reader.skip(4)?;
+                ProtoValue::Fixed
+            }
+            wire => {
+                return Err(FastStartupError::AssetError(format!("Unsupported protobuf wire type {}", wire)));
+            }
+        };
+        Ok(Some((key >> 3, value)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn varint(out: &mut Vec<u8>, mut value: u64) {
+        while value >= 0x80 {
+            out.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        out.push(value as u8);
+    }
+
+    fn field(out: &mut Vec<u8>, number: u64, bytes: &[u8]) {
+        varint(out, number << 3 | 2);
+        varint(out, bytes.len() as u64);
+        out.extend_from_slice(bytes);
+    }
+
+    fn steam_manifest(manifest_id: u64, files: &[(&str, u8, &[u8])]) -> Vec<u8> {
+        let mut payload = Vec::new();
+        for (name, hash, chunks) in files {
+            let mut mapping = Vec::new();
+            field(&mut mapping, 1, name.as_bytes());
+            varint(&mut mapping, 2 << 3);
+            varint(&mut mapping, chunks.len() as u64 * 1024);
+            field(&mut mapping, 5, &[*hash; 20]);
+            for chunk in chunks.iter() {
+                let mut data = Vec::new();
+                field(&mut data, 1, &[*chunk; 20]);
+                varint(&mut data, 5 << 3);
+                varint(&mut data, 512);
+                field(&mut mapping, 6, &data);
+            }
+            field(&mut payload, 1, &mapping);
+        }
+        let mut metadata = Vec::new();
+        varint(&mut metadata, 1 << 3);
+        varint(&mut metadata, 2001);
+        varint(&mut metadata, 2 << 3);
+        varint(&mut metadata, manifest_id);
+
+        let mut out = Vec::new();
+        for (magic, section) in [(STEAM_PAYLOAD_MAGIC, payload), (STEAM_METADATA_MAGIC, metadata)] {
+            out.extend_from_slice(&magic.to_le_bytes());
+            out.extend_from_slice(&(section.len() as u32).to_le_bytes());
+            out.extend_from_slice(&section);
+        }
+        out.extend_from_slice(&STEAM_END_MAGIC.to_le_bytes());
+        out
+    }
+
+    fn fstring(out: &mut Vec<u8>, s: &str) {
+        out.extend_from_slice(&(s.len() as i32 + 1).to_le_bytes());
+        out.extend_from_slice(s.as_bytes());
+        out.push(0);
+    }
+
+    fn epic_manifest() -> Vec<u8> {
+        let guid = |n: u32| [n, 0, 0, n].iter().flat_map(|w| w.to_le_bytes()).collect::<Vec<u8>>();
+        let mut meta = vec![0, 0, 0, 0, 2];
+        meta.extend_from_slice(&[0; 9]);
+        fstring(&mut meta, "Shooter");
+        fstring(&mut meta, "1.2.0-CL-100");
+        let size = meta.len() as u32;
+        meta[..4].copy_from_slice(&size.to_le_bytes());
+
+        let mut chunks = vec![0, 0, 0, 0, 0];
+        chunks.extend_from_slice(&2i32.to_le_bytes());
+        chunks.extend(guid(1));
+        chunks.extend(guid(2));
+        chunks.extend_from_slice(&[0; 2 * (8 + 20 + 1 + 4)]);
+        chunks.extend_from_slice(&700i64.to_le_bytes());
+        chunks.extend_from_slice(&300i64.to_le_bytes());
+        let size = chunks.len() as u32;
+        chunks[..4].copy_from_slice(&size.to_le_bytes());
+
+        let mut files = vec![0, 0, 0, 0, 0];
+        files.extend_from_slice(&1i32.to_le_bytes());
+        fstring(&mut files, "Shooter/Content/Paks/pakchunk0-Windows.pak");
+        fstring(&mut files, "");
+        files.extend_from_slice(&[0xAB; 20]);
+        files.push(0);
+        files.extend_from_slice(&0i32.to_le_bytes());
+        files.extend_from_slice(&2i32.to_le_bytes());
+        for (n, size) in [(1u32, 1000u32), (2, 400)] {
+            files.extend_from_slice(&28u32.to_le_bytes());
+            files.extend(guid(n));
+            files.extend_from_slice(&0u32.to_le_bytes());
+            files.extend_from_slice(&size.to_le_bytes());
+        }
+
+        let body = [meta, chunks, files].concat();
+        let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&body).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut out = Vec::new();
+        for word in [EPIC_MANIFEST_MAGIC, 41, body.len() as u32, compressed.len() as u32] {
+            out.extend_from_slice(&word.to_le_bytes());
+        }
+        out.extend_from_slice(&[0; 20]);
+        out.push(EPIC_STORED_COMPRESSED);
+        out.extend_from_slice(&0i32.to_le_bytes());
+        out.extend_from_slice(&compressed);
+        out
+    }
+
+    #[test]
+    fn test_binary_manifests_and_delta() {
+        let base = StoreManifest::read_steam(&steam_manifest(
+            11,
+            &[("Shooter\\Content\\Paks\\pakchunk0-Windows.pak", 1, &[1, 2, 3, 4]), ("Shooter.exe", 2, &[5])],
+        ))
+        .unwrap();
+        assert_eq!((base.app.as_str(), base.build.as_str()), ("2001", "11"));
+        assert_eq!(base.files[0].path, "Shooter/Content/Paks/pakchunk0-Windows.pak");
+        assert_eq!(base.files[0].chunk_count, 4);
+        assert_eq!(base.download_bytes(), 5 * 512);
+
+        let patched = StoreManifest::read_steam(&steam_manifest(
+            12,
+            &[
+                ("Shooter\\Content\\Paks\\pakchunk0-Windows.pak", 3, &[1, 6, 7, 8]),
+                ("Shooter\\Content\\Movies\\Intro.uasset", 4, &[9]),
+            ],
+        ))
+        .unwrap();
+        let report = ManifestReport::new(patched, Some(&base));
+        let delta = report.delta.as_ref().unwrap();
+        assert_eq!(delta.added, ["Shooter/Content/Movies/Intro.uasset"]);
+        assert_eq!(delta.removed, ["Shooter.exe"]);
+        assert_eq!(delta.changed[0].new_chunks, 3);
+        assert_eq!(delta.download_bytes, 4 * 512);
+        let rules: Vec<String> = report.findings().into_iter().map(|f| f.rule_id).collect();
+        assert_eq!(rules, ["store-manifest/loose-packages", "store-manifest/container-churn"]);
+
+        let epic = StoreManifest::read_epic(&epic_manifest()).unwrap();
+        assert_eq!((epic.app.as_str(), epic.build.as_str()), ("Shooter", "1.2.0-CL-100"));
+        assert_eq!(epic.files[0].size_bytes, 1400);
+        assert_eq!(epic.files[0].sha1, "ab".repeat(20));
+        assert_eq!(epic.files[0].chunks, ["00000001000000000000000000000001", "00000002000000000000000000000002"]);
+        assert_eq!(epic.download_bytes(), 1000);
+    }
+
+    #[test]
+    fn test_text_manifests() {
+        let listing = "Content Manifest for Depot 2001\n\n\
+            Manifest ID / date     : 7791 / 01/02/2026 10:00:00\n\
+            Total number of files  : 2\n\
+            Total number of chunks : 5\n\n\
+            \x20         Size Chunks File SHA                                 Flags Name\n\
+            \x20      2048      2 aabbccddeeff00112233445566778899aabbccdd     0 Shooter/Content/Paks/My Game.pak\n\
+            \x20         0      0 0000000000000000000000000000000000000000    64 Shooter/Content\n";
+        let steam = StoreManifest::read_steam_listing(listing).unwrap();
+        assert_eq!((steam.app.as_str(), steam.build.as_str()), ("2001", "7791"));
+        assert_eq!(steam.files.len(), 1);
+        assert_eq!(steam.files[0].path, "Shooter/Content/Paks/My Game.pak");
+        assert_eq!((steam.files[0].size_bytes, steam.chunk_count()), (2048, 5));
+
+        let json = r#"{"AppNameString":"Shooter","BuildVersionString":"1.0","FileManifestList":[
+            {"Filename":"Shooter.exe","FileHash":"001002003004005006007008009010011012013014015016017018019020",
+             "FileChunkParts":[{"Guid":"0000000a0000000b0000000c0000000d","Offset":"000000000000","Size":"000001000000"}]}],
+            "ChunkFilesizeList":{"0000000A0000000B0000000C0000000D":"200000000000000000"}}"#;
+        let epic = StoreManifest::read_epic_json(json).unwrap();
+        assert_eq!(epic.files[0].size_bytes, 256);
+        assert_eq!(epic.files[0].sha1, "0102030405060708090a0b0c0d0e0f1011121314");
+        assert_eq!(epic.files[0].chunks, ["0000000A0000000B0000000C0000000D"]);
+        assert_eq!(epic.download_bytes(), 200);
+    }
+}
@@ -0,0 +1,294 @@
+//! Strategy Comparison Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! A/B comparison of cache strategies. Every strategy, a load order
+//! arrangement plus a hash algorithm, gets a real cache built, saved,
+//! reloaded and verified against the project, and its startup reads
+//! replayed by the load simulation; the results sit side by side as deltas
+//! to the first strategy.
+
+use crate::cache::{CacheBuilder, StartupCache};
+use crate::config::ProjectConfig;
+use crate::graph::DependencyGraph;
+use crate::hash::HashAlgorithm;
+use crate::load_sim::{IoModel, LoadSimulation, OrderStrategy};
+use crate::report::{Finding, ReportData, Severity, Table};
+use crate::scanner::AssetInfo;
+use crate::{FastStartupError, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use tracing::info;
+
+/// Simulated differences below this are noise
+pub const MIN_DELTA_MS: f64 = 1.0;
+
+/// `<order>[:<hash>]`, e.g. `computed`, `size:blake3`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StrategySpec {
+    pub order: OrderStrategy,
+    pub hash: HashAlgorithm,
+}
+
+impl StrategySpec {
+    /// File name safe label, e.g. `size-blake3`
+    pub fn slug(&self) -> String {
+        format!("{}-{}", self.order, self.hash)
+    }
+}
+
+impl std::fmt::Display for StrategySpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.order, self.hash)
+    }
+}
+
+impl std::str::FromStr for StrategySpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (order, hash) = s.split_once(':').unwrap_or((s, HashAlgorithm::default().as_str()));
+        let order: OrderStrategy = order.parse()?;
+        if order == OrderStrategy::File {
+            return Err("the file strategy needs an order file; compare it with `simulate --order`".to_string());
+        }
+        let hash = hash.parse::<HashAlgorithm>().map_err(|e| e.to_string())?;
+        Ok(Self { order, hash })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyResult {
+    /// `<order>:<hash>`
+    pub strategy: String,
+    pub order: OrderStrategy,
+    pub hash_algorithm: String,
+    /// Hashing and load order arrangement
+    pub build_ms: f64,
+    pub cache_bytes: u64,
+    /// Reading the saved cache back
+    pub load_ms: f64,
+    /// Verifying the cache against the project, as a launch does
+    pub verify_ms: f64,
+    /// Startup reads replayed against the I/O model
+    pub simulated_ms: f64,
+    pub seeks: usize,
+    /// Where the cache was kept, with `--output-dir`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_path: Option<PathBuf>,
+}
+
+impl StrategyResult {
+    /// Measured and simulated startup cost
+    pub fn startup_ms(&self) -> f64 {
+        self.load_ms + self.verify_ms + self.simulated_ms
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyComparison {
+    pub project_name: String,
+    pub io: IoModel,
+    pub startup_packages: usize,
+    /// In the order asked for; the first is the baseline
+    pub results: Vec<StrategyResult>,
+}
+
+impl StrategyComparison {
+    /// Build, reload and verify a cache per strategy from one scan and
+    /// graph, made with the project's `config`. Caches are kept in
+    /// `output_dir` when given, else removed. Timings after the first build
+    /// run with a warm page cache.
+    #[allow(clippy::too_many_arguments)]
+    pub fn run(
+        project_root: &Path,
+        config: &ProjectConfig,
+        assets: &[AssetInfo],
+        graph: &DependencyGraph,
+        startup_order: &[String],
+        specs: &[StrategySpec],
+        io: IoModel,
+        output_dir: Option<&Path>,
+    ) -> Result<Self> {
+        let project_name = project_root.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        if let Some(dir) = output_dir {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let mut results = Vec::with_capacity(specs.len());
+        let mut startup_packages = 0;
+        for spec in specs {
+            info!("Strategy {}...", spec);
+            let builder = CacheBuilder::new(project_root)?
+                .with_config(config)
+                .with_hash_algorithm(spec.hash)
+                .with_load_order_strategy(spec.order);
+            let started = Instant::now();
+            let (mut cache, _) = builder.build_from(assets, graph)?;
+            let build_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+            let path = match output_dir {
+                Some(dir) => dir.join(format!("{}-{}.uefast", project_name, spec.slug())),
+                None => std::env::temp_dir().join(format!("uefast-{}-{}.uefast", std::process::id(), spec.slug())),
+            };
+            cache.save(&path)?;
+            let cache_bytes = std::fs::metadata(&path)?.len();
+
+            let started = Instant::now();
+            let loaded = StartupCache::load(&path)?;
+            let load_ms = started.elapsed().as_secs_f64() * 1000.0;
+            let started = Instant::now();
+            let verified = loaded.verify(project_root, config)?;
+            let verify_ms = started.elapsed().as_secs_f64() * 1000.0;
+            if !verified.is_valid {
+                return Err(FastStartupError::CacheError(format!(
+                    "The {} cache does not verify ({} changed, {} missing); is the project being edited?",
+                    spec,
+                    verified.changed_assets.len(),
+                    verified.missing_assets.len()
+                )));
+            }
+            if output_dir.is_none() {
+                let _ = std::fs::remove_file(&path);
+            }
+
+            let simulation = LoadSimulation::run(&project_name, startup_order, assets, io, &[spec.order], &[]);
+            startup_packages = simulation.packages;
+            let simulated = simulation.fastest();
+            results.push(StrategyResult {
+                strategy: spec.to_string(),
+                order: spec.order,
+                hash_algorithm: spec.hash.as_str().to_string(),
+                build_ms,
+                cache_bytes,
+                load_ms,
+                verify_ms,
+                simulated_ms: simulated.map(|o| o.total_ms).unwrap_or(0.0),
+                seeks: simulated.map(|o| o.seeks).unwrap_or(0),
+                cache_path: output_dir.map(|_| path),
+            });
+        }
+
+        Ok(Self { project_name, io, startup_packages, results })
+    }
+
+    pub fn baseline(&self) -> Option<&StrategyResult> {
+        self.results.first()
+    }
+
+    /// Lowest measured plus simulated startup cost
+    pub fn best(&self) -> Option<&StrategyResult> {
+        self.results.iter().min_by(|a, b| a.startup_ms().total_cmp(&b.startup_ms()))
+    }
+}
+
+impl ReportData for StrategyComparison {
+    fn title(&self) -> String {
+        format!("Strategy comparison: {}", self.project_name)
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        serde_json::to_value(self).map_err(|e| FastStartupError::SerializationError(e.to_string()))
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        vec![
+            ("Strategies".to_string(), self.results.len().to_string()),
+            ("Startup packages".to_string(), self.startup_packages.to_string()),
+            ("I/O model".to_string(), self.io.describe()),
+            ("Baseline".to_string(), self.baseline().map(|r| r.strategy.clone()).unwrap_or_default()),
+            (
+                "Best".to_string(),
+                self.best().map(|r| format!("{} (~{:.0} ms)", r.strategy, r.startup_ms())).unwrap_or_default(),
+            ),
+        ]
+    }
+
+    fn table(&self) -> Table {
+        let mut table = Table::new(&[
+            "strategy",
+            "build_ms",
+            "cache_kb",
+            "load_ms",
+            "verify_ms",
+            "simulated_ms",
+            "seeks",
+            "startup_ms",
+            "delta_ms",
+        ]);
+        let baseline = self.baseline().map(|r| r.startup_ms()).unwrap_or(0.0);
+        for result in &self.results {
+            table.push(vec![
+                result.strategy.clone(),
+                format!("{:.1}", result.build_ms),
+                (result.cache_bytes / 1024).to_string(),
+                format!("{:.1}", result.load_ms),
+                format!("{:.1}", result.verify_ms),
+                format!("{:.1}", result.simulated_ms),
+                result.seeks.to_string(),
+                format!("{:.1}", result.startup_ms()),
+                format!("{:+.1}", result.startup_ms() - baseline),
+            ]);
+        }
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        let (Some(baseline), Some(best)) = (self.baseline(), self.best()) else {
+            return Vec::new();
+        };
+        let saved = baseline.startup_ms() - best.startup_ms();
+        if saved < MIN_DELTA_MS {
+            return Vec::new();
+        }
+        vec![Finding {
+            rule_id: "compare-strategies/faster".to_string(),
+            severity: Severity::Note,
+            message: format!(
+                "{} starts ~{:.0} ms faster than {}; set load_order_strategy = \"{}\" and build caches with --hash-algorithm {}",
+                best.strategy, saved, baseline.strategy, best.order, best.hash_algorithm
+            ),
+            location: None,
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strategy_spec() {
+        let spec: StrategySpec = "size:blake3".parse().unwrap();
+        assert_eq!(spec, StrategySpec { order: OrderStrategy::Size, hash: HashAlgorithm::Blake3 });
+        assert_eq!(spec.slug(), "size-blake3");
+        let spec: StrategySpec = "computed".parse().unwrap();
+        assert_eq!(spec.to_string(), "computed:xxh3");
+        assert!("file".parse::<StrategySpec>().is_err());
+        assert!("size:md5".parse::<StrategySpec>().is_err());
+
+        let result = |strategy: &str, simulated_ms: f64| StrategyResult {
+            strategy: strategy.to_string(),
+            order: strategy.split(':').next().unwrap().parse().unwrap(),
+            hash_algorithm: "xxh3".to_string(),
+            build_ms: 10.0,
+            cache_bytes: 2048,
+            load_ms: 1.0,
+            verify_ms: 5.0,
+            simulated_ms,
+            seeks: 0,
+            cache_path: None,
+        };
+        let comparison = StrategyComparison {
+            project_name: "Game".to_string(),
+            io: IoModel::for_tier(crate::estimator::StorageTier::Hdd),
+            startup_packages: 3,
+            results: vec![result("alphabetical:xxh3", 50.0), result("computed:xxh3", 20.0)],
+        };
+        assert_eq!(comparison.best().unwrap().order, OrderStrategy::Computed);
+        assert_eq!(comparison.table().rows[1].last().unwrap(), "-30.0");
+        assert!(comparison.findings()[0].message.contains("load_order_strategy = \"computed\""));
+    }
+}
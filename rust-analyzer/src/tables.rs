@@ -0,0 +1,138 @@
+//! Data Table Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Row struct and row count inspection of DataTable and CurveTable assets
+
+use crate::properties::PropertyReader;
+use crate::scanner::{AssetInfo, AssetType};
+use crate::uasset::{resolve_import, ImportEntry, Package};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Export classes whose native data starts with a row count
+const TABLE_CLASSES: &[&str] = &[
+    "DataTable",
+    "CompositeDataTable",
+    "CurveTable",
+    "CompositeCurveTable",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataTableInfo {
+    pub relative_path: String,
+    pub table_class: String,
+    /// Row struct of DataTables, when the property could be read
+    pub row_struct: Option<String>,
+    /// `None` for unversioned (cooked) or unreadable tables
+    pub row_count: Option<usize>,
+    pub size_bytes: u64,
+}
+
+/// Inspect every DataTable/CurveTable among `assets`, largest first
+pub fn inspect_tables(assets: &[AssetInfo]) -> Vec<DataTableInfo> {
+    let mut tables: Vec<DataTableInfo> = assets
+        .par_iter()
+        .filter(|a| a.asset_type == AssetType::UAsset)
+        .filter_map(inspect_table)
+        .collect();
+
+    tables.sort_by(|a, b| {
+        b.size_bytes.cmp(&a.size_bytes).then_with(|| a.relative_path.cmp(&b.relative_path))
+    });
+    tables
+}
+
+/// `None` when the asset is not a table or its export map cannot be read
+pub fn inspect_table(asset: &AssetInfo) -> Option<DataTableInfo> {
+    let package = Package::open(&asset.path).ok()?;
+    let imports = package.imports().ok()?;
+    let exports = package.exports().ok()?;
+
+    let (export, table_class) = exports.iter().find_map(|export| {
+        let class = import_name(&imports, export.class_index)?;
+        TABLE_CLASSES.contains(&class).then(|| (export, class.to_string()))
+    })?;
+
+    let (row_struct, row_count) = package
+        .export_data(export)
+        .and_then(|serial| parse_table_data(serial, &package.names, &imports, package.header.file_version_ue5))
+        .unwrap_or((None, None));
+
+    Some(DataTableInfo {
+        relative_path: asset.relative_path.clone(),
+        table_class,
+        row_struct,
+        row_count,
+        size_bytes: asset.size_bytes,
+    })
+}
+
+/// Read the tagged properties (for `RowStruct`) and the row count that
+/// UDataTable/UCurveTable serialize right after them
+pub fn parse_table_data(
+    data: &[u8],
+    names: &[String],
+    imports: &[ImportEntry],
+    ue5_version: i32,
+) -> Option<(Option<String>, Option<usize>)> {
+    let mut reader = PropertyReader::new(data, names, ue5_version);
+    let tags = reader.read_tags()?;
+
+    let row_struct = tags
+        .iter()
+        .find(|t| t.name == "RowStruct" && t.type_name == "ObjectProperty")
+        .and_then(|t| reader.object_index(t))
+        .and_then(|index| import_name(imports, index))
+        .map(str::to_string);
+
+    // UObject guid (UBOOL + FGuid), then the row count
+    let row_count = (|| {
+        if reader.read_i32()? != 0 {
+            reader.skip(16)?;
+        }
+        let rows = usize::try_from(reader.read_i32()?).ok()?;
+        // Every row needs at least its FName
+        (rows * 8 <= data.len()).then_some(rows)
+    })();
+
+    Some((row_struct, row_count))
+}
+
+fn import_name(imports: &[ImportEntry], index: i32) -> Option<&str> {
+    resolve_import(imports, index).map(|i| i.object_name.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_table_data() {
+        let names: Vec<String> = ["None", "RowStruct", "ObjectProperty"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let imports = vec![ImportEntry {
+            class_package: "/Script/CoreUObject".to_string(),
+            class_name: "ScriptStruct".to_string(),
+            outer_index: 0,
+            object_name: "WeaponRow".to_string(),
+        }];
+
+        let mut data = Vec::new();
+        for value in [1i32, 0, 2, 0, 4, 0] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.push(0); // HasPropertyGuid
+        data.extend_from_slice(&(-1i32).to_le_bytes()); // RowStruct -> import 0
+        for value in [0i32, 0, 0, 3] {
+            data.extend_from_slice(&value.to_le_bytes()); // None, no guid, rows
+        }
+        data.extend_from_slice(&[0u8; 24]);
+
+        let (row_struct, rows) = parse_table_data(&data, &names, &imports, 1009).unwrap();
+        assert_eq!(row_struct.as_deref(), Some("WeaponRow"));
+        assert_eq!(rows, Some(3));
+    }
+}
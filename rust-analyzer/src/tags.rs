@@ -0,0 +1,199 @@
+//! Asset Tags Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Gameplay feature tags (e.g. "Chapter1", "Multiplayer") imported from UE
+//! collections or an editor-exported CSV and kept in `.uefast/tags.json`,
+//! so reports and budgets can slice assets by feature instead of folder
+
+use crate::scanner::package_name_from_relative;
+use crate::{FastStartupError, Result, STATE_DIR};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+pub const TAGS_FILE: &str = "tags.json";
+
+/// Shared and local collection folders, relative to the project root
+const COLLECTION_DIRS: &[&str] = &["Content/Collections", "Saved/Collections"];
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AssetTags {
+    /// Package name (`/Game/...`) -> tags
+    packages: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl AssetTags {
+    pub fn path(project_root: &Path) -> PathBuf {
+        project_root.join(STATE_DIR).join(TAGS_FILE)
+    }
+
+    /// Tags of `project_root`, empty if none were imported
+    pub fn load(project_root: &Path) -> Result<Self> {
+        let path = Self::path(project_root);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let data = std::fs::read_to_string(&path)?;
+        serde_json::from_str(&data).map_err(|e| {
+            FastStartupError::SerializationError(format!("{}: {}", path.display(), e))
+        })
+    }
+
+    pub fn save(&self, project_root: &Path) -> Result<PathBuf> {
+        let path = Self::path(project_root);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let data = serde_json::to_string_pretty(self)
+            .map_err(|e| FastStartupError::SerializationError(e.to_string()))?;
+        std::fs::write(&path, data)?;
+        Ok(path)
+    }
+
+    /// Tag `package`, given as a package or object path (`/Game/A/B.B`)
+    pub fn insert(&mut self, package: &str, tag: &str) {
+        let package = package.split('.').next().unwrap_or(package).trim();
+        let tag = tag.trim();
+        if package.starts_with('/') && !tag.is_empty() {
+            self.packages
+                .entry(package.to_string())
+                .or_default()
+                .insert(tag.to_string());
+        }
+    }
+
+    pub fn merge(&mut self, other: AssetTags) {
+        for (package, tags) in other.packages {
+            self.packages.entry(package).or_default().extend(tags);
+        }
+    }
+
+    /// Tags of the asset at `relative_path` (e.g. `Content/A/B.uasset`)
+    pub fn tags_of(&self, relative_path: &str) -> impl Iterator<Item = &str> {
+        package_name_from_relative(relative_path)
+            .and_then(|package| self.packages.get(&package))
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+    }
+
+    /// Every distinct tag
+    pub fn tag_names(&self) -> BTreeSet<&str> {
+        self.packages.values().flatten().map(String::as_str).collect()
+    }
+
+    /// Number of tagged packages
+    pub fn len(&self) -> usize {
+        self.packages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.packages.is_empty()
+    }
+}
+
+/// Tags from every `.collection` file of the project, one tag per
+/// collection named after the file
+pub fn load_collections(project_root: &Path) -> Result<AssetTags> {
+    let mut tags = AssetTags::default();
+    for dir in COLLECTION_DIRS {
+        let dir = project_root.join(dir);
+        if !dir.is_dir() {
+            continue;
+        }
+        for entry in WalkDir::new(&dir).into_iter().filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("collection") {
+                continue;
+            }
+            let Some(name) = path.file_stem().map(|s| s.to_string_lossy()) else {
+                continue;
+            };
+            let text = std::fs::read_to_string(path)?;
+            for package in parse_collection(&text) {
+                tags.insert(package, &name);
+            }
+        }
+    }
+    Ok(tags)
+}
+
+/// Object paths of a static collection. The `Key:Value` header ends at the
+/// first blank line; dynamic collections have no body.
+pub fn parse_collection(text: &str) -> Vec<&str> {
+    text.lines()
+        .skip_while(|line| !line.trim().is_empty())
+        .map(str::trim)
+        .filter(|line| line.starts_with('/'))
+        .collect()
+}
+
+/// Tags from a CSV with a package column and a tag column. A tag cell may
+/// hold several tags separated by `;` or `|`.
+pub fn parse_tag_csv(text: &str) -> Result<AssetTags> {
+    let mut lines = text.lines().filter(|l| !l.trim().is_empty());
+    let header: Vec<String> = lines
+        .next()
+        .unwrap_or_default()
+        .split(',')
+        .map(|h| h.trim().trim_matches('"').to_ascii_lowercase())
+        .collect();
+
+    let package_col = header.iter().position(|h| {
+        matches!(h.as_str(), "package" | "packagename" | "package name" | "objectpath" | "object path" | "asset" | "path")
+    });
+    let tag_col = header
+        .iter()
+        .position(|h| matches!(h.as_str(), "tag" | "tags" | "collection" | "feature"));
+
+    let (Some(package_col), Some(tag_col)) = (package_col, tag_col) else {
+        return Err(FastStartupError::InvalidArgument(
+            "Tag CSV needs a package/path column and a tag column".to_string(),
+        ));
+    };
+
+    let mut tags = AssetTags::default();
+    for line in lines {
+        let cols: Vec<&str> = line.split(',').map(|c| c.trim().trim_matches('"')).collect();
+        let (Some(package), Some(cell)) = (cols.get(package_col), cols.get(tag_col)) else {
+            continue;
+        };
+        for tag in cell.split([';', '|']) {
+            tags.insert(package, tag);
+        }
+    }
+    Ok(tags)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_collection() {
+        let text = "FileVersion:2\nType:Static\nGuid:0123\nParentGuid:0000\n\n\
+                    /Game/Maps/Chapter1.Chapter1\n/Game/Hero/SK_Hero.SK_Hero\n";
+        assert_eq!(
+            parse_collection(text),
+            ["/Game/Maps/Chapter1.Chapter1", "/Game/Hero/SK_Hero.SK_Hero"]
+        );
+        assert!(parse_collection("FileVersion:2\nType:Dynamic\n").is_empty());
+    }
+
+    #[test]
+    fn test_parse_tag_csv() {
+        let text = "ObjectPath,Tags\n\
+                    /Game/Hero/SK_Hero.SK_Hero,Chapter1;Multiplayer\n\
+                    /Game/UI/WBP_Lobby.WBP_Lobby,Multiplayer\n\
+                    not-a-package,Chapter1\n";
+        let tags = parse_tag_csv(text).unwrap();
+
+        assert_eq!(tags.len(), 2);
+        let hero: Vec<&str> = tags.tags_of("Content/Hero/SK_Hero.uasset").collect();
+        assert_eq!(hero, ["Chapter1", "Multiplayer"]);
+        assert_eq!(tags.tag_names().len(), 2);
+        assert!(parse_tag_csv("Name,Size\n").is_err());
+    }
+}
@@ -0,0 +1,237 @@
+//! Telemetry Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Per-phase throughput and CPU utilization of scan and hash workers, to tell
+//! I/O-bound from CPU-bound runs
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+use tracing::info;
+
+/// Tracing target of metric events, for subscribers that collect them
+pub const METRICS_TARGET: &str = "uefast::metrics";
+
+/// Below this CPU utilization a phase is considered I/O-bound
+const IO_BOUND_UTILIZATION: f64 = 0.6;
+
+const MB: f64 = 1024.0 * 1024.0;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhaseMetrics {
+    pub phase: String,
+    pub files: usize,
+    pub bytes: u64,
+    pub elapsed_secs: f64,
+    /// Process CPU time (user + system) spent during the phase, when available
+    pub cpu_secs: Option<f64>,
+    pub threads: usize,
+    /// Average number of operations in flight when one started
+    pub avg_queue_depth: f64,
+}
+
+impl PhaseMetrics {
+    pub fn mb_per_sec(&self) -> f64 {
+        rate(self.bytes as f64 / MB, self.elapsed_secs)
+    }
+
+    pub fn files_per_sec(&self) -> f64 {
+        rate(self.files as f64, self.elapsed_secs)
+    }
+
+    /// CPU time over the wall time of all worker threads (0..1)
+    pub fn cpu_utilization(&self) -> Option<f64> {
+        let cpu = self.cpu_secs?;
+        let available = self.elapsed_secs * self.threads.max(1) as f64;
+        Some(if available > 0.0 { (cpu / available).min(1.0) } else { 0.0 })
+    }
+
+    /// "cpu" or "io", `None` when CPU time is unavailable
+    pub fn bound(&self) -> Option<&'static str> {
+        let utilization = self.cpu_utilization()?;
+        Some(if utilization < IO_BOUND_UTILIZATION { "io" } else { "cpu" })
+    }
+
+    /// Log the metrics and emit them as a structured event
+    pub fn emit(&self) {
+        let utilization = self.cpu_utilization().map(|u| u * 100.0);
+        info!(
+            target: METRICS_TARGET,
+            phase = %self.phase,
+            files = self.files,
+            bytes = self.bytes,
+            elapsed_secs = self.elapsed_secs,
+            mb_per_sec = self.mb_per_sec(),
+            files_per_sec = self.files_per_sec(),
+            avg_queue_depth = self.avg_queue_depth,
+            cpu_utilization = utilization,
+            bound = self.bound(),
+            "{}: {:.1} MB/s, {:.0} files/s, queue depth {:.1}, CPU {}",
+            self.phase,
+            self.mb_per_sec(),
+            self.files_per_sec(),
+            self.avg_queue_depth,
+            utilization
+                .map(|u| format!("{:.0}% ({}-bound)", u, self.bound().unwrap_or("?")))
+                .unwrap_or_else(|| "n/a".to_string()),
+        );
+    }
+}
+
+fn rate(amount: f64, secs: f64) -> f64 {
+    if secs > 0.0 {
+        amount / secs
+    } else {
+        0.0
+    }
+}
+
+/// Measures one phase; worker closures run through `track`
+pub struct PhaseTimer {
+    phase: String,
+    started: Instant,
+    cpu_started: Option<Duration>,
+    files: AtomicUsize,
+    bytes: AtomicU64,
+    in_flight: AtomicUsize,
+    depth_samples: AtomicU64,
+}
+
+impl PhaseTimer {
+    pub fn start(phase: &str) -> Self {
+        Self {
+            phase: phase.to_string(),
+            started: Instant::now(),
+            cpu_started: process_cpu_time(),
+            files: AtomicUsize::new(0),
+            bytes: AtomicU64::new(0),
+            in_flight: AtomicUsize::new(0),
+            depth_samples: AtomicU64::new(0),
+        }
+    }
+
+    /// Run one unit of work over a file of `bytes`
+    pub fn track<T>(&self, bytes: u64, work: impl FnOnce() -> T) -> T {
+        let depth = self.in_flight.fetch_add(1, Ordering::Relaxed) + 1;
+        self.depth_samples.fetch_add(depth as u64, Ordering::Relaxed);
+        let result = work();
+        self.in_flight.fetch_sub(1, Ordering::Relaxed);
+        self.files.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(bytes, Ordering::Relaxed);
+        result
+    }
+
+    /// Count work done outside `track`
+    pub fn record(&self, files: usize, bytes: u64) {
+        self.files.fetch_add(files, Ordering::Relaxed);
+        self.bytes.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    pub fn finish(self) -> PhaseMetrics {
+        let files = self.files.into_inner();
+        let samples = self.depth_samples.into_inner();
+        let cpu_secs = match (self.cpu_started, process_cpu_time()) {
+            (Some(start), Some(end)) => Some(end.saturating_sub(start).as_secs_f64()),
+            _ => None,
+        };
+        PhaseMetrics {
+            phase: self.phase,
+            files,
+            bytes: self.bytes.into_inner(),
+            elapsed_secs: self.started.elapsed().as_secs_f64(),
+            cpu_secs,
+            threads: rayon::current_num_threads(),
+            avg_queue_depth: if files > 0 { samples as f64 / files as f64 } else { 0.0 },
+        }
+    }
+}
+
+/// Scan and hash timings of one `bench` iteration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchIteration {
+    pub iteration: u32,
+    pub assets: usize,
+    pub scan_secs: f64,
+    pub hash_secs: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub project_name: String,
+    /// Files hashed per iteration
+    pub hashed_files: usize,
+    pub direct_io: bool,
+    pub cold: bool,
+    pub iterations: Vec<BenchIteration>,
+}
+
+impl BenchReport {
+    pub fn average_scan_secs(&self) -> f64 {
+        average(self.iterations.iter().map(|i| i.scan_secs))
+    }
+
+    pub fn average_hash_secs(&self) -> f64 {
+        average(self.iterations.iter().map(|i| i.hash_secs))
+    }
+}
+
+fn average(values: impl ExactSizeIterator<Item = f64>) -> f64 {
+    let count = values.len();
+    if count == 0 {
+        0.0
+    } else {
+        values.sum::<f64>() / count as f64
+    }
+}
+
+/// User plus system CPU time of this process
+#[cfg(unix)]
+fn process_cpu_time() -> Option<Duration> {
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    if unsafe { libc::getrusage(libc::RUSAGE_SELF, &mut usage) } != 0 {
+        return None;
+    }
+    let to_duration = |tv: libc::timeval| {
+        Duration::from_secs(tv.tv_sec as u64) + Duration::from_micros(tv.tv_usec as u64)
+    };
+    Some(to_duration(usage.ru_utime) + to_duration(usage.ru_stime))
+}
+
+#[cfg(not(unix))]
+fn process_cpu_time() -> Option<Duration> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_phase_metrics() {
+        let metrics = PhaseMetrics {
+            phase: "hash".to_string(),
+            files: 200,
+            bytes: 400 * 1024 * 1024,
+            elapsed_secs: 2.0,
+            cpu_secs: Some(2.0),
+            threads: 4,
+            avg_queue_depth: 3.5,
+        };
+        assert_eq!(metrics.mb_per_sec(), 200.0);
+        assert_eq!(metrics.files_per_sec(), 100.0);
+        assert_eq!(metrics.cpu_utilization(), Some(0.25));
+        assert_eq!(metrics.bound(), Some("io"));
+    }
+
+    #[test]
+    fn test_phase_timer_track() {
+        let timer = PhaseTimer::start("hash");
+        let value = timer.track(1024, || 7);
+        assert_eq!(value, 7);
+
+        let metrics = timer.finish();
+        assert_eq!((metrics.files, metrics.bytes), (1, 1024));
+        assert_eq!(metrics.avg_queue_depth, 1.0);
+    }
+}
@@ -0,0 +1,164 @@
+//! Terminal Output Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Status glyphs and log colors for console output, with ASCII
+//! fallbacks for consoles and CI logs that mangle Unicode or ANSI escapes
+
+use crate::FastStartupError;
+use std::io::IsTerminal;
+use std::sync::OnceLock;
+
+/// Forces ASCII output when set to anything but `0`
+pub const ASCII_ENV: &str = "UEFAST_ASCII";
+
+static STYLE: OnceLock<OutputStyle> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorMode {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl std::str::FromStr for ColorMode {
+    type Err = FastStartupError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "auto" => Ok(ColorMode::Auto),
+            "always" => Ok(ColorMode::Always),
+            "never" => Ok(ColorMode::Never),
+            other => Err(FastStartupError::InvalidArgument(format!(
+                "Unknown color mode '{}' (expected auto, always or never)",
+                other
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Glyph {
+    Ok,
+    Fail,
+    Warn,
+    Turbo,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OutputStyle {
+    pub ascii: bool,
+    pub color: bool,
+}
+
+impl OutputStyle {
+    /// Style for the current process: `--ascii` or the environment decide
+    /// glyphs, `color` and the environment decide ANSI colors
+    pub fn detect(ascii: bool, color: ColorMode) -> Self {
+        let env = |key: &str| std::env::var(key).ok();
+        Self {
+            ascii: ascii || Self::ascii_from_env(env),
+            color: match color {
+                ColorMode::Always => true,
+                ColorMode::Never => false,
+                ColorMode::Auto => {
+                    std::io::stderr().is_terminal() && std::io::stdout().is_terminal() && Self::color_from_env(env)
+                }
+            },
+        }
+    }
+
+    /// Unicode is unsafe on dumb terminals, non-UTF-8 locales and legacy
+    /// Windows consoles (no Windows Terminal or VS Code host)
+    fn ascii_from_env(env: impl Fn(&str) -> Option<String>) -> bool {
+        if let Some(value) = env(ASCII_ENV) {
+            return value != "0";
+        }
+        if env("TERM").as_deref() == Some("dumb") {
+            return true;
+        }
+        if cfg!(windows) {
+            return env("WT_SESSION").is_none() && env("TERM_PROGRAM").is_none();
+        }
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"].into_iter().find_map(|key| env(key).filter(|v| !v.is_empty()));
+        match locale {
+            Some(locale) => {
+                let locale = locale.to_ascii_lowercase();
+                !(locale.contains("utf-8") || locale.contains("utf8"))
+            }
+            None => false,
+        }
+    }
+
+    /// https://no-color.org and dumb terminals disable colors
+    fn color_from_env(env: impl Fn(&str) -> Option<String>) -> bool {
+        env("NO_COLOR").is_none_or(|v| v.is_empty()) && env("TERM").as_deref() != Some("dumb")
+    }
+
+    pub fn glyph(&self, glyph: Glyph) -> &'static str {
+        match (glyph, self.ascii) {
+            (Glyph::Ok, false) => "✓",
+            (Glyph::Ok, true) => "[ok]",
+            (Glyph::Fail, false) => "✗",
+            (Glyph::Fail, true) => "[x]",
+            (Glyph::Warn, false) => "⚠",
+            (Glyph::Warn, true) => "[!]",
+            (Glyph::Turbo, false) => "⚡",
+            (Glyph::Turbo, true) => ">>",
+        }
+    }
+
+    /// Spinner frames for progress bars
+    pub fn spinner(&self) -> &'static str {
+        if self.ascii {
+            "-\\|/ "
+        } else {
+            "⠁⠂⠄⡀⢀⠠⠐⠈ "
+        }
+    }
+}
+
+/// Set the process-wide style; later calls are ignored
+pub fn init(style: OutputStyle) -> OutputStyle {
+    *STYLE.get_or_init(|| style)
+}
+
+/// Process-wide style, plain Unicode without colors until `init`
+pub fn style() -> OutputStyle {
+    STYLE.get().copied().unwrap_or_default()
+}
+
+pub fn glyph(glyph: Glyph) -> &'static str {
+    style().glyph(glyph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |key| vars.iter().find(|(k, _)| *k == key).map(|(_, v)| v.to_string())
+    }
+
+    #[test]
+    fn test_ascii_detection() {
+        assert!(OutputStyle::ascii_from_env(env(&[(ASCII_ENV, "1")])));
+        assert!(!OutputStyle::ascii_from_env(env(&[(ASCII_ENV, "0"), ("TERM", "dumb")])));
+        assert!(OutputStyle::ascii_from_env(env(&[("TERM", "dumb")])));
+        if !cfg!(windows) {
+            assert!(OutputStyle::ascii_from_env(env(&[("LANG", "C")])));
+            assert!(!OutputStyle::ascii_from_env(env(&[("LC_ALL", ""), ("LANG", "en_US.UTF-8")])));
+        }
+    }
+
+    #[test]
+    fn test_glyphs() {
+        let ascii = OutputStyle { ascii: true, color: false };
+        assert_eq!(ascii.glyph(Glyph::Fail), "[x]");
+        assert!(ascii.spinner().is_ascii());
+
+        assert_eq!(OutputStyle::default().glyph(Glyph::Ok), "✓");
+        assert!(!OutputStyle::color_from_env(env(&[("NO_COLOR", "1")])));
+    }
+}
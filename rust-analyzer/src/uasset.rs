@@ -4,14 +4,87 @@
 //!
 //! Minimal UAsset parsing for dependency extraction
 
+use crate::byte_reader::ByteReader;
+use crate::mmap_pool::{MappedFile, MmapPool};
 use crate::{FastStartupError, Result};
-use memmap2::Mmap;
-use std::fs::File;
-use std::io::{Read, Seek, SeekFrom};
+use serde::{Deserialize, Serialize};
 use std::path::Path;
+use xxhash_rust::xxh3::xxh3_64;
 
 const UASSET_MAGIC: u32 = 0x9E2A83C1;
 
+/// Package saved without editor-only data (cooked)
+const PKG_FILTER_EDITOR_ONLY: u32 = 0x8000_0000;
+
+/// UE4 object versions that change the import/export entry layout
+const VER_UE4_TEMPLATE_INDEX_IN_COOKED_EXPORTS: i32 = 508;
+const VER_UE4_NON_OUTER_PACKAGE_IMPORT: i32 = 520;
+
+/// UE4 object versions that change the package summary
+const VER_UE4_ADD_STRING_ASSET_REFERENCES_MAP: i32 = 384;
+const VER_UE4_SERIALIZE_TEXT_IN_PACKAGES: i32 = 459;
+const VER_UE4_ADDED_SOFT_OBJECT_PATH: i32 = 514;
+const VER_UE4_ADDED_PACKAGE_SUMMARY_LOCALIZATION_ID: i32 = 516;
+
+/// UE5 object versions that change the import/export entry layout
+const VER_UE5_OPTIONAL_RESOURCES: i32 = 1003;
+const VER_UE5_REMOVE_OBJECT_EXPORT_PACKAGE_GUID: i32 = 1005;
+const VER_UE5_TRACK_OBJECT_EXPORT_IS_INHERITED: i32 = 1006;
+const VER_UE5_SCRIPT_SERIALIZATION_OFFSET: i32 = 1010;
+
+/// UE5 object versions that change the package summary
+const VER_UE5_ADD_SOFTOBJECTPATH_LIST: i32 = 1008;
+const VER_UE5_METADATA_SERIALIZATION_OFFSET: i32 = 1014;
+const VER_UE5_VERSE_CELLS: i32 = 1015;
+
+/// Package file summary layouts this parser understands (UE 4.0 to 5.x)
+const SUPPORTED_LEGACY_VERSIONS: std::ops::RangeInclusive<i32> = -9..=-4;
+
+/// Why a package could not be parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ParseFailure {
+    /// The file could not be opened or mapped
+    Io,
+    /// IoStore (Zen) package summary, which has no legacy file magic
+    Zen,
+    /// Legacy package with a summary layout newer or older than supported
+    UnsupportedVersion,
+    /// Truncated or malformed data
+    Corrupt,
+}
+
+impl ParseFailure {
+    /// Classify the failure to parse `path` that produced `error`
+    pub fn classify(path: &Path, error: &FastStartupError) -> Self {
+        if matches!(error, FastStartupError::IoError(_)) {
+            return ParseFailure::Io;
+        }
+        let Ok(data) = MmapPool::global().map(path) else {
+            return ParseFailure::Io;
+        };
+
+        let mut reader = ByteReader::new(&data);
+        match (reader.u32(), reader.i32()) {
+            (Ok(UASSET_MAGIC), Ok(legacy)) if !SUPPORTED_LEGACY_VERSIONS.contains(&legacy) => {
+                ParseFailure::UnsupportedVersion
+            }
+            (Ok(UASSET_MAGIC), _) => ParseFailure::Corrupt,
+            // Zen summaries start with a bHasVersioningInfo flag
+            (Ok(0 | 1), Ok(_)) => ParseFailure::Zen,
+            _ => ParseFailure::Corrupt,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ParseFailure::Io => "io",
+            ParseFailure::Zen => "zen",
+            ParseFailure::UnsupportedVersion => "unsupported_version",
+            ParseFailure::Corrupt => "corrupt",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct UAssetHeader {
     pub magic: u32,
@@ -29,83 +102,285 @@ pub struct UAssetHeader {
     pub import_offset: i32,
     pub export_count: i32,
     pub export_offset: i32,
+    /// Packages referenced through soft object paths; 0 when unknown
+    /// (unversioned packages)
+    pub soft_package_references_count: i32,
+    pub soft_package_references_offset: i32,
+}
+
+impl UAssetHeader {
+    /// Cooked packages are saved without engine version numbers
+    pub fn is_unversioned(&self) -> bool {
+        self.file_version_ue4 == 0 && self.file_version_ue5 == 0
+    }
+
+    fn import_entry_size(&self) -> usize {
+        let mut size = 28;
+        if self.file_version_ue4 >= VER_UE4_NON_OUTER_PACKAGE_IMPORT
+            && self.package_flags & PKG_FILTER_EDITOR_ONLY == 0
+        {
+            size += 8;
+        }
+        if self.file_version_ue5 >= VER_UE5_OPTIONAL_RESOURCES {
+            size += 4;
+        }
+        size
+    }
+
+    fn export_entry_size(&self) -> Option<usize> {
+        if self.is_unversioned() || self.file_version_ue4 < VER_UE4_TEMPLATE_INDEX_IN_COOKED_EXPORTS {
+            return None;
+        }
+
+        // Object indices, name, flags, serial size/offset, forced/client/server
+        let mut size = 16 + 8 + 4 + 16 + 12;
+        if self.file_version_ue5 < VER_UE5_REMOVE_OBJECT_EXPORT_PACKAGE_GUID {
+            size += 16;
+        }
+        if self.file_version_ue5 >= VER_UE5_TRACK_OBJECT_EXPORT_IS_INHERITED {
+            size += 4;
+        }
+        // PackageFlags, bNotAlwaysLoadedForEditorGame, bIsAsset
+        size += 12;
+        if self.file_version_ue5 >= VER_UE5_OPTIONAL_RESOURCES {
+            size += 4;
+        }
+        // First export dependency and the four dependency counts
+        size += 20;
+        if self.file_version_ue5 >= VER_UE5_SCRIPT_SERIALIZATION_OFFSET {
+            size += 16;
+        }
+        Some(size)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportEntry {
+    pub class_package: String,
+    pub class_name: String,
+    /// FPackageIndex of the outer object; 0 for top-level imports
+    pub outer_index: i32,
+    pub object_name: String,
+}
+
+impl ImportEntry {
+    /// Top-level import of a whole package
+    pub fn is_package(&self) -> bool {
+        self.outer_index == 0 && self.class_name == "Package"
+    }
+}
+
+/// Object references held by an export entry. Negative indices are imports
+/// (`-index - 1`), positive ones exports (`index - 1`), zero is null.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportEntry {
+    pub class_index: i32,
+    pub super_index: i32,
+    pub template_index: i32,
+    pub outer_index: i32,
+    pub serial_size: i64,
+    pub serial_offset: i64,
+}
+
+/// Import referenced by a negative package index
+pub fn resolve_import(imports: &[ImportEntry], index: i32) -> Option<&ImportEntry> {
+    if index >= 0 {
+        return None;
+    }
+    imports.get(usize::try_from(-(index as i64) - 1).ok()?)
+}
+
+/// Packages a package references, split by how they load
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PackageReferences {
+    /// Import map packages, loaded with the package
+    pub imports: Vec<String>,
+    /// Soft package references, loaded on demand
+    pub soft: Vec<String>,
+}
+
+/// Content package (game, engine or plugin mount) rather than a native
+/// `/Script/` module
+fn is_content_package(name: &str) -> bool {
+    name.starts_with('/') && !name.starts_with("/Script/")
+}
+
+/// FName reference (name map index + instance number) resolved to its string
+fn read_fname(reader: &mut ByteReader, names: &[String]) -> Result<String> {
+    let index = reader.i32()?;
+    reader.skip(4)?;
+    Ok(usize::try_from(index)
+        .ok()
+        .and_then(|i| names.get(i))
+        .cloned()
+        .unwrap_or_default())
+}
+
+/// Table entry count, capped so corrupt counts cannot request huge allocations
+fn capacity_for(count: i32, data: &[u8], entry_size: usize) -> usize {
+    (count.max(0) as usize).min(data.len() / entry_size.max(1))
+}
+
+/// A package mapped once, with its header and name map decoded. Import and
+/// export tables and serialized export data are read from the same mapping.
+pub struct Package {
+    data: MappedFile<'static>,
+    pub header: UAssetHeader,
+    pub names: Vec<String>,
+}
+
+impl Package {
+    pub fn open(path: &Path) -> Result<Self> {
+        let data = MmapPool::global().map(path)?;
+        let header = UAssetParser::read_header(&data)?;
+        let names = UAssetParser::read_name_table(&data, &header)?;
+        Ok(Self { data, header, names })
+    }
+
+    pub fn imports(&self) -> Result<Vec<ImportEntry>> {
+        UAssetParser::read_import_table(&self.data, &self.header, &self.names)
+    }
+
+    /// `/Game/` and `/Engine/` packages this package imports
+    pub fn package_imports(&self) -> Result<Vec<String>> {
+        let mut imports = Vec::new();
+
+        for import in self.imports()? {
+            // Package imports name the package itself; blueprint class
+            // imports carry it in ClassPackage
+            let package_name = if import.is_package() {
+                import.object_name
+            } else {
+                import.class_package
+            };
+
+            if is_content_package(&package_name) {
+                imports.push(package_name);
+            }
+        }
+
+        Ok(imports)
+    }
+
+    /// Content packages named in the soft package reference list
+    pub fn soft_package_references(&self) -> Result<Vec<String>> {
+        let mut references = UAssetParser::read_soft_package_references(&self.data, &self.header, &self.names)?;
+        references.retain(|name| is_content_package(name));
+        Ok(references)
+    }
+
+    /// Imported and softly referenced content packages
+    pub fn package_references(&self) -> Result<PackageReferences> {
+        Ok(PackageReferences { imports: self.package_imports()?, soft: self.soft_package_references()? })
+    }
+
+    /// Hash of the package summary (header, name, import and export maps),
+    /// which fully determines the import list
+    pub fn summary_hash(&self) -> u64 {
+        let end = usize::try_from(self.header.total_header_size)
+            .unwrap_or(0)
+            .clamp(0, self.data.len());
+        xxh3_64(&self.data[..end])
+    }
+
+    pub fn exports(&self) -> Result<Vec<ExportEntry>> {
+        UAssetParser::read_export_table(&self.data, &self.header)
+    }
+
+    /// Serialized data of `export`, if it lies within the file
+    pub fn export_data(&self, export: &ExportEntry) -> Option<&[u8]> {
+        let start = usize::try_from(export.serial_offset).ok()?;
+        let end = start.checked_add(usize::try_from(export.serial_size).ok()?)?;
+        self.data.get(start..end)
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
 }
 
 pub struct UAssetParser;
 
 impl UAssetParser {
     pub fn parse_header(path: &Path) -> Result<UAssetHeader> {
-        let mut file = File::open(path)?;
-        let mut buffer = [0u8; 4];
+        let mmap = MmapPool::global().map(path)?;
+        Self::read_header(&mmap)
+    }
 
-        // Read magic
-        file.read_exact(&mut buffer)?;
-        let magic = u32::from_le_bytes(buffer);
+    fn read_header(data: &[u8]) -> Result<UAssetHeader> {
+        let mut reader = ByteReader::new(data);
 
+        let magic = reader.u32()?;
         if magic != UASSET_MAGIC {
             return Err(FastStartupError::AssetError(
                 format!("Invalid UAsset magic: {:08X}", magic)
             ));
         }
 
-        // Read legacy version
-        file.read_exact(&mut buffer)?;
-        let legacy_version = i32::from_le_bytes(buffer);
-
-        // Read legacy UE3 version
-        file.read_exact(&mut buffer)?;
-        let legacy_ue3_version = i32::from_le_bytes(buffer);
-
-        // Read UE4 file version
-        file.read_exact(&mut buffer)?;
-        let file_version_ue4 = i32::from_le_bytes(buffer);
-
-        // Read UE5 file version (if applicable)
-        file.read_exact(&mut buffer)?;
-        let file_version_ue5 = i32::from_le_bytes(buffer);
+        let legacy_version = reader.i32()?;
+        if !SUPPORTED_LEGACY_VERSIONS.contains(&legacy_version) {
+            return Err(FastStartupError::AssetError(
+                format!("Unsupported package legacy version {}", legacy_version)
+            ));
+        }
+        let legacy_ue3_version = reader.i32()?;
+        let file_version_ue4 = reader.i32()?;
 
-        // Read licensee version
-        file.read_exact(&mut buffer)?;
-        let file_version_licensee_ue4 = i32::from_le_bytes(buffer);
+        // UE5 file version (only written since legacy version -8)
+        let file_version_ue5 = if legacy_version <= -8 { reader.i32()? } else { 0 };
+        let file_version_licensee_ue4 = reader.i32()?;
 
-        // Skip custom versions
-        file.read_exact(&mut buffer)?;
-        let custom_version_count = i32::from_le_bytes(buffer);
-        
         // Each custom version is 20 bytes (GUID + version)
-        file.seek(SeekFrom::Current(custom_version_count as i64 * 20))?;
-
-        // Read total header size
-        file.read_exact(&mut buffer)?;
-        let total_header_size = i32::from_le_bytes(buffer);
-
-        // Read package name (FString)
-        let package_name = Self::read_fstring(&mut file)?;
-
-        // Read package flags
-        file.read_exact(&mut buffer)?;
-        let package_flags = u32::from_le_bytes(buffer);
+        let custom_version_count = reader.index()?;
+        reader.skip(custom_version_count.saturating_mul(20))?;
 
-        // Read name count and offset
-        file.read_exact(&mut buffer)?;
-        let name_count = i32::from_le_bytes(buffer);
-        file.read_exact(&mut buffer)?;
-        let name_offset = i32::from_le_bytes(buffer);
+        let total_header_size = reader.i32()?;
+        let package_name = reader.fstring()?;
+        let package_flags = reader.u32()?;
 
-        // Skip some fields to get to imports
-        file.seek(SeekFrom::Current(16))?; // Skip gatherable text data
+        let name_count = reader.i32()?;
+        let name_offset = reader.i32()?;
 
-        // Read export count and offset
-        file.read_exact(&mut buffer)?;
-        let export_count = i32::from_le_bytes(buffer);
-        file.read_exact(&mut buffer)?;
-        let export_offset = i32::from_le_bytes(buffer);
+        // Unversioned (cooked) packages are laid out as by the latest engine
+        let unversioned = file_version_ue4 == 0 && file_version_ue5 == 0;
+        // Soft object path list count and offset
+        if unversioned || file_version_ue5 >= VER_UE5_ADD_SOFTOBJECTPATH_LIST {
+            reader.skip(8)?;
+        }
+        if !unversioned
+            && package_flags & PKG_FILTER_EDITOR_ONLY == 0
+            && file_version_ue4 >= VER_UE4_ADDED_PACKAGE_SUMMARY_LOCALIZATION_ID
+        {
+            reader.fstring()?;
+        }
+        // Gatherable text data count and offset
+        if unversioned || file_version_ue4 >= VER_UE4_SERIALIZE_TEXT_IN_PACKAGES {
+            reader.skip(8)?;
+        }
 
-        // Read import count and offset
-        file.read_exact(&mut buffer)?;
-        let import_count = i32::from_le_bytes(buffer);
-        file.read_exact(&mut buffer)?;
-        let import_offset = i32::from_le_bytes(buffer);
+        let export_count = reader.i32()?;
+        let export_offset = reader.i32()?;
+        let import_count = reader.i32()?;
+        let import_offset = reader.i32()?;
+
+        let mut soft_package_references_count = 0;
+        let mut soft_package_references_offset = 0;
+        if !unversioned {
+            // Verse cell export and import count and offset
+            if file_version_ue5 >= VER_UE5_VERSE_CELLS {
+                reader.skip(16)?;
+            }
+            // Metadata offset
+            if file_version_ue5 >= VER_UE5_METADATA_SERIALIZATION_OFFSET {
+                reader.skip(4)?;
+            }
+            // Depends offset
+            reader.skip(4)?;
+            if file_version_ue4 >= VER_UE4_ADD_STRING_ASSET_REFERENCES_MAP {
+                soft_package_references_count = reader.i32()?;
+                soft_package_references_offset = reader.i32()?;
+            }
+        }
 
         Ok(UAssetHeader {
             magic,
@@ -123,157 +398,167 @@ impl UAssetParser {
             import_offset,
             export_count,
             export_offset,
+            soft_package_references_count,
+            soft_package_references_offset,
         })
     }
 
     pub fn parse_imports(path: &Path) -> Result<Vec<String>> {
-        let file = File::open(path)?;
-        let mmap = unsafe { Mmap::map(&file)? };
-
-        // Verify magic using ASM if available
-        if mmap.len() < 4 {
-            return Err(FastStartupError::AssetError("File too small".to_string()));
-        }
-
-        let magic = u32::from_le_bytes([mmap[0], mmap[1], mmap[2], mmap[3]]);
-        if magic != UASSET_MAGIC {
-            return Err(FastStartupError::AssetError(
-                format!("Invalid UAsset magic: {:08X}", magic)
-            ));
-        }
+        Package::open(path)?.package_imports()
+    }
 
-        // Parse header to get import offset
-        let header = Self::parse_header(path)?;
+    /// Full import map with resolved names
+    pub fn parse_import_table(path: &Path) -> Result<Vec<ImportEntry>> {
+        Package::open(path)?.imports()
+    }
 
+    fn read_import_table(data: &[u8], header: &UAssetHeader, names: &[String]) -> Result<Vec<ImportEntry>> {
         if header.import_count <= 0 || header.import_offset <= 0 {
             return Ok(Vec::new());
         }
 
-        // Read name table first
-        let names = Self::read_name_table(&mmap, &header)?;
-
-        // Read imports
-        let mut imports = Vec::new();
-        let mut offset = header.import_offset as usize;
+        // Import structure:
+        // - ClassPackage (FName) - 8 bytes
+        // - ClassName (FName) - 8 bytes
+        // - OuterIndex (FPackageIndex) - 4 bytes
+        // - ObjectName (FName) - 8 bytes
+        // - PackageName (FName, editor packages only) - 8 bytes
+        // - bImportOptional (UE5) - 4 bytes
+        let entry_size = header.import_entry_size();
+        let mut imports = Vec::with_capacity(capacity_for(header.import_count, data, entry_size));
+        let Ok(mut reader) = ByteReader::at(data, header.import_offset as usize) else {
+            return Ok(imports);
+        };
 
         for _ in 0..header.import_count {
-            if offset + 28 > mmap.len() {
+            let start = reader.position();
+            if reader.remaining() < entry_size {
                 break;
             }
 
-            // Import structure:
-            // - ClassPackage (FName index) - 8 bytes
-            // - ClassName (FName index) - 8 bytes
-            // - OuterIndex - 4 bytes
-            // - ObjectName (FName index) - 8 bytes
-
-            let class_package_idx = i32::from_le_bytes([
-                mmap[offset], mmap[offset + 1], mmap[offset + 2], mmap[offset + 3]
-            ]) as usize;
-
-            let _object_name_idx = i32::from_le_bytes([
-                mmap[offset + 20], mmap[offset + 21], mmap[offset + 22], mmap[offset + 23]
-            ]) as usize;
-
-            if class_package_idx < names.len() {
-                let package_name = &names[class_package_idx];
-                if package_name.starts_with("/Game/") || package_name.starts_with("/Engine/") {
-                    imports.push(package_name.clone());
-                }
-            }
+            imports.push(ImportEntry {
+                class_package: read_fname(&mut reader, names)?,
+                class_name: read_fname(&mut reader, names)?,
+                outer_index: reader.i32()?,
+                object_name: read_fname(&mut reader, names)?,
+            });
 
-            offset += 28;
+            reader.seek(start + entry_size)?;
         }
 
         Ok(imports)
     }
 
-    fn read_name_table(mmap: &Mmap, header: &UAssetHeader) -> Result<Vec<String>> {
-        let mut names = Vec::with_capacity(header.name_count as usize);
-        let mut offset = header.name_offset as usize;
-
-        for _ in 0..header.name_count {
-            if offset + 4 > mmap.len() {
-                break;
+    /// Soft package reference list, up to the first truncated entry
+    fn read_soft_package_references(data: &[u8], header: &UAssetHeader, names: &[String]) -> Result<Vec<String>> {
+        let mut references = Vec::with_capacity(capacity_for(header.soft_package_references_count, data, 8));
+        if header.soft_package_references_count <= 0 || header.soft_package_references_offset <= 0 {
+            return Ok(references);
+        }
+        let Ok(mut reader) = ByteReader::at(data, header.soft_package_references_offset as usize) else {
+            return Ok(references);
+        };
+
+        // FName since soft object paths replaced string asset references,
+        // FString before
+        for _ in 0..header.soft_package_references_count {
+            let reference = if header.file_version_ue4 >= VER_UE4_ADDED_SOFT_OBJECT_PATH {
+                read_fname(&mut reader, names)
+            } else {
+                reader.fstring()
+            };
+            match reference {
+                Ok(reference) => references.push(reference),
+                Err(_) => break,
             }
+        }
 
-            // Read string length
-            let len = i32::from_le_bytes([
-                mmap[offset], mmap[offset + 1], mmap[offset + 2], mmap[offset + 3]
-            ]);
-            offset += 4;
+        Ok(references)
+    }
 
-            if len <= 0 {
-                names.push(String::new());
-                continue;
-            }
+    /// Export map object references and serial ranges. Fails for package
+    /// versions whose export layout is unknown (pre-4.14 or unversioned).
+    pub fn parse_export_table(path: &Path) -> Result<Vec<ExportEntry>> {
+        Package::open(path)?.exports()
+    }
 
-            let str_len = len.abs() as usize;
-            if offset + str_len > mmap.len() {
-                break;
-            }
+    fn read_export_table(data: &[u8], header: &UAssetHeader) -> Result<Vec<ExportEntry>> {
+        let entry_size = header.export_entry_size().ok_or_else(|| {
+            FastStartupError::AssetError(format!(
+                "Unsupported export layout (UE4 {}, UE5 {})",
+                header.file_version_ue4, header.file_version_ue5
+            ))
+        })?;
 
-            // Read string (null-terminated)
-            let name = if len > 0 {
-                // ASCII/UTF-8
-                String::from_utf8_lossy(&mmap[offset..offset + str_len - 1]).to_string()
-            } else {
-                // UTF-16
-                let utf16: Vec<u16> = mmap[offset..offset + str_len]
-                    .chunks(2)
-                    .map(|c| u16::from_le_bytes([c[0], c.get(1).copied().unwrap_or(0)]))
-                    .collect();
-                String::from_utf16_lossy(&utf16)
-            };
+        if header.export_count <= 0 || header.export_offset <= 0 {
+            return Ok(Vec::new());
+        }
 
-            names.push(name);
-            offset += str_len;
+        let mut exports = Vec::with_capacity(capacity_for(header.export_count, data, entry_size));
+        let Ok(mut reader) = ByteReader::at(data, header.export_offset as usize) else {
+            return Ok(exports);
+        };
 
-            // Skip hash
-            if offset + 4 <= mmap.len() {
-                offset += 4;
+        for _ in 0..header.export_count {
+            let start = reader.position();
+            if reader.remaining() < entry_size {
+                break;
             }
+
+            // ClassIndex, SuperIndex, TemplateIndex, OuterIndex, ObjectName,
+            // ObjectFlags, SerialSize, SerialOffset, ...
+            let class_index = reader.i32()?;
+            let super_index = reader.i32()?;
+            let template_index = reader.i32()?;
+            let outer_index = reader.i32()?;
+            reader.skip(12)?;
+            exports.push(ExportEntry {
+                class_index,
+                super_index,
+                template_index,
+                outer_index,
+                serial_size: reader.i64()?,
+                serial_offset: reader.i64()?,
+            });
+
+            reader.seek(start + entry_size)?;
         }
 
-        Ok(names)
+        Ok(exports)
     }
 
-    fn read_fstring(file: &mut File) -> Result<String> {
-        let mut buffer = [0u8; 4];
-        file.read_exact(&mut buffer)?;
-        let len = i32::from_le_bytes(buffer);
+    /// Package name map, indexed by FName index
+    pub fn parse_name_table(path: &Path) -> Result<Vec<String>> {
+        Ok(Package::open(path)?.names)
+    }
 
-        if len == 0 {
-            return Ok(String::new());
-        }
+    /// Names up to the first truncated or corrupt entry
+    fn read_name_table(data: &[u8], header: &UAssetHeader) -> Result<Vec<String>> {
+        // Smallest entry: empty string length plus hash
+        let mut names = Vec::with_capacity(capacity_for(header.name_count, data, 8));
+        let Ok(mut reader) = ByteReader::at(data, header.name_offset.max(0) as usize) else {
+            return Ok(names);
+        };
+
+        for _ in 0..header.name_count {
+            match reader.fstring() {
+                Ok(name) => names.push(name),
+                Err(_) => break,
+            }
 
-        let str_len = len.abs() as usize;
-        let mut str_buffer = vec![0u8; str_len];
-        file.read_exact(&mut str_buffer)?;
-
-        if len > 0 {
-            // ASCII/UTF-8 (null-terminated)
-            Ok(String::from_utf8_lossy(&str_buffer[..str_len.saturating_sub(1)]).to_string())
-        } else {
-            // UTF-16
-            let utf16: Vec<u16> = str_buffer
-                .chunks(2)
-                .map(|c| u16::from_le_bytes([c[0], c.get(1).copied().unwrap_or(0)]))
-                .collect();
-            Ok(String::from_utf16_lossy(&utf16))
+            // Skip hash
+            if reader.skip(4).is_err() {
+                break;
+            }
         }
+
+        Ok(names)
     }
 
     pub fn is_valid_uasset(path: &Path) -> bool {
-        if let Ok(file) = File::open(path) {
-            if let Ok(mmap) = unsafe { Mmap::map(&file) } {
-                if mmap.len() >= 4 {
-                    let magic = u32::from_le_bytes([mmap[0], mmap[1], mmap[2], mmap[3]]);
-                    return magic == UASSET_MAGIC;
-                }
-            }
-        }
-        false
+        MmapPool::global()
+            .map(path)
+            .is_ok_and(|mmap| ByteReader::new(&mmap).u32().is_ok_and(|magic| magic == UASSET_MAGIC))
     }
 
     pub fn get_ue_version(path: &Path) -> Result<(i32, i32)> {
@@ -290,4 +575,66 @@ mod tests {
     fn test_uasset_magic() {
         assert_eq!(UASSET_MAGIC, 0x9E2A83C1);
     }
+
+    #[test]
+    fn test_truncated_header_is_an_error() {
+        let data = crate::testgen::package_bytes("/Game/A", &["/Game/B"], 0);
+        let header = UAssetParser::read_header(&data).unwrap();
+        assert_eq!(header.package_name, "/Game/A");
+        assert_eq!(UAssetParser::read_name_table(&data, &header).unwrap().len(), 4);
+
+        for len in 0..header.name_offset as usize {
+            assert!(UAssetParser::read_header(&data[..len]).is_err());
+        }
+        // A name map cut short yields the names before the cut
+        let cut = &data[..header.name_offset as usize + 10];
+        assert!(UAssetParser::read_name_table(cut, &header).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_package_single_mapping() {
+        let path = std::env::temp_dir().join(format!("uefast_package_{}.uasset", std::process::id()));
+        std::fs::write(&path, crate::testgen::package_bytes("/Game/A", &["/Game/B"], 0)).unwrap();
+
+        let package = Package::open(&path).unwrap();
+        assert_eq!(package.header.package_name, "/Game/A");
+        assert_eq!(package.imports().unwrap()[0].object_name, "/Game/B");
+        assert!(package.exports().unwrap().is_empty());
+        assert_eq!(UAssetParser::parse_imports(&path).unwrap(), vec!["/Game/B"]);
+        assert!(package.soft_package_references().unwrap().is_empty());
+        drop(package);
+
+        let data = crate::testgen::package_bytes_with_soft("/Game/A", &["/Game/B"], &["/Game/C", "/Script/Engine"], 0);
+        std::fs::write(&path, data).unwrap();
+        let package = Package::open(&path).unwrap();
+        let references = package.package_references().unwrap();
+        assert_eq!(references.imports, vec!["/Game/B"]);
+        assert_eq!(references.soft, vec!["/Game/C"]);
+        drop(package);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_classify_failures() {
+        let dir = std::env::temp_dir().join(format!("uefast_classify_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let io = FastStartupError::IoError(std::io::Error::other("denied"));
+        let parse = FastStartupError::AssetError("bad".to_string());
+
+        let mut future = crate::testgen::package_bytes("/Game/A", &[], 0);
+        future[4..8].copy_from_slice(&(-12i32).to_le_bytes());
+        let cases: [(&str, Vec<u8>, ParseFailure); 3] = [
+            ("future.uasset", future, ParseFailure::UnsupportedVersion),
+            ("zen.uasset", [1u32.to_le_bytes(), 64u32.to_le_bytes()].concat(), ParseFailure::Zen),
+            ("garbage.uasset", vec![0xFF; 16], ParseFailure::Corrupt),
+        ];
+        for (name, data, expected) in cases {
+            let path = dir.join(name);
+            std::fs::write(&path, data).unwrap();
+            assert!(Package::open(&path).is_err());
+            assert_eq!(ParseFailure::classify(&path, &parse), expected, "{}", name);
+        }
+        assert_eq!(ParseFailure::classify(&dir.join("missing.uasset"), &io), ParseFailure::Io);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
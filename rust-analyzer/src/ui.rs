@@ -0,0 +1,185 @@
+//! UI Analysis Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! UMG Widget Blueprints and fonts in the startup closure
+
+use crate::scanner::{package_name_from_relative, AssetInfo, AssetType};
+use crate::uasset::{resolve_import, ImportEntry, Package};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+/// Composite fonts with more face/texture pages than this are flagged
+pub const MAX_STARTUP_FONT_PAGES: usize = 8;
+
+const WIDGET_CLASSES: &[&str] = &["WidgetBlueprint", "WidgetBlueprintGeneratedClass"];
+const TEXTURE_CLASSES: &[&str] = &["Texture2D", "TextureCube", "Texture2DArray", "TextureRenderTarget2D"];
+const FONT_CLASSES: &[&str] = &["Font", "FontFace"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WidgetInfo {
+    pub relative_path: String,
+    /// Texture packages imported directly by the widget
+    pub textures: Vec<String>,
+    /// Font and font face packages imported directly by the widget
+    pub fonts: Vec<String>,
+    pub texture_bytes: u64,
+    pub font_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FontInfo {
+    pub relative_path: String,
+    /// Font faces of the composite font (default, fallback and sub-typefaces)
+    pub face_count: usize,
+    /// Pre-rendered texture pages of offline cached fonts
+    pub texture_pages: usize,
+    pub size_bytes: u64,
+}
+
+impl FontInfo {
+    pub fn pages(&self) -> usize {
+        self.face_count + self.texture_pages
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UiReport {
+    /// Widget Blueprints in the startup closure, heaviest dependencies first
+    pub widgets: Vec<WidgetInfo>,
+    /// Fonts in the startup closure, most pages first
+    pub fonts: Vec<FontInfo>,
+    /// Distinct texture bytes imported by startup widgets
+    pub total_texture_bytes: u64,
+    /// Distinct font bytes imported by startup widgets
+    pub total_font_bytes: u64,
+}
+
+impl UiReport {
+    /// Inspect `closure`, the startup assets plus their hard dependencies.
+    /// `assets` resolves package names to sizes.
+    pub fn build(closure: &[AssetInfo], assets: &[AssetInfo]) -> Self {
+        let sizes: HashMap<String, u64> = assets
+            .iter()
+            .filter_map(|a| Some((package_name_from_relative(&a.relative_path)?, a.size_bytes)))
+            .collect();
+        let size_of = |packages: &[String]| -> u64 {
+            packages.iter().filter_map(|p| sizes.get(p)).sum()
+        };
+
+        let parsed: Vec<(&AssetInfo, String, Vec<ImportEntry>, usize)> = closure
+            .par_iter()
+            .filter(|a| a.asset_type == AssetType::UAsset)
+            .filter_map(|asset| {
+                let package = Package::open(&asset.path).ok()?;
+                let imports = package.imports().ok()?;
+                let exports = package.exports().ok()?;
+                let classes: Vec<&str> = exports
+                    .iter()
+                    .filter_map(|e| resolve_import(&imports, e.class_index))
+                    .map(|i| i.object_name.as_str())
+                    .collect();
+
+                let class = classes
+                    .iter()
+                    .find(|c| WIDGET_CLASSES.contains(c) || FONT_CLASSES.contains(c))?
+                    .to_string();
+                let texture_exports = classes.iter().filter(|c| **c == "Texture2D").count();
+                Some((asset, class, imports, texture_exports))
+            })
+            .collect();
+
+        let mut report = Self::default();
+        let mut all_textures = BTreeSet::new();
+        let mut all_fonts = BTreeSet::new();
+
+        for (asset, class, imports, texture_exports) in parsed {
+            if WIDGET_CLASSES.contains(&class.as_str()) {
+                let textures = imported_packages(&imports, TEXTURE_CLASSES);
+                let fonts = imported_packages(&imports, FONT_CLASSES);
+                all_textures.extend(textures.iter().cloned());
+                all_fonts.extend(fonts.iter().cloned());
+
+                report.widgets.push(WidgetInfo {
+                    relative_path: asset.relative_path.clone(),
+                    texture_bytes: size_of(&textures),
+                    font_bytes: size_of(&fonts),
+                    textures,
+                    fonts,
+                });
+            } else if class == "Font" {
+                report.fonts.push(FontInfo {
+                    relative_path: asset.relative_path.clone(),
+                    face_count: imported_packages(&imports, &["FontFace"]).len(),
+                    texture_pages: texture_exports,
+                    size_bytes: asset.size_bytes,
+                });
+            }
+        }
+
+        report.total_texture_bytes = all_textures.iter().filter_map(|p| sizes.get(p)).sum();
+        report.total_font_bytes = all_fonts.iter().filter_map(|p| sizes.get(p)).sum();
+        report.widgets.sort_by(|a, b| {
+            (b.texture_bytes + b.font_bytes)
+                .cmp(&(a.texture_bytes + a.font_bytes))
+                .then_with(|| a.relative_path.cmp(&b.relative_path))
+        });
+        report.fonts.sort_by(|a, b| {
+            b.pages().cmp(&a.pages()).then_with(|| a.relative_path.cmp(&b.relative_path))
+        });
+        report
+    }
+
+    /// Fonts whose pages all load with the first widget that uses them
+    pub fn heavy_fonts(&self) -> impl Iterator<Item = &FontInfo> {
+        self.fonts.iter().filter(|f| f.pages() > MAX_STARTUP_FONT_PAGES)
+    }
+}
+
+/// Sorted `/Game/` packages of imported objects whose class is in `classes`
+fn imported_packages(imports: &[ImportEntry], classes: &[&str]) -> Vec<String> {
+    let packages: BTreeSet<String> = imports
+        .iter()
+        .filter(|i| classes.contains(&i.class_name.as_str()))
+        .filter_map(|i| {
+            // Walk outers up to the package import
+            let mut outer = resolve_import(imports, i.outer_index)?;
+            while !outer.is_package() {
+                outer = resolve_import(imports, outer.outer_index)?;
+            }
+            Some(outer.object_name.clone())
+        })
+        .filter(|p| p.starts_with("/Game/"))
+        .collect();
+    packages.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn import(class_name: &str, outer_index: i32, object_name: &str) -> ImportEntry {
+        ImportEntry {
+            class_package: "/Script/Engine".to_string(),
+            class_name: class_name.to_string(),
+            outer_index,
+            object_name: object_name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_imported_packages() {
+        let imports = vec![
+            import("Package", 0, "/Game/UI/T_Frame"),
+            import("Texture2D", -1, "T_Frame"),
+            import("Package", 0, "/Game/UI/F_Body"),
+            import("Font", -3, "F_Body"),
+            import("Package", 0, "/Engine/EngineFonts/Roboto"),
+            import("Font", -5, "Roboto"),
+        ];
+
+        assert_eq!(imported_packages(&imports, TEXTURE_CLASSES), vec!["/Game/UI/T_Frame"]);
+        assert_eq!(imported_packages(&imports, FONT_CLASSES), vec!["/Game/UI/F_Body"]);
+    }
+}
@@ -0,0 +1,288 @@
+//! Usage Metrics Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Opt-in, anonymized usage records: which command ran, how long it took,
+//! how large the project was (bucketed) and how it ended. Records never
+//! contain paths, project or asset names. They stay in a local file until
+//! the user uploads them.
+
+use crate::{FastStartupError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
+
+/// `1` records and `0` does not, overriding the saved setting
+pub const TELEMETRY_ENV: &str = "UEFAST_TELEMETRY";
+
+/// Overrides the per-user directory holding settings and records
+pub const HOME_ENV: &str = "UEFAST_HOME";
+
+pub const SETTINGS_FILE: &str = "telemetry.toml";
+pub const USAGE_FILE: &str = "usage.jsonl";
+
+/// Largest project scanned during this run: assets and bytes
+static SCALE: Mutex<Option<(usize, u64)>> = Mutex::new(None);
+
+/// Command being run and when it started
+static RUN: OnceLock<(String, Instant)> = OnceLock::new();
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TelemetrySettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Endpoint `telemetry upload` posts the records to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upload_url: Option<String>,
+}
+
+impl TelemetrySettings {
+    pub fn load(dir: &Path) -> Result<Self> {
+        let path = dir.join(SETTINGS_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = std::fs::read_to_string(&path)?;
+        toml::from_str(&text).map_err(|e| FastStartupError::ConfigError(format!("{}: {}", path.display(), e)))
+    }
+
+    pub fn save(&self, dir: &Path) -> Result<PathBuf> {
+        std::fs::create_dir_all(dir)?;
+        let path = dir.join(SETTINGS_FILE);
+        let text = toml::to_string_pretty(self).map_err(|e| FastStartupError::ConfigError(e.to_string()))?;
+        std::fs::write(&path, text)?;
+        Ok(path)
+    }
+
+    /// The saved setting unless `UEFAST_TELEMETRY` says otherwise
+    pub fn is_enabled(&self, env: Option<&str>) -> bool {
+        match env {
+            Some("1") => true,
+            Some("0") => false,
+            _ => self.enabled,
+        }
+    }
+}
+
+/// `UEFAST_HOME`, else `.uefast` in the user's home directory
+pub fn user_dir() -> Option<PathBuf> {
+    if let Some(dir) = std::env::var_os(HOME_ENV) {
+        return Some(PathBuf::from(dir));
+    }
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(crate::STATE_DIR))
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UsageRecord {
+    /// Day only, e.g. `2026-10-16`
+    pub date: String,
+    pub version: String,
+    pub os: String,
+    /// Subcommand path, e.g. `cache compare`
+    pub command: String,
+    pub duration_ms: u64,
+    /// `ok`, `panic`, or the error category (see `FastStartupError::category`)
+    pub outcome: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assets: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<String>,
+    pub threads: usize,
+}
+
+pub fn asset_bucket(assets: usize) -> &'static str {
+    match assets {
+        0..=999 => "<1k",
+        1_000..=9_999 => "1k-10k",
+        10_000..=99_999 => "10k-100k",
+        _ => ">=100k",
+    }
+}
+
+pub fn size_bucket(bytes: u64) -> &'static str {
+    const GB: u64 = 1024 * 1024 * 1024;
+    match bytes / GB {
+        0 => "<1GB",
+        1..=9 => "1-10GB",
+        10..=99 => "10-100GB",
+        _ => ">=100GB",
+    }
+}
+
+/// Note a scanned project's scale; the largest one of the run is recorded
+pub fn observe_scale(assets: usize, bytes: u64) {
+    if let Ok(mut scale) = SCALE.lock() {
+        if scale.is_none_or(|(seen, _)| assets > seen) {
+            *scale = Some((assets, bytes));
+        }
+    }
+}
+
+/// Start timing `command`
+pub fn begin(command: &str) {
+    let _ = RUN.set((command.to_string(), Instant::now()));
+}
+
+/// Record the run started with `begin`, if telemetry is enabled. Returns
+/// whether a record was written.
+pub fn finish(outcome: &str) -> Result<bool> {
+    let Some((command, started)) = RUN.get() else {
+        return Ok(false);
+    };
+    let Some(dir) = user_dir() else {
+        return Ok(false);
+    };
+    let env = std::env::var(TELEMETRY_ENV).ok();
+    if !TelemetrySettings::load(&dir)?.is_enabled(env.as_deref()) {
+        return Ok(false);
+    }
+
+    let scale = SCALE.lock().ok().and_then(|s| *s);
+    let record = UsageRecord {
+        date: chrono::Utc::now().format("%Y-%m-%d").to_string(),
+        version: crate::VERSION.to_string(),
+        os: std::env::consts::OS.to_string(),
+        command: command.clone(),
+        duration_ms: started.elapsed().as_millis() as u64,
+        outcome: outcome.to_string(),
+        assets: scale.map(|(assets, _)| asset_bucket(assets).to_string()),
+        size: scale.map(|(_, bytes)| size_bucket(bytes).to_string()),
+        threads: rayon::current_num_threads(),
+    };
+    append(&dir, &record)?;
+    Ok(true)
+}
+
+/// Record panics as `panic` before the default hook reports them
+pub fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let _ = finish("panic");
+        default_hook(info);
+    }));
+}
+
+pub fn append(dir: &Path, record: &UsageRecord) -> Result<()> {
+    std::fs::create_dir_all(dir)?;
+    let line = serde_json::to_string(record).map_err(|e| FastStartupError::SerializationError(e.to_string()))?;
+    let mut file = std::fs::OpenOptions::new().create(true).append(true).open(dir.join(USAGE_FILE))?;
+    writeln!(file, "{}", line)?;
+    Ok(())
+}
+
+/// Every record in `dir`, oldest first; unreadable lines are skipped
+pub fn load(dir: &Path) -> Result<Vec<UsageRecord>> {
+    let path = dir.join(USAGE_FILE);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    Ok(std::fs::read_to_string(&path)?
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+pub fn clear(dir: &Path) -> Result<()> {
+    let path = dir.join(USAGE_FILE);
+    if path.exists() {
+        std::fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+/// POST the records as a JSON array
+pub fn upload(url: &str, records: &[UsageRecord]) -> Result<()> {
+    ureq::post(url)
+        .send_json(records)
+        .map_err(|e| FastStartupError::TelemetryError(e.to_string()))?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct CommandUsage {
+    pub runs: usize,
+    pub failures: usize,
+    pub median_ms: u64,
+    pub max_ms: u64,
+}
+
+/// Runs, failures and durations per command
+pub fn summarize(records: &[UsageRecord]) -> BTreeMap<String, CommandUsage> {
+    let mut durations: BTreeMap<String, (Vec<u64>, usize)> = BTreeMap::new();
+    for record in records {
+        let (times, failures) = durations.entry(record.command.clone()).or_default();
+        times.push(record.duration_ms);
+        if record.outcome != "ok" {
+            *failures += 1;
+        }
+    }
+    durations
+        .into_iter()
+        .map(|(command, (mut times, failures))| {
+            times.sort_unstable();
+            let usage = CommandUsage {
+                runs: times.len(),
+                failures,
+                median_ms: times[times.len() / 2],
+                max_ms: times[times.len() - 1],
+            };
+            (command, usage)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(command: &str, duration_ms: u64, outcome: &str) -> UsageRecord {
+        UsageRecord {
+            date: "2026-10-16".to_string(),
+            version: crate::VERSION.to_string(),
+            os: "linux".to_string(),
+            command: command.to_string(),
+            duration_ms,
+            outcome: outcome.to_string(),
+            assets: Some(asset_bucket(12_000).to_string()),
+            size: None,
+            threads: 8,
+        }
+    }
+
+    #[test]
+    fn test_buckets_and_opt_in() {
+        assert_eq!(asset_bucket(999), "<1k");
+        assert_eq!(asset_bucket(12_000), "10k-100k");
+        assert_eq!(size_bucket(3 * 1024 * 1024 * 1024), "1-10GB");
+
+        let saved = TelemetrySettings { enabled: true, upload_url: None };
+        assert!(!TelemetrySettings::default().is_enabled(None));
+        assert!(TelemetrySettings::default().is_enabled(Some("1")));
+        assert!(!saved.is_enabled(Some("0")));
+        assert!(saved.is_enabled(Some("")));
+    }
+
+    #[test]
+    fn test_append_load_and_summarize() {
+        let dir = std::env::temp_dir().join(format!("uefast_usage_{}", std::process::id()));
+        append(&dir, &record("analyze", 300, "ok")).unwrap();
+        append(&dir, &record("analyze", 100, "ok")).unwrap();
+        append(&dir, &record("scan", 50, "project_not_found")).unwrap();
+
+        let records = load(&dir).unwrap();
+        assert_eq!(records.len(), 3);
+        let summary = summarize(&records);
+        assert_eq!(summary["analyze"], CommandUsage { runs: 2, failures: 0, median_ms: 300, max_ms: 300 });
+        assert_eq!(summary["scan"].failures, 1);
+
+        clear(&dir).unwrap();
+        assert!(load(&dir).unwrap().is_empty());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
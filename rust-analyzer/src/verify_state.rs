@@ -0,0 +1,176 @@
+//! Verify State Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Quick-verify results persisted in `.uefast/quick_verify.bin` with the
+//! time of the run, so `quick-verify --since-last` only rehashes files
+//! touched since then and reuses every other verdict
+
+use crate::{FastStartupError, Result, STATE_DIR};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+pub const VERIFY_STATE_FILE: &str = "quick_verify.bin";
+
+/// Bumped whenever the layout or the meaning of the saved verdicts changes
+const VERIFY_STATE_VERSION: u32 = 1;
+
+/// Size and modification time (seconds since the epoch) of a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct FileStamp {
+    pub size_bytes: u64,
+    pub modified: u64,
+}
+
+impl FileStamp {
+    pub fn of(path: &Path) -> Option<Self> {
+        let metadata = std::fs::metadata(path).ok()?;
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        Some(Self { size_bytes: metadata.len(), modified })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct Verdict {
+    stamp: FileStamp,
+    /// Differs from the cache (or is not in it)
+    changed: bool,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct VerifyState {
+    version: u32,
+    /// Cache the verdicts were checked against, and its stamp at the time
+    cache: String,
+    cache_stamp: FileStamp,
+    /// Start of the run, seconds since the epoch; files modified at or after
+    /// it are inspected again even when their stamp matches
+    verified_at: u64,
+    /// Relative path -> verdict
+    files: HashMap<String, Verdict>,
+}
+
+impl VerifyState {
+    pub fn path(project_root: &Path) -> PathBuf {
+        project_root.join(STATE_DIR).join(VERIFY_STATE_FILE)
+    }
+
+    /// Empty state for a run against `cache_path` starting at `verified_at`
+    pub fn new(cache_path: &Path, verified_at: u64) -> Self {
+        Self {
+            version: VERIFY_STATE_VERSION,
+            cache: cache_key(cache_path),
+            cache_stamp: FileStamp::of(cache_path).unwrap_or_default(),
+            verified_at,
+            files: HashMap::new(),
+        }
+    }
+
+    /// State of the last run against `cache_path`, if that cache is unchanged
+    /// since
+    pub fn load(project_root: &Path, cache_path: &Path) -> Option<Self> {
+        let data = std::fs::read(Self::path(project_root)).ok()?;
+        bincode::deserialize::<Self>(&data)
+            .ok()
+            .filter(|state| state.version == VERIFY_STATE_VERSION)
+            .filter(|state| state.cache == cache_key(cache_path))
+            .filter(|state| FileStamp::of(cache_path).is_some_and(|stamp| stamp == state.cache_stamp))
+    }
+
+    pub fn save(&self, project_root: &Path) -> Result<PathBuf> {
+        let path = Self::path(project_root);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let data = bincode::serialize(self)
+            .map_err(|e| FastStartupError::SerializationError(e.to_string()))?;
+        std::fs::write(&path, data)?;
+        Ok(path)
+    }
+
+    /// Whether `relative` differed from the cache last time, if it has not
+    /// been touched since
+    pub fn verdict(&self, relative: &str, stamp: FileStamp) -> Option<bool> {
+        let verdict = self.files.get(relative)?;
+        (verdict.stamp == stamp && stamp.modified < self.verified_at).then_some(verdict.changed)
+    }
+
+    pub fn record(&mut self, relative: String, stamp: FileStamp, changed: bool) {
+        self.files.insert(relative, Verdict { stamp, changed });
+    }
+
+    /// Start of the run these verdicts come from
+    pub fn verified_at(&self) -> u64 {
+        self.verified_at
+    }
+
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
+
+/// Outcome of one quick verify
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuickVerifyResult {
+    pub cache: PathBuf,
+    /// Files that differ from the cache or are not in it, sorted
+    pub changed_assets: Vec<String>,
+    pub files: usize,
+    /// Files hashed this run; the rest reused the last run's verdict
+    pub rehashed: usize,
+    pub elapsed_ms: f64,
+}
+
+impl QuickVerifyResult {
+    pub fn is_valid(&self) -> bool {
+        self.changed_assets.is_empty()
+    }
+}
+
+fn cache_key(cache_path: &Path) -> String {
+    let path = std::fs::canonicalize(cache_path).unwrap_or_else(|_| cache_path.to_path_buf());
+    path.to_string_lossy().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verdict_reuse() {
+        let dir = std::env::temp_dir().join(format!("uefast_verify_state_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let cache = dir.join("Game.uefast");
+        std::fs::write(&cache, b"cache").unwrap();
+
+        let old = FileStamp { size_bytes: 10, modified: 100 };
+        let mut state = VerifyState::new(&cache, 200);
+        state.record("Content/A.uasset".to_string(), old, true);
+        state.record("Content/B.uasset".to_string(), FileStamp { size_bytes: 10, modified: 200 }, false);
+        state.save(&dir).unwrap();
+
+        let state = VerifyState::load(&dir, &cache).unwrap();
+        assert_eq!(state.verdict("Content/A.uasset", old), Some(true));
+        assert_eq!(state.verdict("Content/A.uasset", FileStamp { size_bytes: 11, modified: 100 }), None);
+        // Written during the last run, so its stamp proves nothing
+        assert_eq!(state.verdict("Content/B.uasset", FileStamp { size_bytes: 10, modified: 200 }), None);
+        assert_eq!(state.verdict("Content/C.uasset", old), None);
+
+        // A rebuilt cache invalidates every verdict
+        std::fs::write(&cache, b"rebuilt cache").unwrap();
+        assert!(VerifyState::load(&dir, &cache).is_none());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
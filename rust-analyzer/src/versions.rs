@@ -0,0 +1,239 @@
+//! Engine Version Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Compares per-asset serialized package versions against the project engine
+
+use crate::scanner::{package_name_from_relative, AssetInfo, AssetType};
+use crate::uasset::UAssetParser;
+use crate::Result;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::Path;
+
+/// Latest `EUnrealEngineObjectUE5Version` written by each engine release
+const UE5_OBJECT_VERSIONS: &[(u32, i32)] = &[
+    (0, 1004), // LARGE_WORLD_COORDINATES
+    (1, 1008), // ADD_SOFTOBJECTPATH_LIST
+    (2, 1009), // DATA_RESOURCES
+    (3, 1010), // SCRIPT_SERIALIZATION_OFFSET
+    (4, 1012), // PROPERTY_TAG_COMPLETE_TYPE_NAME
+    (5, 1013), // ASSETREGISTRY_PACKAGEBUILDDEPENDENCIES
+    (6, 1016), // PACKAGE_SAVED_HASH
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EngineVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl EngineVersion {
+    /// Parse a `.uproject` EngineAssociation like "5.3". Source builds use a
+    /// GUID association and have no comparable version.
+    pub fn from_association(association: &str) -> Option<Self> {
+        let mut parts = association.trim().split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        Some(Self { major, minor })
+    }
+
+    /// Expected UE5 object version for packages saved by this engine
+    pub fn ue5_object_version(&self) -> Option<i32> {
+        if self.major != 5 {
+            return None;
+        }
+
+        UE5_OBJECT_VERSIONS
+            .iter()
+            .rev()
+            .find(|(minor, _)| *minor <= self.minor)
+            .map(|(_, version)| *version)
+    }
+}
+
+impl std::fmt::Display for EngineVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VersionStatus {
+    Older,
+    Matching,
+    Newer,
+    Unversioned,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetVersion {
+    pub relative_path: String,
+    /// Long package name under the asset's mount point
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package_name: Option<String>,
+    pub file_version_ue4: i32,
+    pub file_version_ue5: i32,
+    pub file_version_licensee_ue4: i32,
+    pub status: VersionStatus,
+}
+
+/// Distribution of serialized package versions relative to the project engine
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionMatrix {
+    pub engine_association: String,
+    pub expected_ue5_version: Option<i32>,
+    /// Asset count per serialized UE5 object version
+    pub assets_by_ue5_version: BTreeMap<i32, usize>,
+    pub parsed_assets: usize,
+    pub unversioned_assets: usize,
+    /// Saved by an older engine: converted on every load until resaved
+    pub older: Vec<AssetVersion>,
+    /// Saved by a newer engine: the project engine cannot load them
+    pub newer: Vec<AssetVersion>,
+}
+
+impl VersionMatrix {
+    pub fn build(engine_association: &str, assets: &[AssetInfo]) -> Self {
+        let engine = EngineVersion::from_association(engine_association);
+        let expected = engine.and_then(|v| v.ue5_object_version());
+
+        // Releases newer than the table may legitimately write higher versions
+        let newest_known_minor = UE5_OBJECT_VERSIONS.last().map(|(minor, _)| *minor).unwrap_or(0);
+        let detect_newer = engine.map(|v| v.minor <= newest_known_minor).unwrap_or(false);
+
+        let versions: Vec<AssetVersion> = assets
+            .par_iter()
+            .filter(|a| matches!(a.asset_type, AssetType::UAsset | AssetType::UMap))
+            .filter_map(|asset| {
+                let header = UAssetParser::parse_header(&asset.path).ok()?;
+                let status = match classify(&header, expected) {
+                    VersionStatus::Newer if !detect_newer => VersionStatus::Matching,
+                    status => status,
+                };
+                Some(AssetVersion {
+                    relative_path: asset.relative_path.clone(),
+                    package_name: asset.package_name(),
+                    file_version_ue4: header.file_version_ue4,
+                    file_version_ue5: header.file_version_ue5,
+                    file_version_licensee_ue4: header.file_version_licensee_ue4,
+                    status,
+                })
+            })
+            .collect();
+
+        let mut matrix = Self {
+            engine_association: engine_association.to_string(),
+            expected_ue5_version: expected,
+            assets_by_ue5_version: BTreeMap::new(),
+            parsed_assets: versions.len(),
+            unversioned_assets: 0,
+            older: Vec::new(),
+            newer: Vec::new(),
+        };
+
+        for version in versions {
+            *matrix.assets_by_ue5_version.entry(version.file_version_ue5).or_insert(0) += 1;
+            match version.status {
+                VersionStatus::Older => matrix.older.push(version),
+                VersionStatus::Newer => matrix.newer.push(version),
+                VersionStatus::Unversioned => matrix.unversioned_assets += 1,
+                VersionStatus::Matching => {}
+            }
+        }
+
+        matrix.older.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+        matrix.newer.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+        matrix
+    }
+
+    /// Long package names of outdated packages, one per line, in the format
+    /// accepted by the ResavePackages commandlet's `-PackageList`
+    pub fn resave_candidates(&self) -> Vec<String> {
+        self.older
+            .iter()
+            .filter_map(|a| a.package_name.clone().or_else(|| package_name_from_relative(&a.relative_path)))
+            .collect()
+    }
+
+    pub fn write_resave_list(&self, path: &Path) -> Result<usize> {
+        let candidates = self.resave_candidates();
+        let mut file = std::io::BufWriter::new(std::fs::File::create(path)?);
+        for package in &candidates {
+            writeln!(file, "{}", package)?;
+        }
+        file.flush()?;
+        Ok(candidates.len())
+    }
+}
+
+fn classify(header: &crate::uasset::UAssetHeader, expected_ue5: Option<i32>) -> VersionStatus {
+    if header.is_unversioned() {
+        return VersionStatus::Unversioned;
+    }
+
+    // Pre-UE5 package in a UE5 project
+    if header.file_version_ue5 == 0 {
+        return match expected_ue5 {
+            Some(_) => VersionStatus::Older,
+            None => VersionStatus::Matching,
+        };
+    }
+
+    match expected_ue5 {
+        Some(expected) if header.file_version_ue5 < expected => VersionStatus::Older,
+        Some(expected) if header.file_version_ue5 > expected => VersionStatus::Newer,
+        _ => VersionStatus::Matching,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_engine_version_from_association() {
+        assert_eq!(
+            EngineVersion::from_association("5.3"),
+            Some(EngineVersion { major: 5, minor: 3 })
+        );
+        assert_eq!(EngineVersion::from_association("{8A1B2C3D-0000}"), None);
+    }
+
+    #[test]
+    fn test_ue5_object_version() {
+        let v = |s| EngineVersion::from_association(s).unwrap().ue5_object_version();
+        assert_eq!(v("5.0"), Some(1004));
+        assert_eq!(v("5.3"), Some(1010));
+        // Unknown future minors map to the newest known version
+        assert_eq!(v("5.9"), Some(1016));
+        assert_eq!(v("4.27"), None);
+    }
+
+    #[test]
+    fn test_resave_candidates_include_plugins() {
+        let version = |relative_path: &str, package_name: Option<&str>| AssetVersion {
+            relative_path: relative_path.to_string(),
+            package_name: package_name.map(str::to_string),
+            file_version_ue4: 522,
+            file_version_ue5: 1004,
+            file_version_licensee_ue4: 0,
+            status: VersionStatus::Older,
+        };
+        let matrix = VersionMatrix {
+            engine_association: "5.3".to_string(),
+            expected_ue5_version: Some(1010),
+            assets_by_ue5_version: BTreeMap::new(),
+            parsed_assets: 2,
+            unversioned_assets: 0,
+            older: vec![
+                version("Content/Maps/Entry.umap", None),
+                version("Plugins/Gameplay/WeaponPack/Content/Rifles/AK.uasset", Some("/Weapons/Rifles/AK")),
+            ],
+            newer: Vec::new(),
+        };
+        assert_eq!(matrix.resave_candidates(), ["/Game/Maps/Entry", "/Weapons/Rifles/AK"]);
+    }
+}
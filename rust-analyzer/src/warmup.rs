@@ -0,0 +1,317 @@
+//! Warmup Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! OS page cache state of the startup set. A sample of the startup-critical
+//! files is checked with mincore to tell whether the next launch reads from
+//! memory or from disk, and the load simulation prices both cases; `warmup`
+//! pre-reads the set so the launch starts warm.
+
+use crate::load_sim::{IoModel, LoadSimulation, OrderStrategy};
+use crate::report::{Finding, ReportData, Severity, Table};
+use crate::scanner::{package_stem, AssetInfo, AssetType};
+use crate::{FastStartupError, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+const MB: f64 = 1024.0 * 1024.0;
+
+/// Files whose residency is checked, spread over the load order
+pub const MAX_SAMPLED_FILES: usize = 256;
+
+/// Resident share at or above which the startup set counts as warm
+pub const WARM_RATIO: f64 = 0.9;
+
+/// Resident share at or below which the startup set counts as cold
+pub const COLD_RATIO: f64 = 0.1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheState {
+    Cold,
+    Partial,
+    Warm,
+    /// Residency cannot be queried on this platform
+    Unknown,
+}
+
+impl CacheState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CacheState::Cold => "cold",
+            CacheState::Partial => "partial",
+            CacheState::Warm => "warm",
+            CacheState::Unknown => "unknown",
+        }
+    }
+
+    fn of(resident_ratio: Option<f64>) -> Self {
+        match resident_ratio {
+            None => CacheState::Unknown,
+            Some(ratio) if ratio >= WARM_RATIO => CacheState::Warm,
+            Some(ratio) if ratio <= COLD_RATIO => CacheState::Cold,
+            Some(_) => CacheState::Partial,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WarmupStatus {
+    pub project_name: String,
+    pub io: IoModel,
+    /// Startup packages and their `.uexp`/`.ubulk` companions
+    pub startup_files: usize,
+    pub startup_bytes: u64,
+    pub sampled_files: usize,
+    pub sampled_bytes: u64,
+    /// Of `sampled_bytes`, bytes in the page cache; `None` when unknown
+    pub resident_bytes: Option<u64>,
+    pub state: CacheState,
+    /// Startup reads with nothing cached
+    pub cold_ms: f64,
+    /// Startup reads served from the page cache
+    pub warm_ms: f64,
+    /// Cold and warm time blended by the resident share
+    pub expected_ms: f64,
+}
+
+impl WarmupStatus {
+    /// Sample the residency of the files behind `load_order` (relative
+    /// startup package paths) and price the launch against `io`
+    pub fn sample(
+        project_name: &str,
+        project_root: &Path,
+        load_order: &[String],
+        assets: &[AssetInfo],
+        io: IoModel,
+    ) -> Self {
+        let files = startup_files(project_root, load_order, assets);
+        let startup_bytes = files.iter().map(|(_, size)| size).sum();
+
+        let step = files.len().div_ceil(MAX_SAMPLED_FILES).max(1);
+        let mut sampled_files = 0;
+        let mut sampled_bytes = 0;
+        let mut resident_bytes = Some(0);
+        for (path, _) in files.iter().step_by(step) {
+            let Some((resident, size)) = page_residency(path) else {
+                resident_bytes = None;
+                break;
+            };
+            sampled_files += 1;
+            sampled_bytes += size;
+            resident_bytes = resident_bytes.map(|r| r + resident);
+        }
+        if sampled_files == 0 {
+            resident_bytes = None;
+        }
+
+        let simulate = |io: IoModel| {
+            LoadSimulation::run(project_name, load_order, assets, io, &[OrderStrategy::Computed], &[])
+                .fastest()
+                .map(|o| o.total_ms)
+                .unwrap_or(0.0)
+        };
+        let cold_ms = simulate(io);
+        let warm_ms = simulate(IoModel::page_cache());
+
+        let mut status = Self {
+            project_name: project_name.to_string(),
+            io,
+            startup_files: files.len(),
+            startup_bytes,
+            sampled_files,
+            sampled_bytes,
+            resident_bytes,
+            state: CacheState::Unknown,
+            cold_ms,
+            warm_ms,
+            expected_ms: cold_ms,
+        };
+        status.state = CacheState::of(status.resident_ratio());
+        if let Some(ratio) = status.resident_ratio() {
+            status.expected_ms = cold_ms * (1.0 - ratio) + warm_ms * ratio;
+        }
+        status
+    }
+
+    /// Resident share of the sampled bytes
+    pub fn resident_ratio(&self) -> Option<f64> {
+        let resident = self.resident_bytes?;
+        if self.sampled_bytes == 0 {
+            return Some(1.0);
+        }
+        Some(resident as f64 / self.sampled_bytes as f64)
+    }
+}
+
+/// Read every file behind `load_order` once so the OS caches it; returns
+/// the files and bytes read
+pub fn warm(project_root: &Path, load_order: &[String], assets: &[AssetInfo]) -> Result<(usize, u64)> {
+    let files = startup_files(project_root, load_order, assets);
+    let mut bytes = 0;
+    for (path, _) in &files {
+        let mut file = File::open(path)?;
+        bytes += std::io::copy(&mut file, &mut std::io::sink())?;
+    }
+    Ok((files.len(), bytes))
+}
+
+/// Startup packages in load order, each followed by its companions
+fn startup_files(project_root: &Path, load_order: &[String], assets: &[AssetInfo]) -> Vec<(PathBuf, u64)> {
+    let stem = |relative: &str| package_stem(Path::new(""), relative);
+    let mut by_stem: HashMap<String, Vec<&AssetInfo>> = HashMap::new();
+    for asset in assets {
+        by_stem.entry(stem(&asset.relative_path)).or_default().push(asset);
+    }
+
+    let mut seen: HashSet<&Path> = HashSet::new();
+    let mut files = Vec::new();
+    for relative in load_order {
+        let Some(package) = by_stem.get(&stem(relative)) else {
+            continue;
+        };
+        let package_first = package
+            .iter()
+            .filter(|a| !matches!(a.asset_type, AssetType::UExp | AssetType::UBulk))
+            .chain(package.iter().filter(|a| matches!(a.asset_type, AssetType::UExp | AssetType::UBulk)));
+        for asset in package_first {
+            if seen.insert(asset.path.as_path()) {
+                files.push((project_root.join(&asset.relative_path), asset.size_bytes));
+            }
+        }
+    }
+    files
+}
+
+/// Bytes of `path` in the OS page cache and its size, from mincore over a
+/// read-only mapping. `None` when the file cannot be mapped or the platform
+/// offers no residency query (Windows' QueryWorkingSetEx only sees pages
+/// faulted into the calling process, not the standby list).
+pub fn page_residency(path: &Path) -> Option<(u64, u64)> {
+    #[cfg(unix)]
+    {
+        let file = File::open(path).ok()?;
+        let len = file.metadata().ok()?.len();
+        if len == 0 {
+            return Some((0, 0));
+        }
+        // SAFETY: the mapping is only passed to mincore, never read
+        let map = unsafe { memmap2::Mmap::map(&file) }.ok()?;
+        let page = usize::try_from(unsafe { libc::sysconf(libc::_SC_PAGESIZE) }).ok()?.max(1);
+        let mut pages = vec![0; map.len().div_ceil(page)];
+        let rc = unsafe { libc::mincore(map.as_ptr() as *mut libc::c_void, map.len(), pages.as_mut_ptr()) };
+        if rc != 0 {
+            return None;
+        }
+        let resident = pages.iter().filter(|&&p| p & 1 != 0).count() as u64 * page as u64;
+        Some((resident.min(len), len))
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = path;
+        None
+    }
+}
+
+impl ReportData for WarmupStatus {
+    fn title(&self) -> String {
+        format!("Page cache status: {}", self.project_name)
+    }
+
+    fn to_json(&self) -> Result<Value> {
+        serde_json::to_value(self).map_err(|e| FastStartupError::SerializationError(e.to_string()))
+    }
+
+    fn summary(&self) -> Vec<(String, String)> {
+        vec![
+            ("State".to_string(), self.state.as_str().to_string()),
+            (
+                "Resident".to_string(),
+                self.resident_ratio().map(|r| format!("{:.0}%", r * 100.0)).unwrap_or_else(|| "unknown".to_string()),
+            ),
+            (
+                "Sampled".to_string(),
+                format!("{} of {} files ({:.1} MB)", self.sampled_files, self.startup_files, self.sampled_bytes as f64 / MB),
+            ),
+            ("I/O model".to_string(), self.io.describe()),
+            ("Expected startup reads".to_string(), format!("{:.0} ms", self.expected_ms)),
+        ]
+    }
+
+    fn table(&self) -> Table {
+        let mut table = Table::new(&["scenario", "read_ms"]);
+        table.push(vec!["cold".to_string(), format!("{:.1}", self.cold_ms)]);
+        table.push(vec!["warm".to_string(), format!("{:.1}", self.warm_ms)]);
+        table.push(vec!["expected".to_string(), format!("{:.1}", self.expected_ms)]);
+        table
+    }
+
+    fn findings(&self) -> Vec<Finding> {
+        match self.state {
+            CacheState::Cold | CacheState::Partial => vec![Finding {
+                rule_id: "warmup/cold".to_string(),
+                severity: Severity::Note,
+                message: format!(
+                    "{:.0} MB of the startup set is not cached; `warmup` before launching saves ~{:.0} ms of reads",
+                    self.startup_bytes as f64 * (1.0 - self.resident_ratio().unwrap_or(0.0)) / MB,
+                    self.expected_ms - self.warm_ms
+                ),
+                location: None,
+            }],
+            CacheState::Unknown => vec![Finding {
+                rule_id: "warmup/unknown".to_string(),
+                severity: Severity::Note,
+                message: "Page cache residency cannot be sampled here; the expected time assumes a cold start".to_string(),
+                location: None,
+            }],
+            CacheState::Warm => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_warmup_status() {
+        let root = std::env::temp_dir().join(format!("uefast_warmup_{}", std::process::id()));
+        std::fs::create_dir_all(root.join("Content")).unwrap();
+        let mut assets = Vec::new();
+        for (name, size) in [("Content/Menu.uasset", 8192usize), ("Content/Menu.uexp", 65536), ("Content/Other.uasset", 4096)] {
+            std::fs::write(root.join(name), vec![1u8; size]).unwrap();
+            assets.push(AssetInfo {
+                path: root.join(name),
+                relative_path: name.to_string(),
+                asset_type: AssetType::from_extension(name.rsplit('.').next().unwrap()),
+                size_bytes: size as u64,
+                modified: 0,
+                mount_point: "/Game".to_string(),
+            });
+        }
+        let load_order = vec!["Content/Menu.uasset".to_string()];
+
+        let files = startup_files(&root, &load_order, &assets);
+        assert_eq!(files.len(), 2);
+        assert!(files[0].0.ends_with("Menu.uasset"));
+        assert_eq!(warm(&root, &load_order, &assets).unwrap(), (2, 8192 + 65536));
+
+        let io = IoModel::for_tier(crate::estimator::StorageTier::Hdd);
+        let status = WarmupStatus::sample("Game", &root, &load_order, &assets, io);
+        assert_eq!(status.startup_bytes, 8192 + 65536);
+        assert!(status.cold_ms > status.warm_ms);
+        if cfg!(unix) {
+            // Just written and read back, so resident unless memory is tight
+            assert_eq!(status.sampled_files, 2);
+            assert!(status.resident_bytes.is_some());
+            assert!(status.expected_ms <= status.cold_ms);
+        } else {
+            assert_eq!(status.state, CacheState::Unknown);
+        }
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,226 @@
+//! Watcher Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Keeps a `.uefast` cache current during development. File system events
+//! under the content roots are collected until the tree has been quiet for
+//! the debounce interval; the touched assets are then rehashed into the
+//! in-memory cache and the file is rewritten atomically.
+
+use crate::cache::StartupCache;
+use crate::cache_log::{self, CacheChanges, CacheLogEntry};
+use crate::config::ProjectConfig;
+use crate::project::{content_root_of, content_roots, ContentRoot};
+use crate::scanner::AssetType;
+use crate::{FastStartupError, Result};
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+/// Quiet time after the last event before the cache is written
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_secs(2);
+
+/// A steady stream of events is flushed after this many debounce intervals
+/// at the latest
+pub const MAX_DEBOUNCE_INTERVALS: u32 = 10;
+
+pub struct CacheWatcher {
+    project_root: PathBuf,
+    cache_path: PathBuf,
+    cache: StartupCache,
+    roots: Vec<ContentRoot>,
+    /// The project's `.uefast.toml`, whose exclude globs full updates apply
+    config: ProjectConfig,
+    debounce: Duration,
+    log: bool,
+}
+
+impl CacheWatcher {
+    /// Watch `project_root` for the cache at `cache_path`, which must exist
+    pub fn new(project_root: &Path, cache_path: &Path) -> Result<Self> {
+        if !cache_path.exists() {
+            return Err(FastStartupError::CacheError(format!(
+                "{} does not exist; build it with `cache` first",
+                cache_path.display()
+            )));
+        }
+        Ok(Self {
+            project_root: project_root.to_path_buf(),
+            cache_path: cache_path.to_path_buf(),
+            cache: StartupCache::load(cache_path)?,
+            roots: content_roots(project_root),
+            config: ProjectConfig::default(),
+            debounce: DEFAULT_DEBOUNCE,
+            log: cache_log::log_path(cache_path).exists(),
+        })
+    }
+
+    /// Update with the exclude globs of `config`, the project's loaded
+    /// `.uefast.toml`
+    pub fn with_config(mut self, config: &ProjectConfig) -> Self {
+        self.config = config.clone();
+        self
+    }
+
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Append every write to the cache's change log
+    pub fn with_log(mut self, log: bool) -> Self {
+        self.log |= log;
+        self
+    }
+
+    pub fn cache(&self) -> &StartupCache {
+        &self.cache
+    }
+
+    /// Catch up with changes made while nobody watched, then write the cache
+    /// after every burst of changes until the watch fails
+    pub fn run(&mut self) -> Result<()> {
+        let changes = self.cache.update(&self.project_root, &self.config)?;
+        self.write("update", changes)?;
+
+        let (sender, events) = channel();
+        let mut watcher = RecommendedWatcher::new(sender, notify::Config::default())
+            .map_err(|e| FastStartupError::WatchError(e.to_string()))?;
+        for root in &self.roots {
+            let dir = self.project_root.join(&root.relative_dir);
+            watcher
+                .watch(&dir, RecursiveMode::Recursive)
+                .map_err(|e| FastStartupError::WatchError(format!("{}: {}", dir.display(), e)))?;
+        }
+        info!("Watching {} content roots (debounce {:?})", self.roots.len(), self.debounce);
+
+        let mut pending: BTreeSet<String> = BTreeSet::new();
+        let mut pending_since = Instant::now();
+        loop {
+            let wait = if pending.is_empty() { Duration::from_secs(3600) } else { self.debounce };
+            match events.recv_timeout(wait) {
+                Ok(Ok(event)) => {
+                    if pending.is_empty() {
+                        pending_since = Instant::now();
+                    }
+                    pending.extend(event.paths.iter().filter_map(|path| self.relative_asset_path(path)));
+                    if pending.is_empty() || pending_since.elapsed() < self.debounce * MAX_DEBOUNCE_INTERVALS {
+                        continue;
+                    }
+                }
+                Ok(Err(e)) => {
+                    warn!("Watch error: {}", e);
+                    continue;
+                }
+                Err(RecvTimeoutError::Timeout) if pending.is_empty() => continue,
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => {
+                    return Err(FastStartupError::WatchError("the file system watcher stopped".to_string()));
+                }
+            }
+
+            let paths: Vec<String> = std::mem::take(&mut pending).into_iter().collect();
+            if let Err(e) = self.flush(&paths) {
+                warn!("Failed to update the cache: {}", e);
+            }
+        }
+    }
+
+    /// Rehash `relative_paths` into the cache and write it if anything changed
+    pub fn flush(&mut self, relative_paths: &[String]) -> Result<CacheChanges> {
+        debug!("Flushing {} touched paths", relative_paths.len());
+        let changes = self.cache.update_paths(&self.project_root, &self.config, relative_paths)?;
+        self.write("watch", changes.clone())?;
+        Ok(changes)
+    }
+
+    fn write(&mut self, operation: &str, changes: CacheChanges) -> Result<()> {
+        if changes.total() == 0 {
+            return Ok(());
+        }
+        self.cache.save_atomic(&self.cache_path)?;
+        info!(
+            "Cache updated: {} changed, {} added, {} removed, {} renamed",
+            changes.changed.len(),
+            changes.added.len(),
+            changes.removed.len(),
+            changes.renamed.len()
+        );
+        if self.log {
+            cache_log::append(&self.cache_path, &CacheLogEntry::new(operation, &self.cache, changes))?;
+        }
+        Ok(())
+    }
+
+    /// Project-relative path of an asset under a content root, as the
+    /// scanner lists it
+    fn relative_asset_path(&self, path: &Path) -> Option<String> {
+        let relative = path.strip_prefix(&self.project_root).ok()?.to_string_lossy().to_string();
+        let ext = Path::new(&relative).extension().and_then(|ext| ext.to_str())?;
+        if AssetType::from_extension(ext) == AssetType::Other {
+            return None;
+        }
+        content_root_of(&self.roots, &relative).map(|_| relative)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::CacheBuilder;
+
+    #[test]
+    fn test_flush_updates_cache_file() {
+        let root = std::env::temp_dir().join(format!("uefast_watcher_{}", std::process::id()));
+        std::fs::create_dir_all(root.join("Content/UI")).unwrap();
+        std::fs::write(root.join("Content/UI/Menu.uasset"), b"menu").unwrap();
+        std::fs::write(root.join("Content/UI/Old.uasset"), b"old").unwrap();
+        let cache_path = root.join("Game.uefast");
+        CacheBuilder::new(&root).unwrap().build().unwrap().save(&cache_path).unwrap();
+
+        let mut watcher = CacheWatcher::new(&root, &cache_path).unwrap();
+        std::fs::write(root.join("Content/UI/Menu.uasset"), b"menu v2").unwrap();
+        std::fs::write(root.join("Content/UI/New.uasset"), b"new").unwrap();
+        std::fs::remove_file(root.join("Content/UI/Old.uasset")).unwrap();
+        let touched: Vec<String> = ["Menu", "New", "Old"]
+            .iter()
+            .map(|name| Path::new("Content").join("UI").join(format!("{}.uasset", name)).to_string_lossy().to_string())
+            .collect();
+        assert_eq!(watcher.relative_asset_path(&root.join(&touched[0])), Some(touched[0].clone()));
+        assert_eq!(watcher.relative_asset_path(&root.join("Content/UI/notes.txt")), None);
+
+        let changes = watcher.flush(&touched).unwrap();
+        assert_eq!(changes.changed, [touched[0].clone()]);
+        assert_eq!(changes.added, [touched[1].clone()]);
+        assert_eq!(changes.removed, [touched[2].clone()]);
+
+        let saved = StartupCache::load(&cache_path).unwrap();
+        assert_eq!(saved.asset_count(), 2);
+        assert!(saved.verify(&root, &ProjectConfig::default()).unwrap().is_valid);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_flush_skips_excluded_assets() {
+        let root = std::env::temp_dir().join(format!("uefast_watcher_exclude_{}", std::process::id()));
+        std::fs::create_dir_all(root.join("Content/UI")).unwrap();
+        std::fs::create_dir_all(root.join("Content/Developers")).unwrap();
+        std::fs::write(root.join("Content/UI/Menu.uasset"), b"menu").unwrap();
+        let config = ProjectConfig { exclude: vec!["Content/Developers/".to_string()], ..Default::default() };
+        let cache_path = root.join("Game.uefast");
+        CacheBuilder::new(&root).unwrap().with_config(&config).build().unwrap().save(&cache_path).unwrap();
+
+        let mut watcher = CacheWatcher::new(&root, &cache_path).unwrap().with_config(&config);
+        std::fs::write(root.join("Content/Developers/Sandbox.uasset"), b"sandbox").unwrap();
+        let changes = watcher.flush(&["Content/Developers/Sandbox.uasset".to_string()]).unwrap();
+        assert!(changes.added.is_empty());
+
+        let saved = StartupCache::load(&cache_path).unwrap();
+        assert_eq!(saved.asset_count(), 1);
+        assert!(saved.verify(&root, &config).unwrap().is_valid);
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
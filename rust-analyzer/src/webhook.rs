@@ -0,0 +1,125 @@
+//! Webhook Notification Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! Posts budget breaches and startup regressions to Slack/Teams channels
+
+use crate::check::CheckReport;
+use crate::config::NotifyConfig;
+use crate::{FastStartupError, Result};
+use serde_json::json;
+use tracing::info;
+
+const MB: f64 = 1024.0 * 1024.0;
+
+pub const DEFAULT_TEMPLATE: &str =
+    "UE5 startup {status} for *{project}*: {startup_mb} MB at startup ({delta_mb} MB vs baseline). {details}";
+
+/// Values substituted into the message template
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub project: String,
+    pub status: String,
+    pub startup_size_bytes: u64,
+    pub delta_bytes: Option<i64>,
+    pub details: String,
+}
+
+impl Notification {
+    pub fn from_check(check: &CheckReport) -> Self {
+        let breaches: Vec<String> = check.budgets
+            .iter()
+            .filter(|b| !b.passed)
+            .map(|b| format!("{} {:.1}/{:.1} {}", b.name, b.actual, b.limit, b.unit))
+            .collect();
+
+        let mut details = if breaches.is_empty() {
+            String::new()
+        } else {
+            format!("Over budget: {}.", breaches.join(", "))
+        };
+        if let Some(top) = check.new_offenders.first() {
+            details.push_str(&format!(
+                " Largest new startup asset: {} ({:.1} MB).",
+                top.relative_path,
+                top.size_bytes as f64 / MB
+            ));
+        }
+
+        Self {
+            project: check.project_name.clone(),
+            status: if check.passed { "check passed" } else { "budget breach" }.to_string(),
+            startup_size_bytes: check.startup_size_bytes,
+            delta_bytes: check.baseline.as_ref().map(|d| d.startup_size_delta_bytes),
+            details: details.trim().to_string(),
+        }
+    }
+
+    /// Whether this notification should be posted under `config`
+    pub fn should_send(&self, config: &NotifyConfig, breached: bool) -> bool {
+        let threshold = (config.regression_threshold_mb * MB) as i64;
+        let regressed = self.delta_bytes.map(|d| d >= threshold).unwrap_or(false);
+        config.always || breached || regressed
+    }
+}
+
+/// Replace `{project}`, `{status}`, `{startup_mb}`, `{delta_mb}` and `{details}`
+pub fn render_template(template: &str, n: &Notification) -> String {
+    let delta = n.delta_bytes
+        .map(|d| format!("{:+.1}", d as f64 / MB))
+        .unwrap_or_else(|| "n/a".to_string());
+
+    template
+        .replace("{project}", &n.project)
+        .replace("{status}", &n.status)
+        .replace("{startup_mb}", &format!("{:.1}", n.startup_size_bytes as f64 / MB))
+        .replace("{delta_mb}", &delta)
+        .replace("{details}", &n.details)
+}
+
+/// Post to the configured webhook. Slack and Teams both accept a `text` payload.
+pub fn send(config: &NotifyConfig, notification: &Notification) -> Result<()> {
+    let template = config.template.as_deref().unwrap_or(DEFAULT_TEMPLATE);
+    let text = render_template(template, notification);
+
+    ureq::post(&config.webhook_url)
+        .send_json(json!({ "text": text }))
+        .map_err(|e| FastStartupError::NotifyError(e.to_string()))?;
+
+    info!("Notification posted for {}", notification.project);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notification(delta_mb: i64) -> Notification {
+        Notification {
+            project: "Game".to_string(),
+            status: "regression".to_string(),
+            startup_size_bytes: 50 * 1024 * 1024,
+            delta_bytes: Some(delta_mb * 1024 * 1024),
+            details: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_render_template() {
+        let text = render_template("{project}: {startup_mb} MB ({delta_mb})", &notification(3));
+        assert_eq!(text, "Game: 50.0 MB (+3.0)");
+    }
+
+    #[test]
+    fn test_should_send_on_regression() {
+        let config = NotifyConfig {
+            webhook_url: String::new(),
+            template: None,
+            regression_threshold_mb: 10.0,
+            always: false,
+        };
+        assert!(!notification(3).should_send(&config, false));
+        assert!(notification(12).should_send(&config, false));
+        assert!(notification(3).should_send(&config, true));
+    }
+}
@@ -0,0 +1,221 @@
+//! Setup Wizard Module
+//! Copyright 2026 Eddi Andreé Salazar Matos
+//! Licensed under Apache 2.0
+//!
+//! `init`: asks for storage, startup maps and budgets (or takes detected
+//! defaults) and turns the answers into a `.uefast.toml`
+
+use crate::check::Budgets;
+use crate::config::ProjectConfig;
+use crate::estimator::StorageTier;
+use crate::scenarios::ScenarioConfig;
+use crate::{FastStartupError, Result};
+use std::io::{BufRead, Write};
+use std::path::Path;
+
+const MB: f64 = 1024.0 * 1024.0;
+
+/// Scenario created from the startup maps
+pub const STARTUP_SCENARIO: &str = "Startup";
+
+/// `GameMapsSettings` keys naming maps loaded at boot
+const MAP_KEYS: &[&str] = &["GameDefaultMap", "ServerDefaultMap", "TransitionMap"];
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InitAnswers {
+    pub storage: Option<StorageTier>,
+    /// Package names, e.g. `/Game/Maps/Entry`
+    pub startup_maps: Vec<String>,
+    pub max_startup_mb: Option<f64>,
+    pub max_startup_assets: Option<usize>,
+}
+
+impl InitAnswers {
+    /// Merge the answers into `config`, keeping unrelated sections
+    pub fn apply(&self, config: &mut ProjectConfig) {
+        config.storage = self.storage;
+        config.budgets = Budgets {
+            max_startup_bytes: self.max_startup_mb.map(|mb| (mb * MB) as u64),
+            max_startup_assets: self.max_startup_assets,
+            ..config.budgets.clone()
+        };
+        if self.startup_maps.is_empty() {
+            config.scenarios.remove(STARTUP_SCENARIO);
+        } else {
+            let scenario = config.scenarios.entry(STARTUP_SCENARIO.to_string()).or_default();
+            *scenario = ScenarioConfig {
+                roots: self.startup_maps.clone(),
+                ..scenario.clone()
+            };
+        }
+    }
+}
+
+/// Maps the project boots into, from `Config/DefaultEngine.ini`
+pub fn detect_startup_maps(project_root: &Path) -> Vec<String> {
+    std::fs::read_to_string(project_root.join("Config").join("DefaultEngine.ini"))
+        .map(|ini| parse_startup_maps(&ini))
+        .unwrap_or_default()
+}
+
+/// `GameDefaultMap=/Game/Maps/Entry.Entry` and friends, as package names
+pub fn parse_startup_maps(ini: &str) -> Vec<String> {
+    let mut maps: Vec<String> = Vec::new();
+    for line in ini.lines() {
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        if !MAP_KEYS.contains(&key.trim()) {
+            continue;
+        }
+        let package = value.trim().trim_matches('"').split('.').next().unwrap_or_default();
+        if package.starts_with("/Game/") && !maps.iter().any(|m| m == package) {
+            maps.push(package.to_string());
+        }
+    }
+    maps
+}
+
+/// Commands worth running once the config is in place
+pub fn recommended_commands(project_root: &Path) -> Vec<String> {
+    let project = project_root.display();
+    vec![
+        format!("ue5-fast-startup optimize --project \"{}\"", project),
+        format!("ue5-fast-startup check --project \"{}\"", project),
+        format!("ue5-fast-startup analyze --project \"{}\" --record", project),
+        format!("ue5-fast-startup calibrate --project \"{}\" --log <Saved/Logs/Game.log>", project),
+    ]
+}
+
+/// Line-based prompts; with `defaults` every question takes its default
+/// without reading input
+pub struct Prompter<R, W> {
+    input: R,
+    output: W,
+    defaults: bool,
+}
+
+impl<R: BufRead, W: Write> Prompter<R, W> {
+    pub fn new(input: R, output: W, defaults: bool) -> Self {
+        Self { input, output, defaults }
+    }
+
+    /// Ask `question`; an empty answer takes `default`
+    pub fn ask(&mut self, question: &str, default: &str) -> Result<String> {
+        write!(self.output, "{} [{}]: ", question, default)?;
+        if self.defaults {
+            writeln!(self.output, "{}", default)?;
+            return Ok(default.to_string());
+        }
+        self.output.flush()?;
+
+        let mut line = String::new();
+        self.input.read_line(&mut line)?;
+        let answer = line.trim();
+        Ok(if answer.is_empty() { default } else { answer }.to_string())
+    }
+
+    /// Ask until the answer parses; "none" or an empty default yields `None`
+    pub fn ask_parsed<T>(&mut self, question: &str, default: Option<T>) -> Result<Option<T>>
+    where
+        T: std::str::FromStr + std::fmt::Display,
+    {
+        let default_text = default.map(|d| d.to_string()).unwrap_or_else(|| "none".to_string());
+        loop {
+            let answer = self.ask(question, &default_text)?;
+            if answer.eq_ignore_ascii_case("none") {
+                return Ok(None);
+            }
+            match answer.parse() {
+                Ok(value) => return Ok(Some(value)),
+                Err(_) if self.defaults => {
+                    return Err(FastStartupError::InvalidArgument(format!(
+                        "Invalid default '{}' for: {}",
+                        answer, question
+                    )))
+                }
+                Err(_) => writeln!(self.output, "  '{}' is not valid, try again", answer)?,
+            }
+        }
+    }
+
+    /// Walk through every question, proposing `detected` values
+    pub fn run(&mut self, detected: &InitAnswers) -> Result<InitAnswers> {
+        let storage = self.ask_parsed(
+            "Storage the project lives on (nvme, ssd, hdd, network)",
+            detected.storage,
+        )?;
+
+        let maps = self.ask(
+            "Startup maps, comma separated",
+            &if detected.startup_maps.is_empty() {
+                "none".to_string()
+            } else {
+                detected.startup_maps.join(", ")
+            },
+        )?;
+        let startup_maps = maps
+            .split(',')
+            .map(|m| m.trim().split('.').next().unwrap_or_default().to_string())
+            .filter(|m| m.starts_with('/'))
+            .collect();
+
+        let max_startup_mb = self.ask_parsed("Startup size budget in MB", detected.max_startup_mb)?;
+        let max_startup_assets = self.ask_parsed("Startup asset count budget", detected.max_startup_assets)?;
+
+        Ok(InitAnswers {
+            storage,
+            startup_maps,
+            max_startup_mb,
+            max_startup_assets,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_startup_maps() {
+        let ini = "[/Script/EngineSettings.GameMapsSettings]\n\
+                   EditorStartupMap=/Game/Maps/Editor.Editor\n\
+                   GameDefaultMap=/Game/Maps/Entry.Entry\n\
+                   TransitionMap=/Game/Maps/Entry.Entry\n\
+                   ServerDefaultMap=/Engine/Maps/Entry\n";
+        assert_eq!(parse_startup_maps(ini), ["/Game/Maps/Entry"]);
+    }
+
+    #[test]
+    fn test_prompts_and_apply() {
+        let input = "ssd\n\n512\nlots\n2000\n";
+        let mut output = Vec::new();
+        let detected = InitAnswers {
+            storage: Some(StorageTier::Nvme),
+            startup_maps: vec!["/Game/Maps/Entry".to_string()],
+            ..Default::default()
+        };
+        let answers = Prompter::new(input.as_bytes(), &mut output, false).run(&detected).unwrap();
+
+        assert_eq!(answers.storage, Some(StorageTier::Ssd));
+        assert_eq!(answers.startup_maps, ["/Game/Maps/Entry"]);
+        assert_eq!(answers.max_startup_mb, Some(512.0));
+        assert_eq!(answers.max_startup_assets, Some(2000));
+        assert!(String::from_utf8(output).unwrap().contains("'lots' is not valid"));
+
+        let mut config = ProjectConfig::default();
+        answers.apply(&mut config);
+        assert_eq!(config.budgets.max_startup_bytes, Some(512 * 1024 * 1024));
+        assert_eq!(config.scenarios[STARTUP_SCENARIO].roots, ["/Game/Maps/Entry"]);
+    }
+
+    #[test]
+    fn test_defaults_skip_input() {
+        let detected = InitAnswers {
+            storage: Some(StorageTier::Hdd),
+            ..Default::default()
+        };
+        let answers = Prompter::new(&b""[..], Vec::new(), true).run(&detected).unwrap();
+        assert_eq!(answers, detected);
+    }
+}